@@ -0,0 +1,150 @@
+use crate::types::McpTool;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A difference between two tool listings from the same service.
+#[derive(Debug, Clone)]
+pub enum ToolCatalogChange {
+    Added {
+        service: String,
+        tool: McpTool,
+    },
+    Removed {
+        service: String,
+        tool: McpTool,
+    },
+    SchemaChanged {
+        service: String,
+        previous: McpTool,
+        current: McpTool,
+    },
+}
+
+impl ToolCatalogChange {
+    pub fn service(&self) -> &str {
+        match self {
+            ToolCatalogChange::Added { service, .. }
+            | ToolCatalogChange::Removed { service, .. }
+            | ToolCatalogChange::SchemaChanged { service, .. } => service,
+        }
+    }
+
+    pub fn tool_name(&self) -> &str {
+        match self {
+            ToolCatalogChange::Added { tool, .. } | ToolCatalogChange::Removed { tool, .. } => {
+                &tool.name
+            }
+            ToolCatalogChange::SchemaChanged { current, .. } => &current.name,
+        }
+    }
+}
+
+pub type ToolCatalogListener = Arc<dyn Fn(&[ToolCatalogChange]) + Send + Sync>;
+
+/// Compares two listings by tool name. A tool counts as changed when its input or
+/// output schema differs; description and annotation edits are ignored.
+pub fn diff_tool_catalog(
+    service: &str,
+    previous: &[McpTool],
+    current: &[McpTool],
+) -> Vec<ToolCatalogChange> {
+    let before: HashMap<&str, &McpTool> = previous.iter().map(|t| (t.name.as_str(), t)).collect();
+    let after: HashMap<&str, &McpTool> = current.iter().map(|t| (t.name.as_str(), t)).collect();
+
+    let mut changes = Vec::new();
+
+    for tool in current {
+        match before.get(tool.name.as_str()) {
+            None => changes.push(ToolCatalogChange::Added {
+                service: service.to_string(),
+                tool: tool.clone(),
+            }),
+            Some(old)
+                if old.input_schema != tool.input_schema
+                    || old.output_schema != tool.output_schema =>
+            {
+                changes.push(ToolCatalogChange::SchemaChanged {
+                    service: service.to_string(),
+                    previous: (*old).clone(),
+                    current: tool.clone(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+
+    for tool in previous {
+        if !after.contains_key(tool.name.as_str()) {
+            changes.push(ToolCatalogChange::Removed {
+                service: service.to_string(),
+                tool: tool.clone(),
+            });
+        }
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn tool(name: &str, schema: serde_json::Value) -> McpTool {
+        McpTool {
+            name: name.to_string(),
+            title: None,
+            description: Some(format!("{} tool", name)),
+            input_schema: schema,
+            output_schema: None,
+            annotations: None,
+            tags: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_detects_added_removed_and_schema_changes() {
+        let previous = vec![
+            tool("scan", json!({"type": "object"})),
+            tool("score", json!({"type": "object"})),
+        ];
+        let mut updated_score = tool("score", json!({"type": "object", "required": ["mint"]}));
+        updated_score.description = Some("reworded".to_string());
+        let current = vec![
+            updated_score,
+            tool("execute", json!({"type": "object"})),
+            tool("scan", json!({"type": "object"})),
+        ];
+
+        let changes = diff_tool_catalog("arbfarm", &previous, &current);
+        let summary: Vec<(&str, &str)> = changes
+            .iter()
+            .map(|c| {
+                let kind = match c {
+                    ToolCatalogChange::Added { .. } => "added",
+                    ToolCatalogChange::Removed { .. } => "removed",
+                    ToolCatalogChange::SchemaChanged { .. } => "schema",
+                };
+                (kind, c.tool_name())
+            })
+            .collect();
+
+        assert_eq!(summary, vec![("schema", "score"), ("added", "execute")]);
+        assert!(changes.iter().all(|c| c.service() == "arbfarm"));
+
+        let changes = diff_tool_catalog("arbfarm", &current, &previous);
+        assert!(changes.iter().any(|c| matches!(
+            c,
+            ToolCatalogChange::Removed { tool, .. } if tool.name == "execute"
+        )));
+    }
+
+    #[test]
+    fn test_description_only_edit_is_not_a_change() {
+        let previous = vec![tool("scan", json!({"type": "object"}))];
+        let mut reworded = tool("scan", json!({"type": "object"}));
+        reworded.description = None;
+
+        assert!(diff_tool_catalog("arbfarm", &previous, &[reworded]).is_empty());
+    }
+}
@@ -1,4 +1,7 @@
 use crate::error::{McpError, McpResult};
+use crate::trace::{
+    current_correlation_id, generate_correlation_id, traced, CORRELATION_ID_HEADER,
+};
 use crate::types::*;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -280,11 +283,16 @@ impl McpClient {
         self.request_id.fetch_add(1, Ordering::SeqCst)
     }
 
-    fn build_request(&self, request_body: &serde_json::Value) -> reqwest::RequestBuilder {
+    fn build_request(
+        &self,
+        request_body: &serde_json::Value,
+        correlation_id: &str,
+    ) -> reqwest::RequestBuilder {
         let mut builder = self
             .http_client
             .post(&self.endpoint_url)
             .header("Content-Type", "application/json")
+            .header(CORRELATION_ID_HEADER, correlation_id)
             .json(request_body);
 
         if let Some(ref token) = self.auth.bearer_token {
@@ -307,6 +315,21 @@ impl McpClient {
         &self,
         method: &str,
         params: Option<serde_json::Value>,
+    ) -> McpResult<serde_json::Value> {
+        let correlation_id = current_correlation_id().unwrap_or_else(generate_correlation_id);
+        self.send_request_inner(method, params, &correlation_id)
+            .await
+            .map_err(|e| {
+                warn!(method = method, correlation_id = %correlation_id, error = %e, "MCP request failed");
+                e.with_correlation_id(correlation_id.as_str())
+            })
+    }
+
+    async fn send_request_inner(
+        &self,
+        method: &str,
+        params: Option<serde_json::Value>,
+        correlation_id: &str,
     ) -> McpResult<serde_json::Value> {
         let id = self.next_request_id();
 
@@ -320,9 +343,12 @@ impl McpClient {
             request_body["params"] = params;
         }
 
-        debug!(method = method, id = id, endpoint = %self.endpoint_url, "Sending MCP request");
+        debug!(method = method, id = id, correlation_id = correlation_id, endpoint = %self.endpoint_url, "Sending MCP request");
 
-        let response = self.build_request(&request_body).send().await?;
+        let response = self
+            .build_request(&request_body, correlation_id)
+            .send()
+            .await?;
 
         if !response.status().is_success() {
             return Err(McpError::ProtocolError(format!(
@@ -363,12 +389,18 @@ impl McpClient {
             request_body["params"] = params;
         }
 
-        debug!(method = method, "Sending MCP notification");
+        let correlation_id = current_correlation_id().unwrap_or_else(generate_correlation_id);
+        debug!(method = method, correlation_id = %correlation_id, "Sending MCP notification");
 
-        let response = self.build_request(&request_body).send().await?;
+        let response = self
+            .build_request(&request_body, &correlation_id)
+            .send()
+            .await
+            .map_err(|e| McpError::from(e).with_correlation_id(correlation_id.as_str()))?;
 
         if !response.status().is_success() {
             warn!(
+                correlation_id = %correlation_id,
                 "MCP notification {} returned status: {}",
                 method,
                 response.status()
@@ -379,6 +411,10 @@ impl McpClient {
     }
 
     pub async fn connect(&self) -> McpResult<InitializeResult> {
+        traced(self.connect_traced()).await
+    }
+
+    async fn connect_traced(&self) -> McpResult<InitializeResult> {
         info!(
             endpoint = %self.endpoint_url,
             name = %self.name,
@@ -463,6 +499,10 @@ impl McpClient {
     }
 
     pub async fn list_tools(&self) -> McpResult<Vec<McpTool>> {
+        traced(self.list_tools_traced()).await
+    }
+
+    async fn list_tools_traced(&self) -> McpResult<Vec<McpTool>> {
         self.ensure_connected().await?;
 
         {
@@ -493,14 +533,25 @@ impl McpClient {
         self.list_tools().await
     }
 
+    /// Tool calls carry the correlation id of the enclosing `with_correlation_id`
+    /// scope, or a freshly generated one.
     pub async fn call_tool(
         &self,
         name: &str,
         arguments: HashMap<String, serde_json::Value>,
+    ) -> McpResult<CallToolResult> {
+        traced(self.call_tool_traced(name, arguments)).await
+    }
+
+    async fn call_tool_traced(
+        &self,
+        name: &str,
+        arguments: HashMap<String, serde_json::Value>,
     ) -> McpResult<CallToolResult> {
         self.ensure_connected().await?;
 
-        info!(tool = name, endpoint = %self.endpoint_url, "Calling MCP tool");
+        let correlation_id = current_correlation_id().unwrap_or_default();
+        info!(tool = name, endpoint = %self.endpoint_url, correlation_id = %correlation_id, "Calling MCP tool");
 
         let params = json!({
             "name": name,
@@ -513,7 +564,7 @@ impl McpClient {
 
         if tool_result.is_error() {
             let error_text = tool_result.text_content();
-            error!(tool = name, error = %error_text, "Tool returned error");
+            error!(tool = name, correlation_id = %correlation_id, error = %error_text, "Tool returned error");
         } else {
             debug!(tool = name, "Tool executed successfully");
         }
@@ -535,6 +586,10 @@ impl McpClient {
     }
 
     pub async fn ping(&self) -> McpResult<()> {
+        traced(self.ping_traced()).await
+    }
+
+    async fn ping_traced(&self) -> McpResult<()> {
         self.ensure_connected().await?;
         let _ = self.send_request("ping", None).await?;
         debug!("MCP ping successful");
@@ -625,6 +680,100 @@ impl Clone for McpClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::spawn_jsonrpc_server;
+    use crate::trace::with_correlation_id;
+    use std::sync::{Arc, Mutex};
+
+    type SeenRequests = Arc<Mutex<Vec<(String, Option<String>)>>>;
+
+    /// Minimal MCP server recording (method, correlation id) per request. Fails
+    /// `tools/call` with a JSON-RPC error when `fail_tool_calls` is set.
+    async fn spawn_mcp_server(fail_tool_calls: bool) -> (String, SeenRequests) {
+        let seen: SeenRequests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = seen.clone();
+
+        let url = spawn_jsonrpc_server(move |headers, request| {
+            let correlation_id = headers
+                .get(CORRELATION_ID_HEADER)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
+            let method = request["method"].as_str().unwrap_or_default().to_string();
+            recorded
+                .lock()
+                .unwrap()
+                .push((method.clone(), correlation_id));
+
+            match method.as_str() {
+                "initialize" => json!({"jsonrpc": "2.0", "id": request["id"], "result": {
+                    "protocolVersion": MCP_PROTOCOL_VERSION,
+                    "capabilities": {},
+                    "serverInfo": {"name": "test", "version": "0.1.0"}
+                }}),
+                "tools/call" if fail_tool_calls => {
+                    json!({"jsonrpc": "2.0", "id": request["id"],
+                    "error": {"code": -32000, "message": "tool exploded"}})
+                }
+                "tools/call" => json!({"jsonrpc": "2.0", "id": request["id"], "result": {
+                    "content": [{"type": "text", "text": "ok"}]
+                }}),
+                _ => json!({"jsonrpc": "2.0", "id": request["id"], "result": {}}),
+            }
+        })
+        .await;
+
+        (url, seen)
+    }
+
+    #[tokio::test]
+    async fn test_correlation_id_header_generated_and_shared_per_call() {
+        let (url, seen) = spawn_mcp_server(false).await;
+        let client = McpClient::new(url);
+
+        client.call_tool("scan", HashMap::new()).await.unwrap();
+
+        let seen = seen.lock().unwrap();
+        let methods: Vec<&str> = seen.iter().map(|(m, _)| m.as_str()).collect();
+        assert_eq!(methods, vec!["initialize", "initialized", "tools/call"]);
+
+        let id = seen[0].1.clone().expect("correlation id header missing");
+        assert!(!id.is_empty());
+        assert!(seen.iter().all(|(_, c)| c.as_deref() == Some(id.as_str())));
+    }
+
+    #[tokio::test]
+    async fn test_incoming_correlation_id_preserved() {
+        let (url, seen) = spawn_mcp_server(false).await;
+        let client = McpClient::new(url);
+
+        with_correlation_id("orchestration-42", async {
+            client.call_tool("scan", HashMap::new()).await.unwrap();
+            client.call_tool("score", HashMap::new()).await.unwrap();
+        })
+        .await;
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 4);
+        assert!(seen
+            .iter()
+            .all(|(_, c)| c.as_deref() == Some("orchestration-42")));
+    }
+
+    #[tokio::test]
+    async fn test_correlation_id_surfaced_in_error() {
+        let (url, _) = spawn_mcp_server(true).await;
+        let client = McpClient::new(url);
+
+        let err = with_correlation_id("trace-abc", client.call_tool("scan", HashMap::new()))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.correlation_id(), Some("trace-abc"));
+        assert!(matches!(
+            err.root(),
+            McpError::JsonRpcError { code: -32000, .. }
+        ));
+        assert!(err.to_string().contains("trace-abc"));
+    }
 
     #[test]
     fn test_client_creation() {
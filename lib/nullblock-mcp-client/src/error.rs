@@ -34,6 +34,40 @@ pub enum McpError {
 
     #[error("Configuration error: {0}")]
     ConfigError(String),
+
+    #[error("{source} (correlation_id: {correlation_id})")]
+    Traced {
+        correlation_id: String,
+        #[source]
+        source: Box<McpError>,
+    },
+}
+
+impl McpError {
+    pub fn with_correlation_id(self, correlation_id: impl Into<String>) -> Self {
+        match self {
+            traced @ McpError::Traced { .. } => traced,
+            other => McpError::Traced {
+                correlation_id: correlation_id.into(),
+                source: Box::new(other),
+            },
+        }
+    }
+
+    pub fn correlation_id(&self) -> Option<&str> {
+        match self {
+            McpError::Traced { correlation_id, .. } => Some(correlation_id),
+            _ => None,
+        }
+    }
+
+    /// The underlying error, without any correlation wrapper.
+    pub fn root(&self) -> &McpError {
+        match self {
+            McpError::Traced { source, .. } => source.root(),
+            other => other,
+        }
+    }
 }
 
 pub type McpResult<T> = Result<T, McpError>;
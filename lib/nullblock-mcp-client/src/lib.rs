@@ -1,17 +1,26 @@
+pub mod catalog;
 pub mod client;
 #[cfg(feature = "config-file")]
 pub mod config;
 pub mod error;
 pub mod filter;
 pub mod registry;
+#[cfg(test)]
+mod test_support;
+pub mod trace;
 pub mod types;
 
+pub use catalog::{diff_tool_catalog, ToolCatalogChange, ToolCatalogListener};
 pub use client::{AuthConfig, McpClient, McpServerConfig};
 pub use error::{McpError, McpResult};
 pub use filter::{
     filter_by_tag, filter_idempotent, filter_not_destructive, filter_read_only, ToolFilter,
 };
 pub use registry::{RegistryStats, ServiceEndpoint, ServiceRegistry};
+pub use trace::{
+    correlation_id_from_headers, current_correlation_id, with_correlation_id,
+    CORRELATION_ID_HEADER,
+};
 pub use types::*;
 
 #[cfg(feature = "config-file")]
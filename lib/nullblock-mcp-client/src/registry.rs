@@ -1,11 +1,13 @@
+use crate::catalog::{diff_tool_catalog, ToolCatalogChange, ToolCatalogListener};
 use crate::client::{AuthConfig, McpClient, McpServerConfig};
 use crate::error::{McpError, McpResult};
 use crate::filter::{filter_read_only, ToolFilter};
 use crate::types::{CallToolResult, ClientInfo, McpTool, ServerCapabilities, ServerInfo};
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
 use tracing::{debug, info, warn};
 
 #[derive(Debug, Clone)]
@@ -72,6 +74,7 @@ pub struct ServiceRegistry {
     services: RwLock<HashMap<String, ServiceEndpoint>>,
     clients: RwLock<HashMap<String, Arc<McpClient>>>,
     tool_service_map: RwLock<HashMap<String, String>>,
+    catalog_listeners: RwLock<Vec<ToolCatalogListener>>,
     client_info: ClientInfo,
 }
 
@@ -81,6 +84,7 @@ impl ServiceRegistry {
             services: RwLock::new(HashMap::new()),
             clients: RwLock::new(HashMap::new()),
             tool_service_map: RwLock::new(HashMap::new()),
+            catalog_listeners: RwLock::new(Vec::new()),
             client_info: ClientInfo::default(),
         }
     }
@@ -90,6 +94,7 @@ impl ServiceRegistry {
             services: RwLock::new(HashMap::new()),
             clients: RwLock::new(HashMap::new()),
             tool_service_map: RwLock::new(HashMap::new()),
+            catalog_listeners: RwLock::new(Vec::new()),
             client_info,
         }
     }
@@ -282,23 +287,48 @@ impl ServiceRegistry {
         let capabilities = client.get_server_capabilities().await;
         let server_info = client.get_server_info().await;
 
-        {
+        // The first successful listing is the baseline, not a change
+        let changes = {
             let mut services = self.services.write().await;
-            if let Some(endpoint) = services.get_mut(service_name) {
-                endpoint.tools = tools.clone();
-                endpoint.capabilities = capabilities;
-                endpoint.server_info = server_info;
-                endpoint.last_refreshed = Some(Instant::now());
+            match services.get_mut(service_name) {
+                Some(endpoint) => {
+                    let changes = match endpoint.last_refreshed {
+                        Some(_) => diff_tool_catalog(service_name, &endpoint.tools, &tools),
+                        None => Vec::new(),
+                    };
+                    endpoint.tools = tools.clone();
+                    endpoint.capabilities = capabilities;
+                    endpoint.server_info = server_info;
+                    endpoint.last_refreshed = Some(Instant::now());
+                    changes
+                }
+                None => Vec::new(),
             }
-        }
+        };
 
         {
             let mut tool_map = self.tool_service_map.write().await;
+            for change in &changes {
+                if let ToolCatalogChange::Removed { tool, .. } = change {
+                    if tool_map.get(&tool.name).map(String::as_str) == Some(service_name) {
+                        tool_map.remove(&tool.name);
+                    }
+                }
+            }
             for tool in &tools {
                 tool_map.insert(tool.name.clone(), service_name.to_string());
             }
         }
 
+        if !changes.is_empty() {
+            info!(
+                service = service_name,
+                changes = changes.len(),
+                "Service tool catalog changed"
+            );
+            self.notify_catalog_change(&changes).await;
+        }
+
         info!(
             service = service_name,
             tool_count = tools.len(),
@@ -308,6 +338,40 @@ impl ServiceRegistry {
         Ok(tools)
     }
 
+    /// Registers a callback invoked with the changes whenever a refresh finds that a
+    /// service added, removed or reshaped tools since its previous listing.
+    pub async fn on_tool_catalog_change<F>(&self, listener: F)
+    where
+        F: Fn(&[ToolCatalogChange]) + Send + Sync + 'static,
+    {
+        self.catalog_listeners
+            .write()
+            .await
+            .push(Arc::new(listener));
+    }
+
+    async fn notify_catalog_change(&self, changes: &[ToolCatalogChange]) {
+        let listeners = self.catalog_listeners.read().await.clone();
+        for listener in listeners {
+            listener(changes);
+        }
+    }
+
+    /// Re-runs tool discovery every `interval` so catalog listeners hear about
+    /// downstream changes without anyone calling `refresh` by hand.
+    pub fn start_periodic_refresh(self: &Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        let registry = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = registry.discover_all_tools().await {
+                    warn!(error = %e, "Periodic tool catalog refresh failed");
+                }
+            }
+        })
+    }
+
     pub async fn get_all_tools(&self) -> Vec<McpTool> {
         let services = self.services.read().await;
         services
@@ -473,6 +537,129 @@ pub struct RegistryStats {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::spawn_jsonrpc_server;
+    use serde_json::{json, Value};
+    use std::sync::Mutex;
+
+    /// MCP server answering `tools/list` with whatever is currently in the returned
+    /// handle, so tests can change the catalog between refreshes.
+    async fn spawn_catalog_server(tools: Value) -> (String, Arc<Mutex<Value>>) {
+        let catalog = Arc::new(Mutex::new(tools));
+        let served = catalog.clone();
+
+        let url = spawn_jsonrpc_server(move |_, request| {
+            let result = match request["method"].as_str().unwrap_or_default() {
+                "initialize" => json!({
+                    "protocolVersion": crate::types::MCP_PROTOCOL_VERSION,
+                    "capabilities": {},
+                    "serverInfo": {"name": "test", "version": "0.1.0"}
+                }),
+                "tools/list" => json!({"tools": served.lock().unwrap().clone()}),
+                _ => json!({}),
+            };
+            json!({"jsonrpc": "2.0", "id": request["id"], "result": result})
+        })
+        .await;
+
+        (url, catalog)
+    }
+
+    fn tool_json(name: &str, required: &[&str]) -> Value {
+        json!({
+            "name": name,
+            "description": format!("{} tool", name),
+            "inputSchema": {"type": "object", "required": required}
+        })
+    }
+
+    #[tokio::test]
+    async fn test_refresh_notifies_catalog_changes() {
+        let (url, catalog) =
+            spawn_catalog_server(json!([tool_json("scan", &[]), tool_json("score", &[])])).await;
+        let registry = ServiceRegistry::new();
+        registry.register("arbfarm", &url).await.unwrap();
+
+        let received: Arc<Mutex<Vec<ToolCatalogChange>>> = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        registry
+            .on_tool_catalog_change(move |changes| sink.lock().unwrap().extend_from_slice(changes))
+            .await;
+
+        registry.refresh("arbfarm").await.unwrap();
+        assert!(received.lock().unwrap().is_empty());
+
+        *catalog.lock().unwrap() = json!([
+            tool_json("score", &["mint"]),
+            tool_json("execute", &["edge_id"])
+        ]);
+        registry.refresh("arbfarm").await.unwrap();
+
+        let changes = received.lock().unwrap().clone();
+        assert_eq!(changes.len(), 3);
+        assert!(changes.iter().any(|c| matches!(
+            c,
+            ToolCatalogChange::Added { tool, .. } if tool.name == "execute"
+        )));
+        assert!(changes.iter().any(|c| matches!(
+            c,
+            ToolCatalogChange::Removed { tool, .. } if tool.name == "scan"
+        )));
+        assert!(changes.iter().any(|c| matches!(
+            c,
+            ToolCatalogChange::SchemaChanged { previous, current, .. }
+                if current.name == "score"
+                    && previous.input_schema["required"] == json!([])
+                    && current.input_schema["required"] == json!(["mint"])
+        )));
+        assert!(changes.iter().all(|c| c.service() == "arbfarm"));
+
+        assert_eq!(registry.get_service_for_tool("scan").await, None);
+        assert_eq!(
+            registry.get_service_for_tool("execute").await.as_deref(),
+            Some("arbfarm")
+        );
+
+        // An unchanged listing produces no notification
+        registry.refresh("arbfarm").await.unwrap();
+        assert_eq!(received.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_periodic_refresh_picks_up_changes() {
+        let (url, catalog) = spawn_catalog_server(json!([tool_json("scan", &[])])).await;
+        let registry = Arc::new(ServiceRegistry::new());
+        registry.register("arbfarm", &url).await.unwrap();
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        registry
+            .on_tool_catalog_change(move |changes| {
+                let _ = tx.send(changes.to_vec());
+            })
+            .await;
+
+        let handle = registry.start_periodic_refresh(Duration::from_millis(20));
+        while registry
+            .get_service("arbfarm")
+            .await
+            .and_then(|s| s.last_refreshed)
+            .is_none()
+        {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        *catalog.lock().unwrap() = json!([tool_json("scan", &[]), tool_json("quote", &[])]);
+        let changes = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("no catalog change notification")
+            .unwrap();
+        handle.abort();
+
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(
+            &changes[0],
+            ToolCatalogChange::Added { tool, .. } if tool.name == "quote"
+        ));
+    }
 
     #[tokio::test]
     async fn test_registry_creation() {
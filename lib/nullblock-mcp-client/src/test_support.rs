@@ -0,0 +1,22 @@
+use axum::{http::HeaderMap, routing::post, Json, Router};
+use serde_json::Value;
+use tokio::net::TcpListener;
+
+/// Serves `POST /mcp/jsonrpc` on an ephemeral local port and returns its URL.
+/// `respond` gets the request headers and JSON-RPC body and returns the reply body.
+pub(crate) async fn spawn_jsonrpc_server<F>(respond: F) -> String
+where
+    F: Fn(&HeaderMap, Value) -> Value + Clone + Send + Sync + 'static,
+{
+    let app = Router::new().route(
+        "/mcp/jsonrpc",
+        post(move |headers: HeaderMap, Json(request): Json<Value>| {
+            let reply = respond(&headers, request);
+            async move { Json(reply) }
+        }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/mcp/jsonrpc", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    url
+}
@@ -0,0 +1,37 @@
+use std::future::Future;
+
+pub const CORRELATION_ID_HEADER: &str = "X-Correlation-ID";
+
+tokio::task_local! {
+    static CORRELATION_ID: String;
+}
+
+pub fn generate_correlation_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// The correlation id of the enclosing `with_correlation_id` scope, if any.
+pub fn current_correlation_id() -> Option<String> {
+    CORRELATION_ID.try_with(|id| id.clone()).ok()
+}
+
+/// Runs `f` with `id` as the correlation id for every MCP call made inside it.
+pub async fn with_correlation_id<F: Future>(id: impl Into<String>, f: F) -> F::Output {
+    CORRELATION_ID.scope(id.into(), f).await
+}
+
+/// Runs `f` under the current correlation id, generating one if none is set.
+pub async fn traced<F: Future>(f: F) -> F::Output {
+    let id = current_correlation_id().unwrap_or_else(generate_correlation_id);
+    with_correlation_id(id, f).await
+}
+
+/// Reads an incoming correlation id so it can be continued with `with_correlation_id`.
+pub fn correlation_id_from_headers(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers
+        .get(CORRELATION_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+}
@@ -18,6 +18,7 @@ export EREBUS_HOST=127.0.0.1
 export EREBUS_PORT=3000
 export DATABASE_URL="${DATABASE_URL:-postgresql://postgres:${POSTGRES_PASSWORD:-changeme}@localhost:5440/erebus}"
 export KAFKA_BOOTSTRAP_SERVERS="localhost:9092"
+export WALLET_AUTH_DOMAINS="${WALLET_AUTH_DOMAINS:-localhost:5173}"
 echo "📝 Logs will be written to logs/erebus.log"
 echo ""
 echo "🗄️  Waiting for Erebus database to be ready..."
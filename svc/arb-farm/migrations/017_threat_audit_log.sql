@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS arb_threat_audit_log (
+    id UUID PRIMARY KEY,
+    action VARCHAR(32) NOT NULL,
+    entity_type VARCHAR(32),
+    address TEXT NOT NULL,
+    actor TEXT NOT NULL,
+    reason TEXT,
+    prior_score DOUBLE PRECISION,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_threat_audit_address ON arb_threat_audit_log(address);
+CREATE INDEX IF NOT EXISTS idx_threat_audit_created ON arb_threat_audit_log(created_at DESC);
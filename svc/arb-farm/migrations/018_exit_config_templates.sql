@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS arb_exit_config_templates (
+    name VARCHAR(64) PRIMARY KEY,
+    description TEXT,
+    config JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS arb_auto_executions (
+    edge_id UUID PRIMARY KEY,
+    strategy_id UUID NOT NULL,
+    mint TEXT NOT NULL,
+    sol_amount_lamports BIGINT NOT NULL,
+    tokens_received BIGINT,
+    signature TEXT,
+    status VARCHAR(16) NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    started_at TIMESTAMPTZ NOT NULL,
+    completed_at TIMESTAMPTZ,
+    error TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_auto_executions_started ON arb_auto_executions(started_at DESC);
+CREATE INDEX IF NOT EXISTS idx_auto_executions_strategy ON arb_auto_executions(strategy_id);
+CREATE INDEX IF NOT EXISTS idx_auto_executions_status ON arb_auto_executions(status);
@@ -0,0 +1,6 @@
+-- Track whether a position's auto-exit flag was set explicitly, so it can take
+-- precedence over category-level auto-exit toggles
+ALTER TABLE arb_positions ADD COLUMN IF NOT EXISTS auto_exit_overridden BOOLEAN NOT NULL DEFAULT false;
+
+-- Positions already in manual mode were toggled explicitly
+UPDATE arb_positions SET auto_exit_overridden = true WHERE auto_exit_enabled = false;
@@ -0,0 +1,3 @@
+-- SOL/USD rate at entry and exit so historical P&L can be reported in USD
+ALTER TABLE arb_positions ADD COLUMN IF NOT EXISTS entry_sol_usd_rate DECIMAL(20, 8);
+ALTER TABLE arb_positions ADD COLUMN IF NOT EXISTS exit_sol_usd_rate DECIMAL(20, 8);
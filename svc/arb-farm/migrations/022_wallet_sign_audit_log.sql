@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS arb_wallet_sign_audit_log (
+    id UUID PRIMARY KEY,
+    signer VARCHAR(32) NOT NULL,
+    description TEXT NOT NULL,
+    edge_id UUID,
+    amount_lamports BIGINT NOT NULL,
+    estimated_profit_lamports BIGINT,
+    summary JSONB NOT NULL,
+    outcome VARCHAR(32) NOT NULL,
+    policy_passed BOOLEAN NOT NULL,
+    policy_violation JSONB,
+    signature TEXT,
+    error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_wallet_sign_audit_outcome ON arb_wallet_sign_audit_log(outcome);
+CREATE INDEX IF NOT EXISTS idx_wallet_sign_audit_created ON arb_wallet_sign_audit_log(created_at DESC);
@@ -0,0 +1,5 @@
+-- Review notes and tags on positions (e.g. why it was entered, "kol-copy", "experiment")
+ALTER TABLE arb_positions ADD COLUMN IF NOT EXISTS notes TEXT;
+ALTER TABLE arb_positions ADD COLUMN IF NOT EXISTS tags TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE INDEX IF NOT EXISTS idx_arb_positions_tags ON arb_positions USING GIN (tags);
@@ -0,0 +1,2 @@
+-- DCA plan and filled tranches for positions that scale in on dips
+ALTER TABLE arb_positions ADD COLUMN IF NOT EXISTS scale_in JSONB;
@@ -0,0 +1,2 @@
+-- Round-trip costs recorded at entry (and grown by scale-in buys), so break-even survives restarts
+ALTER TABLE arb_positions ADD COLUMN IF NOT EXISTS round_trip_costs JSONB;
@@ -0,0 +1,2 @@
+-- Per-KOL copy budgets are rebuilt from executed copy entries since this time
+ALTER TABLE arb_kol_entities ADD COLUMN IF NOT EXISTS copy_budget_reset_at TIMESTAMPTZ;
@@ -0,0 +1,24 @@
+-- Research source reliability, keyed by platform and handle because monitored sources
+-- get fresh ids on every start
+CREATE TABLE IF NOT EXISTS arb_research_source_reliability (
+    source_type VARCHAR(20) NOT NULL,
+    handle VARCHAR(255) NOT NULL,
+    approved INTEGER NOT NULL DEFAULT 0,
+    rejected INTEGER NOT NULL DEFAULT 0,
+    profitable_strategies INTEGER NOT NULL DEFAULT 0,
+    losing_strategies INTEGER NOT NULL DEFAULT 0,
+    strategy_pnl_sol DOUBLE PRECISION NOT NULL DEFAULT 0,
+    score DOUBLE PRECISION NOT NULL DEFAULT 0.5,
+    auto_muted BOOLEAN NOT NULL DEFAULT FALSE,
+    updated_at TIMESTAMPTZ,
+    PRIMARY KEY (source_type, handle)
+);
+
+-- Strategies built from a source's discoveries; their closed trades are credited to it
+CREATE TABLE IF NOT EXISTS arb_research_strategy_sources (
+    strategy_id UUID PRIMARY KEY REFERENCES arb_strategies(id) ON DELETE CASCADE,
+    source_type VARCHAR(20) NOT NULL,
+    handle VARCHAR(255) NOT NULL,
+    discovery_id UUID,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
 
 use crate::agents::curve_metrics::{CurveMetricsCollector, DetailedCurveMetrics};
 use crate::error::AppResult;
@@ -7,6 +10,13 @@ use crate::venues::curves::{
     HolderAnalyzer, HolderDistribution, OnChainCurveState, OnChainFetcher,
 };
 
+/// Cached scores older than this are dropped on the next insert.
+const SCORE_CACHE_RETENTION: Duration = Duration::from_secs(600);
+
+fn score_cache_key(mint: &str, venue: &str) -> String {
+    format!("{}:{}", venue, mint)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Recommendation {
@@ -147,6 +157,8 @@ pub struct CurveOpportunityScorer {
     on_chain_fetcher: Arc<OnChainFetcher>,
     weights: ScoringWeights,
     thresholds: ScoringThresholds,
+    /// Recent scores by `venue:mint`, for read paths that can serve a slightly old score.
+    score_cache: RwLock<HashMap<String, (OpportunityScore, Instant)>>,
 }
 
 impl CurveOpportunityScorer {
@@ -161,6 +173,7 @@ impl CurveOpportunityScorer {
             on_chain_fetcher,
             weights: ScoringWeights::default(),
             thresholds: ScoringThresholds::default(),
+            score_cache: RwLock::new(HashMap::new()),
         }
     }
 
@@ -184,7 +197,38 @@ impl CurveOpportunityScorer {
 
         let on_chain = self.on_chain_fetcher.get_bonding_curve_state(mint).await?;
 
-        self.calculate_score(mint, venue, &metrics, &holders, &on_chain)
+        let score = self.calculate_score(mint, venue, &metrics, &holders, &on_chain)?;
+        self.cache_score(&score, Instant::now()).await;
+        Ok(score)
+    }
+
+    /// `score_opportunity`, but a score computed within `max_age` is returned as is.
+    pub async fn score_opportunity_cached(
+        &self,
+        mint: &str,
+        venue: &str,
+        max_age: Duration,
+    ) -> AppResult<OpportunityScore> {
+        if let Some((score, scored_at)) = self
+            .score_cache
+            .read()
+            .await
+            .get(&score_cache_key(mint, venue))
+        {
+            if scored_at.elapsed() <= max_age {
+                return Ok(score.clone());
+            }
+        }
+        self.score_opportunity(mint, venue).await
+    }
+
+    async fn cache_score(&self, score: &OpportunityScore, scored_at: Instant) {
+        let mut cache = self.score_cache.write().await;
+        cache.retain(|_, (_, at)| at.elapsed() <= SCORE_CACHE_RETENTION);
+        cache.insert(
+            score_cache_key(&score.mint, &score.venue),
+            (score.clone(), scored_at),
+        );
     }
 
     pub fn calculate_score(
@@ -517,6 +561,67 @@ impl CurveOpportunityScorer {
     }
 }
 
+pub const CONTENDER_OPPORTUNITY_WEIGHT: f64 = 0.5;
+pub const CONTENDER_MOMENTUM_WEIGHT: f64 = 0.3;
+pub const CONTENDER_SAFETY_WEIGHT: f64 = 0.2;
+/// Safety assumed for contenders the threat detector has not scored yet.
+const UNKNOWN_THREAT_SAFETY: f64 = 50.0;
+
+/// Inputs to a contender's composite score. `opportunity` and `momentum` are 0-100,
+/// `threat` is the detector's 0 (safe) to 1 (dangerous) score when one is cached.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct ContenderSubScores {
+    pub opportunity: f64,
+    pub momentum: f64,
+    pub threat: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContenderScore {
+    pub composite: f64,
+    pub opportunity: f64,
+    pub momentum: f64,
+    pub threat: Option<f64>,
+    pub safety: f64,
+}
+
+impl ContenderScore {
+    pub fn from_sub_scores(sub: ContenderSubScores) -> Self {
+        let opportunity = sub.opportunity.clamp(0.0, 100.0);
+        let momentum = sub.momentum.clamp(0.0, 100.0);
+        let safety = sub
+            .threat
+            .map(|t| (1.0 - t.clamp(0.0, 1.0)) * 100.0)
+            .unwrap_or(UNKNOWN_THREAT_SAFETY);
+
+        let composite = opportunity * CONTENDER_OPPORTUNITY_WEIGHT
+            + momentum * CONTENDER_MOMENTUM_WEIGHT
+            + safety * CONTENDER_SAFETY_WEIGHT;
+
+        Self {
+            composite,
+            opportunity,
+            momentum,
+            threat: sub.threat,
+            safety,
+        }
+    }
+}
+
+/// Scores each item and returns them best-first.
+pub fn rank_contenders<T>(contenders: Vec<(T, ContenderSubScores)>) -> Vec<(T, ContenderScore)> {
+    let mut ranked: Vec<(T, ContenderScore)> = contenders
+        .into_iter()
+        .map(|(item, sub)| (item, ContenderScore::from_sub_scores(sub)))
+        .collect();
+    ranked.sort_by(|a, b| {
+        b.1.composite
+            .partial_cmp(&a.1.composite)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    ranked
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -619,6 +724,7 @@ mod tests {
             on_chain_fetcher: Arc::new(OnChainFetcher::new_mock()),
             weights: ScoringWeights::default(),
             thresholds: ScoringThresholds::default(),
+            score_cache: RwLock::new(HashMap::new()),
         };
 
         let score = scorer
@@ -668,6 +774,7 @@ mod tests {
             on_chain_fetcher: Arc::new(OnChainFetcher::new_mock()),
             weights: ScoringWeights::default(),
             thresholds: ScoringThresholds::default(),
+            score_cache: RwLock::new(HashMap::new()),
         };
 
         let score = scorer
@@ -682,4 +789,109 @@ mod tests {
         assert!(!score.risk_warnings.is_empty());
         assert_eq!(score.recommendation, Recommendation::Avoid);
     }
+
+    #[test]
+    fn test_contenders_ranked_by_composite() {
+        let ranked = rank_contenders(vec![
+            (
+                "hot_but_risky",
+                ContenderSubScores {
+                    opportunity: 90.0,
+                    momentum: 90.0,
+                    threat: Some(0.9),
+                },
+            ),
+            (
+                "balanced",
+                ContenderSubScores {
+                    opportunity: 80.0,
+                    momentum: 70.0,
+                    threat: Some(0.1),
+                },
+            ),
+            (
+                "unscored_threat",
+                ContenderSubScores {
+                    opportunity: 80.0,
+                    momentum: 70.0,
+                    threat: None,
+                },
+            ),
+            (
+                "weak",
+                ContenderSubScores {
+                    opportunity: 20.0,
+                    momentum: 10.0,
+                    threat: Some(0.0),
+                },
+            ),
+        ]);
+
+        let order: Vec<&str> = ranked.iter().map(|(name, _)| *name).collect();
+        assert_eq!(
+            order,
+            vec!["balanced", "hot_but_risky", "unscored_threat", "weak"]
+        );
+
+        // balanced: 80*0.5 + 70*0.3 + 90*0.2 = 79
+        let (_, balanced) = &ranked[0];
+        assert!((balanced.composite - 79.0).abs() < 1e-9);
+        assert!((balanced.safety - 90.0).abs() < 1e-9);
+        assert!(ranked
+            .windows(2)
+            .all(|w| w[0].1.composite >= w[1].1.composite));
+    }
+
+    #[test]
+    fn test_contender_sub_scores_are_clamped() {
+        let score = ContenderScore::from_sub_scores(ContenderSubScores {
+            opportunity: 150.0,
+            momentum: -20.0,
+            threat: Some(1.5),
+        });
+        assert_eq!(score.opportunity, 100.0);
+        assert_eq!(score.momentum, 0.0);
+        assert_eq!(score.safety, 0.0);
+        assert!((score.composite - 50.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_recent_scores_served_from_cache() {
+        let scorer = CurveOpportunityScorer::new(
+            Arc::new(CurveMetricsCollector::new_mock()),
+            Arc::new(HolderAnalyzer::new_mock()),
+            Arc::new(OnChainFetcher::new_mock()),
+        );
+        let score = scorer
+            .calculate_score(
+                "test",
+                "pump_fun",
+                &mock_metrics(),
+                &mock_holders(),
+                &mock_on_chain(),
+            )
+            .unwrap();
+        scorer.cache_score(&score, Instant::now()).await;
+
+        let cached = scorer
+            .score_opportunity_cached("test", "pump_fun", Duration::from_secs(30))
+            .await
+            .unwrap();
+        assert_eq!(cached.overall, score.overall);
+
+        // Entries past retention are dropped when the next score lands. On a
+        // freshly booted host the monotonic clock may not reach back that far.
+        let Some(stale_at) = Instant::now().checked_sub(Duration::from_secs(700)) else {
+            return;
+        };
+        let old = OpportunityScore {
+            mint: "old".to_string(),
+            ..score.clone()
+        };
+        scorer.cache_score(&old, stale_at).await;
+        scorer.cache_score(&score, Instant::now()).await;
+        let cache = scorer.score_cache.read().await;
+        assert!(!cache.contains_key(&score_cache_key("old", "pump_fun")));
+        assert!(cache.contains_key(&score_cache_key("test", "pump_fun")));
+    }
 }
@@ -9,8 +9,12 @@ use crate::events::{engram as engram_topics, AgentType, ArbEvent, EventSource};
 use crate::models::{
     ArbEngram, AvoidanceContent, AvoidanceSeverity, EdgePatternContent, EngramMetadata,
     EngramQuery, EngramSearchResult, EngramSource, EngramType, PatternMatch, PatternMatchRequest,
+    StrategyContent, StrategySuggestion, StrategySuggestionThresholds,
 };
 
+const SUGGESTION_STATUS_PENDING: &str = "pending_approval";
+const SUGGESTION_MAX_POSITION_SOL: f64 = 0.1;
+
 pub struct EngramHarvester {
     id: Uuid,
     engrams: Arc<RwLock<HashMap<String, ArbEngram>>>,
@@ -39,6 +43,7 @@ pub struct HarvesterStats {
     pub engrams_by_type: HashMap<String, u64>,
     pub patterns_matched: u64,
     pub avoidances_created: u64,
+    pub strategies_suggested: u64,
     pub last_harvest_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
@@ -390,6 +395,80 @@ impl EngramHarvester {
         self.store_engram(engram).await
     }
 
+    pub async fn suggest_strategies_from_patterns(
+        &self,
+        thresholds: &StrategySuggestionThresholds,
+    ) -> AppResult<Vec<StrategySuggestion>> {
+        let candidates: Vec<ArbEngram> = {
+            let engrams = self.engrams.read().await;
+            engrams
+                .values()
+                .filter(|e| e.engram_type == EngramType::EdgePattern)
+                .filter(|e| !engrams.contains_key(&suggestion_key_for(&e.key)))
+                .cloned()
+                .collect()
+        };
+
+        let mut suggestions = Vec::new();
+
+        for pattern in candidates {
+            let content: EdgePatternContent = match serde_json::from_value(pattern.content.clone())
+            {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+
+            let Some(suggestion) = build_strategy_suggestion(&pattern, &content, thresholds) else {
+                continue;
+            };
+
+            let mut metadata = EngramMetadata::default();
+            metadata.tags = vec![
+                format!("status:{}", SUGGESTION_STATUS_PENDING),
+                format!("source_pattern:{}", pattern.key),
+                format!("strategy_type:{}", content.edge_type),
+            ];
+            metadata.related_tokens = pattern.metadata.related_tokens.clone();
+            metadata.effectiveness_score = Some(content.success_rate);
+
+            let engram = ArbEngram::new(
+                suggestion.suggestion_key.clone(),
+                EngramType::Strategy,
+                serde_json::to_value(&suggestion)?,
+                EngramSource::Agent("engram_harvester".to_string()),
+            )
+            .with_confidence(pattern.confidence)
+            .with_metadata(metadata);
+
+            self.store_engram(engram).await?;
+
+            crate::events::broadcast_event(
+                &self.event_tx,
+                ArbEvent::new(
+                    "strategy_suggested",
+                    EventSource::Agent(AgentType::EngramHarvester),
+                    engram_topics::STRATEGY_SUGGESTED,
+                    serde_json::json!({
+                        "suggestion_key": suggestion.suggestion_key,
+                        "pattern_key": suggestion.pattern_key,
+                        "strategy_type": suggestion.strategy.strategy_type,
+                        "status": suggestion.status,
+                        "rationale": suggestion.rationale,
+                    }),
+                ),
+            );
+
+            suggestions.push(suggestion);
+        }
+
+        if !suggestions.is_empty() {
+            let mut stats = self.stats.write().await;
+            stats.strategies_suggested += suggestions.len() as u64;
+        }
+
+        Ok(suggestions)
+    }
+
     pub async fn get_stats(&self) -> HarvesterStats {
         self.stats.read().await.clone()
     }
@@ -492,6 +571,7 @@ impl EngramHarvester {
             tags: None,
             limit: Some(100),
             offset: None,
+            created_after: None,
         };
 
         if let Ok(patterns) = client.search_engrams(pattern_search).await {
@@ -525,6 +605,7 @@ impl EngramHarvester {
             tags: None,
             limit: Some(100),
             offset: None,
+            created_after: None,
         };
 
         if let Ok(avoidances) = client.search_engrams(avoidance_search).await {
@@ -580,6 +661,81 @@ impl EngramHarvester {
     }
 }
 
+fn suggestion_key_for(pattern_key: &str) -> String {
+    format!("arb.strategy.suggestion.{}", pattern_key)
+}
+
+fn build_strategy_suggestion(
+    pattern: &ArbEngram,
+    content: &EdgePatternContent,
+    thresholds: &StrategySuggestionThresholds,
+) -> Option<StrategySuggestion> {
+    if content.success_rate < thresholds.min_success_rate
+        || content.sample_count < thresholds.min_sample_count
+        || pattern.confidence < thresholds.min_confidence
+        || content.avg_profit_bps < thresholds.min_avg_profit_bps
+    {
+        return None;
+    }
+
+    let rationale = vec![
+        format!(
+            "success_rate {:.2} >= {:.2}",
+            content.success_rate, thresholds.min_success_rate
+        ),
+        format!(
+            "sample_count {} >= {}",
+            content.sample_count, thresholds.min_sample_count
+        ),
+        format!(
+            "confidence {:.2} >= {:.2}",
+            pattern.confidence, thresholds.min_confidence
+        ),
+        format!(
+            "avg_profit_bps {:.1} >= {:.1}",
+            content.avg_profit_bps, thresholds.min_avg_profit_bps
+        ),
+        format!("derived from pattern {}", pattern.key),
+    ];
+
+    let mut entry_conditions = vec![
+        format!("edge_type == {}", content.edge_type),
+        format!("venue_type == {}", content.venue_type),
+        format!("route_signature == {}", content.route_signature),
+    ];
+    entry_conditions.extend(content.optimal_conditions.iter().cloned());
+
+    let min_profit_bps = (content.avg_profit_bps / 2.0).round().max(1.0) as u16;
+
+    let strategy = StrategyContent {
+        name: format!("Suggested {} on {}", content.edge_type, content.venue_type),
+        strategy_type: content.edge_type.clone(),
+        entry_conditions,
+        exit_conditions: vec![format!("take_profit_bps >= {:.0}", content.avg_profit_bps)],
+        risk_parameters: serde_json::json!({
+            "max_position_sol": SUGGESTION_MAX_POSITION_SOL,
+            "min_profit_bps": min_profit_bps,
+            "auto_execute_enabled": false,
+            "require_confirmation": true,
+            "risk_factors": content.risk_factors,
+        }),
+        backtest_results: None,
+        live_performance: None,
+    };
+
+    Some(StrategySuggestion {
+        suggestion_key: suggestion_key_for(&pattern.key),
+        pattern_key: pattern.key.clone(),
+        pattern_engram_id: pattern.id,
+        status: SUGGESTION_STATUS_PENDING.to_string(),
+        is_active: false,
+        strategy,
+        venue_types: vec![content.venue_type.clone()],
+        rationale,
+        suggested_at: chrono::Utc::now(),
+    })
+}
+
 fn calculate_pattern_confidence(success_rate: f64, sample_count: u32) -> f64 {
     let base_confidence = success_rate;
 
@@ -597,3 +753,85 @@ fn calculate_pattern_confidence(success_rate: f64, sample_count: u32) -> f64 {
 
     (base_confidence * sample_factor).clamp(0.0, 1.0)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_strong_pattern_produces_draft_suggestion() {
+        let (tx, mut rx) = broadcast::channel(16);
+        let harvester = EngramHarvester::new(tx);
+
+        harvester
+            .create_edge_pattern_engram(
+                "curve_arb",
+                "bonding_curve",
+                "route12345678",
+                0.85,
+                120.0,
+                150,
+            )
+            .await
+            .unwrap();
+        let pattern_key = "arb.pattern.curve_arb.bonding_curve.route123".to_string();
+
+        let suggestions = harvester
+            .suggest_strategies_from_patterns(&StrategySuggestionThresholds::default())
+            .await
+            .unwrap();
+
+        assert_eq!(suggestions.len(), 1);
+        let suggestion = &suggestions[0];
+        assert_eq!(suggestion.pattern_key, pattern_key);
+        assert!(!suggestion.is_active);
+        assert_eq!(suggestion.status, "pending_approval");
+        assert_eq!(suggestion.strategy.strategy_type, "curve_arb");
+        assert!(suggestion
+            .rationale
+            .iter()
+            .any(|r| r.contains(&pattern_key)));
+
+        let stored = harvester
+            .get_engram(&suggestion.suggestion_key)
+            .await
+            .unwrap();
+        assert_eq!(stored.engram_type, EngramType::Strategy);
+        assert!(stored
+            .metadata
+            .tags
+            .contains(&format!("source_pattern:{}", pattern_key)));
+
+        let mut saw_event = false;
+        while let Ok(event) = rx.try_recv() {
+            if event.topic == engram_topics::STRATEGY_SUGGESTED {
+                saw_event = true;
+            }
+        }
+        assert!(saw_event);
+
+        let repeat = harvester
+            .suggest_strategies_from_patterns(&StrategySuggestionThresholds::default())
+            .await
+            .unwrap();
+        assert!(repeat.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_weak_pattern_is_not_suggested() {
+        let (tx, _rx) = broadcast::channel(16);
+        let harvester = EngramHarvester::new(tx);
+
+        harvester
+            .create_edge_pattern_engram("dex_arb", "dex_amm", "weakroute", 0.55, 30.0, 8)
+            .await
+            .unwrap();
+
+        let suggestions = harvester
+            .suggest_strategies_from_patterns(&StrategySuggestionThresholds::default())
+            .await
+            .unwrap();
+
+        assert!(suggestions.is_empty());
+    }
+}
@@ -530,6 +530,17 @@ impl GraduationSniper {
                                             }
                                         };
 
+                                        if let (Some(pm), Some(rc)) = (&position_manager, &risk_config) {
+                                            let cooldown_secs = rc.read().await.reentry_cooldown_secs;
+                                            if let Some(remaining_secs) = pm.reentry_cooldown_remaining(mint, cooldown_secs).await {
+                                                tracing::info!(
+                                                    "⏭️ Post-grad entry skipped for {} - re-entry cooldown after losing exit ({}s remaining)",
+                                                    symbol, remaining_secs
+                                                );
+                                                continue;
+                                            }
+                                        }
+
                                         // Check if buy is already in flight to prevent duplicates
                                         {
                                             let mut in_flight = in_flight_buys.write().await;
@@ -17,7 +17,8 @@ pub use autonomous_executor::{
 };
 pub use curve_metrics::{CurveMetricsCollector, DetailedCurveMetrics, MetricsSample};
 pub use curve_scorer::{
-    CurveOpportunityScorer, OpportunityScore, Recommendation, ScoringThresholds, ScoringWeights,
+    rank_contenders, ContenderScore, ContenderSubScores, CurveOpportunityScorer, OpportunityScore,
+    Recommendation, ScoringThresholds, ScoringWeights,
 };
 pub use engram_harvester::{EngramHarvester, HarvesterStats};
 pub use graduation_sniper::{
@@ -34,4 +35,8 @@ pub use strategies::{
     BehavioralStrategy, GraduationEvent, GraduationSniperStrategy, RaydiumSnipeStrategy,
     StrategyRegistry, TokenData, VenueSnapshot,
 };
-pub use strategy_engine::{MatchResult, StrategyEngine};
+pub use strategy_engine::{
+    AutoKillTrigger, CriterionCheck, MatchCriterion, MatchExplanation, MatchResult, PreviewEdge,
+    PreviewRejection, StrategyEngine, StrategyMatchExplanation, StrategyPreview,
+    StrategyTradeStats,
+};
@@ -6,11 +6,11 @@ use tokio::time::{interval, Duration};
 use uuid::Uuid;
 
 use super::strategies::{
-    BehavioralStrategy, GraduationEvent, RaydiumSnipeStrategy, StrategyRegistry, TokenData,
-    VenueSnapshot,
+    BehavioralStrategy, GraduationEvent, RaydiumSnipeStrategy, SnapshotCache, SnapshotFreshness,
+    StrategyRegistry, TokenData, VenueSnapshot,
 };
 use super::StrategyEngine;
-use crate::error::AppResult;
+use crate::error::{AppError, AppResult};
 use crate::events::{
     scanner as scanner_topics, swarm as swarm_topics, AgentType, ArbEvent, EventSource,
 };
@@ -58,6 +58,7 @@ const GRADUATED_MINTS_TTL_SECS: i64 = 1800; // 30 minutes
 pub struct ScannerAgent {
     id: Uuid,
     venues: Arc<RwLock<HashMap<Uuid, Box<dyn MevVenue>>>>,
+    disabled_venues: Arc<RwLock<HashSet<Uuid>>>,
     event_tx: broadcast::Sender<ArbEvent>,
     scan_interval_ms: u64,
     is_running: Arc<RwLock<bool>>,
@@ -70,6 +71,7 @@ pub struct ScannerAgent {
     graduated_mints: Arc<RwLock<HashMap<String, chrono::DateTime<chrono::Utc>>>>,
     raydium_snipe_strategy: Arc<RwLock<Option<Arc<RaydiumSnipeStrategy>>>>,
     pump_fun_venue: Arc<RwLock<Option<Arc<crate::venues::curves::pump_fun::PumpFunVenue>>>>,
+    snapshot_cache: Arc<SnapshotCache>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -81,6 +83,10 @@ pub struct ScannerStats {
     pub last_scan_at: Option<chrono::DateTime<chrono::Utc>>,
     pub healthy_venues: u32,
     pub total_venues: u32,
+    /// Names of venues switched off at runtime; the scan loop skips them.
+    pub disabled_venues: Vec<String>,
+    /// Venue scans served from a snapshot still inside its freshness window.
+    pub snapshot_cache_hits: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -98,6 +104,7 @@ pub struct VenueStatus {
     pub name: String,
     pub venue_type: VenueType,
     pub is_healthy: bool,
+    pub is_enabled: bool,
 }
 
 const DEFAULT_RATE_LIMIT_INTERVAL_MS: u64 = 150; // 150ms minimum between venue API calls (~6.7 req/sec)
@@ -107,6 +114,7 @@ impl ScannerAgent {
         Self {
             id: Uuid::new_v4(),
             venues: Arc::new(RwLock::new(HashMap::new())),
+            disabled_venues: Arc::new(RwLock::new(HashSet::new())),
             event_tx,
             scan_interval_ms,
             is_running: Arc::new(RwLock::new(false)),
@@ -119,6 +127,7 @@ impl ScannerAgent {
             graduated_mints: Arc::new(RwLock::new(HashMap::new())),
             raydium_snipe_strategy: Arc::new(RwLock::new(None)),
             pump_fun_venue: Arc::new(RwLock::new(None)),
+            snapshot_cache: Arc::new(SnapshotCache::new(SnapshotFreshness::from_env())),
         }
     }
 
@@ -127,6 +136,11 @@ impl ScannerAgent {
         self
     }
 
+    pub fn with_snapshot_freshness(mut self, freshness: SnapshotFreshness) -> Self {
+        self.snapshot_cache = Arc::new(SnapshotCache::new(freshness));
+        self
+    }
+
     pub fn get_strategy_registry(&self) -> Arc<StrategyRegistry> {
         Arc::clone(&self.behavioral_strategies)
     }
@@ -184,20 +198,67 @@ impl ScannerAgent {
 
     pub async fn remove_venue(&self, venue_id: Uuid) -> bool {
         let mut venues = self.venues.write().await;
-        if venues.remove(&venue_id).is_some() {
+        if let Some(venue) = venues.remove(&venue_id) {
+            self.disabled_venues.write().await.remove(&venue_id);
+            self.snapshot_cache.invalidate(venue_id).await;
             let mut stats = self.stats.write().await;
             stats.total_venues = stats.total_venues.saturating_sub(1);
+            stats.disabled_venues.retain(|name| name != venue.name());
             true
         } else {
             false
         }
     }
 
+    /// Enables or disables a venue by id or name (case-insensitive) without
+    /// stopping the scanner. Returns None if no registered venue matches.
+    pub async fn set_venue_enabled(&self, venue: &str, enabled: bool) -> Option<VenueStatus> {
+        let venues = self.venues.read().await;
+        let target_id = Uuid::parse_str(venue).ok();
+        let matched = venues
+            .values()
+            .find(|v| Some(v.venue_id()) == target_id || v.name().eq_ignore_ascii_case(venue))?;
+        let venue_id = matched.venue_id();
+
+        let mut disabled = self.disabled_venues.write().await;
+        if enabled {
+            disabled.remove(&venue_id);
+        } else {
+            disabled.insert(venue_id);
+        }
+
+        let mut disabled_names: Vec<String> = venues
+            .values()
+            .filter(|v| disabled.contains(&v.venue_id()))
+            .map(|v| v.name().to_string())
+            .collect();
+        disabled_names.sort();
+        self.stats.write().await.disabled_venues = disabled_names;
+        drop(disabled);
+
+        tracing::info!(
+            venue_id = %venue_id,
+            venue_name = %matched.name(),
+            enabled,
+            "📡 Scanner: Venue {}",
+            if enabled { "enabled" } else { "disabled" }
+        );
+
+        Some(VenueStatus {
+            id: venue_id,
+            name: matched.name().to_string(),
+            venue_type: matched.venue_type(),
+            is_healthy: matched.is_healthy().await,
+            is_enabled: enabled,
+        })
+    }
+
     pub async fn get_status(&self) -> ScannerStatus {
         let is_running = *self.is_running.read().await;
         let stats = self.stats.read().await.clone();
 
         let venues = self.venues.read().await;
+        let disabled = self.disabled_venues.read().await.clone();
         let mut venue_statuses = Vec::new();
 
         for venue in venues.values() {
@@ -207,6 +268,7 @@ impl ScannerAgent {
                 name: venue.name().to_string(),
                 venue_type: venue.venue_type(),
                 is_healthy,
+                is_enabled: !disabled.contains(&venue.venue_id()),
             });
         }
 
@@ -228,6 +290,7 @@ impl ScannerAgent {
         drop(is_running);
 
         let venues = Arc::clone(&self.venues);
+        let disabled_venues = Arc::clone(&self.disabled_venues);
         let event_tx = self.event_tx.clone();
         let stats = Arc::clone(&self.stats);
         let is_running = Arc::clone(&self.is_running);
@@ -240,6 +303,7 @@ impl ScannerAgent {
         let graduated_mints = Arc::clone(&self.graduated_mints);
         let raydium_snipe_strategy = Arc::clone(&self.raydium_snipe_strategy);
         let pump_fun_venue = Arc::clone(&self.pump_fun_venue);
+        let snapshot_cache = Arc::clone(&self.snapshot_cache);
 
         if let Err(e) = event_tx.send(ArbEvent::new(
             "scanner_started",
@@ -264,20 +328,27 @@ impl ScannerAgent {
                 }
 
                 let venues_guard = venues.read().await;
+                let disabled = disabled_venues.read().await.clone();
                 let mut all_signals: Vec<Signal> = Vec::new();
                 let mut all_token_data: Vec<TokenData> = Vec::new();
                 let mut healthy_count = 0u32;
+                let mut cache_hits = 0u64;
 
                 for venue in venues_guard.values() {
+                    if disabled.contains(&venue.venue_id()) {
+                        continue;
+                    }
+
                     if venue.is_healthy().await {
                         healthy_count += 1;
 
-                        rate_limiter.wait_for_venue(venue.venue_id()).await;
-
-                        match venue.scan_for_token_data().await {
-                            Ok(token_data) => {
-                                for td in token_data {
-                                    all_token_data.push(TokenData {
+                        let fetch = || async {
+                            rate_limiter.wait_for_venue(venue.venue_id()).await;
+                            let token_data = venue.scan_for_token_data().await?;
+                            Ok::<_, AppError>(
+                                token_data
+                                    .into_iter()
+                                    .map(|td| TokenData {
                                         mint: td.mint,
                                         name: td.name,
                                         symbol: td.symbol,
@@ -292,8 +363,25 @@ impl ScannerAgent {
                                         created_at: chrono::Utc::now(),
                                         last_trade_at: None,
                                         metadata: td.metadata,
-                                    });
+                                    })
+                                    .collect(),
+                            )
+                        };
+
+                        match snapshot_cache
+                            .get_or_refresh(
+                                venue.venue_id(),
+                                venue.venue_type(),
+                                venue.name(),
+                                fetch,
+                            )
+                            .await
+                        {
+                            Ok((snapshot, from_cache)) => {
+                                if from_cache {
+                                    cache_hits += 1;
                                 }
+                                all_token_data.extend(snapshot.tokens);
                             }
                             Err(e) => {
                                 tracing::warn!(
@@ -435,6 +523,13 @@ impl ScannerAgent {
                                         strategy.name(),
                                         strategy_signals.len()
                                     );
+                                    behavioral_strategies
+                                        .performance()
+                                        .record_signals(
+                                            strategy.strategy_type(),
+                                            strategy_signals.len(),
+                                        )
+                                        .await;
                                     for signal in strategy_signals {
                                         if let Err(e) = event_tx.send(ArbEvent::new(
                                             "signal_detected",
@@ -475,6 +570,7 @@ impl ScannerAgent {
                 stats_guard.total_scans += 1;
                 stats_guard.last_scan_at = Some(chrono::Utc::now());
                 stats_guard.healthy_venues = healthy_count;
+                stats_guard.snapshot_cache_hits += cache_hits;
                 stats_guard.total_signals_detected += all_signals.len() as u64;
 
                 for signal in &all_signals {
@@ -553,9 +649,14 @@ impl ScannerAgent {
 
     pub async fn scan_once(&self) -> AppResult<Vec<Signal>> {
         let venues = self.venues.read().await;
+        let disabled = self.disabled_venues.read().await.clone();
         let mut all_signals = Vec::new();
 
         for venue in venues.values() {
+            if disabled.contains(&venue.venue_id()) {
+                continue;
+            }
+
             if venue.is_healthy().await {
                 match venue.scan_for_signals().await {
                     Ok(signals) => {
@@ -601,6 +702,21 @@ impl ScannerAgent {
         signals
     }
 
+    /// Cached signals from the last `SIGNAL_CACHE_TTL_SECS`, newest first, including
+    /// ones that have since expired. Used to preview strategies against recent history.
+    pub async fn get_signal_history(&self) -> Vec<Signal> {
+        let cache = self.recent_signals.read().await;
+        let cutoff = chrono::Utc::now() - chrono::Duration::seconds(SIGNAL_CACHE_TTL_SECS);
+
+        let mut signals: Vec<Signal> = cache
+            .iter()
+            .filter(|s| s.detected_at > cutoff)
+            .cloned()
+            .collect();
+        signals.sort_by(|a, b| b.detected_at.cmp(&a.detected_at));
+        signals
+    }
+
     pub async fn get_cached_signals_by_venue(
         &self,
         venue_type: VenueType,
@@ -670,3 +786,154 @@ impl ScannerAgent {
             .collect())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::AppError;
+    use crate::venues::{ProfitEstimate, Quote, QuoteParams, VenueTokenData};
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingVenue {
+        id: Uuid,
+        name: &'static str,
+        scans: Arc<AtomicUsize>,
+    }
+
+    impl CountingVenue {
+        fn new(name: &'static str) -> (Self, Arc<AtomicUsize>) {
+            let scans = Arc::new(AtomicUsize::new(0));
+            let venue = Self {
+                id: Uuid::new_v4(),
+                name,
+                scans: Arc::clone(&scans),
+            };
+            (venue, scans)
+        }
+    }
+
+    #[async_trait]
+    impl MevVenue for CountingVenue {
+        fn venue_id(&self) -> Uuid {
+            self.id
+        }
+
+        fn venue_type(&self) -> VenueType {
+            VenueType::BondingCurve
+        }
+
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn scan_for_signals(&self) -> AppResult<Vec<Signal>> {
+            self.scans.fetch_add(1, Ordering::SeqCst);
+            Ok(Vec::new())
+        }
+
+        async fn estimate_profit(&self, _signal: &Signal) -> AppResult<ProfitEstimate> {
+            Err(AppError::Internal("not used".to_string()))
+        }
+
+        async fn get_quote(&self, _params: &QuoteParams) -> AppResult<Quote> {
+            Err(AppError::Internal("not used".to_string()))
+        }
+
+        async fn is_healthy(&self) -> bool {
+            true
+        }
+
+        async fn scan_for_token_data(&self) -> AppResult<Vec<VenueTokenData>> {
+            self.scans.fetch_add(1, Ordering::SeqCst);
+            Ok(Vec::new())
+        }
+    }
+
+    async fn scanner_with_two_venues() -> (ScannerAgent, Arc<AtomicUsize>, Arc<AtomicUsize>) {
+        let (event_tx, _) = broadcast::channel(64);
+        let scanner = ScannerAgent::new(event_tx, 10).with_rate_limit_ms(0);
+        let (pump, pump_scans) = CountingVenue::new("pump.fun");
+        let (moonshot, moonshot_scans) = CountingVenue::new("moonshot");
+        scanner.add_venue(Box::new(pump)).await;
+        scanner.add_venue(Box::new(moonshot)).await;
+        (scanner, pump_scans, moonshot_scans)
+    }
+
+    #[tokio::test]
+    async fn test_disabled_venue_skipped_by_scan() {
+        let (scanner, pump_scans, moonshot_scans) = scanner_with_two_venues().await;
+
+        let status = scanner.set_venue_enabled("Pump.Fun", false).await.unwrap();
+        assert_eq!(status.name, "pump.fun");
+        assert!(!status.is_enabled);
+
+        scanner.scan_once().await.unwrap();
+        assert_eq!(pump_scans.load(Ordering::SeqCst), 0);
+        assert_eq!(moonshot_scans.load(Ordering::SeqCst), 1);
+
+        let status = scanner.get_status().await;
+        assert_eq!(status.stats.disabled_venues, vec!["pump.fun".to_string()]);
+        assert_eq!(status.stats.total_venues, 2);
+        let pump = status
+            .venue_statuses
+            .iter()
+            .find(|v| v.name == "pump.fun")
+            .unwrap();
+        assert!(!pump.is_enabled);
+
+        // Re-enabling by id puts it back in rotation
+        let pump_id = pump.id.to_string();
+        let status = scanner.set_venue_enabled(&pump_id, true).await.unwrap();
+        assert!(status.is_enabled);
+        scanner.scan_once().await.unwrap();
+        assert_eq!(pump_scans.load(Ordering::SeqCst), 1);
+        assert_eq!(moonshot_scans.load(Ordering::SeqCst), 2);
+        assert!(scanner.get_status().await.stats.disabled_venues.is_empty());
+
+        assert!(scanner.set_venue_enabled("raydium", false).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_scan_loop_reuses_fresh_venue_snapshot() {
+        let (event_tx, _) = broadcast::channel(64);
+        let scanner = ScannerAgent::new(event_tx, 10)
+            .with_rate_limit_ms(0)
+            .with_snapshot_freshness(
+                SnapshotFreshness::default().with_venue_ttl("moonshot", Duration::from_secs(60)),
+            );
+        let (pump, pump_scans) = CountingVenue::new("pump.fun");
+        let (moonshot, moonshot_scans) = CountingVenue::new("moonshot");
+        scanner.add_venue(Box::new(pump)).await;
+        scanner.add_venue(Box::new(moonshot)).await;
+
+        scanner.start().await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        scanner.stop().await;
+
+        let stats = scanner.get_status().await.stats;
+        assert!(stats.total_scans > 1);
+        // The slow venue is fetched once; the one without a window every scan
+        assert_eq!(moonshot_scans.load(Ordering::SeqCst), 1);
+        assert!(pump_scans.load(Ordering::SeqCst) > 1);
+        assert_eq!(stats.snapshot_cache_hits, stats.total_scans - 1);
+    }
+
+    #[tokio::test]
+    async fn test_scan_loop_continues_other_venues_while_one_disabled() {
+        let (scanner, pump_scans, moonshot_scans) = scanner_with_two_venues().await;
+        scanner.set_venue_enabled("moonshot", false).await.unwrap();
+
+        scanner.start().await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        scanner.stop().await;
+
+        assert!(pump_scans.load(Ordering::SeqCst) > 0);
+        assert_eq!(moonshot_scans.load(Ordering::SeqCst), 0);
+
+        let stats = scanner.get_status().await.stats;
+        assert!(stats.total_scans > 0);
+        assert_eq!(stats.healthy_venues, 1);
+        assert_eq!(stats.disabled_venues, vec!["moonshot".to_string()]);
+    }
+}
@@ -96,6 +96,7 @@ impl BehavioralStrategy for GraduationSniperStrategy {
                         confidence,
                         significance,
                         metadata: serde_json::json!({
+                            "signal_source": self.strategy_type(),
                             "token_name": token.name,
                             "token_symbol": token.symbol,
                             "progress_percent": token.graduation_progress,
@@ -1,12 +1,14 @@
 pub mod graduation_sniper_strategy;
+pub mod performance;
 pub mod raydium_snipe_strategy;
 pub mod venue_snapshot;
 // TODO: WIP - uncomment when KOL copy trading strategy is ready
 // pub mod kol_copy_strategy;
 
 pub use graduation_sniper_strategy::GraduationSniperStrategy;
+pub use performance::{StrategyFeedbackConfig, StrategyPerformance, StrategyPerformanceTracker};
 pub use raydium_snipe_strategy::RaydiumSnipeStrategy;
-pub use venue_snapshot::{TokenData, VenueSnapshot};
+pub use venue_snapshot::{SnapshotCache, SnapshotFreshness, TokenData, VenueSnapshot};
 
 #[derive(Debug, Clone)]
 pub struct GraduationEvent {
@@ -19,9 +21,11 @@ pub struct GraduationEvent {
 
 use async_trait::async_trait;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
 
+use crate::agents::strategy_engine::ClosedTradeTally;
 use crate::error::AppResult;
+use crate::events::{topics, AgentType, ArbEvent, EventSource};
 use crate::models::{Edge, RiskParams, Signal, VenueType};
 
 #[async_trait]
@@ -45,15 +49,25 @@ pub trait BehavioralStrategy: Send + Sync {
 
 pub struct StrategyRegistry {
     strategies: Arc<RwLock<Vec<Arc<dyn BehavioralStrategy>>>>,
+    performance: Arc<StrategyPerformanceTracker>,
 }
 
 impl StrategyRegistry {
     pub fn new() -> Self {
+        Self::with_feedback_config(StrategyFeedbackConfig::from_env())
+    }
+
+    pub fn with_feedback_config(config: StrategyFeedbackConfig) -> Self {
         Self {
             strategies: Arc::new(RwLock::new(Vec::new())),
+            performance: Arc::new(StrategyPerformanceTracker::new(config)),
         }
     }
 
+    pub fn performance(&self) -> Arc<StrategyPerformanceTracker> {
+        Arc::clone(&self.performance)
+    }
+
     pub async fn register(&self, strategy: Arc<dyn BehavioralStrategy>) {
         let mut strategies = self.strategies.write().await;
         strategies.push(strategy);
@@ -85,6 +99,11 @@ impl StrategyRegistry {
         let strategies = self.strategies.read().await;
         for strategy in strategies.iter() {
             if strategy.name() == name {
+                if active && !strategy.is_active() {
+                    self.performance
+                        .reset_window(strategy.strategy_type())
+                        .await;
+                }
                 strategy.set_active(active).await;
                 return true;
             }
@@ -92,6 +111,97 @@ impl StrategyRegistry {
         false
     }
 
+    /// Attributes a closed trade to its strategy type and deactivates the matching
+    /// strategies if their recent P&L crossed the auto-disable threshold. Returns
+    /// the names of any strategies that were disabled.
+    pub async fn record_trade_closed(
+        &self,
+        strategy_type: &str,
+        realized_pnl_sol: f64,
+    ) -> Vec<String> {
+        if !self
+            .performance
+            .record_trade_closed(strategy_type, realized_pnl_sol)
+            .await
+        {
+            return Vec::new();
+        }
+
+        let mut disabled = Vec::new();
+        for strategy in self.strategies.read().await.iter() {
+            if strategy.strategy_type() == strategy_type && strategy.is_active() {
+                strategy.set_active(false).await;
+                disabled.push(strategy.name().to_string());
+            }
+        }
+        if !disabled.is_empty() {
+            self.performance.mark_auto_disabled(strategy_type).await;
+        }
+        disabled
+    }
+
+    /// Feeds position open/close events back into per-strategy performance until the
+    /// event channel closes.
+    pub fn start_feedback_loop(self: &Arc<Self>, event_tx: &broadcast::Sender<ArbEvent>) {
+        let mut event_rx = event_tx.subscribe();
+        let registry = Arc::clone(self);
+        let event_tx = event_tx.clone();
+
+        tokio::spawn(async move {
+            let mut closed_trades = ClosedTradeTally::default();
+            loop {
+                let event = match event_rx.recv().await {
+                    Ok(event) => event,
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Strategy feedback loop lagged, skipped {} events", skipped);
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+
+                let Some(source) = event
+                    .payload
+                    .get("signal_source")
+                    .and_then(|v| v.as_str())
+                    .filter(|s| !s.is_empty())
+                else {
+                    continue;
+                };
+
+                if event.topic == topics::position::OPENED {
+                    registry.performance.record_trade_opened(source).await;
+                } else if event.topic == topics::position::CLOSED {
+                    let Some(pnl) = closed_trades.record(&event.payload) else {
+                        continue;
+                    };
+
+                    for name in registry.record_trade_closed(source, pnl).await {
+                        let performance = registry.performance.get(source).await;
+                        tracing::warn!(
+                            strategy = %name,
+                            recent_pnl_sol = ?performance.as_ref().map(|p| p.recent_pnl_sol),
+                            "⛔ Behavioral strategy auto-disabled after sustained losses"
+                        );
+                        crate::events::broadcast_event(
+                            &event_tx,
+                            ArbEvent::new(
+                                "strategy_auto_disabled",
+                                EventSource::Agent(AgentType::Scanner),
+                                topics::strategy::DISABLED,
+                                serde_json::json!({
+                                    "name": name,
+                                    "strategy_type": source,
+                                    "reason": "recent_pnl_below_threshold",
+                                    "performance": performance,
+                                }),
+                            ),
+                        );
+                    }
+                }
+            }
+        });
+    }
+
     pub async fn count(&self) -> usize {
         self.strategies.read().await.len()
     }
@@ -107,3 +217,83 @@ impl Default for StrategyRegistry {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    struct TestStrategy {
+        active: AtomicBool,
+    }
+
+    #[async_trait]
+    impl BehavioralStrategy for TestStrategy {
+        fn strategy_type(&self) -> &str {
+            "graduation_snipe"
+        }
+
+        fn name(&self) -> &str {
+            "Test Sniper"
+        }
+
+        fn supported_venues(&self) -> Vec<VenueType> {
+            vec![VenueType::BondingCurve]
+        }
+
+        async fn scan(&self, _snapshot: &VenueSnapshot) -> AppResult<Vec<Signal>> {
+            Ok(Vec::new())
+        }
+
+        fn is_active(&self) -> bool {
+            self.active.load(Ordering::SeqCst)
+        }
+
+        async fn set_active(&self, active: bool) {
+            self.active.store(active, Ordering::SeqCst);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_losing_strategy_auto_disabled_and_reenabled() {
+        let registry = StrategyRegistry::with_feedback_config(StrategyFeedbackConfig {
+            auto_disable_enabled: true,
+            window_size: 4,
+            min_trades: 2,
+            auto_disable_pnl_sol: -0.1,
+        });
+        let strategy = Arc::new(TestStrategy {
+            active: AtomicBool::new(true),
+        });
+        registry.register(strategy.clone()).await;
+
+        assert!(registry
+            .record_trade_closed("graduation_snipe", -0.05)
+            .await
+            .is_empty());
+        assert!(registry
+            .record_trade_closed("other_source", -1.0)
+            .await
+            .is_empty());
+        assert!(strategy.is_active());
+
+        let disabled = registry
+            .record_trade_closed("graduation_snipe", -0.05)
+            .await;
+        assert_eq!(disabled, vec!["Test Sniper".to_string()]);
+        assert!(!strategy.is_active());
+
+        assert!(registry.toggle("Test Sniper", true).await);
+        let perf = registry
+            .performance()
+            .get("graduation_snipe")
+            .await
+            .unwrap();
+        assert_eq!(perf.recent_trades, 0);
+        assert!(registry
+            .record_trade_closed("graduation_snipe", -0.05)
+            .await
+            .is_empty());
+        assert!(strategy.is_active());
+    }
+}
@@ -0,0 +1,280 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use tokio::sync::RwLock;
+
+pub const DEFAULT_FEEDBACK_WINDOW: usize = 10;
+pub const DEFAULT_AUTO_DISABLE_MIN_TRADES: usize = 5;
+pub const DEFAULT_AUTO_DISABLE_PNL_SOL: f64 = -0.1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyFeedbackConfig {
+    pub auto_disable_enabled: bool,
+    /// Number of most recent closed trades that make up the feedback window.
+    pub window_size: usize,
+    /// Closed trades required in the window before auto-disable is considered.
+    pub min_trades: usize,
+    /// Auto-disable when the window's P&L sum is at or below this value.
+    pub auto_disable_pnl_sol: f64,
+}
+
+impl Default for StrategyFeedbackConfig {
+    fn default() -> Self {
+        Self {
+            auto_disable_enabled: false,
+            window_size: DEFAULT_FEEDBACK_WINDOW,
+            min_trades: DEFAULT_AUTO_DISABLE_MIN_TRADES,
+            auto_disable_pnl_sol: DEFAULT_AUTO_DISABLE_PNL_SOL,
+        }
+    }
+}
+
+impl StrategyFeedbackConfig {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let window_size = std::env::var("STRATEGY_FEEDBACK_WINDOW")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(defaults.window_size)
+            .max(1);
+
+        Self {
+            auto_disable_enabled: std::env::var("STRATEGY_AUTO_DISABLE_ENABLED")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(defaults.auto_disable_enabled),
+            window_size,
+            min_trades: std::env::var("STRATEGY_AUTO_DISABLE_MIN_TRADES")
+                .ok()
+                .and_then(|v| v.parse::<usize>().ok())
+                .unwrap_or(defaults.min_trades)
+                .clamp(1, window_size),
+            auto_disable_pnl_sol: std::env::var("STRATEGY_AUTO_DISABLE_PNL_SOL")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(defaults.auto_disable_pnl_sol),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyPerformance {
+    pub strategy_type: String,
+    pub signals_generated: u64,
+    pub trades_opened: u64,
+    pub trades_closed: u64,
+    pub wins: u64,
+    pub losses: u64,
+    pub total_pnl_sol: f64,
+    pub recent_trades: usize,
+    pub recent_pnl_sol: f64,
+    pub recent_win_rate: f64,
+    pub signal_to_trade_rate: f64,
+    pub auto_disabled_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Default)]
+struct PerformanceRecord {
+    signals_generated: u64,
+    trades_opened: u64,
+    trades_closed: u64,
+    wins: u64,
+    losses: u64,
+    total_pnl_sol: f64,
+    recent_pnl: VecDeque<f64>,
+    auto_disabled_at: Option<DateTime<Utc>>,
+}
+
+impl PerformanceRecord {
+    fn recent_pnl_sol(&self) -> f64 {
+        self.recent_pnl.iter().sum()
+    }
+
+    fn snapshot(&self, strategy_type: &str) -> StrategyPerformance {
+        let recent_wins = self.recent_pnl.iter().filter(|p| **p > 0.0).count();
+        StrategyPerformance {
+            strategy_type: strategy_type.to_string(),
+            signals_generated: self.signals_generated,
+            trades_opened: self.trades_opened,
+            trades_closed: self.trades_closed,
+            wins: self.wins,
+            losses: self.losses,
+            total_pnl_sol: self.total_pnl_sol,
+            recent_trades: self.recent_pnl.len(),
+            recent_pnl_sol: self.recent_pnl_sol(),
+            recent_win_rate: if self.recent_pnl.is_empty() {
+                0.0
+            } else {
+                recent_wins as f64 / self.recent_pnl.len() as f64
+            },
+            signal_to_trade_rate: if self.signals_generated == 0 {
+                0.0
+            } else {
+                self.trades_opened as f64 / self.signals_generated as f64
+            },
+            auto_disabled_at: self.auto_disabled_at,
+        }
+    }
+}
+
+/// Attributes signals, trades and realized P&L to the behavioral strategy that
+/// produced them, keyed by the `signal_source` carried on signals and positions.
+pub struct StrategyPerformanceTracker {
+    config: RwLock<StrategyFeedbackConfig>,
+    records: RwLock<HashMap<String, PerformanceRecord>>,
+}
+
+impl StrategyPerformanceTracker {
+    pub fn new(config: StrategyFeedbackConfig) -> Self {
+        Self {
+            config: RwLock::new(config),
+            records: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub async fn config(&self) -> StrategyFeedbackConfig {
+        self.config.read().await.clone()
+    }
+
+    pub async fn record_signals(&self, strategy_type: &str, count: usize) {
+        let mut records = self.records.write().await;
+        records
+            .entry(strategy_type.to_string())
+            .or_default()
+            .signals_generated += count as u64;
+    }
+
+    pub async fn record_trade_opened(&self, strategy_type: &str) {
+        let mut records = self.records.write().await;
+        records
+            .entry(strategy_type.to_string())
+            .or_default()
+            .trades_opened += 1;
+    }
+
+    /// Records a closed trade and returns true when the strategy's recent P&L has
+    /// crossed the auto-disable threshold.
+    pub async fn record_trade_closed(&self, strategy_type: &str, realized_pnl_sol: f64) -> bool {
+        let config = self.config().await;
+        let mut records = self.records.write().await;
+        let record = records.entry(strategy_type.to_string()).or_default();
+
+        record.trades_closed += 1;
+        record.total_pnl_sol += realized_pnl_sol;
+        if realized_pnl_sol > 0.0 {
+            record.wins += 1;
+        } else if realized_pnl_sol < 0.0 {
+            record.losses += 1;
+        }
+
+        record.recent_pnl.push_back(realized_pnl_sol);
+        while record.recent_pnl.len() > config.window_size.max(1) {
+            record.recent_pnl.pop_front();
+        }
+
+        config.auto_disable_enabled
+            && record.auto_disabled_at.is_none()
+            && record.recent_pnl.len() >= config.min_trades
+            && record.recent_pnl_sol() <= config.auto_disable_pnl_sol
+    }
+
+    pub async fn mark_auto_disabled(&self, strategy_type: &str) {
+        let mut records = self.records.write().await;
+        records
+            .entry(strategy_type.to_string())
+            .or_default()
+            .auto_disabled_at = Some(Utc::now());
+    }
+
+    /// Clears the feedback window so a manually re-enabled strategy starts fresh.
+    pub async fn reset_window(&self, strategy_type: &str) {
+        if let Some(record) = self.records.write().await.get_mut(strategy_type) {
+            record.recent_pnl.clear();
+            record.auto_disabled_at = None;
+        }
+    }
+
+    pub async fn get(&self, strategy_type: &str) -> Option<StrategyPerformance> {
+        self.records
+            .read()
+            .await
+            .get(strategy_type)
+            .map(|r| r.snapshot(strategy_type))
+    }
+
+    pub async fn all(&self) -> Vec<StrategyPerformance> {
+        let records = self.records.read().await;
+        let mut all: Vec<StrategyPerformance> =
+            records.iter().map(|(k, r)| r.snapshot(k)).collect();
+        all.sort_by(|a, b| a.strategy_type.cmp(&b.strategy_type));
+        all
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracker() -> StrategyPerformanceTracker {
+        StrategyPerformanceTracker::new(StrategyFeedbackConfig {
+            auto_disable_enabled: true,
+            window_size: 5,
+            min_trades: 3,
+            auto_disable_pnl_sol: -0.1,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_losing_trades_lower_recent_pnl() {
+        let tracker = tracker();
+        tracker.record_signals("graduation_snipe", 4).await;
+        tracker.record_trade_opened("graduation_snipe").await;
+        tracker.record_trade_opened("graduation_snipe").await;
+
+        assert!(!tracker.record_trade_closed("graduation_snipe", 0.05).await);
+        let after_win = tracker.get("graduation_snipe").await.unwrap();
+        assert!((after_win.recent_pnl_sol - 0.05).abs() < 1e-9);
+        assert_eq!(after_win.recent_win_rate, 1.0);
+        assert_eq!(after_win.signal_to_trade_rate, 0.5);
+
+        assert!(!tracker.record_trade_closed("graduation_snipe", -0.04).await);
+        let after_loss = tracker.get("graduation_snipe").await.unwrap();
+        assert!(after_loss.recent_pnl_sol < after_win.recent_pnl_sol);
+        assert_eq!(after_loss.recent_win_rate, 0.5);
+        assert_eq!(after_loss.losses, 1);
+    }
+
+    #[tokio::test]
+    async fn test_auto_disable_triggers_at_threshold() {
+        let tracker = tracker();
+
+        assert!(!tracker.record_trade_closed("raydium_snipe", -0.06).await);
+        assert!(!tracker.record_trade_closed("raydium_snipe", -0.06).await);
+        // Third loss meets min_trades and takes the window to -0.18.
+        assert!(tracker.record_trade_closed("raydium_snipe", -0.06).await);
+
+        tracker.mark_auto_disabled("raydium_snipe").await;
+        assert!(!tracker.record_trade_closed("raydium_snipe", -0.06).await);
+
+        tracker.reset_window("raydium_snipe").await;
+        let perf = tracker.get("raydium_snipe").await.unwrap();
+        assert_eq!(perf.recent_trades, 0);
+        assert_eq!(perf.trades_closed, 4);
+        assert!(perf.auto_disabled_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_window_above_threshold_stays_enabled() {
+        let tracker = tracker();
+        for pnl in [-0.03, -0.03, -0.03, 0.2, -0.03] {
+            assert!(!tracker.record_trade_closed("graduation_snipe", pnl).await);
+        }
+
+        let disabled = StrategyPerformanceTracker::new(StrategyFeedbackConfig {
+            auto_disable_enabled: false,
+            ..StrategyFeedbackConfig::default()
+        });
+        for _ in 0..10 {
+            assert!(!disabled.record_trade_closed("graduation_snipe", -1.0).await);
+        }
+    }
+}
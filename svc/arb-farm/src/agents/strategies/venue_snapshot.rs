@@ -1,7 +1,13 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::Duration;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
 use uuid::Uuid;
 
+use crate::error::AppResult;
 use crate::models::{Signal, VenueType};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,6 +44,13 @@ impl VenueSnapshot {
         self
     }
 
+    /// Whether the snapshot is younger than `ttl`. A zero window is never fresh.
+    pub fn is_fresh(&self, ttl: Duration) -> bool {
+        chrono::Duration::from_std(ttl)
+            .map(|ttl| Utc::now() - self.timestamp < ttl)
+            .unwrap_or(false)
+    }
+
     pub fn token_count(&self) -> usize {
         self.tokens.len()
     }
@@ -61,6 +74,122 @@ impl VenueSnapshot {
     }
 }
 
+/// How long each venue's snapshot is reused before the scanner fetches it again.
+/// Venues without an override use `default_ttl`; zero refreshes on every scan.
+#[derive(Debug, Clone, Default)]
+pub struct SnapshotFreshness {
+    pub default_ttl: Duration,
+    pub venue_ttls: HashMap<String, Duration>,
+}
+
+impl SnapshotFreshness {
+    /// `SCANNER_SNAPSHOT_TTL_MS` (default 0) and `SCANNER_VENUE_SNAPSHOT_TTL_MS`, a list of
+    /// per-venue overrides such as `moonshot=30000,pump.fun=2000`.
+    pub fn from_env() -> Self {
+        let default_ttl = std::env::var("SCANNER_SNAPSHOT_TTL_MS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(Duration::from_millis)
+            .unwrap_or_default();
+        let venue_ttls = std::env::var("SCANNER_VENUE_SNAPSHOT_TTL_MS")
+            .map(|v| Self::parse_venue_ttls(&v))
+            .unwrap_or_default();
+
+        Self {
+            default_ttl,
+            venue_ttls,
+        }
+    }
+
+    fn parse_venue_ttls(value: &str) -> HashMap<String, Duration> {
+        value
+            .split(',')
+            .filter_map(|entry| {
+                let (venue, ms) = entry.split_once('=')?;
+                let ms = ms.trim().parse::<u64>().ok()?;
+                Some((venue.trim().to_lowercase(), Duration::from_millis(ms)))
+            })
+            .collect()
+    }
+
+    pub fn with_venue_ttl(mut self, venue_name: &str, ttl: Duration) -> Self {
+        self.venue_ttls.insert(venue_name.to_lowercase(), ttl);
+        self
+    }
+
+    pub fn ttl_for(&self, venue_name: &str) -> Duration {
+        self.venue_ttls
+            .get(&venue_name.to_lowercase())
+            .copied()
+            .unwrap_or(self.default_ttl)
+    }
+}
+
+/// Last snapshot fetched from each venue, reused while it is within the venue's
+/// freshness window.
+#[derive(Debug, Default)]
+pub struct SnapshotCache {
+    freshness: SnapshotFreshness,
+    snapshots: RwLock<HashMap<Uuid, VenueSnapshot>>,
+}
+
+impl SnapshotCache {
+    pub fn new(freshness: SnapshotFreshness) -> Self {
+        Self {
+            freshness,
+            snapshots: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn freshness(&self) -> &SnapshotFreshness {
+        &self.freshness
+    }
+
+    pub async fn get_fresh(&self, venue_id: Uuid) -> Option<VenueSnapshot> {
+        let snapshots = self.snapshots.read().await;
+        let snapshot = snapshots.get(&venue_id)?;
+        snapshot
+            .is_fresh(self.freshness.ttl_for(&snapshot.venue_name))
+            .then(|| snapshot.clone())
+    }
+
+    pub async fn store(&self, snapshot: VenueSnapshot) {
+        self.snapshots
+            .write()
+            .await
+            .insert(snapshot.venue_id, snapshot);
+    }
+
+    pub async fn invalidate(&self, venue_id: Uuid) {
+        self.snapshots.write().await.remove(&venue_id);
+    }
+
+    /// Returns the venue's snapshot, calling `fetch` only when the cached one is missing
+    /// or stale. The flag is true when the snapshot came from the cache. A failed fetch
+    /// is returned as-is; a stale snapshot is never served in its place.
+    pub async fn get_or_refresh<F, Fut>(
+        &self,
+        venue_id: Uuid,
+        venue_type: VenueType,
+        venue_name: &str,
+        fetch: F,
+    ) -> AppResult<(VenueSnapshot, bool)>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = AppResult<Vec<TokenData>>>,
+    {
+        if let Some(snapshot) = self.get_fresh(venue_id).await {
+            return Ok((snapshot, true));
+        }
+
+        let tokens = fetch().await?;
+        let snapshot =
+            VenueSnapshot::new(venue_id, venue_type, venue_name.to_string()).with_tokens(tokens);
+        self.store(snapshot.clone()).await;
+        Ok((snapshot, false))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenData {
     pub mint: String,
@@ -132,3 +261,101 @@ impl TokenData {
         self.volume_24h_sol > min_velocity * self.market_cap_sol.max(0.01)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    async fn fetch_counted(
+        cache: &SnapshotCache,
+        venue_id: Uuid,
+        venue_name: &str,
+        fetches: &AtomicUsize,
+    ) -> (VenueSnapshot, bool) {
+        cache
+            .get_or_refresh(venue_id, VenueType::BondingCurve, venue_name, || async {
+                let n = fetches.fetch_add(1, Ordering::SeqCst);
+                Ok(vec![TokenData::new(
+                    format!("Mint{}", n),
+                    "Token".to_string(),
+                    "TKN".to_string(),
+                )])
+            })
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fresh_snapshot_reused_within_window() {
+        let cache = SnapshotCache::new(SnapshotFreshness {
+            default_ttl: Duration::from_secs(60),
+            ..Default::default()
+        });
+        let venue_id = Uuid::new_v4();
+        let fetches = AtomicUsize::new(0);
+
+        let (first, cached) = fetch_counted(&cache, venue_id, "moonshot", &fetches).await;
+        assert!(!cached);
+        let (second, cached) = fetch_counted(&cache, venue_id, "moonshot", &fetches).await;
+        assert!(cached);
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        assert_eq!(second.tokens[0].mint, first.tokens[0].mint);
+
+        // Other venues keep their own entries
+        fetch_counted(&cache, Uuid::new_v4(), "pump.fun", &fetches).await;
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_stale_snapshot_refreshed() {
+        let cache = SnapshotCache::new(
+            SnapshotFreshness {
+                default_ttl: Duration::from_secs(60),
+                ..Default::default()
+            }
+            .with_venue_ttl("Pump.Fun", Duration::from_secs(2)),
+        );
+        let venue_id = Uuid::new_v4();
+        let fetches = AtomicUsize::new(0);
+
+        let (mut snapshot, _) = fetch_counted(&cache, venue_id, "pump.fun", &fetches).await;
+        assert!(
+            fetch_counted(&cache, venue_id, "pump.fun", &fetches)
+                .await
+                .1
+        );
+
+        // Past the venue's 2s window, though still inside the 60s default
+        snapshot.timestamp = Utc::now() - chrono::Duration::seconds(3);
+        cache.store(snapshot).await;
+        let (refreshed, cached) = fetch_counted(&cache, venue_id, "pump.fun", &fetches).await;
+        assert!(!cached);
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+        assert_eq!(refreshed.tokens[0].mint, "Mint1");
+    }
+
+    #[tokio::test]
+    async fn test_zero_window_always_refreshes() {
+        let cache = SnapshotCache::default();
+        let venue_id = Uuid::new_v4();
+        let fetches = AtomicUsize::new(0);
+
+        for _ in 0..3 {
+            assert!(
+                !fetch_counted(&cache, venue_id, "moonshot", &fetches)
+                    .await
+                    .1
+            );
+        }
+        assert_eq!(fetches.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_parse_venue_ttls() {
+        let ttls = SnapshotFreshness::parse_venue_ttls("moonshot=30000, Pump.Fun = 2000,bad");
+        assert_eq!(ttls.len(), 2);
+        assert_eq!(ttls["moonshot"], Duration::from_secs(30));
+        assert_eq!(ttls["pump.fun"], Duration::from_secs(2));
+    }
+}
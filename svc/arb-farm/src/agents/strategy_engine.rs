@@ -1,21 +1,138 @@
+use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::{broadcast, RwLock};
 use uuid::Uuid;
 
 use crate::consensus::{format_edge_context, ConsensusEngine};
+use crate::database::StrategyRepository;
 use crate::error::AppResult;
 use crate::events::{
-    edge as edge_topics, strategy as strategy_topics, AgentType, ArbEvent, AtomicityLevel,
-    EventSource,
+    edge as edge_topics, position as position_topics, strategy as strategy_topics, AgentType,
+    ArbEvent, AtomicityLevel, EventSource,
 };
+use crate::execution::ApprovalManager;
 use crate::models::{Edge, EdgeStatus, RiskParams, Signal, Strategy};
 
+const MIN_SIGNAL_CONFIDENCE: f64 = 0.1;
+const GRADUATION_SNIPE_MIN_PROGRESS: f64 = 85.0;
+
 pub struct StrategyEngine {
     id: Uuid,
     strategies: Arc<RwLock<HashMap<Uuid, Strategy>>>,
     event_tx: broadcast::Sender<ArbEvent>,
     processed_signals: Arc<RwLock<HashSet<Uuid>>>,
+    trade_stats: Arc<RwLock<HashMap<Uuid, StrategyTradeStats>>>,
+    closed_trades: Arc<RwLock<ClosedTradeTally>>,
+}
+
+/// Closed-trade results for a strategy since it was last enabled, used for auto-kill.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StrategyTradeStats {
+    pub trades_closed: u64,
+    pub consecutive_losses: u32,
+    pub realized_pnl_sol: f64,
+    pub peak_pnl_sol: f64,
+    pub auto_kill: Option<AutoKillTrigger>,
+}
+
+impl StrategyTradeStats {
+    fn record(&mut self, realized_pnl_sol: f64) {
+        self.trades_closed += 1;
+        if realized_pnl_sol < 0.0 {
+            self.consecutive_losses += 1;
+        } else {
+            self.consecutive_losses = 0;
+        }
+        self.realized_pnl_sol += realized_pnl_sol;
+        self.peak_pnl_sol = self.peak_pnl_sol.max(self.realized_pnl_sol);
+    }
+
+    pub fn drawdown_sol(&self) -> f64 {
+        self.peak_pnl_sol - self.realized_pnl_sol
+    }
+
+    fn check_auto_kill(&self, risk_params: &RiskParams) -> Option<AutoKillTrigger> {
+        if let Some(limit) = risk_params.auto_kill_consecutive_losses {
+            if limit > 0 && self.consecutive_losses >= limit {
+                return Some(AutoKillTrigger::ConsecutiveLosses {
+                    losses: self.consecutive_losses,
+                    limit,
+                });
+            }
+        }
+        if let Some(limit_sol) = risk_params.auto_kill_max_drawdown_sol {
+            let drawdown_sol = self.drawdown_sol();
+            if limit_sol > 0.0 && drawdown_sol >= limit_sol {
+                return Some(AutoKillTrigger::MaxDrawdown {
+                    drawdown_sol,
+                    limit_sol,
+                });
+            }
+        }
+        None
+    }
+}
+
+/// Folds `position.closed` events into one closed trade per position. Partial exits
+/// (`is_final: false`) are held back and added to the P&L of the final exit.
+#[derive(Debug, Default)]
+pub struct ClosedTradeTally {
+    partial_pnl_sol: HashMap<Uuid, f64>,
+}
+
+impl ClosedTradeTally {
+    /// Returns the position's total realized P&L once its final exit arrives. Events
+    /// without `is_final` are treated as final, like manual closes.
+    pub fn record(&mut self, payload: &serde_json::Value) -> Option<f64> {
+        let realized_pnl_sol = payload
+            .get("realized_pnl_sol")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(0.0);
+        let is_final = payload
+            .get("is_final")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+        let position_id = payload
+            .get("position_id")
+            .and_then(|v| v.as_str())
+            .and_then(|s| Uuid::parse_str(s).ok());
+
+        match (position_id, is_final) {
+            (Some(position_id), false) => {
+                *self.partial_pnl_sol.entry(position_id).or_default() += realized_pnl_sol;
+                None
+            }
+            (Some(position_id), true) => {
+                Some(self.partial_pnl_sol.remove(&position_id).unwrap_or(0.0) + realized_pnl_sol)
+            }
+            (None, _) => Some(realized_pnl_sol),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "condition", rename_all = "snake_case")]
+pub enum AutoKillTrigger {
+    ConsecutiveLosses { losses: u32, limit: u32 },
+    MaxDrawdown { drawdown_sol: f64, limit_sol: f64 },
+}
+
+impl AutoKillTrigger {
+    pub fn describe(&self) -> String {
+        match self {
+            Self::ConsecutiveLosses { losses, limit } => {
+                format!("{} consecutive losses (limit {})", losses, limit)
+            }
+            Self::MaxDrawdown {
+                drawdown_sol,
+                limit_sol,
+            } => format!(
+                "drawdown {:.4} SOL (limit {:.4} SOL)",
+                drawdown_sol, limit_sol
+            ),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -26,6 +143,126 @@ pub struct MatchResult {
     pub approved: bool,
     pub reason: Option<String>,
     pub created_edge: Option<Edge>,
+    pub explanation: MatchExplanation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchCriterion {
+    StrategyActive,
+    Venue,
+    GraduationProgress,
+    SignalSource,
+    MinProfit,
+    MinConfidence,
+}
+
+impl MatchCriterion {
+    /// Risk criteria reject a signal the strategy targets; the others decide
+    /// whether the strategy targets the signal at all.
+    pub fn is_risk(&self) -> bool {
+        matches!(self, Self::MinProfit | Self::MinConfidence)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CriterionCheck {
+    pub criterion: MatchCriterion,
+    pub passed: bool,
+    pub detail: String,
+}
+
+/// Every criterion evaluated for a signal/strategy pair, including the ones after
+/// the first failure, so a near miss shows everything that would need to change.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MatchExplanation {
+    pub checks: Vec<CriterionCheck>,
+}
+
+impl MatchExplanation {
+    fn check(&mut self, criterion: MatchCriterion, passed: bool, detail: String) {
+        self.checks.push(CriterionCheck {
+            criterion,
+            passed,
+            detail,
+        });
+    }
+
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|c| c.passed)
+    }
+
+    pub fn targets_signal(&self) -> bool {
+        self.checks
+            .iter()
+            .filter(|c| !c.criterion.is_risk())
+            .all(|c| c.passed)
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = &CriterionCheck> {
+        self.checks.iter().filter(|c| !c.passed)
+    }
+
+    pub fn failed_criteria(&self) -> Vec<MatchCriterion> {
+        self.failed().map(|c| c.criterion).collect()
+    }
+
+    pub fn summary(&self) -> String {
+        let failures: Vec<String> = self
+            .failed()
+            .map(|c| format!("{}: {}", criterion_name(c.criterion), c.detail))
+            .collect();
+
+        if failures.is_empty() {
+            "all criteria passed".to_string()
+        } else {
+            failures.join("; ")
+        }
+    }
+}
+
+fn criterion_name(criterion: MatchCriterion) -> &'static str {
+    match criterion {
+        MatchCriterion::StrategyActive => "strategy_active",
+        MatchCriterion::Venue => "venue",
+        MatchCriterion::GraduationProgress => "graduation_progress",
+        MatchCriterion::SignalSource => "signal_source",
+        MatchCriterion::MinProfit => "min_profit",
+        MatchCriterion::MinConfidence => "min_confidence",
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StrategyMatchExplanation {
+    pub strategy_id: Uuid,
+    pub strategy_name: String,
+    pub explanation: MatchExplanation,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PreviewEdge {
+    pub signal_id: Uuid,
+    pub edge: Edge,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PreviewRejection {
+    pub signal_id: Uuid,
+    pub reason: String,
+    pub explanation: MatchExplanation,
+}
+
+/// What a strategy would have done with a batch of signals. `edges` are never
+/// broadcast or persisted; `rejected` holds signals the strategy targets but its
+/// risk params turn down.
+#[derive(Debug, Clone, Serialize)]
+pub struct StrategyPreview {
+    pub strategy_id: Uuid,
+    pub strategy_name: String,
+    pub is_active: bool,
+    pub signals_evaluated: usize,
+    pub edges: Vec<PreviewEdge>,
+    pub rejected: Vec<PreviewRejection>,
 }
 
 impl StrategyEngine {
@@ -35,6 +272,8 @@ impl StrategyEngine {
             strategies: Arc::new(RwLock::new(HashMap::new())),
             event_tx,
             processed_signals: Arc::new(RwLock::new(HashSet::new())),
+            trade_stats: Arc::new(RwLock::new(HashMap::new())),
+            closed_trades: Arc::new(RwLock::new(ClosedTradeTally::default())),
         }
     }
 
@@ -86,6 +325,10 @@ impl StrategyEngine {
         let mut strategies = self.strategies.write().await;
         if let Some(strategy) = strategies.get_mut(&strategy_id) {
             strategy.is_active = enabled;
+            if enabled {
+                // Auto-kill counters start fresh each time the strategy is re-enabled
+                self.trade_stats.write().await.remove(&strategy_id);
+            }
             let topic = if enabled {
                 strategy_topics::ENABLED
             } else {
@@ -243,6 +486,7 @@ impl StrategyEngine {
     pub async fn reset_stats(&self, strategy_id: Uuid) -> AppResult<()> {
         let strategies = self.strategies.read().await;
         if strategies.contains_key(&strategy_id) {
+            self.trade_stats.write().await.remove(&strategy_id);
             crate::events::broadcast_event(
                 &self.event_tx,
                 ArbEvent::new(
@@ -265,6 +509,14 @@ impl StrategyEngine {
     }
 
     pub async fn kill_strategy(&self, strategy_id: Uuid) -> AppResult<String> {
+        self.kill_strategy_with_trigger(strategy_id, None).await
+    }
+
+    async fn kill_strategy_with_trigger(
+        &self,
+        strategy_id: Uuid,
+        trigger: Option<&AutoKillTrigger>,
+    ) -> AppResult<String> {
         let mut strategies = self.strategies.write().await;
         if let Some(strategy) = strategies.get_mut(&strategy_id) {
             let strategy_name = strategy.name.clone();
@@ -284,6 +536,8 @@ impl StrategyEngine {
                         "strategy_name": strategy_name,
                         "killed": true,
                         "action": "emergency_stop",
+                        "reason": if trigger.is_some() { "auto_kill" } else { "manual" },
+                        "trigger": trigger,
                         "cancel_pending_edges": true,
                         "cancel_pending_approvals": true,
                     }),
@@ -293,6 +547,7 @@ impl StrategyEngine {
             tracing::warn!(
                 strategy_id = %strategy_id,
                 strategy_name = %strategy_name,
+                trigger = ?trigger.map(AutoKillTrigger::describe),
                 "Strategy killed - all related operations should halt"
             );
 
@@ -305,28 +560,138 @@ impl StrategyEngine {
         }
     }
 
+    pub async fn trade_stats(&self, strategy_id: Uuid) -> Option<StrategyTradeStats> {
+        self.trade_stats.read().await.get(&strategy_id).cloned()
+    }
+
+    /// Records a closed trade and kills the strategy if that trips one of its auto-kill
+    /// conditions. Returns the trigger when this call killed it.
+    pub async fn record_trade_closed(
+        &self,
+        strategy_id: Uuid,
+        realized_pnl_sol: f64,
+    ) -> Option<AutoKillTrigger> {
+        let (risk_params, is_active) = {
+            let strategies = self.strategies.read().await;
+            let strategy = strategies.get(&strategy_id)?;
+            (strategy.risk_params.clone(), strategy.is_active)
+        };
+
+        let trigger = {
+            let mut trade_stats = self.trade_stats.write().await;
+            let stats = trade_stats.entry(strategy_id).or_default();
+            stats.record(realized_pnl_sol);
+            if !is_active || stats.auto_kill.is_some() {
+                return None;
+            }
+            let trigger = stats.check_auto_kill(&risk_params)?;
+            stats.auto_kill = Some(trigger.clone());
+            trigger
+        };
+
+        self.kill_strategy_with_trigger(strategy_id, Some(&trigger))
+            .await
+            .ok()?;
+        Some(trigger)
+    }
+
+    /// Records a `position.closed` event for its strategy. Partial exits only count
+    /// once the position's final exit arrives, with their P&L summed into it.
+    pub async fn record_position_exit(
+        &self,
+        strategy_id: Uuid,
+        payload: &serde_json::Value,
+    ) -> Option<AutoKillTrigger> {
+        let pnl = self.closed_trades.write().await.record(payload)?;
+        self.record_trade_closed(strategy_id, pnl).await
+    }
+
+    /// Feeds `position.closed` events into auto-kill. A tripped strategy is persisted as
+    /// inactive and its pending approvals are cancelled, same as a manual kill.
+    pub fn start_auto_kill_loop(
+        self: &Arc<Self>,
+        strategy_repo: Arc<StrategyRepository>,
+        approval_manager: Arc<ApprovalManager>,
+    ) {
+        let mut event_rx = self.event_tx.subscribe();
+        let engine = Arc::clone(self);
+
+        tokio::spawn(async move {
+            loop {
+                let event = match event_rx.recv().await {
+                    Ok(event) => event,
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!(
+                            "Strategy auto-kill loop lagged, skipped {} events",
+                            skipped
+                        );
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+
+                if event.topic != position_topics::CLOSED {
+                    continue;
+                }
+                let Some(strategy_id) = event
+                    .payload
+                    .get("strategy_id")
+                    .and_then(|v| v.as_str())
+                    .and_then(|s| Uuid::parse_str(s).ok())
+                else {
+                    continue;
+                };
+
+                if let Some(trigger) = engine
+                    .record_position_exit(strategy_id, &event.payload)
+                    .await
+                {
+                    tracing::warn!(
+                        strategy_id = %strategy_id,
+                        "⛔ Strategy auto-killed: {}",
+                        trigger.describe()
+                    );
+                    if let Err(e) = approval_manager.cancel_by_strategy(strategy_id).await {
+                        tracing::warn!(
+                            strategy_id = %strategy_id,
+                            error = %e,
+                            "Failed to cancel pending approvals"
+                        );
+                    }
+                    if let Err(e) = strategy_repo.toggle(strategy_id, false).await {
+                        tracing::warn!(
+                            strategy_id = %strategy_id,
+                            error = %e,
+                            "Failed to persist auto-kill to database"
+                        );
+                    }
+                }
+            }
+        });
+    }
+
     pub async fn match_signal(&self, signal: &Signal) -> Option<MatchResult> {
         let strategies = self.strategies.read().await;
 
         for strategy in strategies.values() {
-            if !strategy.is_active {
-                continue;
-            }
+            let explanation = self.explain_match(signal, strategy);
 
-            if !self.signal_matches_strategy(signal, strategy) {
+            if !explanation.targets_signal() {
                 continue;
             }
 
-            let risk_params = self.get_risk_params(strategy);
-
-            if !self.check_risk_params(signal, &risk_params) {
+            if !explanation.passed() {
                 return Some(MatchResult {
                     signal_id: signal.id,
                     strategy_id: strategy.id,
                     execution_mode: strategy.execution_mode.clone(),
                     approved: false,
-                    reason: Some("Signal exceeds risk parameters".to_string()),
+                    reason: Some(format!(
+                        "Signal exceeds risk parameters: {}",
+                        explanation.summary()
+                    )),
                     created_edge: None,
+                    explanation,
                 });
             }
 
@@ -357,6 +722,7 @@ impl StrategyEngine {
                 approved: true,
                 reason: None,
                 created_edge: Some(edge),
+                explanation,
             });
         }
 
@@ -367,47 +733,153 @@ impl StrategyEngine {
         strategy.risk_params.clone()
     }
 
-    fn signal_matches_strategy(&self, signal: &Signal, strategy: &Strategy) -> bool {
+    /// Explains why each strategy did or didn't pick up a signal.
+    pub async fn explain_signal(&self, signal: &Signal) -> Vec<StrategyMatchExplanation> {
+        let strategies = self.strategies.read().await;
+        strategies
+            .values()
+            .map(|strategy| StrategyMatchExplanation {
+                strategy_id: strategy.id,
+                strategy_name: strategy.name.clone(),
+                explanation: self.explain_match(signal, strategy),
+            })
+            .collect()
+    }
+
+    /// Runs `signals` through a strategy as if it were active, without broadcasting
+    /// edges or marking signals processed. Works on inactive strategies too.
+    pub async fn preview_strategy(
+        &self,
+        strategy_id: Uuid,
+        signals: &[Signal],
+    ) -> AppResult<StrategyPreview> {
+        let strategy = self.get_strategy(strategy_id).await.ok_or_else(|| {
+            crate::error::AppError::NotFound(format!("Strategy {} not found", strategy_id))
+        })?;
+
+        let mut candidate = strategy.clone();
+        candidate.is_active = true;
+
+        let mut preview = StrategyPreview {
+            strategy_id,
+            strategy_name: strategy.name.clone(),
+            is_active: strategy.is_active,
+            signals_evaluated: signals.len(),
+            edges: Vec::new(),
+            rejected: Vec::new(),
+        };
+
+        for signal in signals {
+            let explanation = self.explain_match(signal, &candidate);
+            if !explanation.targets_signal() {
+                continue;
+            }
+
+            if explanation.passed() {
+                preview.edges.push(PreviewEdge {
+                    signal_id: signal.id,
+                    edge: self.create_edge_from_signal(signal, &candidate),
+                });
+            } else {
+                preview.rejected.push(PreviewRejection {
+                    signal_id: signal.id,
+                    reason: explanation.summary(),
+                    explanation,
+                });
+            }
+        }
+
+        Ok(preview)
+    }
+
+    pub fn explain_match(&self, signal: &Signal, strategy: &Strategy) -> MatchExplanation {
+        let mut explanation = MatchExplanation::default();
+
+        explanation.check(
+            MatchCriterion::StrategyActive,
+            strategy.is_active,
+            if strategy.is_active {
+                "strategy is active".to_string()
+            } else {
+                "strategy is inactive".to_string()
+            },
+        );
+
         let signal_venue_str = format!("{:?}", signal.venue_type).to_lowercase();
         let venue_matches = strategy.venue_types.iter().any(|vt| {
             vt.to_lowercase() == signal_venue_str || vt.to_lowercase().contains(&signal_venue_str)
         });
-
-        if !venue_matches {
-            return false;
-        }
+        explanation.check(
+            MatchCriterion::Venue,
+            venue_matches,
+            format!(
+                "signal venue {} vs strategy venues [{}]",
+                signal_venue_str,
+                strategy.venue_types.join(", ")
+            ),
+        );
 
         // For bonding curve strategies, differentiate scanner vs sniper by progress
-        let progress = signal
-            .metadata
-            .get("progress_percent")
-            .and_then(|v| v.as_f64())
-            .unwrap_or(0.0);
-
         match strategy.strategy_type.as_str() {
-            "graduation_snipe" => progress >= 85.0,
+            "graduation_snipe" => {
+                let progress = signal
+                    .metadata
+                    .get("progress_percent")
+                    .and_then(|v| v.as_f64())
+                    .unwrap_or(0.0);
+                explanation.check(
+                    MatchCriterion::GraduationProgress,
+                    progress >= GRADUATION_SNIPE_MIN_PROGRESS,
+                    format!(
+                        "progress {:.1}% (min {:.1}%)",
+                        progress, GRADUATION_SNIPE_MIN_PROGRESS
+                    ),
+                );
+            }
             "raydium_snipe" => {
                 let source = signal
                     .metadata
                     .get("signal_source")
                     .and_then(|v| v.as_str())
                     .unwrap_or("");
-                source == "raydium_snipe"
+                explanation.check(
+                    MatchCriterion::SignalSource,
+                    source == "raydium_snipe",
+                    format!("signal_source '{}' (requires 'raydium_snipe')", source),
+                );
             }
-            _ => true,
+            _ => {}
         }
-    }
 
-    fn check_risk_params(&self, signal: &Signal, risk_params: &RiskParams) -> bool {
-        if signal.estimated_profit_bps < risk_params.min_profit_bps as i32 {
-            return false;
-        }
+        let risk_params = self.get_risk_params(strategy);
+        self.check_risk_params(signal, &risk_params, &mut explanation);
 
-        if signal.confidence < 0.1 {
-            return false;
-        }
+        explanation
+    }
+
+    fn check_risk_params(
+        &self,
+        signal: &Signal,
+        risk_params: &RiskParams,
+        explanation: &mut MatchExplanation,
+    ) {
+        explanation.check(
+            MatchCriterion::MinProfit,
+            signal.estimated_profit_bps >= risk_params.min_profit_bps as i32,
+            format!(
+                "estimated profit {} bps (min {} bps)",
+                signal.estimated_profit_bps, risk_params.min_profit_bps
+            ),
+        );
 
-        true
+        explanation.check(
+            MatchCriterion::MinConfidence,
+            signal.confidence >= MIN_SIGNAL_CONFIDENCE,
+            format!(
+                "confidence {:.2} (min {:.2})",
+                signal.confidence, MIN_SIGNAL_CONFIDENCE
+            ),
+        );
     }
 
     fn create_edge_from_signal(&self, signal: &Signal, strategy: &Strategy) -> Edge {
@@ -497,24 +969,24 @@ impl StrategyEngine {
         let strategies = self.strategies.read().await;
 
         for strategy in strategies.values() {
-            if !strategy.is_active {
-                continue;
-            }
+            let explanation = self.explain_match(signal, strategy);
 
-            if !self.signal_matches_strategy(signal, strategy) {
+            if !explanation.targets_signal() {
                 continue;
             }
 
-            let risk_params = self.get_risk_params(strategy);
-
-            if !self.check_risk_params(signal, &risk_params) {
+            if !explanation.passed() {
                 return Some(MatchResult {
                     signal_id: signal.id,
                     strategy_id: strategy.id,
                     execution_mode: strategy.execution_mode.clone(),
                     approved: false,
-                    reason: Some("Signal exceeds risk parameters".to_string()),
+                    reason: Some(format!(
+                        "Signal exceeds risk parameters: {}",
+                        explanation.summary()
+                    )),
                     created_edge: None,
+                    explanation,
                 });
             }
 
@@ -572,6 +1044,7 @@ impl StrategyEngine {
                                     consensus_result.reasoning_summary
                                 )),
                                 created_edge: Some(edge),
+                                explanation,
                             });
                         }
 
@@ -617,6 +1090,7 @@ impl StrategyEngine {
                 approved: true,
                 reason: None,
                 created_edge: Some(edge),
+                explanation,
             });
         }
 
@@ -642,3 +1116,341 @@ impl StrategyEngine {
         results
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::Significance;
+    use crate::models::{SignalType, VenueType};
+
+    fn graduation_strategy(min_profit_bps: u16) -> Strategy {
+        Strategy {
+            id: Uuid::new_v4(),
+            wallet_address: "test_wallet".to_string(),
+            name: "Graduation Sniper".to_string(),
+            strategy_type: "graduation_snipe".to_string(),
+            venue_types: vec!["bondingcurve".to_string()],
+            execution_mode: "autonomous".to_string(),
+            risk_params: RiskParams {
+                min_profit_bps,
+                ..RiskParams::default()
+            },
+            is_active: true,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+            last_tested_at: None,
+            last_executed_at: None,
+            test_results: None,
+        }
+    }
+
+    fn curve_signal(progress: f64, profit_bps: i32) -> Signal {
+        Signal::new(
+            SignalType::CurveGraduation,
+            Uuid::new_v4(),
+            VenueType::BondingCurve,
+            Significance::High,
+        )
+        .with_profit(profit_bps, 0.8)
+        .with_metadata(serde_json::json!({ "progress_percent": progress }))
+    }
+
+    #[tokio::test]
+    async fn test_near_miss_progress_is_explained() {
+        let (tx, _rx) = broadcast::channel(16);
+        let engine = StrategyEngine::new(tx);
+        let strategy = graduation_strategy(50);
+        let strategy_id = strategy.id;
+        engine.add_strategy(strategy).await;
+
+        let signal = curve_signal(82.5, 120);
+        assert!(engine.match_signal(&signal).await.is_none());
+
+        let explanations = engine.explain_signal(&signal).await;
+        assert_eq!(explanations.len(), 1);
+        let explanation = &explanations[0].explanation;
+        assert_eq!(explanations[0].strategy_id, strategy_id);
+        assert_eq!(
+            explanation.failed_criteria(),
+            vec![MatchCriterion::GraduationProgress]
+        );
+        assert!(explanation.summary().contains("progress 82.5% (min 85.0%)"));
+        assert!(explanation
+            .checks
+            .iter()
+            .filter(|c| c.criterion != MatchCriterion::GraduationProgress)
+            .all(|c| c.passed));
+    }
+
+    #[tokio::test]
+    async fn test_risk_rejection_names_failing_threshold() {
+        let (tx, _rx) = broadcast::channel(16);
+        let engine = StrategyEngine::new(tx);
+        engine.add_strategy(graduation_strategy(150)).await;
+
+        let result = engine.match_signal(&curve_signal(92.0, 140)).await.unwrap();
+
+        assert!(!result.approved);
+        assert!(result.created_edge.is_none());
+        assert_eq!(
+            result.explanation.failed_criteria(),
+            vec![MatchCriterion::MinProfit]
+        );
+        assert!(result
+            .reason
+            .unwrap()
+            .contains("estimated profit 140 bps (min 150 bps)"));
+    }
+
+    #[tokio::test]
+    async fn test_preview_inactive_strategy_without_side_effects() {
+        let (tx, mut rx) = broadcast::channel(16);
+        let engine = StrategyEngine::new(tx);
+        let mut strategy = graduation_strategy(100);
+        strategy.is_active = false;
+        let strategy_id = strategy.id;
+        engine.add_strategy(strategy).await;
+        while rx.try_recv().is_ok() {}
+
+        let qualifying = curve_signal(92.0, 150);
+        let near_miss = curve_signal(95.0, 80);
+        let untargeted = curve_signal(40.0, 300);
+        let history = vec![qualifying.clone(), near_miss.clone(), untargeted];
+
+        let preview = engine
+            .preview_strategy(strategy_id, &history)
+            .await
+            .unwrap();
+
+        assert!(!preview.is_active);
+        assert_eq!(preview.signals_evaluated, 3);
+        assert_eq!(preview.edges.len(), 1);
+        assert_eq!(preview.edges[0].signal_id, qualifying.id);
+        assert_eq!(preview.edges[0].edge.strategy_id, Some(strategy_id));
+        assert_eq!(preview.rejected.len(), 1);
+        assert_eq!(preview.rejected[0].signal_id, near_miss.id);
+        assert_eq!(
+            preview.rejected[0].explanation.failed_criteria(),
+            vec![MatchCriterion::MinProfit]
+        );
+
+        assert!(!engine.get_strategy(strategy_id).await.unwrap().is_active);
+        assert!(rx.try_recv().is_err());
+
+        // Preview must not mark signals as processed.
+        engine.toggle_strategy(strategy_id, true).await.unwrap();
+        let results = engine.process_signals(vec![qualifying]).await;
+        assert_eq!(results.len(), 1);
+        assert!(results[0].approved);
+    }
+
+    #[tokio::test]
+    async fn test_preview_unknown_strategy_is_not_found() {
+        let (tx, _rx) = broadcast::channel(16);
+        let engine = StrategyEngine::new(tx);
+        let result = engine.preview_strategy(Uuid::new_v4(), &[]).await;
+        assert!(matches!(result, Err(crate::error::AppError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_consecutive_losses_auto_kill_blocks_signals() {
+        let (tx, mut rx) = broadcast::channel(16);
+        let engine = StrategyEngine::new(tx);
+        let mut strategy = graduation_strategy(50);
+        strategy.risk_params.auto_kill_consecutive_losses = Some(3);
+        let strategy_id = strategy.id;
+        engine.add_strategy(strategy).await;
+        while rx.try_recv().is_ok() {}
+
+        // A win in between resets the streak
+        assert!(engine
+            .record_trade_closed(strategy_id, -0.02)
+            .await
+            .is_none());
+        assert!(engine
+            .record_trade_closed(strategy_id, -0.02)
+            .await
+            .is_none());
+        assert!(engine
+            .record_trade_closed(strategy_id, 0.01)
+            .await
+            .is_none());
+        assert!(engine
+            .record_trade_closed(strategy_id, -0.02)
+            .await
+            .is_none());
+        assert!(engine
+            .record_trade_closed(strategy_id, -0.02)
+            .await
+            .is_none());
+        assert!(engine
+            .match_signal(&curve_signal(92.0, 120))
+            .await
+            .is_some());
+
+        let trigger = engine.record_trade_closed(strategy_id, -0.02).await;
+        assert_eq!(
+            trigger,
+            Some(AutoKillTrigger::ConsecutiveLosses {
+                losses: 3,
+                limit: 3
+            })
+        );
+        assert!(!engine.get_strategy(strategy_id).await.unwrap().is_active);
+
+        let event = rx.try_recv().unwrap();
+        assert_eq!(event.topic, strategy_topics::DISABLED);
+        assert_eq!(event.payload["reason"], "auto_kill");
+        assert_eq!(event.payload["trigger"]["condition"], "consecutive_losses");
+
+        // Killed strategies no longer pick up signals, and further losses don't re-fire
+        assert!(engine
+            .match_signal(&curve_signal(92.0, 120))
+            .await
+            .is_none());
+        assert!(engine
+            .record_trade_closed(strategy_id, -0.02)
+            .await
+            .is_none());
+        assert!(rx.try_recv().is_err());
+
+        // Re-enabling starts the streak over
+        engine.toggle_strategy(strategy_id, true).await.unwrap();
+        assert!(engine
+            .record_trade_closed(strategy_id, -0.02)
+            .await
+            .is_none());
+        assert_eq!(
+            engine
+                .trade_stats(strategy_id)
+                .await
+                .unwrap()
+                .consecutive_losses,
+            1
+        );
+    }
+
+    fn exit_payload(position_id: Uuid, pnl: f64, exit_percent: f64) -> serde_json::Value {
+        serde_json::json!({
+            "position_id": position_id,
+            "realized_pnl_sol": pnl,
+            "exit_percent": exit_percent,
+            "is_final": exit_percent >= 100.0,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_partial_take_profit_then_stop_loss_counts_one_trade() {
+        let (tx, _rx) = broadcast::channel(16);
+        let engine = StrategyEngine::new(tx);
+        let mut strategy = graduation_strategy(50);
+        strategy.risk_params.auto_kill_consecutive_losses = Some(2);
+        let strategy_id = strategy.id;
+        engine.add_strategy(strategy).await;
+
+        // Take 50% at +0.05, then the rest stops out at -0.02: one winning trade
+        let position_id = Uuid::new_v4();
+        assert!(engine
+            .record_position_exit(strategy_id, &exit_payload(position_id, 0.05, 50.0))
+            .await
+            .is_none());
+        assert!(engine.trade_stats(strategy_id).await.is_none());
+        assert!(engine
+            .record_position_exit(strategy_id, &exit_payload(position_id, -0.02, 100.0))
+            .await
+            .is_none());
+
+        let stats = engine.trade_stats(strategy_id).await.unwrap();
+        assert_eq!(stats.trades_closed, 1);
+        assert_eq!(stats.consecutive_losses, 0);
+        assert!((stats.realized_pnl_sol - 0.03).abs() < 1e-9);
+
+        // Two straight losing positions still trip the limit
+        for _ in 0..2 {
+            let position_id = Uuid::new_v4();
+            assert!(engine
+                .record_position_exit(strategy_id, &exit_payload(position_id, -0.01, 25.0))
+                .await
+                .is_none());
+            let trigger = engine
+                .record_position_exit(strategy_id, &exit_payload(position_id, -0.02, 100.0))
+                .await;
+            let stats = engine.trade_stats(strategy_id).await.unwrap();
+            if stats.trades_closed == 3 {
+                assert_eq!(
+                    trigger,
+                    Some(AutoKillTrigger::ConsecutiveLosses {
+                        losses: 2,
+                        limit: 2
+                    })
+                );
+            } else {
+                assert!(trigger.is_none());
+            }
+        }
+        let stats = engine.trade_stats(strategy_id).await.unwrap();
+        assert_eq!(stats.trades_closed, 3);
+        assert!((stats.realized_pnl_sol + 0.03).abs() < 1e-9);
+        assert!(!engine.get_strategy(strategy_id).await.unwrap().is_active);
+    }
+
+    #[tokio::test]
+    async fn test_max_drawdown_auto_kill_measures_from_peak() {
+        let (tx, _rx) = broadcast::channel(16);
+        let engine = StrategyEngine::new(tx);
+        let mut strategy = graduation_strategy(50);
+        strategy.risk_params.auto_kill_max_drawdown_sol = Some(0.5);
+        let strategy_id = strategy.id;
+        engine.add_strategy(strategy).await;
+
+        assert!(engine.record_trade_closed(strategy_id, 0.4).await.is_none());
+        assert!(engine
+            .record_trade_closed(strategy_id, -0.3)
+            .await
+            .is_none());
+        assert!(engine.record_trade_closed(strategy_id, 0.1).await.is_none());
+
+        let trigger = engine.record_trade_closed(strategy_id, -0.5).await.unwrap();
+        match trigger {
+            AutoKillTrigger::MaxDrawdown {
+                drawdown_sol,
+                limit_sol,
+            } => {
+                assert!((drawdown_sol - 0.7).abs() < 1e-9);
+                assert_eq!(limit_sol, 0.5);
+            }
+            other => panic!("unexpected trigger {:?}", other),
+        }
+        assert!(!engine.get_strategy(strategy_id).await.unwrap().is_active);
+    }
+
+    #[tokio::test]
+    async fn test_strategy_without_conditions_is_never_auto_killed() {
+        let (tx, _rx) = broadcast::channel(16);
+        let engine = StrategyEngine::new(tx);
+        let strategy = graduation_strategy(50);
+        let strategy_id = strategy.id;
+        engine.add_strategy(strategy).await;
+
+        for _ in 0..20 {
+            assert!(engine
+                .record_trade_closed(strategy_id, -1.0)
+                .await
+                .is_none());
+        }
+        assert!(engine.get_strategy(strategy_id).await.unwrap().is_active);
+    }
+
+    #[tokio::test]
+    async fn test_approved_match_carries_passing_explanation() {
+        let (tx, _rx) = broadcast::channel(16);
+        let engine = StrategyEngine::new(tx);
+        engine.add_strategy(graduation_strategy(50)).await;
+
+        let result = engine.match_signal(&curve_signal(92.0, 120)).await.unwrap();
+
+        assert!(result.approved);
+        assert!(result.explanation.passed());
+        assert_eq!(result.explanation.summary(), "all criteria passed");
+    }
+}
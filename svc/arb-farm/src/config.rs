@@ -1,5 +1,9 @@
 use std::env;
 
+use crate::webhooks::helius::{
+    parse_transaction_types, TransactionType, DEFAULT_WEBHOOK_TRANSACTION_TYPES,
+};
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub service_name: String,
@@ -22,6 +26,8 @@ pub struct Config {
     pub helius_api_url: String,
     pub helius_api_key: Option<String>,
     pub helius_webhook_auth_token: Option<String>,
+    /// `None` when `HELIUS_WEBHOOK_TRANSACTION_TYPES` is unset or has no valid names.
+    pub helius_webhook_transaction_types: Option<Vec<TransactionType>>,
     pub helius_sender_url: String,
     pub helius_laserstream_url: String,
     pub birdeye_api_url: String,
@@ -108,6 +114,9 @@ impl Config {
                 .unwrap_or_else(|_| "https://mainnet.helius-rpc.com".to_string()),
             helius_api_key: env::var("HELIUS_API_KEY").ok(),
             helius_webhook_auth_token: env::var("HELIUS_WEBHOOK_AUTH_TOKEN").ok(),
+            helius_webhook_transaction_types: parse_transaction_types(
+                &env::var("HELIUS_WEBHOOK_TRANSACTION_TYPES").unwrap_or_default(),
+            ),
             helius_sender_url: env::var("HELIUS_SENDER_URL")
                 .unwrap_or_else(|_| "https://mainnet.helius-rpc.com".to_string()),
             helius_laserstream_url: env::var("HELIUS_LASERSTREAM_URL")
@@ -186,6 +195,13 @@ impl Config {
         })
     }
 
+    /// Transaction types for KOL webhooks and for filtering deliveries.
+    pub fn webhook_transaction_types(&self) -> Vec<TransactionType> {
+        self.helius_webhook_transaction_types
+            .clone()
+            .unwrap_or_else(|| DEFAULT_WEBHOOK_TRANSACTION_TYPES.to_vec())
+    }
+
     pub fn validate(&self) -> Result<(), Vec<String>> {
         let mut errors = Vec::new();
 
@@ -2,7 +2,13 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+use crate::engrams::schemas::Recommendation;
+
 pub const DEV_WALLET: &str = "YOUR_DEV_WALLET_PUBKEY";
+pub const DEFAULT_MIN_ACTION_CONFIDENCE: f64 = 0.7;
+pub const DEFAULT_MIN_TRADES_FOR_ANALYSIS: u32 = 10;
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: u32 = 1;
+pub const DEFAULT_MAX_MANUAL_REQUESTS_PER_HOUR: u32 = 10;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConsensusConfig {
@@ -12,11 +18,39 @@ pub struct ConsensusConfig {
     pub models: Vec<ConsensusModelConfig>,
     pub min_consensus_threshold: f64,
     pub auto_apply_recommendations: bool,
+    /// Recommendations below this confidence are informational only and can't be applied
+    #[serde(default = "default_min_action_confidence")]
+    pub min_action_confidence: f64,
+    /// Scheduled analysis is skipped until at least this many trades have closed
+    #[serde(default = "default_min_trades_for_analysis")]
+    pub min_trades_for_analysis: u32,
+    /// Analyses allowed to run at once, manual and scheduled combined
+    #[serde(default = "default_max_concurrent_requests")]
+    pub max_concurrent_requests: u32,
+    /// Manual `/consensus/request` calls allowed in any rolling hour
+    #[serde(default = "default_max_manual_requests_per_hour")]
+    pub max_manual_requests_per_hour: u32,
     pub review_interval_hours: u32,
     pub max_tokens_per_request: u32,
     pub timeout_ms: u64,
 }
 
+fn default_min_action_confidence() -> f64 {
+    DEFAULT_MIN_ACTION_CONFIDENCE
+}
+
+fn default_min_trades_for_analysis() -> u32 {
+    DEFAULT_MIN_TRADES_FOR_ANALYSIS
+}
+
+fn default_max_concurrent_requests() -> u32 {
+    DEFAULT_MAX_CONCURRENT_REQUESTS
+}
+
+fn default_max_manual_requests_per_hour() -> u32 {
+    DEFAULT_MAX_MANUAL_REQUESTS_PER_HOUR
+}
+
 impl Default for ConsensusConfig {
     fn default() -> Self {
         Self {
@@ -26,6 +60,10 @@ impl Default for ConsensusConfig {
             models: get_standard_models(),
             min_consensus_threshold: 0.6,
             auto_apply_recommendations: false,
+            min_action_confidence: DEFAULT_MIN_ACTION_CONFIDENCE,
+            min_trades_for_analysis: DEFAULT_MIN_TRADES_FOR_ANALYSIS,
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+            max_manual_requests_per_hour: DEFAULT_MAX_MANUAL_REQUESTS_PER_HOUR,
             review_interval_hours: 1,
             max_tokens_per_request: 2048,
             timeout_ms: 30000,
@@ -33,6 +71,41 @@ impl Default for ConsensusConfig {
     }
 }
 
+impl ConsensusConfig {
+    pub fn meets_action_floor(&self, confidence: f64) -> bool {
+        confidence >= self.min_action_confidence
+    }
+
+    /// Why a recommendation can't be applied, if it is informational only. One stored as
+    /// below the floor stays informational even if the floor is lowered later.
+    pub fn action_floor_rejection(&self, recommendation: &Recommendation) -> Option<String> {
+        if recommendation.below_confidence_floor
+            || !self.meets_action_floor(recommendation.confidence)
+        {
+            Some(format!(
+                "Recommendation confidence {:.2} is below the action floor {:.2}; it is informational only and can't be applied automatically",
+                recommendation.confidence, self.min_action_confidence
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Why a scheduled analysis cycle should not run on this much history, if it shouldn't.
+    pub fn scheduled_analysis_skip_reason(&self, total_trades: u32) -> Option<String> {
+        if total_trades == 0 {
+            Some("No trades yet".to_string())
+        } else if total_trades < self.min_trades_for_analysis {
+            Some(format!(
+                "Only {} trades closed, need {} for a meaningful analysis",
+                total_trades, self.min_trades_for_analysis
+            ))
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConsensusModelConfig {
     pub model_id: String,
@@ -108,6 +181,31 @@ pub fn get_models_for_wallet(wallet: &str) -> Vec<ConsensusModelConfig> {
     }
 }
 
+/// Models in the dev lineup that standard wallets don't get.
+pub fn dev_only_model_ids() -> Vec<String> {
+    let standard: Vec<String> = get_standard_models()
+        .into_iter()
+        .map(|m| m.model_id)
+        .collect();
+    get_dev_wallet_models()
+        .into_iter()
+        .map(|m| m.model_id)
+        .filter(|id| !standard.contains(id))
+        .collect()
+}
+
+pub fn is_model_allowed_for_wallet(wallet: &str, model_id: &str) -> bool {
+    is_dev_wallet(wallet) || !dev_only_model_ids().iter().any(|id| id == model_id)
+}
+
+/// Splits `models` into the ones `wallet` may query and the ones it may not.
+pub fn partition_models_for_wallet(wallet: &str, models: &[String]) -> (Vec<String>, Vec<String>) {
+    models
+        .iter()
+        .cloned()
+        .partition(|model| is_model_allowed_for_wallet(wallet, model))
+}
+
 pub fn get_enabled_model_ids(config: &ConsensusConfig) -> Vec<String> {
     config
         .models
@@ -208,6 +306,10 @@ pub struct UpdateConsensusConfigRequest {
     pub models: Option<Vec<ConsensusModelConfig>>,
     pub min_consensus_threshold: Option<f64>,
     pub auto_apply_recommendations: Option<bool>,
+    pub min_action_confidence: Option<f64>,
+    pub min_trades_for_analysis: Option<u32>,
+    pub max_concurrent_requests: Option<u32>,
+    pub max_manual_requests_per_hour: Option<u32>,
     pub review_interval_hours: Option<u32>,
 }
 
@@ -232,3 +334,142 @@ pub fn get_all_available_models() -> Vec<ConsensusModelConfig> {
         .map(|(id, name, weight)| ConsensusModelConfig::new(*id, *weight).with_display_name(*name))
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engrams::schemas::{
+        RecommendationCategory, RecommendationSource, RecommendationStatus, SuggestedAction,
+        SuggestedActionType, SupportingData,
+    };
+
+    fn recommendation(confidence: f64, config: &ConsensusConfig) -> Recommendation {
+        Recommendation {
+            recommendation_id: uuid::Uuid::new_v4(),
+            source: RecommendationSource::ConsensusLlm,
+            category: RecommendationCategory::Risk,
+            title: "Tighten stop loss".to_string(),
+            description: "Stop losses are hit late".to_string(),
+            suggested_action: SuggestedAction {
+                action_type: SuggestedActionType::RiskAdjustment,
+                target: "risk.max_drawdown_percent".to_string(),
+                current_value: Some(serde_json::json!(15.0)),
+                suggested_value: serde_json::json!(10.0),
+                reasoning: "Losses run past the stop".to_string(),
+            },
+            confidence,
+            supporting_data: SupportingData {
+                trades_analyzed: 40,
+                time_period: "Last 7 days".to_string(),
+                relevant_engrams: Vec::new(),
+                metrics: None,
+            },
+            status: RecommendationStatus::Pending,
+            created_at: chrono::Utc::now(),
+            applied_at: None,
+            below_confidence_floor: !config.meets_action_floor(confidence),
+            models: Vec::new(),
+            outcome: None,
+        }
+    }
+
+    #[test]
+    fn test_dev_only_models_restricted_to_dev_wallet() {
+        let dev_only = dev_only_model_ids();
+        assert!(dev_only.contains(&"anthropic/claude-3-opus".to_string()));
+        assert!(!dev_only.contains(&"openai/gpt-4-turbo".to_string()));
+
+        let requested = vec![
+            "anthropic/claude-3-opus".to_string(),
+            "anthropic/claude-3.5-sonnet".to_string(),
+        ];
+        let (allowed, denied) = partition_models_for_wallet("StandardWallet1111", &requested);
+        assert_eq!(allowed, vec!["anthropic/claude-3.5-sonnet".to_string()]);
+        assert_eq!(denied, vec!["anthropic/claude-3-opus".to_string()]);
+
+        let (allowed, denied) = partition_models_for_wallet(DEV_WALLET, &requested);
+        assert_eq!(allowed, requested);
+        assert!(denied.is_empty());
+    }
+
+    #[test]
+    fn test_action_floor() {
+        let config = ConsensusConfig {
+            min_action_confidence: 0.7,
+            ..Default::default()
+        };
+        assert!(!config.meets_action_floor(0.55));
+        assert!(config.meets_action_floor(0.7));
+        assert!(config.meets_action_floor(0.85));
+    }
+
+    #[test]
+    fn test_below_floor_recommendation_cannot_be_applied() {
+        let config = ConsensusConfig {
+            min_action_confidence: 0.7,
+            ..Default::default()
+        };
+
+        let weak = recommendation(0.55, &config);
+        assert!(weak.below_confidence_floor);
+        let reason = config.action_floor_rejection(&weak).unwrap();
+        assert!(reason.contains("0.55"));
+        assert!(reason.contains("informational only"));
+
+        // Stored as informational, it stays so even if the floor is lowered later
+        let lowered = ConsensusConfig {
+            min_action_confidence: 0.5,
+            ..config.clone()
+        };
+        assert!(lowered.action_floor_rejection(&weak).is_some());
+
+        let strong = recommendation(0.85, &config);
+        assert!(!strong.below_confidence_floor);
+        assert!(config.action_floor_rejection(&strong).is_none());
+
+        // Raising the floor above a stored recommendation blocks it too
+        let raised = ConsensusConfig {
+            min_action_confidence: 0.9,
+            ..config
+        };
+        assert!(raised.action_floor_rejection(&strong).is_some());
+    }
+
+    #[test]
+    fn test_scheduled_analysis_skipped_below_min_trades() {
+        let config = ConsensusConfig {
+            min_trades_for_analysis: 10,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.scheduled_analysis_skip_reason(0).as_deref(),
+            Some("No trades yet")
+        );
+        let reason = config.scheduled_analysis_skip_reason(9).unwrap();
+        assert!(reason.contains("Only 9 trades"));
+        assert!(reason.contains("need 10"));
+    }
+
+    #[test]
+    fn test_scheduled_analysis_runs_at_or_above_min_trades() {
+        let config = ConsensusConfig {
+            min_trades_for_analysis: 10,
+            ..Default::default()
+        };
+        assert!(config.scheduled_analysis_skip_reason(10).is_none());
+        assert!(config.scheduled_analysis_skip_reason(250).is_none());
+
+        // Older saved configs without the field fall back to the default threshold
+        let mut legacy = serde_json::to_value(ConsensusConfig::default()).unwrap();
+        legacy
+            .as_object_mut()
+            .unwrap()
+            .remove("min_trades_for_analysis");
+        let legacy: ConsensusConfig = serde_json::from_value(legacy).unwrap();
+        assert_eq!(
+            legacy.min_trades_for_analysis,
+            DEFAULT_MIN_TRADES_FOR_ANALYSIS
+        );
+    }
+}
@@ -0,0 +1,478 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::engrams::schemas::{SuggestedAction, SuggestedActionType};
+use crate::error::{AppError, AppResult};
+use crate::execution::risk::{RiskConfig, MAX_REENTRY_COOLDOWN_SECS};
+
+/// A config value tagged with its type, so a recommended change can be checked and
+/// applied without guessing from free-form JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum ConfigValue {
+    Float(f64),
+    Integer(u64),
+    Bool(bool),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigValueKind {
+    Float,
+    Integer,
+    Bool,
+}
+
+impl ConfigValueKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Float => "float",
+            Self::Integer => "integer",
+            Self::Bool => "bool",
+        }
+    }
+}
+
+impl ConfigValue {
+    pub fn kind(&self) -> ConfigValueKind {
+        match self {
+            Self::Float(_) => ConfigValueKind::Float,
+            Self::Integer(_) => ConfigValueKind::Integer,
+            Self::Bool(_) => ConfigValueKind::Bool,
+        }
+    }
+
+    /// Reads `value` as `kind`. Model output often quotes numbers and booleans, so
+    /// strings are parsed too. Integers reject fractions and negatives.
+    pub fn from_json(value: &Value, kind: ConfigValueKind) -> Option<Self> {
+        let value = match value {
+            Value::String(s) => serde_json::from_str::<Value>(s.trim()).ok()?,
+            other => other.clone(),
+        };
+        match kind {
+            ConfigValueKind::Float => value.as_f64().filter(|v| v.is_finite()).map(Self::Float),
+            ConfigValueKind::Integer => value
+                .as_u64()
+                .or_else(|| {
+                    value
+                        .as_f64()
+                        .filter(|v| *v >= 0.0 && v.fract() == 0.0 && *v < u64::MAX as f64)
+                        .map(|v| v as u64)
+                })
+                .map(Self::Integer),
+            ConfigValueKind::Bool => value.as_bool().map(Self::Bool),
+        }
+    }
+}
+
+impl ConfigValue {
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Float(v) => Some(*v),
+            Self::Integer(v) => Some(*v as f64),
+            Self::Bool(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Float(v) => write!(f, "{}", v),
+            Self::Integer(v) => write!(f, "{}", v),
+            Self::Bool(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskConfigField {
+    MaxPositionSol,
+    DailyLossLimitSol,
+    MaxDrawdownPercent,
+    MaxConcurrentPositions,
+    MaxPositionPerTokenSol,
+    CooldownAfterLossMs,
+    VolatilityScalingEnabled,
+    AutoPauseOnDrawdown,
+    TakeProfitPercent,
+    TrailingStopPercent,
+    TimeLimitMinutes,
+    ReentryCooldownSecs,
+}
+
+/// The fields a recommendation may change and the range it may set each one to.
+/// `volatility_scaling_enabled` and `auto_pause_on_drawdown` are safety toggles and are
+/// left out on purpose: only an operator turns them off.
+const RECOMMENDABLE_FIELDS: [(RiskConfigField, f64, f64); 10] = [
+    (RiskConfigField::MaxPositionSol, 0.01, 10.0),
+    (RiskConfigField::DailyLossLimitSol, 0.01, 10.0),
+    (RiskConfigField::MaxDrawdownPercent, 1.0, 50.0),
+    (RiskConfigField::MaxConcurrentPositions, 1.0, 20.0),
+    (RiskConfigField::MaxPositionPerTokenSol, 0.01, 10.0),
+    (RiskConfigField::CooldownAfterLossMs, 0.0, 3_600_000.0),
+    (RiskConfigField::TakeProfitPercent, 1.0, 500.0),
+    (RiskConfigField::TrailingStopPercent, 1.0, 50.0),
+    (RiskConfigField::TimeLimitMinutes, 1.0, 240.0),
+    (
+        RiskConfigField::ReentryCooldownSecs,
+        0.0,
+        MAX_REENTRY_COOLDOWN_SECS as f64,
+    ),
+];
+
+impl RiskConfigField {
+    pub const ALL: [Self; 12] = [
+        Self::MaxPositionSol,
+        Self::DailyLossLimitSol,
+        Self::MaxDrawdownPercent,
+        Self::MaxConcurrentPositions,
+        Self::MaxPositionPerTokenSol,
+        Self::CooldownAfterLossMs,
+        Self::VolatilityScalingEnabled,
+        Self::AutoPauseOnDrawdown,
+        Self::TakeProfitPercent,
+        Self::TrailingStopPercent,
+        Self::TimeLimitMinutes,
+        Self::ReentryCooldownSecs,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MaxPositionSol => "max_position_sol",
+            Self::DailyLossLimitSol => "daily_loss_limit_sol",
+            Self::MaxDrawdownPercent => "max_drawdown_percent",
+            Self::MaxConcurrentPositions => "max_concurrent_positions",
+            Self::MaxPositionPerTokenSol => "max_position_per_token_sol",
+            Self::CooldownAfterLossMs => "cooldown_after_loss_ms",
+            Self::VolatilityScalingEnabled => "volatility_scaling_enabled",
+            Self::AutoPauseOnDrawdown => "auto_pause_on_drawdown",
+            Self::TakeProfitPercent => "take_profit_percent",
+            Self::TrailingStopPercent => "trailing_stop_percent",
+            Self::TimeLimitMinutes => "time_limit_minutes",
+            Self::ReentryCooldownSecs => "reentry_cooldown_secs",
+        }
+    }
+
+    /// Accepts the bare field name or the `risk.`-prefixed form recommendations use.
+    pub fn parse(target: &str) -> Option<Self> {
+        let target = target.trim();
+        let name = target.strip_prefix("risk.").unwrap_or(target);
+        Self::ALL.into_iter().find(|f| f.as_str() == name)
+    }
+
+    pub fn kind(&self) -> ConfigValueKind {
+        match self {
+            Self::MaxPositionSol
+            | Self::DailyLossLimitSol
+            | Self::MaxDrawdownPercent
+            | Self::MaxPositionPerTokenSol
+            | Self::TakeProfitPercent
+            | Self::TrailingStopPercent => ConfigValueKind::Float,
+            Self::MaxConcurrentPositions
+            | Self::CooldownAfterLossMs
+            | Self::TimeLimitMinutes
+            | Self::ReentryCooldownSecs => ConfigValueKind::Integer,
+            Self::VolatilityScalingEnabled | Self::AutoPauseOnDrawdown => ConfigValueKind::Bool,
+        }
+    }
+
+    /// The range a recommendation may set this field to; `None` if only an operator
+    /// may change it.
+    pub fn recommendation_bounds(&self) -> Option<(f64, f64)> {
+        RECOMMENDABLE_FIELDS
+            .iter()
+            .find(|(field, _, _)| field == self)
+            .map(|(_, min, max)| (*min, *max))
+    }
+
+    pub fn read(&self, config: &RiskConfig) -> ConfigValue {
+        match self {
+            Self::MaxPositionSol => ConfigValue::Float(config.max_position_sol),
+            Self::DailyLossLimitSol => ConfigValue::Float(config.daily_loss_limit_sol),
+            Self::MaxDrawdownPercent => ConfigValue::Float(config.max_drawdown_percent),
+            Self::MaxConcurrentPositions => {
+                ConfigValue::Integer(config.max_concurrent_positions as u64)
+            }
+            Self::MaxPositionPerTokenSol => ConfigValue::Float(config.max_position_per_token_sol),
+            Self::CooldownAfterLossMs => ConfigValue::Integer(config.cooldown_after_loss_ms),
+            Self::VolatilityScalingEnabled => ConfigValue::Bool(config.volatility_scaling_enabled),
+            Self::AutoPauseOnDrawdown => ConfigValue::Bool(config.auto_pause_on_drawdown),
+            Self::TakeProfitPercent => ConfigValue::Float(config.take_profit_percent),
+            Self::TrailingStopPercent => ConfigValue::Float(config.trailing_stop_percent),
+            Self::TimeLimitMinutes => ConfigValue::Integer(config.time_limit_minutes as u64),
+            Self::ReentryCooldownSecs => ConfigValue::Integer(config.reentry_cooldown_secs),
+        }
+    }
+
+    pub fn write(&self, config: &mut RiskConfig, value: ConfigValue) -> AppResult<()> {
+        let mismatch = || {
+            AppError::Validation(format!(
+                "{} expects a {} value, got {}",
+                self.as_str(),
+                self.kind().as_str(),
+                value.kind().as_str()
+            ))
+        };
+        let narrow = |v: u64| {
+            u32::try_from(v).map_err(|_| {
+                AppError::Validation(format!("{} is out of range for {}", v, self.as_str()))
+            })
+        };
+
+        match (self, value) {
+            (Self::MaxPositionSol, ConfigValue::Float(v)) => config.max_position_sol = v,
+            (Self::DailyLossLimitSol, ConfigValue::Float(v)) => config.daily_loss_limit_sol = v,
+            (Self::MaxDrawdownPercent, ConfigValue::Float(v)) => config.max_drawdown_percent = v,
+            (Self::MaxConcurrentPositions, ConfigValue::Integer(v)) => {
+                config.max_concurrent_positions = narrow(v)?
+            }
+            (Self::MaxPositionPerTokenSol, ConfigValue::Float(v)) => {
+                config.max_position_per_token_sol = v
+            }
+            (Self::CooldownAfterLossMs, ConfigValue::Integer(v)) => {
+                config.cooldown_after_loss_ms = v
+            }
+            (Self::VolatilityScalingEnabled, ConfigValue::Bool(v)) => {
+                config.volatility_scaling_enabled = v
+            }
+            (Self::AutoPauseOnDrawdown, ConfigValue::Bool(v)) => config.auto_pause_on_drawdown = v,
+            (Self::TakeProfitPercent, ConfigValue::Float(v)) => config.take_profit_percent = v,
+            (Self::TrailingStopPercent, ConfigValue::Float(v)) => config.trailing_stop_percent = v,
+            (Self::TimeLimitMinutes, ConfigValue::Integer(v)) => {
+                config.time_limit_minutes = narrow(v)?
+            }
+            (Self::ReentryCooldownSecs, ConfigValue::Integer(v)) => {
+                config.reentry_cooldown_secs = v
+            }
+            _ => return Err(mismatch()),
+        }
+        Ok(())
+    }
+}
+
+/// Typed change a `RiskAdjustment` recommendation makes to the live risk config.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RiskConfigDiff {
+    pub field: RiskConfigField,
+    /// Live value at the time the diff was taken.
+    pub from: ConfigValue,
+    pub to: ConfigValue,
+    /// What the recommendation believed the value was, when it said.
+    pub expected_from: Option<ConfigValue>,
+}
+
+impl RiskConfigDiff {
+    pub fn is_noop(&self) -> bool {
+        self.from == self.to
+    }
+
+    /// The live value no longer matches what the recommendation was based on.
+    pub fn is_stale(&self) -> bool {
+        self.expected_from
+            .is_some_and(|expected| expected != self.from)
+    }
+
+    pub fn apply(&self, config: &mut RiskConfig) -> AppResult<()> {
+        self.field.write(config, self.to)
+    }
+}
+
+impl std::fmt::Display for RiskConfigDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "risk.{}: {} → {}",
+            self.field.as_str(),
+            self.from,
+            self.to
+        )
+    }
+}
+
+/// Resolves a `RiskAdjustment` recommendation to the config field it targets and the
+/// typed from→to change against `config`.
+pub fn diff_risk_adjustment(
+    action: &SuggestedAction,
+    config: &RiskConfig,
+) -> AppResult<RiskConfigDiff> {
+    if !matches!(action.action_type, SuggestedActionType::RiskAdjustment) {
+        return Err(AppError::Validation(format!(
+            "{:?} is not a risk adjustment",
+            action.action_type
+        )));
+    }
+
+    let field = RiskConfigField::parse(&action.target).ok_or_else(|| {
+        AppError::Validation(format!("Unknown risk adjustment target: {}", action.target))
+    })?;
+    let (min, max) = field.recommendation_bounds().ok_or_else(|| {
+        AppError::Validation(format!(
+            "{} is a safety setting and can only be changed manually",
+            field.as_str()
+        ))
+    })?;
+    let kind = field.kind();
+    let to = ConfigValue::from_json(&action.suggested_value, kind).ok_or_else(|| {
+        AppError::Validation(format!(
+            "{} expects a {} value, got {}",
+            field.as_str(),
+            kind.as_str(),
+            action.suggested_value
+        ))
+    })?;
+    if to.as_f64().is_some_and(|v| v < min || v > max) {
+        return Err(AppError::Validation(format!(
+            "{} must be between {} and {}, got {}",
+            field.as_str(),
+            min,
+            max,
+            to
+        )));
+    }
+    let expected_from = action
+        .current_value
+        .as_ref()
+        .and_then(|v| ConfigValue::from_json(v, kind));
+
+    Ok(RiskConfigDiff {
+        field,
+        from: field.read(config),
+        to,
+        expected_from,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn risk_adjustment(target: &str, current: Option<Value>, suggested: Value) -> SuggestedAction {
+        SuggestedAction {
+            action_type: SuggestedActionType::RiskAdjustment,
+            target: target.to_string(),
+            current_value: current,
+            suggested_value: suggested,
+            reasoning: "Losses run past the stop".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_risk_adjustment_diff_typed_against_live_config() {
+        let mut config = RiskConfig::default();
+        let action = risk_adjustment("risk.max_drawdown_percent", Some(json!(10.0)), json!(7.5));
+
+        let diff = diff_risk_adjustment(&action, &config).unwrap();
+        assert_eq!(diff.field, RiskConfigField::MaxDrawdownPercent);
+        assert_eq!(diff.from, ConfigValue::Float(10.0));
+        assert_eq!(diff.to, ConfigValue::Float(7.5));
+        assert!(!diff.is_stale());
+        assert!(!diff.is_noop());
+        assert_eq!(diff.to_string(), "risk.max_drawdown_percent: 10 → 7.5");
+        assert_eq!(
+            serde_json::to_value(&diff).unwrap(),
+            json!({
+                "field": "max_drawdown_percent",
+                "from": {"type": "float", "value": 10.0},
+                "to": {"type": "float", "value": 7.5},
+                "expected_from": {"type": "float", "value": 10.0},
+            })
+        );
+
+        diff.apply(&mut config).unwrap();
+        assert_eq!(config.max_drawdown_percent, 7.5);
+    }
+
+    #[test]
+    fn test_integer_and_bool_fields_keep_their_type() {
+        let mut config = RiskConfig::default();
+
+        assert_eq!(
+            ConfigValue::from_json(&json!(4.0), ConfigValueKind::Integer),
+            Some(ConfigValue::Integer(4))
+        );
+        // Quoted numbers from model output still parse to the field's type
+        let diff = diff_risk_adjustment(
+            &risk_adjustment("max_concurrent_positions", Some(json!(5)), json!("3")),
+            &config,
+        )
+        .unwrap();
+        assert_eq!(diff.from, ConfigValue::Integer(2));
+        assert_eq!(diff.to, ConfigValue::Integer(3));
+        // The recommendation thought the live value was 5
+        assert!(diff.is_stale());
+        diff.apply(&mut config).unwrap();
+        assert_eq!(config.max_concurrent_positions, 3);
+
+        RiskConfigField::AutoPauseOnDrawdown
+            .write(&mut config, ConfigValue::Bool(false))
+            .unwrap();
+        assert_eq!(
+            RiskConfigField::AutoPauseOnDrawdown.read(&config),
+            ConfigValue::Bool(false)
+        );
+    }
+
+    #[test]
+    fn test_recommendations_limited_to_bounded_allowlist() {
+        let config = RiskConfig::default();
+
+        // Safety toggles are manual-only, whatever the value
+        for target in ["risk.auto_pause_on_drawdown", "volatility_scaling_enabled"] {
+            let err = diff_risk_adjustment(&risk_adjustment(target, None, json!(false)), &config)
+                .unwrap_err();
+            assert!(err.to_string().contains("changed manually"), "{}", err);
+        }
+
+        for (target, value) in [
+            ("max_drawdown_percent", json!(95.0)),
+            ("max_drawdown_percent", json!(0.0)),
+            ("max_concurrent_positions", json!(500)),
+            ("max_position_sol", json!(1000.0)),
+            (
+                "reentry_cooldown_secs",
+                json!(MAX_REENTRY_COOLDOWN_SECS + 1),
+            ),
+        ] {
+            let err =
+                diff_risk_adjustment(&risk_adjustment(target, None, value), &config).unwrap_err();
+            assert!(err.to_string().contains("must be between"), "{}", err);
+        }
+
+        let at_bound = risk_adjustment("max_drawdown_percent", None, json!(50.0));
+        assert!(diff_risk_adjustment(&at_bound, &config).is_ok());
+        for field in RiskConfigField::ALL {
+            let manual_only = matches!(
+                field,
+                RiskConfigField::AutoPauseOnDrawdown | RiskConfigField::VolatilityScalingEnabled
+            );
+            assert_eq!(field.recommendation_bounds().is_none(), manual_only);
+        }
+    }
+
+    #[test]
+    fn test_mistyped_or_unknown_targets_rejected() {
+        let config = RiskConfig::default();
+
+        let fractional = risk_adjustment("max_concurrent_positions", None, json!(2.5));
+        assert!(matches!(
+            diff_risk_adjustment(&fractional, &config),
+            Err(AppError::Validation(_))
+        ));
+        let not_a_number = risk_adjustment("max_position_sol", None, json!("lower"));
+        assert!(diff_risk_adjustment(&not_a_number, &config).is_err());
+        let unknown = risk_adjustment("risk.leverage", None, json!(2.0));
+        assert!(diff_risk_adjustment(&unknown, &config).is_err());
+
+        let mut toggle = risk_adjustment("max_position_sol", None, json!(1.0));
+        toggle.action_type = SuggestedActionType::StrategyToggle;
+        assert!(diff_risk_adjustment(&toggle, &config).is_err());
+
+        let mut config = config;
+        assert!(RiskConfigField::TimeLimitMinutes
+            .write(&mut config, ConfigValue::Float(3.0))
+            .is_err());
+    }
+}
@@ -7,6 +7,8 @@ use uuid::Uuid;
 use nullblock_mcp_client::{filter_read_only, McpClient, McpTool};
 
 use super::{
+    config::partition_models_for_wallet,
+    model_health::ModelHealthTracker,
     openrouter::{
         get_default_models, get_model_weight, mcp_tools_to_openrouter, ChatMessage,
         OpenRouterClient, RawToolCall, ToolDefinition, ToolModelResponseType,
@@ -41,6 +43,8 @@ pub struct ConsensusEngine {
     max_iterations: usize,
     disabled: bool,
     event_tx: Option<broadcast::Sender<ArbEvent>>,
+    model_health: ModelHealthTracker,
+    wallet_address: String,
 }
 
 impl ConsensusEngine {
@@ -57,6 +61,8 @@ impl ConsensusEngine {
             max_iterations: DEFAULT_MAX_ITERATIONS,
             disabled: false,
             event_tx: None,
+            model_health: ModelHealthTracker::from_env(),
+            wallet_address: String::new(),
         }
     }
 
@@ -79,6 +85,8 @@ impl ConsensusEngine {
             max_iterations: DEFAULT_MAX_ITERATIONS,
             disabled: true,
             event_tx: None,
+            model_health: ModelHealthTracker::default(),
+            wallet_address: String::new(),
         }
     }
 
@@ -102,11 +110,49 @@ impl ConsensusEngine {
         self
     }
 
+    pub fn with_model_health(mut self, model_health: ModelHealthTracker) -> Self {
+        self.model_health = model_health;
+        self
+    }
+
+    pub fn model_health(&self) -> &ModelHealthTracker {
+        &self.model_health
+    }
+
     pub fn with_models(mut self, models: Vec<String>) -> Self {
         self.default_models = models;
         self
     }
 
+    /// Wallet whose model access applies to every request this engine makes.
+    pub fn with_wallet_address(mut self, wallet: impl Into<String>) -> Self {
+        self.wallet_address = wallet.into();
+        self
+    }
+
+    /// Explicitly requested models the wallet may not query are rejected; the default
+    /// lineup is quietly narrowed to what the wallet is allowed.
+    fn models_for_wallet(&self, requested: Option<Vec<String>>) -> AppResult<Vec<String>> {
+        let explicit = requested.is_some();
+        let models = requested.unwrap_or_else(|| self.default_models.clone());
+        let (allowed, denied) = partition_models_for_wallet(&self.wallet_address, &models);
+
+        if !denied.is_empty() {
+            if explicit {
+                return Err(AppError::Unauthorized(format!(
+                    "Wallet is not allowed to query: {}",
+                    denied.join(", ")
+                )));
+            }
+            tracing::debug!(
+                denied = ?denied,
+                "Skipping default models this wallet may not query"
+            );
+        }
+
+        Ok(allowed)
+    }
+
     pub fn with_min_agreement(mut self, min_agreement: f64) -> Self {
         self.voting_engine = VotingEngine::new(min_agreement, 0.6);
         self
@@ -146,7 +192,9 @@ impl ConsensusEngine {
             ));
         }
 
-        let models_to_query = self.default_models.clone();
+        let models_to_query = self
+            .model_health
+            .filter_available(&self.models_for_wallet(None)?);
         let prompt = generate_analysis_prompt(&context);
 
         let system_prompt = Some(
@@ -176,6 +224,7 @@ impl ConsensusEngine {
             join_all(futures).await
         };
 
+        self.record_model_health(&models_to_query, &results);
         let model_votes: Vec<AnalysisModelVote> =
             results.into_iter().filter_map(|r| r.ok()).collect();
 
@@ -251,19 +300,23 @@ impl ConsensusEngine {
                         "Agentic analysis completed with text response"
                     );
 
-                    let vote = parse_analysis_response(&text).ok_or_else(|| {
+                    let parsed = parse_analysis_response(&text).ok_or_else(|| {
                         AppError::ExternalApi(format!(
                             "Failed to parse agentic analysis vote from {}: {}",
                             model,
                             &text[..text.len().min(200)]
                         ))
                     })?;
+                    if parsed.repaired {
+                        tracing::warn!(model = model, "Repaired malformed analysis JSON");
+                    }
 
                     return Ok(AnalysisModelVote {
                         model: model.to_string(),
-                        vote,
+                        vote: parsed.value,
                         latency_ms: total_latency_ms,
                         tools_called,
+                        json_repaired: parsed.repaired,
                     });
                 }
                 ToolModelResponseType::ToolUse(tool_calls) => {
@@ -374,19 +427,23 @@ impl ConsensusEngine {
 
         let response = result?;
 
-        let vote = parse_analysis_response(&response.content).ok_or_else(|| {
+        let parsed = parse_analysis_response(&response.content).ok_or_else(|| {
             AppError::ExternalApi(format!(
                 "Failed to parse analysis vote from {}: {}",
                 model,
                 &response.content[..response.content.len().min(200)]
             ))
         })?;
+        if parsed.repaired {
+            tracing::warn!(model = model, "Repaired malformed analysis JSON");
+        }
 
         Ok(AnalysisModelVote {
             model: model.to_string(),
-            vote,
+            vote: parsed.value,
             latency_ms: response.latency_ms,
             tools_called: 0,
+            json_repaired: parsed.repaired,
         })
     }
 
@@ -485,6 +542,7 @@ impl ConsensusEngine {
             trade_analyses: all_trade_analyses,
             pattern_summary: aggregated_pattern_summary,
             tools_called: total_tools_called,
+            repaired_votes: votes.iter().filter(|v| v.json_repaired).count(),
         }
     }
 
@@ -500,6 +558,8 @@ impl ConsensusEngine {
             ));
         }
 
+        let allowed_models = self.models_for_wallet(models)?;
+
         if let Some(ref tx) = self.event_tx {
             let _ = tx.send(
                 ArbEvent::new(
@@ -515,7 +575,7 @@ impl ConsensusEngine {
             );
         }
 
-        let models_to_query = models.unwrap_or_else(|| self.default_models.clone());
+        let models_to_query = self.model_health.filter_available(&allowed_models);
         let prompt = generate_trade_prompt(edge_context);
 
         let system_prompt = Some(
@@ -528,6 +588,7 @@ impl ConsensusEngine {
             .collect();
 
         let results = join_all(futures).await;
+        self.record_model_health(&models_to_query, &results);
 
         let votes: Vec<ModelVote> = results.into_iter().filter_map(|r| r.ok()).collect();
 
@@ -565,6 +626,7 @@ impl ConsensusEngine {
                         "approved": consensus.approved,
                         "agreement_score": consensus.agreement_score,
                         "models_responded": consensus.model_votes.len(),
+                        "repaired_votes": consensus.repaired_votes,
                         "timestamp": Utc::now(),
                     }),
                 )
@@ -577,12 +639,22 @@ impl ConsensusEngine {
             approved = consensus.approved,
             agreement = consensus.agreement_score,
             models_responded = consensus.model_votes.len(),
+            repaired_votes = consensus.repaired_votes,
             "Consensus decision reached"
         );
 
         Ok(consensus)
     }
 
+    fn record_model_health<T>(&self, models: &[String], results: &[AppResult<T>]) {
+        for (model, result) in models.iter().zip(results) {
+            match result {
+                Ok(_) => self.model_health.record_success(model),
+                Err(e) => self.model_health.record_failure(model, &e.to_string()),
+            }
+        }
+    }
+
     async fn query_single_model(
         &self,
         model: &str,
@@ -601,19 +673,23 @@ impl ConsensusEngine {
 
         let response = result?;
 
-        let vote = parse_trade_approval(&response.content).ok_or_else(|| {
+        let parsed = parse_trade_approval(&response.content).ok_or_else(|| {
             AppError::ExternalApi(format!(
                 "Failed to parse vote from {}: {}",
                 model, response.content
             ))
         })?;
+        if parsed.repaired {
+            tracing::warn!(model = model, "Repaired malformed vote JSON");
+        }
 
         Ok(ModelVote {
             model: model.to_string(),
-            approved: vote.approved,
-            confidence: vote.confidence,
-            reasoning: vote.reasoning,
+            approved: parsed.value.approved,
+            confidence: parsed.value.confidence,
+            reasoning: parsed.value.reasoning,
             latency_ms: response.latency_ms,
+            json_repaired: parsed.repaired,
         })
     }
 
@@ -691,6 +767,7 @@ pub struct AnalysisModelVote {
     pub vote: AnalysisVote,
     pub latency_ms: u64,
     pub tools_called: usize,
+    pub json_repaired: bool,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -704,6 +781,7 @@ pub struct AnalysisResult {
     pub trade_analyses: Vec<TradeAnalysisItem>,
     pub pattern_summary: Option<PatternSummary>,
     pub tools_called: usize,
+    pub repaired_votes: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -788,3 +866,53 @@ pub fn format_edge_context(
         Utc::now().to_rfc3339()
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::config::DEV_WALLET;
+
+    fn engine(wallet: &str) -> ConsensusEngine {
+        ConsensusEngine::new("test-key")
+            .with_models(vec![
+                "anthropic/claude-3-opus".to_string(),
+                "openai/gpt-4-turbo".to_string(),
+            ])
+            .with_wallet_address(wallet)
+    }
+
+    #[tokio::test]
+    async fn test_standard_wallet_cannot_request_dev_only_models() {
+        let engine = engine("StandardWallet1111");
+
+        let err = engine
+            .request_consensus(
+                Uuid::new_v4(),
+                "edge",
+                Some(vec!["anthropic/claude-3-opus".to_string()]),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::Unauthorized(_)));
+
+        assert_eq!(
+            engine.models_for_wallet(None).unwrap(),
+            vec!["openai/gpt-4-turbo".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_dev_wallet_can_request_dev_only_models() {
+        let engine = engine(DEV_WALLET);
+        let requested = vec![
+            "anthropic/claude-3-opus".to_string(),
+            "meta-llama/llama-3.1-405b-instruct".to_string(),
+        ];
+
+        assert_eq!(
+            engine.models_for_wallet(Some(requested.clone())).unwrap(),
+            requested
+        );
+        assert_eq!(engine.models_for_wallet(None).unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,209 @@
+use serde::de::DeserializeOwned;
+
+#[derive(Debug, Clone)]
+pub struct ParsedModelJson<T> {
+    pub value: T,
+    /// True when the response only parsed after `repair_json` fixed it up.
+    pub repaired: bool,
+}
+
+/// Parses the JSON object embedded in a model response, falling back to
+/// `repair_json` when the raw object is malformed.
+pub fn parse_model_json<T: DeserializeOwned>(content: &str) -> Option<ParsedModelJson<T>> {
+    let start = content.find('{')?;
+    if let Some(end) = content.rfind('}').filter(|end| *end > start) {
+        if let Ok(value) = serde_json::from_str(&content[start..=end]) {
+            return Some(ParsedModelJson {
+                value,
+                repaired: false,
+            });
+        }
+    }
+
+    let repaired = repair_json(&content[start..])?;
+    serde_json::from_str(&repaired)
+        .ok()
+        .map(|value| ParsedModelJson {
+            value,
+            repaired: true,
+        })
+}
+
+/// Best-effort repair of near-valid JSON as commonly produced by LLMs: markdown
+/// fences and trailing prose, `//` comments, trailing commas, unquoted keys,
+/// single or smart quotes, Python literals, raw newlines inside strings and
+/// responses truncated before the closing braces. Returns None when the content
+/// has no JSON object at all.
+pub fn repair_json(content: &str) -> Option<String> {
+    let start = content.find('{')?;
+    let chars: Vec<char> = content[start..].chars().collect();
+
+    let mut out = String::with_capacity(chars.len() + 8);
+    let mut closers: Vec<char> = Vec::new();
+    let mut string_delim: Option<char> = None;
+    let mut escaped = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if let Some(delim) = string_delim {
+            if escaped {
+                out.push(c);
+                escaped = false;
+            } else if c == '\\' {
+                out.push(c);
+                escaped = true;
+            } else if c == delim || (delim == '"' && c == '\u{201D}') {
+                out.push('"');
+                string_delim = None;
+            } else {
+                match c {
+                    '"' => out.push_str("\\\""),
+                    '\n' => out.push_str("\\n"),
+                    '\r' => {}
+                    '\t' => out.push_str("\\t"),
+                    _ => out.push(c),
+                }
+            }
+            i += 1;
+            continue;
+        }
+
+        match c {
+            '"' | '\u{201C}' | '\u{201D}' => {
+                out.push('"');
+                string_delim = Some('"');
+            }
+            '\'' => {
+                out.push('"');
+                string_delim = Some('\'');
+            }
+            '{' => {
+                out.push(c);
+                closers.push('}');
+            }
+            '[' => {
+                out.push(c);
+                closers.push(']');
+            }
+            '}' | ']' => {
+                trim_trailing_comma(&mut out);
+                out.push(closers.pop().unwrap_or(c));
+                if closers.is_empty() {
+                    return Some(out);
+                }
+            }
+            '/' if chars.get(i + 1) == Some(&'/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                let word_end = chars[i..]
+                    .iter()
+                    .position(|ch| !(ch.is_ascii_alphanumeric() || *ch == '_'))
+                    .map_or(chars.len(), |offset| i + offset);
+                let word: String = chars[i..word_end].iter().collect();
+                let is_key = chars[word_end..]
+                    .iter()
+                    .find(|ch| !ch.is_whitespace())
+                    .is_some_and(|ch| *ch == ':');
+
+                match word.as_str() {
+                    _ if is_key => {
+                        out.push('"');
+                        out.push_str(&word);
+                        out.push('"');
+                    }
+                    "True" => out.push_str("true"),
+                    "False" => out.push_str("false"),
+                    "None" => out.push_str("null"),
+                    _ => out.push_str(&word),
+                }
+                i = word_end;
+                continue;
+            }
+            _ => out.push(c),
+        }
+        i += 1;
+    }
+
+    // Truncated response: close any open string and containers.
+    if string_delim.is_some() {
+        if escaped {
+            out.pop();
+        }
+        out.push('"');
+    }
+    trim_trailing_comma(&mut out);
+    if out.ends_with(':') {
+        out.push_str("null");
+    }
+    while let Some(closer) = closers.pop() {
+        trim_trailing_comma(&mut out);
+        out.push(closer);
+    }
+
+    Some(out)
+}
+
+fn trim_trailing_comma(out: &mut String) {
+    let trimmed_len = out.trim_end().len();
+    out.truncate(trimmed_len);
+    if out.ends_with(',') {
+        out.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_valid_json_is_not_marked_repaired() {
+        let parsed: ParsedModelJson<Value> =
+            parse_model_json("Here you go: {\"approved\": true, \"confidence\": 0.8} Thanks")
+                .unwrap();
+
+        assert!(!parsed.repaired);
+        assert_eq!(parsed.value, json!({"approved": true, "confidence": 0.8}));
+    }
+
+    #[test]
+    fn test_repairs_common_llm_mistakes() {
+        let content = "```json\n{\n  approved: True, // looks good\n  'confidence': 0.72,\n  \"reasoning\": \u{201C}Deep liquidity\nand low slippage\u{201D},\n  \"tags\": [\"momentum\", \"volume\",],\n}\n```";
+
+        let parsed: ParsedModelJson<Value> = parse_model_json(content).unwrap();
+
+        assert!(parsed.repaired);
+        assert_eq!(
+            parsed.value,
+            json!({
+                "approved": true,
+                "confidence": 0.72,
+                "reasoning": "Deep liquidity\nand low slippage",
+                "tags": ["momentum", "volume"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_repairs_truncated_response() {
+        let parsed: ParsedModelJson<Value> = parse_model_json(
+            "{\"approved\": false, \"confidence\": 0.4, \"risk_alerts\": [\"thin liq",
+        )
+        .unwrap();
+
+        assert!(parsed.repaired);
+        assert_eq!(parsed.value["risk_alerts"], json!(["thin liq"]));
+    }
+
+    #[test]
+    fn test_hopeless_response_is_discarded() {
+        assert!(parse_model_json::<Value>("I cannot evaluate this trade.").is_none());
+        assert!(parse_model_json::<Value>("{ approved maybe confidence high }").is_none());
+    }
+}
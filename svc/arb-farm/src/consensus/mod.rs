@@ -1,21 +1,35 @@
 pub mod config;
+pub mod config_diff;
 mod engine;
+mod json_repair;
 pub mod model_discovery;
+pub mod model_health;
 mod openrouter;
+pub mod outcomes;
 pub mod providers;
+mod rate_limit;
+pub mod report;
 mod voting;
 
 pub use config::{
-    get_all_available_models, get_dev_wallet_models, get_models_for_wallet, get_standard_models,
-    is_dev_wallet, ConsensusConfig, ConsensusConfigManager, ConsensusModelConfig,
-    UpdateConsensusConfigRequest, AVAILABLE_MODELS as CONFIG_AVAILABLE_MODELS,
+    dev_only_model_ids, get_all_available_models, get_dev_wallet_models, get_models_for_wallet,
+    get_standard_models, is_dev_wallet, is_model_allowed_for_wallet, partition_models_for_wallet,
+    ConsensusConfig, ConsensusConfigManager, ConsensusModelConfig, UpdateConsensusConfigRequest,
+    AVAILABLE_MODELS as CONFIG_AVAILABLE_MODELS,
+};
+pub use config_diff::{
+    diff_risk_adjustment, ConfigValue, ConfigValueKind, RiskConfigDiff, RiskConfigField,
 };
 pub use engine::*;
+pub use json_repair::ParsedModelJson;
 pub use model_discovery::{
     discover_best_reasoning_models, get_discovered_models, get_discovery_status,
     get_fallback_models, refresh_models, ModelDiscoveryStatus,
 };
+pub use model_health::{ModelHealthStatus, ModelHealthTracker};
 pub use openrouter::{
     get_default_models, get_model_weight, quick_llm_call, OpenRouterClient, AVAILABLE_MODELS,
 };
+pub use outcomes::{ModelAccuracy, ModelAccuracyTracker};
+pub use rate_limit::{ConsensusPermit, ConsensusRateLimiter, ConsensusThrottle};
 pub use voting::*;
@@ -0,0 +1,245 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+pub const DEFAULT_MODEL_FAILURE_THRESHOLD: u32 = 3;
+pub const DEFAULT_MODEL_COOLDOWN_SECS: i64 = 600;
+
+#[derive(Debug, Clone, Default)]
+struct ModelHealthEntry {
+    consecutive_failures: u32,
+    total_successes: u64,
+    total_failures: u64,
+    last_error: Option<String>,
+    last_success_at: Option<DateTime<Utc>>,
+    last_failure_at: Option<DateTime<Utc>>,
+    excluded_until: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelHealthStatus {
+    pub model: String,
+    pub excluded: bool,
+    pub consecutive_failures: u32,
+    pub total_successes: u64,
+    pub total_failures: u64,
+    pub last_error: Option<String>,
+    pub last_success_at: Option<DateTime<Utc>>,
+    pub last_failure_at: Option<DateTime<Utc>>,
+    pub excluded_until: Option<DateTime<Utc>>,
+}
+
+/// Benches a model from the voting pool after `failure_threshold` consecutive failures.
+/// Once the cooldown passes the model is queried again; a success clears its record,
+/// another failure benches it for a fresh cooldown.
+#[derive(Debug, Clone)]
+pub struct ModelHealthTracker {
+    state: Arc<Mutex<HashMap<String, ModelHealthEntry>>>,
+    failure_threshold: u32,
+    cooldown: Duration,
+}
+
+impl Default for ModelHealthTracker {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_MODEL_FAILURE_THRESHOLD,
+            Duration::seconds(DEFAULT_MODEL_COOLDOWN_SECS),
+        )
+    }
+}
+
+impl ModelHealthTracker {
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(HashMap::new())),
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+        }
+    }
+
+    /// `CONSENSUS_MODEL_FAILURE_THRESHOLD` (default 3) and `CONSENSUS_MODEL_COOLDOWN_SECS`
+    /// (default 600).
+    pub fn from_env() -> Self {
+        let failure_threshold = std::env::var("CONSENSUS_MODEL_FAILURE_THRESHOLD")
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok())
+            .unwrap_or(DEFAULT_MODEL_FAILURE_THRESHOLD);
+        let cooldown_secs = std::env::var("CONSENSUS_MODEL_COOLDOWN_SECS")
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(DEFAULT_MODEL_COOLDOWN_SECS);
+
+        Self::new(failure_threshold, Duration::seconds(cooldown_secs))
+    }
+
+    pub fn record_success(&self, model: &str) {
+        self.record_success_at(model, Utc::now());
+    }
+
+    pub fn record_failure(&self, model: &str, error: &str) {
+        self.record_failure_at(model, error, Utc::now());
+    }
+
+    /// Models that may be queried now. If every model is benched the full list is
+    /// returned, so a provider-wide outage doesn't leave consensus with nobody to ask.
+    pub fn filter_available(&self, models: &[String]) -> Vec<String> {
+        self.filter_available_at(models, Utc::now())
+    }
+
+    pub fn snapshot(&self) -> Vec<ModelHealthStatus> {
+        self.snapshot_at(Utc::now())
+    }
+
+    fn record_success_at(&self, model: &str, now: DateTime<Utc>) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = state.entry(model.to_string()).or_default();
+        if entry.excluded_until.is_some() {
+            tracing::info!(model = model, "Model recovered, back in the voting pool");
+        }
+        entry.consecutive_failures = 0;
+        entry.total_successes += 1;
+        entry.last_success_at = Some(now);
+        entry.excluded_until = None;
+    }
+
+    fn record_failure_at(&self, model: &str, error: &str, now: DateTime<Utc>) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = state.entry(model.to_string()).or_default();
+        entry.consecutive_failures += 1;
+        entry.total_failures += 1;
+        entry.last_error = Some(error.to_string());
+        entry.last_failure_at = Some(now);
+
+        if entry.consecutive_failures >= self.failure_threshold {
+            let until = now + self.cooldown;
+            tracing::warn!(
+                model = model,
+                consecutive_failures = entry.consecutive_failures,
+                excluded_until = %until,
+                "Excluding failing model from the voting pool"
+            );
+            entry.excluded_until = Some(until);
+        }
+    }
+
+    fn filter_available_at(&self, models: &[String], now: DateTime<Utc>) -> Vec<String> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let available: Vec<String> = models
+            .iter()
+            .filter(|model| {
+                !state
+                    .get(model.as_str())
+                    .is_some_and(|entry| is_excluded(entry, now))
+            })
+            .cloned()
+            .collect();
+
+        if available.is_empty() {
+            models.to_vec()
+        } else {
+            available
+        }
+    }
+
+    fn snapshot_at(&self, now: DateTime<Utc>) -> Vec<ModelHealthStatus> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let mut models: Vec<ModelHealthStatus> = state
+            .iter()
+            .map(|(model, entry)| ModelHealthStatus {
+                model: model.clone(),
+                excluded: is_excluded(entry, now),
+                consecutive_failures: entry.consecutive_failures,
+                total_successes: entry.total_successes,
+                total_failures: entry.total_failures,
+                last_error: entry.last_error.clone(),
+                last_success_at: entry.last_success_at,
+                last_failure_at: entry.last_failure_at,
+                excluded_until: entry.excluded_until.filter(|until| *until > now),
+            })
+            .collect();
+        models.sort_by(|a, b| a.model.cmp(&b.model));
+        models
+    }
+}
+
+fn is_excluded(entry: &ModelHealthEntry, now: DateTime<Utc>) -> bool {
+    entry.excluded_until.is_some_and(|until| until > now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool() -> Vec<String> {
+        vec!["good/model".to_string(), "flaky/model".to_string()]
+    }
+
+    #[test]
+    fn test_failing_model_excluded_then_reincluded_after_cooldown() {
+        let tracker = ModelHealthTracker::new(3, Duration::minutes(10));
+        let start = Utc::now();
+
+        for i in 0..2 {
+            tracker.record_failure_at(
+                "flaky/model",
+                "502 Bad Gateway",
+                start + Duration::seconds(i),
+            );
+        }
+        assert_eq!(tracker.filter_available_at(&pool(), start), pool());
+
+        tracker.record_failure_at(
+            "flaky/model",
+            "502 Bad Gateway",
+            start + Duration::seconds(2),
+        );
+        tracker.record_success_at("good/model", start + Duration::seconds(2));
+        let benched = start + Duration::minutes(5);
+        assert_eq!(
+            tracker.filter_available_at(&pool(), benched),
+            vec!["good/model".to_string()]
+        );
+        let status = tracker.snapshot_at(benched);
+        let flaky = status.iter().find(|s| s.model == "flaky/model").unwrap();
+        assert!(flaky.excluded);
+        assert_eq!(flaky.consecutive_failures, 3);
+        assert_eq!(flaky.last_error.as_deref(), Some("502 Bad Gateway"));
+
+        // Cooldown over: queried again, and a success fully restores it
+        let after_cooldown = start + Duration::minutes(11);
+        assert_eq!(tracker.filter_available_at(&pool(), after_cooldown), pool());
+        tracker.record_success_at("flaky/model", after_cooldown);
+        let status = tracker.snapshot_at(after_cooldown);
+        let flaky = status.iter().find(|s| s.model == "flaky/model").unwrap();
+        assert!(!flaky.excluded);
+        assert_eq!(flaky.consecutive_failures, 0);
+        assert!(flaky.excluded_until.is_none());
+    }
+
+    #[test]
+    fn test_failure_after_cooldown_rebenches_immediately() {
+        let tracker = ModelHealthTracker::new(2, Duration::minutes(10));
+        let start = Utc::now();
+        tracker.record_failure_at("flaky/model", "timeout", start);
+        tracker.record_failure_at("flaky/model", "timeout", start);
+
+        let retry_at = start + Duration::minutes(11);
+        tracker.record_failure_at("flaky/model", "timeout", retry_at);
+        assert_eq!(
+            tracker.filter_available_at(&pool(), retry_at + Duration::minutes(1)),
+            vec!["good/model".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_all_models_excluded_falls_back_to_full_pool() {
+        let tracker = ModelHealthTracker::new(1, Duration::minutes(10));
+        let now = Utc::now();
+        tracker.record_failure_at("good/model", "outage", now);
+        tracker.record_failure_at("flaky/model", "outage", now);
+
+        assert_eq!(tracker.filter_available_at(&pool(), now), pool());
+    }
+}
@@ -0,0 +1,420 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::engrams::schemas::{
+    Recommendation, RecommendationOutcome, RecommendationStatus, TransactionSummary,
+};
+use crate::engrams::EngramsClient;
+
+pub const DEFAULT_OUTCOME_WINDOW_HOURS: i64 = 24;
+const OUTCOME_CHECK_INTERVAL_SECS: u64 = 900; // 15 minutes
+const OUTCOME_RECOMMENDATION_LIMIT: i64 = 200;
+/// Bucket for recommendations saved before model attribution was recorded.
+pub const UNATTRIBUTED_MODEL: &str = "unattributed";
+
+pub fn get_outcome_window_hours() -> i64 {
+    std::env::var("ARB_RECOMMENDATION_OUTCOME_WINDOW_HOURS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .filter(|hours: &i64| *hours > 0)
+        .unwrap_or(DEFAULT_OUTCOME_WINDOW_HOURS)
+}
+
+/// Compares realized P&L over equal windows either side of `applied_at`. None until the
+/// recommendation has been applied and the window after it has fully elapsed.
+pub fn measure_outcome(
+    recommendation: &Recommendation,
+    trades: &[TransactionSummary],
+    window_hours: i64,
+    now: DateTime<Utc>,
+) -> Option<RecommendationOutcome> {
+    if recommendation.status != RecommendationStatus::Applied {
+        return None;
+    }
+    let applied_at = recommendation.applied_at?;
+    let window = Duration::hours(window_hours);
+    if now < applied_at + window {
+        return None;
+    }
+
+    let realized = |from: DateTime<Utc>, to: DateTime<Utc>| {
+        trades
+            .iter()
+            .filter(|t| t.timestamp >= from && t.timestamp < to)
+            .filter_map(|t| t.pnl_sol)
+            .fold((0u32, 0.0f64), |(count, pnl), trade_pnl| {
+                (count + 1, pnl + trade_pnl)
+            })
+    };
+    let (trades_before, pnl_before_sol) = realized(applied_at - window, applied_at);
+    let (trades_after, pnl_after_sol) = realized(applied_at, applied_at + window);
+    let pnl_delta_sol = pnl_after_sol - pnl_before_sol;
+
+    Some(RecommendationOutcome {
+        window_hours,
+        trades_before,
+        trades_after,
+        pnl_before_sol,
+        pnl_after_sol,
+        pnl_delta_sol,
+        improved: pnl_delta_sol > 0.0,
+        measured_at: now,
+    })
+}
+
+/// Start of the earliest before-window still waiting to be measured: trades saved since
+/// then cover every outcome the next pass can record. None when nothing is due.
+pub fn trade_window_start(
+    recommendations: &[Recommendation],
+    window_hours: i64,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let window = Duration::hours(window_hours);
+    recommendations
+        .iter()
+        .filter(|r| r.outcome.is_none() && r.status == RecommendationStatus::Applied)
+        .filter_map(|r| r.applied_at)
+        .filter(|applied_at| now >= *applied_at + window)
+        .map(|applied_at| applied_at - window)
+        .min()
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModelAccuracy {
+    pub model: String,
+    pub outcomes_measured: u32,
+    pub improved: u32,
+    /// Share of measured recommendations that improved P&L
+    pub accuracy: f64,
+    pub total_pnl_delta_sol: f64,
+}
+
+#[derive(Debug, Default)]
+struct AccuracyState {
+    by_model: HashMap<String, ModelAccuracy>,
+    recorded: HashSet<Uuid>,
+}
+
+/// Per-model hit rate of applied recommendations, fed by measured outcomes.
+#[derive(Debug, Clone, Default)]
+pub struct ModelAccuracyTracker {
+    state: Arc<RwLock<AccuracyState>>,
+}
+
+impl ModelAccuracyTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Credits the outcome to every model that voted. Returns false if the recommendation
+    /// has no outcome yet or was already recorded.
+    pub async fn record(&self, recommendation: &Recommendation) -> bool {
+        let Some(outcome) = recommendation.outcome.as_ref() else {
+            return false;
+        };
+
+        let mut state = self.state.write().await;
+        if !state.recorded.insert(recommendation.recommendation_id) {
+            return false;
+        }
+
+        let models: Vec<&str> = if recommendation.models.is_empty() {
+            vec![UNATTRIBUTED_MODEL]
+        } else {
+            recommendation.models.iter().map(String::as_str).collect()
+        };
+        for model in models {
+            let entry = state
+                .by_model
+                .entry(model.to_string())
+                .or_insert_with(|| ModelAccuracy {
+                    model: model.to_string(),
+                    ..Default::default()
+                });
+            entry.outcomes_measured += 1;
+            if outcome.improved {
+                entry.improved += 1;
+            }
+            entry.total_pnl_delta_sol += outcome.pnl_delta_sol;
+            entry.accuracy = entry.improved as f64 / entry.outcomes_measured as f64;
+        }
+        true
+    }
+
+    pub async fn snapshot(&self) -> Vec<ModelAccuracy> {
+        let state = self.state.read().await;
+        let mut models: Vec<ModelAccuracy> = state.by_model.values().cloned().collect();
+        models.sort_by(|a, b| a.model.cmp(&b.model));
+        models
+    }
+}
+
+/// Measures applied recommendations whose window has closed and feeds every measured one
+/// to the tracker. Returns the recommendations that gained an outcome on this pass.
+pub async fn evaluate_outcomes(
+    recommendations: &[Recommendation],
+    trades: &[TransactionSummary],
+    window_hours: i64,
+    now: DateTime<Utc>,
+    tracker: &ModelAccuracyTracker,
+) -> Vec<Recommendation> {
+    let mut measured = Vec::new();
+
+    for recommendation in recommendations {
+        if recommendation.outcome.is_some() {
+            tracker.record(recommendation).await;
+            continue;
+        }
+
+        if let Some(outcome) = measure_outcome(recommendation, trades, window_hours, now) {
+            let updated = Recommendation {
+                outcome: Some(outcome),
+                ..recommendation.clone()
+            };
+            tracker.record(&updated).await;
+            measured.push(updated);
+        }
+    }
+
+    measured
+}
+
+pub async fn start_recommendation_outcome_scheduler(
+    engrams_client: Arc<EngramsClient>,
+    tracker: ModelAccuracyTracker,
+    wallet_address: String,
+) {
+    let window_hours = get_outcome_window_hours();
+
+    loop {
+        let recommendations = match engrams_client
+            .get_recommendations(
+                &wallet_address,
+                Some(&RecommendationStatus::Applied),
+                Some(OUTCOME_RECOMMENDATION_LIMIT),
+            )
+            .await
+        {
+            Ok(recommendations) => recommendations,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load applied recommendations");
+                Vec::new()
+            }
+        };
+
+        let now = Utc::now();
+        let trades = match trade_window_start(&recommendations, window_hours, now) {
+            Some(since) => {
+                engrams_client
+                    .get_trade_history_since(&wallet_address, since)
+                    .await
+            }
+            // Nothing due for measurement; already-measured ones still feed the tracker
+            None => Ok(Vec::new()),
+        };
+
+        if !recommendations.is_empty() {
+            match trades {
+                Ok(trades) => {
+                    let measured =
+                        evaluate_outcomes(&recommendations, &trades, window_hours, now, &tracker)
+                            .await;
+
+                    for recommendation in &measured {
+                        if let Err(e) = engrams_client
+                            .save_recommendation(&wallet_address, recommendation)
+                            .await
+                        {
+                            tracing::warn!(
+                                recommendation_id = %recommendation.recommendation_id,
+                                error = %e,
+                                "Failed to save recommendation outcome"
+                            );
+                        } else if let Some(outcome) = recommendation.outcome.as_ref() {
+                            tracing::info!(
+                                recommendation_id = %recommendation.recommendation_id,
+                                pnl_delta_sol = outcome.pnl_delta_sol,
+                                "📈 Recorded outcome for '{}'",
+                                recommendation.title
+                            );
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to load trade history for outcomes");
+                }
+            }
+        }
+
+        tokio::time::sleep(std::time::Duration::from_secs(OUTCOME_CHECK_INTERVAL_SECS)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engrams::schemas::{
+        RecommendationCategory, RecommendationSource, SuggestedAction, SuggestedActionType,
+        SupportingData, TransactionAction, TransactionMetadata,
+    };
+
+    fn recommendation(models: &[&str]) -> Recommendation {
+        Recommendation {
+            recommendation_id: Uuid::new_v4(),
+            source: RecommendationSource::ConsensusLlm,
+            category: RecommendationCategory::Risk,
+            title: "Tighten stop loss".to_string(),
+            description: "Losers run past the stop".to_string(),
+            suggested_action: SuggestedAction {
+                action_type: SuggestedActionType::RiskAdjustment,
+                target: "stop_loss_percent".to_string(),
+                current_value: Some(serde_json::json!(15.0)),
+                suggested_value: serde_json::json!(10.0),
+                reasoning: "Cut losers sooner".to_string(),
+            },
+            confidence: 0.8,
+            supporting_data: SupportingData {
+                trades_analyzed: 40,
+                time_period: "Last 7 days".to_string(),
+                relevant_engrams: Vec::new(),
+                metrics: None,
+            },
+            status: RecommendationStatus::Pending,
+            created_at: Utc::now() - Duration::hours(48),
+            applied_at: None,
+            below_confidence_floor: false,
+            models: models.iter().map(|m| m.to_string()).collect(),
+            outcome: None,
+        }
+    }
+
+    fn applied(recommendation: Recommendation, applied_at: DateTime<Utc>) -> Recommendation {
+        Recommendation {
+            status: RecommendationStatus::Applied,
+            applied_at: Some(applied_at),
+            ..recommendation
+        }
+    }
+
+    fn trade(timestamp: DateTime<Utc>, pnl_sol: f64) -> TransactionSummary {
+        TransactionSummary {
+            tx_signature: Uuid::new_v4().to_string(),
+            action: TransactionAction::Sell,
+            token_mint: "mint".to_string(),
+            token_symbol: None,
+            venue: "pump_fun".to_string(),
+            entry_sol: 0.1,
+            exit_sol: Some(0.1 + pnl_sol),
+            pnl_sol: Some(pnl_sol),
+            pnl_percent: None,
+            slippage_bps: 0,
+            execution_time_ms: 0,
+            strategy_id: None,
+            timestamp,
+            metadata: TransactionMetadata::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_applied_recommendation_records_pnl_delta() {
+        let now = Utc::now();
+        let applied_at = now - Duration::hours(25);
+        let rec = applied(
+            recommendation(&["openai/gpt-4o", "anthropic/claude-3.5-sonnet"]),
+            applied_at,
+        );
+        let trades = vec![
+            trade(applied_at - Duration::hours(30), 5.0), // outside the before window
+            trade(applied_at - Duration::hours(10), -0.3),
+            trade(applied_at - Duration::hours(2), 0.1),
+            trade(applied_at + Duration::hours(1), 0.2),
+            trade(applied_at + Duration::hours(20), 0.15),
+        ];
+        let tracker = ModelAccuracyTracker::new();
+
+        let measured = evaluate_outcomes(&[rec.clone()], &trades, 24, now, &tracker).await;
+
+        assert_eq!(measured.len(), 1);
+        let outcome = measured[0].outcome.as_ref().unwrap();
+        assert_eq!(outcome.trades_before, 2);
+        assert_eq!(outcome.trades_after, 2);
+        assert!((outcome.pnl_before_sol - -0.2).abs() < 1e-9);
+        assert!((outcome.pnl_after_sol - 0.35).abs() < 1e-9);
+        assert!((outcome.pnl_delta_sol - 0.55).abs() < 1e-9);
+        assert!(outcome.improved);
+
+        let accuracy = tracker.snapshot().await;
+        assert_eq!(accuracy.len(), 2);
+        for model in &accuracy {
+            assert_eq!(model.outcomes_measured, 1);
+            assert_eq!(model.improved, 1);
+            assert_eq!(model.accuracy, 1.0);
+        }
+
+        // Already-measured recommendations aren't re-measured or double counted
+        let again = evaluate_outcomes(&measured, &trades, 24, now, &tracker).await;
+        assert!(again.is_empty());
+        assert_eq!(tracker.snapshot().await[0].outcomes_measured, 1);
+    }
+
+    #[tokio::test]
+    async fn test_outcome_waits_for_window_and_application() {
+        let now = Utc::now();
+        let trades = vec![trade(now - Duration::hours(2), -0.4)];
+        let tracker = ModelAccuracyTracker::new();
+
+        let pending = recommendation(&["openai/gpt-4o"]);
+        let recent = applied(recommendation(&["openai/gpt-4o"]), now - Duration::hours(2));
+        let measured = evaluate_outcomes(&[pending, recent], &trades, 24, now, &tracker).await;
+        assert!(measured.is_empty());
+        assert!(tracker.snapshot().await.is_empty());
+
+        // A worse after-window counts against the model; missing models are bucketed
+        let old = applied(recommendation(&[]), now - Duration::hours(3));
+        let outcome = measure_outcome(&old, &trades, 2, now).unwrap();
+        assert_eq!(outcome.trades_before, 0);
+        assert!((outcome.pnl_delta_sol - -0.4).abs() < 1e-9);
+        assert!(!outcome.improved);
+
+        let measured = evaluate_outcomes(&[old], &trades, 2, now, &tracker).await;
+        assert_eq!(measured.len(), 1);
+        let accuracy = tracker.snapshot().await;
+        assert_eq!(accuracy[0].model, UNATTRIBUTED_MODEL);
+        assert_eq!(accuracy[0].improved, 0);
+        assert_eq!(accuracy[0].accuracy, 0.0);
+    }
+
+    #[test]
+    fn test_trade_window_covers_earliest_due_recommendation() {
+        let now = Utc::now();
+        let due_early = applied(recommendation(&[]), now - Duration::hours(72));
+        let due_late = applied(recommendation(&[]), now - Duration::hours(30));
+        let not_due = applied(recommendation(&[]), now - Duration::hours(2));
+        let mut measured = applied(recommendation(&[]), now - Duration::hours(200));
+        measured.outcome = measure_outcome(&measured, &[], 24, now);
+        let pending = recommendation(&[]);
+
+        let start = trade_window_start(
+            &[
+                due_late,
+                measured.clone(),
+                due_early,
+                not_due.clone(),
+                pending.clone(),
+            ],
+            24,
+            now,
+        );
+        assert_eq!(start, Some(now - Duration::hours(96)));
+
+        // Already measured or not yet due: no trades needed
+        assert_eq!(
+            trade_window_start(&[measured, not_due, pending], 24, now),
+            None
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use super::ConsensusConfig;
+
+#[derive(Debug, Default)]
+struct LimiterState {
+    in_flight: u32,
+    manual_started: VecDeque<DateTime<Utc>>,
+}
+
+/// Why a consensus request was turned away.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum ConsensusThrottle {
+    ConcurrencyCap {
+        in_flight: u32,
+        max_concurrent: u32,
+        retry_after_secs: u64,
+    },
+    HourlyCap {
+        limit: u32,
+        retry_after_secs: u64,
+    },
+}
+
+impl ConsensusThrottle {
+    pub fn retry_after_secs(&self) -> u64 {
+        match self {
+            Self::ConcurrencyCap {
+                retry_after_secs, ..
+            }
+            | Self::HourlyCap {
+                retry_after_secs, ..
+            } => *retry_after_secs,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            Self::ConcurrencyCap {
+                in_flight,
+                max_concurrent,
+                ..
+            } => format!(
+                "{} consensus request(s) already in flight (max {})",
+                in_flight, max_concurrent
+            ),
+            Self::HourlyCap { limit, .. } => {
+                format!("Manual consensus limit of {} per hour reached", limit)
+            }
+        }
+    }
+}
+
+/// Held for the duration of a consensus analysis; releases its slot on drop.
+#[derive(Debug)]
+pub struct ConsensusPermit {
+    state: Arc<Mutex<LimiterState>>,
+}
+
+impl Drop for ConsensusPermit {
+    fn drop(&mut self) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.in_flight = state.in_flight.saturating_sub(1);
+    }
+}
+
+/// Shared by manual requests and the analysis scheduler. Both count against the
+/// concurrency cap; only manual requests count against the hourly cap.
+#[derive(Debug, Clone, Default)]
+pub struct ConsensusRateLimiter {
+    state: Arc<Mutex<LimiterState>>,
+}
+
+impl ConsensusRateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn try_acquire_manual(
+        &self,
+        config: &ConsensusConfig,
+    ) -> Result<ConsensusPermit, ConsensusThrottle> {
+        self.try_acquire_manual_at(config, Utc::now())
+    }
+
+    pub fn try_acquire_scheduled(
+        &self,
+        config: &ConsensusConfig,
+    ) -> Result<ConsensusPermit, ConsensusThrottle> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        self.check_concurrency(&state, config)?;
+        state.in_flight += 1;
+        Ok(self.permit())
+    }
+
+    fn try_acquire_manual_at(
+        &self,
+        config: &ConsensusConfig,
+        now: DateTime<Utc>,
+    ) -> Result<ConsensusPermit, ConsensusThrottle> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        self.check_concurrency(&state, config)?;
+
+        let window_start = now - Duration::hours(1);
+        while state
+            .manual_started
+            .front()
+            .is_some_and(|started| *started <= window_start)
+        {
+            state.manual_started.pop_front();
+        }
+
+        let limit = config.max_manual_requests_per_hour;
+        if state.manual_started.len() >= limit as usize {
+            let retry_after_secs = state
+                .manual_started
+                .front()
+                .map(|oldest| (*oldest + Duration::hours(1) - now).num_seconds().max(1) as u64)
+                .unwrap_or(3600);
+            return Err(ConsensusThrottle::HourlyCap {
+                limit,
+                retry_after_secs,
+            });
+        }
+
+        state.manual_started.push_back(now);
+        state.in_flight += 1;
+        Ok(self.permit())
+    }
+
+    fn check_concurrency(
+        &self,
+        state: &LimiterState,
+        config: &ConsensusConfig,
+    ) -> Result<(), ConsensusThrottle> {
+        let max_concurrent = config.max_concurrent_requests.max(1);
+        if state.in_flight >= max_concurrent {
+            // A running analysis finishes or times out within `timeout_ms`.
+            return Err(ConsensusThrottle::ConcurrencyCap {
+                in_flight: state.in_flight,
+                max_concurrent,
+                retry_after_secs: config.timeout_ms.div_ceil(1000).max(1),
+            });
+        }
+        Ok(())
+    }
+
+    fn permit(&self) -> ConsensusPermit {
+        ConsensusPermit {
+            state: self.state.clone(),
+        }
+    }
+
+    pub fn in_flight(&self) -> u32 {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .in_flight
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(max_concurrent: u32, per_hour: u32) -> ConsensusConfig {
+        ConsensusConfig {
+            max_concurrent_requests: max_concurrent,
+            max_manual_requests_per_hour: per_hour,
+            timeout_ms: 30_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_second_concurrent_manual_request_rejected() {
+        let limiter = ConsensusRateLimiter::new();
+        let config = config(1, 10);
+
+        let permit = limiter.try_acquire_manual(&config).unwrap();
+        let rejected = limiter.try_acquire_manual(&config).unwrap_err();
+        assert!(matches!(
+            rejected,
+            ConsensusThrottle::ConcurrencyCap { in_flight: 1, .. }
+        ));
+        assert_eq!(rejected.retry_after_secs(), 30);
+
+        // The scheduler shares the cap.
+        assert!(limiter.try_acquire_scheduled(&config).is_err());
+
+        drop(permit);
+        assert_eq!(limiter.in_flight(), 0);
+        assert!(limiter.try_acquire_manual(&config).is_ok());
+    }
+
+    #[test]
+    fn test_hourly_cap_enforced_and_reopens() {
+        let limiter = ConsensusRateLimiter::new();
+        let config = config(1, 2);
+        let start = Utc::now();
+
+        for minutes in [0, 10] {
+            let permit = limiter
+                .try_acquire_manual_at(&config, start + Duration::minutes(minutes))
+                .unwrap();
+            drop(permit);
+        }
+
+        let rejected = limiter
+            .try_acquire_manual_at(&config, start + Duration::minutes(20))
+            .unwrap_err();
+        assert_eq!(
+            rejected,
+            ConsensusThrottle::HourlyCap {
+                limit: 2,
+                retry_after_secs: 40 * 60,
+            }
+        );
+
+        // Scheduled runs don't count against the manual budget.
+        assert!(limiter.try_acquire_scheduled(&config).is_ok());
+
+        assert!(limiter
+            .try_acquire_manual_at(&config, start + Duration::minutes(61))
+            .is_ok());
+    }
+}
@@ -0,0 +1,555 @@
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::ModelVote;
+use crate::database::repositories::ConsensusRecord;
+use crate::engrams::schemas::{ConsensusAnalysis, ConversationLog, Recommendation};
+
+const REDACTED: &str = "[REDACTED]";
+
+lazy_static::lazy_static! {
+    static ref SECRET_PATTERNS: Vec<Regex> = vec![
+        Regex::new(r"sk-[A-Za-z0-9_\-]{16,}").unwrap(),
+        Regex::new(r"(?i)bearer\s+[A-Za-z0-9_\-\.=]{16,}").unwrap(),
+        Regex::new(r"(?i)(api[_-]?key|secret|private[_-]?key|password)\s*[:=]\s*\S+").unwrap(),
+        // Base58 keypairs are the same 87-88 chars as tx signatures, so only mask them
+        // behind a key-like label
+        Regex::new(
+            r"(?i)\b(key\s*pair|secret[_\s-]?key|private[_\s-]?key|signer|seed)\b[^1-9A-HJ-NP-Za-km-z]{0,4}[1-9A-HJ-NP-Za-km-z]{86,90}\b"
+        )
+        .unwrap(),
+        // Keypair files are a JSON array of 64 bytes
+        Regex::new(r"\[\s*\d{1,3}(?:\s*,\s*\d{1,3}){63}\s*\]").unwrap(),
+    ];
+}
+
+const SECRET_FIELD_NAMES: &[&str] = &[
+    "api_key",
+    "apikey",
+    "secret",
+    "private_key",
+    "password",
+    "access_token",
+    "auth_token",
+    "authorization",
+];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsensusReportKind {
+    Analysis,
+    Decision,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportVote {
+    pub model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approved: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+    pub reasoning: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsensusReport {
+    pub id: Uuid,
+    pub kind: ConsensusReportKind,
+    pub created_at: DateTime<Utc>,
+    pub assessment: String,
+    pub risk_alerts: Vec<String>,
+    pub metrics: Value,
+    pub votes: Vec<ReportVote>,
+    pub recommendations: Vec<Recommendation>,
+    /// Recommendation ids referenced by the analysis that could not be loaded.
+    pub missing_recommendation_ids: Vec<Uuid>,
+    pub transcripts: Vec<ConversationLog>,
+    pub exported_at: DateTime<Utc>,
+}
+
+impl ConsensusReport {
+    pub fn from_analysis(
+        analysis: ConsensusAnalysis,
+        mut recommendations: Vec<Recommendation>,
+        transcripts: Vec<ConversationLog>,
+    ) -> Self {
+        recommendations.retain(|r| analysis.recommendation_ids.contains(&r.recommendation_id));
+        let missing_recommendation_ids = analysis
+            .recommendation_ids
+            .iter()
+            .filter(|id| !recommendations.iter().any(|r| &r.recommendation_id == *id))
+            .copied()
+            .collect();
+
+        let votes = analysis
+            .models_queried
+            .iter()
+            .map(|model| ReportVote {
+                model: model.clone(),
+                approved: None,
+                confidence: None,
+                reasoning: String::new(),
+            })
+            .collect();
+
+        Self {
+            id: analysis.analysis_id,
+            kind: ConsensusReportKind::Analysis,
+            created_at: analysis.created_at,
+            assessment: analysis.overall_assessment,
+            risk_alerts: analysis.risk_alerts,
+            metrics: serde_json::json!({
+                "analysis_type": analysis.analysis_type,
+                "time_period": analysis.time_period,
+                "trades_analyzed": analysis.total_trades_analyzed,
+                "avg_confidence": analysis.avg_confidence,
+                "total_latency_ms": analysis.total_latency_ms,
+                "win_rate": analysis.context_summary.win_rate,
+                "total_pnl_sol": analysis.context_summary.total_pnl_sol,
+            }),
+            votes,
+            recommendations,
+            missing_recommendation_ids,
+            transcripts,
+            exported_at: Utc::now(),
+        }
+    }
+
+    pub fn from_decision(record: &ConsensusRecord, transcripts: Vec<ConversationLog>) -> Self {
+        let model_votes: Vec<ModelVote> =
+            serde_json::from_value(record.model_votes.clone()).unwrap_or_default();
+
+        Self {
+            id: record.id,
+            kind: ConsensusReportKind::Decision,
+            created_at: record.created_at,
+            assessment: record.reasoning_summary.clone().unwrap_or_default(),
+            risk_alerts: Vec::new(),
+            metrics: serde_json::json!({
+                "edge_id": record.edge_id,
+                "approved": record.approved,
+                "agreement_score": record.agreement_score,
+                "weighted_confidence": record.weighted_confidence,
+                "total_latency_ms": record.total_latency_ms,
+                "edge_context": record.edge_context,
+            }),
+            votes: model_votes
+                .into_iter()
+                .map(|v| ReportVote {
+                    model: v.model,
+                    approved: Some(v.approved),
+                    confidence: Some(v.confidence),
+                    reasoning: v.reasoning,
+                })
+                .collect(),
+            recommendations: Vec::new(),
+            missing_recommendation_ids: Vec::new(),
+            transcripts,
+            exported_at: Utc::now(),
+        }
+    }
+
+    /// Round-trips through JSON, masking secret-looking strings and secret-named fields.
+    /// If the masked JSON no longer fits the report, nothing but the ids and timestamps
+    /// is kept.
+    pub fn redacted(&self) -> Self {
+        let mut value = serde_json::to_value(self).unwrap_or(Value::Null);
+        redact_value(&mut value);
+        self.from_redacted_value(value)
+    }
+
+    fn from_redacted_value(&self, value: Value) -> Self {
+        serde_json::from_value(value).unwrap_or_else(|e| {
+            tracing::warn!(
+                report_id = %self.id,
+                "Redacted consensus report failed to deserialize, exporting placeholder: {}",
+                e
+            );
+            self.placeholder()
+        })
+    }
+
+    fn placeholder(&self) -> Self {
+        Self {
+            id: self.id,
+            kind: self.kind,
+            created_at: self.created_at,
+            assessment: REDACTED.to_string(),
+            risk_alerts: Vec::new(),
+            metrics: Value::Null,
+            votes: Vec::new(),
+            recommendations: Vec::new(),
+            missing_recommendation_ids: Vec::new(),
+            transcripts: Vec::new(),
+            exported_at: self.exported_at,
+        }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::new();
+        let title = match self.kind {
+            ConsensusReportKind::Analysis => "Consensus Analysis",
+            ConsensusReportKind::Decision => "Consensus Decision",
+        };
+
+        md.push_str(&format!("# {} `{}`\n\n", title, self.id));
+        md.push_str(&format!(
+            "- **Created:** {}\n- **Exported:** {}\n\n",
+            self.created_at.to_rfc3339(),
+            self.exported_at.to_rfc3339()
+        ));
+
+        md.push_str("## Assessment\n\n");
+        md.push_str(&non_empty(&self.assessment, "_No assessment recorded._"));
+        md.push_str("\n\n");
+
+        if let Some(metrics) = self.metrics.as_object() {
+            md.push_str("## Metrics\n\n| Metric | Value |\n|--------|-------|\n");
+            for (key, value) in metrics {
+                let rendered = match value {
+                    Value::String(s) => s.clone(),
+                    Value::Null => "-".to_string(),
+                    other => other.to_string(),
+                };
+                md.push_str(&format!("| {} | {} |\n", key, escape_cell(&rendered)));
+            }
+            md.push('\n');
+        }
+
+        if !self.risk_alerts.is_empty() {
+            md.push_str("## Risk Alerts\n\n");
+            for alert in &self.risk_alerts {
+                md.push_str(&format!("- {}\n", single_line(alert)));
+            }
+            md.push('\n');
+        }
+
+        md.push_str("## Votes\n\n");
+        if self.votes.is_empty() {
+            md.push_str("_No votes recorded._\n\n");
+        } else {
+            md.push_str("| Model | Approved | Confidence | Reasoning |\n");
+            md.push_str("|-------|----------|------------|-----------|\n");
+            for vote in &self.votes {
+                md.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    escape_cell(&vote.model),
+                    vote.approved
+                        .map(|a| if a { "yes" } else { "no" })
+                        .unwrap_or("-"),
+                    vote.confidence
+                        .map(|c| format!("{:.0}%", c * 100.0))
+                        .unwrap_or_else(|| "-".to_string()),
+                    escape_cell(&vote.reasoning),
+                ));
+            }
+            md.push('\n');
+        }
+
+        md.push_str("## Recommendations\n\n");
+        if self.recommendations.is_empty() {
+            md.push_str("_No recommendations._\n\n");
+        }
+        for (i, rec) in self.recommendations.iter().enumerate() {
+            md.push_str(&format!(
+                "### {}. {}\n\n- **Id:** `{}`\n- **Confidence:** {:.0}%\n- **Target:** `{}`\n- **Suggested value:** `{}`\n\n{}\n\n",
+                i + 1,
+                single_line(&rec.title),
+                rec.recommendation_id,
+                rec.confidence * 100.0,
+                rec.suggested_action.target,
+                rec.suggested_action.suggested_value,
+                non_empty(&rec.description, "_No description._"),
+            ));
+        }
+        if !self.missing_recommendation_ids.is_empty() {
+            md.push_str("_Missing recommendation engrams:_\n\n");
+            for id in &self.missing_recommendation_ids {
+                md.push_str(&format!("- `{}`\n", id));
+            }
+            md.push('\n');
+        }
+
+        if !self.transcripts.is_empty() {
+            md.push_str("## Transcripts\n\n");
+            for transcript in &self.transcripts {
+                md.push_str(&format!("### Session `{}`\n\n", transcript.session_id));
+                for message in &transcript.messages {
+                    md.push_str(&format!(
+                        "**{}** ({})\n\n```text\n{}\n```\n\n",
+                        single_line(&message.role),
+                        message.timestamp.to_rfc3339(),
+                        message.content.replace("```", "'''"),
+                    ));
+                }
+            }
+        }
+
+        md
+    }
+}
+
+fn redact_value(value: &mut Value) {
+    match value {
+        Value::String(s) => *s = redact_text(s),
+        Value::Array(items) => items.iter_mut().for_each(redact_value),
+        Value::Object(map) => {
+            for (key, v) in map.iter_mut() {
+                let lower = key.to_lowercase();
+                let is_secret_field = SECRET_FIELD_NAMES
+                    .iter()
+                    .any(|n| lower == *n || lower.ends_with(&format!("_{}", n)));
+                if v.is_string() && is_secret_field {
+                    *v = Value::String(REDACTED.to_string());
+                } else {
+                    redact_value(v);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+pub fn redact_text(text: &str) -> String {
+    SECRET_PATTERNS.iter().fold(text.to_string(), |acc, re| {
+        re.replace_all(&acc, REDACTED).into_owned()
+    })
+}
+
+fn non_empty(text: &str, fallback: &str) -> String {
+    if text.trim().is_empty() {
+        fallback.to_string()
+    } else {
+        text.trim().to_string()
+    }
+}
+
+fn single_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn escape_cell(text: &str) -> String {
+    single_line(text).replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engrams::schemas::{
+        AnalysisContextSummary, ConsensusAnalysisType, ConversationContext, ConversationMessage,
+        ConversationOutcome, ConversationTopic, ConversationTrigger, RecommendationCategory,
+        RecommendationSource, RecommendationStatus, SuggestedAction, SuggestedActionType,
+        SupportingData,
+    };
+
+    fn recommendation(id: Uuid, title: &str) -> Recommendation {
+        Recommendation {
+            recommendation_id: id,
+            source: RecommendationSource::ConsensusLlm,
+            category: RecommendationCategory::Risk,
+            title: title.to_string(),
+            description: "Losses | cluster beyond -15%".to_string(),
+            suggested_action: SuggestedAction {
+                action_type: SuggestedActionType::RiskAdjustment,
+                target: "stop_loss_percent".to_string(),
+                current_value: Some(serde_json::json!(15.0)),
+                suggested_value: serde_json::json!(12.0),
+                reasoning: "Cut losers sooner".to_string(),
+            },
+            confidence: 0.8,
+            supporting_data: SupportingData {
+                trades_analyzed: 40,
+                time_period: "Last 7 days".to_string(),
+                relevant_engrams: vec![],
+                metrics: None,
+            },
+            status: RecommendationStatus::Pending,
+            created_at: Utc::now(),
+            applied_at: None,
+            below_confidence_floor: false,
+            models: Vec::new(),
+            outcome: None,
+        }
+    }
+
+    fn analysis(recommendation_ids: Vec<Uuid>) -> ConsensusAnalysis {
+        ConsensusAnalysis {
+            analysis_id: Uuid::new_v4(),
+            analysis_type: ConsensusAnalysisType::Scheduled,
+            time_period: "Last 7 days".to_string(),
+            total_trades_analyzed: 40,
+            overall_assessment: "Win rate is healthy; tighten exits.".to_string(),
+            risk_alerts: vec!["Key sk-or-v1-abcdef0123456789abcdef leaked in logs".to_string()],
+            recommendations_count: recommendation_ids.len() as u32,
+            recommendation_ids,
+            avg_confidence: 0.75,
+            models_queried: vec!["anthropic/claude-sonnet".to_string()],
+            total_latency_ms: 4200,
+            context_summary: AnalysisContextSummary {
+                win_rate: 0.62,
+                total_pnl_sol: 1.4,
+                top_venue: None,
+                error_count: 0,
+            },
+            created_at: Utc::now(),
+        }
+    }
+
+    fn transcript(content: &str) -> ConversationLog {
+        ConversationLog {
+            session_id: Uuid::new_v4(),
+            participants: vec!["anthropic/claude-sonnet".to_string()],
+            topic: ConversationTopic::TradeAnalysis,
+            context: ConversationContext {
+                trigger: ConversationTrigger::Scheduled,
+                trades_in_scope: Some(40),
+                time_period: None,
+                additional_context: Some(serde_json::json!({"api_key": "hunter2"})),
+            },
+            messages: vec![ConversationMessage {
+                role: "assistant".to_string(),
+                content: content.to_string(),
+                timestamp: Utc::now(),
+                tokens_used: None,
+                latency_ms: None,
+            }],
+            outcome: ConversationOutcome {
+                consensus_reached: true,
+                recommendations_generated: 2,
+                engram_refs: vec![],
+                summary: None,
+            },
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_report_includes_all_linked_recommendations() {
+        let (a, b, unrelated) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let missing = Uuid::new_v4();
+        let report = ConsensusReport::from_analysis(
+            analysis(vec![a, b, missing]),
+            vec![
+                recommendation(a, "Tighten stop loss"),
+                recommendation(b, "Avoid late entries"),
+                recommendation(unrelated, "Unrelated"),
+            ],
+            vec![transcript("Looks good")],
+        );
+
+        let ids: Vec<Uuid> = report
+            .recommendations
+            .iter()
+            .map(|r| r.recommendation_id)
+            .collect();
+        assert_eq!(ids, vec![a, b]);
+        assert_eq!(report.missing_recommendation_ids, vec![missing]);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["recommendations"].as_array().unwrap().len(), 2);
+        assert_eq!(json["transcripts"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_markdown_renders_sections_and_tables() {
+        let a = Uuid::new_v4();
+        let report = ConsensusReport::from_analysis(
+            analysis(vec![a]),
+            vec![recommendation(a, "Tighten\nstop loss")],
+            vec![transcript("Use ```code``` fences carefully")],
+        );
+        let md = report.to_markdown();
+
+        assert!(md.starts_with("# Consensus Analysis `"));
+        for heading in [
+            "## Assessment",
+            "## Metrics",
+            "## Votes",
+            "## Recommendations",
+            "## Transcripts",
+        ] {
+            assert!(md.contains(heading), "missing {}", heading);
+        }
+        assert!(md.contains("### 1. Tighten stop loss"));
+        assert!(md.contains(&a.to_string()));
+
+        // Every table row has the same number of unescaped cell separators as its header.
+        let mut expected_cols = None;
+        for line in md.lines() {
+            if !line.starts_with('|') {
+                expected_cols = None;
+                continue;
+            }
+            let cols = line.replace("\\|", "").matches('|').count();
+            match expected_cols {
+                None => expected_cols = Some(cols),
+                Some(n) => assert_eq!(cols, n, "ragged table row: {}", line),
+            }
+        }
+
+        // Code fences are balanced so transcripts cannot break out of their blocks.
+        assert_eq!(md.matches("```").count() % 2, 0);
+    }
+
+    #[test]
+    fn test_redaction_masks_secrets() {
+        let report = ConsensusReport::from_analysis(
+            analysis(vec![]),
+            vec![],
+            vec![transcript("auth: Bearer abcdefghijklmnopqrstuvwxyz123456")],
+        )
+        .redacted();
+
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(!json.contains("sk-or-v1-abcdef0123456789abcdef"));
+        assert!(!json.contains("abcdefghijklmnopqrstuvwxyz123456"));
+        assert!(!json.contains("hunter2"));
+        assert!(json.contains(REDACTED));
+        assert!(json.contains("Win rate is healthy"));
+    }
+
+    #[test]
+    fn test_redaction_fails_closed() {
+        let report = ConsensusReport::from_analysis(
+            analysis(vec![]),
+            vec![],
+            vec![transcript("auth: Bearer abcdefghijklmnopqrstuvwxyz123456")],
+        );
+
+        // Masked JSON that no longer deserializes must not fall back to the raw report
+        let placeholder = report.from_redacted_value(serde_json::json!({ "id": "not-a-uuid" }));
+        assert_eq!(placeholder.id, report.id);
+        assert_eq!(placeholder.assessment, REDACTED);
+        assert!(placeholder.transcripts.is_empty());
+        assert!(placeholder.risk_alerts.is_empty());
+        assert!(placeholder.metrics.is_null());
+
+        let json = serde_json::to_string(&placeholder).unwrap();
+        assert!(!json.contains("abcdefghijklmnopqrstuvwxyz123456"));
+        assert!(!json.contains("Win rate is healthy"));
+    }
+
+    #[test]
+    fn test_redaction_keeps_tx_signatures_and_masks_keypairs() {
+        let signature = "MASi45ub7Qe4ZE36UT5G6cU4ud8Fhhe4deS4F3cw9KTAb8dLcukC7edhDQ7cn5d4gEYkbUrMWeWQLGsCmrG6dLaY";
+        let short_signature = "FQR9Dn369GtZESi2WYWRYdDzvS6YFq2mJaTXzRo8jH7u5RgvR7j4NGm6YziazEetAf5b3YmDAxeWoeyVKcPwyU9";
+        let text = format!(
+            "Exit landed in tx {} after retry {}",
+            signature, short_signature
+        );
+        assert_eq!(redact_text(&text), text);
+
+        let keypair = format!("Loaded keypair: {}", signature);
+        assert!(!redact_text(&keypair).contains(signature));
+
+        let bytes = (0..64).map(|b| b.to_string()).collect::<Vec<_>>().join(",");
+        let keypair_file = format!("wallet.json contains [{}]", bytes);
+        assert_eq!(
+            redact_text(&keypair_file),
+            "wallet.json contains [REDACTED]"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use super::json_repair::{parse_model_json, ParsedModelJson};
 use super::openrouter::get_model_weight;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -9,6 +10,9 @@ pub struct ModelVote {
     pub confidence: f64,
     pub reasoning: String,
     pub latency_ms: u64,
+    /// The model's JSON was malformed and only counted after repair.
+    #[serde(default)]
+    pub json_repaired: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +23,8 @@ pub struct ConsensusResult {
     pub model_votes: Vec<ModelVote>,
     pub reasoning_summary: String,
     pub total_latency_ms: u64,
+    #[serde(default)]
+    pub repaired_votes: usize,
 }
 
 pub struct VotingEngine {
@@ -64,6 +70,7 @@ impl VotingEngine {
                 model_votes: vec![],
                 reasoning_summary: "No votes received".to_string(),
                 total_latency_ms: 0,
+                repaired_votes: 0,
             };
         }
 
@@ -115,6 +122,7 @@ impl VotingEngine {
         let total_latency_ms = votes.iter().map(|v| v.latency_ms).max().unwrap_or(0);
 
         let reasoning_summary = self.summarize_reasoning(&votes, approved);
+        let repaired_votes = votes.iter().filter(|v| v.json_repaired).count();
 
         ConsensusResult {
             approved,
@@ -123,6 +131,7 @@ impl VotingEngine {
             model_votes: votes,
             reasoning_summary,
             total_latency_ms,
+            repaired_votes,
         }
     }
 
@@ -178,16 +187,8 @@ pub struct TradeApprovalVote {
     pub recommended_position_size: Option<f64>,
 }
 
-pub fn parse_trade_approval(content: &str) -> Option<TradeApprovalVote> {
-    let json_start = content.find('{');
-    let json_end = content.rfind('}');
-
-    let json_str = match (json_start, json_end) {
-        (Some(start), Some(end)) => &content[start..=end],
-        _ => return None,
-    };
-
-    serde_json::from_str(json_str).ok()
+pub fn parse_trade_approval(content: &str) -> Option<ParsedModelJson<TradeApprovalVote>> {
+    parse_model_json(content)
 }
 
 pub fn generate_trade_prompt(edge_context: &str) -> String {
@@ -492,16 +493,8 @@ Requirements:
     )
 }
 
-pub fn parse_analysis_response(content: &str) -> Option<AnalysisVote> {
-    let json_start = content.find('{');
-    let json_end = content.rfind('}');
-
-    let json_str = match (json_start, json_end) {
-        (Some(start), Some(end)) if end > start => &content[start..=end],
-        _ => return None,
-    };
-
-    serde_json::from_str(json_str).ok()
+pub fn parse_analysis_response(content: &str) -> Option<ParsedModelJson<AnalysisVote>> {
+    parse_model_json(content)
 }
 
 #[cfg(test)]
@@ -519,6 +512,7 @@ mod tests {
                 confidence: 0.85,
                 reasoning: "Strong opportunity".to_string(),
                 latency_ms: 1200,
+                json_repaired: false,
             },
             ModelVote {
                 model: "openai/gpt-4-turbo".to_string(),
@@ -526,6 +520,7 @@ mod tests {
                 confidence: 0.75,
                 reasoning: "Looks profitable".to_string(),
                 latency_ms: 800,
+                json_repaired: false,
             },
             ModelVote {
                 model: "meta-llama/llama-3.1-70b-instruct".to_string(),
@@ -533,6 +528,7 @@ mod tests {
                 confidence: 0.60,
                 reasoning: "Too risky".to_string(),
                 latency_ms: 600,
+                json_repaired: false,
             },
         ];
 
@@ -541,4 +537,31 @@ mod tests {
         assert!(result.approved);
         assert!(result.agreement_score > 0.5);
     }
+
+    #[test]
+    fn test_malformed_vote_is_repaired_and_counted() {
+        let content = "```json\n{\n  \"approved\": true,\n  \"confidence\": 0.9,\n  \"reasoning\": \"Graduation momentum\",\n  \"risk_assessment\": \"low\",\n  \"recommended_position_size\": null,\n}\n```";
+        let parsed = parse_trade_approval(content).unwrap();
+        assert!(parsed.repaired);
+
+        let votes = vec![ModelVote {
+            model: "openai/gpt-4-turbo".to_string(),
+            approved: parsed.value.approved,
+            confidence: parsed.value.confidence,
+            reasoning: parsed.value.reasoning,
+            latency_ms: 900,
+            json_repaired: parsed.repaired,
+        }];
+        let result = VotingEngine::new(0.5, 0.1).calculate_consensus(votes);
+
+        assert_eq!(result.model_votes.len(), 1);
+        assert_eq!(result.repaired_votes, 1);
+        assert!(result.approved);
+    }
+
+    #[test]
+    fn test_unparseable_vote_is_discarded() {
+        assert!(parse_trade_approval("I would approve this trade with high confidence.").is_none());
+        assert!(parse_trade_approval("{\"approved\": \"maybe\"}").is_none());
+    }
 }
@@ -2,7 +2,7 @@ pub mod repositories;
 
 pub use repositories::{
     CreateTradeRecord, EdgeRepository, PendingExitSignalRow, PositionRepository,
-    SettingsRepository, StrategyRepository, TradeRepository,
+    SettingsRepository, StrategyRepository, ThreatAuditRepository, TradeRepository,
 };
 
 use sqlx::postgres::PgPoolOptions;
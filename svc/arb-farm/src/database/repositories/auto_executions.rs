@@ -0,0 +1,199 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::agents::autonomous_executor::{
+    AutoExecutionFilter, AutoExecutionRecord, AutoExecutionStatus,
+};
+use crate::error::{AppError, AppResult};
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct AutoExecutionRow {
+    pub edge_id: Uuid,
+    pub strategy_id: Uuid,
+    pub mint: String,
+    pub sol_amount_lamports: i64,
+    pub tokens_received: Option<i64>,
+    pub signature: Option<String>,
+    pub status: String,
+    pub attempts: i32,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub error: Option<String>,
+}
+
+impl From<&AutoExecutionRecord> for AutoExecutionRow {
+    fn from(record: &AutoExecutionRecord) -> Self {
+        Self {
+            edge_id: record.edge_id,
+            strategy_id: record.strategy_id,
+            mint: record.mint.clone(),
+            sol_amount_lamports: record.sol_amount_lamports as i64,
+            tokens_received: record.tokens_received.map(|t| t as i64),
+            signature: record.signature.clone(),
+            status: record.status.as_str().to_string(),
+            attempts: record.attempts as i32,
+            started_at: record.started_at,
+            completed_at: record.completed_at,
+            error: record.error.clone(),
+        }
+    }
+}
+
+impl TryFrom<AutoExecutionRow> for AutoExecutionRecord {
+    type Error = AppError;
+
+    fn try_from(row: AutoExecutionRow) -> AppResult<Self> {
+        let status = AutoExecutionStatus::parse(&row.status).ok_or_else(|| {
+            AppError::Database(format!(
+                "Unknown auto-execution status '{}' for edge {}",
+                row.status, row.edge_id
+            ))
+        })?;
+
+        Ok(Self {
+            edge_id: row.edge_id,
+            strategy_id: row.strategy_id,
+            mint: row.mint,
+            sol_amount_lamports: row.sol_amount_lamports.max(0) as u64,
+            tokens_received: row.tokens_received.map(|t| t.max(0) as u64),
+            signature: row.signature,
+            status,
+            attempts: row.attempts.max(0) as u32,
+            started_at: row.started_at,
+            completed_at: row.completed_at,
+            error: row.error,
+        })
+    }
+}
+
+pub struct AutoExecutionRepository {
+    pool: PgPool,
+}
+
+impl AutoExecutionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn upsert(&self, record: &AutoExecutionRecord) -> AppResult<()> {
+        let row = AutoExecutionRow::from(record);
+        sqlx::query(
+            r#"
+            INSERT INTO arb_auto_executions (
+                edge_id, strategy_id, mint, sol_amount_lamports, tokens_received,
+                signature, status, attempts, started_at, completed_at, error
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            ON CONFLICT (edge_id) DO UPDATE SET
+                tokens_received = EXCLUDED.tokens_received,
+                signature = EXCLUDED.signature,
+                status = EXCLUDED.status,
+                attempts = EXCLUDED.attempts,
+                completed_at = EXCLUDED.completed_at,
+                error = EXCLUDED.error
+            "#,
+        )
+        .bind(row.edge_id)
+        .bind(row.strategy_id)
+        .bind(&row.mint)
+        .bind(row.sol_amount_lamports)
+        .bind(row.tokens_received)
+        .bind(&row.signature)
+        .bind(&row.status)
+        .bind(row.attempts)
+        .bind(row.started_at)
+        .bind(row.completed_at)
+        .bind(&row.error)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    pub async fn list(&self, filter: &AutoExecutionFilter) -> AppResult<Vec<AutoExecutionRecord>> {
+        let rows = sqlx::query_as::<_, AutoExecutionRow>(
+            r#"
+            SELECT edge_id, strategy_id, mint, sol_amount_lamports, tokens_received,
+                   signature, status, attempts, started_at, completed_at, error
+            FROM arb_auto_executions
+            WHERE ($1::TEXT IS NULL OR status = $1)
+              AND ($2::UUID IS NULL OR strategy_id = $2)
+              AND ($3::TIMESTAMPTZ IS NULL OR started_at >= $3)
+              AND ($4::TIMESTAMPTZ IS NULL OR started_at < $4)
+            ORDER BY started_at DESC
+            LIMIT $5
+            "#,
+        )
+        .bind(filter.status.map(|s| s.as_str()))
+        .bind(filter.strategy_id)
+        .bind(filter.since)
+        .bind(filter.until)
+        .bind(filter.limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        // One bad row shouldn't hide the rest of the history
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| match AutoExecutionRecord::try_from(row) {
+                Ok(record) => Some(record),
+                Err(e) => {
+                    tracing::warn!(error = %e, "Skipping unreadable auto-execution row");
+                    None
+                }
+            })
+            .collect())
+    }
+
+    pub async fn delete_older_than(&self, cutoff: DateTime<Utc>) -> AppResult<u64> {
+        let result = sqlx::query("DELETE FROM arb_auto_executions WHERE started_at < $1")
+            .bind(cutoff)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(migrations = "./migrations")]
+    #[ignore = "needs DATABASE_URL pointing at a Postgres server"]
+    async fn test_list_skips_rows_with_unknown_status(pool: PgPool) {
+        let repo = AutoExecutionRepository::new(pool.clone());
+        let record = AutoExecutionRecord {
+            edge_id: Uuid::new_v4(),
+            strategy_id: Uuid::new_v4(),
+            mint: "Mint1111".to_string(),
+            sol_amount_lamports: 50_000_000,
+            tokens_received: None,
+            signature: None,
+            status: AutoExecutionStatus::Pending,
+            attempts: 0,
+            started_at: Utc::now(),
+            completed_at: None,
+            error: None,
+        };
+        repo.upsert(&record).await.unwrap();
+        repo.upsert(&AutoExecutionRecord {
+            edge_id: Uuid::new_v4(),
+            ..record.clone()
+        })
+        .await
+        .unwrap();
+        sqlx::query("UPDATE arb_auto_executions SET status = 'exploded' WHERE edge_id <> $1")
+            .bind(record.edge_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let listed = repo.list(&AutoExecutionFilter::default()).await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].edge_id, record.edge_id);
+    }
+}
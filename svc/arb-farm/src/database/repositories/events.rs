@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::error::{AppError, AppResult};
+use crate::events::{source_columns, ArbEvent, EventQuery, EventRow};
+
+pub struct EventLogRepository {
+    pool: PgPool,
+}
+
+impl EventLogRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Events published through `EventBus` are already stored, so duplicates are ignored.
+    pub async fn insert(&self, event: &ArbEvent) -> AppResult<()> {
+        let (source_type, source_id) = source_columns(&event.source);
+
+        sqlx::query(
+            r#"
+            INSERT INTO arb_events (id, event_type, source_type, source_id, topic, payload, correlation_id, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+        )
+        .bind(event.id)
+        .bind(&event.event_type)
+        .bind(source_type)
+        .bind(&source_id)
+        .bind(&event.topic)
+        .bind(&event.payload)
+        .bind(event.correlation_id)
+        .bind(event.timestamp)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    pub async fn query(&self, query: &EventQuery) -> AppResult<Vec<ArbEvent>> {
+        let topic_patterns: Vec<String> = query.topics.iter().map(|t| like_pattern(t)).collect();
+
+        let rows: Vec<EventRow> = sqlx::query_as(
+            r#"
+            SELECT id, event_type, source_type, source_id, topic, payload, correlation_id, created_at
+            FROM arb_events
+            WHERE (cardinality($1::TEXT[]) = 0 OR topic LIKE ANY($1))
+              AND ($2::TIMESTAMPTZ IS NULL OR created_at >= $2)
+              AND ($3::TIMESTAMPTZ IS NULL OR created_at < $3)
+            ORDER BY created_at DESC
+            LIMIT $4
+            "#,
+        )
+        .bind(&topic_patterns)
+        .bind(query.from)
+        .bind(query.to)
+        .bind(query.limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(rows.into_iter().map(ArbEvent::from).collect())
+    }
+
+    pub async fn delete_older_than(&self, cutoff: DateTime<Utc>) -> AppResult<u64> {
+        let result = sqlx::query("DELETE FROM arb_events WHERE created_at < $1")
+            .bind(cutoff)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+/// Translates a topic pattern (`arb.edge.*` or an exact topic) into a LIKE pattern.
+fn like_pattern(pattern: &str) -> String {
+    let (base, wildcard) = match pattern.strip_suffix(".*") {
+        Some(prefix) => (prefix, true),
+        None => (pattern, false),
+    };
+    let escaped = base
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    if wildcard {
+        format!("{}%", escaped)
+    } else {
+        escaped
+    }
+}
@@ -169,6 +169,15 @@ pub struct KolEntityStats {
     pub last_trade_at: Option<DateTime<Utc>>,
 }
 
+/// Copy entries a KOL has deployed since its budget was last reset.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct KolCopySpendRecord {
+    pub entity_id: Uuid,
+    pub spent_sol: Decimal,
+    pub entries: i64,
+    pub last_entry_at: Option<DateTime<Utc>>,
+}
+
 pub struct KolRepository {
     pool: PgPool,
 }
@@ -545,6 +554,48 @@ impl KolRepository {
         Ok(record)
     }
 
+    /// Executed buy copies per KOL since each KOL's last budget reset.
+    pub async fn get_copy_budget_spend(&self) -> AppResult<Vec<KolCopySpendRecord>> {
+        let records = sqlx::query_as::<_, KolCopySpendRecord>(
+            r#"
+            SELECT
+                ct.entity_id,
+                COALESCE(SUM(ct.copy_amount_sol), 0) as spent_sol,
+                COUNT(*) as entries,
+                MAX(ct.created_at) as last_entry_at
+            FROM arb_copy_trades ct
+            JOIN arb_kol_trades kt ON kt.id = ct.kol_trade_id
+            JOIN arb_kol_entities e ON e.id = ct.entity_id
+            WHERE kt.trade_type = 'buy'
+              AND ct.status = 'executed'
+              AND (e.copy_budget_reset_at IS NULL OR ct.created_at > e.copy_budget_reset_at)
+            GROUP BY ct.entity_id
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(records)
+    }
+
+    pub async fn reset_copy_budget(&self, entity_id: Uuid) -> AppResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE arb_kol_entities SET
+                copy_budget_reset_at = NOW(),
+                updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(entity_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
     pub async fn get_copy_history(
         &self,
         entity_id: Uuid,
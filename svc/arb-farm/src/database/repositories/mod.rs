@@ -1,21 +1,33 @@
+pub mod auto_executions;
 pub mod consensus;
 pub mod edges;
+pub mod events;
 pub mod kol;
 pub mod positions;
+pub mod research_sources;
 pub mod settings;
+pub mod sign_audit;
 pub mod strategies;
+pub mod threat_audit;
 pub mod trades;
 
+pub use auto_executions::{AutoExecutionRepository, AutoExecutionRow};
 pub use consensus::{ConsensusRecord, ConsensusRepository, ConsensusStats, CreateConsensusRecord};
 pub use edges::{CreateEdgeRecord, EdgeRecord, EdgeRepository, StatusCount, UpdateEdgeRecord};
+pub use events::EventLogRepository;
 pub use kol::{
     CopyStats, CopyTradeRecord, CreateCopyTradeRecord, CreateKolEntityRecord, CreateKolTradeRecord,
     KolEntityRecord, KolEntityStats, KolRepository, KolTradeRecord, UpdateCopyTradeRecord,
     UpdateKolEntityRecord,
 };
-pub use positions::{PendingExitSignalRow, PnLStats, PositionRepository, PositionRow, RecentTrade};
+pub use positions::{
+    PendingExitSignalRow, PnLStats, PositionRepository, PositionRow, RecentTrade, UsdPnLStats,
+};
+pub use research_sources::{SourceReliabilityRepository, StrategySourceRecord};
 pub use settings::SettingsRepository;
+pub use sign_audit::{SignAuditRecord, SignAuditRepository};
 pub use strategies::{
     CreateStrategyRecord, StrategyRecord, StrategyRepository, StrategyStats, UpdateStrategyRecord,
 };
+pub use threat_audit::{ThreatAuditRecord, ThreatAuditRepository};
 pub use trades::{CreateTradeRecord, DailyStats, TradeRecord, TradeRepository, TradeStats};
@@ -5,9 +5,12 @@ use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::error::{AppError, AppResult};
+use crate::execution::break_even::RoundTripCosts;
 use crate::execution::position_manager::{
-    BaseCurrency, ExitConfig, ExitMode, MomentumData, OpenPosition, PartialExit, PositionStatus,
+    BaseCurrency, ExitConfig, ExitConfigTemplate, ExitMode, MomentumData, OpenPosition,
+    PartialExit, PositionStatus,
 };
+use crate::execution::scale_in::ScaleInPlan;
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct PositionRow {
@@ -38,8 +41,15 @@ pub struct PositionRow {
     pub remaining_token_amount: Option<Decimal>,
     pub is_inferred_exit: bool,
     pub auto_exit_enabled: bool,
+    pub auto_exit_overridden: bool,
+    pub entry_sol_usd_rate: Option<Decimal>,
+    pub exit_sol_usd_rate: Option<Decimal>,
     pub signal_source: Option<String>,
     pub venue: Option<String>,
+    pub notes: Option<String>,
+    pub tags: Vec<String>,
+    pub scale_in: Option<serde_json::Value>,
+    pub round_trip_costs: Option<serde_json::Value>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -55,6 +65,8 @@ impl From<PositionRow> for OpenPosition {
         let status = match row.status.as_str() {
             "open" => PositionStatus::Open,
             "pending_exit" => PositionStatus::PendingExit,
+            // Nothing is tracking the exit after a restart; let the pending-exit retry verify it
+            "settling" => PositionStatus::PendingExit,
             "partially_exited" => PositionStatus::PartiallyExited,
             "closed" => PositionStatus::Closed,
             "failed" => PositionStatus::Failed,
@@ -99,6 +111,18 @@ impl From<PositionRow> for OpenPosition {
             venue: row.venue,
             signal_source: row.signal_source,
             auto_exit_enabled: row.auto_exit_enabled,
+            auto_exit_overridden: row.auto_exit_overridden,
+            entry_sol_usd_rate: row.entry_sol_usd_rate.map(decimal_to_f64),
+            exit_sol_usd_rate: row.exit_sol_usd_rate.map(decimal_to_f64),
+            round_trip_costs: row
+                .round_trip_costs
+                .and_then(|v| serde_json::from_value::<RoundTripCosts>(v).ok())
+                .unwrap_or_default(),
+            notes: row.notes,
+            tags: row.tags,
+            scale_in: row
+                .scale_in
+                .and_then(|v| serde_json::from_value::<ScaleInPlan>(v).ok()),
         }
     }
 }
@@ -147,6 +171,7 @@ impl PositionRepository {
         let status = match position.status {
             PositionStatus::Open => "open",
             PositionStatus::PendingExit => "pending_exit",
+            PositionStatus::Settling => "settling",
             PositionStatus::PartiallyExited => "partially_exited",
             PositionStatus::Closed => "closed",
             PositionStatus::Failed => "failed",
@@ -159,14 +184,17 @@ impl PositionRepository {
         let partial_exits_json = serde_json::to_value(&position.partial_exits)
             .map_err(|e| AppError::Database(e.to_string()))?;
 
+        let round_trip_costs_json = serde_json::to_value(position.round_trip_costs)
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
         sqlx::query(r#"
             INSERT INTO arb_positions (
                 id, edge_id, strategy_id, token_mint, token_symbol,
                 entry_amount_base, entry_token_amount, entry_price, entry_time, entry_tx_signature,
                 current_price, current_value_base, unrealized_pnl, unrealized_pnl_percent, high_water_mark,
                 exit_config, partial_exits, status, remaining_amount_base, remaining_token_amount, auto_exit_enabled,
-                signal_source, venue
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23)
+                signal_source, venue, auto_exit_overridden, entry_sol_usd_rate, round_trip_costs
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26)
             ON CONFLICT (id) DO UPDATE SET
                 current_price = EXCLUDED.current_price,
                 current_value_base = EXCLUDED.current_value_base,
@@ -178,6 +206,7 @@ impl PositionRepository {
                 remaining_amount_base = EXCLUDED.remaining_amount_base,
                 remaining_token_amount = EXCLUDED.remaining_token_amount,
                 auto_exit_enabled = EXCLUDED.auto_exit_enabled,
+                auto_exit_overridden = EXCLUDED.auto_exit_overridden,
                 updated_at = NOW()
         "#)
             .bind(position.id)
@@ -203,6 +232,9 @@ impl PositionRepository {
             .bind(position.auto_exit_enabled)
             .bind(&position.signal_source)
             .bind(&position.venue)
+            .bind(position.auto_exit_overridden)
+            .bind(position.entry_sol_usd_rate.map(f64_to_decimal))
+            .bind(round_trip_costs_json)
             .execute(&self.pool)
             .await
             .map_err(|e| AppError::Database(e.to_string()))?;
@@ -218,6 +250,7 @@ impl PositionRepository {
         exit_reason: &str,
         exit_tx_signature: Option<&str>,
         momentum_at_exit: Option<f64>,
+        exit_sol_usd_rate: Option<f64>,
     ) -> AppResult<()> {
         // Detect inferred exits - these are exits where we detected tokens left wallet
         // but didn't capture the actual on-chain transaction signature
@@ -245,6 +278,7 @@ impl PositionRepository {
                 exit_reason = $5,
                 is_inferred_exit = $6,
                 momentum_at_exit = $7,
+                exit_sol_usd_rate = $8,
                 updated_at = NOW()
             WHERE id = $1 AND status != 'closed'
         "#,
@@ -256,6 +290,7 @@ impl PositionRepository {
         .bind(exit_reason)
         .bind(is_inferred)
         .bind(momentum_at_exit.map(f64_to_decimal))
+        .bind(exit_sol_usd_rate.map(f64_to_decimal))
         .execute(&self.pool)
         .await
         .map_err(|e| AppError::Database(e.to_string()))?;
@@ -272,7 +307,7 @@ impl PositionRepository {
 
     pub async fn get_open_positions(&self) -> AppResult<Vec<OpenPosition>> {
         let rows: Vec<PositionRow> = sqlx::query_as(
-            "SELECT * FROM arb_positions WHERE status IN ('open', 'pending_exit', 'partially_exited') ORDER BY entry_time DESC"
+            "SELECT * FROM arb_positions WHERE status IN ('open', 'pending_exit', 'settling', 'partially_exited') ORDER BY entry_time DESC"
         )
             .fetch_all(&self.pool)
             .await
@@ -283,7 +318,7 @@ impl PositionRepository {
 
     pub async fn get_open_by_mint(&self, token_mint: &str) -> AppResult<Option<OpenPosition>> {
         let row: Option<PositionRow> = sqlx::query_as(
-            "SELECT * FROM arb_positions WHERE token_mint = $1 AND status IN ('open', 'pending_exit', 'partially_exited') ORDER BY entry_time DESC LIMIT 1"
+            "SELECT * FROM arb_positions WHERE token_mint = $1 AND status IN ('open', 'pending_exit', 'settling', 'partially_exited') ORDER BY entry_time DESC LIMIT 1"
         )
             .bind(token_mint)
             .fetch_optional(&self.pool)
@@ -304,6 +339,24 @@ impl PositionRepository {
         Ok(rows)
     }
 
+    /// Newest positions first, optionally only those carrying `tag`.
+    pub async fn get_position_history(
+        &self,
+        limit: i32,
+        tag: Option<&str>,
+    ) -> AppResult<Vec<PositionRow>> {
+        let rows: Vec<PositionRow> = sqlx::query_as(
+            "SELECT * FROM arb_positions WHERE ($2::text IS NULL OR $2 = ANY(tags)) ORDER BY created_at DESC LIMIT $1",
+        )
+        .bind(limit)
+        .bind(tag)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(rows)
+    }
+
     pub async fn get_by_edge_id(&self, edge_id: Uuid) -> AppResult<Option<PositionRow>> {
         let row: Option<PositionRow> = sqlx::query_as(
             "SELECT * FROM arb_positions WHERE edge_id = $1 ORDER BY created_at DESC LIMIT 1",
@@ -328,7 +381,7 @@ impl PositionRepository {
 
     pub async fn get_positions_by_mint(&self, token_mint: &str) -> AppResult<Vec<OpenPosition>> {
         let rows: Vec<PositionRow> = sqlx::query_as(
-            "SELECT * FROM arb_positions WHERE token_mint = $1 AND status IN ('open', 'pending_exit', 'partially_exited') ORDER BY entry_time DESC"
+            "SELECT * FROM arb_positions WHERE token_mint = $1 AND status IN ('open', 'pending_exit', 'settling', 'partially_exited') ORDER BY entry_time DESC"
         )
             .bind(token_mint)
             .fetch_all(&self.pool)
@@ -385,6 +438,25 @@ impl PositionRepository {
         Ok(rows.into_iter().map(|r| r.into()).collect())
     }
 
+    /// Latest losing exit per mint since `since`, for rebuilding the re-entry cooldown.
+    pub async fn get_losing_exits_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<(String, DateTime<Utc>)>> {
+        sqlx::query_as(
+            r#"
+            SELECT token_mint, MAX(exit_time)
+            FROM arb_positions
+            WHERE status = 'closed' AND realized_pnl < 0 AND exit_time >= $1
+            GROUP BY token_mint
+            "#,
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))
+    }
+
     pub async fn update_status(&self, position_id: Uuid, status: &str) -> AppResult<()> {
         sqlx::query(
             r#"
@@ -412,6 +484,7 @@ impl PositionRepository {
             r#"
             UPDATE arb_positions
             SET auto_exit_enabled = $2,
+                auto_exit_overridden = true,
                 updated_at = NOW()
             WHERE id = $1
         "#,
@@ -425,6 +498,117 @@ impl PositionRepository {
         Ok(())
     }
 
+    /// Returns false when no position has this id.
+    pub async fn update_annotations(
+        &self,
+        position_id: Uuid,
+        notes: Option<&str>,
+        tags: &[String],
+    ) -> AppResult<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE arb_positions
+            SET notes = $2,
+                tags = $3,
+                updated_at = NOW()
+            WHERE id = $1
+        "#,
+        )
+        .bind(position_id)
+        .bind(notes)
+        .bind(tags)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn update_scale_in(
+        &self,
+        position_id: Uuid,
+        plan: Option<&ScaleInPlan>,
+    ) -> AppResult<()> {
+        let plan_json = plan
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            UPDATE arb_positions
+            SET scale_in = $2,
+                updated_at = NOW()
+            WHERE id = $1
+        "#,
+        )
+        .bind(position_id)
+        .bind(plan_json)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Persists the larger size and averaged entry after a scale-in buy.
+    pub async fn record_scale_in(&self, position: &OpenPosition) -> AppResult<()> {
+        let plan_json = position
+            .scale_in
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| AppError::Database(e.to_string()))?;
+        let round_trip_costs_json = serde_json::to_value(position.round_trip_costs)
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            UPDATE arb_positions
+            SET entry_amount_base = $2,
+                entry_token_amount = $3,
+                entry_price = $4,
+                remaining_amount_base = $5,
+                remaining_token_amount = $6,
+                scale_in = $7,
+                round_trip_costs = $8,
+                updated_at = NOW()
+            WHERE id = $1
+        "#,
+        )
+        .bind(position.id)
+        .bind(f64_to_decimal(position.entry_amount_base))
+        .bind(f64_to_decimal(position.entry_token_amount))
+        .bind(f64_to_decimal(position.entry_price))
+        .bind(f64_to_decimal(position.remaining_amount_base))
+        .bind(f64_to_decimal(position.remaining_token_amount))
+        .bind(plan_json)
+        .bind(round_trip_costs_json)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    pub async fn clear_auto_exit_override(&self, position_id: Uuid) -> AppResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE arb_positions
+            SET auto_exit_enabled = true,
+                auto_exit_overridden = false,
+                updated_at = NOW()
+            WHERE id = $1
+        "#,
+        )
+        .bind(position_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
     pub async fn get_pnl_stats(&self) -> AppResult<PnLStats> {
         self.get_pnl_stats_since(None).await
     }
@@ -458,7 +642,7 @@ impl PositionRepository {
             if reason.starts_with("TakeProfit") {
                 stats.take_profits += cnt;
                 stats.take_profit_pnl += pnl;
-            } else if reason.starts_with("StopLoss") {
+            } else if reason.starts_with("StopLoss") || reason.starts_with("BreakevenStop") {
                 stats.stop_losses += cnt;
                 stats.stop_loss_pnl += pnl;
             } else if reason.starts_with("Manual") {
@@ -575,12 +759,17 @@ impl PositionRepository {
             momentum_at_exit: Option<Decimal>,
             entry_tx_signature: Option<String>,
             exit_tx_signature: Option<String>,
+            entry_sol_usd_rate: Option<Decimal>,
+            exit_sol_usd_rate: Option<Decimal>,
+            base_currency: Option<String>,
         }
 
         let rows: Vec<TradeRow> = sqlx::query_as(
             r#"SELECT id, token_symbol, token_mint, realized_pnl, exit_reason,
                       entry_time, exit_time, entry_amount_base, entry_price, exit_price,
-                      momentum_at_exit, entry_tx_signature, exit_tx_signature
+                      momentum_at_exit, entry_tx_signature, exit_tx_signature,
+                      entry_sol_usd_rate, exit_sol_usd_rate,
+                      exit_config->>'base_currency' AS base_currency
                FROM arb_positions
                WHERE status = 'closed' AND exit_time IS NOT NULL
                ORDER BY exit_time DESC
@@ -613,11 +802,55 @@ impl PositionRepository {
                     entry_time: Some(r.entry_time),
                     entry_tx_signature: r.entry_tx_signature,
                     exit_tx_signature: r.exit_tx_signature,
+                    entry_sol_usd_rate: r.entry_sol_usd_rate.map(decimal_to_f64),
+                    exit_sol_usd_rate: r.exit_sol_usd_rate.map(decimal_to_f64),
+                    base_currency: r
+                        .base_currency
+                        .and_then(|c| serde_json::from_value(serde_json::Value::String(c)).ok())
+                        .unwrap_or(BaseCurrency::Sol),
                 }
             })
             .collect())
     }
 
+    /// Realized P&L in USD using the SOL/USD rates stamped at entry and exit.
+    /// Mirrors `BaseCurrency::pnl_usd`: only SOL trades are converted, stablecoin P&L is
+    /// taken as USD. SOL trades with neither rate are counted as unpriced.
+    pub async fn get_usd_pnl_stats_since(
+        &self,
+        since: Option<DateTime<Utc>>,
+    ) -> AppResult<UsdPnLStats> {
+        let row: (Option<Decimal>, Option<Decimal>, Option<Decimal>, i64) = sqlx::query_as(
+            r#"WITH priced AS (
+                   SELECT exit_time,
+                          CASE WHEN COALESCE(exit_config->>'base_currency', 'sol') = 'sol'
+                               THEN (entry_amount_base + realized_pnl) * COALESCE(exit_sol_usd_rate, entry_sol_usd_rate)
+                                      - entry_amount_base * COALESCE(entry_sol_usd_rate, exit_sol_usd_rate)
+                               ELSE realized_pnl
+                          END AS pnl_usd
+                   FROM arb_positions
+                   WHERE status = 'closed' AND realized_pnl IS NOT NULL
+                     AND ($1::timestamptz IS NULL OR exit_time >= $1)
+               )
+               SELECT SUM(pnl_usd),
+                      SUM(pnl_usd) FILTER (WHERE exit_time >= CURRENT_DATE),
+                      SUM(pnl_usd) FILTER (WHERE exit_time >= CURRENT_DATE - INTERVAL '7 days'),
+                      COUNT(*) FILTER (WHERE pnl_usd IS NULL)
+               FROM priced"#,
+        )
+        .bind(since)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(UsdPnLStats {
+            total_pnl_usd: row.0.map(decimal_to_f64).unwrap_or(0.0),
+            today_pnl_usd: row.1.map(decimal_to_f64).unwrap_or(0.0),
+            week_pnl_usd: row.2.map(decimal_to_f64).unwrap_or(0.0),
+            unpriced_trades: row.3 as u32,
+        })
+    }
+
     pub async fn get_closed_positions_for_period(
         &self,
         start: DateTime<Utc>,
@@ -889,6 +1122,94 @@ impl PositionRepository {
 
         Ok(())
     }
+
+    // ========== EXIT CONFIG TEMPLATES ==========
+
+    /// Inserts or replaces a template by name in one statement. An existing row keeps
+    /// its `created_at`; the stored timestamps are returned.
+    pub async fn upsert_exit_template(
+        &self,
+        template: &ExitConfigTemplate,
+    ) -> AppResult<ExitConfigTemplate> {
+        let config = serde_json::to_value(&template.config)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize exit config: {}", e)))?;
+
+        let (created_at, updated_at): (DateTime<Utc>, DateTime<Utc>) = sqlx::query_as(
+            r#"
+            INSERT INTO arb_exit_config_templates (name, description, config, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $4)
+            ON CONFLICT (name) DO UPDATE SET
+                description = EXCLUDED.description,
+                config = EXCLUDED.config,
+                updated_at = EXCLUDED.updated_at
+            RETURNING created_at, updated_at
+        "#,
+        )
+        .bind(&template.name)
+        .bind(&template.description)
+        .bind(config)
+        .bind(template.updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(ExitConfigTemplate {
+            created_at,
+            updated_at,
+            ..template.clone()
+        })
+    }
+
+    pub async fn get_exit_templates(&self) -> AppResult<Vec<ExitConfigTemplate>> {
+        #[derive(sqlx::FromRow)]
+        struct Row {
+            name: String,
+            description: Option<String>,
+            config: serde_json::Value,
+            created_at: DateTime<Utc>,
+            updated_at: DateTime<Utc>,
+        }
+
+        let rows: Vec<Row> = sqlx::query_as(
+            r#"SELECT name, description, config, created_at, updated_at
+               FROM arb_exit_config_templates
+               ORDER BY name ASC"#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|r| match serde_json::from_value::<ExitConfig>(r.config) {
+                Ok(config) => Some(ExitConfigTemplate {
+                    name: r.name,
+                    description: r.description,
+                    config,
+                    created_at: r.created_at,
+                    updated_at: r.updated_at,
+                }),
+                Err(e) => {
+                    tracing::warn!(
+                        template = %r.name,
+                        error = %e,
+                        "Skipping exit config template with unreadable config"
+                    );
+                    None
+                }
+            })
+            .collect())
+    }
+
+    pub async fn delete_exit_template(&self, name: &str) -> AppResult<()> {
+        sqlx::query("DELETE FROM arb_exit_config_templates WHERE name = $1")
+            .bind(name)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -962,4 +1283,222 @@ pub struct RecentTrade {
     pub entry_time: Option<DateTime<Utc>>,
     pub entry_tx_signature: Option<String>,
     pub exit_tx_signature: Option<String>,
+    pub entry_sol_usd_rate: Option<f64>,
+    pub exit_sol_usd_rate: Option<f64>,
+    pub base_currency: BaseCurrency,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UsdPnLStats {
+    pub total_pnl_usd: f64,
+    pub today_pnl_usd: f64,
+    pub week_pnl_usd: f64,
+    pub unpriced_trades: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::position_manager::{ExitConfig, PositionManager};
+    use std::sync::Arc;
+
+    #[sqlx::test(migrations = "./migrations")]
+    #[ignore = "needs DATABASE_URL pointing at a Postgres server"]
+    async fn test_position_history_filters_by_tag(pool: PgPool) {
+        let repo = Arc::new(PositionRepository::new(pool));
+        let manager = PositionManager::with_repository(repo.clone());
+
+        let mut ids = Vec::new();
+        for mint in ["TagMintA1111111111", "TagMintB1111111111"] {
+            let position = manager
+                .open_position(
+                    Uuid::new_v4(),
+                    Uuid::new_v4(),
+                    mint.to_string(),
+                    None,
+                    0.5,
+                    1_000_000.0,
+                    0.0000005,
+                    ExitConfig::default(),
+                    None,
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+            ids.push(position.id);
+        }
+        manager
+            .annotate_position(
+                ids[0],
+                None,
+                Some(vec!["kol-copy".to_string(), "experiment".to_string()]),
+            )
+            .await
+            .unwrap();
+
+        let tagged = repo
+            .get_position_history(10, Some("kol-copy"))
+            .await
+            .unwrap();
+        assert_eq!(tagged.len(), 1);
+        assert_eq!(tagged[0].id, ids[0]);
+
+        assert_eq!(repo.get_position_history(10, None).await.unwrap().len(), 2);
+        assert!(repo
+            .get_position_history(10, Some("kol"))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    #[ignore = "needs DATABASE_URL pointing at a Postgres server"]
+    async fn test_reentry_cooldown_survives_restart(pool: PgPool) {
+        let repo = Arc::new(PositionRepository::new(pool));
+        let manager = PositionManager::with_repository(repo.clone());
+
+        for (mint, exit_price, pnl) in [
+            ("CooldownLoser11111", 0.0000004, -0.1),
+            ("CooldownWinner1111", 0.0000006, 0.1),
+        ] {
+            let position = manager
+                .open_position(
+                    Uuid::new_v4(),
+                    Uuid::new_v4(),
+                    mint.to_string(),
+                    None,
+                    0.5,
+                    1_000_000.0,
+                    0.0000005,
+                    ExitConfig::default(),
+                    None,
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+            manager
+                .close_position(position.id, exit_price, pnl, "StopLoss", None, None)
+                .await
+                .unwrap();
+        }
+
+        let restarted = PositionManager::with_repository(repo);
+        assert_eq!(restarted.load_losing_exits_from_db().await.unwrap(), 1);
+        assert!(restarted
+            .reentry_cooldown_remaining("CooldownLoser11111", 900)
+            .await
+            .is_some());
+        assert_eq!(
+            restarted
+                .reentry_cooldown_remaining("CooldownWinner1111", 900)
+                .await,
+            None
+        );
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    #[ignore = "needs DATABASE_URL pointing at a Postgres server"]
+    async fn test_exit_template_upsert_keeps_created_at(pool: PgPool) {
+        let repo = PositionRepository::new(pool.clone());
+
+        let first = repo
+            .upsert_exit_template(&ExitConfigTemplate::new(
+                "swing",
+                None,
+                ExitConfig::default(),
+            ))
+            .await
+            .unwrap();
+        let replaced = repo
+            .upsert_exit_template(&ExitConfigTemplate::new(
+                "swing",
+                Some("Longer hold".to_string()),
+                ExitConfig {
+                    time_limit_minutes: Some(240),
+                    ..ExitConfig::default()
+                },
+            ))
+            .await
+            .unwrap();
+        assert_eq!(replaced.created_at, first.created_at);
+        assert!(replaced.updated_at >= first.updated_at);
+
+        sqlx::query(
+            "INSERT INTO arb_exit_config_templates (name, config) VALUES ('broken', '\"nope\"'::jsonb)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let templates = repo.get_exit_templates().await.unwrap();
+        assert_eq!(templates.len(), 1);
+        assert_eq!(templates[0].description.as_deref(), Some("Longer hold"));
+        assert_eq!(templates[0].config.time_limit_minutes, Some(240));
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    #[ignore = "needs DATABASE_URL pointing at a Postgres server"]
+    async fn test_usd_pnl_converts_only_sol_positions(pool: PgPool) {
+        let repo = Arc::new(PositionRepository::new(pool.clone()));
+        let manager = PositionManager::with_repository(repo.clone());
+
+        let mut ids = Vec::new();
+        for (mint, base_currency, entry_amount) in [
+            ("UsdPnlSolMint11111", BaseCurrency::Sol, 1.0),
+            ("UsdPnlUsdcMint1111", BaseCurrency::Usdc, 100.0),
+        ] {
+            let position = manager
+                .open_position(
+                    Uuid::new_v4(),
+                    Uuid::new_v4(),
+                    mint.to_string(),
+                    None,
+                    entry_amount,
+                    1_000_000.0,
+                    entry_amount / 1_000_000.0,
+                    ExitConfig {
+                        base_currency,
+                        ..ExitConfig::default()
+                    },
+                    None,
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+            ids.push(position.id);
+        }
+        sqlx::query("UPDATE arb_positions SET entry_sol_usd_rate = 100")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        // +0.1 SOL while SOL went $100 -> $150, and +10 USDC
+        repo.close_position(
+            ids[0],
+            0.0000011,
+            0.1,
+            "TakeProfit",
+            None,
+            None,
+            Some(150.0),
+        )
+        .await
+        .unwrap();
+        repo.close_position(ids[1], 0.00011, 10.0, "TakeProfit", None, None, Some(150.0))
+            .await
+            .unwrap();
+
+        let stats = repo.get_usd_pnl_stats_since(None).await.unwrap();
+        assert!((stats.total_pnl_usd - 75.0).abs() < 1e-6);
+        assert_eq!(stats.unpriced_trades, 0);
+
+        let trades = repo.get_recent_trades(10).await.unwrap();
+        let usdc = trades.iter().find(|t| t.id == ids[1].to_string()).unwrap();
+        assert_eq!(usdc.base_currency, BaseCurrency::Usdc);
+        let sol = trades.iter().find(|t| t.id == ids[0].to_string()).unwrap();
+        assert_eq!(sol.base_currency, BaseCurrency::Sol);
+    }
 }
@@ -0,0 +1,149 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::research::social_monitor::SourceReliability;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct SourceReliabilityRow {
+    approved: i32,
+    rejected: i32,
+    profitable_strategies: i32,
+    losing_strategies: i32,
+    strategy_pnl_sol: f64,
+    score: f64,
+    auto_muted: bool,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<SourceReliabilityRow> for SourceReliability {
+    fn from(row: SourceReliabilityRow) -> Self {
+        Self {
+            approved: row.approved.max(0) as u32,
+            rejected: row.rejected.max(0) as u32,
+            profitable_strategies: row.profitable_strategies.max(0) as u32,
+            losing_strategies: row.losing_strategies.max(0) as u32,
+            strategy_pnl_sol: row.strategy_pnl_sol,
+            score: row.score,
+            auto_muted: row.auto_muted,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct StrategySourceRecord {
+    pub strategy_id: Uuid,
+    pub source_type: String,
+    pub handle: String,
+    pub discovery_id: Option<Uuid>,
+}
+
+pub struct SourceReliabilityRepository {
+    pool: PgPool,
+}
+
+impl SourceReliabilityRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn get(
+        &self,
+        source_type: &str,
+        handle: &str,
+    ) -> AppResult<Option<SourceReliability>> {
+        let row = sqlx::query_as::<_, SourceReliabilityRow>(
+            r#"
+            SELECT approved, rejected, profitable_strategies, losing_strategies,
+                   strategy_pnl_sol, score, auto_muted, updated_at
+            FROM arb_research_source_reliability
+            WHERE source_type = $1 AND handle = $2
+            "#,
+        )
+        .bind(source_type)
+        .bind(handle)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(row.map(SourceReliability::from))
+    }
+
+    pub async fn upsert(
+        &self,
+        source_type: &str,
+        handle: &str,
+        reliability: &SourceReliability,
+    ) -> AppResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO arb_research_source_reliability (
+                source_type, handle, approved, rejected, profitable_strategies,
+                losing_strategies, strategy_pnl_sol, score, auto_muted, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (source_type, handle) DO UPDATE SET
+                approved = EXCLUDED.approved,
+                rejected = EXCLUDED.rejected,
+                profitable_strategies = EXCLUDED.profitable_strategies,
+                losing_strategies = EXCLUDED.losing_strategies,
+                strategy_pnl_sol = EXCLUDED.strategy_pnl_sol,
+                score = EXCLUDED.score,
+                auto_muted = EXCLUDED.auto_muted,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(source_type)
+        .bind(handle)
+        .bind(reliability.approved as i32)
+        .bind(reliability.rejected as i32)
+        .bind(reliability.profitable_strategies as i32)
+        .bind(reliability.losing_strategies as i32)
+        .bind(reliability.strategy_pnl_sol)
+        .bind(reliability.score)
+        .bind(reliability.auto_muted)
+        .bind(reliability.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    pub async fn attribute_strategy(&self, record: &StrategySourceRecord) -> AppResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO arb_research_strategy_sources (strategy_id, source_type, handle, discovery_id)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (strategy_id) DO UPDATE SET
+                source_type = EXCLUDED.source_type,
+                handle = EXCLUDED.handle,
+                discovery_id = EXCLUDED.discovery_id
+            "#,
+        )
+        .bind(record.strategy_id)
+        .bind(&record.source_type)
+        .bind(&record.handle)
+        .bind(record.discovery_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    pub async fn list_strategy_sources(&self) -> AppResult<Vec<StrategySourceRecord>> {
+        sqlx::query_as::<_, StrategySourceRecord>(
+            r#"
+            SELECT strategy_id, source_type, handle, discovery_id
+            FROM arb_research_strategy_sources
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))
+    }
+}
@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::wallet::sign_audit::SignAuditEntry;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct SignAuditRecord {
+    pub id: Uuid,
+    pub signer: String,
+    pub description: String,
+    pub edge_id: Option<Uuid>,
+    pub amount_lamports: i64,
+    pub estimated_profit_lamports: Option<i64>,
+    pub summary: serde_json::Value,
+    pub outcome: String,
+    pub policy_passed: bool,
+    pub policy_violation: Option<serde_json::Value>,
+    pub signature: Option<String>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+pub struct SignAuditRepository {
+    pool: PgPool,
+}
+
+impl SignAuditRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn insert(&self, entry: &SignAuditEntry) -> AppResult<()> {
+        let summary = serde_json::to_value(&entry.summary)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize summary: {}", e)))?;
+        let violation = entry
+            .policy_violation
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| AppError::Internal(format!("Failed to serialize violation: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO arb_wallet_sign_audit_log (
+                id, signer, description, edge_id, amount_lamports, estimated_profit_lamports,
+                summary, outcome, policy_passed, policy_violation, signature, error, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            "#,
+        )
+        .bind(entry.id)
+        .bind(&entry.signer)
+        .bind(&entry.description)
+        .bind(entry.edge_id)
+        .bind(entry.amount_lamports as i64)
+        .bind(entry.estimated_profit_lamports)
+        .bind(summary)
+        .bind(entry.outcome.as_str())
+        .bind(entry.policy_passed)
+        .bind(violation)
+        .bind(&entry.signature)
+        .bind(&entry.error)
+        .bind(entry.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    pub async fn list(
+        &self,
+        signer: Option<&str>,
+        outcome: Option<&str>,
+        limit: i64,
+    ) -> AppResult<Vec<SignAuditRecord>> {
+        sqlx::query_as::<_, SignAuditRecord>(
+            r#"
+            SELECT id, signer, description, edge_id, amount_lamports, estimated_profit_lamports,
+                   summary, outcome, policy_passed, policy_violation, signature, error, created_at
+            FROM arb_wallet_sign_audit_log
+            WHERE ($1::TEXT IS NULL OR signer = $1)
+              AND ($2::TEXT IS NULL OR outcome = $2)
+            ORDER BY created_at DESC
+            LIMIT $3
+            "#,
+        )
+        .bind(signer)
+        .bind(outcome)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))
+    }
+}
@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::ThreatAuditEntry;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ThreatAuditRecord {
+    pub id: Uuid,
+    pub action: String,
+    pub entity_type: Option<String>,
+    pub address: String,
+    pub actor: String,
+    pub reason: Option<String>,
+    pub prior_score: Option<f64>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ThreatAuditEntry> for ThreatAuditRecord {
+    fn from(entry: ThreatAuditEntry) -> Self {
+        Self {
+            id: entry.id,
+            action: entry.action.as_str().to_string(),
+            entity_type: entry.entity_type.map(|t| t.as_str().to_string()),
+            address: entry.address,
+            actor: entry.actor,
+            reason: entry.reason,
+            prior_score: entry.prior_score,
+            created_at: entry.created_at,
+        }
+    }
+}
+
+pub struct ThreatAuditRepository {
+    pool: PgPool,
+}
+
+impl ThreatAuditRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn insert(&self, entry: &ThreatAuditEntry) -> AppResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO arb_threat_audit_log (
+                id, action, entity_type, address, actor, reason, prior_score, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(entry.id)
+        .bind(entry.action.as_str())
+        .bind(entry.entity_type.as_ref().map(|t| t.as_str()))
+        .bind(&entry.address)
+        .bind(&entry.actor)
+        .bind(&entry.reason)
+        .bind(entry.prior_score)
+        .bind(entry.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    pub async fn list(
+        &self,
+        address: Option<&str>,
+        action: Option<&str>,
+        limit: i64,
+    ) -> AppResult<Vec<ThreatAuditRecord>> {
+        sqlx::query_as::<_, ThreatAuditRecord>(
+            r#"
+            SELECT id, action, entity_type, address, actor, reason, prior_score, created_at
+            FROM arb_threat_audit_log
+            WHERE ($1::TEXT IS NULL OR address = $1)
+              AND ($2::TEXT IS NULL OR action = $2)
+            ORDER BY created_at DESC
+            LIMIT $3
+            "#,
+        )
+        .bind(address)
+        .bind(action)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))
+    }
+}
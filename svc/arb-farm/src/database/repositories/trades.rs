@@ -2,6 +2,7 @@ use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use std::collections::HashMap;
 use uuid::Uuid;
 
 use crate::error::{AppError, AppResult};
@@ -158,6 +159,24 @@ impl TradeRepository {
         Ok(records)
     }
 
+    /// Net P&L per strategy for trades executed since `since`.
+    pub async fn get_pnl_by_strategy(&self, since: DateTime<Utc>) -> AppResult<HashMap<Uuid, i64>> {
+        let rows: Vec<(Uuid, i64)> = sqlx::query_as(
+            r#"
+            SELECT strategy_id, COALESCE(SUM(profit_lamports), 0)::BIGINT
+            FROM arb_trades
+            WHERE strategy_id IS NOT NULL AND executed_at >= $1
+            GROUP BY strategy_id
+            "#,
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(rows.into_iter().collect())
+    }
+
     pub async fn get_stats(&self, period_days: Option<i32>) -> AppResult<TradeStats> {
         let period_clause = if let Some(days) = period_days {
             format!("WHERE executed_at > NOW() - INTERVAL '{} days'", days)
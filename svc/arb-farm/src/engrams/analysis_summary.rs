@@ -0,0 +1,262 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::future::Future;
+use tokio::sync::RwLock;
+
+use super::schemas::{StoredPatternSummary, TradeAnalysis};
+
+pub const RECENT_TRADE_ANALYSES: usize = 10;
+/// Analyses fetched for a full recompute.
+pub const ANALYSIS_SUMMARY_FETCH_LIMIT: i64 = 1000;
+/// A cached summary is rebuilt from engrams after this long, dropping any drift from
+/// analyses written or removed outside this process.
+pub const ANALYSIS_SUMMARY_RESYNC_SECS: i64 = 600;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct AnalysisSummary {
+    pub trade_analyses_count: usize,
+    pub latest_pattern_summary: Option<StoredPatternSummary>,
+    /// Newest first, at most `RECENT_TRADE_ANALYSES`.
+    pub recent_trade_analyses: Vec<TradeAnalysis>,
+}
+
+impl AnalysisSummary {
+    /// Full recompute over every stored analysis.
+    pub fn from_parts(
+        mut analyses: Vec<TradeAnalysis>,
+        latest_pattern_summary: Option<StoredPatternSummary>,
+    ) -> Self {
+        let trade_analyses_count = analyses.len();
+        analyses.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        analyses.truncate(RECENT_TRADE_ANALYSES);
+
+        Self {
+            trade_analyses_count,
+            latest_pattern_summary,
+            recent_trade_analyses: analyses,
+        }
+    }
+
+    pub fn add_trade_analysis(&mut self, analysis: TradeAnalysis) {
+        self.trade_analyses_count += 1;
+        let index = self
+            .recent_trade_analyses
+            .iter()
+            .position(|a| a.created_at < analysis.created_at)
+            .unwrap_or(self.recent_trade_analyses.len());
+        self.recent_trade_analyses.insert(index, analysis);
+        self.recent_trade_analyses.truncate(RECENT_TRADE_ANALYSES);
+    }
+
+    pub fn set_pattern_summary(&mut self, summary: StoredPatternSummary) {
+        let is_newer = self
+            .latest_pattern_summary
+            .as_ref()
+            .map_or(true, |s| s.created_at <= summary.created_at);
+        if is_newer {
+            self.latest_pattern_summary = Some(summary);
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct WalletSummary {
+    summary: Option<(AnalysisSummary, DateTime<Utc>)>,
+    /// Bumped on every write so a recompute that raced one is not cached.
+    generation: u64,
+}
+
+/// Per-wallet analysis summaries, kept current as analyses are stored instead of being
+/// recomputed on every read.
+#[derive(Debug, Default)]
+pub struct AnalysisSummaryCache {
+    wallets: RwLock<HashMap<String, WalletSummary>>,
+}
+
+impl AnalysisSummaryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The cached summary, or the result of `load` when there is none or it is due a
+    /// resync.
+    pub async fn get_or_load<F, Fut>(
+        &self,
+        wallet: &str,
+        load: F,
+    ) -> Result<AnalysisSummary, String>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<AnalysisSummary, String>>,
+    {
+        let generation = {
+            let wallets = self.wallets.read().await;
+            let entry = wallets.get(wallet);
+            if let Some((summary, computed_at)) = entry.and_then(|e| e.summary.as_ref()) {
+                if (Utc::now() - *computed_at).num_seconds() < ANALYSIS_SUMMARY_RESYNC_SECS {
+                    return Ok(summary.clone());
+                }
+            }
+            entry.map_or(0, |e| e.generation)
+        };
+
+        let summary = load().await?;
+
+        let mut wallets = self.wallets.write().await;
+        let entry = wallets.entry(wallet.to_string()).or_default();
+        if entry.generation == generation {
+            entry.summary = Some((summary.clone(), Utc::now()));
+        }
+
+        Ok(summary)
+    }
+
+    pub async fn record_trade_analysis(&self, wallet: &str, analysis: &TradeAnalysis) {
+        self.update(wallet, |summary| {
+            summary.add_trade_analysis(analysis.clone())
+        })
+        .await;
+    }
+
+    pub async fn record_pattern_summary(&self, wallet: &str, pattern: &StoredPatternSummary) {
+        self.update(wallet, |summary| {
+            summary.set_pattern_summary(pattern.clone())
+        })
+        .await;
+    }
+
+    pub async fn invalidate(&self, wallet: &str) {
+        let mut wallets = self.wallets.write().await;
+        let entry = wallets.entry(wallet.to_string()).or_default();
+        entry.summary = None;
+        entry.generation += 1;
+    }
+
+    async fn update(&self, wallet: &str, apply: impl FnOnce(&mut AnalysisSummary)) {
+        let mut wallets = self.wallets.write().await;
+        let entry = wallets.entry(wallet.to_string()).or_default();
+        entry.generation += 1;
+        if let Some((summary, _)) = entry.summary.as_mut() {
+            apply(summary);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use uuid::Uuid;
+
+    fn analysis(minutes_ago: i64) -> TradeAnalysis {
+        TradeAnalysis {
+            analysis_id: Uuid::new_v4(),
+            position_id: Uuid::new_v4(),
+            token_symbol: "TEST".to_string(),
+            venue: "pump.fun".to_string(),
+            pnl_sol: -0.01,
+            exit_reason: "StopLoss".to_string(),
+            root_cause: "Entered late".to_string(),
+            config_issue: None,
+            pattern: None,
+            suggested_fix: None,
+            confidence: 0.7,
+            created_at: Utc::now() - chrono::Duration::minutes(minutes_ago),
+        }
+    }
+
+    fn pattern(minutes_ago: i64) -> StoredPatternSummary {
+        StoredPatternSummary {
+            summary_id: Uuid::new_v4(),
+            losing_patterns: vec!["late entries".to_string()],
+            winning_patterns: vec![],
+            config_recommendations: vec![],
+            trades_analyzed: 5,
+            time_period: "Last 7 days".to_string(),
+            created_at: Utc::now() - chrono::Duration::minutes(minutes_ago),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cached_summary_served_until_new_analysis() {
+        let cache = AnalysisSummaryCache::new();
+        let loads = &AtomicUsize::new(0);
+        let stored = &vec![analysis(30), analysis(20)];
+        let load = move || async move {
+            loads.fetch_add(1, Ordering::SeqCst);
+            Ok(AnalysisSummary::from_parts(stored.clone(), None))
+        };
+
+        let first = cache.get_or_load("wallet", load).await.unwrap();
+        let second = cache.get_or_load("wallet", load).await.unwrap();
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+        assert_eq!(first, second);
+        assert_eq!(second.trade_analyses_count, 2);
+
+        let newest = analysis(0);
+        cache.record_trade_analysis("wallet", &newest).await;
+        let updated = cache.get_or_load("wallet", load).await.unwrap();
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+        assert_eq!(updated.trade_analyses_count, 3);
+        assert_eq!(
+            updated.recent_trade_analyses[0].analysis_id,
+            newest.analysis_id
+        );
+
+        cache.invalidate("wallet").await;
+        cache.get_or_load("wallet", load).await.unwrap();
+        assert_eq!(loads.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_incremental_update_matches_full_recompute() {
+        let cache = AnalysisSummaryCache::new();
+        let mut stored: Vec<TradeAnalysis> = (0..12).map(|i| analysis(100 + i)).collect();
+        let initial = AnalysisSummary::from_parts(stored.clone(), Some(pattern(90)));
+        cache
+            .get_or_load("wallet", move || async move { Ok(initial) })
+            .await
+            .unwrap();
+
+        // Includes one older than everything kept in the recent list
+        for added in [analysis(5), analysis(200), analysis(1)] {
+            cache.record_trade_analysis("wallet", &added).await;
+            stored.push(added);
+        }
+        let latest_pattern = pattern(0);
+        cache
+            .record_pattern_summary("wallet", &latest_pattern)
+            .await;
+        cache.record_pattern_summary("wallet", &pattern(60)).await;
+
+        let incremental = cache
+            .get_or_load("wallet", || async { Err("not cached".to_string()) })
+            .await
+            .unwrap();
+        assert_eq!(
+            incremental,
+            AnalysisSummary::from_parts(stored, Some(latest_pattern))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_recompute_racing_a_write_is_not_cached() {
+        let cache = &AnalysisSummaryCache::new();
+        cache
+            .get_or_load("wallet", move || async move {
+                cache.record_trade_analysis("wallet", &analysis(0)).await;
+                Ok(AnalysisSummary::default())
+            })
+            .await
+            .unwrap();
+
+        let reloaded = cache
+            .get_or_load("wallet", || async {
+                Ok(AnalysisSummary::from_parts(vec![analysis(0)], None))
+            })
+            .await
+            .unwrap();
+        assert_eq!(reloaded.trade_analyses_count, 1);
+    }
+}
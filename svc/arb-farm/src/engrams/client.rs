@@ -3,6 +3,10 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tracing::{debug, error, info, warn};
 
+use super::analysis_summary::{
+    AnalysisSummary, AnalysisSummaryCache, ANALYSIS_SUMMARY_FETCH_LIMIT,
+};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateEngramRequest {
     pub wallet_address: String,
@@ -130,16 +134,22 @@ pub struct SearchRequest {
     pub query: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<String>>,
+    /// Only engrams created at or after this time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_after: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<i64>,
 }
 
+const TRADE_HISTORY_PAGE_SIZE: i64 = 200;
+
 pub struct EngramsClient {
     base_url: String,
     http_client: Client,
     default_wallet: Option<String>,
+    analysis_summaries: AnalysisSummaryCache,
 }
 
 impl EngramsClient {
@@ -148,6 +158,7 @@ impl EngramsClient {
             base_url,
             http_client: Client::new(),
             default_wallet: None,
+            analysis_summaries: AnalysisSummaryCache::new(),
         }
     }
 
@@ -464,6 +475,7 @@ impl EngramsClient {
             tags: Some(vec!["avoid".to_string()]),
             limit: Some(1),
             offset: None,
+            created_after: None,
         };
 
         match self.search_engrams(search).await {
@@ -483,6 +495,7 @@ impl EngramsClient {
             tags: Some(vec!["pattern".to_string()]),
             limit: Some(100),
             offset: None,
+            created_after: None,
         };
 
         let engrams = self.search_engrams(search).await?;
@@ -562,6 +575,7 @@ impl EngramsClient {
                     tags: None,
                     limit: Some(100),
                     offset: None,
+                    created_after: None,
                 };
 
                 if let Ok(engrams) = self.search_engrams(search).await {
@@ -876,6 +890,7 @@ impl EngramsClient {
             tags: Some(vec!["kol".to_string()]),
             limit: Some(100),
             offset: None,
+            created_after: None,
         };
 
         let engrams = self.search_engrams(search).await?;
@@ -897,6 +912,7 @@ impl EngramsClient {
             tags: Some(vec!["strategy".to_string()]),
             limit: Some(50),
             offset: None,
+            created_after: None,
         };
 
         let engrams = self.search_engrams(search).await?;
@@ -918,6 +934,7 @@ impl EngramsClient {
             tags: Some(vec!["avoid".to_string()]),
             limit: Some(500),
             offset: None,
+            created_after: None,
         };
 
         let engrams = self.search_engrams(search).await?;
@@ -1113,6 +1130,7 @@ impl EngramsClient {
             "content_type": "recommendation",
             "category": category_str,
             "confidence": recommendation.confidence,
+            "informational": recommendation.below_confidence_floor,
             "status": serde_json::to_string(&recommendation.status).unwrap_or_default().trim_matches('"'),
         });
 
@@ -1121,19 +1139,24 @@ impl EngramsClient {
             .trim_matches('"')
             .to_string();
 
+        let mut tags = vec![
+            "arb".to_string(),
+            crate::engrams::schemas::A2A_TAG_LEARNING.to_string(),
+            crate::engrams::schemas::RECOMMENDATION_TAG.to_string(),
+            format!("category.{}", category_str),
+            format!("status.{}", status_str),
+        ];
+        if recommendation.below_confidence_floor {
+            tags.push("informational".to_string());
+        }
+
         let request = CreateEngramRequest {
             wallet_address: wallet.to_string(),
             engram_type: "knowledge".to_string(),
             key,
             content,
             metadata: Some(metadata),
-            tags: Some(vec![
-                "arb".to_string(),
-                crate::engrams::schemas::A2A_TAG_LEARNING.to_string(),
-                crate::engrams::schemas::RECOMMENDATION_TAG.to_string(),
-                format!("category.{}", category_str),
-                format!("status.{}", status_str),
-            ]),
+            tags: Some(tags),
             is_public: Some(false),
         };
 
@@ -1296,6 +1319,7 @@ impl EngramsClient {
             tags: Some(tags),
             limit: limit.or(Some(50)),
             offset: None,
+            created_after: None,
         };
 
         self.search_engrams(search).await
@@ -1394,6 +1418,7 @@ impl EngramsClient {
             tags: Some(vec!["trade".to_string(), "summary".to_string()]),
             limit: limit.or(Some(100)),
             offset: None,
+            created_after: None,
         };
 
         let engrams = self.search_engrams(search).await?;
@@ -1406,6 +1431,44 @@ impl EngramsClient {
         Ok(trades)
     }
 
+    /// Every trade summary saved since `since`, fetched page by page so a busy wallet
+    /// can't push older trades in the window out of a fixed-size result.
+    pub async fn get_trade_history_since(
+        &self,
+        wallet: &str,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<crate::engrams::schemas::TransactionSummary>, String> {
+        let mut trades = Vec::new();
+        let mut offset = 0;
+
+        loop {
+            let search = SearchRequest {
+                wallet_address: Some(wallet.to_string()),
+                engram_type: Some("knowledge".to_string()),
+                query: None,
+                tags: Some(vec!["trade".to_string(), "summary".to_string()]),
+                created_after: Some(since),
+                limit: Some(TRADE_HISTORY_PAGE_SIZE),
+                offset: Some(offset),
+            };
+
+            let engrams = self.search_engrams(search).await?;
+            let page_len = engrams.len() as i64;
+            trades.extend(
+                engrams
+                    .into_iter()
+                    .filter_map(|e| serde_json::from_str(&e.content).ok()),
+            );
+
+            if page_len < TRADE_HISTORY_PAGE_SIZE {
+                break;
+            }
+            offset += page_len;
+        }
+
+        Ok(trades)
+    }
+
     pub async fn get_error_history(
         &self,
         wallet: &str,
@@ -1418,6 +1481,7 @@ impl EngramsClient {
             tags: Some(vec!["error".to_string()]),
             limit: limit.or(Some(50)),
             offset: None,
+            created_after: None,
         };
 
         let engrams = self.search_engrams(search).await?;
@@ -1442,6 +1506,7 @@ impl EngramsClient {
             tags: Some(vec!["trade".to_string(), "summary".to_string()]),
             limit: limit.or(Some(100)),
             offset: None,
+            created_after: None,
         };
 
         let engrams = self.search_engrams(search).await?;
@@ -1509,6 +1574,7 @@ impl EngramsClient {
             tags: Some(vec!["error".to_string()]),
             limit: limit.or(Some(50)),
             offset: None,
+            created_after: None,
         };
 
         let engrams = self.search_engrams(search).await?;
@@ -1611,6 +1677,7 @@ impl EngramsClient {
             tags: Some(vec![crate::engrams::schemas::WATCHLIST_TAG.to_string()]),
             limit: Some(500),
             offset: None,
+            created_after: None,
         };
 
         let engrams = self.search_engrams(search).await?;
@@ -1727,7 +1794,11 @@ impl EngramsClient {
             is_public: Some(false),
         };
 
-        self.create_engram(request).await
+        let engram = self.create_engram(request).await?;
+        self.analysis_summaries
+            .record_trade_analysis(wallet, analysis)
+            .await;
+        Ok(engram)
     }
 
     pub async fn save_pattern_summary(
@@ -1765,7 +1836,11 @@ impl EngramsClient {
             is_public: Some(false),
         };
 
-        self.create_engram(request).await
+        let engram = self.create_engram(request).await?;
+        self.analysis_summaries
+            .record_pattern_summary(wallet, summary)
+            .await;
+        Ok(engram)
     }
 
     pub async fn get_trade_analyses(
@@ -1780,6 +1855,7 @@ impl EngramsClient {
             tags: Some(vec![crate::engrams::schemas::TRADE_ANALYSIS_TAG.to_string()]),
             limit: limit.or(Some(50)),
             offset: None,
+            created_after: None,
         };
 
         let engrams = self.search_engrams(search).await?;
@@ -1806,6 +1882,7 @@ impl EngramsClient {
             ]),
             limit: limit.or(Some(10)),
             offset: None,
+            created_after: None,
         };
 
         let engrams = self.search_engrams(search).await?;
@@ -1826,6 +1903,20 @@ impl EngramsClient {
         Ok(summaries.into_iter().next())
     }
 
+    /// Trade analysis count, recent analyses and latest pattern summary. Served from
+    /// a cache that analyses and summaries saved through this client keep current.
+    pub async fn get_analysis_summary(&self, wallet: &str) -> Result<AnalysisSummary, String> {
+        self.analysis_summaries
+            .get_or_load(wallet, move || async move {
+                let analyses = self
+                    .get_trade_analyses(wallet, Some(ANALYSIS_SUMMARY_FETCH_LIMIT))
+                    .await?;
+                let pattern_summary = self.get_latest_pattern_summary(wallet).await?;
+                Ok(AnalysisSummary::from_parts(analyses, pattern_summary))
+            })
+            .await
+    }
+
     pub async fn save_web_research(
         &self,
         wallet: &str,
@@ -1890,6 +1981,7 @@ impl EngramsClient {
             tags: Some(vec![crate::engrams::schemas::WEB_RESEARCH_TAG.to_string()]),
             limit: limit.or(Some(50)),
             offset: None,
+            created_after: None,
         };
 
         let engrams = self.search_engrams(search).await?;
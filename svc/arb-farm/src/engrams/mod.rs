@@ -1,6 +1,9 @@
+pub mod analysis_summary;
 pub mod client;
 pub mod schemas;
 
+pub use analysis_summary::{AnalysisSummary, AnalysisSummaryCache};
+
 pub use client::{
     AvoidanceEngram, CreateEngramRequest, Engram, EngramsClient, KolDiscoveryEngram, PatternEngram,
     SearchRequest, StrategyEngram, WorkflowState,
@@ -11,8 +14,8 @@ pub use schemas::{
     AnalysisContextSummary, ConsensusAnalysis, ConsensusAnalysisType, ConsensusDecision,
     ConversationContext, ConversationLog, ConversationMessage, ConversationOutcome,
     ConversationTopic, ConversationTrigger, DailyMetrics, ErrorContext, ExecutionError,
-    ExecutionErrorType, Recommendation, RecommendationCategory, RecommendationSource,
-    RecommendationStatus, StrategyMetrics, SuggestedAction, SuggestedActionType, SupportingData,
-    TradeHighlight, TransactionAction, TransactionMetadata, TransactionSummary, VenueMetrics,
-    WatchlistToken, A2A_TAG_LEARNING, WATCHLIST_TAG,
+    ExecutionErrorType, Recommendation, RecommendationCategory, RecommendationOutcome,
+    RecommendationSource, RecommendationStatus, StrategyMetrics, SuggestedAction,
+    SuggestedActionType, SupportingData, TradeHighlight, TransactionAction, TransactionMetadata,
+    TransactionSummary, VenueMetrics, WatchlistToken, A2A_TAG_LEARNING, WATCHLIST_TAG,
 };
@@ -122,6 +122,27 @@ pub struct Recommendation {
     pub status: RecommendationStatus,
     pub created_at: DateTime<Utc>,
     pub applied_at: Option<DateTime<Utc>>,
+    /// Confidence was below the action floor when generated; informational only
+    #[serde(default)]
+    pub below_confidence_floor: bool,
+    /// Models that voted in the analysis that produced this recommendation
+    #[serde(default)]
+    pub models: Vec<String>,
+    /// P&L impact, measured once the outcome window after `applied_at` has passed
+    #[serde(default)]
+    pub outcome: Option<RecommendationOutcome>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecommendationOutcome {
+    pub window_hours: i64,
+    pub trades_before: u32,
+    pub trades_after: u32,
+    pub pnl_before_sol: f64,
+    pub pnl_after_sol: f64,
+    pub pnl_delta_sol: f64,
+    pub improved: bool,
+    pub measured_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -355,7 +376,7 @@ pub fn generate_web_research_key(research_id: &Uuid) -> String {
     format!("arb.research.web.{}", research_id)
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TradeAnalysis {
     pub analysis_id: Uuid,
     pub position_id: Uuid,
@@ -375,7 +396,7 @@ pub fn generate_trade_analysis_key(analysis_id: &Uuid) -> String {
     format!("arb.learning.trade_analysis.{}", analysis_id)
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StoredPatternSummary {
     pub summary_id: Uuid,
     pub losing_patterns: Vec<String>,
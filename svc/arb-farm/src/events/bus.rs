@@ -42,19 +42,7 @@ impl EventBus {
     }
 
     async fn persist_event(&self, event: &ArbEvent) -> AppResult<()> {
-        let source_type = match &event.source {
-            EventSource::Agent(_) => "agent",
-            EventSource::Tool(_) => "tool",
-            EventSource::External(_) => "external",
-            EventSource::System => "system",
-        };
-
-        let source_id = match &event.source {
-            EventSource::Agent(t) => t.to_string(),
-            EventSource::Tool(s) => s.clone(),
-            EventSource::External(s) => s.clone(),
-            EventSource::System => "system".to_string(),
-        };
+        let (source_type, source_id) = source_columns(&event.source);
 
         sqlx::query(
             r#"
@@ -192,8 +180,18 @@ impl EventSubscription {
     }
 }
 
+/// `(source_type, source_id)` column values for an event source.
+pub(crate) fn source_columns(source: &EventSource) -> (&'static str, String) {
+    match source {
+        EventSource::Agent(t) => ("agent", t.to_string()),
+        EventSource::Tool(s) => ("tool", s.clone()),
+        EventSource::External(s) => ("external", s.clone()),
+        EventSource::System => ("system", "system".to_string()),
+    }
+}
+
 #[derive(sqlx::FromRow)]
-struct EventRow {
+pub(crate) struct EventRow {
     id: Uuid,
     event_type: String,
     source_type: String,
@@ -1,8 +1,12 @@
 mod bus;
+pub mod sink;
+pub mod stream;
 pub mod topics;
 mod types;
 
 pub use bus::*;
+pub use sink::{EventQuery, EventSink, EventSinkStats};
+pub use stream::{event_frames, get_sse_heartbeat_interval, EventFrame};
 pub use topics::*;
 pub use types::*;
 
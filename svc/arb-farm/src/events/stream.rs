@@ -0,0 +1,164 @@
+use futures::stream::{self, Stream};
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::time::Instant;
+use uuid::Uuid;
+
+use super::ArbEvent;
+
+pub const DEFAULT_SSE_HEARTBEAT_SECS: u64 = 15;
+
+pub fn get_sse_heartbeat_interval() -> Duration {
+    let secs = std::env::var("ARB_SSE_HEARTBEAT_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(DEFAULT_SSE_HEARTBEAT_SECS);
+    Duration::from_secs(secs)
+}
+
+#[derive(Debug, Clone)]
+pub enum EventFrame {
+    Event(ArbEvent),
+    /// Nothing matched for a full heartbeat interval; keeps idle proxies from
+    /// closing the connection.
+    Heartbeat,
+}
+
+struct FrameState<F> {
+    replay: VecDeque<ArbEvent>,
+    replayed: HashSet<Uuid>,
+    rx: broadcast::Receiver<ArbEvent>,
+    filter: F,
+}
+
+/// Replays `replay` first, then follows `rx`. Live events already sent during replay
+/// are skipped, and a heartbeat is emitted whenever no frame has gone out for
+/// `heartbeat`, even if filtered-out events are still arriving. Subscribe `rx`
+/// before reading the replay so nothing falls between the two.
+pub fn event_frames<F>(
+    replay: Vec<ArbEvent>,
+    rx: broadcast::Receiver<ArbEvent>,
+    filter: F,
+    heartbeat: Duration,
+) -> impl Stream<Item = EventFrame> + Send
+where
+    F: Fn(&ArbEvent) -> bool + Send + 'static,
+{
+    let replay: VecDeque<ArbEvent> = replay.into_iter().filter(|e| filter(e)).collect();
+    let replayed = replay.iter().map(|e| e.id).collect();
+    let state = FrameState {
+        replay,
+        replayed,
+        rx,
+        filter,
+    };
+
+    stream::unfold(state, move |mut state| async move {
+        if let Some(event) = state.replay.pop_front() {
+            return Some((EventFrame::Event(event), state));
+        }
+
+        let deadline = Instant::now() + heartbeat;
+        loop {
+            match tokio::time::timeout_at(deadline, state.rx.recv()).await {
+                Err(_) => return Some((EventFrame::Heartbeat, state)),
+                Ok(Ok(event)) => {
+                    if state.replayed.remove(&event.id) || !(state.filter)(&event) {
+                        continue;
+                    }
+                    return Some((EventFrame::Event(event), state));
+                }
+                Ok(Err(RecvError::Lagged(skipped))) => {
+                    tracing::warn!("SSE subscriber lagged, skipped {} events", skipped);
+                }
+                Ok(Err(RecvError::Closed)) => return None,
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{topics, EventSource};
+    use futures::StreamExt;
+
+    fn event(topic: &str) -> ArbEvent {
+        ArbEvent::new("test", EventSource::System, topic, serde_json::json!({}))
+    }
+
+    async fn next_frame(frames: &mut (impl Stream<Item = EventFrame> + Unpin)) -> EventFrame {
+        tokio::time::timeout(Duration::from_secs(2), frames.next())
+            .await
+            .expect("stream stalled")
+            .expect("stream ended")
+    }
+
+    #[tokio::test]
+    async fn test_idle_stream_sends_heartbeats() {
+        let (tx, rx) = broadcast::channel(16);
+        let mut frames = Box::pin(event_frames(
+            Vec::new(),
+            rx,
+            |e| e.topic.starts_with("arb.edge."),
+            Duration::from_millis(30),
+        ));
+
+        assert!(matches!(
+            next_frame(&mut frames).await,
+            EventFrame::Heartbeat
+        ));
+
+        // Unrelated traffic must not hold off the heartbeat.
+        let sender = tokio::spawn(async move {
+            for _ in 0..10 {
+                let _ = tx.send(event(topics::position::OPENED));
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+            tx
+        });
+        assert!(matches!(
+            next_frame(&mut frames).await,
+            EventFrame::Heartbeat
+        ));
+
+        let tx = sender.await.unwrap();
+        tx.send(event(topics::edge::DETECTED)).unwrap();
+        match next_frame(&mut frames).await {
+            EventFrame::Event(e) => assert_eq!(e.topic, topics::edge::DETECTED),
+            EventFrame::Heartbeat => panic!("expected the buffered edge event"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resume_replays_only_missed_events_once() {
+        let (tx, rx) = broadcast::channel(16);
+        let missed_edge = event(topics::edge::DETECTED);
+        let missed_position = event(topics::position::OPENED);
+        let mut frames = Box::pin(event_frames(
+            vec![missed_edge.clone(), missed_position],
+            rx,
+            |e| e.topic.starts_with("arb.edge."),
+            Duration::from_secs(60),
+        ));
+
+        // The missed edge reached the live channel too while the replay was read.
+        let live_edge = event(topics::edge::EXECUTED);
+        tx.send(missed_edge.clone()).unwrap();
+        tx.send(live_edge.clone()).unwrap();
+
+        let mut ids = Vec::new();
+        for _ in 0..2 {
+            match next_frame(&mut frames).await {
+                EventFrame::Event(e) => ids.push(e.id),
+                EventFrame::Heartbeat => panic!("unexpected heartbeat"),
+            }
+        }
+        assert_eq!(ids, vec![missed_edge.id, live_edge.id]);
+
+        drop(tx);
+        assert!(frames.next().await.is_none());
+    }
+}
@@ -61,6 +61,7 @@ pub mod engram {
     pub const PATTERN_MATCHED: &str = "arb.engram.pattern.matched";
     pub const AVOIDANCE_CREATED: &str = "arb.engram.avoidance.created";
     pub const STRATEGY_OPTIMIZED: &str = "arb.engram.strategy.optimized";
+    pub const STRATEGY_SUGGESTED: &str = "arb.engram.strategy.suggested";
 }
 
 pub mod swarm {
@@ -114,6 +115,9 @@ pub mod position {
     pub const MOMENTUM_EXIT: &str = "arb.position.momentum_exit";
     pub const EMERGENCY_EXIT: &str = "arb.position.emergency_exit";
     pub const BULK_CLEARED: &str = "arb.position.bulk_cleared";
+    pub const RECONCILE_TOKEN_DISCOVERED: &str = "arb.position.reconcile.token_discovered";
+    pub const RECONCILE_ORPHANED: &str = "arb.position.reconcile.orphaned";
+    pub const RECONCILE_EXIT_STRATEGY_FIXED: &str = "arb.position.reconcile.exit_strategy_fixed";
 }
 
 pub mod curve {
@@ -123,6 +127,13 @@ pub mod curve {
     pub const GRADUATED: &str = "arb.curve.graduated";
 }
 
+pub mod lending {
+    pub const ALL: &str = "arb.lending.*";
+    pub const RISK_WARNING: &str = "arb.lending.risk.warning";
+    pub const RISK_CRITICAL: &str = "arb.lending.risk.critical";
+    pub const RISK_RECOVERED: &str = "arb.lending.risk.recovered";
+}
+
 pub mod helius {
     pub const ALL: &str = "arb.helius.*";
 
@@ -1,4 +1,4 @@
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{broadcast, RwLock};
@@ -7,8 +7,8 @@ use uuid::Uuid;
 use crate::error::{AppError, AppResult};
 use crate::events::{approval as approval_topics, AgentType, ArbEvent, EventSource};
 use crate::models::{
-    ApprovalStatus, ApprovalType, GlobalExecutionConfig, HecateRecommendation, PendingApproval,
-    UpdateExecutionConfigRequest,
+    ApprovalRequest, ApprovalStatus, ApprovalType, ExecutionSchedule, GlobalExecutionConfig,
+    HecateRecommendation, ManualExecutionOverride, PendingApproval, UpdateExecutionConfigRequest,
 };
 
 pub struct ApprovalManager {
@@ -95,9 +95,25 @@ impl ApprovalManager {
     }
 
     pub async fn toggle_execution(&self, enabled: bool) -> GlobalExecutionConfig {
+        self.toggle_execution_at(enabled, Utc::now()).await
+    }
+
+    async fn toggle_execution_at(
+        &self,
+        enabled: bool,
+        now: DateTime<Utc>,
+    ) -> GlobalExecutionConfig {
         let mut config = self.config.write().await;
         config.auto_execution_enabled = enabled;
-        config.updated_at = Utc::now();
+        config.updated_at = now;
+
+        if config.schedule.enabled {
+            config.manual_override = Some(ManualExecutionOverride {
+                enabled,
+                set_at: now,
+                window_state_at_set: config.schedule.is_within_window(now),
+            });
+        }
 
         let topic = if enabled {
             approval_topics::EXECUTION_ENABLED
@@ -137,7 +153,74 @@ impl ApprovalManager {
         }
     }
 
+    pub async fn set_schedule(
+        &self,
+        schedule: ExecutionSchedule,
+    ) -> AppResult<GlobalExecutionConfig> {
+        schedule.validate()?;
+
+        let mut config = self.config.write().await;
+        config.schedule = schedule;
+        config.manual_override = None;
+        config.updated_at = Utc::now();
+
+        Ok(config.clone())
+    }
+
+    pub async fn clear_manual_override(&self) -> GlobalExecutionConfig {
+        let mut config = self.config.write().await;
+        config.manual_override = None;
+        config.clone()
+    }
+
+    /// Applies the trading schedule at `now`. Returns the new execution state when it
+    /// changed so the caller can start or stop the executor.
+    pub async fn evaluate_schedule(&self, now: DateTime<Utc>) -> Option<bool> {
+        let mut config = self.config.write().await;
+
+        if config.has_stale_override(now) {
+            config.manual_override = None;
+        }
+
+        let desired = config.scheduled_execution_state(now)?;
+        if desired == config.auto_execution_enabled {
+            return None;
+        }
+
+        config.auto_execution_enabled = desired;
+        config.updated_at = now;
+
+        let topic = if desired {
+            approval_topics::EXECUTION_ENABLED
+        } else {
+            approval_topics::EXECUTION_DISABLED
+        };
+
+        crate::events::broadcast_event(
+            &self.event_tx,
+            ArbEvent::new(
+                if desired {
+                    "execution_enabled"
+                } else {
+                    "execution_disabled"
+                },
+                EventSource::Agent(AgentType::ApprovalManager),
+                topic,
+                serde_json::json!({
+                    "enabled": desired,
+                    "source": "schedule",
+                }),
+            ),
+        );
+
+        Some(desired)
+    }
+
     pub async fn create_approval(&self, approval: PendingApproval) -> AppResult<PendingApproval> {
+        if let Some(request) = &approval.request {
+            request.validate()?;
+        }
+
         let config = self.config.read().await;
 
         {
@@ -185,6 +268,7 @@ impl ApprovalManager {
                 serde_json::json!({
                     "approval_id": approval_id,
                     "approval_type": format!("{}", approval_type),
+                    "kind": approval.request.as_ref().map(|r| r.kind()),
                     "auto_approved": should_auto_approve,
                     "expires_at": approval.expires_at.to_rfc3339(),
                 }),
@@ -257,6 +341,7 @@ impl ApprovalManager {
         &self,
         approval_id: Uuid,
         notes: Option<String>,
+        amended_request: Option<ApprovalRequest>,
     ) -> AppResult<PendingApproval> {
         let mut pending = self.pending.write().await;
 
@@ -276,6 +361,22 @@ impl ApprovalManager {
             )));
         }
 
+        if let Some(amended) = amended_request {
+            if let Some(existing) = &approval.request {
+                if existing.kind() != amended.kind() {
+                    return Err(AppError::Validation(format!(
+                        "Cannot approve {} approval with a {} payload",
+                        existing.kind(),
+                        amended.kind()
+                    )));
+                }
+            }
+            amended.validate()?;
+            approval.set_request(amended);
+        } else if let Some(request) = &approval.request {
+            request.validate()?;
+        }
+
         approval.status = ApprovalStatus::Approved;
         approval.user_decision = Some(true);
         approval.user_decided_at = Some(Utc::now());
@@ -482,3 +583,141 @@ impl ApprovalManager {
         Ok(cancelled_ids)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RecommendationApprovalPayload, TradingWindow};
+    use chrono::{NaiveDate, NaiveTime};
+
+    fn trade_approval(amount_sol: f64) -> PendingApproval {
+        PendingApproval::new_entry(
+            Uuid::new_v4(),
+            None,
+            None,
+            None,
+            Some("TestMint".to_string()),
+            None,
+            Some(amount_sol),
+            serde_json::json!({}),
+            300,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_invalid_payload() {
+        let (event_tx, _) = broadcast::channel(16);
+        let manager = ApprovalManager::new(event_tx);
+
+        assert!(manager.create_approval(trade_approval(-1.0)).await.is_err());
+        assert!(manager.list_all().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_approve_validates_amended_payload() {
+        let (event_tx, _) = broadcast::channel(16);
+        let manager = ApprovalManager::new(event_tx);
+        let created = manager.create_approval(trade_approval(0.1)).await.unwrap();
+
+        let wrong_kind = ApprovalRequest::Recommendation(RecommendationApprovalPayload {
+            source: "test".to_string(),
+            title: "Not a trade".to_string(),
+            description: String::new(),
+            confidence: 0.5,
+            suggested_action: None,
+        });
+        assert!(manager
+            .approve(created.id, None, Some(wrong_kind))
+            .await
+            .is_err());
+
+        let ApprovalRequest::Trade(mut amended) = created.request.clone().unwrap() else {
+            unreachable!()
+        };
+        amended.amount_sol = 0.05;
+        let approved = manager
+            .approve(created.id, None, Some(ApprovalRequest::Trade(amended)))
+            .await
+            .unwrap();
+
+        assert_eq!(approved.status, ApprovalStatus::Approved);
+        assert_eq!(approved.amount_sol, Some(0.05));
+        assert_eq!(approved.token_mint.as_deref(), Some("TestMint"));
+        match approved.request {
+            Some(ApprovalRequest::Trade(trade)) => assert_eq!(trade.amount_sol, 0.05),
+            other => panic!("unexpected request: {:?}", other),
+        }
+    }
+
+    fn utc(h: u32, m: u32) -> DateTime<Utc> {
+        NaiveDate::from_ymd_opt(2026, 3, 2)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+            .and_utc()
+    }
+
+    async fn scheduled_manager() -> ApprovalManager {
+        let (event_tx, _) = broadcast::channel(16);
+        let manager = ApprovalManager::new(event_tx);
+        manager
+            .set_schedule(ExecutionSchedule {
+                enabled: true,
+                timezone: "UTC".to_string(),
+                windows: vec![TradingWindow {
+                    start: NaiveTime::from_hms_opt(13, 0, 0).unwrap(),
+                    end: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+                }],
+            })
+            .await
+            .unwrap();
+        manager
+    }
+
+    #[tokio::test]
+    async fn test_schedule_enables_inside_window_and_pauses_outside() {
+        let manager = scheduled_manager().await;
+
+        assert_eq!(manager.evaluate_schedule(utc(12, 59)).await, None);
+        assert!(!manager.get_config().await.auto_execution_enabled);
+
+        assert_eq!(manager.evaluate_schedule(utc(13, 0)).await, Some(true));
+        assert!(manager.get_config().await.auto_execution_enabled);
+        assert_eq!(manager.evaluate_schedule(utc(16, 30)).await, None);
+
+        assert_eq!(manager.evaluate_schedule(utc(17, 0)).await, Some(false));
+        assert!(!manager.get_config().await.auto_execution_enabled);
+    }
+
+    #[tokio::test]
+    async fn test_manual_override_wins_until_next_boundary() {
+        let manager = scheduled_manager().await;
+        manager.evaluate_schedule(utc(14, 0)).await;
+
+        manager.toggle_execution_at(false, utc(14, 5)).await;
+        assert_eq!(manager.evaluate_schedule(utc(15, 0)).await, None);
+        assert!(!manager.get_config().await.auto_execution_enabled);
+
+        assert_eq!(manager.evaluate_schedule(utc(17, 30)).await, None);
+        assert!(manager.get_config().await.manual_override.is_none());
+
+        manager.toggle_execution_at(true, utc(18, 0)).await;
+        assert_eq!(manager.evaluate_schedule(utc(23, 0)).await, None);
+        assert!(manager.get_config().await.auto_execution_enabled);
+
+        manager.clear_manual_override().await;
+        assert_eq!(manager.evaluate_schedule(utc(23, 1)).await, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_no_schedule_leaves_execution_untouched() {
+        let (event_tx, _) = broadcast::channel(16);
+        let manager = ApprovalManager::new(event_tx);
+        manager.toggle_execution(true).await;
+
+        assert_eq!(manager.evaluate_schedule(utc(3, 0)).await, None);
+        let config = manager.get_config().await;
+        assert!(config.auto_execution_enabled);
+        assert!(config.manual_override.is_none());
+    }
+}
@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+
+use super::position_manager::{BaseCurrency, CURVE_ENTRY_FEE_BPS, CURVE_EXIT_FEE_BPS};
+
+/// Signature fee, priority fee and Jito tip for one leg.
+pub const DEFAULT_NETWORK_FEE_LAMPORTS_PER_LEG: u64 = 20_000;
+/// Rent-exempt minimum of an SPL token account; exits don't close the account.
+pub const DEFAULT_TOKEN_ACCOUNT_RENT_LAMPORTS: u64 = 2_039_280;
+pub const DEFAULT_DEX_FEE_BPS: u16 = 25;
+
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+/// Everything a round trip costs on top of the amount put in, in the position's base
+/// currency (venue fees as bps of each leg).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct RoundTripCosts {
+    pub entry_fee_bps: u16,
+    pub exit_fee_bps: u16,
+    /// Signature fees, priority fees and tips for both legs.
+    pub network_fees_base: f64,
+    /// Token account rent left locked after the exit.
+    pub rent_base: f64,
+}
+
+impl RoundTripCosts {
+    pub fn fixed_costs_base(&self) -> f64 {
+        self.network_fees_base + self.rent_base
+    }
+
+    /// Price at which selling `amount_base` worth (valued at `entry_price`) returns the
+    /// amount plus every cost. Entry fees are charged on top of the amount, exit fees
+    /// come out of the proceeds.
+    pub fn break_even_price(&self, entry_price: f64, amount_base: f64) -> f64 {
+        if amount_base <= 0.0 || entry_price <= 0.0 {
+            return entry_price;
+        }
+        let entry_fee = self.entry_fee_bps as f64 / 10_000.0;
+        let exit_fee = self.exit_fee_bps as f64 / 10_000.0;
+        let cost = amount_base * (1.0 + entry_fee) + self.fixed_costs_base();
+        entry_price * cost / (amount_base * (1.0 - exit_fee))
+    }
+
+    /// Realized P&L of selling `amount_base` worth at `price` after every cost.
+    pub fn net_pnl_base(&self, entry_price: f64, amount_base: f64, price: f64) -> f64 {
+        if entry_price <= 0.0 {
+            return 0.0;
+        }
+        let entry_fee = self.entry_fee_bps as f64 / 10_000.0;
+        let exit_fee = self.exit_fee_bps as f64 / 10_000.0;
+        let proceeds = amount_base * (price / entry_price) * (1.0 - exit_fee);
+        proceeds - amount_base * (1.0 + entry_fee) - self.fixed_costs_base()
+    }
+}
+
+/// Turns venue and base currency into the costs recorded on a position.
+/// Lamport-denominated costs only apply to SOL-based positions.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BreakEvenCostModel {
+    pub network_fee_lamports_per_leg: u64,
+    pub token_account_rent_lamports: u64,
+    pub dex_fee_bps: u16,
+}
+
+impl Default for BreakEvenCostModel {
+    fn default() -> Self {
+        Self {
+            network_fee_lamports_per_leg: DEFAULT_NETWORK_FEE_LAMPORTS_PER_LEG,
+            token_account_rent_lamports: DEFAULT_TOKEN_ACCOUNT_RENT_LAMPORTS,
+            dex_fee_bps: DEFAULT_DEX_FEE_BPS,
+        }
+    }
+}
+
+impl BreakEvenCostModel {
+    /// Costs that are never charged; break-even is the entry price.
+    pub fn free() -> Self {
+        Self {
+            network_fee_lamports_per_leg: 0,
+            token_account_rent_lamports: 0,
+            dex_fee_bps: 0,
+        }
+    }
+
+    /// `BREAKEVEN_NETWORK_FEE_LAMPORTS` (per leg, default 20000), `BREAKEVEN_RENT_LAMPORTS`
+    /// (default 2039280, set 0 if exits close the token account) and
+    /// `BREAKEVEN_DEX_FEE_BPS` (default 25).
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let network_fee_lamports_per_leg = std::env::var("BREAKEVEN_NETWORK_FEE_LAMPORTS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(defaults.network_fee_lamports_per_leg);
+        let token_account_rent_lamports = std::env::var("BREAKEVEN_RENT_LAMPORTS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(defaults.token_account_rent_lamports);
+        let dex_fee_bps = std::env::var("BREAKEVEN_DEX_FEE_BPS")
+            .ok()
+            .and_then(|v| v.parse::<u16>().ok())
+            .unwrap_or(defaults.dex_fee_bps);
+
+        Self {
+            network_fee_lamports_per_leg,
+            token_account_rent_lamports,
+            dex_fee_bps,
+        }
+    }
+
+    pub fn costs_for(&self, venue: Option<&str>, base_currency: BaseCurrency) -> RoundTripCosts {
+        let (entry_fee_bps, exit_fee_bps) = if is_curve_venue(venue) {
+            (CURVE_ENTRY_FEE_BPS, CURVE_EXIT_FEE_BPS)
+        } else {
+            (self.dex_fee_bps, self.dex_fee_bps)
+        };
+
+        let (network_fees_base, rent_base) = match base_currency {
+            BaseCurrency::Sol => (
+                (self.network_fee_lamports_per_leg * 2) as f64 / LAMPORTS_PER_SOL,
+                self.token_account_rent_lamports as f64 / LAMPORTS_PER_SOL,
+            ),
+            BaseCurrency::Usdc | BaseCurrency::Usdt => (0.0, 0.0),
+        };
+
+        RoundTripCosts {
+            entry_fee_bps,
+            exit_fee_bps,
+            network_fees_base,
+            rent_base,
+        }
+    }
+}
+
+fn is_curve_venue(venue: Option<&str>) -> bool {
+    matches!(
+        venue.map(|v| v.to_lowercase()).as_deref(),
+        Some("pump_fun" | "pumpfun" | "moonshot" | "curve" | "bonding_curve")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_break_even_price_includes_fees() {
+        let costs = BreakEvenCostModel::default().costs_for(Some("pump_fun"), BaseCurrency::Sol);
+        assert_eq!(costs.entry_fee_bps, CURVE_ENTRY_FEE_BPS);
+        assert_eq!(costs.exit_fee_bps, CURVE_EXIT_FEE_BPS);
+        assert!((costs.network_fees_base - 0.00004).abs() < 1e-12);
+        assert!((costs.rent_base - 0.00203928).abs() < 1e-12);
+
+        let break_even = costs.break_even_price(0.001, 1.0);
+        // Venue fees alone move break-even past +2%, network fees and rent add the rest
+        assert!(break_even > 0.001 * 1.02);
+        let fee_only = RoundTripCosts {
+            network_fees_base: 0.0,
+            rent_base: 0.0,
+            ..costs
+        };
+        assert!(break_even > fee_only.break_even_price(0.001, 1.0));
+
+        let free = BreakEvenCostModel::free().costs_for(Some("raydium"), BaseCurrency::Sol);
+        assert_eq!(free.break_even_price(0.001, 1.0), 0.001);
+    }
+
+    #[test]
+    fn test_exit_at_break_even_nets_zero() {
+        let costs = BreakEvenCostModel::default().costs_for(Some("raydium"), BaseCurrency::Sol);
+        for amount in [0.05, 0.5, 5.0] {
+            let break_even = costs.break_even_price(0.002, amount);
+            assert!(costs.net_pnl_base(0.002, amount, break_even).abs() < 1e-12);
+            // Selling at the raw entry price loses every cost
+            assert!(costs.net_pnl_base(0.002, amount, 0.002) < -costs.fixed_costs_base() + 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_lamport_costs_only_apply_to_sol_positions() {
+        let costs = BreakEvenCostModel::default().costs_for(None, BaseCurrency::Usdc);
+        assert_eq!(costs.fixed_costs_base(), 0.0);
+        assert_eq!(costs.entry_fee_bps, DEFAULT_DEX_FEE_BPS);
+    }
+}
@@ -5,6 +5,12 @@ use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
+use crate::database::TradeRepository;
+use crate::error::AppError;
+use crate::wallet::BalanceCache;
+
+pub type StrategyId = Uuid;
+
 #[derive(Debug, Clone)]
 pub struct CapitalReservation {
     pub strategy_id: Uuid,
@@ -15,12 +21,75 @@ pub struct CapitalReservation {
 
 #[derive(Debug, Clone)]
 pub struct StrategyAllocation {
+    /// Current budget, as a percent of total balance.
     pub max_percent: f64,
+    /// Budget the auto-rebalancer is moving `max_percent` towards.
+    pub target_percent: f64,
     pub reserved_lamports: u64,
     pub active_positions: u32,
     pub max_positions: u32,
 }
 
+/// Bounds for performance-based rebalancing. Each auto-rebalance moves a strategy's
+/// budget at most `max_step_percent` towards its target.
+#[derive(Debug, Clone)]
+pub struct RebalanceConfig {
+    pub auto_enabled: bool,
+    pub min_percent: f64,
+    pub max_percent: f64,
+    pub max_step_percent: f64,
+    pub interval_secs: u64,
+    pub lookback_hours: i64,
+}
+
+impl Default for RebalanceConfig {
+    fn default() -> Self {
+        Self {
+            auto_enabled: false,
+            min_percent: 10.0,
+            max_percent: 70.0,
+            max_step_percent: 10.0,
+            interval_secs: 3600,
+            lookback_hours: 24,
+        }
+    }
+}
+
+impl RebalanceConfig {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let parse_f64 = |key: &str, default: f64| {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse::<f64>().ok())
+                .filter(|v| v.is_finite() && *v >= 0.0)
+                .unwrap_or(default)
+        };
+
+        Self {
+            auto_enabled: std::env::var("CAPITAL_AUTO_REBALANCE")
+                .map(|v| v == "1" || v.to_lowercase() == "true")
+                .unwrap_or(defaults.auto_enabled),
+            min_percent: parse_f64("CAPITAL_REBALANCE_MIN_PERCENT", defaults.min_percent),
+            max_percent: parse_f64("CAPITAL_REBALANCE_MAX_PERCENT", defaults.max_percent),
+            max_step_percent: parse_f64(
+                "CAPITAL_REBALANCE_STEP_PERCENT",
+                defaults.max_step_percent,
+            ),
+            interval_secs: std::env::var("CAPITAL_REBALANCE_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(defaults.interval_secs),
+            lookback_hours: std::env::var("CAPITAL_REBALANCE_LOOKBACK_HOURS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(defaults.lookback_hours),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct CapitalManager {
     total_balance_lamports: RwLock<u64>,
@@ -28,6 +97,8 @@ pub struct CapitalManager {
     reservations: RwLock<HashMap<Uuid, CapitalReservation>>,
     global_reserved_lamports: RwLock<u64>,
     db_pool: Option<PgPool>,
+    rebalance_config: RebalanceConfig,
+    balance_cache: Option<Arc<BalanceCache>>,
 }
 
 impl CapitalManager {
@@ -38,6 +109,8 @@ impl CapitalManager {
             reservations: RwLock::new(HashMap::new()),
             global_reserved_lamports: RwLock::new(0),
             db_pool: None,
+            rebalance_config: RebalanceConfig::default(),
+            balance_cache: None,
         }
     }
 
@@ -46,6 +119,20 @@ impl CapitalManager {
         self
     }
 
+    pub fn with_rebalance_config(mut self, config: RebalanceConfig) -> Self {
+        self.rebalance_config = config;
+        self
+    }
+
+    pub fn with_balance_cache(mut self, cache: Arc<BalanceCache>) -> Self {
+        self.balance_cache = Some(cache);
+        self
+    }
+
+    pub fn rebalance_config(&self) -> &RebalanceConfig {
+        &self.rebalance_config
+    }
+
     pub async fn load_reservations_from_db(&self) -> Result<usize, String> {
         let Some(pool) = &self.db_pool else {
             return Ok(0);
@@ -153,6 +240,7 @@ impl CapitalManager {
             strategy_id,
             StrategyAllocation {
                 max_percent,
+                target_percent: max_percent,
                 reserved_lamports: 0,
                 active_positions: 0,
                 max_positions,
@@ -391,6 +479,116 @@ impl CapitalManager {
         Some(release_lamports)
     }
 
+    /// Grows a position's reservation for an add to it, such as a scale-in buy. The add
+    /// must fit the strategy's budget and the unreserved balance; it does not count as a
+    /// new position. A position without a reservation gets one.
+    pub async fn extend_reservation(
+        &self,
+        strategy_id: Uuid,
+        position_id: Uuid,
+        amount_lamports: u64,
+    ) -> Result<(), CapitalError> {
+        let total_balance = *self.total_balance_lamports.read().await;
+        if total_balance == 0 {
+            return Err(CapitalError::NoBalance);
+        }
+
+        let existing = self.reservations.read().await.get(&position_id).cloned();
+        let strategy_id = existing.as_ref().map_or(strategy_id, |r| r.strategy_id);
+
+        {
+            let allocations = self.strategy_allocations.read().await;
+            if let Some(allocation) = allocations.get(&strategy_id) {
+                let max_capital = (total_balance as f64 * allocation.max_percent / 100.0) as u64;
+                if allocation.reserved_lamports + amount_lamports > max_capital {
+                    return Err(CapitalError::AllocationExceeded {
+                        requested: amount_lamports,
+                        available: max_capital.saturating_sub(allocation.reserved_lamports),
+                        max_percent: allocation.max_percent,
+                    });
+                }
+            }
+        }
+
+        let global_reserved = *self.global_reserved_lamports.read().await;
+        let available_global = total_balance.saturating_sub(global_reserved);
+        if amount_lamports > available_global {
+            return Err(CapitalError::InsufficientGlobalCapital {
+                requested: amount_lamports,
+                available: available_global,
+            });
+        }
+
+        let reservation = match &existing {
+            Some(reservation) => CapitalReservation {
+                amount_lamports: reservation.amount_lamports + amount_lamports,
+                ..reservation.clone()
+            },
+            None => CapitalReservation {
+                strategy_id,
+                position_id,
+                amount_lamports,
+                created_at: chrono::Utc::now(),
+            },
+        };
+        self.persist_reservation(&reservation).await;
+
+        {
+            let mut allocations = self.strategy_allocations.write().await;
+            if let Some(allocation) = allocations.get_mut(&strategy_id) {
+                allocation.reserved_lamports += amount_lamports;
+                if existing.is_none() {
+                    allocation.active_positions += 1;
+                }
+            }
+        }
+        {
+            let mut global = self.global_reserved_lamports.write().await;
+            *global += amount_lamports;
+        }
+        {
+            let mut reservations = self.reservations.write().await;
+            reservations.insert(position_id, reservation);
+        }
+
+        debug!(
+            "Extended reservation for position {} by {} SOL",
+            position_id,
+            amount_lamports as f64 / 1_000_000_000.0
+        );
+
+        Ok(())
+    }
+
+    /// Undoes an `extend_reservation` whose add did not go through.
+    pub async fn shrink_reservation(&self, position_id: Uuid, amount_lamports: u64) {
+        let Some(reservation) = self.reservations.read().await.get(&position_id).cloned() else {
+            return;
+        };
+        let released = amount_lamports.min(reservation.amount_lamports);
+        let reservation = CapitalReservation {
+            amount_lamports: reservation.amount_lamports - released,
+            ..reservation
+        };
+        self.persist_reservation(&reservation).await;
+
+        {
+            let mut allocations = self.strategy_allocations.write().await;
+            if let Some(allocation) = allocations.get_mut(&reservation.strategy_id) {
+                allocation.reserved_lamports =
+                    allocation.reserved_lamports.saturating_sub(released);
+            }
+        }
+        {
+            let mut global = self.global_reserved_lamports.write().await;
+            *global = global.saturating_sub(released);
+        }
+        {
+            let mut reservations = self.reservations.write().await;
+            reservations.insert(position_id, reservation);
+        }
+    }
+
     pub async fn get_strategy_usage(&self, strategy_id: Uuid) -> Option<StrategyUsage> {
         let total_balance = *self.total_balance_lamports.read().await;
         let allocations = self.strategy_allocations.read().await;
@@ -400,6 +598,7 @@ impl CapitalManager {
             StrategyUsage {
                 strategy_id,
                 max_allocation_percent: allocation.max_percent,
+                target_allocation_percent: allocation.target_percent,
                 max_allocation_lamports: max_capital,
                 current_reserved_lamports: allocation.reserved_lamports,
                 available_lamports: max_capital.saturating_sub(allocation.reserved_lamports),
@@ -446,6 +645,7 @@ impl CapitalManager {
                 StrategyUsage {
                     strategy_id: *strategy_id,
                     max_allocation_percent: allocation.max_percent,
+                    target_allocation_percent: allocation.target_percent,
                     max_allocation_lamports: max_capital,
                     current_reserved_lamports: allocation.reserved_lamports,
                     available_lamports: max_capital.saturating_sub(allocation.reserved_lamports),
@@ -498,6 +698,7 @@ impl CapitalManager {
         let mut allocations = self.strategy_allocations.write().await;
         for allocation in allocations.values_mut() {
             allocation.max_percent = equal_percent;
+            allocation.target_percent = equal_percent;
         }
 
         info!(
@@ -506,6 +707,113 @@ impl CapitalManager {
         );
     }
 
+    /// Sets each listed strategy's budget (percent of balance) immediately; unlisted
+    /// ones keep their budget. Every listed strategy must be registered and within the
+    /// rebalance bounds, and the resulting budgets may not add up to more than 100%.
+    /// Nothing changes if any check fails.
+    pub async fn rebalance(
+        &self,
+        allocations: HashMap<StrategyId, f64>,
+    ) -> Result<(), CapitalError> {
+        let (min, max) = (
+            self.rebalance_config.min_percent,
+            self.rebalance_config.max_percent,
+        );
+        let mut current = self.strategy_allocations.write().await;
+
+        for (strategy_id, percent) in &allocations {
+            if !current.contains_key(strategy_id) {
+                return Err(CapitalError::StrategyNotRegistered);
+            }
+            if !percent.is_finite() || *percent < min || *percent > max {
+                return Err(CapitalError::AllocationOutOfBounds {
+                    strategy_id: *strategy_id,
+                    percent: *percent,
+                    min_percent: min,
+                    max_percent: max,
+                });
+            }
+        }
+        let total_percent: f64 = current
+            .iter()
+            .map(|(id, allocation)| {
+                allocations
+                    .get(id)
+                    .copied()
+                    .unwrap_or(allocation.max_percent)
+            })
+            .sum();
+        if total_percent > 100.0 + 1e-9 {
+            return Err(CapitalError::AllocationsOverCommitted { total_percent });
+        }
+
+        for (strategy_id, percent) in allocations {
+            if let Some(allocation) = current.get_mut(&strategy_id) {
+                allocation.max_percent = percent;
+                allocation.target_percent = percent;
+                info!("📊 Rebalanced strategy {} to {:.2}%", strategy_id, percent);
+            }
+        }
+        Ok(())
+    }
+
+    /// Retargets budgets by recent P&L and moves each strategy's current budget
+    /// towards its target by at most `max_step_percent`. Strategies missing from
+    /// `pnl_by_strategy` are treated as flat. Returns the new targets.
+    pub async fn auto_rebalance(
+        &self,
+        pnl_by_strategy: &HashMap<StrategyId, f64>,
+    ) -> HashMap<StrategyId, f64> {
+        let mut allocations = self.strategy_allocations.write().await;
+        let pnl: HashMap<StrategyId, f64> = allocations
+            .keys()
+            .map(|id| (*id, pnl_by_strategy.get(id).copied().unwrap_or(0.0)))
+            .collect();
+        let targets = performance_targets(&pnl, &self.rebalance_config);
+        let step = self.rebalance_config.max_step_percent;
+
+        for (strategy_id, target) in &targets {
+            if let Some(allocation) = allocations.get_mut(strategy_id) {
+                let delta = (target - allocation.max_percent).clamp(-step, step);
+                allocation.target_percent = *target;
+                allocation.max_percent += delta;
+                debug!(
+                    "📊 Strategy {} budget {:.2}% -> target {:.2}% (pnl {:.4})",
+                    strategy_id, allocation.max_percent, target, pnl[strategy_id]
+                );
+            }
+        }
+
+        targets
+    }
+
+    /// Periodically shifts budget towards strategies with better recent P&L.
+    pub async fn start_auto_rebalance(self: Arc<Self>, trade_repo: Arc<TradeRepository>) {
+        let config = self.rebalance_config.clone();
+        info!(
+            "📊 Starting capital auto-rebalance (every {}s, {}h lookback, {:.0}-{:.0}% bounds)",
+            config.interval_secs, config.lookback_hours, config.min_percent, config.max_percent
+        );
+
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(std::time::Duration::from_secs(config.interval_secs)).await;
+
+                let since = chrono::Utc::now() - chrono::Duration::hours(config.lookback_hours);
+                match trade_repo.get_pnl_by_strategy(since).await {
+                    Ok(pnl) => {
+                        let pnl_sol = pnl
+                            .into_iter()
+                            .map(|(id, lamports)| (id, lamports as f64 / 1_000_000_000.0))
+                            .collect();
+                        self.auto_rebalance(&pnl_sol).await;
+                    }
+                    Err(e) => warn!("Capital auto-rebalance skipped: {}", e),
+                }
+            }
+        });
+    }
+
     pub async fn get_strategy_count(&self) -> usize {
         self.strategy_allocations.read().await.len()
     }
@@ -536,7 +844,10 @@ impl CapitalManager {
             loop {
                 tokio::time::sleep(std::time::Duration::from_secs(refresh_interval_secs)).await;
 
-                match Self::fetch_sol_balance(&client, &rpc_url, &wallet_address).await {
+                match self
+                    .read_sol_balance(&client, &rpc_url, &wallet_address)
+                    .await
+                {
                     Ok(balance_lamports) => {
                         let current = self.get_balance().await;
                         if balance_lamports != current {
@@ -556,6 +867,26 @@ impl CapitalManager {
         });
     }
 
+    async fn read_sol_balance(
+        &self,
+        client: &reqwest::Client,
+        rpc_url: &str,
+        wallet_address: &str,
+    ) -> Result<u64, String> {
+        let Some(cache) = &self.balance_cache else {
+            return Self::fetch_sol_balance(client, rpc_url, wallet_address).await;
+        };
+        cache
+            .get_or_fetch(wallet_address, move || async move {
+                Self::fetch_sol_balance(client, rpc_url, wallet_address)
+                    .await
+                    .map_err(AppError::ExternalApi)
+            })
+            .await
+            .map(|cached| cached.lamports)
+            .map_err(|e| e.to_string())
+    }
+
     async fn fetch_sol_balance(
         client: &reqwest::Client,
         rpc_url: &str,
@@ -603,6 +934,73 @@ impl CapitalManager {
     }
 }
 
+/// Budget targets weighted by P&L: the best performer gets up to 3x the share of the
+/// worst, then shares are clamped to the configured bounds and renormalized to 100%.
+fn performance_targets(
+    pnl: &HashMap<StrategyId, f64>,
+    config: &RebalanceConfig,
+) -> HashMap<StrategyId, f64> {
+    if pnl.is_empty() {
+        return HashMap::new();
+    }
+
+    let min_pnl = pnl.values().copied().fold(f64::INFINITY, f64::min);
+    let max_pnl = pnl.values().copied().fold(f64::NEG_INFINITY, f64::max);
+    let spread = max_pnl - min_pnl;
+
+    let weights: HashMap<StrategyId, f64> = pnl
+        .iter()
+        .map(|(id, p)| {
+            let weight = if spread > 0.0 {
+                0.5 + (p - min_pnl) / spread
+            } else {
+                1.0
+            };
+            (*id, weight)
+        })
+        .collect();
+    let total_weight: f64 = weights.values().sum();
+    let mut targets: HashMap<StrategyId, f64> = weights
+        .into_iter()
+        .map(|(id, w)| (id, w / total_weight * 100.0))
+        .collect();
+
+    // Bounds that can't all be met (e.g. 4 strategies with a 30% floor) collapse to equal
+    let equal = 100.0 / pnl.len() as f64;
+    let lower = config.min_percent.min(equal);
+    let upper = config.max_percent.max(equal);
+
+    for _ in 0..pnl.len() {
+        let mut clamped_total = 0.0;
+        let mut free_total = 0.0;
+        let mut free = Vec::new();
+        for (id, target) in targets.iter_mut() {
+            if *target <= lower {
+                *target = lower;
+                clamped_total += lower;
+            } else if *target >= upper {
+                *target = upper;
+                clamped_total += upper;
+            } else {
+                free_total += *target;
+                free.push(*id);
+            }
+        }
+
+        let remaining = 100.0 - clamped_total;
+        if free.is_empty() || free_total <= 0.0 || (remaining - free_total).abs() < 1e-9 {
+            break;
+        }
+        for id in free {
+            if let Some(target) = targets.get_mut(&id) {
+                *target *= remaining / free_total;
+            }
+        }
+    }
+
+    targets
+}
+
 impl Default for CapitalManager {
     fn default() -> Self {
         Self::new()
@@ -613,6 +1011,7 @@ impl Default for CapitalManager {
 pub struct StrategyUsage {
     pub strategy_id: Uuid,
     pub max_allocation_percent: f64,
+    pub target_allocation_percent: f64,
     pub max_allocation_lamports: u64,
     pub current_reserved_lamports: u64,
     pub available_lamports: u64,
@@ -646,6 +1045,15 @@ pub enum CapitalError {
         requested: u64,
         available: u64,
     },
+    AllocationOutOfBounds {
+        strategy_id: StrategyId,
+        percent: f64,
+        min_percent: f64,
+        max_percent: f64,
+    },
+    AllocationsOverCommitted {
+        total_percent: f64,
+    },
 }
 
 impl std::fmt::Display for CapitalError {
@@ -676,8 +1084,191 @@ impl std::fmt::Display for CapitalError {
                 *requested as f64 / 1_000_000_000.0,
                 *available as f64 / 1_000_000_000.0
             ),
+            CapitalError::AllocationOutOfBounds {
+                strategy_id,
+                percent,
+                min_percent,
+                max_percent,
+            } => write!(
+                f,
+                "Allocation for strategy {} must be between {}% and {}%, got {}%",
+                strategy_id, min_percent, max_percent, percent
+            ),
+            CapitalError::AllocationsOverCommitted { total_percent } => write!(
+                f,
+                "Allocations would total {:.2}% of balance, more than 100%",
+                total_percent
+            ),
         }
     }
 }
 
 impl std::error::Error for CapitalError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> RebalanceConfig {
+        RebalanceConfig {
+            min_percent: 20.0,
+            max_percent: 60.0,
+            max_step_percent: 100.0,
+            ..RebalanceConfig::default()
+        }
+    }
+
+    async fn manager_with_two_strategies(config: RebalanceConfig) -> (CapitalManager, Uuid, Uuid) {
+        let manager = CapitalManager::new().with_rebalance_config(config);
+        let winner = Uuid::new_v4();
+        let loser = Uuid::new_v4();
+        manager
+            .register_strategies_equal(vec![winner, loser], 5)
+            .await;
+        (manager, winner, loser)
+    }
+
+    #[tokio::test]
+    async fn test_auto_rebalance_shifts_budget_to_better_performer_within_bounds() {
+        let (manager, winner, loser) = manager_with_two_strategies(config()).await;
+
+        let pnl = HashMap::from([(winner, 2.5), (loser, -1.0)]);
+        let targets = manager.auto_rebalance(&pnl).await;
+
+        assert!(targets[&winner] > targets[&loser]);
+        assert!((targets.values().sum::<f64>() - 100.0).abs() < 1e-6);
+
+        let winner_usage = manager.get_strategy_usage(winner).await.unwrap();
+        let loser_usage = manager.get_strategy_usage(loser).await.unwrap();
+        assert!(winner_usage.max_allocation_percent > 50.0);
+        assert!(loser_usage.max_allocation_percent < 50.0);
+        assert!(winner_usage.max_allocation_percent <= 60.0 + 1e-9);
+        assert!(loser_usage.max_allocation_percent >= 20.0 - 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_auto_rebalance_clamps_and_steps_towards_target() {
+        let (manager, winner, loser) = manager_with_two_strategies(RebalanceConfig {
+            min_percent: 45.0,
+            max_percent: 55.0,
+            max_step_percent: 2.0,
+            ..RebalanceConfig::default()
+        })
+        .await;
+
+        let pnl = HashMap::from([(winner, 100.0), (loser, -100.0)]);
+        let targets = manager.auto_rebalance(&pnl).await;
+        assert!((targets[&winner] - 55.0).abs() < 1e-9);
+        assert!((targets[&loser] - 45.0).abs() < 1e-9);
+
+        let winner_usage = manager.get_strategy_usage(winner).await.unwrap();
+        assert!((winner_usage.max_allocation_percent - 52.0).abs() < 1e-9);
+        assert!((winner_usage.target_allocation_percent - 55.0).abs() < 1e-9);
+
+        for _ in 0..5 {
+            manager.auto_rebalance(&pnl).await;
+        }
+        let winner_usage = manager.get_strategy_usage(winner).await.unwrap();
+        let loser_usage = manager.get_strategy_usage(loser).await.unwrap();
+        assert!((winner_usage.max_allocation_percent - 55.0).abs() < 1e-9);
+        assert!((loser_usage.max_allocation_percent - 45.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_extend_reservation_respects_budget_and_balance() {
+        let (manager, strategy, _) = manager_with_two_strategies(config()).await;
+        manager.update_balance(10_000_000_000).await;
+        let position_id = Uuid::new_v4();
+        manager
+            .reserve_capital(strategy, position_id, 3_000_000_000)
+            .await
+            .unwrap();
+
+        // 50% of 10 SOL: 2 SOL left in the strategy's budget
+        assert!(matches!(
+            manager
+                .extend_reservation(strategy, position_id, 2_500_000_000)
+                .await,
+            Err(CapitalError::AllocationExceeded { .. })
+        ));
+        manager
+            .extend_reservation(strategy, position_id, 1_500_000_000)
+            .await
+            .unwrap();
+        let usage = manager.get_strategy_usage(strategy).await.unwrap();
+        assert_eq!(usage.current_reserved_lamports, 4_500_000_000);
+        assert_eq!(usage.active_positions, 1);
+
+        manager.shrink_reservation(position_id, 1_500_000_000).await;
+        assert_eq!(
+            manager.get_global_usage().await.global_reserved_lamports,
+            3_000_000_000
+        );
+        assert_eq!(
+            manager.release_capital(position_id).await,
+            Some(3_000_000_000)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_equal_pnl_and_manual_rebalance() {
+        let (manager, first, second) = manager_with_two_strategies(config()).await;
+
+        let targets = manager.auto_rebalance(&HashMap::new()).await;
+        assert!((targets[&first] - 50.0).abs() < 1e-9);
+        assert!((targets[&second] - 50.0).abs() < 1e-9);
+
+        manager
+            .rebalance(HashMap::from([(first, 40.0), (second, 60.0)]))
+            .await
+            .unwrap();
+        let usage = manager.get_strategy_usage(first).await.unwrap();
+        assert_eq!(usage.max_allocation_percent, 40.0);
+        assert_eq!(usage.target_allocation_percent, 40.0);
+        assert_eq!(manager.get_strategy_count().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_manual_rebalance_rejects_invalid_allocations() {
+        let (manager, first, second) = manager_with_two_strategies(config()).await;
+        let budget = |id| {
+            let manager = &manager;
+            async move {
+                manager
+                    .get_strategy_usage(id)
+                    .await
+                    .unwrap()
+                    .max_allocation_percent
+            }
+        };
+        let before = (budget(first).await, budget(second).await);
+
+        // 60% next to the other strategy's 50% over-commits the balance
+        assert!(matches!(
+            manager.rebalance(HashMap::from([(first, 60.0)])).await,
+            Err(CapitalError::AllocationsOverCommitted { .. })
+        ));
+        // Above the configured per-strategy maximum, even though the sum fits
+        assert!(matches!(
+            manager
+                .rebalance(HashMap::from([(first, 80.0), (second, 15.0)]))
+                .await,
+            Err(CapitalError::AllocationOutOfBounds { .. })
+        ));
+        assert!(matches!(
+            manager
+                .rebalance(HashMap::from([(first, 5.0), (second, 50.0)]))
+                .await,
+            Err(CapitalError::AllocationOutOfBounds { .. })
+        ));
+        assert!(matches!(
+            manager
+                .rebalance(HashMap::from([(first, 30.0), (Uuid::new_v4(), 10.0)]))
+                .await,
+            Err(CapitalError::StrategyNotRegistered)
+        ));
+
+        // A rejected rebalance changes nothing, not even its valid entries
+        assert_eq!((budget(first).await, budget(second).await), before);
+    }
+}
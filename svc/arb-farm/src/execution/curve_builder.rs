@@ -8,10 +8,15 @@ use solana_sdk::{
     transaction::Transaction,
 };
 use spl_associated_token_account::get_associated_token_address_with_program_id;
+use std::collections::HashMap;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
+use super::exit_fallback::ExitVenue;
+use super::sell_protection::{ProtectedSellResult, SellProtection};
 use crate::error::{AppError, AppResult};
+use crate::helius::{estimate_priority_fee_for_accounts, PriorityFeeSource, PriorityLevel};
 use crate::venues::curves::math::{
     calculate_min_sol_out, calculate_min_tokens_out, BondingCurveMath, BuyResult, PumpFunCurve,
     SellResult, PUMP_FUN_EVENT_AUTHORITY, PUMP_FUN_FEE_PROGRAM, PUMP_FUN_FEE_RECIPIENT,
@@ -20,6 +25,7 @@ use crate::venues::curves::math::{
 use crate::venues::curves::on_chain::{
     derive_pump_fun_bonding_curve, OnChainCurveState, OnChainFetcher,
 };
+use crate::wallet::BalanceCache;
 
 const DEFAULT_COMPUTE_UNITS: u32 = 200_000;
 const DEFAULT_PRIORITY_FEE_MICRO_LAMPORTS: u64 = 10_000_000; // 10x increase for reliable exits (~0.002 SOL per tx)
@@ -27,6 +33,12 @@ const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
 const RAYDIUM_API_URL: &str = "https://transaction-v1.raydium.io";
 const HIGH_SLIPPAGE_WARNING_BPS: u16 = 1500; // 15% - warn above this
 const EXTREME_SLIPPAGE_WARNING_BPS: u16 = 3000; // 30% - warn strongly above this
+const BASE_SIGNATURE_FEE_LAMPORTS: u64 = 5_000;
+pub const DEFAULT_MAX_NETWORK_FEE_FRACTION: f64 = 0.15;
+/// Ceiling on an estimated compute-unit price (~0.01 SOL at the default compute units).
+pub const DEFAULT_MAX_PRIORITY_FEE_MICRO_LAMPORTS: u64 = 50_000_000;
+const PRIORITY_FEE_CACHE_TTL: Duration = Duration::from_secs(5);
+const PRIORITY_FEE_ESTIMATE_TIMEOUT: Duration = Duration::from_millis(800);
 
 fn deserialize_string_or_f64<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
 where
@@ -140,6 +152,79 @@ pub struct CurveBuildResult {
     pub fee_lamports: u64,
     pub compute_units: u32,
     pub priority_fee_lamports: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_economics: Option<SnipeFeeEconomics>,
+}
+
+/// Fee-adjusted cost of a curve buy. Network fees (base + priority) are fixed per tx,
+/// so they dominate small snipes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnipeFeeEconomics {
+    pub requested_sol_lamports: u64,
+    pub buy_sol_lamports: u64,
+    pub network_fee_lamports: u64,
+    pub curve_fee_lamports: u64,
+    pub total_cost_lamports: u64,
+    pub network_fee_fraction: f64,
+    pub max_network_fee_fraction: f64,
+    pub downsized: bool,
+}
+
+/// Refuses snipes whose network fees exceed `max_fee_fraction` of the requested amount.
+/// With `fee_inclusive` the fees come out of the requested amount, so the curve buy is
+/// downsized and total outlay stays at what was asked for.
+pub fn size_snipe_for_fees(
+    requested_sol_lamports: u64,
+    network_fee_lamports: u64,
+    max_fee_fraction: f64,
+    fee_inclusive: bool,
+) -> AppResult<SnipeFeeEconomics> {
+    if requested_sol_lamports == 0 {
+        return Err(AppError::Validation(
+            "Cannot buy with zero SOL amount".to_string(),
+        ));
+    }
+
+    let network_fee_fraction = network_fee_lamports as f64 / requested_sol_lamports as f64;
+    if network_fee_fraction > max_fee_fraction {
+        return Err(AppError::Validation(format!(
+            "Network fees {} lamports are {:.1}% of {} lamports snipe (max {:.1}%)",
+            network_fee_lamports,
+            network_fee_fraction * 100.0,
+            requested_sol_lamports,
+            max_fee_fraction * 100.0
+        )));
+    }
+
+    let buy_sol_lamports = if fee_inclusive {
+        requested_sol_lamports - network_fee_lamports
+    } else {
+        requested_sol_lamports
+    };
+
+    Ok(SnipeFeeEconomics {
+        requested_sol_lamports,
+        buy_sol_lamports,
+        network_fee_lamports,
+        curve_fee_lamports: 0,
+        total_cost_lamports: buy_sol_lamports + network_fee_lamports,
+        network_fee_fraction,
+        max_network_fee_fraction: max_fee_fraction,
+        downsized: buy_sol_lamports < requested_sol_lamports,
+    })
+}
+
+/// Accounts the transaction writes that aren't signers, i.e. the contended state
+/// (bonding curve, vaults) whose recent fees decide what it takes to land.
+pub fn writable_accounts(instructions: &[Instruction]) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    instructions
+        .iter()
+        .flat_map(|ix| ix.accounts.iter())
+        .filter(|meta| meta.is_writable && !meta.is_signer)
+        .filter(|meta| seen.insert(meta.pubkey))
+        .map(|meta| meta.pubkey.to_string())
+        .collect()
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -173,6 +258,9 @@ pub struct SimulatedTrade {
 pub struct PostGraduationSellResult {
     pub transaction_base64: String,
     pub expected_sol_out: u64,
+    /// Threshold the venue baked into the transaction, when it reported one.
+    #[serde(default)]
+    pub min_sol_out: Option<u64>,
     pub price_impact_percent: f64,
     pub route_label: String,
 }
@@ -248,6 +336,8 @@ struct RaydiumQuoteResponse {
     pub output_mint: String,
     pub in_amount: String,
     pub out_amount: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub other_amount_threshold: Option<String>,
     #[serde(default, deserialize_with = "deserialize_string_or_f64")]
     pub price_impact_pct: Option<f64>,
     #[serde(default)]
@@ -293,6 +383,13 @@ pub struct CurveTransactionBuilder {
     on_chain_fetcher: Arc<OnChainFetcher>,
     compute_units: u32,
     priority_fee_micro_lamports: u64,
+    priority_fee_source: Option<Arc<dyn PriorityFeeSource>>,
+    priority_fee_level: PriorityLevel,
+    max_priority_fee_micro_lamports: u64,
+    priority_fee_cache: Mutex<HashMap<Vec<String>, (u64, Instant)>>,
+    max_network_fee_fraction: f64,
+    fee_inclusive_sizing: bool,
+    balance_cache: Option<Arc<BalanceCache>>,
 }
 
 impl CurveTransactionBuilder {
@@ -302,6 +399,13 @@ impl CurveTransactionBuilder {
             on_chain_fetcher: Arc::new(OnChainFetcher::new(rpc_url)),
             compute_units: DEFAULT_COMPUTE_UNITS,
             priority_fee_micro_lamports: DEFAULT_PRIORITY_FEE_MICRO_LAMPORTS,
+            priority_fee_source: None,
+            priority_fee_level: PriorityLevel::High,
+            max_priority_fee_micro_lamports: DEFAULT_MAX_PRIORITY_FEE_MICRO_LAMPORTS,
+            priority_fee_cache: Mutex::new(HashMap::new()),
+            max_network_fee_fraction: DEFAULT_MAX_NETWORK_FEE_FRACTION,
+            fee_inclusive_sizing: false,
+            balance_cache: None,
         }
     }
 
@@ -350,12 +454,135 @@ impl CurveTransactionBuilder {
         self
     }
 
+    /// Prices pump.fun transactions from a Helius estimate scoped to the accounts
+    /// they write, falling back to the static fee when no estimate is available.
+    pub fn with_priority_fee_source(
+        mut self,
+        source: Arc<dyn PriorityFeeSource>,
+        level: PriorityLevel,
+    ) -> Self {
+        self.priority_fee_source = Some(source);
+        self.priority_fee_level = level;
+        self
+    }
+
+    /// Caps estimated fees; the static fee is used as configured.
+    pub fn with_max_priority_fee(mut self, micro_lamports: u64) -> Self {
+        self.max_priority_fee_micro_lamports = micro_lamports;
+        self
+    }
+
+    pub fn with_max_network_fee_fraction(mut self, fraction: f64) -> Self {
+        self.max_network_fee_fraction = fraction;
+        self
+    }
+
+    pub fn with_fee_inclusive_sizing(mut self, enabled: bool) -> Self {
+        self.fee_inclusive_sizing = enabled;
+        self
+    }
+
+    pub fn priority_fee_lamports(&self) -> u64 {
+        self.priority_fee_lamports_at(self.priority_fee_micro_lamports)
+    }
+
+    pub fn network_fee_lamports(&self) -> u64 {
+        self.network_fee_lamports_at(self.priority_fee_micro_lamports)
+    }
+
+    fn priority_fee_lamports_at(&self, micro_lamports: u64) -> u64 {
+        (self.compute_units as u64 * micro_lamports) / 1_000_000
+    }
+
+    fn network_fee_lamports_at(&self, micro_lamports: u64) -> u64 {
+        self.priority_fee_lamports_at(micro_lamports) + BASE_SIGNATURE_FEE_LAMPORTS
+    }
+
+    /// Compute-unit price for a transaction built from `instructions`.
+    ///
+    /// Estimates are cached per writable-account set for a few seconds, bounded by a
+    /// timeout so a slow Helius call can't stall the trade, and clamped to the max fee.
+    async fn resolve_priority_fee(&self, instructions: &[Instruction]) -> u64 {
+        let Some(source) = &self.priority_fee_source else {
+            return self.priority_fee_micro_lamports;
+        };
+
+        let accounts = writable_accounts(instructions);
+        if let Some(&(fee, fetched_at)) = self.priority_fee_cache.lock().unwrap().get(&accounts) {
+            if fetched_at.elapsed() < PRIORITY_FEE_CACHE_TTL {
+                return fee;
+            }
+        }
+
+        let estimate = tokio::time::timeout(
+            PRIORITY_FEE_ESTIMATE_TIMEOUT,
+            estimate_priority_fee_for_accounts(source.as_ref(), &accounts, self.priority_fee_level),
+        )
+        .await;
+        let fee = match estimate {
+            Ok(Ok(fee)) if fee.micro_lamports > 0 => {
+                let clamped = fee.micro_lamports.min(self.max_priority_fee_micro_lamports);
+                if clamped < fee.micro_lamports {
+                    tracing::warn!(
+                        estimated = fee.micro_lamports,
+                        max = self.max_priority_fee_micro_lamports,
+                        "Priority fee estimate above max, clamping"
+                    );
+                }
+                tracing::debug!(
+                    scope = ?fee.scope,
+                    accounts = accounts.len(),
+                    micro_lamports = clamped,
+                    "Resolved curve priority fee"
+                );
+                clamped
+            }
+            Ok(Ok(_)) => return self.priority_fee_micro_lamports,
+            Ok(Err(e)) => {
+                tracing::warn!(
+                    "Priority fee estimate unavailable, using static {} micro-lamports: {}",
+                    self.priority_fee_micro_lamports,
+                    e
+                );
+                return self.priority_fee_micro_lamports;
+            }
+            Err(_) => {
+                tracing::warn!(
+                    "Priority fee estimate timed out after {:?}, using static {} micro-lamports",
+                    PRIORITY_FEE_ESTIMATE_TIMEOUT,
+                    self.priority_fee_micro_lamports
+                );
+                return self.priority_fee_micro_lamports;
+            }
+        };
+
+        let mut cache = self.priority_fee_cache.lock().unwrap();
+        cache.retain(|_, (_, fetched_at)| fetched_at.elapsed() < PRIORITY_FEE_CACHE_TTL);
+        cache.insert(accounts, (fee, Instant::now()));
+        fee
+    }
+
     pub fn with_on_chain_fetcher(mut self, fetcher: Arc<OnChainFetcher>) -> Self {
         self.on_chain_fetcher = fetcher;
         self
     }
 
+    pub fn with_balance_cache(mut self, cache: Arc<BalanceCache>) -> Self {
+        self.balance_cache = Some(cache);
+        self
+    }
+
     pub async fn get_wallet_balance(&self, wallet_address: &str) -> AppResult<u64> {
+        match &self.balance_cache {
+            Some(cache) => Ok(cache
+                .get_or_fetch(wallet_address, || self.fetch_wallet_balance(wallet_address))
+                .await?
+                .lamports),
+            None => self.fetch_wallet_balance(wallet_address).await,
+        }
+    }
+
+    async fn fetch_wallet_balance(&self, wallet_address: &str) -> AppResult<u64> {
         let client = reqwest::Client::new();
         let response = client
             .post(&self.rpc_url)
@@ -453,35 +680,61 @@ impl CurveTransactionBuilder {
     }
 
     pub async fn build_pump_fun_buy(&self, params: &CurveBuyParams) -> AppResult<CurveBuildResult> {
-        // Validate non-zero SOL amount to prevent wasting network fees
-        if params.sol_amount_lamports == 0 {
+        let curve_state = self
+            .on_chain_fetcher
+            .get_pump_fun_bonding_curve(&params.mint)
+            .await?;
+
+        if curve_state.is_complete {
             return Err(AppError::Validation(
-                "Cannot buy with zero SOL amount".to_string(),
+                "Token has graduated. Use DEX for trading.".to_string(),
             ));
         }
 
+        // Amounts don't change which accounts are written, so price against a draft
+        let draft = self.create_pump_fun_buy_instructions(
+            &params.mint,
+            &params.user_wallet,
+            &curve_state,
+            params.sol_amount_lamports,
+            0,
+            self.priority_fee_micro_lamports,
+        )?;
+        let priority_fee = self.resolve_priority_fee(&draft).await;
+
+        let mut fee_economics = size_snipe_for_fees(
+            params.sol_amount_lamports,
+            self.network_fee_lamports_at(priority_fee),
+            self.max_network_fee_fraction,
+            self.fee_inclusive_sizing,
+        )?;
+
         // Minimum 0.001 SOL to prevent dust transactions
         const MIN_SOL_LAMPORTS: u64 = 1_000_000; // 0.001 SOL
-        if params.sol_amount_lamports < MIN_SOL_LAMPORTS {
+        if fee_economics.buy_sol_lamports < MIN_SOL_LAMPORTS {
             return Err(AppError::Validation(format!(
                 "SOL amount {} lamports below minimum {} (0.001 SOL)",
-                params.sol_amount_lamports, MIN_SOL_LAMPORTS
+                fee_economics.buy_sol_lamports, MIN_SOL_LAMPORTS
             )));
         }
 
-        let curve_state = self
-            .on_chain_fetcher
-            .get_pump_fun_bonding_curve(&params.mint)
-            .await?;
-
-        if curve_state.is_complete {
-            return Err(AppError::Validation(
-                "Token has graduated. Use DEX for trading.".to_string(),
-            ));
+        if fee_economics.downsized {
+            tracing::info!(
+                "Downsized curve buy for {} from {} to {} lamports to cover {} lamports network fees",
+                &params.mint[..12.min(params.mint.len())],
+                fee_economics.requested_sol_lamports,
+                fee_economics.buy_sol_lamports,
+                fee_economics.network_fee_lamports
+            );
         }
+        let params = &CurveBuyParams {
+            sol_amount_lamports: fee_economics.buy_sol_lamports,
+            ..params.clone()
+        };
 
         let simulation = self.simulate_buy(params).await?;
         let min_tokens = simulation.min_output;
+        fee_economics.curve_fee_lamports = simulation.fee_lamports;
 
         let instructions = self.create_pump_fun_buy_instructions(
             &params.mint,
@@ -489,6 +742,7 @@ impl CurveTransactionBuilder {
             &curve_state,
             params.sol_amount_lamports,
             min_tokens,
+            priority_fee,
         )?;
 
         let user_pubkey = Pubkey::from_str(&params.user_wallet)
@@ -507,9 +761,6 @@ impl CurveTransactionBuilder {
         let tx_base64 =
             base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &tx_bytes);
 
-        let priority_fee_lamports =
-            (self.compute_units as u64 * self.priority_fee_micro_lamports) / 1_000_000;
-
         Ok(CurveBuildResult {
             transaction_base64: tx_base64,
             expected_tokens_out: Some(simulation.output_amount),
@@ -519,7 +770,8 @@ impl CurveTransactionBuilder {
             price_impact_percent: simulation.price_impact_percent,
             fee_lamports: simulation.fee_lamports,
             compute_units: self.compute_units,
-            priority_fee_lamports,
+            priority_fee_lamports: self.priority_fee_lamports_at(priority_fee),
+            fee_economics: Some(fee_economics),
         })
     }
 
@@ -572,14 +824,29 @@ impl CurveTransactionBuilder {
             "Detected token program for sell"
         );
 
-        let instructions = self.create_pump_fun_sell_instructions(
+        let draft = self.create_pump_fun_sell_instructions(
             &params.mint,
             &params.user_wallet,
             &curve_state,
             params.token_amount,
             min_sol,
             token_program,
+            self.priority_fee_micro_lamports,
         )?;
+        let priority_fee = self.resolve_priority_fee(&draft).await;
+        let instructions = if priority_fee == self.priority_fee_micro_lamports {
+            draft
+        } else {
+            self.create_pump_fun_sell_instructions(
+                &params.mint,
+                &params.user_wallet,
+                &curve_state,
+                params.token_amount,
+                min_sol,
+                token_program,
+                priority_fee,
+            )?
+        };
 
         let user_pubkey = Pubkey::from_str(&params.user_wallet)
             .map_err(|e| AppError::Validation(format!("Invalid user wallet: {}", e)))?;
@@ -597,9 +864,6 @@ impl CurveTransactionBuilder {
         let tx_base64 =
             base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &tx_bytes);
 
-        let priority_fee_lamports =
-            (self.compute_units as u64 * self.priority_fee_micro_lamports) / 1_000_000;
-
         Ok(CurveBuildResult {
             transaction_base64: tx_base64,
             expected_tokens_out: None,
@@ -609,7 +873,8 @@ impl CurveTransactionBuilder {
             price_impact_percent: simulation.price_impact_percent,
             fee_lamports: simulation.fee_lamports,
             compute_units: self.compute_units,
-            priority_fee_lamports,
+            priority_fee_lamports: self.priority_fee_lamports_at(priority_fee),
+            fee_economics: None,
         })
     }
 
@@ -620,6 +885,7 @@ impl CurveTransactionBuilder {
         curve_state: &OnChainCurveState,
         sol_amount: u64,
         min_tokens_out: u64,
+        priority_fee_micro_lamports: u64,
     ) -> AppResult<Vec<Instruction>> {
         let mut instructions = Vec::new();
 
@@ -628,7 +894,7 @@ impl CurveTransactionBuilder {
         ));
 
         instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
-            self.priority_fee_micro_lamports,
+            priority_fee_micro_lamports,
         ));
 
         let program_id = Pubkey::from_str(PUMP_FUN_PROGRAM_ID)
@@ -749,6 +1015,7 @@ impl CurveTransactionBuilder {
         token_amount: u64,
         min_sol_out: u64,
         token_program: Pubkey,
+        priority_fee_micro_lamports: u64,
     ) -> AppResult<Vec<Instruction>> {
         let mut instructions = Vec::new();
 
@@ -757,7 +1024,7 @@ impl CurveTransactionBuilder {
         ));
 
         instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
-            self.priority_fee_micro_lamports,
+            priority_fee_micro_lamports,
         ));
 
         let program_id = Pubkey::from_str(PUMP_FUN_PROGRAM_ID)
@@ -975,6 +1242,7 @@ impl CurveTransactionBuilder {
         Ok(PostGraduationSellResult {
             transaction_base64: swap_result.swap_transaction,
             expected_sol_out,
+            min_sol_out: quote.other_amount_threshold.parse().ok(),
             price_impact_percent: price_impact,
             route_label: quote
                 .route_plan
@@ -1128,11 +1396,76 @@ impl CurveTransactionBuilder {
         Ok(PostGraduationSellResult {
             transaction_base64: transaction,
             expected_sol_out,
+            min_sol_out: quote
+                .other_amount_threshold
+                .as_deref()
+                .and_then(|t| t.parse().ok()),
             price_impact_percent: price_impact,
             route_label: "Raydium".to_string(),
         })
     }
 
+    /// Curve or Raydium market sell with a min-out derived from the venue's quote and
+    /// `protection`, checked to be baked into the returned transaction so a fill below
+    /// it reverts instead of landing. Jupiter sells are protected by
+    /// `TransactionBuilder::build_protected_exit_swap`, which handles every base currency.
+    pub async fn build_protected_sell(
+        &self,
+        params: &CurveSellParams,
+        venue: ExitVenue,
+        protection: SellProtection,
+    ) -> AppResult<ProtectedSellResult> {
+        let params = CurveSellParams {
+            slippage_bps: protection.max_slippage_bps,
+            ..params.clone()
+        };
+
+        let (transaction_base64, expected_out, baked_min_out, price_impact_percent) = match venue {
+            ExitVenue::Curve => {
+                let build = self.build_pump_fun_sell(&params).await?;
+                (
+                    build.transaction_base64,
+                    build.expected_sol_out.unwrap_or(0),
+                    build.min_sol_out,
+                    build.price_impact_percent,
+                )
+            }
+            ExitVenue::Raydium => {
+                let build = self.build_raydium_sell(&params).await?;
+                (
+                    build.transaction_base64,
+                    build.expected_sol_out,
+                    build.min_sol_out,
+                    build.price_impact_percent,
+                )
+            }
+            ExitVenue::Jupiter => {
+                return Err(AppError::Internal(
+                    "Jupiter sells are built by TransactionBuilder::build_protected_exit_swap"
+                        .to_string(),
+                ))
+            }
+        };
+
+        let quote = protection.verify(expected_out, baked_min_out)?;
+
+        tracing::info!(
+            mint = &params.mint[..12.min(params.mint.len())],
+            venue = %venue,
+            expected_out = quote.expected_out,
+            min_out = quote.min_out,
+            "Built protected sell"
+        );
+
+        Ok(ProtectedSellResult {
+            venue,
+            transaction_base64,
+            token_amount_in: params.token_amount,
+            quote,
+            price_impact_percent,
+        })
+    }
+
     /// Build a Jupiter swap transaction for buying a token after graduation (SOL -> Token)
     pub async fn build_post_graduation_buy(
         &self,
@@ -1285,7 +1618,9 @@ impl CurveTransactionBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::helius::priority_fee::PriorityFeeResponse;
     use crate::venues::curves::math::sol_to_lamports;
+    use async_trait::async_trait;
 
     #[test]
     fn test_curve_buy_params() {
@@ -1312,4 +1647,284 @@ mod tests {
         assert_eq!(params.token_amount, 1_000_000_000_000);
         assert_eq!(params.slippage_bps, 150);
     }
+
+    #[test]
+    fn test_high_priority_fee_refuses_tiny_snipe() {
+        let builder = CurveTransactionBuilder::new("http://localhost:8899")
+            .with_priority_fee(50_000_000)
+            .with_max_network_fee_fraction(0.10);
+        let network_fee = builder.network_fee_lamports();
+        assert_eq!(network_fee, 10_005_000);
+
+        let tiny = size_snipe_for_fees(
+            sol_to_lamports(0.02),
+            network_fee,
+            builder.max_network_fee_fraction,
+            false,
+        );
+        assert!(matches!(tiny, Err(AppError::Validation(_))));
+
+        let large = size_snipe_for_fees(
+            sol_to_lamports(0.5),
+            network_fee,
+            builder.max_network_fee_fraction,
+            false,
+        )
+        .unwrap();
+        assert_eq!(large.buy_sol_lamports, sol_to_lamports(0.5));
+        assert_eq!(
+            large.total_cost_lamports,
+            sol_to_lamports(0.5) + network_fee
+        );
+        assert!(large.network_fee_fraction < 0.03);
+        assert!(!large.downsized);
+    }
+
+    #[test]
+    fn test_fee_inclusive_sizing_downsizes_buy() {
+        let economics = size_snipe_for_fees(sol_to_lamports(0.1), 2_005_000, 0.15, true).unwrap();
+
+        assert!(economics.downsized);
+        assert_eq!(economics.buy_sol_lamports, 100_000_000 - 2_005_000);
+        assert_eq!(economics.total_cost_lamports, sol_to_lamports(0.1));
+    }
+
+    fn fee_response(fee: u64) -> PriorityFeeResponse {
+        PriorityFeeResponse {
+            min: fee,
+            low: fee,
+            medium: fee,
+            high: fee,
+            very_high: fee,
+            unsafe_max: fee,
+            recommended: fee,
+        }
+    }
+
+    struct ScopedFees {
+        calls: Mutex<Vec<Option<Vec<String>>>>,
+    }
+
+    #[async_trait]
+    impl PriorityFeeSource for ScopedFees {
+        async fn get_priority_fee_estimate(
+            &self,
+            _transaction_base64: Option<&str>,
+            account_keys: Option<&[String]>,
+        ) -> AppResult<PriorityFeeResponse> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(account_keys.map(|keys| keys.to_vec()));
+            let fee = if account_keys.is_some() {
+                750_000
+            } else {
+                20_000
+            };
+            Ok(fee_response(fee))
+        }
+    }
+
+    struct FixedFee {
+        fee: u64,
+        delay: Duration,
+        calls: Mutex<usize>,
+    }
+
+    #[async_trait]
+    impl PriorityFeeSource for FixedFee {
+        async fn get_priority_fee_estimate(
+            &self,
+            _transaction_base64: Option<&str>,
+            _account_keys: Option<&[String]>,
+        ) -> AppResult<PriorityFeeResponse> {
+            *self.calls.lock().unwrap() += 1;
+            tokio::time::sleep(self.delay).await;
+            Ok(fee_response(self.fee))
+        }
+    }
+
+    fn fixed_fee(fee: u64, delay: Duration) -> Arc<FixedFee> {
+        Arc::new(FixedFee {
+            fee,
+            delay,
+            calls: Mutex::new(0),
+        })
+    }
+
+    fn buy_instructions(builder: &CurveTransactionBuilder) -> Vec<Instruction> {
+        let mint = Pubkey::new_unique();
+        builder
+            .create_pump_fun_buy_instructions(
+                &mint.to_string(),
+                &Pubkey::new_unique().to_string(),
+                &curve_state(&mint),
+                sol_to_lamports(0.1),
+                0,
+                DEFAULT_PRIORITY_FEE_MICRO_LAMPORTS,
+            )
+            .unwrap()
+    }
+
+    fn curve_state(mint: &Pubkey) -> OnChainCurveState {
+        OnChainCurveState {
+            mint: mint.to_string(),
+            bonding_curve_address: Pubkey::new_unique().to_string(),
+            associated_bonding_curve: Pubkey::new_unique().to_string(),
+            virtual_sol_reserves: 30_000_000_000,
+            virtual_token_reserves: 1_073_000_000_000_000,
+            real_sol_reserves: 0,
+            real_token_reserves: 793_100_000_000_000,
+            token_total_supply: 1_000_000_000_000_000,
+            is_complete: false,
+            creator: Pubkey::new_unique().to_string(),
+            created_slot: 0,
+            is_mayhem_mode: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_curve_buy_priced_from_its_writable_accounts() {
+        let source = Arc::new(ScopedFees {
+            calls: Mutex::new(Vec::new()),
+        });
+        let builder = CurveTransactionBuilder::new("http://localhost:8899")
+            .with_priority_fee_source(source.clone(), PriorityLevel::High);
+
+        let mint = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let state = curve_state(&mint);
+        let instructions = builder
+            .create_pump_fun_buy_instructions(
+                &mint.to_string(),
+                &user.to_string(),
+                &state,
+                sol_to_lamports(0.1),
+                0,
+                DEFAULT_PRIORITY_FEE_MICRO_LAMPORTS,
+            )
+            .unwrap();
+
+        assert_eq!(builder.resolve_priority_fee(&instructions).await, 750_000);
+
+        let calls = source.calls.lock().unwrap();
+        assert_eq!(calls.len(), 1);
+        let accounts = calls[0].as_ref().expect("account keys passed through");
+        assert_eq!(*accounts, writable_accounts(&instructions));
+        assert!(accounts.contains(&state.bonding_curve_address));
+        assert!(accounts.contains(&state.associated_bonding_curve));
+        // The signer pays; it isn't contended state
+        assert!(!accounts.contains(&user.to_string()));
+    }
+
+    #[test]
+    fn test_protected_min_out_baked_into_curve_sell() {
+        let builder = CurveTransactionBuilder::new("http://localhost:8899");
+        let mint = Pubkey::new_unique();
+        let state = curve_state(&mint);
+        let curve = PumpFunCurve::with_current_state(
+            state.virtual_sol_reserves,
+            state.virtual_token_reserves,
+            state.real_sol_reserves,
+            state.real_token_reserves,
+        );
+        let token_amount = 10_000_000_000_000;
+        let quote = SellProtection::new(800)
+            .quote(curve.calculate_sell_amount(token_amount).sol_out)
+            .unwrap();
+
+        let instructions = builder
+            .create_pump_fun_sell_instructions(
+                &mint.to_string(),
+                &Pubkey::new_unique().to_string(),
+                &state,
+                token_amount,
+                quote.min_out,
+                spl_token::id(),
+                DEFAULT_PRIORITY_FEE_MICRO_LAMPORTS,
+            )
+            .unwrap();
+
+        let sell_ix = instructions.last().unwrap();
+        let args = PumpFunSellArgs::try_from_slice(&sell_ix.data[8..]).unwrap();
+        assert_eq!(args.amount, token_amount);
+        assert_eq!(args.min_sol_output, quote.min_out);
+        assert_eq!(
+            quote.min_out,
+            calculate_min_sol_out(quote.expected_out, 800)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_static_priority_fee_without_source() {
+        let builder = CurveTransactionBuilder::new("http://localhost:8899");
+        assert_eq!(
+            builder.resolve_priority_fee(&[]).await,
+            DEFAULT_PRIORITY_FEE_MICRO_LAMPORTS
+        );
+    }
+
+    #[tokio::test]
+    async fn test_priority_fee_estimate_cached_per_account_set() {
+        let source = fixed_fee(400_000, Duration::ZERO);
+        let builder = CurveTransactionBuilder::new("http://localhost:8899")
+            .with_priority_fee_source(source.clone(), PriorityLevel::High);
+        let first = buy_instructions(&builder);
+        let second = buy_instructions(&builder);
+
+        assert_eq!(builder.resolve_priority_fee(&first).await, 400_000);
+        assert_eq!(builder.resolve_priority_fee(&first).await, 400_000);
+        assert_eq!(*source.calls.lock().unwrap(), 1);
+
+        // A different curve has its own contention
+        builder.resolve_priority_fee(&second).await;
+        assert_eq!(*source.calls.lock().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_slow_priority_fee_estimate_falls_back_to_static() {
+        let source = fixed_fee(400_000, Duration::from_secs(10));
+        let builder = CurveTransactionBuilder::new("http://localhost:8899")
+            .with_priority_fee(123_000)
+            .with_priority_fee_source(source, PriorityLevel::High);
+        let instructions = buy_instructions(&builder);
+
+        assert_eq!(builder.resolve_priority_fee(&instructions).await, 123_000);
+        // The fallback isn't cached; the next trade asks again
+        assert!(builder.priority_fee_cache.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_priority_fee_estimate_clamped_to_max() {
+        let source = fixed_fee(900_000_000, Duration::ZERO);
+        let builder = CurveTransactionBuilder::new("http://localhost:8899")
+            .with_priority_fee_source(source, PriorityLevel::High);
+        let instructions = buy_instructions(&builder);
+        assert_eq!(
+            builder.resolve_priority_fee(&instructions).await,
+            DEFAULT_MAX_PRIORITY_FEE_MICRO_LAMPORTS
+        );
+
+        let capped = CurveTransactionBuilder::new("http://localhost:8899")
+            .with_max_priority_fee(2_000_000)
+            .with_priority_fee_source(fixed_fee(900_000_000, Duration::ZERO), PriorityLevel::High);
+        assert_eq!(capped.resolve_priority_fee(&instructions).await, 2_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_wallet_balance_reads_through_cache() {
+        let cache = Arc::new(BalanceCache::new(Duration::from_secs(60)));
+        cache.store("Wallet111", 42_000).await;
+        let builder =
+            CurveTransactionBuilder::new("http://127.0.0.1:1").with_balance_cache(cache.clone());
+
+        assert_eq!(
+            builder.get_wallet_balance("Wallet111").await.unwrap(),
+            42_000
+        );
+
+        // After invalidation the read goes back to RPC, which is unreachable here
+        cache.invalidate("Wallet111").await;
+        assert!(builder.get_wallet_balance("Wallet111").await.is_err());
+    }
 }
@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+
+use super::position_command::PositionCommand;
+
+/// Upper bound on exits sent at once, whatever `EXIT_WAVE_MAX_SIZE` says.
+pub const MAX_EXIT_WAVE_SIZE: usize = 10;
+pub const DEFAULT_EXIT_WAVE_SIZE: usize = 5;
+pub const DEFAULT_EXIT_TIP_LAMPORTS: u64 = 10_000;
+pub const DEFAULT_EXIT_WAVE_TIP_BUDGET_LAMPORTS: u64 = 50_000;
+
+/// How exits that arrive together (e.g. a market-wide dump) are dispatched. Every exit
+/// is still its own transaction and its own single-transaction Jito bundle; nothing is
+/// bundled together. Exits are sent concurrently in waves, and waves run one after
+/// another so the Jito tips in flight never exceed `wave_tip_budget_lamports`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExitDispatchConfig {
+    pub max_wave_size: usize,
+    pub tip_lamports: u64,
+    pub wave_tip_budget_lamports: u64,
+}
+
+impl Default for ExitDispatchConfig {
+    fn default() -> Self {
+        Self {
+            max_wave_size: DEFAULT_EXIT_WAVE_SIZE,
+            tip_lamports: DEFAULT_EXIT_TIP_LAMPORTS,
+            wave_tip_budget_lamports: DEFAULT_EXIT_WAVE_TIP_BUDGET_LAMPORTS,
+        }
+    }
+}
+
+impl ExitDispatchConfig {
+    /// `EXIT_WAVE_MAX_SIZE` (default 5, capped at 10), `EXIT_TIP_LAMPORTS` (default 10000)
+    /// and `EXIT_WAVE_TIP_BUDGET_LAMPORTS` (default 50000).
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let max_wave_size = std::env::var("EXIT_WAVE_MAX_SIZE")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|v| *v > 0)
+            .map(|v| v.min(MAX_EXIT_WAVE_SIZE))
+            .unwrap_or(defaults.max_wave_size);
+        let tip_lamports = std::env::var("EXIT_TIP_LAMPORTS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(defaults.tip_lamports);
+        let wave_tip_budget_lamports = std::env::var("EXIT_WAVE_TIP_BUDGET_LAMPORTS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(defaults.wave_tip_budget_lamports);
+
+        Self {
+            max_wave_size,
+            tip_lamports,
+            wave_tip_budget_lamports,
+        }
+    }
+
+    /// Wave size after the tip budget is applied; always at least one exit.
+    pub fn effective_wave_size(&self) -> usize {
+        let size = self.max_wave_size.clamp(1, MAX_EXIT_WAVE_SIZE);
+        if self.tip_lamports == 0 {
+            return size;
+        }
+        let affordable = (self.wave_tip_budget_lamports / self.tip_lamports) as usize;
+        size.min(affordable).max(1)
+    }
+}
+
+/// Orders exits by urgency, then by position value (largest first), and splits them into
+/// waves. Each command is paired with the base-currency value it sells.
+pub fn plan_exit_waves(
+    mut exits: Vec<(PositionCommand, f64)>,
+    config: &ExitDispatchConfig,
+) -> Vec<Vec<PositionCommand>> {
+    exits.sort_by(|(a, a_value), (b, b_value)| {
+        a.urgency_sort_key()
+            .cmp(&b.urgency_sort_key())
+            .then_with(|| b_value.total_cmp(a_value))
+    });
+
+    let wave_size = config.effective_wave_size();
+    let mut waves: Vec<Vec<PositionCommand>> = Vec::new();
+    for (cmd, _) in exits {
+        match waves.last_mut() {
+            Some(wave) if wave.len() < wave_size => wave.push(cmd),
+            _ => waves.push(vec![cmd]),
+        }
+    }
+    waves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::position_command::{CommandSource, ExitCommand};
+    use crate::execution::position_manager::{ExitReason, ExitSignal, ExitUrgency};
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn exit(urgency: ExitUrgency, value_base: f64) -> (PositionCommand, f64) {
+        let signal = ExitSignal {
+            position_id: Uuid::new_v4(),
+            reason: ExitReason::StopLoss,
+            exit_percent: 100.0,
+            current_price: 0.0,
+            triggered_at: Utc::now(),
+            urgency,
+        };
+        (
+            PositionCommand::Exit(ExitCommand::new(signal, CommandSource::Monitor)),
+            value_base,
+        )
+    }
+
+    fn ids(wave: &[PositionCommand]) -> Vec<Uuid> {
+        wave.iter().map(|c| c.position_id()).collect()
+    }
+
+    #[test]
+    fn test_simultaneous_exits_sent_in_waves_largest_value_first() {
+        let values = [0.2, 1.5, 0.05, 3.0, 0.8, 2.1, 0.4];
+        let exits: Vec<(PositionCommand, f64)> =
+            values.iter().map(|v| exit(ExitUrgency::High, *v)).collect();
+        let mut by_value: Vec<(Uuid, f64)> = exits
+            .iter()
+            .map(|(cmd, v)| (cmd.position_id(), *v))
+            .collect();
+        by_value.sort_by(|a, b| b.1.total_cmp(&a.1));
+        let expected: Vec<Uuid> = by_value.iter().map(|(id, _)| *id).collect();
+
+        let config = ExitDispatchConfig {
+            max_wave_size: 3,
+            ..ExitDispatchConfig::default()
+        };
+        let waves = plan_exit_waves(exits, &config);
+
+        let sizes: Vec<usize> = waves.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![3, 3, 1]);
+        let ordered: Vec<Uuid> = waves.iter().flat_map(|b| ids(b)).collect();
+        assert_eq!(ordered, expected);
+    }
+
+    #[test]
+    fn test_urgency_outranks_value() {
+        let small_critical = exit(ExitUrgency::Critical, 0.1);
+        let large_low = exit(ExitUrgency::Low, 10.0);
+        let critical_id = small_critical.0.position_id();
+
+        let waves = plan_exit_waves(
+            vec![large_low, small_critical],
+            &ExitDispatchConfig::default(),
+        );
+
+        assert_eq!(waves.len(), 1);
+        assert_eq!(waves[0][0].position_id(), critical_id);
+    }
+
+    #[test]
+    fn test_tip_budget_limits_wave_size() {
+        let config = ExitDispatchConfig {
+            max_wave_size: 5,
+            tip_lamports: 20_000,
+            wave_tip_budget_lamports: 50_000,
+        };
+        assert_eq!(config.effective_wave_size(), 2);
+
+        let exits = (0..5).map(|i| exit(ExitUrgency::High, i as f64)).collect();
+        let sizes: Vec<usize> = plan_exit_waves(exits, &config)
+            .iter()
+            .map(Vec::len)
+            .collect();
+        assert_eq!(sizes, vec![2, 2, 1]);
+
+        // A tip above the whole budget still exits one position at a time
+        let starved = ExitDispatchConfig {
+            tip_lamports: 100_000,
+            ..config
+        };
+        assert_eq!(starved.effective_wave_size(), 1);
+        // The hard cap is never exceeded
+        let oversized = ExitDispatchConfig {
+            max_wave_size: 20,
+            tip_lamports: 0,
+            ..config
+        };
+        assert_eq!(oversized.effective_wave_size(), MAX_EXIT_WAVE_SIZE);
+    }
+}
@@ -0,0 +1,325 @@
+use async_trait::async_trait;
+use serde::Serialize;
+use std::str::FromStr;
+use tracing::warn;
+
+use super::position_manager::{ExitSignal, OpenPosition, PositionManager, PositionStatus};
+use crate::error::{AppError, AppResult};
+
+/// Curve first (cheapest while the token is still bonding), then the aggregator,
+/// then a direct Raydium route for graduated tokens Jupiter can't route.
+const DEFAULT_EMERGENCY_EXIT_VENUES: &[ExitVenue] =
+    &[ExitVenue::Curve, ExitVenue::Jupiter, ExitVenue::Raydium];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExitVenue {
+    Curve,
+    Jupiter,
+    Raydium,
+}
+
+impl ExitVenue {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExitVenue::Curve => "curve",
+            ExitVenue::Jupiter => "jupiter",
+            ExitVenue::Raydium => "raydium",
+        }
+    }
+}
+
+impl std::fmt::Display for ExitVenue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ExitVenue {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "curve" | "pump_fun" | "pumpfun" => Ok(ExitVenue::Curve),
+            "jupiter" => Ok(ExitVenue::Jupiter),
+            "raydium" => Ok(ExitVenue::Raydium),
+            other => Err(AppError::Validation(format!(
+                "Unknown exit venue '{}' (expected curve, jupiter or raydium)",
+                other
+            ))),
+        }
+    }
+}
+
+/// Sells a position through a single venue. Implementations close the position on
+/// success and return the confirmed transaction signature.
+#[async_trait]
+pub trait ExitVenueSeller: Send + Sync {
+    async fn sell_via(
+        &self,
+        venue: ExitVenue,
+        position: &OpenPosition,
+        signal: &ExitSignal,
+    ) -> AppResult<String>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VenueAttempt {
+    pub venue: ExitVenue,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FallbackExit {
+    pub venue: ExitVenue,
+    pub signature: String,
+    pub failed_attempts: Vec<VenueAttempt>,
+}
+
+/// Ordered list of venues tried when force-closing a position, so one venue being
+/// down doesn't strand it.
+#[derive(Debug, Clone)]
+pub struct ExitFallbackChain {
+    venues: Vec<ExitVenue>,
+}
+
+impl Default for ExitFallbackChain {
+    fn default() -> Self {
+        Self::new(DEFAULT_EMERGENCY_EXIT_VENUES.to_vec())
+    }
+}
+
+impl ExitFallbackChain {
+    /// Duplicates are dropped; an empty list falls back to the default chain.
+    pub fn new(venues: Vec<ExitVenue>) -> Self {
+        let mut deduped: Vec<ExitVenue> = Vec::with_capacity(venues.len());
+        for venue in venues {
+            if !deduped.contains(&venue) {
+                deduped.push(venue);
+            }
+        }
+
+        if deduped.is_empty() {
+            deduped = DEFAULT_EMERGENCY_EXIT_VENUES.to_vec();
+        }
+
+        Self { venues: deduped }
+    }
+
+    /// Reads `EMERGENCY_EXIT_VENUES` (comma-separated, e.g. `jupiter,raydium,curve`).
+    pub fn from_env() -> Self {
+        let Ok(raw) = std::env::var("EMERGENCY_EXIT_VENUES") else {
+            return Self::default();
+        };
+
+        let venues = raw
+            .split(',')
+            .filter(|entry| !entry.trim().is_empty())
+            .filter_map(|entry| match entry.parse::<ExitVenue>() {
+                Ok(venue) => Some(venue),
+                Err(e) => {
+                    warn!("⚠️ Ignoring EMERGENCY_EXIT_VENUES entry: {}", e);
+                    None
+                }
+            })
+            .collect();
+
+        Self::new(venues)
+    }
+
+    pub fn venues(&self) -> &[ExitVenue] {
+        &self.venues
+    }
+
+    /// Tries each venue in order until one sells the position. If every venue fails
+    /// the position is left in PendingExit so the pending-exit retry loop keeps
+    /// working on it.
+    pub async fn execute(
+        &self,
+        seller: &dyn ExitVenueSeller,
+        position_manager: &PositionManager,
+        position: &OpenPosition,
+        signal: &ExitSignal,
+    ) -> AppResult<FallbackExit> {
+        let mut failed_attempts = Vec::new();
+
+        for venue in &self.venues {
+            match seller.sell_via(*venue, position, signal).await {
+                Ok(signature) => {
+                    return Ok(FallbackExit {
+                        venue: *venue,
+                        signature,
+                        failed_attempts,
+                    });
+                }
+                Err(e) => {
+                    warn!(
+                        "Emergency exit via {} failed for {}: {}",
+                        venue, position.id, e
+                    );
+                    failed_attempts.push(VenueAttempt {
+                        venue: *venue,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        }
+
+        mark_pending_exit(position_manager, position).await;
+
+        let summary = failed_attempts
+            .iter()
+            .map(|a| format!("{}: {}", a.venue, a.error))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        Err(AppError::Execution(format!(
+            "Emergency exit failed on every venue, left in PendingExit for retry ({})",
+            summary
+        )))
+    }
+}
+
+async fn mark_pending_exit(position_manager: &PositionManager, position: &OpenPosition) {
+    for from in [PositionStatus::Open, PositionStatus::PartiallyExited] {
+        if position_manager
+            .compare_and_swap_status(position.id, from, PositionStatus::PendingExit)
+            .await
+            .unwrap_or_default()
+        {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::position_manager::{ExitConfig, ExitReason, ExitUrgency};
+    use std::sync::Mutex;
+
+    struct MockSeller {
+        working: Vec<ExitVenue>,
+        tried: Mutex<Vec<ExitVenue>>,
+    }
+
+    impl MockSeller {
+        fn working(working: Vec<ExitVenue>) -> Self {
+            Self {
+                working,
+                tried: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ExitVenueSeller for MockSeller {
+        async fn sell_via(
+            &self,
+            venue: ExitVenue,
+            _position: &OpenPosition,
+            _signal: &ExitSignal,
+        ) -> AppResult<String> {
+            self.tried.lock().unwrap().push(venue);
+            if self.working.contains(&venue) {
+                Ok(format!("sig-{}", venue))
+            } else {
+                Err(AppError::ExternalApi(format!("{} unavailable", venue)))
+            }
+        }
+    }
+
+    async fn open_position(manager: &PositionManager) -> (OpenPosition, ExitSignal) {
+        let position = manager
+            .open_position(
+                uuid::Uuid::new_v4(),
+                uuid::Uuid::new_v4(),
+                "StrandedMint1111111111111".to_string(),
+                None,
+                0.1,
+                1000.0,
+                0.0001,
+                ExitConfig::default(),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let signal = ExitSignal {
+            position_id: position.id,
+            reason: ExitReason::Emergency,
+            exit_percent: 100.0,
+            current_price: position.entry_price,
+            triggered_at: chrono::Utc::now(),
+            urgency: ExitUrgency::Critical,
+        };
+        (position, signal)
+    }
+
+    #[test]
+    fn test_chain_parsing_and_defaults() {
+        assert_eq!(
+            ExitFallbackChain::default().venues(),
+            &[ExitVenue::Curve, ExitVenue::Jupiter, ExitVenue::Raydium]
+        );
+        assert_eq!("pump_fun".parse::<ExitVenue>().unwrap(), ExitVenue::Curve);
+        assert_eq!(
+            " Raydium ".parse::<ExitVenue>().unwrap(),
+            ExitVenue::Raydium
+        );
+        assert!("orca".parse::<ExitVenue>().is_err());
+
+        let chain = ExitFallbackChain::new(vec![
+            ExitVenue::Jupiter,
+            ExitVenue::Raydium,
+            ExitVenue::Jupiter,
+        ]);
+        assert_eq!(chain.venues(), &[ExitVenue::Jupiter, ExitVenue::Raydium]);
+        assert_eq!(
+            ExitFallbackChain::new(Vec::new()).venues(),
+            ExitFallbackChain::default().venues()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_when_primary_venue_fails() {
+        let manager = PositionManager::new();
+        let (position, signal) = open_position(&manager).await;
+        let seller = MockSeller::working(vec![ExitVenue::Jupiter]);
+
+        let exit = ExitFallbackChain::default()
+            .execute(&seller, &manager, &position, &signal)
+            .await
+            .unwrap();
+
+        assert_eq!(exit.venue, ExitVenue::Jupiter);
+        assert_eq!(exit.signature, "sig-jupiter");
+        assert_eq!(exit.failed_attempts.len(), 1);
+        assert_eq!(exit.failed_attempts[0].venue, ExitVenue::Curve);
+        assert!(exit.failed_attempts[0].error.contains("curve unavailable"));
+        assert_eq!(
+            *seller.tried.lock().unwrap(),
+            vec![ExitVenue::Curve, ExitVenue::Jupiter]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_all_venues_failing_leaves_position_pending_exit() {
+        let manager = PositionManager::new();
+        let (position, signal) = open_position(&manager).await;
+        let seller = MockSeller::working(Vec::new());
+
+        let err = ExitFallbackChain::default()
+            .execute(&seller, &manager, &position, &signal)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("curve: "));
+        assert!(err.to_string().contains("raydium: "));
+        assert_eq!(seller.tried.lock().unwrap().len(), 3);
+
+        let stored = manager.get_position(position.id).await.unwrap();
+        assert_eq!(stored.status, PositionStatus::PendingExit);
+        assert_eq!(manager.get_pending_exit_positions().await.len(), 1);
+    }
+}
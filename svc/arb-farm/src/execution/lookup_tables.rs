@@ -0,0 +1,354 @@
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    address_lookup_table::state::AddressLookupTable,
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    message::{v0, AddressLookupTableAccount, Message, VersionedMessage},
+    packet::PACKET_DATA_SIZE,
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::VersionedTransaction,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::error::{AppError, AppResult};
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionFormat {
+    #[default]
+    Legacy,
+    V0,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompiledTransaction {
+    pub transaction: VersionedTransaction,
+    pub format: TransactionFormat,
+    pub lookup_tables_used: Vec<Pubkey>,
+    pub size_bytes: usize,
+}
+
+impl CompiledTransaction {
+    pub fn to_base64(&self) -> AppResult<String> {
+        let bytes = bincode::serialize(&self.transaction)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize transaction: {}", e)))?;
+        Ok(base64::Engine::encode(
+            &base64::engine::general_purpose::STANDARD,
+            bytes,
+        ))
+    }
+}
+
+/// Compiles an unsigned transaction, preferring the legacy format and switching to a
+/// v0 message with address lookup tables only when the legacy form doesn't fit in a
+/// single packet.
+pub fn compile_transaction(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+    recent_blockhash: Hash,
+) -> AppResult<CompiledTransaction> {
+    let legacy = Message::new_with_blockhash(instructions, Some(payer), &recent_blockhash);
+    let legacy = unsigned(VersionedMessage::Legacy(legacy));
+    let legacy_size = serialized_size(&legacy)?;
+
+    if legacy_size <= PACKET_DATA_SIZE {
+        return Ok(CompiledTransaction {
+            transaction: legacy,
+            format: TransactionFormat::Legacy,
+            lookup_tables_used: Vec::new(),
+            size_bytes: legacy_size,
+        });
+    }
+
+    if lookup_tables.is_empty() {
+        return Err(AppError::Execution(format!(
+            "Legacy transaction is {} bytes (max {}) and no address lookup tables are available",
+            legacy_size, PACKET_DATA_SIZE
+        )));
+    }
+
+    let message = v0::Message::try_compile(payer, instructions, lookup_tables, recent_blockhash)
+        .map_err(|e| AppError::Execution(format!("Failed to compile v0 message: {}", e)))?;
+    let lookup_tables_used = message
+        .address_table_lookups
+        .iter()
+        .map(|lookup| lookup.account_key)
+        .collect();
+
+    let transaction = unsigned(VersionedMessage::V0(message));
+    let size_bytes = serialized_size(&transaction)?;
+    if size_bytes > PACKET_DATA_SIZE {
+        return Err(AppError::Execution(format!(
+            "v0 transaction is {} bytes even with lookup tables (max {})",
+            size_bytes, PACKET_DATA_SIZE
+        )));
+    }
+
+    Ok(CompiledTransaction {
+        transaction,
+        format: TransactionFormat::V0,
+        lookup_tables_used,
+        size_bytes,
+    })
+}
+
+fn unsigned(message: VersionedMessage) -> VersionedTransaction {
+    let num_signatures = message.header().num_required_signatures as usize;
+    VersionedTransaction {
+        signatures: vec![Signature::default(); num_signatures],
+        message,
+    }
+}
+
+fn serialized_size(transaction: &VersionedTransaction) -> AppResult<usize> {
+    bincode::serialized_size(transaction)
+        .map(|size| size as usize)
+        .map_err(|e| AppError::Internal(format!("Failed to size transaction: {}", e)))
+}
+
+/// Instruction as returned by Jupiter's `/swap-instructions` endpoint.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JupiterInstruction {
+    pub program_id: String,
+    pub accounts: Vec<JupiterAccountMeta>,
+    pub data: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JupiterAccountMeta {
+    pub pubkey: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl TryFrom<&JupiterInstruction> for Instruction {
+    type Error = AppError;
+
+    fn try_from(ix: &JupiterInstruction) -> AppResult<Self> {
+        let program_id = parse_pubkey(&ix.program_id)?;
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|meta| {
+                Ok(AccountMeta {
+                    pubkey: parse_pubkey(&meta.pubkey)?,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+        let data = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &ix.data)
+            .map_err(|e| {
+                AppError::ExternalApi(format!("Invalid Jupiter instruction data: {}", e))
+            })?;
+
+        Ok(Instruction {
+            program_id,
+            accounts,
+            data,
+        })
+    }
+}
+
+pub fn parse_pubkey(value: &str) -> AppResult<Pubkey> {
+    Pubkey::from_str(value)
+        .map_err(|e| AppError::ExternalApi(format!("Invalid pubkey '{}': {}", value, e)))
+}
+
+/// Fetches and caches address lookup tables. Tables Jupiter routes through are
+/// long-lived and only ever extended, so entries are cached for the process lifetime.
+pub struct LookupTableCache {
+    client: reqwest::Client,
+    rpc_url: String,
+    tables: Arc<RwLock<HashMap<Pubkey, AddressLookupTableAccount>>>,
+}
+
+impl LookupTableCache {
+    pub fn new(client: reqwest::Client, rpc_url: String) -> Self {
+        Self {
+            client,
+            rpc_url,
+            tables: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    pub async fn resolve(&self, addresses: &[String]) -> AppResult<Vec<AddressLookupTableAccount>> {
+        let keys = addresses
+            .iter()
+            .map(|a| parse_pubkey(a))
+            .collect::<AppResult<Vec<_>>>()?;
+
+        let missing: Vec<Pubkey> = {
+            let tables = self.tables.read().await;
+            keys.iter()
+                .filter(|k| !tables.contains_key(k))
+                .copied()
+                .collect()
+        };
+
+        if !missing.is_empty() {
+            let fetched = self.fetch(&missing).await?;
+            self.tables
+                .write()
+                .await
+                .extend(fetched.into_iter().map(|t| (t.key, t)));
+        }
+
+        let tables = self.tables.read().await;
+        Ok(keys.iter().filter_map(|k| tables.get(k).cloned()).collect())
+    }
+
+    async fn fetch(&self, keys: &[Pubkey]) -> AppResult<Vec<AddressLookupTableAccount>> {
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "getMultipleAccounts",
+            "params": [
+                keys.iter().map(|k| k.to_string()).collect::<Vec<_>>(),
+                { "encoding": "base64", "commitment": "confirmed" }
+            ]
+        });
+
+        let response: serde_json::Value = self
+            .client
+            .post(&self.rpc_url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| AppError::ExternalApi(format!("Lookup table request failed: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| {
+                AppError::ExternalApi(format!("Failed to parse lookup table response: {}", e))
+            })?;
+
+        let accounts = response
+            .pointer("/result/value")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| {
+                AppError::ExternalApi(format!(
+                    "Lookup table RPC returned no accounts: {}",
+                    response.get("error").cloned().unwrap_or_default()
+                ))
+            })?;
+
+        let mut tables = Vec::with_capacity(keys.len());
+        for (key, account) in keys.iter().zip(accounts) {
+            let Some(data) = account
+                .pointer("/data/0")
+                .and_then(|d| d.as_str())
+                .and_then(|d| {
+                    base64::Engine::decode(&base64::engine::general_purpose::STANDARD, d).ok()
+                })
+            else {
+                tracing::warn!("Address lookup table {} not found", key);
+                continue;
+            };
+
+            let table = AddressLookupTable::deserialize(&data).map_err(|e| {
+                AppError::ExternalApi(format!("Invalid address lookup table {}: {}", key, e))
+            })?;
+            tables.push(AddressLookupTableAccount {
+                key: *key,
+                addresses: table.addresses.to_vec(),
+            });
+        }
+
+        Ok(tables)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swap_instruction(program_id: Pubkey, payer: Pubkey, accounts: &[Pubkey]) -> Instruction {
+        let mut metas = vec![AccountMeta::new(payer, true)];
+        metas.extend(accounts.iter().map(|k| AccountMeta::new(*k, false)));
+        Instruction {
+            program_id,
+            accounts: metas,
+            data: vec![7; 40],
+        }
+    }
+
+    #[test]
+    fn test_small_route_stays_legacy() {
+        let payer = Pubkey::new_unique();
+        let accounts: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+        let ix = swap_instruction(Pubkey::new_unique(), payer, &accounts);
+
+        let compiled = compile_transaction(&payer, &[ix], &[], Hash::new_unique()).unwrap();
+        assert_eq!(compiled.format, TransactionFormat::Legacy);
+        assert!(compiled.lookup_tables_used.is_empty());
+        assert_eq!(compiled.transaction.signatures.len(), 1);
+    }
+
+    #[test]
+    fn test_large_route_compiles_to_v0_with_lookup_tables() {
+        let payer = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let route_accounts: Vec<Pubkey> = (0..48).map(|_| Pubkey::new_unique()).collect();
+        let instructions: Vec<Instruction> = route_accounts
+            .chunks(12)
+            .map(|hop| swap_instruction(program_id, payer, hop))
+            .collect();
+
+        assert!(compile_transaction(&payer, &instructions, &[], Hash::new_unique()).is_err());
+
+        let table = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: route_accounts.clone(),
+        };
+        let compiled = compile_transaction(
+            &payer,
+            &instructions,
+            std::slice::from_ref(&table),
+            Hash::new_unique(),
+        )
+        .unwrap();
+
+        assert_eq!(compiled.format, TransactionFormat::V0);
+        assert_eq!(compiled.lookup_tables_used, vec![table.key]);
+        assert!(compiled.size_bytes <= PACKET_DATA_SIZE);
+
+        let VersionedMessage::V0(message) = &compiled.transaction.message else {
+            panic!("expected a v0 message");
+        };
+        assert_eq!(message.address_table_lookups[0].writable_indexes.len(), 48);
+
+        let bytes = base64::Engine::decode(
+            &base64::engine::general_purpose::STANDARD,
+            compiled.to_base64().unwrap(),
+        )
+        .unwrap();
+        let decoded: VersionedTransaction = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(decoded.message, compiled.transaction.message);
+    }
+
+    #[test]
+    fn test_jupiter_instruction_conversion() {
+        let program_id = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+        let ix: JupiterInstruction = serde_json::from_value(serde_json::json!({
+            "programId": program_id.to_string(),
+            "accounts": [
+                { "pubkey": account.to_string(), "isSigner": false, "isWritable": true }
+            ],
+            "data": "AQID"
+        }))
+        .unwrap();
+
+        let converted = Instruction::try_from(&ix).unwrap();
+        assert_eq!(converted.program_id, program_id);
+        assert_eq!(converted.accounts, vec![AccountMeta::new(account, false)]);
+        assert_eq!(converted.data, vec![1, 2, 3]);
+    }
+}
@@ -1,47 +1,75 @@
 pub mod approval_manager;
 pub mod blockhash;
+pub mod break_even;
 pub mod capital_manager;
 pub mod copy_executor;
 pub mod curve_builder;
 pub mod executor;
+pub mod exit_dispatch;
+pub mod exit_fallback;
 pub mod jito;
+pub mod lookup_tables;
 pub mod position_command;
 pub mod position_executor;
 pub mod position_manager;
 pub mod position_monitor;
 pub mod priority_queue;
 pub mod realtime_monitor;
+pub mod reconcile_schedule;
 pub mod risk;
+pub mod scale_in;
+pub mod sell_protection;
 pub mod simulation;
+pub mod slippage_escalation;
+pub mod sol_price;
 pub mod transaction_builder;
 pub mod tx_settlement;
 
 pub use approval_manager::ApprovalManager;
 pub use blockhash::{BlockhashCache, RecentBlockhash};
+pub use break_even::{BreakEvenCostModel, RoundTripCosts};
 pub use capital_manager::{
-    CapitalError, CapitalManager, CapitalReservation, GlobalCapitalUsage, StrategyAllocation,
-    StrategyUsage,
+    CapitalError, CapitalManager, CapitalReservation, GlobalCapitalUsage, RebalanceConfig,
+    StrategyAllocation, StrategyId, StrategyUsage,
+};
+pub use copy_executor::{
+    CopyExecutorConfig, CopyTradeExecutor, CopyTradeResult, KolBudgetUsage, KolBudgets,
 };
-pub use copy_executor::{CopyExecutorConfig, CopyTradeExecutor, CopyTradeResult};
 pub use curve_builder::{
     CurveBuildResult, CurveBuyParams, CurveSellParams, CurveTransactionBuilder,
-    PostGraduationSellResult, SimulatedTrade,
+    PostGraduationSellResult, SimulatedTrade, SnipeFeeEconomics,
 };
 pub use executor::{ExecutionResult, ExecutorAgent};
+pub use exit_dispatch::{plan_exit_waves, ExitDispatchConfig};
+pub use exit_fallback::{
+    ExitFallbackChain, ExitVenue, ExitVenueSeller, FallbackExit, VenueAttempt,
+};
 pub use jito::{BundleStatus, BundleSubmission, JitoClient};
-pub use position_command::{CommandSource, ExitCommand, PositionCommand};
+pub use lookup_tables::{compile_transaction, LookupTableCache, TransactionFormat};
+pub use position_command::{CommandSource, ExitCommand, PositionCommand, ScaleInCommand};
 pub use position_executor::{ExecutorConfig, PositionExecutor};
 pub use position_manager::{
-    AdaptivePartialTakeProfit, BaseCurrency, ExitConfig, ExitMode, ExitReason, ExitSignal,
-    MomentumAdaptiveConfig, MomentumData, MomentumStrength, OpenPosition, PositionManager,
-    PositionStatus, ReconciliationResult, WalletTokenHolding, SOL_MINT, USDC_MINT, USDT_MINT,
+    AdaptivePartialTakeProfit, AutoExitPolicy, BaseCurrency, DustPolicy, DustThresholds,
+    ExitConfig, ExitConfigTemplate, ExitMode, ExitReason, ExitSignal, MomentumAdaptiveConfig,
+    MomentumData, MomentumFadeTakeProfit, MomentumStrength, OpenPosition, PositionAnnotations,
+    PositionManager, PositionStatus, ReconciliationResult, TokenCategory, WalletTokenHolding,
+    MAX_EXIT_SLIPPAGE_BPS, SOL_MINT, USDC_MINT, USDT_MINT,
 };
 pub use position_monitor::{MonitorConfig, PositionMonitor};
 pub use priority_queue::{EdgePriorityQueue, PrioritizedEdge, Priority, QueueStats};
 pub use realtime_monitor::RealtimePositionMonitor;
+pub use reconcile_schedule::{ReconcileSchedule, ReconcileScheduleStatus};
 pub use risk::{RiskCheck, RiskManager, RiskViolation};
+pub use scale_in::{
+    ScaleInConfig, ScaleInFill, ScaleInLimits, ScaleInPlan, ScaleInSignal, ScaleInTranche,
+};
+pub use sell_protection::{ProtectedSellQuote, ProtectedSellResult, SellProtection};
 pub use simulation::{SimulationResult, TransactionSimulator};
+pub use slippage_escalation::{SlippageAttempt, SlippageEscalation};
+pub use sol_price::{pnl_usd, PnlCurrency, SolUsdPriceCache};
 pub use transaction_builder::{
     BuildResult, ExitBuildResult, RouteInfo, SwapParams, TransactionBuilder,
 };
-pub use tx_settlement::{resolve_settlement, TxSettlement};
+pub use tx_settlement::{
+    resolve_settlement, ExitCommitment, ExitSettlementConfig, ExitSettlementOutcome, TxSettlement,
+};
@@ -3,10 +3,12 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::position_manager::{ExitSignal, ExitUrgency};
+use super::scale_in::ScaleInSignal;
 
 #[derive(Debug, Clone)]
 pub enum PositionCommand {
     Exit(ExitCommand),
+    ScaleIn(ScaleInCommand),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,6 +18,7 @@ pub enum CommandSource {
     PendingRetry,
     ManualTrigger,
     CopyTradeEmergency,
+    EmergencyClose,
 }
 
 #[derive(Debug, Clone)]
@@ -35,20 +38,41 @@ impl ExitCommand {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct ScaleInCommand {
+    pub signal: ScaleInSignal,
+    pub queued_at: DateTime<Utc>,
+}
+
+impl ScaleInCommand {
+    pub fn new(signal: ScaleInSignal) -> Self {
+        Self {
+            signal,
+            queued_at: Utc::now(),
+        }
+    }
+}
+
 impl PositionCommand {
     pub fn urgency(&self) -> ExitUrgency {
         match self {
             PositionCommand::Exit(cmd) => cmd.signal.urgency,
+            PositionCommand::ScaleIn(_) => ExitUrgency::Low,
         }
     }
 
     pub fn position_id(&self) -> Uuid {
         match self {
             PositionCommand::Exit(cmd) => cmd.signal.position_id,
+            PositionCommand::ScaleIn(cmd) => cmd.signal.position_id,
         }
     }
 
+    /// Scale-ins sort after every exit so capital is never added ahead of a pending sell.
     pub fn urgency_sort_key(&self) -> u8 {
+        if let PositionCommand::ScaleIn(_) = self {
+            return 4;
+        }
         match self.urgency() {
             ExitUrgency::Critical => 0,
             ExitUrgency::High => 1,
@@ -66,6 +90,7 @@ impl std::fmt::Display for CommandSource {
             CommandSource::PendingRetry => write!(f, "PendingRetry"),
             CommandSource::ManualTrigger => write!(f, "ManualTrigger"),
             CommandSource::CopyTradeEmergency => write!(f, "CopyTradeEmergency"),
+            CommandSource::EmergencyClose => write!(f, "EmergencyClose"),
         }
     }
 }
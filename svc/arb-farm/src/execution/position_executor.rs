@@ -1,3 +1,4 @@
+use async_trait::async_trait;
 use chrono::Utc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -15,16 +16,25 @@ use crate::helius::{HeliusClient, HeliusSender};
 use crate::wallet::turnkey::SignRequest;
 use crate::wallet::DevWalletSigner;
 
-use super::tx_settlement::{resolve_inferred_settlement, resolve_settlement, TxSettlement};
+use super::tx_settlement::{
+    await_exit_commitment, resolve_inferred_settlement, resolve_settlement, ExitSettlementConfig,
+    ExitSettlementOutcome, SignatureStatusSource, TxSettlement,
+};
 
 use super::capital_manager::CapitalManager;
-use super::curve_builder::{CurveSellParams, CurveTransactionBuilder};
+use super::curve_builder::{CurveBuyParams, CurveSellParams, CurveTransactionBuilder};
+use super::exit_dispatch::{plan_exit_waves, ExitDispatchConfig};
+use super::exit_fallback::{ExitFallbackChain, ExitVenue, ExitVenueSeller};
 use super::jito::{BundleState, JitoClient};
-use super::position_command::{CommandSource, ExitCommand, PositionCommand};
+use super::position_command::{CommandSource, ExitCommand, PositionCommand, ScaleInCommand};
 use super::position_manager::{
-    ExitReason, ExitSignal, ExitUrgency, OpenPosition, PositionManager, PositionStatus,
+    BaseCurrency, ExitReason, ExitSignal, ExitUrgency, OpenPosition, PositionManager,
+    PositionStatus, STANDARD_EXIT_SLIPPAGE_BPS,
 };
-use super::transaction_builder::TransactionBuilder;
+use super::scale_in::{self, ScaleInSignal};
+use super::sell_protection::{ProtectedSellResult, SellProtection};
+use super::slippage_escalation::{SlippageAttempt, SlippageEscalation};
+use super::transaction_builder::{SwapParams, TransactionBuilder};
 
 const MIN_DUST_VALUE_SOL: f64 = 0.0001;
 
@@ -34,6 +44,11 @@ pub struct ExecutorConfig {
     pub max_exit_retries: u32,
     pub emergency_slippage_bps: u16,
     pub bundle_timeout_secs: u64,
+    pub slippage_escalation: SlippageEscalation,
+    pub exit_dispatch: ExitDispatchConfig,
+    pub exit_settlement: ExitSettlementConfig,
+    /// Ceiling on the slippage of every protected market sell
+    pub sell_protection: SellProtection,
 }
 
 impl Default for ExecutorConfig {
@@ -43,6 +58,10 @@ impl Default for ExecutorConfig {
             max_exit_retries: 3,
             emergency_slippage_bps: 2500,
             bundle_timeout_secs: 60,
+            slippage_escalation: SlippageEscalation::default(),
+            exit_dispatch: ExitDispatchConfig::default(),
+            exit_settlement: ExitSettlementConfig::default(),
+            sell_protection: SellProtection::default(),
         }
     }
 }
@@ -56,6 +75,7 @@ pub struct PositionExecutor {
     curve_builder: Option<Arc<CurveTransactionBuilder>>,
     helius_sender: Option<Arc<HeliusSender>>,
     helius_client: Option<Arc<HeliusClient>>,
+    signature_status_source: Option<Arc<dyn SignatureStatusSource>>,
     engrams_client: Option<Arc<EngramsClient>>,
     trade_repo: Option<Arc<TradeRepository>>,
     capital_manager: Option<Arc<CapitalManager>>,
@@ -64,6 +84,7 @@ pub struct PositionExecutor {
     consecutive_rate_limits: Arc<tokio::sync::RwLock<u32>>,
     shutdown_flag: Arc<AtomicBool>,
     config: ExecutorConfig,
+    emergency_exit_chain: ExitFallbackChain,
 }
 
 impl PositionExecutor {
@@ -85,6 +106,7 @@ impl PositionExecutor {
             curve_builder: None,
             helius_sender: None,
             helius_client: None,
+            signature_status_source: None,
             engrams_client: None,
             trade_repo: None,
             capital_manager: None,
@@ -93,6 +115,7 @@ impl PositionExecutor {
             consecutive_rate_limits: Arc::new(tokio::sync::RwLock::new(0)),
             shutdown_flag: Arc::new(AtomicBool::new(false)),
             config,
+            emergency_exit_chain: ExitFallbackChain::default(),
         }
     }
 
@@ -122,10 +145,22 @@ impl PositionExecutor {
     }
 
     pub fn with_helius_client(mut self, helius_client: Arc<HeliusClient>) -> Self {
+        self.signature_status_source = Some(helius_client.clone());
         self.helius_client = Some(helius_client);
         self
     }
 
+    /// Where exit signatures are polled for commitment; defaults to the Helius client.
+    pub fn with_signature_status_source(mut self, source: Arc<dyn SignatureStatusSource>) -> Self {
+        self.signature_status_source = Some(source);
+        self
+    }
+
+    pub fn with_emergency_exit_chain(mut self, chain: ExitFallbackChain) -> Self {
+        self.emergency_exit_chain = chain;
+        self
+    }
+
     pub fn get_shutdown_flag(&self) -> Arc<AtomicBool> {
         self.shutdown_flag.clone()
     }
@@ -168,12 +203,47 @@ impl PositionExecutor {
 
             info!("PositionExecutor processing {} commands", deduped.len());
 
-            for cmd in deduped {
-                self.handle_command(cmd).await;
+            if deduped.len() == 1 {
+                for cmd in deduped {
+                    self.handle_command(cmd).await;
+                }
+                continue;
+            }
+
+            let waves = self.plan_dispatch(deduped).await;
+            info!(
+                "Dispatching simultaneous exits in {} concurrent waves (max {} per wave)",
+                waves.len(),
+                self.config.exit_dispatch.effective_wave_size()
+            );
+
+            for wave in waves {
+                futures::future::join_all(wave.into_iter().map(|cmd| self.handle_command(cmd)))
+                    .await;
             }
         }
     }
 
+    /// Splits commands into dispatch waves, valuing every exit from one position snapshot.
+    async fn plan_dispatch(&self, commands: Vec<PositionCommand>) -> Vec<Vec<PositionCommand>> {
+        let ids: Vec<Uuid> = commands.iter().map(|cmd| cmd.position_id()).collect();
+        let values = self.position_manager.current_values_base(&ids).await;
+        let valued = commands
+            .into_iter()
+            .map(|cmd| {
+                let value = match &cmd {
+                    PositionCommand::Exit(exit_cmd) => values
+                        .get(&exit_cmd.signal.position_id)
+                        .map(|v| v * (exit_cmd.signal.exit_percent / 100.0))
+                        .unwrap_or(0.0),
+                    PositionCommand::ScaleIn(_) => 0.0,
+                };
+                (cmd, value)
+            })
+            .collect();
+        plan_exit_waves(valued, &self.config.exit_dispatch)
+    }
+
     fn dedup_commands(commands: Vec<PositionCommand>) -> Vec<PositionCommand> {
         let mut seen = std::collections::HashSet::new();
         let mut result = Vec::new();
@@ -198,7 +268,14 @@ impl PositionExecutor {
                     "Executing exit command"
                 );
 
-                if let Err(e) = self.execute_exit(&exit_cmd.signal).await {
+                let result = match exit_cmd.source {
+                    CommandSource::EmergencyClose => {
+                        self.execute_emergency_exit(&exit_cmd.signal).await
+                    }
+                    _ => self.execute_exit(&exit_cmd.signal).await,
+                };
+
+                if let Err(e) = result {
                     error!(
                         position_id = %position_id,
                         source = %source,
@@ -207,7 +284,213 @@ impl PositionExecutor {
                     );
                 }
             }
+            PositionCommand::ScaleIn(ScaleInCommand { signal, .. }) => {
+                let position_id = signal.position_id;
+                info!(
+                    position_id = %position_id,
+                    tranche = signal.tranche,
+                    amount_base = signal.amount_base,
+                    "Executing scale-in command"
+                );
+
+                if let Err(e) = self.execute_scale_in(&signal).await {
+                    self.position_manager.release_scale_in(position_id).await;
+                    error!(
+                        position_id = %position_id,
+                        tranche = signal.tranche,
+                        error = %e,
+                        "Scale-in command failed"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Buys a scale-in tranche on the venue the position trades on (bonding curve until
+    /// graduation, Jupiter after) and records the fill on the position.
+    async fn execute_scale_in(&self, signal: &ScaleInSignal) -> AppResult<()> {
+        let position = match self.position_manager.get_position(signal.position_id).await {
+            Some(p) if p.status == PositionStatus::Open => p,
+            _ => {
+                warn!(
+                    "Position {} no longer open - skipping scale-in",
+                    signal.position_id
+                );
+                return Ok(());
+            }
+        };
+
+        let base_currency = position.exit_config.base_currency;
+        let amount_lamports =
+            (signal.amount_base * 10f64.powi(base_currency.decimals() as i32)) as u64;
+
+        // Capital reservations are in lamports, so only SOL adds are counted against them
+        let capital_mgr = self
+            .capital_manager
+            .as_ref()
+            .filter(|_| base_currency == BaseCurrency::Sol);
+        if let Some(capital_mgr) = capital_mgr {
+            capital_mgr
+                .extend_reservation(position.strategy_id, position.id, amount_lamports)
+                .await
+                .map_err(|e| {
+                    AppError::Execution(format!("Scale-in capital check failed: {}", e))
+                })?;
         }
+
+        let result = self.buy_scale_in(&position, signal, amount_lamports).await;
+        let (signature, tokens_received) = match result {
+            Ok(fill) => fill,
+            Err(e) => {
+                if let Some(capital_mgr) = capital_mgr {
+                    capital_mgr
+                        .shrink_reservation(position.id, amount_lamports)
+                        .await;
+                }
+                return Err(e);
+            }
+        };
+
+        let fill_price =
+            scale_in::fill_price(amount_lamports, tokens_received).unwrap_or(signal.current_price);
+
+        self.position_manager
+            .record_scale_in(
+                signal.position_id,
+                signal.tranche,
+                signal.amount_base,
+                tokens_received as f64,
+                fill_price,
+                Some(signature),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Sends and confirms the scale-in buy. Returns the signature and the tokens received,
+    /// read from the wallet's balance change and falling back to the quote.
+    async fn buy_scale_in(
+        &self,
+        position: &OpenPosition,
+        signal: &ScaleInSignal,
+        amount_lamports: u64,
+    ) -> AppResult<(String, u64)> {
+        let user_wallet = self
+            .signer
+            .get_status()
+            .await
+            .wallet_address
+            .ok_or_else(|| AppError::Internal("No wallet configured for scale-in".to_string()))?;
+        let user_wallet = user_wallet.as_str();
+        let base_currency = position.exit_config.base_currency;
+        let slippage_bps = self.config.exit_slippage_bps;
+        let balance_before = self
+            .tx_builder
+            .get_token_balance(user_wallet, &position.token_mint)
+            .await
+            .ok();
+
+        let on_curve = match (&self.curve_builder, base_currency) {
+            (Some(curve_builder), BaseCurrency::Sol) => curve_builder
+                .get_curve_state(&position.token_mint)
+                .await
+                .map(|state| !state.is_complete)
+                .unwrap_or(false),
+            _ => false,
+        };
+
+        let (transaction_base64, expected_tokens_out) = match (&self.curve_builder, on_curve) {
+            (Some(curve_builder), true) => {
+                let build = curve_builder
+                    .build_pump_fun_buy(&CurveBuyParams {
+                        mint: position.token_mint.clone(),
+                        sol_amount_lamports: amount_lamports,
+                        slippage_bps,
+                        user_wallet: user_wallet.to_string(),
+                    })
+                    .await?;
+                (
+                    build.transaction_base64,
+                    build.expected_tokens_out.unwrap_or(0),
+                )
+            }
+            _ => {
+                let build = self
+                    .tx_builder
+                    .build_jupiter_swap(
+                        &SwapParams {
+                            input_mint: base_currency.mint().to_string(),
+                            output_mint: position.token_mint.clone(),
+                            amount_lamports,
+                            slippage_bps,
+                            user_public_key: user_wallet.to_string(),
+                        },
+                        position.edge_id,
+                    )
+                    .await?;
+                (build.transaction_base64, build.route_info.out_amount)
+            }
+        };
+
+        let sign_result = self
+            .signer
+            .sign_transaction(SignRequest {
+                transaction_base64,
+                estimated_amount_lamports: amount_lamports,
+                estimated_profit_lamports: None,
+                edge_id: Some(position.edge_id),
+                description: format!(
+                    "Scale-in tranche {} {} {} -> {}",
+                    signal.tranche,
+                    signal.amount_base,
+                    base_currency.symbol(),
+                    position
+                        .token_symbol
+                        .as_deref()
+                        .unwrap_or(&position.token_mint[..8])
+                ),
+            })
+            .await?;
+
+        if !sign_result.success {
+            let error_msg = sign_result
+                .error
+                .or_else(|| sign_result.policy_violation.map(|v| v.message))
+                .unwrap_or_else(|| "Unknown signing error".to_string());
+            return Err(AppError::ExternalApi(format!(
+                "Signing failed: {}",
+                error_msg
+            )));
+        }
+
+        let signed_tx = sign_result
+            .signed_transaction_base64
+            .ok_or_else(|| AppError::ExternalApi("No signed transaction returned".to_string()))?;
+        let helius_sender = self
+            .helius_sender
+            .as_ref()
+            .ok_or_else(|| AppError::Internal("Helius sender not configured".into()))?;
+
+        let signature = helius_sender
+            .send_and_confirm(&signed_tx, Duration::from_secs(60))
+            .await?;
+
+        let balance_after = self
+            .tx_builder
+            .get_token_balance(user_wallet, &position.token_mint)
+            .await
+            .ok();
+        if balance_before.is_none() || balance_after.is_none() {
+            warn!(
+                "Could not read token balance for scale-in on {} - using quoted {} tokens",
+                position.id, expected_tokens_out
+            );
+        }
+        let tokens_received =
+            scale_in_tokens_received(balance_before, balance_after, expected_tokens_out);
+
+        Ok((signature, tokens_received))
     }
 
     async fn is_rate_limited(&self) -> bool {
@@ -263,28 +546,23 @@ impl PositionExecutor {
     }
 
     fn calculate_profit_aware_slippage(&self, position: &OpenPosition, signal: &ExitSignal) -> u16 {
-        const MIN_SLIPPAGE_BPS: u16 = 500;
         const MAX_SLIPPAGE_BPS: u16 = 2000;
-        const SALVAGE_SLIPPAGE_BPS: u16 = 5000;
-        const ABSOLUTE_MAX_SLIPPAGE_BPS: u16 = 5000;
         const PROFIT_SACRIFICE_RATIO: f64 = 0.25;
 
-        let is_dead_token = signal.reason == ExitReason::Salvage
-            || position
+        let is_salvage = signal.reason == ExitReason::Salvage;
+        if is_salvage
+            || position.exit_config.is_dead_token()
+            || position.exit_config.exit_slippage_bps.is_some()
+        {
+            let slippage = position
                 .exit_config
-                .custom_exit_instructions
-                .as_ref()
-                .map(|s| s.contains("DEAD TOKEN"))
-                .unwrap_or(false);
-
-        if is_dead_token {
-            let salvage_slippage = SALVAGE_SLIPPAGE_BPS.min(ABSOLUTE_MAX_SLIPPAGE_BPS);
+                .resolve_exit_slippage_bps(is_salvage, STANDARD_EXIT_SLIPPAGE_BPS);
             info!(
-                "Dead token exit: using salvage slippage {}bps ({}%)",
-                salvage_slippage,
-                salvage_slippage as f64 / 100.0
+                "Exit slippage from position config: {}bps ({}%)",
+                slippage,
+                slippage as f64 / 100.0
             );
-            return salvage_slippage;
+            return slippage;
         }
 
         let pnl_percent = if position.entry_price > 0.0 {
@@ -295,9 +573,9 @@ impl PositionExecutor {
 
         let calculated_slippage = if pnl_percent > 0.0 {
             let profit_based = (pnl_percent * PROFIT_SACRIFICE_RATIO * 100.0) as u16;
-            profit_based.max(MIN_SLIPPAGE_BPS)
+            profit_based.max(STANDARD_EXIT_SLIPPAGE_BPS)
         } else {
-            MIN_SLIPPAGE_BPS
+            STANDARD_EXIT_SLIPPAGE_BPS
         };
 
         let urgency_multiplier = match signal.urgency {
@@ -430,14 +708,70 @@ impl PositionExecutor {
         }
     }
 
+    /// Waits for the sell to reach the configured exit commitment before resolving its
+    /// settlement. Errors (after re-queuing the position) if the sell never gets there.
     async fn resolve_sell_settlement(
         &self,
+        position_id: Uuid,
         signature: &str,
         user_wallet: &str,
-    ) -> Option<TxSettlement> {
-        let helius_client = self.helius_client.as_ref()?;
+    ) -> AppResult<Option<TxSettlement>> {
+        let Some(status_source) = self.signature_status_source.as_ref() else {
+            return Ok(None);
+        };
+
+        if let ExitSettlementOutcome::Requeued { reason } = await_exit_commitment(
+            status_source.as_ref(),
+            &self.position_manager,
+            position_id,
+            signature,
+            &self.config.exit_settlement,
+        )
+        .await
+        {
+            return Err(AppError::Execution(format!(
+                "Exit {} did not settle: {}",
+                &signature[..16.min(signature.len())],
+                reason
+            )));
+        }
+
+        let Some(helius_client) = self.helius_client.as_ref() else {
+            return Ok(None);
+        };
         let settlement = resolve_settlement(helius_client, signature, user_wallet).await;
-        Some(settlement)
+        Ok(Some(settlement))
+    }
+
+    /// `resolve_sell_settlement` for the curve exit loop: a sell that never settles is
+    /// recorded as a failed attempt and reported in `position.exit_failed` before the
+    /// error is returned. The position has already been re-queued.
+    async fn resolve_curve_sell_settlement(
+        &self,
+        position: &OpenPosition,
+        signal: &ExitSignal,
+        signature: &str,
+        user_wallet: &str,
+        attempts: &mut Vec<SlippageAttempt>,
+        slippage_bps: u16,
+    ) -> AppResult<Option<TxSettlement>> {
+        match self
+            .resolve_sell_settlement(position.id, signature, user_wallet)
+            .await
+        {
+            Ok(settlement) => Ok(settlement),
+            Err(e) => {
+                let error = e.to_string();
+                attempts.push(SlippageAttempt::failed(
+                    attempts.len() as u32,
+                    slippage_bps,
+                    &error,
+                ));
+                self.emit_exit_failed_event_with_attempts(position, signal, &error, attempts)
+                    .await;
+                Err(e)
+            }
+        }
     }
 
     fn compute_pnl_with_settlement(
@@ -469,25 +803,19 @@ impl PositionExecutor {
         estimated_pnl
     }
 
-    async fn execute_exit(&self, signal: &ExitSignal) -> AppResult<()> {
-        let position = match self.position_manager.get_position(signal.position_id).await {
-            Some(p) => p,
-            None => {
-                warn!("Position {} no longer exists", signal.position_id);
-                return Ok(());
-            }
-        };
-
+    /// Moves the position into PendingExit. Returns false if it's already closed or
+    /// another exit owns it.
+    async fn claim_for_exit(&self, position: &OpenPosition) -> bool {
         let cas_succeeded = match position.status {
             PositionStatus::Open => {
                 self.position_manager
-                    .transition_to_pending_exit(signal.position_id)
+                    .transition_to_pending_exit(position.id)
                     .await
             }
             PositionStatus::PartiallyExited => self
                 .position_manager
                 .compare_and_swap_status(
-                    signal.position_id,
+                    position.id,
                     PositionStatus::PartiallyExited,
                     PositionStatus::PendingExit,
                 )
@@ -496,24 +824,46 @@ impl PositionExecutor {
             PositionStatus::PendingExit => {
                 debug!(
                     "Position {} already in PendingExit, proceeding with exit",
-                    signal.position_id
+                    position.id
                 );
                 true
             }
+            PositionStatus::Settling => {
+                debug!(
+                    "Position {} exit already sent and settling, skipping exit",
+                    position.id
+                );
+                return false;
+            }
             PositionStatus::Closed | PositionStatus::Failed | PositionStatus::Orphaned => {
                 debug!(
                     "Position {} already closed/failed/orphaned, skipping exit",
-                    signal.position_id
+                    position.id
                 );
-                return Ok(());
+                return false;
             }
         };
 
         if !cas_succeeded {
             warn!(
                 "Position {} status CAS failed - another thread is handling this exit",
-                signal.position_id
+                position.id
             );
+        }
+
+        cas_succeeded
+    }
+
+    async fn execute_exit(&self, signal: &ExitSignal) -> AppResult<()> {
+        let position = match self.position_manager.get_position(signal.position_id).await {
+            Some(p) => p,
+            None => {
+                warn!("Position {} no longer exists", signal.position_id);
+                return Ok(());
+            }
+        };
+
+        if !self.claim_for_exit(&position).await {
             return Ok(());
         }
 
@@ -533,6 +883,7 @@ impl PositionExecutor {
         };
 
         let slippage = self.calculate_profit_aware_slippage(&position, signal);
+        let protection = self.sell_protection_for(slippage);
 
         info!(
             "Processing {} exit for {} | {}% @ {} | slippage: {} bps",
@@ -583,10 +934,9 @@ impl PositionExecutor {
                 .await;
         }
 
-        let (exit_tx_base64, expected_base_out, token_amount_in, route_label) = if let Some(
-            ref curve_builder,
-        ) =
-            self.curve_builder
+        let (exit_tx_base64, expected_base_out, token_amount_in, route_label) = if self
+            .curve_builder
+            .is_some()
         {
             let token_balance = self
                 .tx_builder
@@ -642,7 +992,7 @@ impl PositionExecutor {
             let sell_params = CurveSellParams {
                 mint: position.token_mint.clone(),
                 token_amount: exit_amount,
-                slippage_bps: slippage,
+                slippage_bps: protection.max_slippage_bps,
                 user_wallet: user_wallet.clone(),
             };
 
@@ -654,7 +1004,16 @@ impl PositionExecutor {
                     .unwrap_or(&position.token_mint[..8])
             );
 
-            match curve_builder.build_raydium_sell(&sell_params).await {
+            let build = match self
+                .build_protected_sell(
+                    ExitVenue::Raydium,
+                    &position,
+                    signal,
+                    &sell_params,
+                    protection,
+                )
+                .await
+            {
                 Ok(raydium_result) => {
                     info!(
                         "Built Raydium exit tx for {}: expected {} SOL, impact {:.2}%",
@@ -662,15 +1021,10 @@ impl PositionExecutor {
                             .token_symbol
                             .as_deref()
                             .unwrap_or(&position.token_mint[..8]),
-                        raydium_result.expected_sol_out as f64 / 1e9,
+                        raydium_result.quote.expected_out as f64 / 1e9,
                         raydium_result.price_impact_percent
                     );
-                    (
-                        raydium_result.transaction_base64,
-                        raydium_result.expected_sol_out,
-                        exit_amount,
-                        "Raydium".to_string(),
-                    )
+                    raydium_result
                 }
                 Err(raydium_err) => {
                     warn!(
@@ -681,28 +1035,32 @@ impl PositionExecutor {
                             .unwrap_or(&position.token_mint[..8]),
                         raydium_err
                     );
-                    let exit_build = self
-                        .tx_builder
-                        .build_exit_swap(&position, signal, &user_wallet, slippage)
-                        .await?;
-                    (
-                        exit_build.transaction_base64,
-                        exit_build.expected_base_out,
-                        exit_build.token_amount_in,
-                        "Jupiter".to_string(),
+                    self.build_protected_sell(
+                        ExitVenue::Jupiter,
+                        &position,
+                        signal,
+                        &sell_params,
+                        protection,
                     )
+                    .await?
                 }
-            }
+            };
+            (
+                build.transaction_base64,
+                build.quote.expected_out,
+                build.token_amount_in,
+                build.venue.to_string(),
+            )
         } else {
-            let exit_build = self
+            let build = self
                 .tx_builder
-                .build_exit_swap(&position, signal, &user_wallet, slippage)
+                .build_protected_exit_swap(&position, signal, &user_wallet, protection)
                 .await?;
             (
-                exit_build.transaction_base64,
-                exit_build.expected_base_out,
-                exit_build.token_amount_in,
-                "Jupiter".to_string(),
+                build.transaction_base64,
+                build.quote.expected_out,
+                build.token_amount_in,
+                build.venue.to_string(),
             )
         };
 
@@ -757,7 +1115,7 @@ impl PositionExecutor {
             }
         };
 
-        let tip = 10_000;
+        let tip = self.config.exit_dispatch.tip_lamports;
         let tx_base58 = base64_to_base58(&signed_tx)?;
 
         let mut use_helius_fallback = false;
@@ -979,7 +1337,17 @@ impl PositionExecutor {
         let final_signature = helius_signature.or(sign_result.signature.clone());
         {
             let settlement = if let Some(ref sig) = final_signature {
-                self.resolve_sell_settlement(sig, &user_wallet).await
+                match self
+                    .resolve_sell_settlement(signal.position_id, sig, &user_wallet)
+                    .await
+                {
+                    Ok(settlement) => settlement,
+                    Err(e) => {
+                        self.emit_exit_failed_event(&position, signal, &e.to_string())
+                            .await;
+                        return Err(e);
+                    }
+                }
             } else {
                 None
             };
@@ -1036,6 +1404,7 @@ impl PositionExecutor {
                     signal,
                     realized_pnl_sol,
                     final_signature.as_deref(),
+                    false,
                 )
                 .await;
 
@@ -1092,6 +1461,7 @@ impl PositionExecutor {
                     signal,
                     realized_pnl_sol,
                     final_signature.as_deref(),
+                    true,
                 )
                 .await;
 
@@ -1132,6 +1502,285 @@ impl PositionExecutor {
         Ok(())
     }
 
+    /// Force-close path used by emergency close: walks the configured venue chain
+    /// rather than picking one route from the curve state, so a single venue outage
+    /// doesn't strand the position.
+    async fn execute_emergency_exit(&self, signal: &ExitSignal) -> AppResult<()> {
+        let position = match self.position_manager.get_position(signal.position_id).await {
+            Some(p) => p,
+            None => {
+                warn!("Position {} no longer exists", signal.position_id);
+                return Ok(());
+            }
+        };
+
+        if !self.claim_for_exit(&position).await {
+            return Ok(());
+        }
+
+        let user_wallet = self
+            .signer
+            .get_status()
+            .await
+            .wallet_address
+            .ok_or_else(|| {
+                AppError::Internal(
+                    "No wallet configured for exit - position exit cannot proceed".to_string(),
+                )
+            })?;
+
+        let token_balance = self
+            .tx_builder
+            .get_token_balance(&user_wallet, &position.token_mint)
+            .await?;
+
+        if token_balance == 0 {
+            warn!(
+                "Token balance is 0 for {} - position may have been sold externally",
+                position
+                    .token_symbol
+                    .as_deref()
+                    .unwrap_or(&position.token_mint[..8])
+            );
+            self.position_manager
+                .close_position(
+                    signal.position_id,
+                    signal.current_price,
+                    0.0,
+                    "AlreadySold",
+                    None,
+                    Some(position.momentum.momentum_score),
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let token_amount = if signal.exit_percent >= 100.0 {
+            token_balance
+        } else {
+            ((token_balance as f64) * (signal.exit_percent / 100.0))
+                .round()
+                .min(token_balance as f64) as u64
+        };
+
+        let seller = EmergencyExitSeller {
+            executor: self,
+            user_wallet: &user_wallet,
+            token_amount,
+        };
+
+        match self
+            .emergency_exit_chain
+            .execute(&seller, &self.position_manager, &position, signal)
+            .await
+        {
+            Ok(exit) => {
+                info!(
+                    "🚨 Emergency exit completed: {} via {} ({} venue(s) failed first) | Sig: {}",
+                    position
+                        .token_symbol
+                        .as_deref()
+                        .unwrap_or(&position.token_mint[..8]),
+                    exit.venue,
+                    exit.failed_attempts.len(),
+                    &exit.signature[..16.min(exit.signature.len())]
+                );
+                Ok(())
+            }
+            Err(e) => {
+                error!(
+                    position_id = %signal.position_id,
+                    token = %position.token_symbol.as_deref().unwrap_or(&position.token_mint[..8]),
+                    "Emergency exit failed on all venues: {}", e
+                );
+                self.emit_exit_failed_event(&position, signal, &e.to_string())
+                    .await;
+                Err(e)
+            }
+        }
+    }
+
+    /// Slippage for curve exit attempt `attempt`. Critical exits jump to
+    /// `emergency_slippage_bps` on their first retry and escalate from there; everything
+    /// else escalates one step per retry from `initial_slippage`.
+    fn exit_retry_slippage(&self, signal: &ExitSignal, initial_slippage: u16, attempt: u32) -> u16 {
+        let escalation = self.config.slippage_escalation;
+        if attempt > 0 && signal.urgency == ExitUrgency::Critical {
+            let emergency = self.config.emergency_slippage_bps.max(initial_slippage);
+            return escalation.slippage_for_attempt(emergency, attempt - 1);
+        }
+        escalation.slippage_for_attempt(initial_slippage, attempt)
+    }
+
+    /// Protection for a sell at `slippage_bps`, never looser than `sell_protection`.
+    fn sell_protection_for(&self, slippage_bps: u16) -> SellProtection {
+        let ceiling = self.config.sell_protection.max_slippage_bps;
+        if slippage_bps > ceiling {
+            warn!(
+                "Sell slippage {}bps capped at SELL_PROTECTION_MAX_SLIPPAGE_BPS ({}bps)",
+                slippage_bps, ceiling
+            );
+        }
+        SellProtection::new(slippage_bps.min(ceiling))
+    }
+
+    /// Builds a market sell through `venue` whose min-out is checked against its quote.
+    async fn build_protected_sell(
+        &self,
+        venue: ExitVenue,
+        position: &OpenPosition,
+        signal: &ExitSignal,
+        sell_params: &CurveSellParams,
+        protection: SellProtection,
+    ) -> AppResult<ProtectedSellResult> {
+        match venue {
+            ExitVenue::Jupiter => {
+                self.tx_builder
+                    .build_protected_exit_swap(
+                        position,
+                        signal,
+                        &sell_params.user_wallet,
+                        protection,
+                    )
+                    .await
+            }
+            ExitVenue::Curve | ExitVenue::Raydium => {
+                self.curve_builder
+                    .as_ref()
+                    .ok_or_else(|| AppError::Internal("Curve builder not configured".into()))?
+                    .build_protected_sell(sell_params, venue, protection)
+                    .await
+            }
+        }
+    }
+
+    async fn sell_position_via(
+        &self,
+        venue: ExitVenue,
+        position: &OpenPosition,
+        signal: &ExitSignal,
+        user_wallet: &str,
+        token_amount: u64,
+    ) -> AppResult<String> {
+        let protection = self.sell_protection_for(self.config.emergency_slippage_bps);
+        let sell_params = CurveSellParams {
+            mint: position.token_mint.clone(),
+            token_amount,
+            slippage_bps: protection.max_slippage_bps,
+            user_wallet: user_wallet.to_string(),
+        };
+
+        let build = self
+            .build_protected_sell(venue, position, signal, &sell_params, protection)
+            .await?;
+        let (transaction_base64, expected_sol_out) =
+            (build.transaction_base64, build.quote.expected_out);
+
+        let sign_result = self
+            .signer
+            .sign_transaction(SignRequest {
+                transaction_base64,
+                estimated_amount_lamports: expected_sol_out,
+                estimated_profit_lamports: None,
+                edge_id: Some(position.edge_id),
+                description: format!(
+                    "Emergency exit {} {} -> SOL via {}",
+                    position
+                        .token_symbol
+                        .as_deref()
+                        .unwrap_or(&position.token_mint[..8]),
+                    token_amount,
+                    venue
+                ),
+            })
+            .await?;
+
+        if !sign_result.success {
+            let error_msg = sign_result
+                .error
+                .or_else(|| sign_result.policy_violation.map(|v| v.message))
+                .unwrap_or_else(|| "Unknown signing error".to_string());
+            return Err(AppError::ExternalApi(format!(
+                "Signing failed: {}",
+                error_msg
+            )));
+        }
+
+        let signed_tx = sign_result
+            .signed_transaction_base64
+            .ok_or_else(|| AppError::ExternalApi("No signed transaction returned".to_string()))?;
+        let helius_sender = self
+            .helius_sender
+            .as_ref()
+            .ok_or_else(|| AppError::Internal("Helius sender not configured".into()))?;
+
+        let signature = helius_sender
+            .send_and_confirm(&signed_tx, Duration::from_secs(60))
+            .await?;
+
+        let settlement = self
+            .resolve_sell_settlement(position.id, &signature, user_wallet)
+            .await?;
+        let exit_price = signal.current_price;
+        let pnl_percent = if position.entry_price > 0.0 {
+            (exit_price - position.entry_price) / position.entry_price
+        } else {
+            0.0
+        };
+        let effective_base = if position.remaining_amount_base > 0.0 {
+            position.remaining_amount_base
+        } else {
+            position.entry_amount_base
+        };
+        let realized_pnl_sol = self.compute_pnl_with_settlement(
+            settlement.as_ref(),
+            effective_base * pnl_percent,
+            position,
+        );
+
+        // The sell has landed at this point, so a DB failure must not send the chain on
+        // to the next venue.
+        if let Err(e) = self
+            .position_manager
+            .close_position(
+                signal.position_id,
+                exit_price,
+                realized_pnl_sol,
+                &format!("{:?}-{}", signal.reason, venue),
+                Some(signature.clone()),
+                Some(position.momentum.momentum_score),
+            )
+            .await
+        {
+            error!(
+                "CRITICAL: Emergency exit via {} confirmed (sig: {}) but failed to close position {} in DB: {}",
+                venue, &signature[..16.min(signature.len())], signal.position_id, e
+            );
+        }
+
+        self.emit_exit_completed_event(position, signal, realized_pnl_sol, Some(&signature), true)
+            .await;
+        self.save_exit_to_engrams(
+            position,
+            signal,
+            realized_pnl_sol,
+            Some(&signature),
+            user_wallet,
+        )
+        .await;
+        self.save_sell_trade_record(
+            position,
+            signal,
+            realized_pnl_sol,
+            Some(&signature),
+            slippage,
+            settlement.as_ref(),
+        )
+        .await;
+
+        Ok(signature)
+    }
+
     async fn execute_curve_exit(
         &self,
         position: &OpenPosition,
@@ -1231,20 +1880,26 @@ impl PositionExecutor {
             }
         );
         let max_retries = self.config.max_exit_retries;
+        let escalation = self.config.slippage_escalation;
         let mut current_slippage = initial_slippage;
         let mut last_error = String::new();
-        let mut used_emergency = false;
+        let mut attempts: Vec<SlippageAttempt> = Vec::new();
 
         for attempt in 0..=max_retries {
             if attempt > 0 {
-                if !used_emergency {
-                    current_slippage = self.config.emergency_slippage_bps;
-                    used_emergency = true;
+                attempts.push(SlippageAttempt::failed(
+                    attempt - 1,
+                    current_slippage,
+                    &last_error,
+                ));
+                let next_slippage = self.exit_retry_slippage(signal, initial_slippage, attempt);
+                if next_slippage > current_slippage {
                     warn!(
-                        "EMERGENCY SLIPPAGE: Jumping to {}bps after failure (was {}bps)",
-                        current_slippage, initial_slippage
+                        "ESCALATING SLIPPAGE: {}bps -> {}bps for retry {} (max {}bps)",
+                        current_slippage, next_slippage, attempt, escalation.max_bps
                     );
                 }
+                current_slippage = next_slippage;
                 tokio::time::sleep(std::time::Duration::from_millis(300)).await;
             }
 
@@ -1262,7 +1917,11 @@ impl PositionExecutor {
                 user_wallet: user_wallet.to_string(),
             };
 
-            let build_result = match curve_builder.build_pump_fun_sell(&sell_params).await {
+            let protection = self.sell_protection_for(current_slippage);
+            let build_result = match self
+                .build_protected_sell(ExitVenue::Curve, position, signal, &sell_params, protection)
+                .await
+            {
                 Ok(r) => r,
                 Err(e) => {
                     last_error = e.to_string();
@@ -1276,7 +1935,16 @@ impl PositionExecutor {
                                 .unwrap_or(&position.token_mint[..8])
                         );
 
-                        match curve_builder.build_raydium_sell(&sell_params).await {
+                        match self
+                            .build_protected_sell(
+                                ExitVenue::Raydium,
+                                position,
+                                signal,
+                                &sell_params,
+                                protection,
+                            )
+                            .await
+                        {
                             Ok(raydium_result) => {
                                 info!(
                                     "Built Raydium sell for graduated {}: expected {} SOL",
@@ -1284,19 +1952,9 @@ impl PositionExecutor {
                                         .token_symbol
                                         .as_deref()
                                         .unwrap_or(&position.token_mint[..8]),
-                                    raydium_result.expected_sol_out as f64 / 1e9
+                                    raydium_result.quote.expected_out as f64 / 1e9
                                 );
-                                super::curve_builder::CurveBuildResult {
-                                    transaction_base64: raydium_result.transaction_base64,
-                                    expected_tokens_out: None,
-                                    expected_sol_out: Some(raydium_result.expected_sol_out),
-                                    min_tokens_out: None,
-                                    min_sol_out: None,
-                                    price_impact_percent: raydium_result.price_impact_percent,
-                                    fee_lamports: 0,
-                                    compute_units: 200_000,
-                                    priority_fee_lamports: 0,
-                                }
+                                raydium_result
                             }
                             Err(raydium_err) => {
                                 warn!(
@@ -1308,28 +1966,16 @@ impl PositionExecutor {
                                     raydium_err
                                 );
                                 match self
-                                    .tx_builder
-                                    .build_exit_swap(
+                                    .build_protected_sell(
+                                        ExitVenue::Jupiter,
                                         position,
                                         signal,
-                                        user_wallet,
-                                        current_slippage,
+                                        &sell_params,
+                                        protection,
                                     )
                                     .await
                                 {
-                                    Ok(jupiter_result) => super::curve_builder::CurveBuildResult {
-                                        transaction_base64: jupiter_result.transaction_base64,
-                                        expected_tokens_out: None,
-                                        expected_sol_out: Some(jupiter_result.expected_base_out),
-                                        min_tokens_out: None,
-                                        min_sol_out: None,
-                                        price_impact_percent: jupiter_result.price_impact_bps
-                                            as f64
-                                            / 100.0,
-                                        fee_lamports: 0,
-                                        compute_units: 200_000,
-                                        priority_fee_lamports: 0,
-                                    },
+                                    Ok(jupiter_result) => jupiter_result,
                                     Err(jupiter_err) => {
                                         last_error = format!(
                                             "Raydium: {} | Jupiter: {}",
@@ -1350,7 +1996,7 @@ impl PositionExecutor {
 
             let sign_request = SignRequest {
                 transaction_base64: build_result.transaction_base64.clone(),
-                estimated_amount_lamports: build_result.expected_sol_out.unwrap_or(0) as u64,
+                estimated_amount_lamports: build_result.quote.expected_out,
                 estimated_profit_lamports: None,
                 edge_id: Some(position.edge_id),
                 description: format!(
@@ -1402,7 +2048,16 @@ impl PositionExecutor {
                 .await
             {
                 Ok(signature) => {
-                    let settlement = self.resolve_sell_settlement(&signature, user_wallet).await;
+                    let settlement = self
+                        .resolve_curve_sell_settlement(
+                            position,
+                            signal,
+                            &signature,
+                            user_wallet,
+                            &mut attempts,
+                            current_slippage,
+                        )
+                        .await?;
 
                     let exit_price = signal.current_price;
                     let pnl_percent = if position.entry_price > 0.0 {
@@ -1458,6 +2113,7 @@ impl PositionExecutor {
                             signal,
                             realized_pnl_sol,
                             Some(&signature),
+                            false,
                         )
                         .await;
 
@@ -1522,6 +2178,7 @@ impl PositionExecutor {
                             signal,
                             realized_pnl_sol,
                             Some(&signature),
+                            true,
                         )
                         .await;
 
@@ -1557,6 +2214,15 @@ impl PositionExecutor {
                         )
                         .await;
                     }
+                    if attempt > 0 {
+                        attempts.push(SlippageAttempt::succeeded(attempt, current_slippage));
+                        info!(
+                            "Curve exit filled on attempt {} at {}bps: {}",
+                            attempt + 1,
+                            current_slippage,
+                            serde_json::to_string(&attempts).unwrap_or_default()
+                        );
+                    }
                     return Ok(());
                 }
                 Err(e) => {
@@ -1645,6 +2311,7 @@ impl PositionExecutor {
                                         signal,
                                         realized_pnl_sol,
                                         Some(&inferred_sig),
+                                        true,
                                     )
                                     .await;
 
@@ -1757,24 +2424,34 @@ impl PositionExecutor {
                     .min(token_balance as f64) as u64
             };
 
+            let protection = self.sell_protection_for(self.config.emergency_slippage_bps);
             let sell_params = CurveSellParams {
                 mint: position.token_mint.clone(),
                 token_amount: exit_amount,
-                slippage_bps: self.config.emergency_slippage_bps,
+                slippage_bps: protection.max_slippage_bps,
                 user_wallet: user_wallet.to_string(),
             };
 
-            if let Some(ref curve_builder) = self.curve_builder {
-                match curve_builder.build_raydium_sell(&sell_params).await {
+            if self.curve_builder.is_some() {
+                match self
+                    .build_protected_sell(
+                        ExitVenue::Raydium,
+                        position,
+                        signal,
+                        &sell_params,
+                        protection,
+                    )
+                    .await
+                {
                     Ok(raydium_result) => {
                         info!(
                             "Built Raydium fallback sell: expected {} SOL",
-                            raydium_result.expected_sol_out as f64 / 1e9
+                            raydium_result.quote.expected_out as f64 / 1e9
                         );
 
                         let sign_request = SignRequest {
                             transaction_base64: raydium_result.transaction_base64,
-                            estimated_amount_lamports: raydium_result.expected_sol_out,
+                            estimated_amount_lamports: raydium_result.quote.expected_out,
                             estimated_profit_lamports: None,
                             edge_id: Some(position.edge_id),
                             description: format!(
@@ -1792,8 +2469,15 @@ impl PositionExecutor {
                                             .await
                                         {
                                             let settlement = self
-                                                .resolve_sell_settlement(&signature, user_wallet)
-                                                .await;
+                                                .resolve_curve_sell_settlement(
+                                                    position,
+                                                    signal,
+                                                    &signature,
+                                                    user_wallet,
+                                                    &mut attempts,
+                                                    current_slippage,
+                                                )
+                                                .await?;
 
                                             let exit_price = signal.current_price;
                                             let pnl_percent = if position.entry_price > 0.0 {
@@ -1849,7 +2533,7 @@ impl PositionExecutor {
                                                 signal,
                                                 realized_pnl_sol,
                                                 Some(&signature),
-                                                self.config.emergency_slippage_bps,
+                                                protection.max_slippage_bps,
                                                 settlement.as_ref(),
                                             )
                                             .await;
@@ -1868,18 +2552,13 @@ impl PositionExecutor {
 
             match self
                 .tx_builder
-                .build_exit_swap(
-                    position,
-                    signal,
-                    user_wallet,
-                    self.config.emergency_slippage_bps,
-                )
+                .build_protected_exit_swap(position, signal, user_wallet, protection)
                 .await
             {
                 Ok(jupiter_result) => {
                     let sign_request = SignRequest {
                         transaction_base64: jupiter_result.transaction_base64,
-                        estimated_amount_lamports: jupiter_result.expected_base_out,
+                        estimated_amount_lamports: jupiter_result.quote.expected_out,
                         estimated_profit_lamports: None,
                         edge_id: Some(position.edge_id),
                         description: format!("Jupiter fallback exit {}", &position.token_mint[..8]),
@@ -1894,8 +2573,15 @@ impl PositionExecutor {
                                         .await
                                     {
                                         let settlement = self
-                                            .resolve_sell_settlement(&signature, user_wallet)
-                                            .await;
+                                            .resolve_curve_sell_settlement(
+                                                position,
+                                                signal,
+                                                &signature,
+                                                user_wallet,
+                                                &mut attempts,
+                                                current_slippage,
+                                            )
+                                            .await?;
 
                                         let exit_price = signal.current_price;
                                         let pnl_percent = if position.entry_price > 0.0 {
@@ -1947,7 +2633,7 @@ impl PositionExecutor {
                                             signal,
                                             realized_pnl_sol,
                                             Some(&signature),
-                                            self.config.emergency_slippage_bps,
+                                            protection.max_slippage_bps,
                                             settlement.as_ref(),
                                         )
                                         .await;
@@ -1966,12 +2652,17 @@ impl PositionExecutor {
             warn!("All DEX fallbacks failed for graduated token - queuing for retry");
         }
 
+        attempts.push(SlippageAttempt::failed(
+            attempts.len() as u32,
+            current_slippage,
+            &last_error,
+        ));
         error!(
             "Curve exit failed after {} attempts: {} - QUEUING HIGH PRIORITY RETRY",
-            max_retries + 1,
+            attempts.len(),
             last_error
         );
-        self.emit_exit_failed_event(position, signal, &last_error)
+        self.emit_exit_failed_event_with_attempts(position, signal, &last_error, &attempts)
             .await;
 
         if let Err(e) = self
@@ -1992,17 +2683,19 @@ impl PositionExecutor {
 
         Err(AppError::ExternalApi(format!(
             "Curve exit failed after {} attempts: {}",
-            max_retries + 1,
+            attempts.len(),
             last_error
         )))
     }
 
+    /// `is_final` is false for partial exits; only the final one closes the position.
     async fn emit_exit_completed_event(
         &self,
         position: &OpenPosition,
         signal: &ExitSignal,
         realized_pnl_sol: f64,
         tx_signature: Option<&str>,
+        is_final: bool,
     ) {
         let pnl_percent = if position.entry_amount_base > 0.0 {
             (realized_pnl_sol / position.entry_amount_base) * 100.0
@@ -2018,9 +2711,12 @@ impl PositionExecutor {
                 "position_id": position.id,
                 "edge_id": position.edge_id,
                 "strategy_id": position.strategy_id,
+                "signal_source": position.signal_source,
                 "token_mint": position.token_mint,
                 "token_symbol": position.token_symbol,
                 "exit_reason": format!("{:?}", signal.reason),
+                "exit_percent": signal.exit_percent,
+                "is_final": is_final,
                 "entry_price": position.entry_price,
                 "exit_price": signal.current_price,
                 "entry_amount_sol": position.entry_amount_base,
@@ -2042,16 +2738,31 @@ impl PositionExecutor {
         signal: &ExitSignal,
         error: &str,
     ) {
+        self.emit_exit_failed_event_with_attempts(position, signal, error, &[])
+            .await;
+    }
+
+    async fn emit_exit_failed_event_with_attempts(
+        &self,
+        position: &OpenPosition,
+        signal: &ExitSignal,
+        error: &str,
+        attempts: &[SlippageAttempt],
+    ) {
+        let mut payload = serde_json::json!({
+            "position_id": position.id,
+            "edge_id": position.edge_id,
+            "exit_reason": format!("{:?}", signal.reason),
+            "error": error,
+        });
+        if !attempts.is_empty() {
+            payload["slippage_attempts"] = serde_json::to_value(attempts).unwrap_or_default();
+        }
         let event = ArbEvent::new(
             "position.exit_failed",
             EventSource::Agent(AgentType::Executor),
             topics::position::EXIT_FAILED,
-            serde_json::json!({
-                "position_id": position.id,
-                "edge_id": position.edge_id,
-                "exit_reason": format!("{:?}", signal.reason),
-                "error": error,
-            }),
+            payload,
         );
 
         if let Err(e) = self.event_tx.send(event) {
@@ -2060,6 +2771,26 @@ impl PositionExecutor {
     }
 }
 
+struct EmergencyExitSeller<'a> {
+    executor: &'a PositionExecutor,
+    user_wallet: &'a str,
+    token_amount: u64,
+}
+
+#[async_trait]
+impl ExitVenueSeller for EmergencyExitSeller<'_> {
+    async fn sell_via(
+        &self,
+        venue: ExitVenue,
+        position: &OpenPosition,
+        signal: &ExitSignal,
+    ) -> AppResult<String> {
+        self.executor
+            .sell_position_via(venue, position, signal, self.user_wallet, self.token_amount)
+            .await
+    }
+}
+
 fn base64_to_base58(base64_str: &str) -> AppResult<String> {
     use base64::{engine::general_purpose::STANDARD, Engine};
 
@@ -2069,3 +2800,557 @@ fn base64_to_base58(base64_str: &str) -> AppResult<String> {
 
     Ok(bs58::encode(bytes).into_string())
 }
+
+/// Tokens a buy delivered: the wallet's balance change when both reads succeeded and
+/// show an increase, otherwise the quoted amount.
+fn scale_in_tokens_received(
+    balance_before: Option<u64>,
+    balance_after: Option<u64>,
+    quoted_tokens: u64,
+) -> u64 {
+    match (balance_before, balance_after) {
+        (Some(before), Some(after)) if after > before => after - before,
+        _ => quoted_tokens,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::position_manager::ExitConfig;
+    use crate::execution::scale_in::{ScaleInConfig, ScaleInTranche};
+    use crate::helius::SignatureStatus;
+
+    const LAMPORTS: f64 = 1_000_000_000.0;
+
+    fn executor(
+        position_manager: Arc<PositionManager>,
+    ) -> (PositionExecutor, broadcast::Receiver<ArbEvent>) {
+        let (event_tx, event_rx) = broadcast::channel(16);
+        (executor_on(position_manager, event_tx), event_rx)
+    }
+
+    fn executor_on(
+        position_manager: Arc<PositionManager>,
+        event_tx: broadcast::Sender<ArbEvent>,
+    ) -> PositionExecutor {
+        let (_tx, rx) = mpsc::channel(1);
+        PositionExecutor::new(
+            rx,
+            position_manager,
+            Arc::new(
+                TransactionBuilder::new(
+                    "http://127.0.0.1:1".to_string(),
+                    "http://127.0.0.1:1".to_string(),
+                )
+                .unwrap(),
+            ),
+            Arc::new(JitoClient::new("http://127.0.0.1:1".to_string(), None)),
+            event_tx,
+            Arc::new(DevWalletSigner::new(None, None).unwrap()),
+            ExecutorConfig {
+                exit_settlement: ExitSettlementConfig {
+                    timeout: Duration::from_millis(20),
+                    poll_interval: Duration::from_millis(1),
+                    ..ExitSettlementConfig::default()
+                },
+                ..ExecutorConfig::default()
+            },
+        )
+    }
+
+    /// 0.5 SOL for 5,000,000 tokens at 6 decimals, stored like live entries: lamports per
+    /// raw token.
+    async fn open_position(manager: &PositionManager, strategy_id: Uuid) -> OpenPosition {
+        let position = manager
+            .open_position(
+                Uuid::new_v4(),
+                strategy_id,
+                "ScaleInExecMint111111".to_string(),
+                None,
+                0.5,
+                5_000_000_000_000.0,
+                0.5 * LAMPORTS / 5_000_000_000_000.0,
+                ExitConfig {
+                    stop_loss_percent: Some(50.0),
+                    ..ExitConfig::default()
+                },
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        manager
+            .set_scale_in(
+                position.id,
+                Some(ScaleInConfig {
+                    tranches: vec![ScaleInTranche {
+                        dip_percent: 20.0,
+                        add_percent: 100.0,
+                    }],
+                    max_total_base: 1.0,
+                }),
+            )
+            .await
+            .unwrap();
+        position
+    }
+
+    fn signal(position: &OpenPosition, amount_base: f64) -> ScaleInSignal {
+        ScaleInSignal {
+            position_id: position.id,
+            tranche: 0,
+            amount_base,
+            current_price: position.entry_price * 0.8,
+            triggered_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scale_in_fill_averages_entry_in_lamports_per_raw_token() {
+        let manager = PositionManager::new();
+        let position = open_position(&manager, Uuid::new_v4()).await;
+
+        // 20% lower: the same 0.5 SOL now buys 6,250,000 tokens
+        let tokens = scale_in_tokens_received(
+            Some(5_000_000_000_000),
+            Some(11_250_000_000_000),
+            6_000_000_000_000,
+        );
+        assert_eq!(tokens, 6_250_000_000_000);
+        let price = scale_in::fill_price(500_000_000, tokens).unwrap();
+        assert!((price - position.entry_price * 0.8).abs() < 1e-12);
+
+        let updated = manager
+            .record_scale_in(position.id, 0, 0.5, tokens as f64, price, None)
+            .await
+            .unwrap();
+        // 1 SOL over 11.25M tokens
+        let expected_entry = LAMPORTS / 11_250_000_000_000.0;
+        assert!((updated.entry_price - expected_entry).abs() < 1e-12);
+        assert!((updated.entry_amount_base - 1.0).abs() < 1e-9);
+
+        // An unreadable balance falls back to the quote
+        assert_eq!(scale_in_tokens_received(None, Some(1), 42), 42);
+        assert_eq!(scale_in_tokens_received(Some(7), Some(7), 42), 42);
+    }
+
+    #[tokio::test]
+    async fn test_scale_in_checks_capital_and_releases_on_failure() {
+        let manager = Arc::new(PositionManager::new());
+        let capital = Arc::new(CapitalManager::new());
+        let strategy_id = Uuid::new_v4();
+        capital.register_strategy(strategy_id, 50.0, 5).await;
+        capital.update_balance(2 * LAMPORTS as u64).await;
+
+        let position = open_position(&manager, strategy_id).await;
+        capital
+            .reserve_capital(strategy_id, position.id, 500_000_000)
+            .await
+            .unwrap();
+        let (executor, _) = executor(manager.clone());
+        let executor = executor.with_capital_manager(capital.clone());
+
+        // 0.6 SOL would take the strategy past its 1 SOL budget
+        let err = executor
+            .execute_scale_in(&signal(&position, 0.6))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("capital check"));
+
+        // Within budget it reserves, then hands the reservation back when the buy fails
+        let err = executor
+            .execute_scale_in(&signal(&position, 0.5))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("No wallet configured"));
+        let usage = capital.get_strategy_usage(strategy_id).await.unwrap();
+        assert_eq!(usage.current_reserved_lamports, 500_000_000);
+        let position = manager.get_position(position.id).await.unwrap();
+        assert!(position.scale_in.unwrap().fills.is_empty());
+    }
+
+    /// Solana RPC that reports a token balance for any account and errors on everything
+    /// else, so every curve sell build fails and the exit runs through all its retries.
+    async fn spawn_failing_curve_rpc() -> String {
+        let app = axum::Router::new().route(
+            "/",
+            axum::routing::post(
+                |axum::Json(request): axum::Json<serde_json::Value>| async move {
+                    let id = request["id"].clone();
+                    let body = match request["method"].as_str() {
+                        Some("getTokenAccountBalance") => serde_json::json!({
+                            "jsonrpc": "2.0",
+                            "id": id,
+                            "result": {
+                                "context": { "slot": 1 },
+                                "value": {
+                                    "amount": "5000000000000",
+                                    "decimals": 6,
+                                    "uiAmount": 5000000.0,
+                                    "uiAmountString": "5000000"
+                                }
+                            }
+                        }),
+                        _ => serde_json::json!({
+                            "jsonrpc": "2.0",
+                            "id": id,
+                            "error": { "code": -32602, "message": "account not found" }
+                        }),
+                    };
+                    axum::Json(body)
+                },
+            ),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        url
+    }
+
+    /// Runs a curve exit that never fills and returns the slippage of each attempt, as
+    /// reported on the `position.exit_failed` event.
+    async fn failed_curve_exit_slippage(urgency: ExitUrgency) -> Vec<u64> {
+        let rpc_url = spawn_failing_curve_rpc().await;
+        let manager = Arc::new(PositionManager::new());
+        let (event_tx, mut events) = broadcast::channel(16);
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .connect_lazy("postgres://localhost/unused")
+            .unwrap();
+        let sender = Arc::new(HeliusSender::new(
+            Arc::new(HeliusClient::new_mock_with_rpc_url(&rpc_url)),
+            Arc::new(crate::events::EventBus::new(event_tx.clone(), pool)),
+        ));
+        let mut executor = executor_on(manager.clone(), event_tx)
+            .with_curve_support(Arc::new(CurveTransactionBuilder::new(&rpc_url)), sender);
+        executor.config.max_exit_retries = 3;
+        executor.config.emergency_slippage_bps = 2000;
+        executor.config.slippage_escalation = SlippageEscalation {
+            step_bps: 300,
+            max_bps: 3000,
+        };
+
+        let position = manager
+            .open_position(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                solana_sdk::pubkey::Pubkey::new_unique().to_string(),
+                None,
+                0.5,
+                5_000_000_000_000.0,
+                100.0,
+                ExitConfig::default(),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let signal = ExitSignal {
+            position_id: position.id,
+            reason: ExitReason::StopLoss,
+            exit_percent: 100.0,
+            current_price: 80.0,
+            triggered_at: Utc::now(),
+            urgency,
+        };
+        let wallet = solana_sdk::pubkey::Pubkey::new_unique().to_string();
+
+        let err = executor
+            .execute_curve_exit(&position, &signal, &wallet, 1000)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("after 4 attempts"));
+        assert_eq!(manager.drain_priority_exits().await, vec![position.id]);
+
+        let event = loop {
+            let event = events.try_recv().unwrap();
+            if event.topic == topics::position::EXIT_FAILED {
+                break event;
+            }
+        };
+        event.payload["slippage_attempts"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|a| a["slippage_bps"].as_u64().unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_curve_exit_retries_escalate_slippage_up_to_cap() {
+        assert_eq!(
+            failed_curve_exit_slippage(ExitUrgency::High).await,
+            vec![1000, 1300, 1600, 1900]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_critical_curve_exit_jumps_to_emergency_slippage() {
+        assert_eq!(
+            failed_curve_exit_slippage(ExitUrgency::Critical).await,
+            vec![1000, 2000, 2300, 2600]
+        );
+    }
+
+    /// Never reports the exit in recent statuses; `history` is what a ledger search finds.
+    struct UnseenExit {
+        history: Option<SignatureStatus>,
+    }
+
+    #[async_trait]
+    impl SignatureStatusSource for UnseenExit {
+        async fn signature_status(&self, _signature: &str) -> AppResult<Option<SignatureStatus>> {
+            Ok(None)
+        }
+
+        async fn search_signature_history(
+            &self,
+            _signature: &str,
+        ) -> AppResult<Option<SignatureStatus>> {
+            Ok(self.history.clone())
+        }
+    }
+
+    /// A position that sold a take-profit tier and is now sending the exit for the rest.
+    async fn exiting_partial_position(manager: &PositionManager) -> (OpenPosition, ExitSignal) {
+        let position = manager
+            .open_position(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                "SettleExecMint1111111".to_string(),
+                None,
+                0.5,
+                5_000_000_000_000.0,
+                100.0,
+                ExitConfig::default(),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        manager
+            .record_partial_exit(position.id, 50.0, 120.0, 0.05, None, "PartialTakeProfit")
+            .await
+            .unwrap();
+        assert!(manager
+            .compare_and_swap_status(
+                position.id,
+                PositionStatus::PartiallyExited,
+                PositionStatus::PendingExit,
+            )
+            .await
+            .unwrap());
+        let signal = ExitSignal {
+            position_id: position.id,
+            reason: ExitReason::TakeProfit,
+            exit_percent: 100.0,
+            current_price: 130.0,
+            triggered_at: Utc::now(),
+            urgency: ExitUrgency::High,
+        };
+        let position = manager.get_position(position.id).await.unwrap();
+        (position, signal)
+    }
+
+    #[tokio::test]
+    async fn test_unsettled_curve_exit_restores_partial_status_and_reports_attempt() {
+        let manager = Arc::new(PositionManager::new());
+        let (executor, mut events) = executor(manager.clone());
+        let executor =
+            executor.with_signature_status_source(Arc::new(UnseenExit { history: None }));
+        let (position, signal) = exiting_partial_position(&manager).await;
+
+        let mut attempts = Vec::new();
+        let err = executor
+            .resolve_curve_sell_settlement(
+                &position,
+                &signal,
+                "UnseenExitSignature1111111111",
+                "Wallet1111111111111111111111",
+                &mut attempts,
+                1500,
+            )
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("did not settle"));
+
+        // Back to where it was before the exit, not Open, and queued for a retry
+        assert_eq!(
+            manager.get_position(position.id).await.unwrap().status,
+            PositionStatus::PartiallyExited
+        );
+        assert_eq!(manager.drain_priority_exits().await, vec![position.id]);
+
+        assert_eq!(attempts.len(), 1);
+        assert_eq!(attempts[0].slippage_bps, 1500);
+        let event = events.try_recv().unwrap();
+        assert_eq!(event.topic, topics::position::EXIT_FAILED);
+        assert_eq!(event.payload["slippage_attempts"][0]["slippage_bps"], 1500);
+    }
+
+    #[tokio::test]
+    async fn test_exit_found_in_history_is_not_resent() {
+        let manager = Arc::new(PositionManager::new());
+        let (executor, _) = executor(manager.clone());
+        let executor = executor.with_signature_status_source(Arc::new(UnseenExit {
+            history: Some(SignatureStatus {
+                slot: 7,
+                confirmations: None,
+                err: None,
+                confirmation_status: Some("finalized".to_string()),
+            }),
+        }));
+        let (position, _) = exiting_partial_position(&manager).await;
+
+        let settlement = executor
+            .resolve_sell_settlement(
+                position.id,
+                "LandedExitSignature111111111",
+                "Wallet1111111111111111111111",
+            )
+            .await
+            .unwrap();
+        // Settled, and no Helius client to read the balance change from
+        assert!(settlement.is_none());
+        assert_eq!(
+            manager.get_position(position.id).await.unwrap().status,
+            PositionStatus::Settling
+        );
+        assert!(manager.drain_priority_exits().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_simultaneous_exits_dispatched_in_waves_by_urgency_then_value() {
+        let manager = Arc::new(PositionManager::new());
+        let (mut executor, _events) = executor(manager.clone());
+        executor.config.exit_dispatch.max_wave_size = 2;
+
+        let mut ids = Vec::new();
+        for (i, amount) in [0.2, 1.5, 0.8, 0.05].into_iter().enumerate() {
+            let position = manager
+                .open_position(
+                    Uuid::new_v4(),
+                    Uuid::new_v4(),
+                    format!("WaveMint{}11111111111", i),
+                    None,
+                    amount,
+                    1_000_000.0,
+                    amount * LAMPORTS / 1_000_000.0,
+                    ExitConfig::default(),
+                    None,
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+            ids.push(position.id);
+        }
+
+        let exit = |position_id: Uuid, exit_percent: f64, urgency: ExitUrgency| {
+            PositionCommand::Exit(ExitCommand::new(
+                ExitSignal {
+                    position_id,
+                    reason: ExitReason::StopLoss,
+                    exit_percent,
+                    current_price: 0.0,
+                    triggered_at: Utc::now(),
+                    urgency,
+                },
+                CommandSource::Monitor,
+            ))
+        };
+        let untracked = Uuid::new_v4();
+        let commands = vec![
+            exit(ids[0], 100.0, ExitUrgency::High),
+            // Half of 1.5 is still worth more than all of 0.2
+            exit(ids[1], 50.0, ExitUrgency::High),
+            exit(ids[2], 100.0, ExitUrgency::High),
+            exit(untracked, 100.0, ExitUrgency::High),
+            // Smallest, but critical
+            exit(ids[3], 100.0, ExitUrgency::Critical),
+        ];
+
+        let waves = executor.plan_dispatch(commands).await;
+        let order: Vec<Vec<Uuid>> = waves
+            .iter()
+            .map(|wave| wave.iter().map(|c| c.position_id()).collect())
+            .collect();
+        assert_eq!(
+            order,
+            vec![vec![ids[3], ids[2]], vec![ids[1], ids[0]], vec![untracked],]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_strategy_feedback_driven_by_position_events() {
+        use crate::agents::strategies::{
+            BehavioralStrategy, GraduationSniperStrategy, StrategyFeedbackConfig, StrategyRegistry,
+        };
+
+        let (event_tx, _event_rx) = broadcast::channel(64);
+        let registry = Arc::new(StrategyRegistry::with_feedback_config(
+            StrategyFeedbackConfig {
+                auto_disable_enabled: true,
+                window_size: 4,
+                min_trades: 1,
+                auto_disable_pnl_sol: -0.1,
+            },
+        ));
+        let strategy = Arc::new(GraduationSniperStrategy::new());
+        registry.register(strategy.clone()).await;
+        registry.start_feedback_loop(&event_tx);
+
+        let manager = Arc::new(PositionManager::new().with_event_tx(event_tx.clone()));
+        let executor = executor_on(manager.clone(), event_tx);
+
+        // Opened and closed through the same paths live trades use
+        let position = manager
+            .open_position(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                "FeedbackLoopMint11111".to_string(),
+                None,
+                0.5,
+                1_000.0,
+                0.0005,
+                ExitConfig::default(),
+                None,
+                Some("pump_fun".to_string()),
+                Some(strategy.strategy_type().to_string()),
+            )
+            .await
+            .unwrap();
+        let exit = ExitSignal {
+            position_id: position.id,
+            reason: ExitReason::StopLoss,
+            exit_percent: 100.0,
+            current_price: 0.0003,
+            triggered_at: Utc::now(),
+            urgency: ExitUrgency::Critical,
+        };
+        executor
+            .emit_exit_completed_event(&position, &exit, -0.2, None, true)
+            .await;
+
+        let performance = registry.performance();
+        let mut perf = None;
+        for _ in 0..100 {
+            perf = performance
+                .get("graduation_snipe")
+                .await
+                .filter(|p| p.trades_closed == 1);
+            if perf.is_some() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        let perf = perf.expect("close attributed to the strategy");
+        assert_eq!(perf.trades_opened, 1);
+        assert!((perf.recent_pnl_sol + 0.2).abs() < 1e-9);
+        assert!(perf.auto_disabled_at.is_some());
+        assert!(!strategy.is_active());
+    }
+}
@@ -2,24 +2,36 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
 use crate::database::{PendingExitSignalRow, PositionRepository};
 use crate::error::{AppError, AppResult};
+use crate::events::{topics, AgentType, ArbEvent, EventSource};
+use crate::execution::break_even::{BreakEvenCostModel, RoundTripCosts};
+use crate::execution::risk::MAX_REENTRY_COOLDOWN_SECS;
+use crate::execution::scale_in::{
+    ScaleInConfig, ScaleInFill, ScaleInLimits, ScaleInPlan, ScaleInSignal,
+};
+use crate::execution::sol_price::{pnl_usd, SolUsdPriceCache};
+use crate::models::VenueType;
+use crate::wallet::BalanceCache;
 use tracing::error;
 
 pub const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
 pub const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
 pub const USDT_MINT: &str = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB";
 
-#[allow(dead_code)]
 pub const CURVE_ENTRY_FEE_BPS: u16 = 100; // 1% entry fee on bonding curves
-#[allow(dead_code)]
 pub const CURVE_EXIT_FEE_BPS: u16 = 100; // 1% exit fee on bonding curves
 #[allow(dead_code)]
 pub const MIN_EXIT_SLIPPAGE_BPS: u16 = 150; // 1.5% minimum slippage tolerance
+pub const STANDARD_EXIT_SLIPPAGE_BPS: u16 = 500; // 5% floor for profit-aware exits
+pub const DEAD_TOKEN_EXIT_SLIPPAGE_BPS: u16 = 5000; // 50% - thin books need it to fill
+pub const MAX_EXIT_SLIPPAGE_BPS: u16 = 5000;
+/// How long a scale-in handed to the executor blocks another one for the same position.
+pub const SCALE_IN_RETRY_SECS: i64 = 60;
 #[allow(dead_code)]
 pub const MIN_NET_PROFIT_THRESHOLD_PERCENT: f64 = 4.0; // Break-even threshold after all costs
 
@@ -56,6 +68,26 @@ impl BaseCurrency {
         }
     }
 
+    /// USD P&L of a trade in this currency. Only SOL trades are converted, at the
+    /// SOL/USD rates stamped at entry and exit; stablecoin P&L already is USD.
+    pub fn pnl_usd(
+        &self,
+        entry_amount_base: f64,
+        pnl_base: f64,
+        entry_sol_usd_rate: Option<f64>,
+        exit_sol_usd_rate: Option<f64>,
+    ) -> Option<f64> {
+        match self {
+            BaseCurrency::Sol => pnl_usd(
+                entry_amount_base,
+                pnl_base,
+                entry_sol_usd_rate,
+                exit_sol_usd_rate,
+            ),
+            BaseCurrency::Usdc | BaseCurrency::Usdt => Some(pnl_base),
+        }
+    }
+
     pub fn from_mint(mint: &str) -> Option<Self> {
         match mint {
             SOL_MINT => Some(BaseCurrency::Sol),
@@ -103,10 +135,28 @@ pub struct ExitConfig {
     pub partial_take_profit: Option<PartialTakeProfit>,
     #[serde(default)]
     pub custom_exit_instructions: Option<String>,
+    /// Per-position exit slippage override; when unset the executor picks a
+    /// profit-aware value (or the salvage tolerance for dead tokens).
+    #[serde(default)]
+    pub exit_slippage_bps: Option<u16>,
     #[serde(default)]
     pub momentum_adaptive: Option<MomentumAdaptiveConfig>,
     #[serde(default)]
     pub adaptive_partial_tp: Option<AdaptivePartialTakeProfit>,
+    #[serde(default)]
+    pub momentum_fade_partial: Option<MomentumFadeTakeProfit>,
+    /// Once the first take-profit tier has sold, stop the remainder out at entry
+    /// plus round-trip fees instead of the original stop loss.
+    #[serde(default)]
+    pub move_stop_to_breakeven_after_tp: bool,
+    /// Let `time_limit_minutes` pass over positions that are in profit after
+    /// round-trip fees, leaving them to the profit-taking exits.
+    #[serde(default)]
+    pub time_limit_only_if_unprofitable: bool,
+    /// Measure every profit and loss threshold from the fee-aware break-even price
+    /// (venue fees, network fees and token account rent) instead of the entry price.
+    #[serde(default)]
+    pub exits_from_break_even: bool,
 }
 
 impl Default for ExitConfig {
@@ -121,8 +171,13 @@ impl Default for ExitConfig {
             time_limit_minutes: Some(15),  // 15 min - let winners run
             partial_take_profit: None,
             custom_exit_instructions: None,
+            exit_slippage_bps: None,
             momentum_adaptive: None,
             adaptive_partial_tp: None,
+            momentum_fade_partial: None,
+            move_stop_to_breakeven_after_tp: false,
+            time_limit_only_if_unprofitable: false,
+            exits_from_break_even: false,
         }
     }
 }
@@ -138,8 +193,13 @@ impl ExitConfig {
             time_limit_minutes: None,
             partial_take_profit: None,
             custom_exit_instructions: None,
+            exit_slippage_bps: None,
             momentum_adaptive: None,
             adaptive_partial_tp: None,
+            momentum_fade_partial: None,
+            move_stop_to_breakeven_after_tp: false,
+            time_limit_only_if_unprofitable: false,
+            exits_from_break_even: false,
         }
     }
 
@@ -153,8 +213,13 @@ impl ExitConfig {
             time_limit_minutes: None,
             partial_take_profit: None,
             custom_exit_instructions: None,
+            exit_slippage_bps: None,
             momentum_adaptive: None,
             adaptive_partial_tp: None,
+            momentum_fade_partial: None,
+            move_stop_to_breakeven_after_tp: false,
+            time_limit_only_if_unprofitable: false,
+            exits_from_break_even: false,
         }
     }
 
@@ -179,6 +244,23 @@ impl ExitConfig {
         }
     }
 
+    pub fn is_dead_token(&self) -> bool {
+        self.custom_exit_instructions
+            .as_deref()
+            .is_some_and(|s| s.contains("DEAD TOKEN"))
+    }
+
+    /// Slippage for an exit: the per-position override if set, the dead-token
+    /// tolerance for salvage exits or legacy dead-token configs, else `standard_bps`.
+    pub fn resolve_exit_slippage_bps(&self, is_salvage: bool, standard_bps: u16) -> u16 {
+        let bps = match self.exit_slippage_bps {
+            Some(bps) => bps,
+            None if is_salvage || self.is_dead_token() => DEAD_TOKEN_EXIT_SLIPPAGE_BPS,
+            None => standard_bps,
+        };
+        bps.min(MAX_EXIT_SLIPPAGE_BPS)
+    }
+
     pub fn ensure_minimum_exit_strategy(&mut self) {
         if !self.has_valid_exit_strategy() {
             self.stop_loss_percent = Some(15.0);
@@ -206,8 +288,13 @@ impl ExitConfig {
                 second_exit_percent: 100.0,
             }),
             custom_exit_instructions: Some("Auto-created for discovered wallet token".to_string()),
+            exit_slippage_bps: None,
             momentum_adaptive: None,
             adaptive_partial_tp: None,
+            momentum_fade_partial: None,
+            move_stop_to_breakeven_after_tp: false,
+            time_limit_only_if_unprofitable: false,
+            exits_from_break_even: false,
         }
     }
 
@@ -223,8 +310,13 @@ impl ExitConfig {
             time_limit_minutes: Some(1), // Immediate exit
             partial_take_profit: None,
             custom_exit_instructions: Some("DEAD TOKEN - salvage sell".to_string()),
+            exit_slippage_bps: Some(DEAD_TOKEN_EXIT_SLIPPAGE_BPS),
             momentum_adaptive: None,
             adaptive_partial_tp: None,
+            momentum_fade_partial: None,
+            move_stop_to_breakeven_after_tp: false,
+            time_limit_only_if_unprofitable: false,
+            exits_from_break_even: false,
         }
     }
 
@@ -262,8 +354,13 @@ impl ExitConfig {
                 "Auto-created: vol={:.1} SOL, holders={}",
                 volume_24h_sol, holder_count
             )),
+            exit_slippage_bps: None,
             momentum_adaptive: None,
             adaptive_partial_tp: None,
+            momentum_fade_partial: None,
+            move_stop_to_breakeven_after_tp: false,
+            time_limit_only_if_unprofitable: false,
+            exits_from_break_even: false,
         }
     }
 
@@ -290,6 +387,7 @@ impl ExitConfig {
                 second_exit_percent: 25.0,
             }),
             custom_exit_instructions: None,
+            exit_slippage_bps: None,
             momentum_adaptive: Some(MomentumAdaptiveConfig::default()),
             adaptive_partial_tp: Some(AdaptivePartialTakeProfit {
                 first_target_percent: 20.0,
@@ -300,6 +398,10 @@ impl ExitConfig {
                 third_exit_percent: 100.0,
                 enable_extended_targets: true,
             }),
+            momentum_fade_partial: None,
+            move_stop_to_breakeven_after_tp: false,
+            time_limit_only_if_unprofitable: false,
+            exits_from_break_even: false,
         }
     }
 
@@ -318,8 +420,13 @@ impl ExitConfig {
                 second_exit_percent: 100.0,
             }),
             custom_exit_instructions: None,
+            exit_slippage_bps: None,
             momentum_adaptive: None,
             adaptive_partial_tp: None,
+            momentum_fade_partial: None,
+            move_stop_to_breakeven_after_tp: false,
+            time_limit_only_if_unprofitable: false,
+            exits_from_break_even: false,
         }
     }
 
@@ -338,8 +445,13 @@ impl ExitConfig {
                 second_exit_percent: 25.0,
             }),
             custom_exit_instructions: None,
+            exit_slippage_bps: None,
             momentum_adaptive: Some(MomentumAdaptiveConfig::default()),
             adaptive_partial_tp: Some(AdaptivePartialTakeProfit::default()),
+            momentum_fade_partial: None,
+            move_stop_to_breakeven_after_tp: false,
+            time_limit_only_if_unprofitable: false,
+            exits_from_break_even: false,
         }
     }
 
@@ -354,8 +466,13 @@ impl ExitConfig {
             time_limit_minutes: Some(5),      // 5 min max hold - conservative is faster
             partial_take_profit: None,        // No partial - exit all at once
             custom_exit_instructions: None,
+            exit_slippage_bps: None,
             momentum_adaptive: None,
             adaptive_partial_tp: None,
+            momentum_fade_partial: None,
+            move_stop_to_breakeven_after_tp: false,
+            time_limit_only_if_unprofitable: false,
+            exits_from_break_even: false,
         }
     }
 
@@ -386,6 +503,7 @@ impl ExitConfig {
                 second_exit_percent: 65.0,   // Sell remaining
             }),
             custom_exit_instructions: None,
+            exit_slippage_bps: None,
             momentum_adaptive: if momentum_enabled {
                 Some(MomentumAdaptiveConfig::default())
             } else {
@@ -405,6 +523,10 @@ impl ExitConfig {
             } else {
                 None
             },
+            momentum_fade_partial: None,
+            move_stop_to_breakeven_after_tp: false,
+            time_limit_only_if_unprofitable: false,
+            exits_from_break_even: false,
         }
     }
 
@@ -425,6 +547,7 @@ impl ExitConfig {
             custom_exit_instructions: Some(
                 "DEFENSIVE: 15% TP, strong momentum can run".to_string(),
             ),
+            exit_slippage_bps: None,
             momentum_adaptive: Some(MomentumAdaptiveConfig::defensive()),
             adaptive_partial_tp: Some(AdaptivePartialTakeProfit {
                 first_target_percent: 15.0,    // 15% - base target
@@ -435,6 +558,10 @@ impl ExitConfig {
                 third_exit_percent: 100.0,     // Exit remaining
                 enable_extended_targets: true, // Let strong momentum run!
             }),
+            momentum_fade_partial: None,
+            move_stop_to_breakeven_after_tp: false,
+            time_limit_only_if_unprofitable: false,
+            exits_from_break_even: false,
         }
     }
 }
@@ -468,6 +595,8 @@ pub struct MomentumData {
     pub initial_price: Option<f64>, // Preserve initial entry price context (not lost when history window rolls)
     #[serde(default)]
     pub initial_timestamp: Option<DateTime<Utc>>, // When momentum tracking started
+    #[serde(default)]
+    pub strong_since_last_fade: bool, // Armed by a Strong reading, consumed by detect_fade
 }
 
 impl MomentumData {
@@ -561,6 +690,22 @@ impl MomentumData {
         MomentumStrength::Normal
     }
 
+    /// True on the reading where momentum drops to Weak or Reversing after having
+    /// been Strong since the last fade.
+    pub fn detect_fade(&mut self, config: &MomentumAdaptiveConfig) -> bool {
+        match self.classify_strength(config) {
+            MomentumStrength::Strong => {
+                self.strong_since_last_fade = true;
+                false
+            }
+            MomentumStrength::Weak | MomentumStrength::Reversing if self.strong_since_last_fade => {
+                self.strong_since_last_fade = false;
+                true
+            }
+            _ => false,
+        }
+    }
+
     pub fn calculate_adaptive_exit_percent(
         &self,
         base_percent: f64,
@@ -752,6 +897,63 @@ impl MomentumData {
     }
 }
 
+pub const MAX_POSITION_TAGS: usize = 16;
+pub const MAX_POSITION_TAG_LEN: usize = 32;
+pub const MAX_POSITION_NOTES_LEN: usize = 2000;
+
+/// Review notes and tags on a position. Tags are trimmed, lowercased and deduplicated,
+/// so history filters match regardless of how they were typed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PositionAnnotations {
+    pub notes: Option<String>,
+    pub tags: Vec<String>,
+}
+
+impl PositionAnnotations {
+    pub fn new(notes: Option<String>, tags: Vec<String>) -> AppResult<Self> {
+        let notes = notes
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty());
+        if let Some(n) = &notes {
+            if n.chars().count() > MAX_POSITION_NOTES_LEN {
+                return Err(AppError::Validation(format!(
+                    "Notes exceed {} characters",
+                    MAX_POSITION_NOTES_LEN
+                )));
+            }
+        }
+
+        let mut normalized: Vec<String> = Vec::new();
+        for tag in tags.iter().map(|t| Self::normalize_tag(t)) {
+            if tag.is_empty() || normalized.contains(&tag) {
+                continue;
+            }
+            if tag.chars().count() > MAX_POSITION_TAG_LEN {
+                return Err(AppError::Validation(format!(
+                    "Tag '{}' exceeds {} characters",
+                    tag, MAX_POSITION_TAG_LEN
+                )));
+            }
+            normalized.push(tag);
+        }
+        if normalized.len() > MAX_POSITION_TAGS {
+            return Err(AppError::Validation(format!(
+                "A position can carry at most {} tags",
+                MAX_POSITION_TAGS
+            )));
+        }
+
+        Ok(Self {
+            notes,
+            tags: normalized,
+        })
+    }
+
+    pub fn normalize_tag(tag: &str) -> String {
+        tag.trim().to_lowercase()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenPosition {
     pub id: Uuid,
@@ -784,6 +986,107 @@ pub struct OpenPosition {
     pub signal_source: Option<String>,
     #[serde(default = "default_auto_exit_enabled")]
     pub auto_exit_enabled: bool,
+    /// Set once auto-exit is toggled on the position itself; the per-position value
+    /// then takes precedence over any category setting.
+    #[serde(default)]
+    pub auto_exit_overridden: bool,
+    /// SOL/USD rate when the position was opened, for historical USD P&L.
+    #[serde(default)]
+    pub entry_sol_usd_rate: Option<f64>,
+    #[serde(default)]
+    pub exit_sol_usd_rate: Option<f64>,
+    #[serde(default)]
+    pub round_trip_costs: RoundTripCosts,
+    /// Free-form review notes, e.g. why the position was entered
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Optional DCA plan that adds to the position on dips, evaluated by the monitor
+    #[serde(default)]
+    pub scale_in: Option<ScaleInPlan>,
+}
+
+impl OpenPosition {
+    /// Unrealized P&L in USD, valuing the position at `current_sol_usd_rate` against
+    /// its cost at the entry rate. Stablecoin-based positions are already in USD.
+    pub fn unrealized_pnl_usd(&self, current_sol_usd_rate: Option<f64>) -> Option<f64> {
+        self.exit_config.base_currency.pnl_usd(
+            self.entry_amount_base,
+            self.unrealized_pnl,
+            self.entry_sol_usd_rate,
+            current_sol_usd_rate,
+        )
+    }
+
+    fn effective_amount_base(&self) -> f64 {
+        if self.remaining_amount_base > 0.0 {
+            self.remaining_amount_base
+        } else {
+            self.entry_amount_base
+        }
+    }
+
+    /// Price at which closing what's left of the position nets zero after venue fees,
+    /// network fees, tips and rent.
+    pub fn break_even_price(&self) -> f64 {
+        self.round_trip_costs
+            .break_even_price(self.entry_price, self.effective_amount_base())
+    }
+
+    /// Move from true break-even to `price`, in percent.
+    pub fn pnl_percent_from_break_even(&self, price: f64) -> f64 {
+        let break_even = self.break_even_price();
+        if break_even > 0.0 {
+            ((price - break_even) / break_even) * 100.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Price the break-even stop closes the remainder at. Without `exits_from_break_even`
+    /// only the venue fees on both legs count, not network fees or rent.
+    pub fn break_even_stop_price(&self) -> f64 {
+        let costs = if self.exit_config.exits_from_break_even {
+            self.round_trip_costs
+        } else {
+            RoundTripCosts {
+                network_fees_base: 0.0,
+                rent_base: 0.0,
+                ..self.round_trip_costs
+            }
+        };
+        costs.break_even_price(self.entry_price, self.effective_amount_base())
+    }
+
+    /// P&L percent at `price` that exit thresholds are compared against: from break-even
+    /// when the exit config opts in, otherwise from entry.
+    pub fn exit_pnl_percent(&self, price: f64) -> f64 {
+        if self.exit_config.exits_from_break_even {
+            self.pnl_percent_from_break_even(price)
+        } else if self.entry_price > 0.0 {
+            ((price - self.entry_price) / self.entry_price) * 100.0
+        } else {
+            0.0
+        }
+    }
+
+    /// What closing the remaining position at `price` would realize after every cost.
+    pub fn net_pnl_at(&self, price: f64) -> f64 {
+        self.round_trip_costs
+            .net_pnl_base(self.entry_price, self.effective_amount_base(), price)
+    }
+
+    /// Number of take-profit tiers (standard or momentum-adaptive) already sold.
+    pub fn take_profit_tiers_taken(&self) -> usize {
+        self.partial_exits
+            .iter()
+            .filter(|e| {
+                e.reason.starts_with("PartialTakeProfit")
+                    || e.reason.starts_with("MomentumAdaptive")
+            })
+            .count()
+    }
 }
 
 fn default_auto_exit_enabled() -> bool {
@@ -805,6 +1108,8 @@ pub struct PartialExit {
 pub enum PositionStatus {
     Open,
     PendingExit,
+    /// Exit sent, waiting for it to reach the configured commitment before closing.
+    Settling,
     PartiallyExited,
     Closed,
     Failed,
@@ -825,6 +1130,8 @@ pub enum ExitReason {
     MomentumAdaptivePartial, // Partial exit scaled by momentum strength
     MomentumReversal, // Immediate exit due to momentum reversal while profitable
     ExtendedTakeProfit, // Extended target hit due to strong momentum
+    MomentumFadePartial, // Partial exit when momentum fades from strong to weak in profit
+    BreakevenStop, // Remainder stopped at entry + fees after a take-profit tier sold
     Salvage,       // Dead token salvage sell with maximum slippage tolerance
     CopyTradeSell, // Exit triggered by KOL copy trading signal
 }
@@ -942,6 +1249,43 @@ impl Default for AdaptivePartialTakeProfit {
     }
 }
 
+/// Sells part of the position when momentum fades from Strong to Weak (or
+/// Reversing) while the trade is still above `min_profit_percent`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MomentumFadeTakeProfit {
+    /// % of the remaining position to sell (default: 50.0)
+    pub exit_percent: f64,
+    /// P&L the fade must happen above (default: 0.0 = breakeven)
+    pub min_profit_percent: f64,
+}
+
+impl Default for MomentumFadeTakeProfit {
+    fn default() -> Self {
+        Self {
+            exit_percent: 50.0,
+            min_profit_percent: 0.0,
+        }
+    }
+}
+
+impl MomentumFadeTakeProfit {
+    pub fn validate(&self) -> AppResult<()> {
+        if !self.exit_percent.is_finite() || self.exit_percent <= 0.0 || self.exit_percent > 100.0 {
+            return Err(AppError::Validation(format!(
+                "momentum_fade_partial.exit_percent must be in (0, 100], got {}",
+                self.exit_percent
+            )));
+        }
+        if !self.min_profit_percent.is_finite() || self.min_profit_percent < 0.0 {
+            return Err(AppError::Validation(format!(
+                "momentum_fade_partial.min_profit_percent must be >= 0, got {}",
+                self.min_profit_percent
+            )));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExitSignal {
     pub position_id: Uuid,
@@ -1008,6 +1352,217 @@ impl PriorityExitEntry {
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct DustThresholds {
+    pub balance: f64,
+    pub sol_value: f64,
+}
+
+impl Default for DustThresholds {
+    fn default() -> Self {
+        Self {
+            balance: 0.001,    // 0.001 tokens (decimal-adjusted)
+            sol_value: 0.0001, // 0.0001 SOL
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DustPolicy {
+    pub default: DustThresholds,
+    #[serde(default)]
+    pub venues: HashMap<VenueType, DustThresholds>,
+}
+
+impl DustPolicy {
+    pub fn from_env() -> Self {
+        let fallback = DustThresholds::default();
+        let default = DustThresholds {
+            balance: std::env::var("RECONCILE_DUST_BALANCE_THRESHOLD")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(fallback.balance),
+            sol_value: std::env::var("RECONCILE_DUST_SOL_VALUE_THRESHOLD")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(fallback.sol_value),
+        };
+
+        Self {
+            default,
+            venues: HashMap::new(),
+        }
+    }
+
+    pub fn thresholds_for(&self, venue: VenueType) -> DustThresholds {
+        self.venues.get(&venue).copied().unwrap_or(self.default)
+    }
+
+    /// Lowest balance threshold across all venues - safe to apply before the venue is known.
+    pub fn min_balance_threshold(&self) -> f64 {
+        self.venues
+            .values()
+            .map(|t| t.balance)
+            .fold(self.default.balance, f64::min)
+    }
+
+    pub fn is_dust(&self, venue: VenueType, balance: f64, estimated_sol_value: f64) -> bool {
+        let thresholds = self.thresholds_for(venue);
+        balance < thresholds.balance
+            || (estimated_sol_value > 0.0 && estimated_sol_value < thresholds.sol_value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenCategory {
+    BondingCurve,
+    Graduated,
+}
+
+impl TokenCategory {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "bonding_curve" | "curve" => Some(Self::BondingCurve),
+            "graduated" => Some(Self::Graduated),
+            _ => None,
+        }
+    }
+
+    /// Positions entered on a bonding curve venue (or with no recorded venue) count
+    /// as curve tokens; DEX venues and post-graduation snipes count as graduated.
+    pub fn of(position: &OpenPosition) -> Self {
+        let graduated_source = position.signal_source.as_deref() == Some("raydium_snipe");
+        let curve_venue = matches!(
+            position.venue.as_deref(),
+            None | Some("pump_fun") | Some("moonshot")
+        );
+        if graduated_source || !curve_venue {
+            Self::Graduated
+        } else {
+            Self::BondingCurve
+        }
+    }
+}
+
+/// Category-level auto-exit toggles. A position's own toggle wins once set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AutoExitPolicy {
+    #[serde(default)]
+    pub categories: HashMap<TokenCategory, bool>,
+}
+
+impl AutoExitPolicy {
+    /// Reads `AUTO_EXIT_DISABLED_CATEGORIES`, a comma-separated category list.
+    pub fn from_env() -> Self {
+        let categories = std::env::var("AUTO_EXIT_DISABLED_CATEGORIES")
+            .map(|v| {
+                v.split(',')
+                    .filter_map(|c| TokenCategory::parse(c.trim()))
+                    .map(|c| (c, false))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self { categories }
+    }
+
+    pub fn is_enabled(&self, position: &OpenPosition) -> bool {
+        if position.auto_exit_overridden {
+            return position.auto_exit_enabled;
+        }
+        self.categories
+            .get(&TokenCategory::of(position))
+            .copied()
+            .unwrap_or(position.auto_exit_enabled)
+    }
+}
+
+/// Named, reusable exit config (e.g. "aggressive-scalp", "swing") that can be applied
+/// to new or open positions instead of the hard-coded `ExitConfig::for_*` presets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExitConfigTemplate {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub config: ExitConfig,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ExitConfigTemplate {
+    pub fn new(name: impl Into<String>, description: Option<String>, config: ExitConfig) -> Self {
+        let now = Utc::now();
+        Self {
+            name: name.into(),
+            description,
+            config,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> AppResult<()> {
+        let valid_name = !self.name.is_empty()
+            && self.name.len() <= 64
+            && self
+                .name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+        if !valid_name {
+            return Err(AppError::Validation(format!(
+                "Invalid template name '{}': use 1-64 lowercase letters, digits, '-' or '_'",
+                self.name
+            )));
+        }
+
+        let percents = [
+            ("stop_loss_percent", self.config.stop_loss_percent),
+            ("take_profit_percent", self.config.take_profit_percent),
+            ("trailing_stop_percent", self.config.trailing_stop_percent),
+        ];
+        for (field, value) in percents {
+            if let Some(v) = value {
+                if !v.is_finite() || v <= 0.0 {
+                    return Err(AppError::Validation(format!(
+                        "{} must be a positive number, got {}",
+                        field, v
+                    )));
+                }
+            }
+        }
+
+        if let Some(bps) = self.config.exit_slippage_bps {
+            if bps == 0 || bps > MAX_EXIT_SLIPPAGE_BPS {
+                return Err(AppError::Validation(format!(
+                    "exit_slippage_bps must be between 1 and {}, got {}",
+                    MAX_EXIT_SLIPPAGE_BPS, bps
+                )));
+            }
+        }
+
+        if let Some(fade_tp) = &self.config.momentum_fade_partial {
+            fade_tp.validate()?;
+        }
+
+        if !self.config.has_valid_exit_strategy() {
+            return Err(AppError::Validation(format!(
+                "Template '{}' has no exit condition (stop loss, take profit, trailing stop or time limit)",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+
+    /// The template's config with the position's own base currency kept intact.
+    pub fn config_for(&self, base_currency: BaseCurrency) -> ExitConfig {
+        ExitConfig {
+            base_currency,
+            ..self.config.clone()
+        }
+    }
+}
+
 pub struct PositionManager {
     positions: Arc<RwLock<HashMap<Uuid, OpenPosition>>>,
     positions_by_edge: Arc<RwLock<HashMap<Uuid, Uuid>>>,
@@ -1017,6 +1572,17 @@ pub struct PositionManager {
     stats: Arc<RwLock<PositionManagerStats>>,
     position_repo: Option<Arc<PositionRepository>>,
     pending_exit_retry_index: Arc<RwLock<usize>>,
+    dust_policy: Arc<RwLock<DustPolicy>>,
+    auto_exit_policy: Arc<RwLock<AutoExitPolicy>>,
+    exit_templates: Arc<RwLock<HashMap<String, ExitConfigTemplate>>>,
+    event_tx: Option<broadcast::Sender<ArbEvent>>,
+    /// Last losing exit per mint, used for the re-entry cooldown
+    losing_exits: Arc<RwLock<HashMap<String, DateTime<Utc>>>>,
+    sol_price: Option<Arc<SolUsdPriceCache>>,
+    break_even_costs: BreakEvenCostModel,
+    /// Scale-in buys handed to the executor and not yet recorded or released
+    scale_ins_in_flight: Arc<RwLock<HashMap<Uuid, DateTime<Utc>>>>,
+    balance_cache: Option<Arc<BalanceCache>>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -1042,6 +1608,15 @@ impl PositionManager {
             stats: Arc::new(RwLock::new(PositionManagerStats::default())),
             position_repo: None,
             pending_exit_retry_index: Arc::new(RwLock::new(0)),
+            dust_policy: Arc::new(RwLock::new(DustPolicy::from_env())),
+            auto_exit_policy: Arc::new(RwLock::new(AutoExitPolicy::from_env())),
+            exit_templates: Arc::new(RwLock::new(HashMap::new())),
+            event_tx: None,
+            losing_exits: Arc::new(RwLock::new(HashMap::new())),
+            sol_price: None,
+            break_even_costs: BreakEvenCostModel::from_env(),
+            scale_ins_in_flight: Arc::new(RwLock::new(HashMap::new())),
+            balance_cache: None,
         }
     }
 
@@ -1055,6 +1630,51 @@ impl PositionManager {
             stats: Arc::new(RwLock::new(PositionManagerStats::default())),
             position_repo: Some(position_repo),
             pending_exit_retry_index: Arc::new(RwLock::new(0)),
+            dust_policy: Arc::new(RwLock::new(DustPolicy::from_env())),
+            auto_exit_policy: Arc::new(RwLock::new(AutoExitPolicy::from_env())),
+            exit_templates: Arc::new(RwLock::new(HashMap::new())),
+            event_tx: None,
+            losing_exits: Arc::new(RwLock::new(HashMap::new())),
+            sol_price: None,
+            break_even_costs: BreakEvenCostModel::from_env(),
+            scale_ins_in_flight: Arc::new(RwLock::new(HashMap::new())),
+            balance_cache: None,
+        }
+    }
+
+    pub fn with_event_tx(mut self, event_tx: broadcast::Sender<ArbEvent>) -> Self {
+        self.event_tx = Some(event_tx);
+        self
+    }
+
+    pub fn with_break_even_costs(mut self, break_even_costs: BreakEvenCostModel) -> Self {
+        self.break_even_costs = break_even_costs;
+        self
+    }
+
+    pub fn with_sol_price(mut self, sol_price: Arc<SolUsdPriceCache>) -> Self {
+        self.sol_price = Some(sol_price);
+        self
+    }
+
+    /// Cached wallet balances are dropped as soon as a fill is recorded, before any
+    /// event goes out, so no reader sees the pre-trade balance afterwards.
+    pub fn with_balance_cache(mut self, balance_cache: Arc<BalanceCache>) -> Self {
+        self.balance_cache = Some(balance_cache);
+        self
+    }
+
+    async fn invalidate_balances(&self) {
+        if let Some(cache) = &self.balance_cache {
+            cache.invalidate_all().await;
+        }
+    }
+
+    /// Current cached SOL/USD rate, if a price source is configured and fresh.
+    pub async fn sol_usd_rate(&self) -> Option<f64> {
+        match &self.sol_price {
+            Some(cache) => cache.cached_rate().await,
+            None => None,
         }
     }
 
@@ -1079,7 +1699,14 @@ impl PositionManager {
         let mut by_token = self.positions_by_token.write().await;
         let mut stats = self.stats.write().await;
 
-        for position in db_positions {
+        for mut position in db_positions {
+            // Rows written before costs were stored get the current model's estimate
+            if position.round_trip_costs == RoundTripCosts::default() {
+                position.round_trip_costs = self.break_even_costs.costs_for(
+                    position.venue.as_deref(),
+                    position.exit_config.base_currency,
+                );
+            }
             info!(
                 "  ↳ Restored: {} | {} | Entry: {:.6} SOL @ {:.12}",
                 &position.token_mint[..12],
@@ -1203,6 +1830,7 @@ impl PositionManager {
                             pos.status,
                             PositionStatus::Open
                                 | PositionStatus::PendingExit
+                                | PositionStatus::Settling
                                 | PositionStatus::PartiallyExited
                         ) {
                             info!(
@@ -1232,6 +1860,10 @@ impl PositionManager {
         let is_snipe = signal_source.as_deref() == Some("graduation_sniper");
         let snipe_emoji = if is_snipe { "🔫 " } else { "" };
 
+        let round_trip_costs = self
+            .break_even_costs
+            .costs_for(venue.as_deref(), exit_config.base_currency);
+
         let position = OpenPosition {
             id: position_id,
             edge_id,
@@ -1257,6 +1889,13 @@ impl PositionManager {
             venue,
             signal_source,
             auto_exit_enabled: true,
+            auto_exit_overridden: false,
+            entry_sol_usd_rate: self.sol_usd_rate().await,
+            exit_sol_usd_rate: None,
+            round_trip_costs,
+            notes: None,
+            tags: Vec::new(),
+            scale_in: None,
         };
 
         // Persist to database FIRST before updating in-memory state
@@ -1299,6 +1938,31 @@ impl PositionManager {
             stats.total_positions_opened += 1;
             stats.active_positions += 1;
         }
+        self.invalidate_balances().await;
+
+        // Every open path comes through here, so this is the one position-opened event
+        if let Some(tx) = &self.event_tx {
+            crate::events::broadcast_event(
+                tx,
+                ArbEvent::new(
+                    "position_opened",
+                    EventSource::Agent(AgentType::Executor),
+                    topics::position::OPENED,
+                    serde_json::json!({
+                        "position_id": position_id,
+                        "edge_id": edge_id,
+                        "strategy_id": strategy_id,
+                        "signal_source": position.signal_source,
+                        "mint": token_mint,
+                        "symbol": position.token_symbol,
+                        "venue": position.venue,
+                        "sol_amount": entry_amount_base,
+                        "entry_price": entry_price,
+                        "base_currency": position.exit_config.base_currency.symbol(),
+                    }),
+                ),
+            );
+        }
 
         info!(
             "{}📈 Position opened: {} | {} @ {} | Entry: {} {} | Exit config: SL {}% / TP {}%",
@@ -1388,15 +2052,21 @@ impl PositionManager {
         position_id: Uuid,
         current_price: f64,
     ) -> Option<ExitSignal> {
+        let auto_exit_policy = self.auto_exit_policy.read().await.clone();
         let mut positions = self.positions.write().await;
         let position = positions.get_mut(&position_id)?;
 
-        if position.status != PositionStatus::Open {
+        // The remainder of a partially exited position is still monitored
+        if !matches!(
+            position.status,
+            PositionStatus::Open | PositionStatus::PartiallyExited
+        ) {
             return None;
         }
 
-        // Skip automatic exit checks for positions with auto_exit disabled (manual mode)
-        if !position.auto_exit_enabled {
+        // Skip automatic exit checks for positions with auto_exit disabled (manual mode),
+        // either on the position itself or for its token category
+        if !auto_exit_policy.is_enabled(position) {
             debug!(
                 position_id = %position_id,
                 mint = %position.token_mint[..8.min(position.token_mint.len())],
@@ -1425,11 +2095,7 @@ impl PositionManager {
 
         // Calculate unrealized P&L based on REMAINING position size (not original entry)
         // This fixes the bug where P&L was calculated on full entry after partial exits
-        let effective_base = if position.remaining_amount_base > 0.0 {
-            position.remaining_amount_base
-        } else {
-            position.entry_amount_base
-        };
+        let effective_base = position.effective_amount_base();
 
         // Validate partial exit tracking consistency
         // Sum of partial exit percentages should roughly match (entry - remaining) / entry
@@ -1498,8 +2164,10 @@ impl PositionManager {
             });
         }
 
+        let exit_pnl = position.exit_pnl_percent(current_price);
+
         if let Some(stop_loss) = config.stop_loss_percent {
-            if position.unrealized_pnl_percent <= -stop_loss {
+            if exit_pnl <= -stop_loss {
                 position.status = PositionStatus::PendingExit;
                 return Some(ExitSignal {
                     position_id,
@@ -1512,12 +2180,35 @@ impl PositionManager {
             }
         }
 
+        let tiers_taken = position.take_profit_tiers_taken();
+
+        if config.move_stop_to_breakeven_after_tp
+            && tiers_taken > 0
+            && current_price <= position.break_even_stop_price()
+        {
+            tracing::info!(
+                position_id = %position_id,
+                pnl_pct = position.unrealized_pnl_percent,
+                break_even_price = position.break_even_stop_price(),
+                "🛡️ Break-even stop hit after take-profit - closing remainder"
+            );
+            position.status = PositionStatus::PendingExit;
+            return Some(ExitSignal {
+                position_id,
+                reason: ExitReason::BreakevenStop,
+                exit_percent: 100.0,
+                current_price,
+                triggered_at: now,
+                urgency: ExitUrgency::Critical,
+            });
+        }
+
         // ==================== MOMENTUM-ADAPTIVE EXIT LOGIC ====================
         // Check momentum reversal (Critical urgency, full exit to protect profits)
         if let Some(ref momentum_config) = config.momentum_adaptive {
             if position
                 .momentum
-                .should_exit_on_reversal(momentum_config, position.unrealized_pnl_percent)
+                .should_exit_on_reversal(momentum_config, exit_pnl)
             {
                 let strength = position.momentum.classify_strength(momentum_config);
                 tracing::info!(
@@ -1540,17 +2231,38 @@ impl PositionManager {
             }
         }
 
+        // Partial profit-take when momentum fades from strong to weak while in profit
+        if let Some(ref fade_tp) = config.momentum_fade_partial {
+            let momentum_config = config.momentum_adaptive.clone().unwrap_or_default();
+            if position.momentum.detect_fade(&momentum_config)
+                && exit_pnl > fade_tp.min_profit_percent
+            {
+                tracing::info!(
+                    position_id = %position_id,
+                    pnl_pct = position.unrealized_pnl_percent,
+                    velocity = position.momentum.velocity,
+                    momentum_score = position.momentum.momentum_score,
+                    exit_pct = fade_tp.exit_percent,
+                    "📉 Momentum faded strong→weak in profit - taking partial"
+                );
+                return Some(ExitSignal {
+                    position_id,
+                    reason: ExitReason::MomentumFadePartial,
+                    exit_percent: fade_tp.exit_percent,
+                    current_price,
+                    triggered_at: now,
+                    urgency: ExitUrgency::Medium,
+                });
+            }
+        }
+
         // Check momentum-adaptive partial take profits (takes priority over standard partial TP)
         if let (Some(ref momentum_config), Some(ref adaptive_tp)) =
             (&config.momentum_adaptive, &config.adaptive_partial_tp)
         {
             let strength = position.momentum.classify_strength(momentum_config);
-            let already_did_first = position.partial_exits.iter().any(|e| {
-                e.reason.contains("PartialTakeProfit1") || e.reason.contains("MomentumAdaptive1")
-            });
-            let already_did_second = position.partial_exits.iter().any(|e| {
-                e.reason.contains("PartialTakeProfit2") || e.reason.contains("MomentumAdaptive2")
-            });
+            let already_did_first = tiers_taken >= 1;
+            let already_did_second = tiers_taken >= 2;
             let already_did_third = position.partial_exits.iter().any(|e| {
                 e.reason.contains("PartialTakeProfit3") || e.reason.contains("ExtendedTP")
             });
@@ -1564,7 +2276,7 @@ impl PositionManager {
                 .calculate_adaptive_exit_percent(adaptive_tp.first_exit_percent, momentum_config);
 
             // First adaptive partial: momentum-adjusted target and size
-            if !already_did_first && position.unrealized_pnl_percent >= first_target {
+            if !already_did_first && exit_pnl >= first_target {
                 tracing::info!(
                     position_id = %position_id,
                     pnl_pct = position.unrealized_pnl_percent,
@@ -1593,10 +2305,7 @@ impl PositionManager {
                 .momentum
                 .calculate_adaptive_exit_percent(adaptive_tp.second_exit_percent, momentum_config);
 
-            if already_did_first
-                && !already_did_second
-                && position.unrealized_pnl_percent >= second_target
-            {
+            if already_did_first && !already_did_second && exit_pnl >= second_target {
                 tracing::info!(
                     position_id = %position_id,
                     pnl_pct = position.unrealized_pnl_percent,
@@ -1627,7 +2336,7 @@ impl PositionManager {
                     .momentum
                     .calculate_adaptive_target(adaptive_tp.third_target_percent, momentum_config);
 
-                if position.unrealized_pnl_percent >= third_target {
+                if exit_pnl >= third_target {
                     tracing::info!(
                         position_id = %position_id,
                         pnl_pct = position.unrealized_pnl_percent,
@@ -1651,19 +2360,11 @@ impl PositionManager {
 
         // Check partial take profit BEFORE full take profit (standard, non-adaptive)
         if let Some(ref partial_tp) = config.partial_take_profit {
-            let already_did_first_partial = position
-                .partial_exits
-                .iter()
-                .any(|e| e.reason == "PartialTakeProfit1");
-            let already_did_second_partial = position
-                .partial_exits
-                .iter()
-                .any(|e| e.reason == "PartialTakeProfit2");
+            let already_did_first_partial = tiers_taken >= 1;
+            let already_did_second_partial = tiers_taken >= 2;
 
             // First partial: sell first_exit_percent at first_target_percent
-            if !already_did_first_partial
-                && position.unrealized_pnl_percent >= partial_tp.first_target_percent
-            {
+            if !already_did_first_partial && exit_pnl >= partial_tp.first_target_percent {
                 tracing::info!(
                     position_id = %position_id,
                     pnl_pct = position.unrealized_pnl_percent,
@@ -1684,7 +2385,7 @@ impl PositionManager {
             // Second partial: sell remaining at second_target_percent (if not at full TP yet)
             if already_did_first_partial
                 && !already_did_second_partial
-                && position.unrealized_pnl_percent >= partial_tp.second_target_percent
+                && exit_pnl >= partial_tp.second_target_percent
             {
                 tracing::info!(
                     position_id = %position_id,
@@ -1704,7 +2405,7 @@ impl PositionManager {
         }
 
         if let Some(take_profit) = config.take_profit_percent {
-            if position.unrealized_pnl_percent >= take_profit {
+            if exit_pnl >= take_profit {
                 position.status = PositionStatus::PendingExit;
                 return Some(ExitSignal {
                     position_id,
@@ -1720,7 +2421,7 @@ impl PositionManager {
         if let Some(trailing_stop) = config.trailing_stop_percent {
             let drawdown_from_high =
                 ((position.high_water_mark - current_price) / position.high_water_mark) * 100.0;
-            if drawdown_from_high >= trailing_stop && position.unrealized_pnl_percent > 0.0 {
+            if drawdown_from_high >= trailing_stop && exit_pnl > 0.0 {
                 position.status = PositionStatus::PendingExit;
                 return Some(ExitSignal {
                     position_id,
@@ -1733,7 +2434,7 @@ impl PositionManager {
             }
         }
 
-        if position.unrealized_pnl_percent > 8.0 {
+        if exit_pnl > 8.0 {
             // Exit if velocity turns strongly negative while profitable (requires stronger reversal confirmation)
             // Require: velocity < -0.5 (actual decline), decay_count >= 3 (sustained), momentum_score < -10 (confirmed negative)
             if position.momentum.velocity < -0.5
@@ -1760,14 +2461,10 @@ impl PositionManager {
             }
 
             // Calculate peak PnL from high water mark
-            let peak_pnl_percent = if position.entry_price > 0.0 {
-                ((position.high_water_mark - position.entry_price) / position.entry_price) * 100.0
-            } else {
-                0.0
-            };
-            let pnl_drop_from_peak = peak_pnl_percent - position.unrealized_pnl_percent;
+            let peak_pnl_percent = position.exit_pnl_percent(position.high_water_mark);
+            let pnl_drop_from_peak = peak_pnl_percent - exit_pnl;
 
-            if pnl_drop_from_peak > 5.0 && position.unrealized_pnl_percent > 3.0 {
+            if pnl_drop_from_peak > 5.0 && exit_pnl > 3.0 {
                 tracing::info!(
                     position_id = %position_id,
                     current_pnl = position.unrealized_pnl_percent,
@@ -1787,7 +2484,7 @@ impl PositionManager {
             }
         }
 
-        let profitable_after_fees = position.unrealized_pnl_percent > 4.0;
+        let profitable_after_fees = exit_pnl > 4.0;
         let momentum_slowing = position.momentum.velocity < 0.5
             && (position.momentum.momentum_decay_count >= 3
                 || position.momentum.velocity.abs() < 0.3
@@ -1816,7 +2513,7 @@ impl PositionManager {
 
         // Check momentum decay - exit if momentum has stalled or reversed
         // Only check if we're not already profitable (don't exit winners early)
-        if position.unrealized_pnl_percent < 10.0 {
+        if exit_pnl < 10.0 {
             if position.momentum.should_exit_momentum_decay(hold_time_mins) {
                 tracing::info!(
                     position_id = %position_id,
@@ -1865,7 +2562,11 @@ impl PositionManager {
 
         if let Some(time_limit) = config.time_limit_minutes {
             let minutes_elapsed = (now - position.entry_time).num_minutes();
-            if minutes_elapsed >= time_limit as i64 {
+            // In profit once above the position's own venue fees, curve or DEX
+            let in_profit = current_price > position.break_even_stop_price();
+            if minutes_elapsed >= time_limit as i64
+                && !(config.time_limit_only_if_unprofitable && in_profit)
+            {
                 position.status = PositionStatus::PendingExit;
                 return Some(ExitSignal {
                     position_id,
@@ -1886,6 +2587,16 @@ impl PositionManager {
         positions.get(&position_id).cloned()
     }
 
+    /// Current base-currency value of each listed position that is still tracked, read
+    /// under a single lock.
+    pub async fn current_values_base(&self, position_ids: &[Uuid]) -> HashMap<Uuid, f64> {
+        let positions = self.positions.read().await;
+        position_ids
+            .iter()
+            .filter_map(|id| positions.get(id).map(|p| (*id, p.current_value_base)))
+            .collect()
+    }
+
     pub async fn get_position_by_edge(&self, edge_id: Uuid) -> Option<OpenPosition> {
         let by_edge = self.positions_by_edge.read().await;
         let position_id = by_edge.get(&edge_id)?;
@@ -1913,6 +2624,7 @@ impl PositionManager {
                 .map(|p| match p.status {
                     PositionStatus::Open => "open",
                     PositionStatus::PendingExit => "pending_exit",
+                    PositionStatus::Settling => "settling",
                     PositionStatus::PartiallyExited => "partially_exited",
                     PositionStatus::Closed => "closed",
                     PositionStatus::Failed => "failed",
@@ -1938,6 +2650,7 @@ impl PositionManager {
                 p.status,
                 PositionStatus::Open
                     | PositionStatus::PendingExit
+                    | PositionStatus::Settling
                     | PositionStatus::PartiallyExited
             ) && p.token_mint == mint
         })
@@ -1954,6 +2667,7 @@ impl PositionManager {
                 p.status,
                 PositionStatus::Open
                     | PositionStatus::PendingExit
+                    | PositionStatus::Settling
                     | PositionStatus::PartiallyExited
             ) && p.token_mint == mint
                 && p.strategy_id == *strategy_id
@@ -1969,6 +2683,7 @@ impl PositionManager {
                     p.status,
                     PositionStatus::Open
                         | PositionStatus::PendingExit
+                        | PositionStatus::Settling
                         | PositionStatus::PartiallyExited
                 ) && p.token_mint == mint
             })
@@ -2069,7 +2784,9 @@ impl PositionManager {
             .ok_or_else(|| AppError::NotFound(format!("Position {} not found", position_id)))?;
 
         let old_enabled = position.auto_exit_enabled;
+        let old_overridden = position.auto_exit_overridden;
         position.auto_exit_enabled = enabled;
+        position.auto_exit_overridden = true;
 
         // Persist to database
         if let Some(repo) = &self.position_repo {
@@ -2081,6 +2798,7 @@ impl PositionManager {
                 );
                 // Rollback in-memory change
                 position.auto_exit_enabled = old_enabled;
+                position.auto_exit_overridden = old_overridden;
                 return Err(AppError::Database(format!(
                     "Failed to update auto_exit: {}",
                     e
@@ -2128,6 +2846,8 @@ impl PositionManager {
             }
         }
 
+        let exit_sol_usd_rate = self.sol_usd_rate().await;
+
         // Persist to database FIRST before updating in-memory state
         // This ensures we don't have in-memory/DB inconsistency on failure
         if let Some(repo) = &self.position_repo {
@@ -2139,6 +2859,7 @@ impl PositionManager {
                     exit_reason,
                     tx_signature.as_deref(),
                     momentum_at_exit,
+                    exit_sol_usd_rate,
                 )
                 .await
             {
@@ -2177,6 +2898,7 @@ impl PositionManager {
             position.status = PositionStatus::Closed;
             position.current_price = exit_price;
             position.unrealized_pnl = 0.0;
+            position.exit_sol_usd_rate = exit_sol_usd_rate;
             position.clone()
         };
 
@@ -2188,7 +2910,7 @@ impl PositionManager {
             stats.total_realized_pnl += realized_pnl;
 
             match exit_reason {
-                "StopLoss" => stats.stop_losses_triggered += 1,
+                "StopLoss" | "BreakevenStop" => stats.stop_losses_triggered += 1,
                 "TakeProfit" => stats.take_profits_triggered += 1,
                 "TimeLimit" => stats.time_exits_triggered += 1,
                 _ => {}
@@ -2196,6 +2918,11 @@ impl PositionManager {
         }
 
         self.clear_exit_signal(position_id).await;
+        self.invalidate_balances().await;
+
+        if realized_pnl < 0.0 {
+            self.record_losing_exit(&closed_position.token_mint).await;
+        }
 
         info!(
             "📉 Position closed: {} | {} | Exit: {} | P&L: {:.4} {} | Reason: {}",
@@ -2213,6 +2940,22 @@ impl PositionManager {
         Ok(closed_position)
     }
 
+    async fn record_losing_exit(&self, mint: &str) {
+        let now = Utc::now();
+        let retention = chrono::Duration::seconds(MAX_REENTRY_COOLDOWN_SECS as i64);
+        let mut losing_exits = self.losing_exits.write().await;
+        losing_exits.retain(|_, exited_at| now - *exited_at < retention);
+        losing_exits.insert(mint.to_string(), now);
+    }
+
+    /// Seconds left before `mint` may be bought again after a losing exit, or `None`
+    /// if re-entry is allowed.
+    pub async fn reentry_cooldown_remaining(&self, mint: &str, cooldown_secs: u64) -> Option<i64> {
+        let exited_at = *self.losing_exits.read().await.get(mint)?;
+        let remaining = chrono::Duration::seconds(cooldown_secs as i64) - (Utc::now() - exited_at);
+        (remaining > chrono::Duration::zero()).then(|| remaining.num_seconds().max(1))
+    }
+
     pub async fn reset_position_status(&self, position_id: Uuid) -> AppResult<()> {
         let mut positions = self.positions.write().await;
         let position = positions
@@ -2220,10 +2963,15 @@ impl PositionManager {
             .ok_or_else(|| AppError::NotFound(format!("Position {} not found", position_id)))?;
 
         if position.status == PositionStatus::PendingExit {
-            position.status = PositionStatus::Open;
+            // A remainder goes back to PartiallyExited so its tier history still reads right
+            position.status = if position.partial_exits.is_empty() {
+                PositionStatus::Open
+            } else {
+                PositionStatus::PartiallyExited
+            };
             info!(
-                "🔄 Position {} reset from PendingExit to Open for retry",
-                position_id
+                "🔄 Position {} reset from PendingExit to {:?} for retry",
+                position_id, position.status
             );
         }
 
@@ -2273,6 +3021,26 @@ impl PositionManager {
         .unwrap_or_default()
     }
 
+    /// Returns a position whose exit never reached its target commitment (dropped by a
+    /// reorg, failed, or timed out) to Open and queues it for a priority retry.
+    /// Puts a position whose exit never settled back to the status it had before the
+    /// exit (PartiallyExited once a tier has sold, else Open) and queues a retry.
+    pub async fn requeue_dropped_exit(&self, position_id: Uuid) -> bool {
+        let restore_to = match self.get_position(position_id).await {
+            Some(position) if !position.partial_exits.is_empty() => PositionStatus::PartiallyExited,
+            _ => PositionStatus::Open,
+        };
+        let reset = self
+            .compare_and_swap_status(position_id, PositionStatus::Settling, restore_to)
+            .await
+            .unwrap_or_default();
+        if reset {
+            self.clear_exit_signal(position_id).await;
+            self.queue_priority_exit(position_id).await;
+        }
+        reset
+    }
+
     pub async fn queue_priority_exit(&self, position_id: Uuid) {
         let mut priority_exits = self.priority_exits.write().await;
         if !priority_exits.contains_key(&position_id) {
@@ -2410,7 +3178,12 @@ impl PositionManager {
         // Dynamically count all stats from actual position state to stay in sync
         current_stats.active_positions = positions
             .values()
-            .filter(|p| p.status == PositionStatus::Open || p.status == PositionStatus::PendingExit)
+            .filter(|p| {
+                matches!(
+                    p.status,
+                    PositionStatus::Open | PositionStatus::PendingExit | PositionStatus::Settling
+                )
+            })
             .count() as u32;
 
         current_stats.total_positions_opened = positions.len() as u64;
@@ -2478,7 +3251,11 @@ impl PositionManager {
             };
             updated.partial_exits.push(partial_exit);
 
-            if updated.status == PositionStatus::Open {
+            // The sell is done: release the exit claim so the remainder keeps being monitored
+            if matches!(
+                updated.status,
+                PositionStatus::Open | PositionStatus::PendingExit | PositionStatus::Settling
+            ) {
                 updated.status = PositionStatus::PartiallyExited;
             }
 
@@ -2504,6 +3281,8 @@ impl PositionManager {
                 *position = updated_position.clone();
             }
         }
+        self.clear_exit_signal(position_id).await;
+        self.invalidate_balances().await;
 
         info!(
             "📊 Partial exit recorded: {} | {}% @ {} | Remaining: {:.6} SOL / {:.0} tokens | Reason: {}",
@@ -2565,6 +3344,15 @@ impl Clone for PositionManager {
             stats: self.stats.clone(),
             position_repo: self.position_repo.clone(),
             pending_exit_retry_index: self.pending_exit_retry_index.clone(),
+            dust_policy: self.dust_policy.clone(),
+            auto_exit_policy: self.auto_exit_policy.clone(),
+            exit_templates: self.exit_templates.clone(),
+            event_tx: self.event_tx.clone(),
+            losing_exits: self.losing_exits.clone(),
+            sol_price: self.sol_price.clone(),
+            break_even_costs: self.break_even_costs,
+            scale_ins_in_flight: self.scale_ins_in_flight.clone(),
+            balance_cache: self.balance_cache.clone(),
         }
     }
 }
@@ -2586,6 +3374,410 @@ pub struct ReconciliationResult {
 }
 
 impl PositionManager {
+    pub async fn get_dust_policy(&self) -> DustPolicy {
+        self.dust_policy.read().await.clone()
+    }
+
+    pub async fn set_dust_policy(&self, policy: DustPolicy) {
+        *self.dust_policy.write().await = policy;
+    }
+
+    pub async fn get_auto_exit_policy(&self) -> AutoExitPolicy {
+        self.auto_exit_policy.read().await.clone()
+    }
+
+    /// Sets auto-exit for every position in `category`; `None` clears the category
+    /// setting so positions fall back to their own flag.
+    pub async fn set_category_auto_exit(&self, category: TokenCategory, enabled: Option<bool>) {
+        let mut policy = self.auto_exit_policy.write().await;
+        match enabled {
+            Some(enabled) => {
+                policy.categories.insert(category, enabled);
+            }
+            None => {
+                policy.categories.remove(&category);
+            }
+        }
+        tracing::info!(?category, ?enabled, "🔧 Category auto-exit updated");
+    }
+
+    pub async fn is_auto_exit_enabled(&self, position: &OpenPosition) -> bool {
+        self.auto_exit_policy.read().await.is_enabled(position)
+    }
+
+    /// Drops a position's own auto-exit toggle so its category setting applies again.
+    pub async fn clear_position_auto_exit_override(
+        &self,
+        position_id: Uuid,
+    ) -> AppResult<OpenPosition> {
+        let mut positions = self.positions.write().await;
+        let position = positions
+            .get_mut(&position_id)
+            .ok_or_else(|| AppError::NotFound(format!("Position {} not found", position_id)))?;
+
+        if let Some(repo) = &self.position_repo {
+            repo.clear_auto_exit_override(position_id).await?;
+        }
+        position.auto_exit_enabled = true;
+        position.auto_exit_overridden = false;
+
+        Ok(position.clone())
+    }
+
+    /// Updates a position's notes and/or tags; `None` keeps the current value and empty
+    /// notes clear them. Closed positions are annotated in the database only.
+    pub async fn annotate_position(
+        &self,
+        position_id: Uuid,
+        notes: Option<String>,
+        tags: Option<Vec<String>>,
+    ) -> AppResult<PositionAnnotations> {
+        let tracked = self
+            .get_position(position_id)
+            .await
+            .map(|position| (position.notes, position.tags));
+        let current = match tracked {
+            Some(current) => Some(current),
+            None => match &self.position_repo {
+                Some(repo) => repo
+                    .get_position(position_id)
+                    .await?
+                    .map(|p| (p.notes, p.tags)),
+                None => None,
+            },
+        };
+        let (current_notes, current_tags) = current
+            .ok_or_else(|| AppError::NotFound(format!("Position {} not found", position_id)))?;
+
+        let annotations =
+            PositionAnnotations::new(notes.or(current_notes), tags.unwrap_or(current_tags))?;
+
+        if let Some(repo) = &self.position_repo {
+            repo.update_annotations(position_id, annotations.notes.as_deref(), &annotations.tags)
+                .await?;
+        }
+        if let Some(position) = self.positions.write().await.get_mut(&position_id) {
+            position.notes = annotations.notes.clone();
+            position.tags = annotations.tags.clone();
+        }
+
+        Ok(annotations)
+    }
+
+    /// Attaches, replaces or (with `None`) removes a position's scale-in plan. Replacing
+    /// keeps the original reference entry and any tranches already bought.
+    pub async fn set_scale_in(
+        &self,
+        position_id: Uuid,
+        config: Option<ScaleInConfig>,
+    ) -> AppResult<Option<ScaleInPlan>> {
+        let position = self
+            .get_position(position_id)
+            .await
+            .ok_or_else(|| AppError::NotFound(format!("Position {} not found", position_id)))?;
+
+        let plan = match config {
+            Some(config) => {
+                config.validate(position.exit_config.stop_loss_percent)?;
+                Some(match position.scale_in {
+                    Some(existing) => ScaleInPlan { config, ..existing },
+                    None => {
+                        ScaleInPlan::new(config, position.entry_price, position.entry_amount_base)
+                    }
+                })
+            }
+            None => None,
+        };
+
+        if let Some(repo) = &self.position_repo {
+            repo.update_scale_in(position_id, plan.as_ref()).await?;
+        }
+
+        let mut positions = self.positions.write().await;
+        if let Some(position) = positions.get_mut(&position_id) {
+            position.scale_in = plan.clone();
+        }
+
+        Ok(plan)
+    }
+
+    /// Scale-in buys due for `token_mint` at `current_price`. Each returned signal stays in
+    /// flight, and its position is skipped, until recorded, released or
+    /// `SCALE_IN_RETRY_SECS` pass.
+    pub async fn check_scale_ins(
+        &self,
+        token_mint: &str,
+        current_price: f64,
+        limits_for: impl Fn(BaseCurrency) -> ScaleInLimits,
+    ) -> Vec<ScaleInSignal> {
+        let position_ids = self
+            .positions_by_token
+            .read()
+            .await
+            .get(token_mint)
+            .cloned()
+            .unwrap_or_default();
+        let positions = self.positions.read().await;
+        let mut in_flight = self.scale_ins_in_flight.write().await;
+        let now = Utc::now();
+
+        let mut signals: Vec<ScaleInSignal> = Vec::new();
+        // Capital still held across the book, plus adds already signalled this pass
+        let open_exposure = |base: BaseCurrency, signals: &[ScaleInSignal]| -> f64 {
+            let held: f64 = positions
+                .values()
+                .filter(|p| {
+                    p.exit_config.base_currency == base
+                        && !matches!(p.status, PositionStatus::Closed | PositionStatus::Failed)
+                })
+                .map(|p| p.remaining_amount_base)
+                .sum();
+            let pending: f64 = signals
+                .iter()
+                .filter(|s| {
+                    positions
+                        .get(&s.position_id)
+                        .is_some_and(|p| p.exit_config.base_currency == base)
+                })
+                .map(|s| s.amount_base)
+                .sum();
+            held + pending
+        };
+        for position_id in position_ids {
+            let Some(position) = positions.get(&position_id) else {
+                continue;
+            };
+            let Some(plan) = &position.scale_in else {
+                continue;
+            };
+            // Partially exited positions are being wound down, not added to
+            if position.status != PositionStatus::Open {
+                continue;
+            }
+            if in_flight
+                .get(&position_id)
+                .is_some_and(|since| (now - *since).num_seconds() < SCALE_IN_RETRY_SECS)
+            {
+                continue;
+            }
+
+            let base_currency = position.exit_config.base_currency;
+            let limits = limits_for(base_currency);
+            if let Some((tranche, amount_base)) = plan.next_tranche(
+                position.entry_amount_base,
+                open_exposure(base_currency, &signals),
+                current_price,
+                &limits,
+            ) {
+                in_flight.insert(position_id, now);
+                signals.push(ScaleInSignal {
+                    position_id,
+                    tranche,
+                    amount_base,
+                    current_price,
+                    triggered_at: now,
+                });
+            }
+        }
+
+        signals
+    }
+
+    /// Adds a bought tranche to the position, averaging the entry price over the larger
+    /// size and charging the extra buy's network fee to the round trip.
+    pub async fn record_scale_in(
+        &self,
+        position_id: Uuid,
+        tranche: usize,
+        amount_base: f64,
+        token_amount: f64,
+        price: f64,
+        tx_signature: Option<String>,
+    ) -> AppResult<OpenPosition> {
+        let updated = {
+            let positions = self.positions.read().await;
+            let position = positions
+                .get(&position_id)
+                .ok_or_else(|| AppError::NotFound(format!("Position {} not found", position_id)))?;
+            let mut updated = position.clone();
+            let plan = updated.scale_in.as_mut().ok_or_else(|| {
+                AppError::Validation(format!("Position {} has no scale-in plan", position_id))
+            })?;
+
+            plan.fills.push(ScaleInFill {
+                tranche,
+                amount_base,
+                token_amount,
+                price,
+                filled_at: Utc::now(),
+                tx_signature,
+            });
+
+            let total_tokens = updated.entry_token_amount + token_amount;
+            if total_tokens > 0.0 {
+                updated.entry_price = (updated.entry_price * updated.entry_token_amount
+                    + price * token_amount)
+                    / total_tokens;
+            }
+            updated.entry_amount_base += amount_base;
+            updated.entry_token_amount = total_tokens;
+            updated.remaining_amount_base += amount_base;
+            updated.remaining_token_amount += token_amount;
+
+            let leg_fees = self
+                .break_even_costs
+                .costs_for(updated.venue.as_deref(), updated.exit_config.base_currency)
+                .network_fees_base
+                / 2.0;
+            updated.round_trip_costs.network_fees_base += leg_fees;
+
+            updated
+        };
+
+        if let Some(repo) = &self.position_repo {
+            if let Err(e) = repo.record_scale_in(&updated).await {
+                error!(
+                    "❌ CRITICAL: Failed to persist scale-in for position {}: {} - on-chain buy succeeded but DB not updated!",
+                    position_id, e
+                );
+            }
+        }
+
+        {
+            let mut positions = self.positions.write().await;
+            if let Some(position) = positions.get_mut(&position_id) {
+                *position = updated.clone();
+            }
+        }
+        self.scale_ins_in_flight.write().await.remove(&position_id);
+        self.invalidate_balances().await;
+
+        info!(
+            "📉 Scale-in tranche {} recorded: {} | +{:.6} {} @ {} | size {:.6}, avg entry {}",
+            tranche,
+            position_id,
+            amount_base,
+            updated.exit_config.base_currency.symbol(),
+            price,
+            updated.entry_amount_base,
+            updated.entry_price
+        );
+
+        Ok(updated)
+    }
+
+    /// Lets a failed scale-in buy be retried on the next monitor pass.
+    pub async fn release_scale_in(&self, position_id: Uuid) {
+        self.scale_ins_in_flight.write().await.remove(&position_id);
+    }
+
+    /// Restores the re-entry cooldown from losing exits recorded before a restart.
+    pub async fn load_losing_exits_from_db(&self) -> AppResult<usize> {
+        let Some(repo) = &self.position_repo else {
+            return Ok(0);
+        };
+
+        let since = Utc::now() - chrono::Duration::seconds(MAX_REENTRY_COOLDOWN_SECS as i64);
+        let exits = repo.get_losing_exits_since(since).await?;
+        let count = exits.len();
+        let mut losing_exits = self.losing_exits.write().await;
+        for (mint, exited_at) in exits {
+            let latest = losing_exits.entry(mint).or_insert(exited_at);
+            *latest = (*latest).max(exited_at);
+        }
+        Ok(count)
+    }
+
+    pub async fn load_exit_templates_from_db(&self) -> AppResult<usize> {
+        let Some(repo) = &self.position_repo else {
+            return Ok(0);
+        };
+
+        let templates = repo.get_exit_templates().await?;
+        let count = templates.len();
+        let mut store = self.exit_templates.write().await;
+        for template in templates {
+            store.insert(template.name.clone(), template);
+        }
+        Ok(count)
+    }
+
+    pub async fn list_exit_templates(&self) -> Vec<ExitConfigTemplate> {
+        let mut templates: Vec<_> = self.exit_templates.read().await.values().cloned().collect();
+        templates.sort_by(|a, b| a.name.cmp(&b.name));
+        templates
+    }
+
+    pub async fn get_exit_template(&self, name: &str) -> Option<ExitConfigTemplate> {
+        self.exit_templates.read().await.get(name).cloned()
+    }
+
+    /// Creates or replaces a template, keeping the original `created_at` on replace.
+    pub async fn save_exit_template(
+        &self,
+        template: ExitConfigTemplate,
+    ) -> AppResult<ExitConfigTemplate> {
+        template.validate()?;
+
+        let template = match &self.position_repo {
+            Some(repo) => repo.upsert_exit_template(&template).await?,
+            None => template,
+        };
+
+        let saved = self
+            .exit_templates
+            .write()
+            .await
+            .entry(template.name.clone())
+            .and_modify(|existing| {
+                *existing = ExitConfigTemplate {
+                    created_at: existing.created_at.min(template.created_at),
+                    ..template.clone()
+                };
+            })
+            .or_insert_with(|| template.clone())
+            .clone();
+
+        info!("📐 Saved exit config template '{}'", saved.name);
+        Ok(saved)
+    }
+
+    pub async fn delete_exit_template(&self, name: &str) -> AppResult<bool> {
+        if let Some(repo) = &self.position_repo {
+            repo.delete_exit_template(name).await?;
+        }
+        Ok(self.exit_templates.write().await.remove(name).is_some())
+    }
+
+    pub async fn apply_exit_template(
+        &self,
+        position_id: Uuid,
+        template_name: &str,
+    ) -> AppResult<OpenPosition> {
+        let template = self.get_exit_template(template_name).await.ok_or_else(|| {
+            AppError::NotFound(format!(
+                "Exit config template '{}' not found",
+                template_name
+            ))
+        })?;
+        let base_currency = self
+            .get_position(position_id)
+            .await
+            .ok_or_else(|| AppError::NotFound(format!("Position {} not found", position_id)))?
+            .exit_config
+            .base_currency;
+
+        self.update_position_exit_config(position_id, template.config_for(base_currency))
+            .await
+    }
+
+    pub async fn is_dust(&self, venue: VenueType, balance: f64, estimated_sol_value: f64) -> bool {
+        self.dust_policy
+            .read()
+            .await
+            .is_dust(venue, balance, estimated_sol_value)
+    }
+
     pub async fn reconcile_wallet_tokens(
         &self,
         wallet_tokens: &[WalletTokenHolding],
@@ -2593,6 +3785,7 @@ impl PositionManager {
         let mut discovered_tokens = Vec::new();
         let mut orphaned_positions = Vec::new();
         let mut positions_needing_exit_fix = Vec::new();
+        let mut diff_events = Vec::new();
 
         // First pass: read-only analysis
         {
@@ -2639,6 +3832,25 @@ impl PositionManager {
                         &holding.mint[..8],
                         holding.balance
                     );
+
+                    let last_known_price = positions
+                        .values()
+                        .filter(|p| p.token_mint == holding.mint && p.current_price > 0.0)
+                        .max_by_key(|p| p.entry_time)
+                        .map(|p| p.current_price);
+
+                    diff_events.push((
+                        "position.reconcile.token_discovered",
+                        topics::position::RECONCILE_TOKEN_DISCOVERED,
+                        serde_json::json!({
+                            "token_mint": holding.mint,
+                            "token_symbol": holding.symbol,
+                            "balance": holding.balance,
+                            "decimals": holding.decimals,
+                            "estimated_value_sol": last_known_price.map(|p| p * holding.balance),
+                            "action": "track_as_discovered",
+                        }),
+                    ));
                     discovered_tokens.push(holding.clone());
                 }
             }
@@ -2648,6 +3860,7 @@ impl PositionManager {
                     position.status,
                     PositionStatus::Open
                         | PositionStatus::PendingExit
+                        | PositionStatus::Settling
                         | PositionStatus::PartiallyExited
                 ) {
                     let wallet_has_token = wallet_tokens
@@ -2678,6 +3891,20 @@ impl PositionManager {
                             position.status,
                             position_age_secs
                         );
+                        diff_events.push((
+                            "position.reconcile.orphaned",
+                            topics::position::RECONCILE_ORPHANED,
+                            serde_json::json!({
+                                "position_id": position.id,
+                                "token_mint": position.token_mint,
+                                "token_symbol": position.token_symbol,
+                                "status": position.status,
+                                "entry_amount_sol": position.entry_amount_base,
+                                "estimated_value_sol": position.current_value_base,
+                                "age_secs": position_age_secs,
+                                "action": "mark_orphaned",
+                            }),
+                        ));
                         orphaned_positions.push(position.id);
                     }
 
@@ -2705,6 +3932,19 @@ impl PositionManager {
                 if let Some(position) = positions.get_mut(&position_id) {
                     position.exit_config.ensure_minimum_exit_strategy();
                     exit_strategies_fixed.push(position_id);
+                    diff_events.push((
+                        "position.reconcile.exit_strategy_fixed",
+                        topics::position::RECONCILE_EXIT_STRATEGY_FIXED,
+                        serde_json::json!({
+                            "position_id": position_id,
+                            "token_mint": position.token_mint,
+                            "token_symbol": position.token_symbol,
+                            "estimated_value_sol": position.current_value_base,
+                            "stop_loss_percent": position.exit_config.stop_loss_percent,
+                            "take_profit_percent": position.exit_config.take_profit_percent,
+                            "action": "assign_default_exit_strategy",
+                        }),
+                    ));
                     info!(
                         "🛡️ Fixed exit strategy for position {} ({}) - assigned SL {}% / TP {}%",
                         position_id,
@@ -2751,6 +3991,20 @@ impl PositionManager {
             exit_strategies_fixed: exit_strategies_fixed.clone(),
         };
 
+        if let Some(tx) = &self.event_tx {
+            for (event_type, topic, payload) in diff_events {
+                crate::events::broadcast_event(
+                    tx,
+                    ArbEvent::new(
+                        event_type,
+                        EventSource::Agent(AgentType::Executor),
+                        topic,
+                        payload,
+                    ),
+                );
+            }
+        }
+
         if !discovered_tokens.is_empty()
             || !orphaned_positions.is_empty()
             || !exit_strategies_fixed.is_empty()
@@ -2830,6 +4084,7 @@ impl PositionManager {
                             pos.status,
                             PositionStatus::Open
                                 | PositionStatus::PendingExit
+                                | PositionStatus::Settling
                                 | PositionStatus::PartiallyExited
                         ) {
                             info!(
@@ -3014,64 +4269,394 @@ impl PositionManager {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_base_currency_mints() {
-        assert_eq!(
-            BaseCurrency::Sol.mint(),
-            "So11111111111111111111111111111111111111112"
-        );
-        assert_eq!(
-            BaseCurrency::Usdc.mint(),
-            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
-        );
-        assert!(BaseCurrency::is_base_currency(USDC_MINT));
-        assert!(!BaseCurrency::is_base_currency("random_mint"));
-    }
-
-    #[tokio::test]
-    async fn test_position_lifecycle() {
-        let manager = PositionManager::new();
-
-        let position = manager
+    /// Opens a 1 SOL position of 1000 tokens at 0.001 SOL.
+    async fn open_test_position(
+        manager: &PositionManager,
+        mint: &str,
+        exit_config: ExitConfig,
+        venue: Option<&str>,
+    ) -> OpenPosition {
+        manager
             .open_position(
                 Uuid::new_v4(),
                 Uuid::new_v4(),
-                "TokenMint123".to_string(),
-                Some("TEST".to_string()),
+                mint.to_string(),
+                None,
                 1.0,
                 1000.0,
                 0.001,
-                ExitConfig::default(),
-                None,
+                exit_config,
                 None,
+                venue.map(str::to_string),
                 None,
             )
             .await
-            .unwrap();
-
-        assert_eq!(position.status, PositionStatus::Open);
-
-        let stats = manager.get_stats().await;
-        assert_eq!(stats.active_positions, 1);
+            .unwrap()
     }
 
     #[tokio::test]
-    async fn test_stop_loss_trigger() {
+    async fn test_dust_policy_per_venue_thresholds() {
         let manager = PositionManager::new();
 
-        let _position = manager
-            .open_position(
-                Uuid::new_v4(),
-                Uuid::new_v4(),
-                "TokenMint123".to_string(),
-                Some("TEST".to_string()),
-                1.0,
-                1000.0,
-                0.001,
-                ExitConfig {
-                    stop_loss_percent: Some(10.0),
-                    ..Default::default()
-                },
+        let mut policy = DustPolicy {
+            default: DustThresholds {
+                balance: 0.001,
+                sol_value: 0.0001,
+            },
+            venues: HashMap::new(),
+        };
+        policy.venues.insert(
+            VenueType::BondingCurve,
+            DustThresholds {
+                balance: 1000.0,
+                sol_value: 0.0005,
+            },
+        );
+        policy.venues.insert(
+            VenueType::DexAmm,
+            DustThresholds {
+                balance: 0.01,
+                sol_value: 0.00005,
+            },
+        );
+        manager.set_dust_policy(policy).await;
+
+        // Same holding: dust on a cheap curve token, not dust once graduated
+        assert!(
+            manager
+                .is_dust(VenueType::BondingCurve, 500.0, 0.0001)
+                .await
+        );
+        assert!(!manager.is_dust(VenueType::DexAmm, 500.0, 0.0001).await);
+
+        // SOL-value threshold applies independently of balance
+        assert!(
+            manager
+                .is_dust(VenueType::BondingCurve, 5000.0, 0.0002)
+                .await
+        );
+        assert!(!manager.is_dust(VenueType::DexAmm, 5000.0, 0.0002).await);
+
+        // Unconfigured venues fall back to the default thresholds
+        assert!(manager.is_dust(VenueType::Lending, 0.0005, 1.0).await);
+        assert!(!manager.is_dust(VenueType::Lending, 1.0, 0.001).await);
+
+        assert_eq!(
+            manager.get_dust_policy().await.min_balance_threshold(),
+            0.001
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reentry_cooldown_after_losing_exit() {
+        let manager = PositionManager::new();
+        let open = |mint: &'static str| {
+            manager.open_position(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                mint.to_string(),
+                None,
+                0.1,
+                1000.0,
+                0.0001,
+                ExitConfig::default(),
+                None,
+                None,
+                None,
+            )
+        };
+
+        let loser = open("StoppedOutMint111111111").await.unwrap();
+        manager
+            .close_position(loser.id, 0.00008, -0.02, "StopLoss", None, None)
+            .await
+            .unwrap();
+        let winner = open("WinnerMint1111111111111").await.unwrap();
+        manager
+            .close_position(winner.id, 0.00012, 0.02, "TakeProfit", None, None)
+            .await
+            .unwrap();
+
+        let remaining = manager
+            .reentry_cooldown_remaining("StoppedOutMint111111111", 900)
+            .await;
+        assert!(remaining.is_some_and(|secs| secs > 0 && secs <= 900));
+        assert_eq!(
+            manager
+                .reentry_cooldown_remaining("WinnerMint1111111111111", 900)
+                .await,
+            None
+        );
+        assert_eq!(
+            manager
+                .reentry_cooldown_remaining("StoppedOutMint111111111", 0)
+                .await,
+            None
+        );
+
+        manager.losing_exits.write().await.insert(
+            "StoppedOutMint111111111".to_string(),
+            Utc::now() - chrono::Duration::seconds(901),
+        );
+        assert_eq!(
+            manager
+                .reentry_cooldown_remaining("StoppedOutMint111111111", 900)
+                .await,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reconciliation_emits_diff_events() {
+        let (tx, mut rx) = broadcast::channel(16);
+        let manager = PositionManager::new().with_event_tx(tx);
+
+        let position = manager
+            .open_position(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                "SoldExternallyMint1111".to_string(),
+                Some("GONE".to_string()),
+                0.5,
+                1000.0,
+                0.0005,
+                ExitConfig::default(),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        {
+            let mut positions = manager.positions.write().await;
+            let p = positions.get_mut(&position.id).unwrap();
+            p.entry_time = Utc::now() - chrono::Duration::minutes(10);
+        }
+
+        let wallet_tokens = vec![WalletTokenHolding {
+            mint: "ReceivedExternallyMint1".to_string(),
+            symbol: Some("NEW".to_string()),
+            balance: 250.0,
+            decimals: 6,
+        }];
+
+        let result = manager.reconcile_wallet_tokens(&wallet_tokens).await;
+        assert_eq!(result.discovered_tokens.len(), 1);
+        assert_eq!(result.orphaned_positions, vec![position.id]);
+
+        let mut events = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            events.push(event);
+        }
+
+        let discovered = events
+            .iter()
+            .find(|e| e.topic == topics::position::RECONCILE_TOKEN_DISCOVERED)
+            .expect("discovered event");
+        assert_eq!(discovered.payload["token_mint"], "ReceivedExternallyMint1");
+        assert_eq!(discovered.payload["balance"], 250.0);
+        assert_eq!(discovered.payload["action"], "track_as_discovered");
+
+        let orphaned = events
+            .iter()
+            .find(|e| e.topic == topics::position::RECONCILE_ORPHANED)
+            .expect("orphaned event");
+        assert_eq!(orphaned.payload["position_id"], position.id.to_string());
+        assert_eq!(orphaned.payload["token_mint"], "SoldExternallyMint1111");
+        assert_eq!(orphaned.payload["action"], "mark_orphaned");
+        assert!(orphaned.payload["estimated_value_sol"].is_number());
+    }
+
+    #[tokio::test]
+    async fn test_reconciliation_without_deltas_emits_nothing() {
+        let (tx, mut rx) = broadcast::channel(16);
+        let manager = PositionManager::new().with_event_tx(tx);
+
+        manager.reconcile_wallet_tokens(&[]).await;
+
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_dust_policy_ignores_unpriced_sol_value() {
+        let policy = DustPolicy::default();
+        assert!(!policy.is_dust(VenueType::BondingCurve, 10.0, 0.0));
+    }
+
+    #[test]
+    fn test_base_currency_mints() {
+        assert_eq!(
+            BaseCurrency::Sol.mint(),
+            "So11111111111111111111111111111111111111112"
+        );
+        assert_eq!(
+            BaseCurrency::Usdc.mint(),
+            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+        );
+        assert!(BaseCurrency::is_base_currency(USDC_MINT));
+        assert!(!BaseCurrency::is_base_currency("random_mint"));
+    }
+
+    #[tokio::test]
+    async fn test_usd_pnl_uses_stamped_entry_rate_and_current_rate() {
+        let sol_price = Arc::new(SolUsdPriceCache::new("http://localhost:0".to_string()).unwrap());
+        let manager = PositionManager::new().with_sol_price(sol_price.clone());
+        let stop_loss = ExitConfig {
+            stop_loss_percent: Some(10.0),
+            ..Default::default()
+        };
+
+        sol_price.set_rate(100.0).await;
+        let mut open = open_test_position(
+            &manager,
+            "UsdOpenMint11111111111",
+            stop_loss.clone(),
+            Some("pump_fun"),
+        )
+        .await;
+        let closing = open_test_position(
+            &manager,
+            "UsdClosedMint111111111",
+            stop_loss,
+            Some("pump_fun"),
+        )
+        .await;
+        assert_eq!(open.entry_sol_usd_rate, Some(100.0));
+
+        sol_price.set_rate(150.0).await;
+        let closed = manager
+            .close_position(closing.id, 0.0011, 0.1, "TakeProfit", None, None)
+            .await
+            .unwrap();
+        assert_eq!(closed.entry_sol_usd_rate, Some(100.0));
+        assert_eq!(closed.exit_sol_usd_rate, Some(150.0));
+        let realized_usd = pnl_usd(
+            closed.entry_amount_base,
+            0.1,
+            closed.entry_sol_usd_rate,
+            closed.exit_sol_usd_rate,
+        )
+        .unwrap();
+        assert!((realized_usd - 65.0).abs() < 1e-9);
+
+        // Open positions are valued at whatever the rate is now.
+        sol_price.set_rate(200.0).await;
+        open.unrealized_pnl = 0.1;
+        let unrealized_usd = open
+            .unrealized_pnl_usd(manager.sol_usd_rate().await)
+            .unwrap();
+        assert!((unrealized_usd - 120.0).abs() < 1e-9);
+
+        open.exit_config.base_currency = BaseCurrency::Usdc;
+        assert_eq!(open.unrealized_pnl_usd(Some(200.0)), Some(0.1));
+    }
+
+    #[tokio::test]
+    async fn test_category_auto_exit_toggle() {
+        let manager = PositionManager::new();
+        let stop_loss = ExitConfig {
+            stop_loss_percent: Some(10.0),
+            ..Default::default()
+        };
+        open_test_position(&manager, "CurveMint", stop_loss.clone(), Some("pump_fun")).await;
+        let graduated_a =
+            open_test_position(&manager, "GradMintA", stop_loss.clone(), Some("raydium")).await;
+        open_test_position(&manager, "GradMintB", stop_loss, Some("raydium")).await;
+
+        assert_eq!(TokenCategory::of(&graduated_a), TokenCategory::Graduated);
+
+        manager
+            .set_category_auto_exit(TokenCategory::Graduated, Some(false))
+            .await;
+
+        assert_eq!(manager.update_price("CurveMint", 0.00085).await.len(), 1);
+        assert!(manager.update_price("GradMintA", 0.00085).await.is_empty());
+        assert!(manager.update_price("GradMintB", 0.00085).await.is_empty());
+
+        manager
+            .set_category_auto_exit(TokenCategory::Graduated, None)
+            .await;
+        assert_eq!(manager.update_price("GradMintB", 0.00084).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_position_override_beats_category() {
+        let manager = PositionManager::new();
+        let stop_loss = ExitConfig {
+            stop_loss_percent: Some(10.0),
+            ..Default::default()
+        };
+        let graduated =
+            open_test_position(&manager, "GradMint", stop_loss.clone(), Some("raydium")).await;
+        let curve = open_test_position(&manager, "CurveMint", stop_loss, Some("pump_fun")).await;
+
+        manager
+            .set_category_auto_exit(TokenCategory::Graduated, Some(false))
+            .await;
+        manager
+            .update_position_auto_exit(graduated.id, true)
+            .await
+            .unwrap();
+        manager
+            .update_position_auto_exit(curve.id, false)
+            .await
+            .unwrap();
+
+        assert_eq!(manager.update_price("GradMint", 0.00085).await.len(), 1);
+        assert!(manager.update_price("CurveMint", 0.00085).await.is_empty());
+
+        let cleared = manager
+            .clear_position_auto_exit_override(curve.id)
+            .await
+            .unwrap();
+        assert!(manager.is_auto_exit_enabled(&cleared).await);
+    }
+
+    #[tokio::test]
+    async fn test_position_lifecycle() {
+        let manager = PositionManager::new();
+
+        let position = manager
+            .open_position(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                "TokenMint123".to_string(),
+                Some("TEST".to_string()),
+                1.0,
+                1000.0,
+                0.001,
+                ExitConfig::default(),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(position.status, PositionStatus::Open);
+
+        let stats = manager.get_stats().await;
+        assert_eq!(stats.active_positions, 1);
+    }
+
+    #[tokio::test]
+    async fn test_stop_loss_trigger() {
+        let manager = PositionManager::new();
+
+        let _position = manager
+            .open_position(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                "TokenMint123".to_string(),
+                Some("TEST".to_string()),
+                1.0,
+                1000.0,
+                0.001,
+                ExitConfig {
+                    stop_loss_percent: Some(10.0),
+                    ..Default::default()
+                },
                 None,
                 None,
                 None,
@@ -3115,6 +4700,563 @@ mod tests {
         assert_eq!(signals[0].reason, ExitReason::TakeProfit);
     }
 
+    /// Replaces the momentum window with prices one minute apart ending a minute ago,
+    /// so the next price update reads the trend they describe.
+    async fn seed_price_history(manager: &PositionManager, position_id: Uuid, prices: &[f64]) {
+        let mut positions = manager.positions.write().await;
+        let momentum = &mut positions.get_mut(&position_id).unwrap().momentum;
+        let now = Utc::now();
+        momentum.price_history = prices
+            .iter()
+            .enumerate()
+            .map(|(i, price)| PricePoint {
+                price: *price,
+                timestamp: now - chrono::Duration::minutes((prices.len() - i) as i64),
+            })
+            .collect();
+    }
+
+    #[tokio::test]
+    async fn test_momentum_fade_in_profit_takes_partial() {
+        let manager = PositionManager::new();
+        let fade = open_test_position(
+            &manager,
+            "FadeMint1111111111111",
+            ExitConfig {
+                momentum_fade_partial: Some(MomentumFadeTakeProfit {
+                    exit_percent: 40.0,
+                    min_profit_percent: 5.0,
+                }),
+                ..Default::default()
+            },
+            None,
+        )
+        .await;
+        let control = open_test_position(
+            &manager,
+            "ControlMint1111111111",
+            ExitConfig::default(),
+            None,
+        )
+        .await;
+
+        // Strong run: +40% over four minutes
+        for (position, mint) in [
+            (&fade, "FadeMint1111111111111"),
+            (&control, "ControlMint1111111111"),
+        ] {
+            seed_price_history(&manager, position.id, &[0.0010, 0.0011, 0.0012, 0.0013]).await;
+            assert!(manager.update_price(mint, 0.0014).await.is_empty());
+        }
+        let armed = manager.get_position(fade.id).await.unwrap();
+        assert_eq!(
+            armed
+                .momentum
+                .classify_strength(&MomentumAdaptiveConfig::default()),
+            MomentumStrength::Strong
+        );
+
+        // Price stalls at the top: momentum fades to weak while still +40%
+        for (position, mint) in [
+            (&fade, "FadeMint1111111111111"),
+            (&control, "ControlMint1111111111"),
+        ] {
+            seed_price_history(&manager, position.id, &[0.0014; 4]).await;
+            let signals = manager.update_price(mint, 0.0014).await;
+
+            if position.id == fade.id {
+                assert_eq!(signals.len(), 1);
+                assert_eq!(signals[0].reason, ExitReason::MomentumFadePartial);
+                assert_eq!(signals[0].exit_percent, 40.0);
+                assert_eq!(signals[0].urgency, ExitUrgency::Medium);
+            } else {
+                assert!(signals.is_empty());
+            }
+        }
+
+        // The fade is only acted on once until momentum turns strong again
+        seed_price_history(&manager, fade.id, &[0.0014; 4]).await;
+        assert!(manager
+            .update_price("FadeMint1111111111111", 0.0014)
+            .await
+            .is_empty());
+        let position = manager.get_position(fade.id).await.unwrap();
+        assert_eq!(position.status, PositionStatus::Open);
+    }
+
+    #[tokio::test]
+    async fn test_momentum_fade_not_triggered_while_momentum_strong() {
+        let manager = PositionManager::new();
+        let position = open_test_position(
+            &manager,
+            "StrongMint11111111111",
+            ExitConfig {
+                momentum_fade_partial: Some(MomentumFadeTakeProfit::default()),
+                ..Default::default()
+            },
+            None,
+        )
+        .await;
+
+        seed_price_history(&manager, position.id, &[0.0010, 0.0011, 0.0012, 0.0013]).await;
+        assert!(manager
+            .update_price("StrongMint11111111111", 0.0014)
+            .await
+            .is_empty());
+
+        seed_price_history(&manager, position.id, &[0.0014, 0.0015, 0.0016, 0.0017]).await;
+        assert!(manager
+            .update_price("StrongMint11111111111", 0.0018)
+            .await
+            .is_empty());
+
+        let position = manager.get_position(position.id).await.unwrap();
+        assert_eq!(position.status, PositionStatus::Open);
+        assert!(position.momentum.strong_since_last_fade);
+    }
+
+    async fn open_after_partial_take_profit(
+        manager: &PositionManager,
+        mint: &str,
+        move_stop_to_breakeven_after_tp: bool,
+    ) -> OpenPosition {
+        let position = open_test_position(
+            manager,
+            mint,
+            ExitConfig {
+                partial_take_profit: Some(PartialTakeProfit {
+                    first_target_percent: 20.0,
+                    first_exit_percent: 50.0,
+                    second_target_percent: 60.0,
+                    second_exit_percent: 25.0,
+                }),
+                move_stop_to_breakeven_after_tp,
+                ..Default::default()
+            },
+            None,
+        )
+        .await;
+
+        let signals = manager.update_price(mint, 0.0012).await;
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].reason, ExitReason::PartialTakeProfit);
+        assert_eq!(signals[0].exit_percent, 50.0);
+
+        manager
+            .record_partial_exit(
+                position.id,
+                50.0,
+                0.0012,
+                0.1,
+                None,
+                &format!("{:?}", signals[0].reason),
+            )
+            .await
+            .unwrap();
+        assert!(manager.get_pending_exit_signals().await.is_empty());
+
+        // Flat history at entry so the dip reads as a price level, not a crash
+        seed_price_history(manager, position.id, &[0.001; 4]).await;
+        position
+    }
+
+    #[tokio::test]
+    async fn test_breakeven_stop_after_partial_take_profit() {
+        let manager = PositionManager::new();
+        let position =
+            open_after_partial_take_profit(&manager, "BreakevenMint11111111", true).await;
+        assert_eq!(
+            manager.get_position(position.id).await.unwrap().status,
+            PositionStatus::PartiallyExited
+        );
+
+        let signals = manager.update_price("BreakevenMint11111111", 0.001).await;
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].reason, ExitReason::BreakevenStop);
+        assert_eq!(signals[0].exit_percent, 100.0);
+        assert_eq!(
+            manager.get_position(position.id).await.unwrap().status,
+            PositionStatus::PendingExit
+        );
+    }
+
+    #[tokio::test]
+    async fn test_original_stop_applies_without_breakeven_option() {
+        let manager = PositionManager::new();
+        let position =
+            open_after_partial_take_profit(&manager, "NoBreakevenMint111111", false).await;
+
+        assert!(manager
+            .update_price("NoBreakevenMint111111", 0.001)
+            .await
+            .is_empty());
+
+        let signals = manager.update_price("NoBreakevenMint111111", 0.00058).await;
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].reason, ExitReason::StopLoss);
+        assert_eq!(
+            manager.get_position(position.id).await.unwrap().status,
+            PositionStatus::PendingExit
+        );
+    }
+
+    #[tokio::test]
+    async fn test_partially_exited_remainder_takes_each_tier_once() {
+        let manager = PositionManager::new();
+        let position =
+            open_after_partial_take_profit(&manager, "SecondTierMint1111111", false).await;
+
+        // The first tier already sold; +60% is the second tier, not the first again
+        let signals = manager.update_price("SecondTierMint1111111", 0.0016).await;
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].reason, ExitReason::PartialTakeProfit);
+        assert_eq!(signals[0].exit_percent, 25.0);
+
+        manager
+            .record_partial_exit(
+                position.id,
+                25.0,
+                0.0016,
+                0.1,
+                None,
+                &format!("{:?}", signals[0].reason),
+            )
+            .await
+            .unwrap();
+        let remainder = manager.get_position(position.id).await.unwrap();
+        assert_eq!(remainder.take_profit_tiers_taken(), 2);
+        assert_eq!(remainder.status, PositionStatus::PartiallyExited);
+
+        assert!(manager
+            .update_price("SecondTierMint1111111", 0.0016)
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reset_after_partial_exit_restores_partially_exited() {
+        let manager = PositionManager::new();
+        let position =
+            open_after_partial_take_profit(&manager, "ResetRemainderMint111", true).await;
+
+        let signals = manager.update_price("ResetRemainderMint111", 0.001).await;
+        assert_eq!(signals[0].reason, ExitReason::BreakevenStop);
+
+        // The exit failed: the remainder goes back under monitoring with its tier history
+        manager.reset_position_status(position.id).await.unwrap();
+        assert_eq!(
+            manager.get_position(position.id).await.unwrap().status,
+            PositionStatus::PartiallyExited
+        );
+
+        let fresh = open_test_position(
+            &manager,
+            "ResetFreshMint1111111",
+            ExitConfig {
+                stop_loss_percent: Some(10.0),
+                ..Default::default()
+            },
+            Some("pump_fun"),
+        )
+        .await;
+        manager
+            .positions
+            .write()
+            .await
+            .get_mut(&fresh.id)
+            .unwrap()
+            .status = PositionStatus::PendingExit;
+        manager.reset_position_status(fresh.id).await.unwrap();
+        assert_eq!(
+            manager.get_position(fresh.id).await.unwrap().status,
+            PositionStatus::Open
+        );
+    }
+
+    #[tokio::test]
+    async fn test_annotate_position_notes_and_tags() {
+        let manager = PositionManager::new();
+        let position = open_test_position(
+            &manager,
+            "NotedMint111111111111",
+            ExitConfig {
+                stop_loss_percent: Some(10.0),
+                ..Default::default()
+            },
+            Some("pump_fun"),
+        )
+        .await;
+        assert!(position.notes.is_none());
+        assert!(position.tags.is_empty());
+
+        let annotations = manager
+            .annotate_position(
+                position.id,
+                Some("  Entered on KOL buy  ".to_string()),
+                Some(vec![
+                    "KOL-Copy".to_string(),
+                    " experiment ".to_string(),
+                    "kol-copy".to_string(),
+                    "".to_string(),
+                ]),
+            )
+            .await
+            .unwrap();
+        assert_eq!(annotations.notes.as_deref(), Some("Entered on KOL buy"));
+        assert_eq!(annotations.tags, vec!["kol-copy", "experiment"]);
+
+        // Omitted fields keep their value; empty notes clear them
+        let annotations = manager
+            .annotate_position(position.id, Some(String::new()), None)
+            .await
+            .unwrap();
+        assert!(annotations.notes.is_none());
+        assert_eq!(annotations.tags, vec!["kol-copy", "experiment"]);
+
+        let stored = manager.get_position(position.id).await.unwrap();
+        assert!(stored.notes.is_none());
+        assert_eq!(stored.tags, vec!["kol-copy", "experiment"]);
+
+        let too_many = (0..=MAX_POSITION_TAGS).map(|i| format!("t{}", i)).collect();
+        assert!(matches!(
+            manager
+                .annotate_position(position.id, None, Some(too_many))
+                .await,
+            Err(AppError::Validation(_))
+        ));
+        assert!(matches!(
+            manager
+                .annotate_position(Uuid::new_v4(), None, Some(vec!["x".to_string()]))
+                .await,
+            Err(AppError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_take_profit_and_stop_loss_measured_from_break_even() {
+        let manager = PositionManager::new().with_break_even_costs(BreakEvenCostModel::default());
+        let take_profit = ExitConfig {
+            stop_loss_percent: Some(10.0),
+            take_profit_percent: Some(10.0),
+            trailing_stop_percent: None,
+            exits_from_break_even: true,
+            ..Default::default()
+        };
+        let position = open_test_position(
+            &manager,
+            "CostlyTpMint111111111",
+            take_profit.clone(),
+            Some("pump_fun"),
+        )
+        .await;
+        let break_even = position.break_even_price();
+        assert!(break_even > position.entry_price * 1.02);
+        assert!(position.net_pnl_at(break_even).abs() < 1e-12);
+
+        // +10% over entry is still short of +10% over break-even
+        assert!(manager
+            .update_price("CostlyTpMint111111111", 0.0011)
+            .await
+            .is_empty());
+        let signals = manager
+            .update_price("CostlyTpMint111111111", break_even * 1.1 + 1e-9)
+            .await;
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].reason, ExitReason::TakeProfit);
+
+        // -8% from entry is already past -10% from break-even
+        let stopped = open_test_position(
+            &manager,
+            "CostlyStopMint1111111",
+            take_profit.clone(),
+            Some("pump_fun"),
+        )
+        .await;
+        let signals = manager.update_price("CostlyStopMint1111111", 0.00092).await;
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].reason, ExitReason::StopLoss);
+        assert_eq!(signals[0].position_id, stopped.id);
+
+        let free = PositionManager::new().with_break_even_costs(BreakEvenCostModel::free());
+        let position = open_test_position(
+            &free,
+            "FreeTpMint11111111111",
+            take_profit,
+            Some("pump_fun"),
+        )
+        .await;
+        assert_eq!(position.break_even_price(), position.entry_price);
+        let signals = free.update_price("FreeTpMint11111111111", 0.00111).await;
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].reason, ExitReason::TakeProfit);
+    }
+
+    #[tokio::test]
+    async fn test_exits_measured_from_entry_unless_opted_in() {
+        let manager = PositionManager::new().with_break_even_costs(BreakEvenCostModel::default());
+        let position = open_test_position(
+            &manager,
+            "EntryTpMint1111111111",
+            ExitConfig {
+                stop_loss_percent: Some(10.0),
+                take_profit_percent: Some(10.0),
+                trailing_stop_percent: None,
+                exits_from_break_even: false,
+                ..Default::default()
+            },
+            Some("pump_fun"),
+        )
+        .await;
+        assert!(position.break_even_price() > position.entry_price * 1.02);
+        assert!(manager
+            .update_price("EntryTpMint1111111111", 0.00092)
+            .await
+            .is_empty());
+        let signals = manager.update_price("EntryTpMint1111111111", 0.00111).await;
+        assert_eq!(signals[0].reason, ExitReason::TakeProfit);
+
+        // Opted in, tiers and the trailing stop's profit gate use break-even too
+        let tiered = manager
+            .open_position(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                "TieredBeMint111111111".to_string(),
+                None,
+                1.0,
+                1000.0,
+                0.001,
+                ExitConfig {
+                    stop_loss_percent: None,
+                    take_profit_percent: None,
+                    trailing_stop_percent: Some(1.0),
+                    partial_take_profit: Some(PartialTakeProfit {
+                        first_target_percent: 3.0,
+                        first_exit_percent: 50.0,
+                        second_target_percent: 50.0,
+                        second_exit_percent: 100.0,
+                    }),
+                    exits_from_break_even: true,
+                    ..Default::default()
+                },
+                None,
+                Some("pump_fun".to_string()),
+                None,
+            )
+            .await
+            .unwrap();
+        let break_even = tiered.break_even_price();
+        // +4% over entry is short of the 3% tier over break-even, and the pullback under
+        // break-even is not a profit for the trailing stop (1% off the high) to protect
+        assert!(manager
+            .update_price("TieredBeMint111111111", 0.00104)
+            .await
+            .is_empty());
+        assert!(manager
+            .update_price("TieredBeMint111111111", 0.00102)
+            .await
+            .is_empty());
+        let signals = manager
+            .update_price("TieredBeMint111111111", break_even * 1.031)
+            .await;
+        assert_eq!(signals[0].reason, ExitReason::PartialTakeProfit);
+    }
+
+    async fn open_held_past_time_limit(
+        manager: &PositionManager,
+        mint: &str,
+        venue: Option<&str>,
+        time_limit_only_if_unprofitable: bool,
+    ) -> OpenPosition {
+        let position = open_test_position(
+            manager,
+            mint,
+            ExitConfig {
+                time_limit_minutes: Some(5),
+                time_limit_only_if_unprofitable,
+                ..Default::default()
+            },
+            venue,
+        )
+        .await;
+
+        manager
+            .positions
+            .write()
+            .await
+            .get_mut(&position.id)
+            .unwrap()
+            .entry_time = Utc::now() - chrono::Duration::minutes(6);
+        position
+    }
+
+    #[tokio::test]
+    async fn test_time_limit_exits_position_held_past_timeout() {
+        let manager = PositionManager::new();
+        let position =
+            open_held_past_time_limit(&manager, "TimeoutMint1111111111", None, false).await;
+
+        // Up 3%: in profit after fees, but the guard is off
+        let signals = manager.update_price("TimeoutMint1111111111", 0.00103).await;
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].reason, ExitReason::TimeLimit);
+        assert_eq!(signals[0].exit_percent, 100.0);
+        assert_eq!(
+            manager.get_position(position.id).await.unwrap().status,
+            PositionStatus::PendingExit
+        );
+    }
+
+    #[tokio::test]
+    async fn test_time_limit_in_profit_guard() {
+        let manager = PositionManager::new();
+        let position =
+            open_held_past_time_limit(&manager, "TimeoutGuardMint11111", None, true).await;
+
+        assert!(manager
+            .update_price("TimeoutGuardMint11111", 0.00103)
+            .await
+            .is_empty());
+        assert_eq!(
+            manager.get_position(position.id).await.unwrap().status,
+            PositionStatus::Open
+        );
+
+        // Back under entry + DEX fees on both legs, the timeout applies again
+        let signals = manager
+            .update_price("TimeoutGuardMint11111", 0.001004)
+            .await;
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].reason, ExitReason::TimeLimit);
+    }
+
+    #[tokio::test]
+    async fn test_time_limit_in_profit_guard_uses_position_venue_fees() {
+        let manager = PositionManager::new().with_break_even_costs(BreakEvenCostModel::default());
+        let dex =
+            open_held_past_time_limit(&manager, "TimeoutDexMint1111111", Some("raydium"), true)
+                .await;
+        let curve =
+            open_held_past_time_limit(&manager, "TimeoutCurveMint11111", Some("pump_fun"), true)
+                .await;
+
+        // +1.5% clears the 0.25% DEX fees but not the 1% curve fees on each leg
+        assert!(manager
+            .update_price("TimeoutDexMint1111111", 0.0010150)
+            .await
+            .is_empty());
+        assert_eq!(
+            manager.get_position(dex.id).await.unwrap().status,
+            PositionStatus::Open
+        );
+
+        let signals = manager
+            .update_price("TimeoutCurveMint11111", 0.0010150)
+            .await;
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].position_id, curve.id);
+        assert_eq!(signals[0].reason, ExitReason::TimeLimit);
+    }
+
     #[test]
     fn test_exit_config_has_valid_exit_strategy_atomic() {
         let config = ExitConfig {
@@ -3237,4 +5379,330 @@ mod tests {
         assert_eq!(config.take_profit_percent, None);
         assert_eq!(config.time_limit_minutes, None);
     }
+
+    #[tokio::test]
+    async fn test_exit_template_applied_to_position() {
+        let manager = PositionManager::new();
+        let scalp = ExitConfig {
+            stop_loss_percent: Some(5.0),
+            take_profit_percent: Some(8.0),
+            trailing_stop_percent: Some(3.0),
+            time_limit_minutes: Some(2),
+            ..ExitConfig::for_defensive()
+        };
+        manager
+            .save_exit_template(ExitConfigTemplate::new(
+                "aggressive-scalp",
+                Some("Tight stops, quick flip".to_string()),
+                scalp.clone(),
+            ))
+            .await
+            .unwrap();
+
+        let position = manager
+            .open_position(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                "TemplateMint11111111111".to_string(),
+                Some("TMPL".to_string()),
+                0.1,
+                1000.0,
+                0.0001,
+                ExitConfig {
+                    base_currency: BaseCurrency::Usdc,
+                    ..ExitConfig::for_curve_bonding()
+                },
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let updated = manager
+            .apply_exit_template(position.id, "aggressive-scalp")
+            .await
+            .unwrap();
+
+        let expected = ExitConfig {
+            base_currency: BaseCurrency::Usdc,
+            ..scalp
+        };
+        assert_eq!(
+            serde_json::to_value(&updated.exit_config).unwrap(),
+            serde_json::to_value(&expected).unwrap()
+        );
+        assert_eq!(
+            serde_json::to_value(&manager.get_position(position.id).await.unwrap().exit_config)
+                .unwrap(),
+            serde_json::to_value(&expected).unwrap()
+        );
+
+        assert!(matches!(
+            manager.apply_exit_template(position.id, "swing").await,
+            Err(AppError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_exit_template_validation_and_replace() {
+        let manager = PositionManager::new();
+
+        let bad_name = ExitConfigTemplate::new("Swing Trade", None, ExitConfig::default());
+        assert!(manager.save_exit_template(bad_name).await.is_err());
+
+        let no_exit = ExitConfigTemplate::new(
+            "swing",
+            None,
+            ExitConfig {
+                exit_mode: ExitMode::Default,
+                stop_loss_percent: None,
+                take_profit_percent: None,
+                trailing_stop_percent: None,
+                time_limit_minutes: None,
+                ..Default::default()
+            },
+        );
+        assert!(manager.save_exit_template(no_exit).await.is_err());
+
+        let first = manager
+            .save_exit_template(ExitConfigTemplate::new(
+                "swing",
+                None,
+                ExitConfig::default(),
+            ))
+            .await
+            .unwrap();
+        let replaced = manager
+            .save_exit_template(ExitConfigTemplate::new(
+                "swing",
+                None,
+                ExitConfig {
+                    time_limit_minutes: Some(240),
+                    ..ExitConfig::default()
+                },
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(replaced.created_at, first.created_at);
+        assert_eq!(manager.list_exit_templates().await.len(), 1);
+        assert_eq!(
+            manager
+                .get_exit_template("swing")
+                .await
+                .unwrap()
+                .config
+                .time_limit_minutes,
+            Some(240)
+        );
+        assert!(manager.delete_exit_template("swing").await.unwrap());
+        assert!(manager.get_exit_template("swing").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_scale_in_buys_tranches_until_caps() {
+        use crate::execution::risk::RiskConfig;
+        use crate::execution::scale_in::ScaleInTranche;
+
+        let manager = PositionManager::new();
+        let config = ScaleInConfig {
+            tranches: vec![
+                ScaleInTranche {
+                    dip_percent: 20.0,
+                    add_percent: 50.0,
+                },
+                ScaleInTranche {
+                    dip_percent: 30.0,
+                    add_percent: 100.0,
+                },
+                ScaleInTranche {
+                    dip_percent: 40.0,
+                    add_percent: 100.0,
+                },
+            ],
+            max_total_base: 2.0,
+        };
+        let exit_config = ExitConfig {
+            stop_loss_percent: Some(50.0),
+            ..ExitConfig::default()
+        };
+        let unlimited = |_: BaseCurrency| ScaleInLimits::default();
+
+        let position =
+            open_test_position(&manager, "ScaleInMint1111111111", exit_config.clone(), None).await;
+        let below_stop = ScaleInConfig {
+            tranches: vec![ScaleInTranche {
+                dip_percent: 60.0,
+                add_percent: 50.0,
+            }],
+            ..config.clone()
+        };
+        assert!(manager
+            .set_scale_in(position.id, Some(below_stop))
+            .await
+            .is_err());
+        manager
+            .set_scale_in(position.id, Some(config.clone()))
+            .await
+            .unwrap();
+
+        let mint = position.token_mint.as_str();
+        assert!(manager
+            .check_scale_ins(mint, 0.00085, unlimited)
+            .await
+            .is_empty());
+
+        let signals = manager.check_scale_ins(mint, 0.0008, unlimited).await;
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].tranche, 0);
+        assert!((signals[0].amount_base - 0.5).abs() < 1e-9);
+        // In flight until the executor records or releases it
+        assert!(manager
+            .check_scale_ins(mint, 0.0008, unlimited)
+            .await
+            .is_empty());
+
+        let updated = manager
+            .record_scale_in(position.id, 0, 0.5, 625.0, 0.0008, None)
+            .await
+            .unwrap();
+        assert!((updated.entry_amount_base - 1.5).abs() < 1e-9);
+        assert!((updated.remaining_token_amount - 1625.0).abs() < 1e-9);
+        assert!((updated.entry_price - 1.5 / 1625.0).abs() < 1e-12);
+        assert!(manager
+            .check_scale_ins(mint, 0.0008, unlimited)
+            .await
+            .is_empty());
+
+        // Tranche 1 wants 1.0 but only 0.5 fits under max_total_base
+        let signals = manager.check_scale_ins(mint, 0.0007, unlimited).await;
+        assert_eq!(signals[0].tranche, 1);
+        assert!((signals[0].amount_base - 0.5).abs() < 1e-9);
+        manager
+            .record_scale_in(position.id, 1, 0.5, 714.0, 0.0007, None)
+            .await
+            .unwrap();
+        assert!(manager
+            .check_scale_ins(mint, 0.0005, unlimited)
+            .await
+            .is_empty());
+
+        // A tighter risk limit stops adds before the plan's own cap
+        let capped = open_test_position(&manager, "ScaleInMint2222222222", exit_config, None).await;
+        manager.set_scale_in(capped.id, Some(config)).await.unwrap();
+        // The book already holds 3.0 SOL, over the 2.4 SOL total exposure cap
+        let full_book = RiskConfig {
+            max_position_sol: 1.2,
+            ..RiskConfig::default()
+        };
+        assert!(manager
+            .check_scale_ins(&capped.token_mint, 0.0008, |base| {
+                ScaleInLimits::from_risk(&full_book, base)
+            })
+            .await
+            .is_empty());
+        let risk = RiskConfig {
+            max_position_sol: 1.2,
+            max_concurrent_positions: 10,
+            ..RiskConfig::default()
+        };
+        let risk_limits = |base: BaseCurrency| ScaleInLimits::from_risk(&risk, base);
+        let signals = manager
+            .check_scale_ins(&capped.token_mint, 0.0008, risk_limits)
+            .await;
+        assert!((signals[0].amount_base - 0.2).abs() < 1e-9);
+        manager
+            .record_scale_in(capped.id, 0, 0.2, 250.0, 0.0008, None)
+            .await
+            .unwrap();
+        assert!(manager
+            .check_scale_ins(&capped.token_mint, 0.0006, risk_limits)
+            .await
+            .is_empty());
+        assert_eq!(
+            manager
+                .get_position(capped.id)
+                .await
+                .unwrap()
+                .scale_in
+                .unwrap()
+                .fills
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_dead_token_exit_uses_elevated_slippage() {
+        let dead = ExitConfig::for_dead_token();
+        assert_eq!(dead.exit_slippage_bps, Some(DEAD_TOKEN_EXIT_SLIPPAGE_BPS));
+        assert_eq!(
+            dead.resolve_exit_slippage_bps(false, STANDARD_EXIT_SLIPPAGE_BPS),
+            DEAD_TOKEN_EXIT_SLIPPAGE_BPS
+        );
+
+        // Positions persisted before the override existed still get the salvage tolerance
+        let legacy = ExitConfig {
+            exit_slippage_bps: None,
+            ..ExitConfig::for_dead_token()
+        };
+        assert_eq!(
+            legacy.resolve_exit_slippage_bps(false, STANDARD_EXIT_SLIPPAGE_BPS),
+            DEAD_TOKEN_EXIT_SLIPPAGE_BPS
+        );
+    }
+
+    #[test]
+    fn test_normal_exit_uses_standard_slippage() {
+        let normal = ExitConfig::for_curve_bonding();
+        assert_eq!(
+            normal.resolve_exit_slippage_bps(false, STANDARD_EXIT_SLIPPAGE_BPS),
+            STANDARD_EXIT_SLIPPAGE_BPS
+        );
+        assert_eq!(
+            normal.resolve_exit_slippage_bps(true, STANDARD_EXIT_SLIPPAGE_BPS),
+            DEAD_TOKEN_EXIT_SLIPPAGE_BPS
+        );
+
+        let overridden = ExitConfig {
+            exit_slippage_bps: Some(1200),
+            ..ExitConfig::for_curve_bonding()
+        };
+        assert_eq!(
+            overridden.resolve_exit_slippage_bps(false, STANDARD_EXIT_SLIPPAGE_BPS),
+            1200
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fills_invalidate_balance_cache_before_returning() {
+        let cache = Arc::new(BalanceCache::new(std::time::Duration::from_secs(60)));
+        let manager = PositionManager::new().with_balance_cache(cache.clone());
+        let wallet = "CacheWallet11111111111111111111111111111111";
+
+        cache.store(wallet, 5_000_000_000).await;
+        let position = open_test_position(
+            &manager,
+            "CacheMint111111111111",
+            ExitConfig::default(),
+            Some("pump_fun"),
+        )
+        .await;
+        assert!(cache.get(wallet).await.is_none());
+
+        cache.store(wallet, 4_500_000_000).await;
+        manager
+            .record_partial_exit(position.id, 50.0, 0.0006, 0.05, None, "PartialTakeProfit")
+            .await
+            .unwrap();
+        assert!(cache.get(wallet).await.is_none());
+
+        cache.store(wallet, 4_800_000_000).await;
+        manager
+            .close_position(position.id, 0.0006, 0.05, "TakeProfit", None, None)
+            .await
+            .unwrap();
+        assert!(cache.get(wallet).await.is_none());
+    }
 }
@@ -2,7 +2,7 @@ use chrono::Utc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::{broadcast, mpsc};
+use tokio::sync::{broadcast, mpsc, RwLock};
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
@@ -10,8 +10,10 @@ use crate::error::AppResult;
 use crate::events::{topics, AgentType, ArbEvent, EventSource};
 
 use super::curve_builder::CurveTransactionBuilder;
-use super::position_command::{CommandSource, ExitCommand, PositionCommand};
+use super::position_command::{CommandSource, ExitCommand, PositionCommand, ScaleInCommand};
 use super::position_manager::{BaseCurrency, ExitReason, ExitSignal, ExitUrgency, PositionManager};
+use super::risk::RiskConfig;
+use super::scale_in::ScaleInLimits;
 use super::transaction_builder::TransactionBuilder;
 
 const PRICE_FETCH_TIMEOUT_SECS: u64 = 10;
@@ -26,6 +28,7 @@ pub struct PositionMonitor {
     curve_builder: Option<Arc<CurveTransactionBuilder>>,
     command_tx: mpsc::Sender<PositionCommand>,
     shutdown_flag: Arc<AtomicBool>,
+    risk_config: Option<Arc<RwLock<RiskConfig>>>,
 }
 
 #[derive(Debug, Clone)]
@@ -57,6 +60,7 @@ impl PositionMonitor {
             curve_builder: None,
             command_tx,
             shutdown_flag: Arc::new(AtomicBool::new(false)),
+            risk_config: None,
         }
     }
 
@@ -73,6 +77,12 @@ impl PositionMonitor {
         self
     }
 
+    /// Caps scale-in buys at the risk config's position limits.
+    pub fn with_risk_config(mut self, risk_config: Arc<RwLock<RiskConfig>>) -> Self {
+        self.risk_config = Some(risk_config);
+        self
+    }
+
     pub async fn start_monitoring(&self) {
         info!(
             "Position monitor started (base interval {}s, adaptive)",
@@ -513,14 +523,12 @@ impl PositionMonitor {
             }
         }
 
+        // Fallback prices below are only good enough for exits, never for buying more
+        let fresh_mints: Vec<String> = prices.keys().cloned().collect();
+
         for position in &positions {
             if !prices.contains_key(&position.token_mint) {
-                let is_dead_token = position
-                    .exit_config
-                    .custom_exit_instructions
-                    .as_ref()
-                    .map(|s| s.contains("DEAD TOKEN"))
-                    .unwrap_or(false);
+                let is_dead_token = position.exit_config.is_dead_token();
 
                 let position_age_secs = (Utc::now() - position.entry_time).num_seconds() as u64;
                 let price_is_stale = position_age_secs > MAX_STALE_PRICE_SECS;
@@ -566,6 +574,11 @@ impl PositionMonitor {
             all_signals.extend(signals);
         }
 
+        let exiting: std::collections::HashSet<Uuid> =
+            all_signals.iter().map(|s| s.position_id).collect();
+        self.process_scale_ins(&fresh_mints, &prices, &exiting)
+            .await;
+
         if all_signals.is_empty() {
             return Ok(());
         }
@@ -596,6 +609,55 @@ impl PositionMonitor {
         Ok(())
     }
 
+    async fn process_scale_ins(
+        &self,
+        mints: &[String],
+        prices: &std::collections::HashMap<String, f64>,
+        exiting: &std::collections::HashSet<Uuid>,
+    ) {
+        let risk = match &self.risk_config {
+            Some(risk_config) => Some(risk_config.read().await.clone()),
+            None => None,
+        };
+        let limits_for = |base_currency| {
+            risk.as_ref()
+                .map(|r| ScaleInLimits::from_risk(r, base_currency))
+                .unwrap_or_default()
+        };
+
+        for mint in mints {
+            let Some(price) = prices.get(mint) else {
+                continue;
+            };
+            for signal in self
+                .position_manager
+                .check_scale_ins(mint, *price, limits_for)
+                .await
+            {
+                if exiting.contains(&signal.position_id) {
+                    self.position_manager
+                        .release_scale_in(signal.position_id)
+                        .await;
+                    continue;
+                }
+
+                info!(
+                    "📉 Scale-in tranche {} triggered for {} at {} ({:.6} base)",
+                    signal.tranche, signal.position_id, signal.current_price, signal.amount_base
+                );
+                let position_id = signal.position_id;
+                let cmd = PositionCommand::ScaleIn(ScaleInCommand::new(signal));
+                if let Err(e) = self.command_tx.send(cmd).await {
+                    error!(
+                        "Failed to queue scale-in command for position {}: {}",
+                        position_id, e,
+                    );
+                    self.position_manager.release_scale_in(position_id).await;
+                }
+            }
+        }
+    }
+
     async fn emit_exit_signal_event(&self, signal: &ExitSignal) {
         let event = ArbEvent::new(
             "position.exit_signal",
@@ -640,6 +702,31 @@ impl PositionMonitor {
         })
     }
 
+    /// Queues a full exit that the executor runs through its emergency venue chain.
+    pub async fn trigger_emergency_exit(&self, position_id: Uuid) -> AppResult<()> {
+        let position = self
+            .position_manager
+            .get_position(position_id)
+            .await
+            .ok_or_else(|| {
+                crate::error::AppError::NotFound(format!("Position {} not found", position_id))
+            })?;
+
+        let signal = ExitSignal {
+            position_id,
+            reason: ExitReason::Emergency,
+            exit_percent: 100.0,
+            current_price: position.current_price,
+            triggered_at: chrono::Utc::now(),
+            urgency: ExitUrgency::Critical,
+        };
+
+        let cmd = PositionCommand::Exit(ExitCommand::new(signal, CommandSource::EmergencyClose));
+        self.command_tx.send(cmd).await.map_err(|e| {
+            crate::error::AppError::Internal(format!("Failed to queue emergency exit: {}", e))
+        })
+    }
+
     pub async fn trigger_exit_with_reason(&self, signal: &ExitSignal) -> AppResult<()> {
         let cmd = PositionCommand::Exit(ExitCommand::new(signal.clone(), CommandSource::Monitor));
         self.command_tx
@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+pub const DEFAULT_RECONCILE_INTERVAL_SECS: u64 = 10;
+pub const MIN_RECONCILE_INTERVAL_SECS: u64 = 1;
+pub const MAX_RECONCILE_INTERVAL_SECS: u64 = 3600;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReconcileScheduleStatus {
+    pub enabled: bool,
+    pub interval_secs: u64,
+}
+
+/// Live controls for the periodic wallet reconciliation loop. Changes wake the loop so
+/// a new interval or a pause applies to the next wait rather than after a restart.
+#[derive(Debug)]
+pub struct ReconcileSchedule {
+    enabled: AtomicBool,
+    interval_ms: AtomicU64,
+    changed: Notify,
+}
+
+impl ReconcileSchedule {
+    pub fn new(enabled: bool, interval: Duration) -> Self {
+        Self {
+            enabled: AtomicBool::new(enabled),
+            interval_ms: AtomicU64::new(interval.as_millis() as u64),
+            changed: Notify::new(),
+        }
+    }
+
+    /// `RECONCILE_ENABLED` (default on) and `RECONCILE_INTERVAL_SECS` (default 10).
+    pub fn from_env() -> Self {
+        let enabled = std::env::var("RECONCILE_ENABLED")
+            .map(|v| v != "0" && v.to_lowercase() != "false")
+            .unwrap_or(true);
+        let interval_secs = std::env::var("RECONCILE_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(|secs| secs.clamp(MIN_RECONCILE_INTERVAL_SECS, MAX_RECONCILE_INTERVAL_SECS))
+            .unwrap_or(DEFAULT_RECONCILE_INTERVAL_SECS);
+
+        Self::new(enabled, Duration::from_secs(interval_secs))
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn interval(&self) -> Duration {
+        Duration::from_millis(self.interval_ms.load(Ordering::Relaxed))
+    }
+
+    pub fn status(&self) -> ReconcileScheduleStatus {
+        ReconcileScheduleStatus {
+            enabled: self.is_enabled(),
+            interval_secs: self.interval().as_secs(),
+        }
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+        self.changed.notify_waiters();
+    }
+
+    pub fn set_interval(&self, interval: Duration) {
+        self.interval_ms
+            .store(interval.as_millis() as u64, Ordering::Relaxed);
+        self.changed.notify_waiters();
+    }
+
+    /// Waits out the current interval and returns once a run is due. While paused it
+    /// keeps waiting; a settings change restarts the wait with the new values.
+    pub async fn wait_for_next_run(&self) {
+        loop {
+            let changed = self.changed.notified();
+            tokio::select! {
+                _ = tokio::time::sleep(self.interval()) => {
+                    if self.is_enabled() {
+                        return;
+                    }
+                }
+                _ = changed => {}
+            }
+        }
+    }
+}
+
+impl Default for ReconcileSchedule {
+    fn default() -> Self {
+        Self::new(true, Duration::from_secs(DEFAULT_RECONCILE_INTERVAL_SECS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    fn spawn_counting_loop(schedule: Arc<ReconcileSchedule>) -> Arc<AtomicUsize> {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&runs);
+        tokio::spawn(async move {
+            loop {
+                schedule.wait_for_next_run().await;
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+        runs
+    }
+
+    #[tokio::test]
+    async fn test_waits_for_configured_interval() {
+        let schedule = ReconcileSchedule::new(true, Duration::from_millis(60));
+
+        let started = Instant::now();
+        schedule.wait_for_next_run().await;
+        assert!(started.elapsed() >= Duration::from_millis(60));
+
+        // A shorter interval set mid-wait applies immediately
+        let schedule = Arc::new(ReconcileSchedule::new(true, Duration::from_secs(60)));
+        let waiter = Arc::clone(&schedule);
+        let wait = tokio::spawn(async move { waiter.wait_for_next_run().await });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        schedule.set_interval(Duration::from_millis(30));
+
+        tokio::time::timeout(Duration::from_secs(2), wait)
+            .await
+            .expect("wait should finish on the new interval")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_pause_and_resume_at_runtime() {
+        let schedule = Arc::new(ReconcileSchedule::new(true, Duration::from_millis(20)));
+        let runs = spawn_counting_loop(Arc::clone(&schedule));
+
+        tokio::time::sleep(Duration::from_millis(110)).await;
+        assert!(runs.load(Ordering::SeqCst) >= 2);
+
+        schedule.set_enabled(false);
+        assert!(!schedule.status().enabled);
+        // Let a run that was already past the check land before taking the baseline
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        let paused_at = runs.load(Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(110)).await;
+        assert_eq!(runs.load(Ordering::SeqCst), paused_at);
+
+        schedule.set_enabled(true);
+        tokio::time::sleep(Duration::from_millis(110)).await;
+        assert!(runs.load(Ordering::SeqCst) > paused_at);
+    }
+}
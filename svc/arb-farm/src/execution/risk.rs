@@ -32,8 +32,14 @@ pub struct RiskConfig {
     pub trailing_stop_percent: f64,
     #[serde(default = "default_time_limit")]
     pub time_limit_minutes: u32,
+    /// Blocks re-buying a mint for this long after a losing exit on it. 0 disables.
+    #[serde(default = "default_reentry_cooldown")]
+    pub reentry_cooldown_secs: u64,
 }
 
+pub const MAX_REENTRY_COOLDOWN_SECS: u64 = 86_400;
+pub const DEFAULT_REENTRY_COOLDOWN_SECS: u64 = 900;
+
 // DEFENSIVE defaults - matches ExitConfig::for_defensive()
 fn default_take_profit() -> f64 {
     15.0
@@ -44,6 +50,9 @@ fn default_trailing_stop() -> f64 {
 fn default_time_limit() -> u32 {
     5
 } // DEFENSIVE: 5 min
+fn default_reentry_cooldown() -> u64 {
+    DEFAULT_REENTRY_COOLDOWN_SECS
+}
 
 impl Default for RiskConfig {
     fn default() -> Self {
@@ -59,6 +68,7 @@ impl Default for RiskConfig {
             take_profit_percent: 15.0,
             trailing_stop_percent: 8.0,
             time_limit_minutes: 5,
+            reentry_cooldown_secs: DEFAULT_REENTRY_COOLDOWN_SECS,
         }
     }
 }
@@ -78,6 +88,7 @@ impl RiskConfig {
             take_profit_percent: 10.0,
             trailing_stop_percent: 8.0,
             time_limit_minutes: 5,
+            reentry_cooldown_secs: 1800,
         }
     }
 
@@ -94,6 +105,7 @@ impl RiskConfig {
             take_profit_percent: 15.0,  // DEFENSIVE: 15% TP
             trailing_stop_percent: 8.0, // DEFENSIVE: 8% trailing
             time_limit_minutes: 5,      // DEFENSIVE: 5 min
+            reentry_cooldown_secs: 300,
         }
     }
 
@@ -110,6 +122,7 @@ impl RiskConfig {
             take_profit_percent: 12.0,
             trailing_stop_percent: 10.0,
             time_limit_minutes: 5,
+            reentry_cooldown_secs: 1800,
         }
     }
 
@@ -131,6 +144,7 @@ impl RiskConfig {
             take_profit_percent: 100.0,  // Same tiered exit
             trailing_stop_percent: 20.0, // Same trailing
             time_limit_minutes: 15,      // Same time limit
+            reentry_cooldown_secs: 300,
         }
     }
 }
@@ -0,0 +1,287 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::execution::risk::RiskConfig;
+
+use super::position_manager::BaseCurrency;
+
+pub const MAX_SCALE_IN_TRANCHES: usize = 8;
+/// Headroom below this is not worth a transaction; the plan is treated as full.
+pub const MIN_SCALE_IN_BASE: f64 = 0.001;
+
+/// Buy `add_percent` of the original entry size once price is `dip_percent` below the
+/// original entry price.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScaleInTranche {
+    pub dip_percent: f64,
+    pub add_percent: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScaleInConfig {
+    pub tranches: Vec<ScaleInTranche>,
+    /// Cap on the whole position (original entry plus adds) in its base currency.
+    pub max_total_base: f64,
+}
+
+impl ScaleInConfig {
+    /// Tranches must be ordered by increasing dip and stay above the stop loss, which
+    /// would otherwise sell before the add.
+    pub fn validate(&self, stop_loss_percent: Option<f64>) -> AppResult<()> {
+        if self.tranches.is_empty() || self.tranches.len() > MAX_SCALE_IN_TRANCHES {
+            return Err(AppError::Validation(format!(
+                "Scale-in needs 1-{} tranches",
+                MAX_SCALE_IN_TRANCHES
+            )));
+        }
+        if !self.max_total_base.is_finite() || self.max_total_base <= 0.0 {
+            return Err(AppError::Validation(
+                "max_total_base must be positive".to_string(),
+            ));
+        }
+
+        let mut previous_dip = 0.0;
+        for tranche in &self.tranches {
+            if !(tranche.dip_percent > previous_dip && tranche.dip_percent < 100.0) {
+                return Err(AppError::Validation(format!(
+                    "Tranche dips must increase between 0 and 100%, got {}",
+                    tranche.dip_percent
+                )));
+            }
+            if !(tranche.add_percent > 0.0 && tranche.add_percent <= 1000.0) {
+                return Err(AppError::Validation(format!(
+                    "Tranche add_percent must be in (0, 1000], got {}",
+                    tranche.add_percent
+                )));
+            }
+            if let Some(stop_loss) = stop_loss_percent {
+                if tranche.dip_percent >= stop_loss {
+                    return Err(AppError::Validation(format!(
+                        "Tranche at -{}% is at or below the {}% stop loss",
+                        tranche.dip_percent, stop_loss
+                    )));
+                }
+            }
+            previous_dip = tranche.dip_percent;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScaleInFill {
+    pub tranche: usize,
+    pub amount_base: f64,
+    pub token_amount: f64,
+    pub price: f64,
+    pub filled_at: DateTime<Utc>,
+    pub tx_signature: Option<String>,
+}
+
+/// A position's scale-in config and progress. Dips and tranche sizes are measured from
+/// the entry captured when the plan was attached, not the averaged entry after adds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScaleInPlan {
+    pub config: ScaleInConfig,
+    pub reference_price: f64,
+    pub reference_amount_base: f64,
+    #[serde(default)]
+    pub fills: Vec<ScaleInFill>,
+}
+
+impl ScaleInPlan {
+    pub fn new(config: ScaleInConfig, reference_price: f64, reference_amount_base: f64) -> Self {
+        Self {
+            config,
+            reference_price,
+            reference_amount_base,
+            fills: Vec::new(),
+        }
+    }
+
+    pub fn is_filled(&self, tranche: usize) -> bool {
+        self.fills.iter().any(|f| f.tranche == tranche)
+    }
+
+    /// The shallowest unfilled tranche `price` has reached and how much to buy, clamped
+    /// to the headroom left under the plan and risk caps. `open_exposure_base` is the
+    /// capital held across every open position in the same base currency.
+    pub fn next_tranche(
+        &self,
+        position_total_base: f64,
+        open_exposure_base: f64,
+        price: f64,
+        limits: &ScaleInLimits,
+    ) -> Option<(usize, f64)> {
+        if self.reference_price <= 0.0 || !price.is_finite() || price <= 0.0 {
+            return None;
+        }
+        let dip = (self.reference_price - price) / self.reference_price * 100.0;
+
+        let (index, tranche) = self
+            .config
+            .tranches
+            .iter()
+            .enumerate()
+            .find(|(i, t)| !self.is_filled(*i) && dip >= t.dip_percent)?;
+
+        let cap = self.config.max_total_base.min(limits.max_position_base);
+        let headroom =
+            (cap - position_total_base).min(limits.max_total_exposure_base - open_exposure_base);
+        let amount = (self.reference_amount_base * tranche.add_percent / 100.0).min(headroom);
+        (amount >= MIN_SCALE_IN_BASE).then_some((index, amount))
+    }
+}
+
+/// Risk caps applied on top of a plan's own `max_total_base`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScaleInLimits {
+    pub max_position_base: f64,
+    pub max_total_exposure_base: f64,
+}
+
+impl Default for ScaleInLimits {
+    fn default() -> Self {
+        Self {
+            max_position_base: f64::INFINITY,
+            max_total_exposure_base: f64::INFINITY,
+        }
+    }
+}
+
+impl ScaleInLimits {
+    /// Risk limits are in SOL, so they only cap SOL-based positions. Total exposure is
+    /// capped at a full book: `max_concurrent_positions` positions of `max_position_sol`.
+    pub fn from_risk(risk: &RiskConfig, base_currency: BaseCurrency) -> Self {
+        match base_currency {
+            BaseCurrency::Sol => Self {
+                max_position_base: risk.max_position_sol.min(risk.max_position_per_token_sol),
+                max_total_exposure_base: risk.max_position_sol
+                    * risk.max_concurrent_positions as f64,
+            },
+            BaseCurrency::Usdc | BaseCurrency::Usdt => Self::default(),
+        }
+    }
+}
+
+/// Fill price of a buy in the units entries are stored in: smallest base-currency units
+/// (lamports for SOL) per raw token.
+pub fn fill_price(amount_base_units: u64, tokens_received: u64) -> Option<f64> {
+    (amount_base_units > 0 && tokens_received > 0)
+        .then(|| amount_base_units as f64 / tokens_received as f64)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScaleInSignal {
+    pub position_id: Uuid,
+    pub tranche: usize,
+    pub amount_base: f64,
+    pub current_price: f64,
+    pub triggered_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan() -> ScaleInPlan {
+        ScaleInPlan::new(
+            ScaleInConfig {
+                tranches: vec![
+                    ScaleInTranche {
+                        dip_percent: 20.0,
+                        add_percent: 50.0,
+                    },
+                    ScaleInTranche {
+                        dip_percent: 30.0,
+                        add_percent: 100.0,
+                    },
+                ],
+                max_total_base: 2.0,
+            },
+            0.001,
+            1.0,
+        )
+    }
+
+    #[test]
+    fn test_tranches_trigger_at_dip_levels() {
+        let plan = plan();
+        let limits = ScaleInLimits::default();
+
+        assert_eq!(plan.next_tranche(1.0, 0.0, 0.00085, &limits), None);
+        assert_eq!(plan.next_tranche(1.0, 0.0, 0.0008, &limits), Some((0, 0.5)));
+        // Gapping through both levels buys the shallower tranche first
+        assert_eq!(plan.next_tranche(1.0, 0.0, 0.0006, &limits), Some((0, 0.5)));
+    }
+
+    #[test]
+    fn test_adds_clamped_to_plan_and_risk_caps() {
+        let mut plan = plan();
+        plan.fills.push(ScaleInFill {
+            tranche: 0,
+            amount_base: 0.5,
+            token_amount: 625.0,
+            price: 0.0008,
+            filled_at: Utc::now(),
+            tx_signature: None,
+        });
+
+        let unlimited = ScaleInLimits::default();
+        assert_eq!(plan.next_tranche(1.5, 1.5, 0.0008, &unlimited), None);
+        // Tranche 1 wants 1.0 but only 0.5 fits under max_total_base
+        assert_eq!(
+            plan.next_tranche(1.5, 1.5, 0.0007, &unlimited),
+            Some((1, 0.5))
+        );
+        assert_eq!(plan.next_tranche(2.0, 2.0, 0.0005, &unlimited), None);
+
+        let risk = RiskConfig {
+            max_position_sol: 1.6,
+            ..RiskConfig::default()
+        };
+        let limits = ScaleInLimits::from_risk(&risk, BaseCurrency::Sol);
+        let (_, amount) = plan.next_tranche(1.5, 1.5, 0.0007, &limits).unwrap();
+        assert!((amount - 0.1).abs() < 1e-9);
+        assert_eq!(plan.next_tranche(1.6, 1.6, 0.0007, &limits), None);
+
+        // Other open positions use up the total exposure cap (1.6 SOL x 2 positions)
+        let (_, amount) = plan.next_tranche(1.5, 3.15, 0.0007, &limits).unwrap();
+        assert!((amount - 0.05).abs() < 1e-9);
+        assert_eq!(plan.next_tranche(1.5, 3.2, 0.0007, &limits), None);
+        assert_eq!(
+            ScaleInLimits::from_risk(&risk, BaseCurrency::Usdc),
+            ScaleInLimits::default()
+        );
+    }
+
+    #[test]
+    fn test_fill_price_matches_entry_units() {
+        // 0.5 SOL for 1,000 tokens at 6 decimals, in lamports per raw token like entries
+        let price = fill_price(500_000_000, 1_000_000_000).unwrap();
+        assert!((price - 0.5).abs() < 1e-12);
+        assert_eq!(fill_price(500_000_000, 0), None);
+    }
+
+    #[test]
+    fn test_config_validation() {
+        let config = plan().config;
+        assert!(config.validate(Some(40.0)).is_ok());
+        assert!(config.validate(Some(25.0)).is_err());
+
+        let unordered = ScaleInConfig {
+            tranches: config.tranches.iter().rev().copied().collect(),
+            ..config.clone()
+        };
+        assert!(unordered.validate(None).is_err());
+        assert!(ScaleInConfig {
+            max_total_base: 0.0,
+            ..config
+        }
+        .validate(None)
+        .is_err());
+    }
+}
@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+
+use super::exit_fallback::ExitVenue;
+use super::position_manager::MAX_EXIT_SLIPPAGE_BPS;
+use crate::error::{AppError, AppResult};
+use crate::venues::curves::math::calculate_min_sol_out;
+
+pub const DEFAULT_SELL_PROTECTION_BPS: u16 = 2500;
+
+/// How far below its quote a market sell may fill. The resulting min-out is baked into
+/// the transaction, so a worse fill (e.g. after a sandwich front-run) reverts on-chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SellProtection {
+    pub max_slippage_bps: u16,
+}
+
+impl Default for SellProtection {
+    fn default() -> Self {
+        Self::new(DEFAULT_SELL_PROTECTION_BPS)
+    }
+}
+
+impl SellProtection {
+    pub fn new(max_slippage_bps: u16) -> Self {
+        Self {
+            max_slippage_bps: max_slippage_bps.min(MAX_EXIT_SLIPPAGE_BPS),
+        }
+    }
+
+    /// `SELL_PROTECTION_MAX_SLIPPAGE_BPS` (default 2500, capped at `MAX_EXIT_SLIPPAGE_BPS`).
+    pub fn from_env() -> Self {
+        std::env::var("SELL_PROTECTION_MAX_SLIPPAGE_BPS")
+            .ok()
+            .and_then(|v| v.parse::<u16>().ok())
+            .map(Self::new)
+            .unwrap_or_default()
+    }
+
+    pub fn quote(&self, expected_out: u64) -> AppResult<ProtectedSellQuote> {
+        if expected_out == 0 {
+            return Err(AppError::ExternalApi(
+                "Sell quote returned zero output - refusing to build an unprotected sell"
+                    .to_string(),
+            ));
+        }
+
+        Ok(ProtectedSellQuote {
+            expected_out,
+            min_out: calculate_min_sol_out(expected_out, self.max_slippage_bps),
+            max_slippage_bps: self.max_slippage_bps,
+        })
+    }
+
+    /// Quotes `expected_out` and checks the min-out a venue baked into its transaction.
+    pub fn verify(
+        &self,
+        expected_out: u64,
+        baked_min_out: Option<u64>,
+    ) -> AppResult<ProtectedSellQuote> {
+        let quote = self.quote(expected_out)?;
+        quote.check_threshold(baked_min_out)?;
+        Ok(quote)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProtectedSellQuote {
+    pub expected_out: u64,
+    /// Lamports the transaction must receive or revert.
+    pub min_out: u64,
+    pub max_slippage_bps: u16,
+}
+
+impl ProtectedSellQuote {
+    /// Aggregators bake their own threshold into the transaction they return; it has to
+    /// be at least as tight as ours.
+    pub fn check_threshold(&self, baked_min_out: Option<u64>) -> AppResult<()> {
+        match baked_min_out {
+            Some(baked) if baked >= self.min_out => Ok(()),
+            Some(baked) => Err(AppError::Execution(format!(
+                "Sell transaction min-out {} is below the protected min-out {} ({}bps)",
+                baked, self.min_out, self.max_slippage_bps
+            ))),
+            None => Err(AppError::Execution(
+                "Sell venue did not report the min-out baked into its transaction".to_string(),
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProtectedSellResult {
+    pub venue: ExitVenue,
+    pub transaction_base64: String,
+    pub token_amount_in: u64,
+    pub quote: ProtectedSellQuote,
+    pub price_impact_percent: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::venues::curves::math::{BondingCurveMath, PumpFunCurve};
+
+    fn curve() -> PumpFunCurve {
+        PumpFunCurve::with_current_state(
+            40_000_000_000,
+            800_000_000_000_000,
+            10_000_000_000,
+            520_000_000_000_000,
+        )
+    }
+
+    #[test]
+    fn test_min_out_computed_from_quote() {
+        let quote = SellProtection::new(500).quote(1_000_000).unwrap();
+        assert_eq!(quote.min_out, 950_000);
+
+        assert!(quote.check_threshold(Some(950_000)).is_ok());
+        assert!(quote.check_threshold(Some(960_000)).is_ok());
+        // A venue that baked in a looser threshold than asked for is rejected
+        assert!(quote.check_threshold(Some(900_000)).is_err());
+        assert!(quote.check_threshold(None).is_err());
+        assert_eq!(
+            SellProtection::new(500)
+                .verify(1_000_000, Some(950_000))
+                .unwrap(),
+            quote
+        );
+        assert!(SellProtection::new(500)
+            .verify(1_000_000, Some(900_000))
+            .is_err());
+
+        assert!(SellProtection::default().quote(0).is_err());
+        assert_eq!(
+            SellProtection::new(9_000).max_slippage_bps,
+            MAX_EXIT_SLIPPAGE_BPS
+        );
+    }
+
+    #[test]
+    fn test_sandwiched_sell_reverts() {
+        let tokens = 5_000_000_000_000;
+        let quoted = curve().calculate_sell_amount(tokens);
+        let quote = SellProtection::new(300).quote(quoted.sol_out).unwrap();
+
+        let front_run = |attacker_tokens: u64| {
+            let attack = curve().calculate_sell_amount(attacker_tokens);
+            let mut moved = curve();
+            moved.params.virtual_sol_reserves = attack.new_virtual_sol;
+            moved.params.virtual_token_reserves = attack.new_virtual_token;
+            moved.calculate_sell_amount(tokens).sol_out
+        };
+
+        // The program fails the sell when its fill is below the min-out in the instruction.
+        // A small front-run stays inside the protection and fills
+        assert!(front_run(5_000_000_000_000) >= quote.min_out);
+        // A sandwich that pushes the price well past it reverts
+        assert!(front_run(60_000_000_000_000) < quote.min_out);
+    }
+}
@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::position_manager::MAX_EXIT_SLIPPAGE_BPS;
+
+pub const DEFAULT_EXIT_SLIPPAGE_STEP_BPS: u16 = 500;
+pub const DEFAULT_EXIT_SLIPPAGE_MAX_BPS: u16 = 2500;
+
+/// Widens exit slippage on each retry so a thinning market still lets the position out,
+/// without ever going past `max_bps`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SlippageEscalation {
+    pub step_bps: u16,
+    pub max_bps: u16,
+}
+
+impl Default for SlippageEscalation {
+    fn default() -> Self {
+        Self {
+            step_bps: DEFAULT_EXIT_SLIPPAGE_STEP_BPS,
+            max_bps: DEFAULT_EXIT_SLIPPAGE_MAX_BPS,
+        }
+    }
+}
+
+impl SlippageEscalation {
+    /// `EXIT_SLIPPAGE_STEP_BPS` (default 500) and `EXIT_SLIPPAGE_MAX_BPS` (default 2500,
+    /// capped at `MAX_EXIT_SLIPPAGE_BPS`).
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let step_bps = std::env::var("EXIT_SLIPPAGE_STEP_BPS")
+            .ok()
+            .and_then(|v| v.parse::<u16>().ok())
+            .unwrap_or(defaults.step_bps);
+        let max_bps = std::env::var("EXIT_SLIPPAGE_MAX_BPS")
+            .ok()
+            .and_then(|v| v.parse::<u16>().ok())
+            .filter(|v| *v > 0)
+            .map(|v| v.min(MAX_EXIT_SLIPPAGE_BPS))
+            .unwrap_or(defaults.max_bps);
+
+        Self { step_bps, max_bps }
+    }
+
+    /// Attempt 0 uses `initial_bps`; each retry adds one step up to the cap. An initial
+    /// tolerance already above the cap is kept rather than lowered.
+    pub fn slippage_for_attempt(&self, initial_bps: u16, attempt: u32) -> u16 {
+        let cap = self.max_bps.max(initial_bps);
+        let escalated = initial_bps as u32 + self.step_bps as u32 * attempt;
+        escalated.min(cap as u32) as u16
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlippageAttempt {
+    pub attempt: u32,
+    pub slippage_bps: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub at: DateTime<Utc>,
+}
+
+impl SlippageAttempt {
+    pub fn failed(attempt: u32, slippage_bps: u16, error: impl Into<String>) -> Self {
+        Self {
+            attempt,
+            slippage_bps,
+            error: Some(error.into()),
+            at: Utc::now(),
+        }
+    }
+
+    pub fn succeeded(attempt: u32, slippage_bps: u16) -> Self {
+        Self {
+            attempt,
+            slippage_bps,
+            error: None,
+            at: Utc::now(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_each_retry_widens_by_one_step() {
+        let escalation = SlippageEscalation {
+            step_bps: 500,
+            max_bps: 2500,
+        };
+
+        let tried: Vec<u16> = (0..3)
+            .map(|attempt| escalation.slippage_for_attempt(1000, attempt))
+            .collect();
+        assert_eq!(tried, vec![1000, 1500, 2000]);
+    }
+
+    #[test]
+    fn test_escalation_is_capped_at_max() {
+        let escalation = SlippageEscalation {
+            step_bps: 1000,
+            max_bps: 2500,
+        };
+
+        assert!((0..=10).all(|attempt| escalation.slippage_for_attempt(1000, attempt) <= 2500));
+        assert_eq!(escalation.slippage_for_attempt(1000, 10), 2500);
+
+        // A caller already asking for more than the cap keeps its tolerance
+        assert_eq!(escalation.slippage_for_attempt(3000, 0), 3000);
+        assert_eq!(escalation.slippage_for_attempt(3000, 5), 3000);
+    }
+}
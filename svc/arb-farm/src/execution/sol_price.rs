@@ -0,0 +1,198 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use super::position_manager::SOL_MINT;
+use crate::error::{AppError, AppResult};
+
+const DEFAULT_SOL_USD_PRICE_URL: &str = "https://lite-api.jup.ag/price/v3";
+const DEFAULT_REFRESH_SECS: u64 = 60;
+/// Cached rates older than this are not stamped onto positions.
+const MAX_RATE_AGE_SECS: i64 = 900;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PnlCurrency {
+    #[default]
+    Sol,
+    Usd,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SolUsdRate {
+    pub rate: f64,
+    pub fetched_at: DateTime<Utc>,
+}
+
+/// SOL/USD rate used to stamp positions at entry/exit and to value open
+/// positions in USD. Refreshed in the background so the trading path only
+/// ever reads the cached value.
+pub struct SolUsdPriceCache {
+    client: reqwest::Client,
+    price_url: String,
+    cached: Arc<RwLock<Option<SolUsdRate>>>,
+    refresh_interval: Duration,
+}
+
+impl SolUsdPriceCache {
+    pub fn new(price_url: String) -> AppResult<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(5))
+            .build()
+            .map_err(|e| AppError::Internal(format!("Failed to create HTTP client: {}", e)))?;
+
+        Ok(Self {
+            client,
+            price_url,
+            cached: Arc::new(RwLock::new(None)),
+            refresh_interval: Duration::from_secs(DEFAULT_REFRESH_SECS),
+        })
+    }
+
+    pub fn from_env() -> AppResult<Self> {
+        let price_url = std::env::var("SOL_USD_PRICE_URL")
+            .unwrap_or_else(|_| DEFAULT_SOL_USD_PRICE_URL.to_string());
+        let refresh_secs = std::env::var("SOL_USD_PRICE_REFRESH_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_REFRESH_SECS)
+            .max(5);
+
+        Ok(Self::new(price_url)?.with_refresh_interval(Duration::from_secs(refresh_secs)))
+    }
+
+    pub fn with_refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+
+    /// Last fetched rate, or None if nothing has been fetched recently enough.
+    pub async fn cached_rate(&self) -> Option<f64> {
+        let cached = (*self.cached.read().await)?;
+        ((Utc::now() - cached.fetched_at).num_seconds() <= MAX_RATE_AGE_SECS).then_some(cached.rate)
+    }
+
+    pub async fn set_rate(&self, rate: f64) {
+        if rate.is_finite() && rate > 0.0 {
+            *self.cached.write().await = Some(SolUsdRate {
+                rate,
+                fetched_at: Utc::now(),
+            });
+        }
+    }
+
+    pub async fn refresh(&self) -> AppResult<f64> {
+        let url = format!("{}?ids={}", self.price_url, SOL_MINT);
+        let response =
+            self.client.get(&url).send().await.map_err(|e| {
+                AppError::ExternalApi(format!("SOL/USD price request failed: {}", e))
+            })?;
+
+        if !response.status().is_success() {
+            return Err(AppError::ExternalApi(format!(
+                "SOL/USD price returned error status: {}",
+                response.status()
+            )));
+        }
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| AppError::ExternalApi(format!("Failed to parse SOL/USD price: {}", e)))?;
+
+        let rate = parse_sol_usd_price(&body).ok_or_else(|| {
+            AppError::ExternalApi(format!("No SOL/USD price in response: {}", body))
+        })?;
+        self.set_rate(rate).await;
+        Ok(rate)
+    }
+
+    pub fn start_refresh_loop(self: &Arc<Self>) {
+        let cache = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(cache.refresh_interval);
+            loop {
+                interval.tick().await;
+                if let Err(e) = cache.refresh().await {
+                    tracing::warn!("SOL/USD price refresh failed: {}", e);
+                }
+            }
+        });
+    }
+}
+
+/// Accepts both the v3 (`{mint: {usdPrice}}`) and older (`{data: {mint: {price}}}`)
+/// Jupiter price response shapes.
+fn parse_sol_usd_price(body: &serde_json::Value) -> Option<f64> {
+    let entry = body
+        .get(SOL_MINT)
+        .or_else(|| body.get("data").and_then(|d| d.get(SOL_MINT)))?;
+    let price = entry.get("usdPrice").or_else(|| entry.get("price"))?;
+
+    price
+        .as_f64()
+        .or_else(|| price.as_str().and_then(|s| s.parse().ok()))
+        .filter(|p| p.is_finite() && *p > 0.0)
+}
+
+/// USD P&L of a SOL-denominated trade: what the position is (or was) worth at the
+/// exit rate minus what it cost at the entry rate. A missing rate on one side falls
+/// back to the other, which reduces to `pnl_sol * rate`.
+pub fn pnl_usd(
+    entry_amount_sol: f64,
+    pnl_sol: f64,
+    entry_rate: Option<f64>,
+    exit_rate: Option<f64>,
+) -> Option<f64> {
+    let exit_rate = exit_rate.or(entry_rate)?;
+    let entry_rate = entry_rate.unwrap_or(exit_rate);
+    Some((entry_amount_sol + pnl_sol) * exit_rate - entry_amount_sol * entry_rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_closed_trade_usd_pnl_uses_entry_and_exit_rates() {
+        // +0.1 SOL on a 1 SOL trade while SOL went from $100 to $150.
+        let usd = pnl_usd(1.0, 0.1, Some(100.0), Some(150.0)).unwrap();
+        assert!((usd - 65.0).abs() < 1e-9);
+
+        // A losing SOL trade can still be a USD gain when SOL rallies.
+        let usd = pnl_usd(1.0, -0.1, Some(100.0), Some(150.0)).unwrap();
+        assert!((usd - 35.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_missing_rates_fall_back() {
+        let usd = pnl_usd(1.0, 0.1, None, Some(150.0)).unwrap();
+        assert!((usd - 15.0).abs() < 1e-9);
+        assert!(pnl_usd(1.0, 0.1, None, None).is_none());
+    }
+
+    #[test]
+    fn test_parse_price_response_shapes() {
+        let v3 = json!({ SOL_MINT: { "usdPrice": 187.25, "decimals": 9 } });
+        assert_eq!(parse_sol_usd_price(&v3), Some(187.25));
+
+        let legacy = json!({ "data": { SOL_MINT: { "id": SOL_MINT, "price": "142.5" } } });
+        assert_eq!(parse_sol_usd_price(&legacy), Some(142.5));
+
+        assert_eq!(parse_sol_usd_price(&json!({ "data": {} })), None);
+    }
+
+    #[tokio::test]
+    async fn test_cached_rate_ignores_invalid_values() {
+        let cache = SolUsdPriceCache::new("http://localhost:0".to_string()).unwrap();
+        assert_eq!(cache.cached_rate().await, None);
+
+        cache.set_rate(160.0).await;
+        cache.set_rate(f64::NAN).await;
+        cache.set_rate(0.0).await;
+        assert_eq!(cache.cached_rate().await, Some(160.0));
+    }
+}
@@ -7,11 +7,18 @@ use tracing::{debug, info, warn};
 use uuid::Uuid;
 
 use super::blockhash::BlockhashCache;
+use super::exit_fallback::ExitVenue;
+use super::lookup_tables::{
+    compile_transaction, parse_pubkey, JupiterInstruction, LookupTableCache, TransactionFormat,
+};
 use super::position_manager::{
     BaseCurrency, ExitSignal, OpenPosition, SOL_MINT, USDC_MINT, USDT_MINT,
 };
+use super::sell_protection::{ProtectedSellResult, SellProtection};
 use crate::error::{AppError, AppResult};
 use crate::models::Edge;
+use crate::venues::dex::RaydiumPoolType;
+use crate::wallet::BalanceCache;
 
 const PRICE_CACHE_TTL_SECS: u64 = 10;
 const JUPITER_RATE_LIMIT_RETRIES: u32 = 4;
@@ -30,9 +37,11 @@ pub struct TransactionBuilder {
     jupiter_api_url: String,
     rpc_url: String,
     blockhash_cache: BlockhashCache,
+    lookup_tables: LookupTableCache,
     price_cache: Arc<RwLock<HashMap<String, CachedPrice>>>,
     jupiter_semaphore: Arc<Semaphore>,
     last_jupiter_request: Arc<AtomicU64>,
+    balance_cache: Option<Arc<BalanceCache>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,6 +61,11 @@ pub struct BuildResult {
     pub priority_fee_lamports: u64,
     pub estimated_compute_units: u64,
     pub route_info: RouteInfo,
+    #[serde(default)]
+    pub transaction_format: TransactionFormat,
+    /// Lookup tables referenced by a v0 transaction.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub address_lookup_tables: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -61,7 +75,29 @@ pub struct RouteInfo {
     pub in_amount: u64,
     pub out_amount: u64,
     pub price_impact_bps: i32,
+    /// Jupiter's `otherAmountThreshold`: the least output the swap accepts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_out_amount: Option<u64>,
     pub route_plan: serde_json::Value,
+    /// Raydium pool types the route passes through, in hop order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub raydium_pool_types: Vec<RaydiumPoolType>,
+}
+
+impl RouteInfo {
+    pub fn uses_clmm(&self) -> bool {
+        self.raydium_pool_types
+            .iter()
+            .any(RaydiumPoolType::is_concentrated)
+    }
+}
+
+fn raydium_pool_types(route_plan: &[JupiterRoutePlan]) -> Vec<RaydiumPoolType> {
+    route_plan
+        .iter()
+        .filter_map(|hop| hop.swap_info.label.as_deref())
+        .filter_map(RaydiumPoolType::from_route_label)
+        .collect()
 }
 
 impl TransactionBuilder {
@@ -72,18 +108,26 @@ impl TransactionBuilder {
             .map_err(|e| AppError::Internal(format!("Failed to create HTTP client: {}", e)))?;
 
         let blockhash_cache = BlockhashCache::new(rpc_url.clone())?;
+        let lookup_tables = LookupTableCache::new(client.clone(), rpc_url.clone());
 
         Ok(Self {
             client,
             jupiter_api_url,
             rpc_url,
             blockhash_cache,
+            lookup_tables,
             price_cache: Arc::new(RwLock::new(HashMap::new())),
             jupiter_semaphore: Arc::new(Semaphore::new(JUPITER_MAX_CONCURRENT_REQUESTS)),
             last_jupiter_request: Arc::new(AtomicU64::new(0)),
+            balance_cache: None,
         })
     }
 
+    pub fn with_balance_cache(mut self, cache: Arc<BalanceCache>) -> Self {
+        self.balance_cache = Some(cache);
+        self
+    }
+
     async fn rate_limit_jupiter(&self) -> AppResult<()> {
         let _permit =
             self.jupiter_semaphore.acquire().await.map_err(|_| {
@@ -141,14 +185,39 @@ impl TransactionBuilder {
         // Step 1: Get quote from Jupiter
         let quote = self.get_jupiter_quote(params).await?;
 
-        // Step 2: Get swap transaction from Jupiter
-        let swap_response = self
-            .get_jupiter_swap_transaction(&quote, &params.user_public_key)
-            .await?;
-
-        // Step 3: Get recent blockhash for reference
+        // Step 2: Get recent blockhash
         let blockhash = self.blockhash_cache.get_blockhash().await?;
 
+        // Step 3: Build the transaction, legacy when it fits and v0 + ALTs otherwise.
+        // Falls back to Jupiter's prebuilt v0 transaction if local compilation fails.
+        let swap = match self
+            .build_from_swap_instructions(&quote, &params.user_public_key, &blockhash.blockhash)
+            .await
+        {
+            Ok(swap) => swap,
+            Err(e) => {
+                warn!(
+                    "Local swap compilation failed ({}), using Jupiter-built transaction",
+                    e
+                );
+                let swap_response = self
+                    .get_jupiter_swap_transaction(&quote, &params.user_public_key)
+                    .await?;
+                BuiltSwap {
+                    transaction_base64: swap_response.swap_transaction,
+                    transaction_format: TransactionFormat::V0,
+                    address_lookup_tables: swap_response
+                        .addresses_by_lookup_table_address
+                        .as_ref()
+                        .and_then(|v| v.as_object())
+                        .map(|tables| tables.keys().cloned().collect())
+                        .unwrap_or_default(),
+                    priority_fee_lamports: swap_response.prioritization_fee_lamports,
+                    compute_unit_limit: swap_response.compute_unit_limit,
+                }
+            }
+        };
+
         // Extract amounts from quote - CRITICAL: Fail if parsing fails to prevent zero-output trades
         let in_amount: u64 = quote.in_amount.parse().map_err(|e| {
             AppError::ExternalApi(format!(
@@ -193,18 +262,74 @@ impl TransactionBuilder {
 
         Ok(BuildResult {
             edge_id,
-            transaction_base64: swap_response.swap_transaction,
+            transaction_base64: swap.transaction_base64,
             last_valid_block_height: blockhash.last_valid_block_height,
-            priority_fee_lamports: swap_response.prioritization_fee_lamports.unwrap_or(0),
-            estimated_compute_units: swap_response.compute_unit_limit.unwrap_or(200_000),
+            priority_fee_lamports: swap.priority_fee_lamports.unwrap_or(0),
+            estimated_compute_units: swap.compute_unit_limit.unwrap_or(200_000),
             route_info: RouteInfo {
                 input_mint: quote.input_mint.clone(),
                 output_mint: quote.output_mint.clone(),
                 in_amount,
                 out_amount,
                 price_impact_bps: (quote.price_impact_pct.unwrap_or(0.0) * 10000.0) as i32,
+                min_out_amount: quote.other_amount_threshold.parse().ok(),
                 route_plan: serde_json::to_value(&quote.route_plan).unwrap_or_default(),
+                raydium_pool_types: raydium_pool_types(&quote.route_plan),
             },
+            transaction_format: swap.transaction_format,
+            address_lookup_tables: swap.address_lookup_tables,
+        })
+    }
+
+    async fn build_from_swap_instructions(
+        &self,
+        quote: &JupiterQuoteResponse,
+        user_public_key: &str,
+        blockhash: &str,
+    ) -> AppResult<BuiltSwap> {
+        let response: JupiterSwapInstructionsResponse = self
+            .post_jupiter_swap("swap-instructions", quote, user_public_key)
+            .await?;
+
+        let payer = parse_pubkey(user_public_key)?;
+        let recent_blockhash = blockhash
+            .parse()
+            .map_err(|e| AppError::Internal(format!("Invalid blockhash '{}': {}", blockhash, e)))?;
+        let instructions = response
+            .ordered_instructions()
+            .into_iter()
+            .map(solana_sdk::instruction::Instruction::try_from)
+            .collect::<AppResult<Vec<_>>>()?;
+
+        let compiled = match compile_transaction(&payer, &instructions, &[], recent_blockhash) {
+            Ok(compiled) => compiled,
+            Err(_) if !response.address_lookup_table_addresses.is_empty() => {
+                let tables = self
+                    .lookup_tables
+                    .resolve(&response.address_lookup_table_addresses)
+                    .await?;
+                compile_transaction(&payer, &instructions, &tables, recent_blockhash)?
+            }
+            Err(e) => return Err(e),
+        };
+
+        debug!(
+            "Compiled {:?} swap transaction: {} bytes, {} lookup tables",
+            compiled.format,
+            compiled.size_bytes,
+            compiled.lookup_tables_used.len()
+        );
+
+        Ok(BuiltSwap {
+            transaction_base64: compiled.to_base64()?,
+            transaction_format: compiled.format,
+            address_lookup_tables: compiled
+                .lookup_tables_used
+                .iter()
+                .map(|k| k.to_string())
+                .collect(),
+            priority_fee_lamports: response.prioritization_fee_lamports,
+            compute_unit_limit: response.compute_unit_limit,
         })
     }
 
@@ -285,7 +410,17 @@ impl TransactionBuilder {
         quote: &JupiterQuoteResponse,
         user_public_key: &str,
     ) -> AppResult<JupiterSwapResponse> {
-        let url = format!("{}/swap", self.jupiter_api_url);
+        self.post_jupiter_swap("swap", quote, user_public_key).await
+    }
+
+    /// Posts a swap request to `/swap` or `/swap-instructions`, retrying on rate limits.
+    async fn post_jupiter_swap<T: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        quote: &JupiterQuoteResponse,
+        user_public_key: &str,
+    ) -> AppResult<T> {
+        let url = format!("{}/{}", self.jupiter_api_url, endpoint);
 
         let request = JupiterSwapRequest {
             user_public_key: user_public_key.to_string(),
@@ -360,7 +495,7 @@ impl TransactionBuilder {
                 AppError::ExternalApi(format!("Failed to read Jupiter swap response: {}", e))
             })?;
 
-            return serde_json::from_str::<JupiterSwapResponse>(&response_text).map_err(|e| {
+            return serde_json::from_str::<T>(&response_text).map_err(|e| {
                 AppError::ExternalApi(format!(
                     "Failed to parse Jupiter swap: {} | Response: {}",
                     e,
@@ -492,6 +627,34 @@ impl TransactionBuilder {
         })
     }
 
+    /// `build_exit_swap` at `protection`'s tolerance, refused unless the min-out Jupiter
+    /// baked into the swap is at least the protected min-out.
+    pub async fn build_protected_exit_swap(
+        &self,
+        position: &OpenPosition,
+        exit_signal: &ExitSignal,
+        user_public_key: &str,
+        protection: SellProtection,
+    ) -> AppResult<ProtectedSellResult> {
+        let build = self
+            .build_exit_swap(
+                position,
+                exit_signal,
+                user_public_key,
+                protection.max_slippage_bps,
+            )
+            .await?;
+        let quote = protection.verify(build.expected_base_out, build.route_info.min_out_amount)?;
+
+        Ok(ProtectedSellResult {
+            venue: ExitVenue::Jupiter,
+            transaction_base64: build.transaction_base64,
+            token_amount_in: build.token_amount_in,
+            quote,
+            price_impact_percent: build.price_impact_bps as f64 / 100.0,
+        })
+    }
+
     pub async fn get_token_balance(&self, wallet: &str, token_mint: &str) -> AppResult<u64> {
         if token_mint == SOL_MINT {
             return self.get_sol_balance(wallet).await;
@@ -572,6 +735,16 @@ impl TransactionBuilder {
     }
 
     async fn get_sol_balance(&self, wallet: &str) -> AppResult<u64> {
+        match &self.balance_cache {
+            Some(cache) => Ok(cache
+                .get_or_fetch(wallet, || self.fetch_sol_balance(wallet))
+                .await?
+                .lamports),
+            None => self.fetch_sol_balance(wallet).await,
+        }
+    }
+
+    async fn fetch_sol_balance(&self, wallet: &str) -> AppResult<u64> {
         let request = serde_json::json!({
             "jsonrpc": "2.0",
             "id": 1,
@@ -913,6 +1086,50 @@ pub struct JupiterSwapResponse {
     pub addresses_by_lookup_table_address: Option<serde_json::Value>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JupiterSwapInstructionsResponse {
+    #[serde(default)]
+    pub compute_budget_instructions: Vec<JupiterInstruction>,
+    #[serde(default)]
+    pub setup_instructions: Vec<JupiterInstruction>,
+    #[serde(default)]
+    pub token_ledger_instruction: Option<JupiterInstruction>,
+    pub swap_instruction: JupiterInstruction,
+    #[serde(default)]
+    pub cleanup_instruction: Option<JupiterInstruction>,
+    #[serde(default)]
+    pub other_instructions: Vec<JupiterInstruction>,
+    #[serde(default)]
+    pub address_lookup_table_addresses: Vec<String>,
+    #[serde(default)]
+    pub prioritization_fee_lamports: Option<u64>,
+    #[serde(default)]
+    pub compute_unit_limit: Option<u64>,
+}
+
+impl JupiterSwapInstructionsResponse {
+    /// Instructions in the order Jupiter expects them to execute.
+    pub fn ordered_instructions(&self) -> Vec<&JupiterInstruction> {
+        self.compute_budget_instructions
+            .iter()
+            .chain(self.other_instructions.iter())
+            .chain(self.token_ledger_instruction.iter())
+            .chain(self.setup_instructions.iter())
+            .chain(std::iter::once(&self.swap_instruction))
+            .chain(self.cleanup_instruction.iter())
+            .collect()
+    }
+}
+
+struct BuiltSwap {
+    transaction_base64: String,
+    transaction_format: TransactionFormat,
+    address_lookup_tables: Vec<String>,
+    priority_fee_lamports: Option<u64>,
+    compute_unit_limit: Option<u64>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct JupiterSimulationError {
@@ -1013,11 +1230,48 @@ mod tests {
                 in_amount: 1000000000,
                 out_amount: 100000000,
                 price_impact_bps: 10,
+                min_out_amount: Some(99_000_000),
                 route_plan: serde_json::json!([]),
+                raydium_pool_types: vec![],
             },
+            transaction_format: TransactionFormat::V0,
+            address_lookup_tables: vec!["table".to_string()],
         };
 
         let json = serde_json::to_string(&result).unwrap();
         assert!(json.contains("test_tx"));
+        assert!(json.contains("\"transaction_format\":\"v0\""));
+    }
+
+    #[test]
+    fn test_route_info_marks_raydium_clmm_hops() {
+        let route_plan: Vec<JupiterRoutePlan> = serde_json::from_value(serde_json::json!([
+            {
+                "swapInfo": {
+                    "ammKey": "pool1",
+                    "label": "Raydium CLMM",
+                    "inputMint": "sol",
+                    "outputMint": "usdc",
+                    "inAmount": "1000",
+                    "outAmount": "900"
+                },
+                "percent": 100
+            },
+            {
+                "swapInfo": {
+                    "ammKey": "pool2",
+                    "label": "Meteora DLMM",
+                    "inputMint": "usdc",
+                    "outputMint": "bonk",
+                    "inAmount": "900",
+                    "outAmount": "800"
+                },
+                "percent": 100
+            }
+        ]))
+        .unwrap();
+
+        let pool_types = raydium_pool_types(&route_plan);
+        assert_eq!(pool_types, vec![RaydiumPoolType::Clmm]);
     }
 }
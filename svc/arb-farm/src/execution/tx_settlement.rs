@@ -1,9 +1,17 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use tracing::{debug, info, warn};
+use uuid::Uuid;
 
-use crate::helius::HeliusClient;
+use super::position_manager::{PositionManager, PositionStatus};
+use crate::error::AppResult;
+use crate::helius::{HeliusClient, SignatureStatus};
 
 const MAX_RETRIES: u32 = 3;
 const RETRY_DELAY_MS: u64 = 2000;
+const DEFAULT_SETTLEMENT_TIMEOUT_SECS: u64 = 60;
+const DEFAULT_SETTLEMENT_POLL_INTERVAL_MS: u64 = 500;
 
 #[derive(Debug, Clone)]
 pub struct TxSettlement {
@@ -257,3 +265,497 @@ pub async fn resolve_inferred_settlement(
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExitCommitment {
+    Processed,
+    Confirmed,
+    Finalized,
+}
+
+impl ExitCommitment {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "processed" => Some(Self::Processed),
+            "confirmed" => Some(Self::Confirmed),
+            "finalized" => Some(Self::Finalized),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Processed => "processed",
+            Self::Confirmed => "confirmed",
+            Self::Finalized => "finalized",
+        }
+    }
+
+    /// RPC nodes leave `confirmationStatus` out for rooted transactions, which also
+    /// report `confirmations: null`.
+    fn of_status(status: &SignatureStatus) -> Self {
+        match status.confirmation_status.as_deref().and_then(Self::parse) {
+            Some(level) => level,
+            None if status.confirmations.is_none() => Self::Finalized,
+            None => Self::Processed,
+        }
+    }
+}
+
+/// How far an exit must settle before its position is marked closed.
+#[derive(Debug, Clone, Copy)]
+pub struct ExitSettlementConfig {
+    pub commitment: ExitCommitment,
+    pub timeout: Duration,
+    pub poll_interval: Duration,
+}
+
+impl Default for ExitSettlementConfig {
+    fn default() -> Self {
+        Self {
+            commitment: ExitCommitment::Confirmed,
+            timeout: Duration::from_secs(DEFAULT_SETTLEMENT_TIMEOUT_SECS),
+            poll_interval: Duration::from_millis(DEFAULT_SETTLEMENT_POLL_INTERVAL_MS),
+        }
+    }
+}
+
+impl ExitSettlementConfig {
+    /// `EXIT_SETTLEMENT_COMMITMENT` (processed, confirmed or finalized; default confirmed)
+    /// and `EXIT_SETTLEMENT_TIMEOUT_SECS` (default 60).
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let commitment = match std::env::var("EXIT_SETTLEMENT_COMMITMENT") {
+            Ok(raw) => ExitCommitment::parse(&raw).unwrap_or_else(|| {
+                warn!(
+                    "⚠️ Unknown EXIT_SETTLEMENT_COMMITMENT '{}', using {}",
+                    raw,
+                    defaults.commitment.as_str()
+                );
+                defaults.commitment
+            }),
+            Err(_) => defaults.commitment,
+        };
+        let timeout = std::env::var("EXIT_SETTLEMENT_TIMEOUT_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|v| *v > 0)
+            .map(Duration::from_secs)
+            .unwrap_or(defaults.timeout);
+
+        Self {
+            commitment,
+            timeout,
+            ..defaults
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SettlementProgress {
+    Settling(Option<ExitCommitment>),
+    Settled { slot: u64 },
+    Dropped(String),
+}
+
+/// Follows one signature's status across polls. A signature that was seen and then
+/// disappears was on a fork that got abandoned.
+#[derive(Debug, Clone)]
+pub struct CommitmentTracker {
+    target: ExitCommitment,
+    reached: Option<ExitCommitment>,
+}
+
+impl CommitmentTracker {
+    pub fn new(target: ExitCommitment) -> Self {
+        Self {
+            target,
+            reached: None,
+        }
+    }
+
+    pub fn observe(&mut self, status: Option<&SignatureStatus>) -> SettlementProgress {
+        let Some(status) = status else {
+            return match self.reached {
+                Some(level) => SettlementProgress::Dropped(format!(
+                    "dropped after reaching {} (reorg)",
+                    level.as_str()
+                )),
+                None => SettlementProgress::Settling(None),
+            };
+        };
+
+        if let Some(err) = &status.err {
+            return SettlementProgress::Dropped(format!("failed on-chain: {}", err));
+        }
+
+        let level = ExitCommitment::of_status(status);
+        self.reached = self.reached.max(Some(level));
+        if level >= self.target {
+            SettlementProgress::Settled { slot: status.slot }
+        } else {
+            SettlementProgress::Settling(Some(level))
+        }
+    }
+}
+
+#[async_trait]
+pub trait SignatureStatusSource: Send + Sync {
+    async fn signature_status(&self, signature: &str) -> AppResult<Option<SignatureStatus>>;
+
+    /// Status lookup that also searches transaction history, used to double-check an
+    /// exit before it is re-sent.
+    async fn search_signature_history(&self, signature: &str)
+        -> AppResult<Option<SignatureStatus>>;
+}
+
+#[async_trait]
+impl SignatureStatusSource for HeliusClient {
+    async fn signature_status(&self, signature: &str) -> AppResult<Option<SignatureStatus>> {
+        self.get_signature_status(signature).await
+    }
+
+    async fn search_signature_history(
+        &self,
+        signature: &str,
+    ) -> AppResult<Option<SignatureStatus>> {
+        self.search_signature_status(signature).await
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExitSettlementOutcome {
+    Settled { slot: Option<u64> },
+    Requeued { reason: String },
+}
+
+/// Holds the position in Settling until its exit reaches the configured commitment.
+/// An exit that is dropped or times out is looked up once more in transaction history;
+/// if it landed there, it counts as settled. Otherwise, and for exits that failed
+/// on-chain, the position goes back to its status before the exit and is queued for a
+/// priority retry. The caller closes the position on `Settled`.
+pub async fn await_exit_commitment(
+    source: &dyn SignatureStatusSource,
+    position_manager: &PositionManager,
+    position_id: Uuid,
+    signature: &str,
+    config: &ExitSettlementConfig,
+) -> ExitSettlementOutcome {
+    // Inferred exits were verified by the wallet balance, there is nothing to track
+    if signature.starts_with("INFERRED_") {
+        return ExitSettlementOutcome::Settled { slot: None };
+    }
+
+    let claimed = position_manager
+        .compare_and_swap_status(
+            position_id,
+            PositionStatus::PendingExit,
+            PositionStatus::Settling,
+        )
+        .await
+        .unwrap_or_default();
+    if !claimed {
+        debug!(
+            "Position {} was not PendingExit when its exit started settling",
+            position_id
+        );
+    }
+
+    let mut tracker = CommitmentTracker::new(config.commitment);
+    let deadline = tokio::time::Instant::now() + config.timeout;
+    let mut failed_on_chain = false;
+    let reason = loop {
+        match source.signature_status(signature).await {
+            Ok(status) => match tracker.observe(status.as_ref()) {
+                SettlementProgress::Settled { slot } => {
+                    debug!(
+                        "Exit {} reached {} at slot {}",
+                        &signature[..16.min(signature.len())],
+                        config.commitment.as_str(),
+                        slot
+                    );
+                    return ExitSettlementOutcome::Settled { slot: Some(slot) };
+                }
+                SettlementProgress::Dropped(reason) => {
+                    failed_on_chain = status.as_ref().is_some_and(|s| s.err.is_some());
+                    break reason;
+                }
+                SettlementProgress::Settling(_) => {}
+            },
+            Err(e) => debug!(
+                "Signature status lookup failed for {}: {}",
+                &signature[..16.min(signature.len())],
+                e
+            ),
+        }
+
+        if tokio::time::Instant::now() + config.poll_interval > deadline {
+            break format!(
+                "not {} after {}s",
+                config.commitment.as_str(),
+                config.timeout.as_secs()
+            );
+        }
+        tokio::time::sleep(config.poll_interval).await;
+    };
+
+    // Re-sending an exit that did land would sell twice
+    if !failed_on_chain {
+        match source.search_signature_history(signature).await {
+            Ok(Some(status)) if status.err.is_none() => {
+                info!(
+                    "Exit {} for position {} found in transaction history at slot {} ({}) - treating as settled",
+                    &signature[..16.min(signature.len())],
+                    position_id,
+                    status.slot,
+                    reason
+                );
+                return ExitSettlementOutcome::Settled {
+                    slot: Some(status.slot),
+                };
+            }
+            Ok(_) => {}
+            Err(e) => warn!(
+                "History lookup for exit {} failed: {}",
+                &signature[..16.min(signature.len())],
+                e
+            ),
+        }
+    }
+
+    warn!(
+        "Exit {} for position {} {} - re-queuing",
+        &signature[..16.min(signature.len())],
+        position_id,
+        reason
+    );
+    position_manager.requeue_dropped_exit(position_id).await;
+    ExitSettlementOutcome::Requeued { reason }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::position_manager::ExitConfig;
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+
+    fn status(level: &str) -> SignatureStatus {
+        SignatureStatus {
+            slot: 42,
+            confirmations: Some(1),
+            err: None,
+            confirmation_status: Some(level.to_string()),
+        }
+    }
+
+    /// Replays scripted statuses and records the position's status at every poll.
+    struct ScriptedStatuses {
+        statuses: Mutex<VecDeque<Option<SignatureStatus>>>,
+        manager: Arc<PositionManager>,
+        position_id: Uuid,
+        seen_position_status: Mutex<Vec<PositionStatus>>,
+        history: Mutex<Option<SignatureStatus>>,
+    }
+
+    #[async_trait]
+    impl SignatureStatusSource for ScriptedStatuses {
+        async fn signature_status(&self, _signature: &str) -> AppResult<Option<SignatureStatus>> {
+            let position = self.manager.get_position(self.position_id).await.unwrap();
+            self.seen_position_status
+                .lock()
+                .unwrap()
+                .push(position.status);
+            Ok(self.statuses.lock().unwrap().pop_front().flatten())
+        }
+
+        async fn search_signature_history(
+            &self,
+            _signature: &str,
+        ) -> AppResult<Option<SignatureStatus>> {
+            Ok(self.history.lock().unwrap().clone())
+        }
+    }
+
+    async fn exiting_position(manager: &PositionManager) -> Uuid {
+        let position = manager
+            .open_position(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                "SettlingMint1111111111111".to_string(),
+                None,
+                0.1,
+                1000.0,
+                0.0001,
+                ExitConfig::default(),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(manager.transition_to_pending_exit(position.id).await);
+        position.id
+    }
+
+    fn scripted(
+        manager: &Arc<PositionManager>,
+        position_id: Uuid,
+        statuses: Vec<Option<SignatureStatus>>,
+    ) -> ScriptedStatuses {
+        ScriptedStatuses {
+            statuses: Mutex::new(statuses.into()),
+            manager: manager.clone(),
+            position_id,
+            seen_position_status: Mutex::new(Vec::new()),
+            history: Mutex::new(None),
+        }
+    }
+
+    fn finalized_config() -> ExitSettlementConfig {
+        ExitSettlementConfig {
+            commitment: ExitCommitment::Finalized,
+            timeout: Duration::from_secs(5),
+            poll_interval: Duration::from_millis(1),
+        }
+    }
+
+    #[test]
+    fn test_tracker_waits_for_target_commitment() {
+        let mut tracker = CommitmentTracker::new(ExitCommitment::Confirmed);
+        assert_eq!(tracker.observe(None), SettlementProgress::Settling(None));
+        assert_eq!(
+            tracker.observe(Some(&status("processed"))),
+            SettlementProgress::Settling(Some(ExitCommitment::Processed))
+        );
+        assert_eq!(
+            tracker.observe(Some(&status("confirmed"))),
+            SettlementProgress::Settled { slot: 42 }
+        );
+
+        let rooted = SignatureStatus {
+            confirmations: None,
+            confirmation_status: None,
+            ..status("processed")
+        };
+        let mut tracker = CommitmentTracker::new(ExitCommitment::Finalized);
+        assert_eq!(
+            tracker.observe(Some(&rooted)),
+            SettlementProgress::Settled { slot: 42 }
+        );
+
+        let failed = SignatureStatus {
+            err: Some(serde_json::json!({"InstructionError": [0, "Custom"]})),
+            ..status("confirmed")
+        };
+        let mut tracker = CommitmentTracker::new(ExitCommitment::Confirmed);
+        assert!(matches!(
+            tracker.observe(Some(&failed)),
+            SettlementProgress::Dropped(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_position_settles_before_close() {
+        let manager = Arc::new(PositionManager::new());
+        let position_id = exiting_position(&manager).await;
+        let source = scripted(
+            &manager,
+            position_id,
+            vec![
+                None,
+                Some(status("processed")),
+                Some(status("confirmed")),
+                Some(status("finalized")),
+            ],
+        );
+
+        let outcome = await_exit_commitment(
+            &source,
+            &manager,
+            position_id,
+            "ExitSignature1111111111111111",
+            &finalized_config(),
+        )
+        .await;
+
+        assert_eq!(outcome, ExitSettlementOutcome::Settled { slot: Some(42) });
+        assert_eq!(
+            *source.seen_position_status.lock().unwrap(),
+            vec![PositionStatus::Settling; 4]
+        );
+        assert_eq!(
+            manager.get_position(position_id).await.unwrap().status,
+            PositionStatus::Settling
+        );
+
+        manager
+            .close_position(position_id, 0.0002, 0.1, "TakeProfit", None, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            manager.get_position(position_id).await.unwrap().status,
+            PositionStatus::Closed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reorg_dropped_exit_is_requeued() {
+        let manager = Arc::new(PositionManager::new());
+        let position_id = exiting_position(&manager).await;
+        let source = scripted(
+            &manager,
+            position_id,
+            vec![Some(status("processed")), Some(status("confirmed")), None],
+        );
+
+        let outcome = await_exit_commitment(
+            &source,
+            &manager,
+            position_id,
+            "ExitSignature1111111111111111",
+            &finalized_config(),
+        )
+        .await;
+
+        let ExitSettlementOutcome::Requeued { reason } = outcome else {
+            panic!("expected the dropped exit to be re-queued");
+        };
+        assert!(reason.contains("reorg"));
+        assert_eq!(source.seen_position_status.lock().unwrap().len(), 3);
+        assert_eq!(
+            manager.get_position(position_id).await.unwrap().status,
+            PositionStatus::Open
+        );
+        assert_eq!(manager.drain_priority_exits().await, vec![position_id]);
+    }
+
+    #[tokio::test]
+    async fn test_exit_found_in_history_is_not_requeued() {
+        let manager = Arc::new(PositionManager::new());
+        let position_id = exiting_position(&manager).await;
+        let source = scripted(&manager, position_id, vec![None, None]);
+        *source.history.lock().unwrap() = Some(status("finalized"));
+        let config = ExitSettlementConfig {
+            timeout: Duration::from_millis(2),
+            ..finalized_config()
+        };
+
+        let outcome = await_exit_commitment(
+            &source,
+            &manager,
+            position_id,
+            "ExitSignature1111111111111111",
+            &config,
+        )
+        .await;
+
+        assert_eq!(outcome, ExitSettlementOutcome::Settled { slot: Some(42) });
+        assert_eq!(
+            manager.get_position(position_id).await.unwrap().status,
+            PositionStatus::Settling
+        );
+        assert!(manager.drain_priority_exits().await.is_empty());
+    }
+}
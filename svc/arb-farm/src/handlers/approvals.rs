@@ -4,11 +4,12 @@ use axum::{
     response::IntoResponse,
     Json,
 };
+use chrono::Utc;
 use uuid::Uuid;
 
 use crate::agents::start_autonomous_executor;
 use crate::models::{
-    ApproveRequest, ExecutionToggleRequest, HecateRecommendation, RejectRequest,
+    ApproveRequest, ExecutionSchedule, ExecutionToggleRequest, HecateRecommendation, RejectRequest,
     UpdateExecutionConfigRequest,
 };
 use crate::server::AppState;
@@ -54,7 +55,11 @@ pub async fn approve_approval(
     Path(id): Path<Uuid>,
     Json(request): Json<ApproveRequest>,
 ) -> impl IntoResponse {
-    match state.approval_manager.approve(id, request.notes).await {
+    match state
+        .approval_manager
+        .approve(id, request.notes, request.request)
+        .await
+    {
         Ok(approval) => (StatusCode::OK, Json(approval)).into_response(),
         Err(e) => (
             StatusCode::BAD_REQUEST,
@@ -353,14 +358,31 @@ pub async fn toggle_execution(
         tracing::warn!("Failed to persist execution toggle to DB: {}", e);
     }
 
-    // Also control the autonomous executor
-    if request.enabled {
+    apply_execution_state(&state, request.enabled).await;
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "enabled": config.auto_execution_enabled,
+            "manual_override": config.manual_override,
+            "message": if config.auto_execution_enabled {
+                "Auto-execution enabled"
+            } else {
+                "Auto-execution disabled"
+            }
+        })),
+    )
+}
+
+/// Starts or stops the autonomous executor and syncs every active strategy's
+/// auto-execute flag and execution mode. Shared by the manual toggle and the schedule.
+pub async fn apply_execution_state(state: &AppState, enabled: bool) {
+    if enabled {
         start_autonomous_executor(state.autonomous_executor.clone());
-        tracing::info!("Auto-execution enabled via global toggle - starting executor");
+        tracing::info!("Auto-execution enabled - starting executor");
     } else {
-        // Stop the autonomous executor when globally disabled
         state.autonomous_executor.stop().await;
-        tracing::info!("Auto-execution disabled via global toggle - stopping executor");
+        tracing::info!("Auto-execution disabled - stopping executor");
     }
 
     // Persist the toggle state for ALL active strategies
@@ -369,10 +391,10 @@ pub async fn toggle_execution(
     let strategies = state.strategy_engine.list_strategies().await;
     for strategy in strategies.iter().filter(|s| s.is_active) {
         let mut updated_params = strategy.risk_params.clone();
-        updated_params.auto_execute_enabled = request.enabled;
+        updated_params.auto_execute_enabled = enabled;
 
         // Set execution_mode based on toggle state
-        let new_execution_mode = if request.enabled {
+        let new_execution_mode = if enabled {
             "autonomous"
         } else {
             "agent_directed"
@@ -442,7 +464,7 @@ pub async fn toggle_execution(
         tracing::info!(
             strategy_id = %strategy.id,
             strategy_name = %strategy.name,
-            auto_execute_enabled = request.enabled,
+            auto_execute_enabled = enabled,
             execution_mode = new_execution_mode,
             "Persisted auto_execute_enabled and execution_mode settings"
         );
@@ -459,16 +481,67 @@ pub async fn toggle_execution(
             "✅ Post-toggle strategy state"
         );
     }
+}
 
+pub async fn get_execution_schedule(State(state): State<AppState>) -> impl IntoResponse {
+    let config = state.approval_manager.get_config().await;
+    let now = Utc::now();
     (
         StatusCode::OK,
         Json(serde_json::json!({
-            "enabled": config.auto_execution_enabled,
-            "message": if config.auto_execution_enabled {
-                "Auto-execution enabled"
-            } else {
-                "Auto-execution disabled"
+            "schedule": config.schedule,
+            "manual_override": config.manual_override,
+            "in_window": config.schedule.is_within_window(now),
+            "scheduled_state": config.scheduled_execution_state(now),
+            "local_time": config.schedule.local_time(now).to_string(),
+        })),
+    )
+}
+
+pub async fn update_execution_schedule(
+    State(state): State<AppState>,
+    Json(schedule): Json<ExecutionSchedule>,
+) -> impl IntoResponse {
+    let config = match state.approval_manager.set_schedule(schedule).await {
+        Ok(config) => config,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": e.to_string()})),
+            )
+                .into_response()
+        }
+    };
+
+    match serde_json::to_string(&config.schedule) {
+        Ok(json) => {
+            if let Err(e) = state.settings_repo.set("execution_schedule", &json).await {
+                tracing::warn!("Failed to persist execution schedule to DB: {}", e);
             }
+        }
+        Err(e) => tracing::warn!("Failed to serialize execution schedule: {}", e),
+    }
+
+    if let Some(enabled) = state.approval_manager.evaluate_schedule(Utc::now()).await {
+        apply_execution_state(&state, enabled).await;
+    }
+
+    (StatusCode::OK, Json(config.schedule)).into_response()
+}
+
+pub async fn clear_execution_override(State(state): State<AppState>) -> impl IntoResponse {
+    state.approval_manager.clear_manual_override().await;
+
+    if let Some(enabled) = state.approval_manager.evaluate_schedule(Utc::now()).await {
+        apply_execution_state(&state, enabled).await;
+    }
+
+    let config = state.approval_manager.get_config().await;
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "enabled": config.auto_execution_enabled,
+            "manual_override": config.manual_override,
         })),
     )
 }
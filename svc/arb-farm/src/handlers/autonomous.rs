@@ -1,7 +1,25 @@
-use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
 
+use crate::agents::autonomous_executor::{AutoExecutionFilter, AutoExecutionStatus};
 use crate::server::AppState;
 
+#[derive(Debug, Deserialize)]
+pub struct ExecutionsQuery {
+    pub status: Option<String>,
+    pub strategy_id: Option<Uuid>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub limit: Option<usize>,
+}
+
 pub async fn get_autonomous_executor_stats(State(state): State<AppState>) -> impl IntoResponse {
     let stats = state.autonomous_executor.get_stats().await;
 
@@ -17,8 +35,31 @@ pub async fn get_autonomous_executor_stats(State(state): State<AppState>) -> imp
     )
 }
 
-pub async fn list_autonomous_executions(State(state): State<AppState>) -> impl IntoResponse {
-    let executions = state.autonomous_executor.list_executions().await;
+pub async fn list_autonomous_executions(
+    State(state): State<AppState>,
+    Query(query): Query<ExecutionsQuery>,
+) -> impl IntoResponse {
+    let status = match query.status.as_deref().map(AutoExecutionStatus::parse) {
+        Some(None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": "Unknown status. Use: pending, building, signing, submitting, confirmed, failed",
+                })),
+            );
+        }
+        Some(status) => status,
+        None => None,
+    };
+
+    let filter = AutoExecutionFilter {
+        status,
+        strategy_id: query.strategy_id,
+        since: query.since,
+        until: query.until,
+        limit: query.limit.unwrap_or(100).min(1000),
+    };
+    let executions = state.autonomous_executor.list_executions(&filter).await;
 
     (
         StatusCode::OK,
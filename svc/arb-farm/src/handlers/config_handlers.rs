@@ -2,6 +2,7 @@ use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
 use serde::{Deserialize, Serialize};
 
 use crate::database::repositories::strategies::UpdateStrategyRecord;
+use crate::execution::risk::MAX_REENTRY_COOLDOWN_SECS;
 use crate::server::AppState;
 
 #[derive(Debug, Deserialize)]
@@ -18,6 +19,7 @@ pub struct SetCustomRiskRequest {
     pub take_profit_percent: Option<f64>,
     pub trailing_stop_percent: Option<f64>,
     pub time_limit_minutes: Option<u32>,
+    pub reentry_cooldown_secs: Option<u64>,
 }
 
 #[derive(Debug, Serialize)]
@@ -195,6 +197,7 @@ pub async fn get_risk_level(State(state): State<AppState>) -> impl IntoResponse
             "time_limit_minutes": risk_config.time_limit_minutes,
             "max_position_per_token_sol": risk_config.max_position_per_token_sol,
             "cooldown_after_loss_ms": risk_config.cooldown_after_loss_ms,
+            "reentry_cooldown_secs": risk_config.reentry_cooldown_secs,
             "volatility_scaling_enabled": risk_config.volatility_scaling_enabled,
             "auto_pause_on_drawdown": risk_config.auto_pause_on_drawdown,
         })),
@@ -299,6 +302,19 @@ pub async fn set_custom_risk(
         risk_config.time_limit_minutes = time_limit;
     }
 
+    if let Some(cooldown) = request.reentry_cooldown_secs {
+        if cooldown > MAX_REENTRY_COOLDOWN_SECS {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "success": false,
+                    "error": format!("reentry_cooldown_secs must be between 0 and {}", MAX_REENTRY_COOLDOWN_SECS)
+                })),
+            );
+        }
+        risk_config.reentry_cooldown_secs = cooldown;
+    }
+
     let max_pos = risk_config.max_position_sol;
     let daily_limit = risk_config.daily_loss_limit_sol;
     let max_dd = risk_config.max_drawdown_percent;
@@ -396,6 +412,7 @@ pub async fn set_custom_risk(
                 "trailing_stop_percent": final_config.trailing_stop_percent,
                 "time_limit_minutes": final_config.time_limit_minutes,
                 "cooldown_after_loss_ms": final_config.cooldown_after_loss_ms,
+                "reentry_cooldown_secs": final_config.reentry_cooldown_secs,
                 "volatility_scaling_enabled": final_config.volatility_scaling_enabled,
                 "auto_pause_on_drawdown": final_config.auto_pause_on_drawdown,
             }
@@ -1,6 +1,6 @@
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{header, StatusCode},
     response::IntoResponse,
     Json,
 };
@@ -9,10 +9,13 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
+use crate::consensus::report::ConsensusReport;
 use crate::consensus::{
-    format_edge_context, get_default_models, ConsensusResult, ModelVote, AVAILABLE_MODELS,
+    format_edge_context, get_default_models, ConsensusResult, ConsensusThrottle, ModelHealthStatus,
+    ModelVote, AVAILABLE_MODELS,
 };
 use crate::database::repositories::{ConsensusRecord, CreateConsensusRecord};
+use crate::error::AppError;
 use crate::server::AppState;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -96,6 +99,7 @@ fn consensus_record_to_history_entry(record: ConsensusRecord) -> ConsensusHistor
             agreement_score: record.agreement_score.unwrap_or(0.0),
             weighted_confidence: record.weighted_confidence.unwrap_or(0.0),
             reasoning_summary: record.reasoning_summary.unwrap_or_default(),
+            repaired_votes: model_votes.iter().filter(|v| v.json_repaired).count(),
             model_votes,
             total_latency_ms: record.total_latency_ms.unwrap_or(0) as u64,
         },
@@ -165,6 +169,137 @@ pub async fn get_consensus_detail(
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ExportConsensusQuery {
+    pub format: Option<String>,
+}
+
+pub async fn export_consensus(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ExportConsensusQuery>,
+) -> impl IntoResponse {
+    let format = query.format.as_deref().unwrap_or("json").to_lowercase();
+    if format != "json" && format != "markdown" && format != "md" {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "format must be json or markdown"})),
+        )
+            .into_response();
+    }
+
+    let wallet = state
+        .config
+        .wallet_address
+        .clone()
+        .unwrap_or_else(|| "default".to_string());
+
+    let report = match build_consensus_report(&state, &wallet, id).await {
+        Ok(Some(report)) => report.redacted(),
+        Ok(None) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({
+                    "error": "Consensus analysis not found",
+                    "consensus_id": id
+                })),
+            )
+                .into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to assemble consensus export: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+                .into_response();
+        }
+    };
+
+    if format == "json" {
+        return (StatusCode::OK, Json(serde_json::json!(report))).into_response();
+    }
+
+    (
+        StatusCode::OK,
+        [
+            (
+                axum::http::header::CONTENT_TYPE,
+                "text/markdown; charset=utf-8".to_string(),
+            ),
+            (
+                axum::http::header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"consensus-{}.md\"", id),
+            ),
+        ],
+        report.to_markdown(),
+    )
+        .into_response()
+}
+
+/// Looks the id up as a stored analysis engram first, then as a consensus decision record.
+async fn build_consensus_report(
+    state: &AppState,
+    wallet: &str,
+    id: Uuid,
+) -> Result<Option<ConsensusReport>, String> {
+    let analysis_key = crate::engrams::schemas::generate_consensus_analysis_key(&id);
+
+    let analysis = match state
+        .engrams_client
+        .get_engram_by_wallet_key(wallet, &analysis_key)
+        .await
+    {
+        Ok(engram) => engram.and_then(|e| {
+            serde_json::from_str::<crate::engrams::schemas::ConsensusAnalysis>(&e.content).ok()
+        }),
+        Err(e) => {
+            tracing::warn!("Failed to fetch analysis engram {}: {}", analysis_key, e);
+            None
+        }
+    };
+
+    let transcripts: Vec<_> = state
+        .engrams_client
+        .get_conversations(wallet, Some(100))
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|c| c.session_id == id || c.outcome.engram_refs.contains(&analysis_key))
+        .collect();
+
+    if let Some(analysis) = analysis {
+        let mut recommendations = Vec::with_capacity(analysis.recommendation_ids.len());
+        for rec_id in &analysis.recommendation_ids {
+            let key = crate::engrams::schemas::generate_recommendation_key(rec_id);
+            match state
+                .engrams_client
+                .get_engram_by_wallet_key(wallet, &key)
+                .await
+            {
+                Ok(Some(engram)) => {
+                    if let Ok(rec) = serde_json::from_str(&engram.content) {
+                        recommendations.push(rec);
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!("Failed to fetch recommendation {}: {}", rec_id, e),
+            }
+        }
+        return Ok(Some(ConsensusReport::from_analysis(
+            analysis,
+            recommendations,
+            transcripts,
+        )));
+    }
+
+    match state.consensus_repo.get_by_id(id).await {
+        Ok(Some(record)) => Ok(Some(ConsensusReport::from_decision(&record, transcripts))),
+        Ok(None) => Ok(None),
+        Err(e) => Err(format!("Database error: {}", e)),
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct RequestConsensusRequest {
     pub edge_id: Option<Uuid>,
@@ -198,6 +333,19 @@ pub struct ModelVoteResponse {
     pub latency_ms: u64,
 }
 
+fn throttled_response(throttle: &ConsensusThrottle) -> axum::response::Response {
+    (
+        StatusCode::TOO_MANY_REQUESTS,
+        [(header::RETRY_AFTER, throttle.retry_after_secs().to_string())],
+        Json(serde_json::json!({
+            "error": throttle.message(),
+            "retry_after_secs": throttle.retry_after_secs(),
+            "throttle": throttle,
+        })),
+    )
+        .into_response()
+}
+
 pub async fn request_consensus(
     State(state): State<AppState>,
     Json(request): Json<RequestConsensusRequest>,
@@ -212,6 +360,12 @@ pub async fn request_consensus(
             .into_response();
     }
 
+    let limiter_config = state.consensus_config.read().await.clone();
+    let _permit = match state.consensus_limiter.try_acquire_manual(&limiter_config) {
+        Ok(permit) => permit,
+        Err(throttle) => return throttled_response(&throttle),
+    };
+
     let edge_id = request.edge_id.unwrap_or_else(Uuid::new_v4);
 
     let edge_context = format_edge_context(
@@ -230,8 +384,12 @@ pub async fn request_consensus(
     {
         Ok(result) => result,
         Err(e) => {
+            let status = match e {
+                AppError::Unauthorized(_) => StatusCode::FORBIDDEN,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
             return (
-                StatusCode::INTERNAL_SERVER_ERROR,
+                status,
                 Json(serde_json::json!({
                     "error": format!("Consensus request failed: {}", e)
                 })),
@@ -307,6 +465,7 @@ pub async fn request_consensus(
 pub struct AvailableModelsResponse {
     pub models: Vec<ModelInfo>,
     pub default_models: Vec<String>,
+    pub model_health: Vec<ModelHealthStatus>,
 }
 
 #[derive(Debug, Serialize)]
@@ -316,7 +475,7 @@ pub struct ModelInfo {
     pub provider: String,
 }
 
-pub async fn list_available_models(State(_state): State<AppState>) -> impl IntoResponse {
+pub async fn list_available_models(State(state): State<AppState>) -> impl IntoResponse {
     let models: Vec<ModelInfo> = AVAILABLE_MODELS
         .iter()
         .map(|(id, display_name, _weight)| {
@@ -340,6 +499,7 @@ pub async fn list_available_models(State(_state): State<AppState>) -> impl IntoR
         Json(AvailableModelsResponse {
             models,
             default_models: get_default_models(),
+            model_health: state.consensus_engine.model_health().snapshot(),
         }),
     )
 }
@@ -401,6 +561,17 @@ pub struct ConsensusConfigResponse {
     pub available_models: Vec<crate::consensus::ConsensusModelConfig>,
 }
 
+/// The recommendation apply path, the analysis scheduler and the request limiter read
+/// the shared engine config, so keep their gates in step with `/consensus/config`.
+async fn sync_action_gate(state: &AppState, config: &crate::consensus::ConsensusConfig) {
+    let mut shared = state.consensus_config.write().await;
+    shared.auto_apply_recommendations = config.auto_apply_recommendations;
+    shared.min_action_confidence = config.min_action_confidence;
+    shared.min_trades_for_analysis = config.min_trades_for_analysis;
+    shared.max_concurrent_requests = config.max_concurrent_requests;
+    shared.max_manual_requests_per_hour = config.max_manual_requests_per_hour;
+}
+
 pub async fn get_consensus_config(State(state): State<AppState>) -> impl IntoResponse {
     let config = CONSENSUS_CONFIG.read().await.clone();
     let wallet = state.config.wallet_address.clone().unwrap_or_default();
@@ -435,10 +606,24 @@ pub async fn update_consensus_config(
     if let Some(auto_apply) = request.auto_apply_recommendations {
         config.auto_apply_recommendations = auto_apply;
     }
+    if let Some(floor) = request.min_action_confidence {
+        config.min_action_confidence = floor.clamp(0.0, 1.0);
+    }
+    if let Some(min_trades) = request.min_trades_for_analysis {
+        config.min_trades_for_analysis = min_trades;
+    }
+    if let Some(max_concurrent) = request.max_concurrent_requests {
+        config.max_concurrent_requests = max_concurrent.max(1);
+    }
+    if let Some(per_hour) = request.max_manual_requests_per_hour {
+        config.max_manual_requests_per_hour = per_hour;
+    }
     if let Some(interval) = request.review_interval_hours {
         config.review_interval_hours = interval;
     }
 
+    sync_action_gate(&state, &config).await;
+
     let wallet = state.config.wallet_address.clone().unwrap_or_default();
     let is_dev = crate::consensus::is_dev_wallet(&wallet);
 
@@ -459,6 +644,7 @@ pub async fn reset_consensus_config(State(state): State<AppState>) -> impl IntoR
     let mut config = CONSENSUS_CONFIG.write().await;
     *config = crate::consensus::ConsensusConfig::default();
     config.models = models;
+    sync_action_gate(&state, &config).await;
 
     let is_dev = crate::consensus::is_dev_wallet(&wallet);
 
@@ -750,6 +936,7 @@ pub async fn list_engrams(
         tags,
         limit: query.limit.or(Some(50)),
         offset: None,
+        created_after: None,
     };
 
     match state.engrams_client.search_engrams(search).await {
@@ -804,6 +991,19 @@ pub async fn get_engram_detail(
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct ModelAccuracyResponse {
+    pub models: Vec<crate::consensus::ModelAccuracy>,
+    pub window_hours: i64,
+}
+
+pub async fn get_model_accuracy(State(state): State<AppState>) -> impl IntoResponse {
+    Json(ModelAccuracyResponse {
+        models: state.model_accuracy.snapshot().await,
+        window_hours: crate::consensus::outcomes::get_outcome_window_hours(),
+    })
+}
+
 pub async fn get_learning_summary(State(state): State<AppState>) -> impl IntoResponse {
     let wallet = state
         .config
@@ -1057,30 +1257,21 @@ pub async fn get_analysis_summary(State(state): State<AppState>) -> impl IntoRes
     let is_dev = crate::consensus::is_dev_wallet(&wallet);
     let config = CONSENSUS_CONFIG.read().await.clone();
 
-    let trade_analyses = state
+    let summary = state
         .engrams_client
-        .get_trade_analyses(&wallet, Some(10))
+        .get_analysis_summary(&wallet)
         .await
-        .unwrap_or_default();
-    let pattern_summary = state
-        .engrams_client
-        .get_latest_pattern_summary(&wallet)
-        .await
-        .ok()
-        .flatten();
-    let total_analyses = state
-        .engrams_client
-        .get_trade_analyses(&wallet, Some(1000))
-        .await
-        .map(|a| a.len())
-        .unwrap_or(0);
+        .unwrap_or_else(|e| {
+            tracing::error!("Failed to build analysis summary: {}", e);
+            Default::default()
+        });
 
     (
         StatusCode::OK,
         Json(AnalysisSummaryResponse {
-            trade_analyses_count: total_analyses,
-            latest_pattern_summary: pattern_summary,
-            recent_trade_analyses: trade_analyses,
+            trade_analyses_count: summary.trade_analyses_count,
+            latest_pattern_summary: summary.latest_pattern_summary,
+            recent_trade_analyses: summary.recent_trade_analyses,
             config,
             is_dev_wallet: is_dev,
         }),
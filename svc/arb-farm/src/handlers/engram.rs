@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::agents::EngramHarvester;
 use crate::models::{
     ArbEngram, AvoidanceSeverity, CreateEngramRequest, EngramQuery, EngramType, PatternMatch,
-    PatternMatchRequest,
+    PatternMatchRequest, StrategySuggestion, StrategySuggestionThresholds,
 };
 use crate::server::AppState;
 
@@ -106,9 +106,17 @@ pub struct HarvesterStatsResponse {
     pub engrams_by_type: std::collections::HashMap<String, u64>,
     pub patterns_matched: u64,
     pub avoidances_created: u64,
+    pub strategies_suggested: u64,
     pub last_harvest_at: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct StrategySuggestionsResponse {
+    pub success: bool,
+    pub suggestions: Vec<StrategySuggestion>,
+    pub count: usize,
+}
+
 pub async fn create_engram(
     State(_state): State<AppState>,
     Json(request): Json<CreateEngramRequest>,
@@ -335,10 +343,33 @@ pub async fn get_harvester_stats(
         engrams_by_type: stats.engrams_by_type,
         patterns_matched: stats.patterns_matched,
         avoidances_created: stats.avoidances_created,
+        strategies_suggested: stats.strategies_suggested,
         last_harvest_at: stats.last_harvest_at.map(|t| t.to_rfc3339()),
     }))
 }
 
+pub async fn suggest_strategies(
+    State(_state): State<AppState>,
+    Json(thresholds): Json<StrategySuggestionThresholds>,
+) -> Result<Json<StrategySuggestionsResponse>, ErrorResponse> {
+    let harvester = get_harvester_clone()?;
+
+    match harvester
+        .suggest_strategies_from_patterns(&thresholds)
+        .await
+    {
+        Ok(suggestions) => Ok(Json(StrategySuggestionsResponse {
+            success: true,
+            count: suggestions.len(),
+            suggestions,
+        })),
+        Err(e) => Err(json_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            &e.to_string(),
+        )),
+    }
+}
+
 fn parse_engram_type(s: &str) -> Option<EngramType> {
     match s.to_lowercase().as_str() {
         "edge_pattern" => Some(EngramType::EdgePattern),
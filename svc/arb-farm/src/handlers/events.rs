@@ -0,0 +1,61 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::error::{AppError, AppResult};
+use crate::events::EventQuery;
+use crate::server::AppState;
+
+const MAX_EVENT_QUERY_LIMIT: usize = 1000;
+
+#[derive(Debug, Deserialize)]
+pub struct EventsQuery {
+    /// Comma-separated topics or `prefix.*` patterns.
+    pub topics: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub limit: Option<usize>,
+}
+
+pub async fn query_events(
+    State(state): State<AppState>,
+    Query(query): Query<EventsQuery>,
+) -> AppResult<Json<serde_json::Value>> {
+    if let (Some(from), Some(to)) = (query.from, query.to) {
+        if from >= to {
+            return Err(AppError::Validation(
+                "`from` must be before `to`".to_string(),
+            ));
+        }
+    }
+
+    let topics: Vec<String> = query
+        .topics
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|t| !t.is_empty() && *t != "*")
+        .map(String::from)
+        .collect();
+
+    let event_query = EventQuery {
+        topics,
+        from: query.from,
+        to: query.to,
+        limit: query.limit.unwrap_or(100).min(MAX_EVENT_QUERY_LIMIT),
+    };
+    let events = state.event_sink.query(&event_query).await?;
+
+    Ok(Json(serde_json::json!({
+        "events": events,
+        "count": events.len(),
+    })))
+}
+
+pub async fn get_event_log_stats(State(state): State<AppState>) -> Json<serde_json::Value> {
+    Json(serde_json::json!(state.event_sink.stats().await))
+}
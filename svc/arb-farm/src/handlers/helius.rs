@@ -5,6 +5,7 @@ use axum::{
 use serde::{Deserialize, Serialize};
 
 use crate::error::AppResult;
+use crate::helius::das::{AssetKind, AssetSort, OwnedAsset, OwnedAssetFilter};
 use crate::helius::laserstream::LaserStreamStatus;
 use crate::helius::priority_fee::PriorityFeeResponse;
 use crate::helius::types::{HeliusConfig, HeliusStatus, SenderStats, TokenMetadata};
@@ -187,11 +188,15 @@ pub struct DasOwnerQuery {
     pub owner: String,
     pub page: Option<u32>,
     pub limit: Option<u32>,
+    pub kind: Option<AssetKind>,
+    pub collection: Option<String>,
+    pub min_value_usd: Option<f64>,
+    pub sort: Option<AssetSort>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct DasAssetsResponse {
-    pub assets: Vec<TokenMetadata>,
+    pub assets: Vec<OwnedAsset>,
     pub total: usize,
 }
 
@@ -202,9 +207,16 @@ pub async fn das_assets_by_owner(
     let page = query.page.unwrap_or(1);
     let limit = query.limit.unwrap_or(20);
 
+    let filter = OwnedAssetFilter {
+        kind: query.kind.unwrap_or_default(),
+        collection: query.collection,
+        min_value_usd: query.min_value_usd,
+        sort: query.sort,
+    };
+
     let assets = state
         .helius_das
-        .get_assets_by_owner(&query.owner, page, limit)
+        .get_owned_assets(&query.owner, page, limit, &filter)
         .await?;
 
     let total = assets.len();
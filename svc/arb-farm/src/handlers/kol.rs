@@ -19,7 +19,7 @@ use crate::models::{
     KolStats, KolTrade, KolTradeType, TrustScoreBreakdown, UpdateKolRequest,
 };
 use crate::server::AppState;
-use crate::webhooks::helius::{TransactionType, WebhookConfig, WebhookType};
+use crate::webhooks::helius::{WebhookConfig, WebhookType};
 
 #[derive(Debug, Deserialize)]
 pub struct ListKolQuery {
@@ -160,7 +160,7 @@ pub async fn add_kol(
                     let config = WebhookConfig {
                         webhook_url,
                         account_addresses: vec![wallet.clone()],
-                        transaction_types: vec![TransactionType::Swap],
+                        transaction_types: state.config.webhook_transaction_types(),
                         webhook_type: WebhookType::Enhanced,
                         auth_header: None,
                     };
@@ -898,6 +898,41 @@ pub async fn get_copy_stats(State(state): State<AppState>) -> impl IntoResponse
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct CopyBudgetResponse {
+    pub budget_sol: Option<f64>,
+    pub kols: Vec<crate::execution::KolBudgetUsage>,
+}
+
+pub async fn get_copy_budgets(State(state): State<AppState>) -> impl IntoResponse {
+    let budget_sol = state.copy_executor.get_config().await.kol_budget_sol;
+    let kols = state.copy_executor.kol_budget_usage().await;
+    (
+        StatusCode::OK,
+        Json(CopyBudgetResponse { budget_sol, kols }),
+    )
+}
+
+pub async fn reset_copy_budget(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match state.copy_executor.reset_kol_budget(id).await {
+        Ok(usage) => {
+            info!(kol_id = %id, "Copy budget reset");
+            (StatusCode::OK, Json(usage)).into_response()
+        }
+        Err(e) => {
+            warn!(kol_id = %id, "Failed to reset copy budget: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e.to_string()})),
+            )
+                .into_response()
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct DiscoveryStatusResponse {
     pub is_running: bool,
@@ -1077,7 +1112,7 @@ pub async fn promote_discovered_kol(
                         let config = WebhookConfig {
                             webhook_url,
                             account_addresses: vec![discovered_kol.wallet_address.clone()],
-                            transaction_types: vec![TransactionType::Swap],
+                            transaction_types: state.config.webhook_transaction_types(),
                             webhook_type: WebhookType::Enhanced,
                             auth_header: state.config.helius_webhook_auth_token.clone(),
                         };
@@ -5,6 +5,7 @@ pub mod consensus;
 pub mod curves;
 pub mod edges;
 pub mod engram;
+pub mod events;
 pub mod health;
 pub mod helius;
 pub mod kol;
@@ -18,5 +19,6 @@ pub mod strategies;
 pub mod swarm;
 pub mod threat;
 pub mod trades;
+pub mod venues;
 pub mod wallet;
 pub mod webhooks;
@@ -1,7 +1,8 @@
+use std::future::Future;
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     Json,
 };
@@ -18,8 +19,9 @@ use crate::engrams::schemas::{
 use crate::error::AppError;
 use crate::events::{topics, AgentType, ArbEvent, EventSource};
 use crate::execution::{
-    BaseCurrency, ExitConfig, ExitReason, OpenPosition, PositionStatus, ReconciliationResult,
-    WalletTokenHolding,
+    BaseCurrency, ExitConfig, ExitConfigTemplate, ExitReason, MomentumFadeTakeProfit, OpenPosition,
+    PnlCurrency, PositionAnnotations, PositionStatus, ReconciliationResult, ScaleInConfig,
+    TokenCategory, WalletTokenHolding, MAX_EXIT_SLIPPAGE_BPS,
 };
 use crate::server::AppState;
 
@@ -128,9 +130,27 @@ pub struct UpdateExitConfigRequest {
     pub trailing_stop_percent: Option<f64>,
     #[serde(default)]
     pub time_limit_minutes: Option<u32>,
+    /// Per-position exit slippage override (dead tokens need a far wider tolerance)
+    #[serde(default)]
+    pub exit_slippage_bps: Option<u16>,
+    /// Partial profit-take on a strong→weak momentum fade
+    #[serde(default)]
+    pub momentum_fade_partial: Option<MomentumFadeTakeProfit>,
+    /// Stop the remainder at entry + fees once a take-profit tier has sold
+    #[serde(default)]
+    pub move_stop_to_breakeven_after_tp: Option<bool>,
+    /// Skip the time limit exit while the position is in profit after fees
+    #[serde(default)]
+    pub time_limit_only_if_unprofitable: Option<bool>,
+    /// Measure exit thresholds from the fee-aware break-even price instead of entry
+    #[serde(default)]
+    pub exits_from_break_even: Option<bool>,
     /// Use a preset config: "curve", "curve_conservative", "default"
     #[serde(default)]
     pub preset: Option<String>,
+    /// Apply a saved exit-config template by name (takes precedence over `preset`)
+    #[serde(default)]
+    pub template: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -147,6 +167,7 @@ fn exit_config_to_summary(config: &ExitConfig) -> ExitConfigSummary {
         take_profit_percent: config.take_profit_percent,
         trailing_stop_percent: config.trailing_stop_percent,
         time_limit_minutes: config.time_limit_minutes,
+        exit_slippage_bps: config.exit_slippage_bps,
     }
 }
 
@@ -163,8 +184,17 @@ pub async fn update_position_exit_config(
 
     let old_config = exit_config_to_summary(&current_position.exit_config);
 
-    // Build new config from preset or individual fields
-    let new_config = if let Some(preset) = &request.preset {
+    // Build new config from template, preset or individual fields
+    let new_config = if let Some(name) = &request.template {
+        state
+            .position_manager
+            .get_exit_template(name)
+            .await
+            .ok_or_else(|| {
+                AppError::NotFound(format!("Exit config template '{}' not found", name))
+            })?
+            .config_for(current_position.exit_config.base_currency)
+    } else if let Some(preset) = &request.preset {
         match preset.as_str() {
             "curve" => ExitConfig::for_curve_bonding(),
             "curve_conservative" => ExitConfig::for_curve_bonding_conservative(),
@@ -191,6 +221,30 @@ pub async fn update_position_exit_config(
         if let Some(tl) = request.time_limit_minutes {
             config.time_limit_minutes = Some(tl);
         }
+        if let Some(bps) = request.exit_slippage_bps {
+            if bps == 0 || bps > MAX_EXIT_SLIPPAGE_BPS {
+                return Err(AppError::BadRequest(format!(
+                    "exit_slippage_bps must be between 1 and {}",
+                    MAX_EXIT_SLIPPAGE_BPS
+                )));
+            }
+            config.exit_slippage_bps = Some(bps);
+        }
+        if let Some(fade_tp) = request.momentum_fade_partial {
+            fade_tp
+                .validate()
+                .map_err(|e| AppError::BadRequest(e.to_string()))?;
+            config.momentum_fade_partial = Some(fade_tp);
+        }
+        if let Some(enabled) = request.move_stop_to_breakeven_after_tp {
+            config.move_stop_to_breakeven_after_tp = enabled;
+        }
+        if let Some(enabled) = request.time_limit_only_if_unprofitable {
+            config.time_limit_only_if_unprofitable = enabled;
+        }
+        if let Some(enabled) = request.exits_from_break_even {
+            config.exits_from_break_even = enabled;
+        }
         config
     };
 
@@ -220,12 +274,28 @@ pub async fn update_all_positions_exit_config(
     State(state): State<AppState>,
     Json(request): Json<UpdateExitConfigRequest>,
 ) -> Result<Json<serde_json::Value>, AppError> {
-    let preset = request.preset.as_deref().unwrap_or("curve");
+    let template = match &request.template {
+        Some(name) => Some(
+            state
+                .position_manager
+                .get_exit_template(name)
+                .await
+                .ok_or_else(|| {
+                    AppError::NotFound(format!("Exit config template '{}' not found", name))
+                })?,
+        ),
+        None => None,
+    };
+    let preset = match &template {
+        Some(t) => t.name.as_str(),
+        None => request.preset.as_deref().unwrap_or("curve"),
+    };
 
-    let new_config = match preset {
-        "curve" => ExitConfig::for_curve_bonding(),
-        "curve_conservative" => ExitConfig::for_curve_bonding_conservative(),
-        "default" => ExitConfig::default(),
+    let new_config = match (&template, preset) {
+        (Some(t), _) => t.config.clone(),
+        (None, "curve") => ExitConfig::for_curve_bonding(),
+        (None, "curve_conservative") => ExitConfig::for_curve_bonding_conservative(),
+        (None, "default") => ExitConfig::default(),
         _ => {
             return Err(AppError::BadRequest(format!(
                 "Unknown preset '{}'. Use: curve, curve_conservative, default",
@@ -239,9 +309,13 @@ pub async fn update_all_positions_exit_config(
     let mut failed = 0;
 
     for position in &positions {
+        let config = match &template {
+            Some(t) => t.config_for(position.exit_config.base_currency),
+            None => new_config.clone(),
+        };
         match state
             .position_manager
-            .update_position_exit_config(position.id, new_config.clone())
+            .update_position_exit_config(position.id, config)
             .await
         {
             Ok(_) => updated += 1,
@@ -277,6 +351,50 @@ pub async fn update_all_positions_exit_config(
     })))
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SaveExitTemplateRequest {
+    #[serde(default)]
+    pub description: Option<String>,
+    pub config: ExitConfig,
+}
+
+pub async fn list_exit_templates(State(state): State<AppState>) -> Json<Vec<ExitConfigTemplate>> {
+    Json(state.position_manager.list_exit_templates().await)
+}
+
+pub async fn save_exit_template(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    Json(request): Json<SaveExitTemplateRequest>,
+) -> Result<Json<ExitConfigTemplate>, AppError> {
+    let template = state
+        .position_manager
+        .save_exit_template(ExitConfigTemplate::new(
+            name,
+            request.description,
+            request.config,
+        ))
+        .await?;
+    Ok(Json(template))
+}
+
+pub async fn delete_exit_template(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let removed = state.position_manager.delete_exit_template(&name).await?;
+    if !removed {
+        return Err(AppError::NotFound(format!(
+            "Exit config template '{}' not found",
+            name
+        )));
+    }
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "name": name,
+    })))
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ToggleAutoExitRequest {
     pub enabled: bool,
@@ -324,18 +442,80 @@ pub async fn toggle_position_auto_exit(
     }))
 }
 
+/// Clear a position's own auto-exit toggle so its category setting applies again
+pub async fn clear_position_auto_exit_override(
+    State(state): State<AppState>,
+    Path(position_id): Path<Uuid>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let updated = state
+        .position_manager
+        .clear_position_auto_exit_override(position_id)
+        .await?;
+    let effective = state.position_manager.is_auto_exit_enabled(&updated).await;
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "position_id": position_id,
+        "category": TokenCategory::of(&updated),
+        "auto_exit_enabled": effective,
+    })))
+}
+
 /// Get count of positions with auto-exit enabled/disabled
 pub async fn get_auto_exit_stats(
     State(state): State<AppState>,
 ) -> Result<Json<serde_json::Value>, AppError> {
     let positions = state.position_manager.get_open_positions().await;
-    let auto_enabled = positions.iter().filter(|p| p.auto_exit_enabled).count();
+    let policy = state.position_manager.get_auto_exit_policy().await;
+    let auto_enabled = positions.iter().filter(|p| policy.is_enabled(p)).count();
     let manual = positions.len() - auto_enabled;
 
     Ok(Json(serde_json::json!({
         "total_positions": positions.len(),
         "auto_exit_enabled": auto_enabled,
         "manual_mode": manual,
+        "categories": policy.categories,
+    })))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetCategoryAutoExitRequest {
+    /// `null` clears the category setting.
+    pub enabled: Option<bool>,
+}
+
+pub async fn set_category_auto_exit(
+    State(state): State<AppState>,
+    Path(category): Path<String>,
+    Json(request): Json<SetCategoryAutoExitRequest>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let category = TokenCategory::parse(&category).ok_or_else(|| {
+        AppError::BadRequest(format!(
+            "Unknown token category '{}' (expected bonding_curve or graduated)",
+            category
+        ))
+    })?;
+
+    state
+        .position_manager
+        .set_category_auto_exit(category, request.enabled)
+        .await;
+
+    let policy = state.position_manager.get_auto_exit_policy().await;
+    let affected = state
+        .position_manager
+        .get_open_positions()
+        .await
+        .iter()
+        .filter(|p| TokenCategory::of(p) == category && !p.auto_exit_overridden)
+        .count();
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "category": category,
+        "enabled": request.enabled,
+        "affected_positions": affected,
+        "categories": policy.categories,
     })))
 }
 
@@ -360,19 +540,28 @@ pub struct EmergencyExitResult {
 pub async fn emergency_close_all(
     State(state): State<AppState>,
 ) -> Result<Json<EmergencyExitResponse>, AppError> {
+    Ok(Json(exit_all_positions(&state, |_, _, _| async {}).await))
+}
+
+/// Force-sells every open position, calling `on_exit(done, total, result)` after each one.
+pub async fn exit_all_positions<F, Fut>(state: &AppState, mut on_exit: F) -> EmergencyExitResponse
+where
+    F: FnMut(usize, usize, &EmergencyExitResult) -> Fut,
+    Fut: Future<Output = ()>,
+{
     info!("🚨 EMERGENCY EXIT ALL - Force selling all positions at market!");
 
     let positions = state.position_manager.get_open_positions().await;
     let total = positions.len();
 
     if total == 0 {
-        return Ok(Json(EmergencyExitResponse {
+        return EmergencyExitResponse {
             positions_exited: 0,
             positions_failed: 0,
             total_positions: 0,
             message: "No open positions to exit".to_string(),
             results: vec![],
-        }));
+        };
     }
 
     let mut results = Vec::new();
@@ -392,7 +581,7 @@ pub async fn emergency_close_all(
 
         match state
             .position_monitor
-            .trigger_manual_exit(position.id, 100.0)
+            .trigger_emergency_exit(position.id)
             .await
         {
             Ok(_) => {
@@ -424,6 +613,10 @@ pub async fn emergency_close_all(
                 });
             }
         }
+
+        if let Some(result) = results.last() {
+            on_exit(results.len(), total, result).await;
+        }
     }
 
     let message = if failed == 0 {
@@ -437,13 +630,13 @@ pub async fn emergency_close_all(
 
     info!("{}", message);
 
-    Ok(Json(EmergencyExitResponse {
+    EmergencyExitResponse {
         positions_exited: exited,
         positions_failed: failed,
         total_positions: total,
         message,
         results,
-    }))
+    }
 }
 
 /// Force clear all positions from tracking (without selling)
@@ -829,6 +1022,7 @@ pub async fn sell_all_wallet_tokens(
                             topics::position::CLOSED,
                             serde_json::json!({
                                 "position_id": position.id,
+                                "signal_source": position.signal_source,
                                 "token_mint": position.token_mint,
                                 "token_symbol": position.token_symbol,
                                 "exit_reason": "ManualSellAll",
@@ -1472,6 +1666,28 @@ pub struct PnLSummary {
     pub active_strategies: Vec<ActiveStrategy>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pnl_reset_at: Option<String>,
+    pub currency: PnlCurrency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usd: Option<UsdPnLSummary>,
+}
+
+/// USD view of the summary. Closed trades use the SOL/USD rates stored at entry
+/// and exit; open positions use the current rate.
+#[derive(Debug, Serialize)]
+pub struct UsdPnLSummary {
+    pub sol_usd_rate: Option<f64>,
+    pub today_usd: f64,
+    pub week_usd: f64,
+    pub total_usd: f64,
+    pub unrealized_usd: f64,
+    /// Closed trades with no stored SOL/USD rate, excluded from the USD totals
+    pub unpriced_trades: u32,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct PnLSummaryQuery {
+    #[serde(default)]
+    pub currency: PnlCurrency,
 }
 
 #[derive(Debug, Serialize)]
@@ -1499,6 +1715,8 @@ pub struct RecentTradeInfo {
     pub exit_time: Option<String>,
     pub entry_tx_signature: Option<String>,
     pub exit_tx_signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pnl_usd: Option<f64>,
 }
 
 #[derive(Debug, Serialize)]
@@ -1507,6 +1725,8 @@ pub struct ActiveStrategy {
     pub mint: String,
     pub entry_sol: f64,
     pub current_pnl_percent: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_pnl_usd: Option<f64>,
     pub stop_loss: Option<f64>,
     pub take_profit: Option<f64>,
     pub trailing_stop: Option<f64>,
@@ -1514,9 +1734,19 @@ pub struct ActiveStrategy {
     pub hold_time_mins: i64,
 }
 
-pub async fn get_pnl_summary(State(state): State<AppState>) -> Result<Json<PnLSummary>, AppError> {
+pub async fn get_pnl_summary(
+    State(state): State<AppState>,
+    Query(query): Query<PnLSummaryQuery>,
+) -> Result<Json<PnLSummary>, AppError> {
     use crate::database::PositionRepository;
 
+    let in_usd = query.currency == PnlCurrency::Usd;
+    let sol_usd_rate = if in_usd {
+        state.position_manager.sol_usd_rate().await
+    } else {
+        None
+    };
+
     let repo = PositionRepository::new(state.db_pool.clone());
 
     let reset_at = state
@@ -1536,6 +1766,17 @@ pub async fn get_pnl_summary(State(state): State<AppState>) -> Result<Json<PnLSu
         }
     };
     let recent = repo.get_recent_trades(20).await.unwrap_or_default();
+    let usd_stats = if in_usd {
+        match repo.get_usd_pnl_stats_since(reset_at).await {
+            Ok(stats) => Some(stats),
+            Err(e) => {
+                tracing::warn!("Failed to get USD PnL stats from DB: {}", e);
+                Some(Default::default())
+            }
+        }
+    } else {
+        None
+    };
 
     let manager_stats = state.position_manager.get_stats().await;
 
@@ -1586,16 +1827,35 @@ pub async fn get_pnl_summary(State(state): State<AppState>) -> Result<Json<PnLSu
                 exit_time: t.time.map(|dt| dt.to_rfc3339()),
                 entry_tx_signature: t.entry_tx_signature,
                 exit_tx_signature: t.exit_tx_signature,
+                pnl_usd: if in_usd {
+                    t.base_currency.pnl_usd(
+                        t.entry_sol,
+                        t.pnl,
+                        t.entry_sol_usd_rate,
+                        t.exit_sol_usd_rate,
+                    )
+                } else {
+                    None
+                },
             }
         })
         .collect();
 
     // Get active positions with their strategies
     let open_positions = state.position_manager.get_open_positions().await;
+    let unrealized_usd: f64 = open_positions
+        .iter()
+        .filter_map(|p| p.unrealized_pnl_usd(sol_usd_rate))
+        .sum();
     let mut active_strategies: Vec<ActiveStrategy> = open_positions
         .into_iter()
         .map(|p| {
             let hold_time_mins = (chrono::Utc::now() - p.entry_time).num_minutes();
+            let current_pnl_usd = if in_usd {
+                p.unrealized_pnl_usd(sol_usd_rate)
+            } else {
+                None
+            };
             ActiveStrategy {
                 symbol: p
                     .token_symbol
@@ -1603,6 +1863,7 @@ pub async fn get_pnl_summary(State(state): State<AppState>) -> Result<Json<PnLSu
                 mint: p.token_mint[..12].to_string(),
                 entry_sol: p.entry_amount_base,
                 current_pnl_percent: p.unrealized_pnl_percent,
+                current_pnl_usd,
                 stop_loss: p.exit_config.stop_loss_percent,
                 take_profit: p.exit_config.take_profit_percent,
                 trailing_stop: p.exit_config.trailing_stop_percent,
@@ -1649,6 +1910,15 @@ pub async fn get_pnl_summary(State(state): State<AppState>) -> Result<Json<PnLSu
         recent_trades,
         active_strategies,
         pnl_reset_at: reset_at.map(|dt| dt.to_rfc3339()),
+        currency: query.currency,
+        usd: usd_stats.map(|stats| UsdPnLSummary {
+            sol_usd_rate,
+            today_usd: stats.today_pnl_usd,
+            week_usd: stats.week_pnl_usd,
+            total_usd: stats.total_pnl_usd,
+            unrealized_usd,
+            unpriced_trades: stats.unpriced_trades,
+        }),
     }))
 }
 
@@ -1957,6 +2227,8 @@ pub struct PositionHistoryItem {
     pub exit_config: ExitConfigSummary,
     pub entry_time: chrono::DateTime<chrono::Utc>,
     pub exit_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub notes: Option<String>,
+    pub tags: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -1965,6 +2237,7 @@ pub struct ExitConfigSummary {
     pub take_profit_percent: Option<f64>,
     pub trailing_stop_percent: Option<f64>,
     pub time_limit_minutes: Option<u32>,
+    pub exit_slippage_bps: Option<u16>,
 }
 
 #[derive(Debug, Serialize)]
@@ -1973,45 +2246,79 @@ pub struct PositionHistoryResponse {
     pub total_count: usize,
 }
 
-pub async fn get_position_history(
-    State(state): State<AppState>,
-) -> Result<Json<PositionHistoryResponse>, AppError> {
+impl From<crate::database::repositories::PositionRow> for PositionHistoryItem {
+    fn from(row: crate::database::repositories::PositionRow) -> Self {
+        let exit_config: ExitConfig =
+            serde_json::from_value(row.exit_config.clone()).unwrap_or_default();
+
+        Self {
+            id: row.id.to_string(),
+            token_mint: row.token_mint,
+            token_symbol: row.token_symbol,
+            status: row.status,
+            entry_sol: row.entry_amount_base.to_string().parse().unwrap_or(0.0),
+            realized_pnl: row
+                .realized_pnl
+                .map(|d| d.to_string().parse().unwrap_or(0.0)),
+            exit_reason: row.exit_reason,
+            exit_config: ExitConfigSummary {
+                stop_loss_percent: exit_config.stop_loss_percent,
+                take_profit_percent: exit_config.take_profit_percent,
+                trailing_stop_percent: exit_config.trailing_stop_percent,
+                time_limit_minutes: exit_config.time_limit_minutes,
+                exit_slippage_bps: exit_config.exit_slippage_bps,
+            },
+            entry_time: row.entry_time,
+            exit_time: row.exit_time,
+            notes: row.notes,
+            tags: row.tags,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct PositionHistoryQuery {
+    pub tag: Option<String>,
+    pub limit: Option<i32>,
+    /// Export only: `json` (default) or `csv`
+    pub format: Option<String>,
+}
+
+impl PositionHistoryQuery {
+    /// Tag filter, normalized the same way stored tags are.
+    pub fn tag(&self) -> Option<String> {
+        self.tag
+            .as_deref()
+            .map(PositionAnnotations::normalize_tag)
+            .filter(|t| !t.is_empty())
+    }
+
+    fn limit_or(&self, default: i32) -> i32 {
+        self.limit.unwrap_or(default).clamp(1, 5000)
+    }
+}
+
+async fn fetch_position_history(
+    state: &AppState,
+    query: &PositionHistoryQuery,
+    default_limit: i32,
+) -> Result<Vec<PositionHistoryItem>, AppError> {
     use crate::database::PositionRepository;
 
     let repo = PositionRepository::new(state.db_pool.clone());
     let rows = repo
-        .get_all_positions(50)
+        .get_position_history(query.limit_or(default_limit), query.tag().as_deref())
         .await
         .map_err(|e| AppError::Database(format!("Failed to fetch positions: {}", e)))?;
 
-    let positions: Vec<PositionHistoryItem> = rows
-        .into_iter()
-        .map(|row| {
-            let exit_config: crate::execution::ExitConfig =
-                serde_json::from_value(row.exit_config.clone()).unwrap_or_default();
-
-            PositionHistoryItem {
-                id: row.id.to_string(),
-                token_mint: row.token_mint.clone(),
-                token_symbol: row.token_symbol.clone(),
-                status: row.status.clone(),
-                entry_sol: row.entry_amount_base.to_string().parse().unwrap_or(0.0),
-                realized_pnl: row
-                    .realized_pnl
-                    .map(|d| d.to_string().parse().unwrap_or(0.0)),
-                exit_reason: row.exit_reason.clone(),
-                exit_config: ExitConfigSummary {
-                    stop_loss_percent: exit_config.stop_loss_percent,
-                    take_profit_percent: exit_config.take_profit_percent,
-                    trailing_stop_percent: exit_config.trailing_stop_percent,
-                    time_limit_minutes: exit_config.time_limit_minutes,
-                },
-                entry_time: row.entry_time,
-                exit_time: row.exit_time,
-            }
-        })
-        .collect();
+    Ok(rows.into_iter().map(PositionHistoryItem::from).collect())
+}
 
+pub async fn get_position_history(
+    State(state): State<AppState>,
+    Query(query): Query<PositionHistoryQuery>,
+) -> Result<Json<PositionHistoryResponse>, AppError> {
+    let positions = fetch_position_history(&state, &query, 50).await?;
     let total_count = positions.len();
 
     Ok(Json(PositionHistoryResponse {
@@ -2019,3 +2326,261 @@ pub async fn get_position_history(
         total_count,
     }))
 }
+
+const EXPORT_CSV_HEADER: &str = "id,token_mint,token_symbol,status,entry_sol,realized_pnl,exit_reason,entry_time,exit_time,notes,tags";
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Free text starting with `=`, `+`, `-` or `@` gets a leading `'` so spreadsheets don't
+/// run it as a formula. Numeric columns are left alone so negative PnL stays a number.
+fn csv_text(value: String) -> String {
+    if value.starts_with(['=', '+', '-', '@']) {
+        format!("'{}", value)
+    } else {
+        value
+    }
+}
+
+/// One row per position; tags are joined with `;`.
+pub fn positions_to_csv(positions: &[PositionHistoryItem]) -> String {
+    let mut csv = String::from(EXPORT_CSV_HEADER);
+    csv.push('\n');
+    for p in positions {
+        let fields = [
+            p.id.clone(),
+            p.token_mint.clone(),
+            csv_text(p.token_symbol.clone().unwrap_or_default()),
+            p.status.clone(),
+            p.entry_sol.to_string(),
+            p.realized_pnl.map(|v| v.to_string()).unwrap_or_default(),
+            p.exit_reason.clone().unwrap_or_default(),
+            p.entry_time.to_rfc3339(),
+            p.exit_time.map(|t| t.to_rfc3339()).unwrap_or_default(),
+            csv_text(p.notes.clone().unwrap_or_default()),
+            p.tags
+                .iter()
+                .map(|tag| csv_text(tag.clone()))
+                .collect::<Vec<_>>()
+                .join(";"),
+        ];
+        let line: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        csv.push_str(&line.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Position history with notes and tags, as JSON or CSV. Accepts the history filters.
+pub async fn export_position_history(
+    State(state): State<AppState>,
+    Query(query): Query<PositionHistoryQuery>,
+) -> Result<axum::response::Response, AppError> {
+    use axum::response::IntoResponse;
+
+    let format = query.format.as_deref().unwrap_or("json").to_lowercase();
+    if format != "json" && format != "csv" {
+        return Err(AppError::BadRequest(
+            "format must be json or csv".to_string(),
+        ));
+    }
+
+    let positions = fetch_position_history(&state, &query, 1000).await?;
+
+    if format == "json" {
+        let total_count = positions.len();
+        return Ok(Json(PositionHistoryResponse {
+            positions,
+            total_count,
+        })
+        .into_response());
+    }
+
+    Ok((
+        StatusCode::OK,
+        [
+            (
+                axum::http::header::CONTENT_TYPE,
+                "text/csv; charset=utf-8".to_string(),
+            ),
+            (
+                axum::http::header::CONTENT_DISPOSITION,
+                "attachment; filename=\"positions.csv\"".to_string(),
+            ),
+        ],
+        positions_to_csv(&positions),
+    )
+        .into_response())
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AnnotatePositionRequest {
+    pub notes: Option<String>,
+    pub tags: Option<Vec<String>>,
+}
+
+pub async fn annotate_position(
+    State(state): State<AppState>,
+    Path(position_id): Path<Uuid>,
+    Json(request): Json<AnnotatePositionRequest>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let annotations = state
+        .position_manager
+        .annotate_position(position_id, request.notes, request.tags)
+        .await?;
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "position_id": position_id,
+        "notes": annotations.notes,
+        "tags": annotations.tags,
+    })))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScaleInRequest {
+    /// `null` removes the plan
+    pub config: Option<ScaleInConfig>,
+}
+
+pub async fn set_position_scale_in(
+    State(state): State<AppState>,
+    Path(position_id): Path<Uuid>,
+    Json(request): Json<ScaleInRequest>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let plan = state
+        .position_manager
+        .set_scale_in(position_id, request.config)
+        .await?;
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "position_id": position_id,
+        "scale_in": plan,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::PositionRow;
+    use rust_decimal::Decimal;
+
+    fn row(symbol: &str, notes: Option<&str>, tags: &[&str]) -> PositionRow {
+        let now = Utc::now();
+        PositionRow {
+            id: Uuid::new_v4(),
+            edge_id: Uuid::new_v4(),
+            strategy_id: Uuid::new_v4(),
+            token_mint: format!("{}Mint1111111111111", symbol),
+            token_symbol: Some(symbol.to_string()),
+            entry_amount_base: Decimal::new(5, 1),
+            entry_token_amount: Decimal::new(1000, 0),
+            entry_price: Decimal::new(5, 4),
+            entry_time: now,
+            entry_tx_signature: None,
+            current_price: Decimal::new(6, 4),
+            current_value_base: Decimal::new(6, 1),
+            unrealized_pnl: Decimal::ZERO,
+            unrealized_pnl_percent: Decimal::ZERO,
+            high_water_mark: Decimal::new(6, 4),
+            exit_config: serde_json::to_value(ExitConfig::default()).unwrap(),
+            partial_exits: serde_json::json!([]),
+            status: "closed".to_string(),
+            exit_price: Some(Decimal::new(6, 4)),
+            exit_time: Some(now),
+            exit_tx_signature: None,
+            realized_pnl: Some(Decimal::new(1, 1)),
+            exit_reason: Some("TakeProfit".to_string()),
+            remaining_amount_base: None,
+            remaining_token_amount: None,
+            is_inferred_exit: false,
+            auto_exit_enabled: true,
+            auto_exit_overridden: false,
+            entry_sol_usd_rate: None,
+            exit_sol_usd_rate: None,
+            signal_source: None,
+            venue: Some("pump_fun".to_string()),
+            notes: notes.map(str::to_string),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            scale_in: None,
+            round_trip_costs: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_history_items_carry_notes_and_tags() {
+        let item = PositionHistoryItem::from(row(
+            "KOL",
+            Some("Copied a KOL buy"),
+            &["kol-copy", "experiment"],
+        ));
+        assert_eq!(item.notes.as_deref(), Some("Copied a KOL buy"));
+        assert_eq!(item.tags, vec!["kol-copy", "experiment"]);
+
+        let json = serde_json::to_value(&item).unwrap();
+        assert_eq!(json["tags"], serde_json::json!(["kol-copy", "experiment"]));
+    }
+
+    #[test]
+    fn test_history_tag_filter_normalized_like_stored_tags() {
+        let query = PositionHistoryQuery {
+            tag: Some("  KOL-Copy ".to_string()),
+            ..Default::default()
+        };
+        let stored = PositionAnnotations::new(None, vec!["Kol-Copy".to_string()]).unwrap();
+        assert_eq!(query.tag().as_deref(), Some(stored.tags[0].as_str()));
+
+        let blank = PositionHistoryQuery {
+            tag: Some("   ".to_string()),
+            ..Default::default()
+        };
+        assert!(blank.tag().is_none());
+        assert_eq!(blank.limit_or(50), 50);
+    }
+
+    #[test]
+    fn test_csv_export_includes_notes_and_tags() {
+        let items = vec![
+            PositionHistoryItem::from(row(
+                "KOL",
+                Some("Entered on \"whale\" buy, sized down"),
+                &["kol-copy", "experiment"],
+            )),
+            PositionHistoryItem::from(row("BARE", None, &[])),
+        ];
+        let csv = positions_to_csv(&items);
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], EXPORT_CSV_HEADER);
+        assert!(lines[1].contains("KOL"));
+        assert!(
+            lines[1].ends_with(",\"Entered on \"\"whale\"\" buy, sized down\",kol-copy;experiment")
+        );
+        assert!(lines[2].ends_with(",,"));
+    }
+
+    #[test]
+    fn test_csv_export_neutralises_formula_notes() {
+        let mut losing = row("SUM", Some("=HYPERLINK(\"x\")"), &["@risk"]);
+        losing.realized_pnl = Some(Decimal::new(-2, 1));
+        let items = vec![
+            PositionHistoryItem::from(losing),
+            PositionHistoryItem::from(row("PLUS", Some("+1"), &["ok", "-x"])),
+        ];
+        let csv = positions_to_csv(&items);
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert!(lines[1].contains(",-0.2,"));
+        assert!(lines[1].ends_with(",\"'=HYPERLINK(\"\"x\"\")\",'@risk"));
+        assert!(lines[2].ends_with(",'+1,ok;'-x"));
+    }
+}
@@ -9,10 +9,9 @@ use uuid::Uuid;
 
 use crate::error::AppResult;
 use crate::research::{
-    backtest::{BacktestConfig, BacktestEngine, BacktestResult},
-    social_monitor::{
-        MonitorStats, MonitoredSource, SocialAlert, SocialMonitor, SourceType, TrackType,
-    },
+    backtest::{BacktestConfig, BacktestEngine, BacktestResult, MonteCarloConfig},
+    discovery::{Discovery, DiscoveryStatus},
+    social_monitor::{MonitoredSource, SocialAlert, SourceOutcome, SourceType, TrackType},
     strategy_extract::{ExtractedStrategy, StrategyExtractor, TextStrategyExtractor},
     url_ingest::{IngestResult, UrlIngester},
 };
@@ -64,6 +63,10 @@ pub async fn ingest_url(
         None
     };
 
+    if let Some(strategy) = &extracted_strategy {
+        state.discovery_store.add(strategy.clone()).await;
+    }
+
     (
         StatusCode::OK,
         Json(IngestUrlResponse {
@@ -109,14 +112,17 @@ pub async fn extract_strategy_from_text(
     );
 
     match extractor.extract_from_text(&request.description, request.context.as_deref()).await {
-        Ok(Some(strategy)) => (
-            StatusCode::OK,
-            Json(ExtractFromTextResponse {
-                extracted_strategy: Some(strategy),
-                success: true,
-                error: None,
-            }),
-        ).into_response(),
+        Ok(Some(strategy)) => {
+            state.discovery_store.add(strategy.clone()).await;
+            (
+                StatusCode::OK,
+                Json(ExtractFromTextResponse {
+                    extracted_strategy: Some(strategy),
+                    success: true,
+                    error: None,
+                }),
+            ).into_response()
+        }
         Ok(None) => (
             StatusCode::OK,
             Json(ExtractFromTextResponse {
@@ -145,65 +151,141 @@ pub struct ListDiscoveriesQuery {
 
 #[derive(Debug, Serialize)]
 pub struct DiscoveryListResponse {
-    pub discoveries: Vec<ExtractedStrategy>,
+    pub discoveries: Vec<Discovery>,
     pub total: usize,
 }
 
 pub async fn list_discoveries(
-    State(_state): State<AppState>,
-    Query(_query): Query<ListDiscoveriesQuery>,
+    State(state): State<AppState>,
+    Query(query): Query<ListDiscoveriesQuery>,
 ) -> impl IntoResponse {
+    let status = match query.status.as_deref() {
+        Some(s) => match DiscoveryStatus::from_str(s) {
+            Some(status) => Some(status),
+            None => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(serde_json::json!({
+                        "error": format!("Unknown discovery status: {}", s)
+                    })),
+                )
+                    .into_response()
+            }
+        },
+        None => None,
+    };
+    let limit = query.limit.unwrap_or(50).clamp(1, 500) as usize;
+    let offset = query.offset.unwrap_or(0).max(0) as usize;
+
+    let (discoveries, total) = state.discovery_store.list(status, limit, offset).await;
+
     (
         StatusCode::OK,
-        Json(DiscoveryListResponse {
-            discoveries: Vec::new(),
-            total: 0,
-        }),
+        Json(DiscoveryListResponse { discoveries, total }),
     )
+        .into_response()
 }
 
 pub async fn get_discovery(
-    State(_state): State<AppState>,
-    Path(_discovery_id): Path<Uuid>,
+    State(state): State<AppState>,
+    Path(discovery_id): Path<Uuid>,
 ) -> impl IntoResponse {
-    (
-        StatusCode::NOT_FOUND,
-        Json(serde_json::json!({
-            "error": "Discovery not found"
-        })),
-    )
+    match state.discovery_store.get(discovery_id).await {
+        Some(discovery) => (StatusCode::OK, Json(discovery)).into_response(),
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "Discovery not found"
+            })),
+        )
+            .into_response(),
+    }
 }
 
 #[derive(Debug, Deserialize)]
 pub struct ApproveDiscoveryRequest {
     pub notes: Option<String>,
+    /// Monitored source that surfaced the discovery, credited in its reliability score
+    pub source_id: Option<Uuid>,
+    /// Backtest the discovery before approving it; defaults to `ARB_DISCOVERY_AUTO_BACKTEST`
+    pub auto_backtest: Option<bool>,
 }
 
 pub async fn approve_discovery(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Path(discovery_id): Path<Uuid>,
-    Json(_request): Json<ApproveDiscoveryRequest>,
+    Json(request): Json<ApproveDiscoveryRequest>,
 ) -> impl IntoResponse {
+    let mut discovery = match state
+        .discovery_store
+        .approve(
+            discovery_id,
+            request.notes,
+            request.auto_backtest,
+            &BacktestEngine::new(),
+        )
+        .await
+    {
+        Ok(discovery) => discovery,
+        Err(e) => return e.into_response(),
+    };
+
+    if let Some(source_id) = request.source_id {
+        state
+            .social_monitor
+            .record_source_outcome(source_id, SourceOutcome::Approved)
+            .await;
+        if let Some(credited) = state
+            .discovery_store
+            .credit_source(discovery_id, source_id)
+            .await
+        {
+            discovery = credited;
+        }
+    }
+
     (
         StatusCode::OK,
         Json(serde_json::json!({
             "message": "Discovery approved",
             "discovery_id": discovery_id,
-            "status": "approved"
+            "status": "approved",
+            "discovery": discovery
         })),
     )
+        .into_response()
 }
 
 #[derive(Debug, Deserialize)]
 pub struct RejectDiscoveryRequest {
     pub reason: String,
+    pub source_id: Option<Uuid>,
 }
 
 pub async fn reject_discovery(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Path(discovery_id): Path<Uuid>,
     Json(request): Json<RejectDiscoveryRequest>,
 ) -> impl IntoResponse {
+    if let Err(e) = state
+        .discovery_store
+        .reject(discovery_id, request.reason.clone())
+        .await
+    {
+        return e.into_response();
+    }
+
+    if let Some(source_id) = request.source_id {
+        state
+            .social_monitor
+            .record_source_outcome(source_id, SourceOutcome::Rejected)
+            .await;
+        state
+            .discovery_store
+            .credit_source(discovery_id, source_id)
+            .await;
+    }
+
     (
         StatusCode::OK,
         Json(serde_json::json!({
@@ -213,6 +295,7 @@ pub async fn reject_discovery(
             "reason": request.reason
         })),
     )
+        .into_response()
 }
 
 #[derive(Debug, Deserialize)]
@@ -221,6 +304,9 @@ pub struct BacktestRequest {
     pub period_days: Option<u32>,
     pub initial_capital_sol: Option<f64>,
     pub max_position_size_sol: Option<f64>,
+    /// Bootstrap the trade sequence to report a return/drawdown distribution
+    #[serde(default)]
+    pub monte_carlo: Option<MonteCarloConfig>,
 }
 
 pub async fn run_backtest(
@@ -231,6 +317,7 @@ pub async fn run_backtest(
         period_days: request.period_days.unwrap_or(30),
         initial_capital_sol: request.initial_capital_sol.unwrap_or(10.0),
         max_position_size_sol: request.max_position_size_sol.unwrap_or(1.0),
+        monte_carlo: request.monte_carlo,
         ..BacktestConfig::default()
     };
 
@@ -266,7 +353,7 @@ pub struct AddSourceRequest {
 }
 
 pub async fn add_source(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Json(request): Json<AddSourceRequest>,
 ) -> impl IntoResponse {
     let source_type = SourceType::from_str(&request.source_type);
@@ -282,6 +369,8 @@ pub async fn add_source(
         source = source.with_keywords(keywords);
     }
 
+    state.social_monitor.add_source(source.clone()).await;
+
     (
         StatusCode::CREATED,
         Json(serde_json::json!({
@@ -298,60 +387,64 @@ pub struct ListSourcesQuery {
     pub active_only: Option<bool>,
 }
 
+/// Sources ordered by reliability score, best first.
 pub async fn list_sources(
-    State(_state): State<AppState>,
-    Query(_query): Query<ListSourcesQuery>,
+    State(state): State<AppState>,
+    Query(query): Query<ListSourcesQuery>,
 ) -> impl IntoResponse {
-    let default_sources: Vec<MonitoredSource> = SocialMonitor::get_default_alpha_accounts()
+    let source_type = query.source_type.as_deref().map(SourceType::from_str);
+    let track_type = query.track_type.as_deref().map(TrackType::from_str);
+    let active_only = query.active_only.unwrap_or(false);
+
+    let sources: Vec<MonitoredSource> = state
+        .social_monitor
+        .list_sources()
+        .await
         .into_iter()
-        .map(|(handle, name)| {
-            MonitoredSource::new(
-                SourceType::Twitter,
-                handle.trim_start_matches('@').to_string(),
-                TrackType::Alpha,
-            )
-            .with_display_name(name.to_string())
-        })
-        .chain(
-            SocialMonitor::get_default_threat_accounts()
-                .into_iter()
-                .map(|(handle, name)| {
-                    MonitoredSource::new(
-                        SourceType::Twitter,
-                        handle.trim_start_matches('@').to_string(),
-                        TrackType::Threat,
-                    )
-                    .with_display_name(name.to_string())
-                }),
-        )
+        .filter(|s| source_type.as_ref().map_or(true, |t| &s.source_type == t))
+        .filter(|s| track_type.as_ref().map_or(true, |t| &s.track_type == t))
+        .filter(|s| !active_only || s.is_active)
         .collect();
 
     (
         StatusCode::OK,
         Json(serde_json::json!({
-            "sources": default_sources,
-            "total": default_sources.len()
+            "sources": sources,
+            "total": sources.len()
         })),
     )
 }
 
 pub async fn get_source(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Path(source_id): Path<Uuid>,
 ) -> impl IntoResponse {
-    (
-        StatusCode::NOT_FOUND,
-        Json(serde_json::json!({
-            "error": "Source not found",
-            "source_id": source_id
-        })),
-    )
+    match state.social_monitor.get_source(source_id).await {
+        Some(source) => (StatusCode::OK, Json(serde_json::json!(source))),
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "Source not found",
+                "source_id": source_id
+            })),
+        ),
+    }
 }
 
 pub async fn delete_source(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Path(source_id): Path<Uuid>,
 ) -> impl IntoResponse {
+    if !state.social_monitor.remove_source(source_id).await {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "Source not found",
+                "source_id": source_id
+            })),
+        );
+    }
+
     (
         StatusCode::OK,
         Json(serde_json::json!({
@@ -367,18 +460,45 @@ pub struct ToggleSourceRequest {
 }
 
 pub async fn toggle_source(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Path(source_id): Path<Uuid>,
     Json(request): Json<ToggleSourceRequest>,
-) -> impl IntoResponse {
-    (
+) -> AppResult<impl IntoResponse> {
+    state
+        .social_monitor
+        .toggle_source(source_id, request.active)
+        .await?;
+
+    Ok((
         StatusCode::OK,
         Json(serde_json::json!({
             "message": if request.active { "Source activated" } else { "Source deactivated" },
             "source_id": source_id,
             "active": request.active
         })),
-    )
+    ))
+}
+
+/// Reports a downstream outcome (approval, rejection or strategy P&L) for a source.
+pub async fn record_source_outcome(
+    State(state): State<AppState>,
+    Path(source_id): Path<Uuid>,
+    Json(outcome): Json<SourceOutcome>,
+) -> impl IntoResponse {
+    match state
+        .social_monitor
+        .record_source_outcome(source_id, outcome)
+        .await
+    {
+        Some(source) => (StatusCode::OK, Json(serde_json::json!(source))),
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "Source not found",
+                "source_id": source_id
+            })),
+        ),
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -401,16 +521,8 @@ pub async fn list_alerts(
     )
 }
 
-pub async fn get_monitor_stats(State(_state): State<AppState>) -> impl IntoResponse {
-    let stats = MonitorStats {
-        total_sources: 8,
-        active_sources: 8,
-        total_alerts: 0,
-        alerts_last_24h: 0,
-        sources_by_type: std::collections::HashMap::from([("Twitter".to_string(), 8)]),
-    };
-
-    (StatusCode::OK, Json(stats))
+pub async fn get_monitor_stats(State(state): State<AppState>) -> impl IntoResponse {
+    (StatusCode::OK, Json(state.social_monitor.get_stats().await))
 }
 
 #[derive(Debug, Deserialize)]
@@ -420,13 +532,14 @@ pub struct MonitorAccountRequest {
 }
 
 pub async fn monitor_account(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Json(request): Json<MonitorAccountRequest>,
 ) -> impl IntoResponse {
     let handle = request.handle.trim_start_matches('@').to_string();
     let track_type = TrackType::from_str(&request.track_type);
 
     let source = MonitoredSource::new(SourceType::Twitter, handle.clone(), track_type);
+    state.social_monitor.add_source(source.clone()).await;
 
     (
         StatusCode::CREATED,
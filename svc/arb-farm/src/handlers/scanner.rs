@@ -4,9 +4,14 @@ use axum::{
     response::IntoResponse,
     Json,
 };
+use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 
-use crate::agents::{ScannerStatus, VenueStatus};
+use crate::agents::strategies::StrategyPerformance;
+use crate::agents::{
+    rank_contenders, ContenderScore, ContenderSubScores, ScannerStatus, StrategyMatchExplanation,
+    VenueStatus,
+};
 use crate::database::repositories::edges::CreateEdgeRecord;
 use crate::events::AtomicityLevel;
 use crate::models::VenueType;
@@ -28,6 +33,8 @@ pub struct ScannerStatsResponse {
     pub last_scan_at: Option<String>,
     pub healthy_venues: u32,
     pub total_venues: u32,
+    pub disabled_venues: Vec<String>,
+    pub snapshot_cache_hits: u64,
 }
 
 #[derive(Debug, Serialize)]
@@ -36,6 +43,7 @@ pub struct VenueStatusResponse {
     pub name: String,
     pub venue_type: String,
     pub is_healthy: bool,
+    pub is_enabled: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -74,6 +82,8 @@ impl From<ScannerStatus> for ScannerStatusResponse {
                 last_scan_at: status.stats.last_scan_at.map(|t| t.to_rfc3339()),
                 healthy_venues: status.stats.healthy_venues,
                 total_venues: status.stats.total_venues,
+                disabled_venues: status.stats.disabled_venues,
+                snapshot_cache_hits: status.stats.snapshot_cache_hits,
             },
             venues: status
                 .venue_statuses
@@ -91,6 +101,7 @@ impl From<VenueStatus> for VenueStatusResponse {
             name: status.name,
             venue_type: format!("{:?}", status.venue_type),
             is_healthy: status.is_healthy,
+            is_enabled: status.is_enabled,
         }
     }
 }
@@ -200,6 +211,16 @@ pub struct ProcessSignalsResponse {
     pub edges_rejected: usize,
     pub created_edge_ids: Vec<String>,
     pub rejection_reasons: Vec<String>,
+    pub near_misses: Vec<SignalNearMiss>,
+}
+
+/// A signal no strategy picked up, paired with a strategy it missed by a single
+/// criterion.
+#[derive(Debug, Serialize)]
+pub struct SignalNearMiss {
+    pub signal_id: uuid::Uuid,
+    #[serde(flatten)]
+    pub strategy: StrategyMatchExplanation,
 }
 
 pub async fn process_signals(State(state): State<AppState>) -> impl IntoResponse {
@@ -212,6 +233,7 @@ pub async fn process_signals(State(state): State<AppState>) -> impl IntoResponse
             let mut edges_rejected = 0;
             let mut created_edge_ids = Vec::new();
             let mut rejection_reasons = Vec::new();
+            let mut near_misses = Vec::new();
 
             for signal in signals {
                 match state.strategy_engine.match_signal(&signal).await {
@@ -277,7 +299,19 @@ pub async fn process_signals(State(state): State<AppState>) -> impl IntoResponse
                         }
                     }
                     None => {
-                        // No matching strategy found - not an error, just no match
+                        // No matching strategy found - not an error, but report near misses
+                        near_misses.extend(
+                            state
+                                .strategy_engine
+                                .explain_signal(&signal)
+                                .await
+                                .into_iter()
+                                .filter(|e| e.explanation.failed().count() == 1)
+                                .map(|strategy| SignalNearMiss {
+                                    signal_id: signal.id,
+                                    strategy,
+                                }),
+                        );
                     }
                 }
             }
@@ -288,6 +322,7 @@ pub async fn process_signals(State(state): State<AppState>) -> impl IntoResponse
                 edges_rejected,
                 created_edge_ids,
                 rejection_reasons,
+                near_misses,
             })
             .into_response()
         }
@@ -307,6 +342,8 @@ pub async fn process_signals(State(state): State<AppState>) -> impl IntoResponse
 
 #[derive(Debug, Serialize)]
 pub struct ContenderResponse {
+    pub rank: usize,
+    pub score: ContenderScore,
     pub mint: String,
     pub name: String,
     pub symbol: String,
@@ -324,22 +361,59 @@ pub struct ContenderQuery {
     pub limit: Option<usize>,
 }
 
+/// Each uncached score costs several RPC calls, so a poll reuses recent scores and
+/// scores the rest a few at a time.
+const CONTENDER_SCORE_MAX_AGE: std::time::Duration = std::time::Duration::from_secs(30);
+const CONTENDER_SCORING_CONCURRENCY: usize = 8;
+
 pub async fn get_contenders(
     State(state): State<AppState>,
     Query(query): Query<ContenderQuery>,
 ) -> impl IntoResponse {
     let limit = query.limit.unwrap_or(10).min(50);
-    let contenders = state.scanner.get_contenders(limit).await;
+    // Rank the whole contender cache so `limit` returns the best, not the first, N
+    let contenders = state.scanner.get_contenders(50).await;
+
+    let scored: Vec<_> = futures::stream::iter(contenders.into_iter().map(|t| {
+        let state = &state;
+        async move {
+            let opportunity = state
+                .curve_scorer
+                .score_opportunity_cached(&t.mint, "pump_fun", CONTENDER_SCORE_MAX_AGE)
+                .await
+                .ok();
+            let momentum = match state.metrics_collector.get_cached_metrics(&t.mint).await {
+                Some(metrics) => metrics.momentum_score(),
+                None => opportunity
+                    .as_ref()
+                    .map(|o| o.momentum_factor)
+                    .unwrap_or(0.0),
+            };
+            let sub_scores = ContenderSubScores {
+                opportunity: opportunity.map(|o| o.overall).unwrap_or(0.0),
+                momentum,
+                threat: crate::threat::cached_score(&t.mint).map(|s| s.overall_score),
+            };
+            (t, sub_scores)
+        }
+    }))
+    .buffer_unordered(CONTENDER_SCORING_CONCURRENCY)
+    .collect()
+    .await;
 
-    let responses: Vec<ContenderResponse> = contenders
+    let responses: Vec<ContenderResponse> = rank_contenders(scored)
         .into_iter()
-        .map(|t| {
+        .take(limit)
+        .enumerate()
+        .map(|(i, (t, score))| {
             let velocity = if t.market_cap_sol > 0.01 {
                 t.volume_24h_sol / t.market_cap_sol
             } else {
                 0.0
             };
             ContenderResponse {
+                rank: i + 1,
+                score,
                 mint: t.mint,
                 name: t.name,
                 symbol: t.symbol,
@@ -372,6 +446,7 @@ pub struct BehavioralStrategyResponse {
     pub is_active: bool,
     pub supported_venues: Vec<String>,
     pub status_detail: Option<String>,
+    pub performance: Option<StrategyPerformance>,
 }
 
 #[derive(Debug, Serialize)]
@@ -385,6 +460,13 @@ pub async fn list_behavioral_strategies(State(state): State<AppState>) -> impl I
     let registry = state.scanner.get_strategy_registry();
     let strategies = registry.list().await;
     let active_count = registry.active_count().await;
+    let performance: std::collections::HashMap<String, StrategyPerformance> = registry
+        .performance()
+        .all()
+        .await
+        .into_iter()
+        .map(|p| (p.strategy_type.clone(), p))
+        .collect();
 
     let webhook_token_set = std::env::var("HELIUS_WEBHOOK_AUTH_TOKEN").is_ok();
 
@@ -414,6 +496,7 @@ pub async fn list_behavioral_strategies(State(state): State<AppState>) -> impl I
                     .map(|v| format!("{:?}", v))
                     .collect(),
                 status_detail,
+                performance: performance.get(s.strategy_type()).cloned(),
             }
         })
         .collect();
@@ -435,11 +518,13 @@ pub async fn get_behavioral_strategy(
     let strategies = registry.list().await;
 
     if let Some(strategy) = strategies.iter().find(|s| s.name() == name) {
+        let performance = registry.performance().get(strategy.strategy_type()).await;
         Json(serde_json::json!({
             "name": strategy.name(),
             "strategy_type": strategy.strategy_type(),
             "is_active": strategy.is_active(),
             "supported_venues": strategy.supported_venues().iter().map(|v| format!("{:?}", v)).collect::<Vec<_>>(),
+            "performance": performance,
         })).into_response()
     } else {
         (
@@ -452,6 +537,17 @@ pub async fn get_behavioral_strategy(
     }
 }
 
+pub async fn get_behavioral_strategy_performance(
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let performance = state.scanner.get_strategy_registry().performance();
+
+    Json(serde_json::json!({
+        "strategies": performance.all().await,
+        "feedback_config": performance.config().await,
+    }))
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ToggleBehavioralStrategyRequest {
     pub active: bool,
@@ -487,6 +583,40 @@ pub async fn toggle_behavioral_strategy(
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ToggleVenueRequest {
+    pub enabled: bool,
+}
+
+pub async fn toggle_venue(
+    State(state): State<AppState>,
+    Path(venue): Path<String>,
+    Json(body): Json<ToggleVenueRequest>,
+) -> impl IntoResponse {
+    match state.scanner.set_venue_enabled(&venue, body.enabled).await {
+        Some(status) => {
+            let message = format!(
+                "Venue '{}' is now {}",
+                status.name,
+                if body.enabled { "enabled" } else { "disabled" }
+            );
+            Json(serde_json::json!({
+                "success": true,
+                "venue": VenueStatusResponse::from(status),
+                "message": message,
+            }))
+            .into_response()
+        }
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": format!("Venue '{}' not found", venue)
+            })),
+        )
+            .into_response(),
+    }
+}
+
 pub async fn toggle_all_behavioral_strategies(
     State(state): State<AppState>,
     Json(body): Json<ToggleBehavioralStrategyRequest>,
@@ -2,7 +2,13 @@ use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
 use serde::{Deserialize, Serialize};
 
 use crate::database::repositories::strategies::UpdateStrategyRecord;
-use crate::execution::risk::RiskConfig;
+use crate::execution::reconcile_schedule::{
+    MAX_RECONCILE_INTERVAL_SECS, MIN_RECONCILE_INTERVAL_SECS,
+};
+use crate::execution::risk::{
+    RiskConfig, DEFAULT_REENTRY_COOLDOWN_SECS, MAX_REENTRY_COOLDOWN_SECS,
+};
+use crate::execution::DustPolicy;
 use crate::server::AppState;
 
 #[derive(Debug, Serialize)]
@@ -27,6 +33,8 @@ pub struct RiskConfigDto {
     pub trailing_stop_percent: f64,
     #[serde(default = "default_time_limit")]
     pub time_limit_minutes: u32,
+    #[serde(default = "default_reentry_cooldown")]
+    pub reentry_cooldown_secs: u64,
 }
 
 fn default_take_profit() -> f64 {
@@ -38,6 +46,9 @@ fn default_trailing_stop() -> f64 {
 fn default_time_limit() -> u32 {
     7
 }
+fn default_reentry_cooldown() -> u64 {
+    DEFAULT_REENTRY_COOLDOWN_SECS
+}
 
 impl From<RiskConfig> for RiskConfigDto {
     fn from(config: RiskConfig) -> Self {
@@ -53,6 +64,7 @@ impl From<RiskConfig> for RiskConfigDto {
             take_profit_percent: config.take_profit_percent,
             trailing_stop_percent: config.trailing_stop_percent,
             time_limit_minutes: config.time_limit_minutes,
+            reentry_cooldown_secs: config.reentry_cooldown_secs,
         }
     }
 }
@@ -71,6 +83,7 @@ impl From<RiskConfigDto> for RiskConfig {
             take_profit_percent: dto.take_profit_percent,
             trailing_stop_percent: dto.trailing_stop_percent,
             time_limit_minutes: dto.time_limit_minutes,
+            reentry_cooldown_secs: dto.reentry_cooldown_secs.min(MAX_REENTRY_COOLDOWN_SECS),
         }
     }
 }
@@ -99,6 +112,7 @@ fn get_risk_presets() -> Vec<RiskPreset> {
                 take_profit_percent: 10.0,
                 trailing_stop_percent: 8.0,
                 time_limit_minutes: 5,
+                reentry_cooldown_secs: RiskConfig::low().reentry_cooldown_secs,
             },
         },
         RiskPreset {
@@ -116,6 +130,7 @@ fn get_risk_presets() -> Vec<RiskPreset> {
                 take_profit_percent: 15.0,      // DEFENSIVE: 15% TP
                 trailing_stop_percent: 8.0,     // DEFENSIVE: 8% trailing
                 time_limit_minutes: 5,          // DEFENSIVE: 5 min
+                reentry_cooldown_secs: RiskConfig::medium().reentry_cooldown_secs,
             },
         },
         RiskPreset {
@@ -133,6 +148,7 @@ fn get_risk_presets() -> Vec<RiskPreset> {
                 take_profit_percent: 12.0,
                 trailing_stop_percent: 10.0,
                 time_limit_minutes: 5,
+                reentry_cooldown_secs: RiskConfig::conservative().reentry_cooldown_secs,
             },
         },
         RiskPreset {
@@ -150,6 +166,7 @@ fn get_risk_presets() -> Vec<RiskPreset> {
                 take_profit_percent: 20.0,
                 trailing_stop_percent: 15.0,
                 time_limit_minutes: 10,
+                reentry_cooldown_secs: RiskConfig::dev_testing().reentry_cooldown_secs,
             },
         },
         RiskPreset {
@@ -167,6 +184,7 @@ fn get_risk_presets() -> Vec<RiskPreset> {
                 take_profit_percent: 20.0,
                 trailing_stop_percent: 15.0,
                 time_limit_minutes: 10,
+                reentry_cooldown_secs: RiskConfig::aggressive().reentry_cooldown_secs,
             },
         },
     ]
@@ -389,6 +407,97 @@ pub async fn get_venue_settings(State(state): State<AppState>) -> impl IntoRespo
     (StatusCode::OK, Json(VenueSettingsResponse { venues }))
 }
 
+pub async fn get_dust_settings(State(state): State<AppState>) -> impl IntoResponse {
+    let policy = state.position_manager.get_dust_policy().await;
+    (StatusCode::OK, Json(policy))
+}
+
+pub async fn update_dust_settings(
+    State(state): State<AppState>,
+    Json(policy): Json<DustPolicy>,
+) -> impl IntoResponse {
+    let invalid = std::iter::once(&policy.default)
+        .chain(policy.venues.values())
+        .any(|t| !(t.balance >= 0.0 && t.sol_value >= 0.0));
+    if invalid {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "success": false,
+                "error": "Dust thresholds must be non-negative",
+            })),
+        );
+    }
+
+    state.position_manager.set_dust_policy(policy.clone()).await;
+
+    tracing::info!(
+        "⚙️ Dust policy updated: default balance={} / SOL value={}, {} venue overrides",
+        policy.default.balance,
+        policy.default.sol_value,
+        policy.venues.len()
+    );
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": true,
+            "policy": policy,
+        })),
+    )
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateReconcileSettingsRequest {
+    pub enabled: Option<bool>,
+    pub interval_secs: Option<u64>,
+}
+
+pub async fn get_reconcile_settings(State(state): State<AppState>) -> impl IntoResponse {
+    (StatusCode::OK, Json(state.reconcile_schedule.status()))
+}
+
+pub async fn update_reconcile_settings(
+    State(state): State<AppState>,
+    Json(request): Json<UpdateReconcileSettingsRequest>,
+) -> impl IntoResponse {
+    if let Some(secs) = request.interval_secs {
+        if !(MIN_RECONCILE_INTERVAL_SECS..=MAX_RECONCILE_INTERVAL_SECS).contains(&secs) {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "success": false,
+                    "error": format!(
+                        "interval_secs must be between {} and {}",
+                        MIN_RECONCILE_INTERVAL_SECS, MAX_RECONCILE_INTERVAL_SECS
+                    ),
+                })),
+            );
+        }
+        state
+            .reconcile_schedule
+            .set_interval(std::time::Duration::from_secs(secs));
+    }
+    if let Some(enabled) = request.enabled {
+        state.reconcile_schedule.set_enabled(enabled);
+    }
+
+    let status = state.reconcile_schedule.status();
+    tracing::info!(
+        "⚙️ Wallet reconciliation updated: {} every {}s",
+        if status.enabled { "enabled" } else { "paused" },
+        status.interval_secs
+    );
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": true,
+            "reconcile": status,
+        })),
+    )
+}
+
 #[derive(Debug, Serialize)]
 pub struct ApiKeyStatusResponse {
     pub services: Vec<ApiKeyStatus>,
@@ -1,166 +1,140 @@
 use axum::{
     extract::State,
-    response::sse::{Event, KeepAlive, Sse},
+    http::HeaderMap,
+    response::sse::{Event, Sse},
 };
-use futures::stream::Stream;
+use futures::stream::{Stream, StreamExt};
 use std::convert::Infallible;
-use std::time::Duration;
-use tokio_stream::wrappers::BroadcastStream;
-use tokio_stream::StreamExt;
+use uuid::Uuid;
 
-use crate::events::ArbEvent;
+use crate::events::{event_frames, get_sse_heartbeat_interval, ArbEvent, EventFrame};
 use crate::server::AppState;
 
-pub async fn scanner_stream(
-    State(state): State<AppState>,
-) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+const LAST_EVENT_ID: &str = "last-event-id";
+
+/// Builds an SSE response over the event bus. Every event carries its id so a
+/// reconnecting client's `Last-Event-Id` resumes from the sink's replay buffer.
+async fn event_stream<F, N>(
+    state: &AppState,
+    headers: &HeaderMap,
+    filter: F,
+    event_name: N,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>>
+where
+    F: Fn(&ArbEvent) -> bool + Send + 'static,
+    N: Fn(&ArbEvent) -> Option<String> + Send + 'static,
+{
     let rx = state.subscribe_events();
-    let stream = BroadcastStream::new(rx);
 
-    let event_stream = stream.filter_map(|result| {
-        match result {
-            Ok(event) => {
-                // Filter for scanner-related events
-                if event.topic.starts_with("arb.scanner.") {
-                    let data = serde_json::to_string(&event).unwrap_or_default();
-                    Some(Ok(Event::default().event("signal").data(data)))
-                } else {
-                    None
+    let last_event_id = headers
+        .get(LAST_EVENT_ID)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| Uuid::parse_str(v.trim()).ok());
+    let replay = match last_event_id {
+        Some(id) => state.event_sink.events_after(id).await.unwrap_or_else(|| {
+            tracing::warn!(
+                "SSE resume from {} requested but it is no longer buffered",
+                id
+            );
+            Vec::new()
+        }),
+        None => Vec::new(),
+    };
+
+    let stream = event_frames(replay, rx, filter, get_sse_heartbeat_interval()).map(move |frame| {
+        Ok(match frame {
+            EventFrame::Event(event) => {
+                let data = serde_json::to_string(&event).unwrap_or_default();
+                let sse = Event::default().id(event.id.to_string()).data(data);
+                match event_name(&event) {
+                    Some(name) => sse.event(name),
+                    None => sse,
                 }
             }
-            Err(_) => None,
-        }
+            EventFrame::Heartbeat => Event::default().comment("keep-alive"),
+        })
     });
 
-    Sse::new(event_stream).keep_alive(
-        KeepAlive::new()
-            .interval(Duration::from_secs(15))
-            .text("keep-alive"),
+    Sse::new(stream)
+}
+
+fn topic_suffix(event: &ArbEvent, fallback: &str) -> String {
+    event
+        .topic
+        .split('.')
+        .last()
+        .unwrap_or(fallback)
+        .to_string()
+}
+
+pub async fn scanner_stream(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    event_stream(
+        &state,
+        &headers,
+        |event| event.topic.starts_with("arb.scanner."),
+        |_| Some("signal".to_string()),
     )
+    .await
 }
 
 pub async fn edges_stream(
     State(state): State<AppState>,
+    headers: HeaderMap,
 ) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
-    let rx = state.subscribe_events();
-    let stream = BroadcastStream::new(rx);
-
-    let event_stream = stream.filter_map(|result| {
-        match result {
-            Ok(event) => {
-                // Filter for edge-related events
-                if event.topic.starts_with("arb.edge.") {
-                    let data = serde_json::to_string(&event).unwrap_or_default();
-                    Some(Ok(Event::default().event("edge").data(data)))
-                } else {
-                    None
-                }
-            }
-            Err(_) => None,
-        }
-    });
-
-    Sse::new(event_stream).keep_alive(
-        KeepAlive::new()
-            .interval(Duration::from_secs(15))
-            .text("keep-alive"),
+    event_stream(
+        &state,
+        &headers,
+        |event| event.topic.starts_with("arb.edge."),
+        |_| Some("edge".to_string()),
     )
+    .await
 }
 
 pub async fn all_events_stream(
     State(state): State<AppState>,
+    headers: HeaderMap,
 ) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
-    let rx = state.subscribe_events();
-    let stream = BroadcastStream::new(rx);
-
-    let event_stream = stream.filter_map(|result| match result {
-        Ok(event) => {
-            let data = serde_json::to_string(&event).unwrap_or_default();
-            Some(Ok(Event::default().data(data)))
-        }
-        Err(_) => None,
-    });
-
-    Sse::new(event_stream).keep_alive(
-        KeepAlive::new()
-            .interval(Duration::from_secs(15))
-            .text("keep-alive"),
-    )
+    event_stream(&state, &headers, |_| true, |_| None).await
 }
 
 pub async fn threat_stream(
     State(state): State<AppState>,
+    headers: HeaderMap,
 ) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
-    let rx = state.subscribe_events();
-    let stream = BroadcastStream::new(rx);
-
-    let event_stream = stream.filter_map(|result| match result {
-        Ok(event) => {
-            if event.topic.starts_with("arb.threat.") {
-                let data = serde_json::to_string(&event).unwrap_or_default();
-                Some(Ok(Event::default().event("threat").data(data)))
-            } else {
-                None
-            }
-        }
-        Err(_) => None,
-    });
-
-    Sse::new(event_stream).keep_alive(
-        KeepAlive::new()
-            .interval(Duration::from_secs(15))
-            .text("keep-alive"),
+    event_stream(
+        &state,
+        &headers,
+        |event| event.topic.starts_with("arb.threat."),
+        |_| Some("threat".to_string()),
     )
+    .await
 }
 
 pub async fn helius_stream(
     State(state): State<AppState>,
+    headers: HeaderMap,
 ) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
-    let rx = state.subscribe_events();
-    let stream = BroadcastStream::new(rx);
-
-    let event_stream = stream.filter_map(|result| match result {
-        Ok(event) => {
-            if event.topic.starts_with("arb.helius.") {
-                let event_name = event.topic.split('.').last().unwrap_or("helius");
-                let data = serde_json::to_string(&event).unwrap_or_default();
-                Some(Ok(Event::default().event(event_name).data(data)))
-            } else {
-                None
-            }
-        }
-        Err(_) => None,
-    });
-
-    Sse::new(event_stream).keep_alive(
-        KeepAlive::new()
-            .interval(Duration::from_secs(15))
-            .text("keep-alive"),
+    event_stream(
+        &state,
+        &headers,
+        |event| event.topic.starts_with("arb.helius."),
+        |event| Some(topic_suffix(event, "helius")),
     )
+    .await
 }
 
 pub async fn positions_stream(
     State(state): State<AppState>,
+    headers: HeaderMap,
 ) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
-    let rx = state.subscribe_events();
-    let stream = BroadcastStream::new(rx);
-
-    let event_stream = stream.filter_map(|result| match result {
-        Ok(event) => {
-            if event.topic.starts_with("arb.position.") {
-                let event_name = event.topic.split('.').last().unwrap_or("position");
-                let data = serde_json::to_string(&event).unwrap_or_default();
-                Some(Ok(Event::default().event(event_name).data(data)))
-            } else {
-                None
-            }
-        }
-        Err(_) => None,
-    });
-
-    Sse::new(event_stream).keep_alive(
-        KeepAlive::new()
-            .interval(Duration::from_secs(15))
-            .text("keep-alive"),
+    event_stream(
+        &state,
+        &headers,
+        |event| event.topic.starts_with("arb.position."),
+        |event| Some(topic_suffix(event, "position")),
     )
+    .await
 }
@@ -90,6 +90,27 @@ pub async fn create_strategy(
     // Add to in-memory engine for fast access
     state.strategy_engine.add_strategy(strategy.clone()).await;
 
+    if let Some(discovery_id) = request.discovery_id {
+        match state
+            .discovery_store
+            .get(discovery_id)
+            .await
+            .and_then(|d| d.source_id)
+        {
+            Some(source_id) => {
+                state
+                    .social_monitor
+                    .attribute_strategy(strategy.id, source_id, Some(discovery_id))
+                    .await;
+            }
+            None => tracing::warn!(
+                strategy_id = %strategy.id,
+                discovery_id = %discovery_id,
+                "Discovery has no credited source; strategy P&L won't be attributed"
+            ),
+        }
+    }
+
     // Persist to engrams for cross-session persistence
     let wallet = state
         .config
@@ -646,3 +667,49 @@ pub async fn toggle_strategy_momentum(
             .into_response(),
     }
 }
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct PreviewStrategyRequest {
+    /// Only evaluate the newest `limit` signals from the scanner's history.
+    pub limit: Option<usize>,
+}
+
+pub async fn preview_strategy(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<PreviewStrategyRequest>,
+) -> impl IntoResponse {
+    let mut history = state.scanner.get_signal_history().await;
+    if let Some(limit) = request.limit {
+        history.truncate(limit);
+    }
+
+    match state.strategy_engine.preview_strategy(id, &history).await {
+        Ok(preview) => (StatusCode::OK, Json(preview)).into_response(),
+        Err(e) => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "success": false,
+                "error": e.to_string()
+            })),
+        )
+            .into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preview_request_defaults_and_rejects_bad_limit() {
+        let empty: PreviewStrategyRequest = serde_json::from_str("{}").unwrap();
+        assert_eq!(empty.limit, None);
+
+        let limited: PreviewStrategyRequest = serde_json::from_str(r#"{"limit": 25}"#).unwrap();
+        assert_eq!(limited.limit, Some(25));
+
+        assert!(serde_json::from_str::<PreviewStrategyRequest>(r#"{"limit": -1}"#).is_err());
+    }
+}
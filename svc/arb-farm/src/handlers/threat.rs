@@ -5,16 +5,22 @@ use axum::{
     Json,
 };
 use serde::{Deserialize, Serialize};
+use tracing::warn;
 
+use crate::database::repositories::ThreatAuditRecord;
+use crate::error::AppError;
 use crate::models::{
-    AlertSeverity, BlockedEntity, ThreatAlert, ThreatCategory, ThreatEntityType, ThreatScore,
-    ThreatStats, WalletAnalysis, WatchedWallet, WhitelistedEntity,
+    AlertSeverity, BlockedEntity, ThreatAlert, ThreatAuditEntry, ThreatCategory, ThreatEntityType,
+    ThreatScore, ThreatStats, WalletAnalysis, WatchedWallet, WhitelistedEntity,
 };
 use crate::server::AppState;
-use crate::threat::ThreatDetector;
+use crate::threat::{
+    BlocklistFeedConfig, BlocklistMerge, SignedBlocklistFeed, ThreatDetector, ThreatResponse,
+};
 
 lazy_static::lazy_static! {
     static ref THREAT_DETECTOR: ThreatDetector = ThreatDetector::default();
+    static ref BLOCKLIST_FEED_CONFIG: BlocklistFeedConfig = BlocklistFeedConfig::from_env();
 }
 
 #[derive(Debug, Serialize)]
@@ -55,6 +61,19 @@ pub struct AlertsQuery {
     pub limit: Option<usize>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct AuditQuery {
+    pub address: Option<String>,
+    pub action: Option<String>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuditActorQuery {
+    pub actor: Option<String>,
+    pub reason: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ReportThreatRequest {
     pub entity_type: ThreatEntityType,
@@ -62,6 +81,7 @@ pub struct ReportThreatRequest {
     pub category: ThreatCategory,
     pub reason: String,
     pub evidence_url: Option<String>,
+    pub reported_by: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -79,6 +99,7 @@ pub struct WhitelistRequest {
     pub entity_type: ThreatEntityType,
     pub address: String,
     pub reason: String,
+    pub actor: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -97,12 +118,21 @@ pub struct WalletCheckResponse {
 pub struct BlockResponse {
     pub success: bool,
     pub entity: BlockedEntity,
+    pub audit: ThreatAuditEntry,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BlocklistImportResponse {
+    pub success: bool,
+    pub merge: BlocklistMerge,
+    pub audit: Vec<ThreatAuditEntry>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct WhitelistResponse {
     pub success: bool,
     pub entity: WhitelistedEntity,
+    pub audit: ThreatAuditEntry,
 }
 
 #[derive(Debug, Serialize)]
@@ -117,16 +147,33 @@ pub struct AlertResponse {
     pub alert: ThreatAlert,
 }
 
+async fn persist_audit(state: &AppState, entry: &ThreatAuditEntry) {
+    if let Err(e) = state.threat_audit_repo.insert(entry).await {
+        warn!(
+            "Failed to persist threat audit entry for {}: {}",
+            entry.address, e
+        );
+    }
+}
+
 pub async fn check_token(
     Path(mint): Path<String>,
     Query(_query): Query<ThreatCheckQuery>,
-    State(_config): State<AppState>,
+    State(state): State<AppState>,
 ) -> Result<Json<ThreatCheckResponse>, ErrorResponse> {
     match THREAT_DETECTOR.check_token(&mint).await {
-        Ok(score) => Ok(Json(ThreatCheckResponse {
-            success: true,
-            score,
-        })),
+        Ok((score, response)) => {
+            if let ThreatResponse::AutoBlocked { audit, .. } = &response {
+                persist_audit(&state, audit).await;
+            }
+            if let Some(event) = response.to_event() {
+                crate::events::broadcast_event(&state.event_tx, event);
+            }
+            Ok(Json(ThreatCheckResponse {
+                success: true,
+                score,
+            }))
+        }
         Err(e) => Err(json_error(
             StatusCode::INTERNAL_SERVER_ERROR,
             &format!("Failed to check token: {}", e),
@@ -160,20 +207,24 @@ pub async fn list_blocked(
 }
 
 pub async fn report_threat(
-    State(_config): State<AppState>,
+    State(state): State<AppState>,
     Json(request): Json<ReportThreatRequest>,
 ) -> Json<BlockResponse> {
-    let entity = THREAT_DETECTOR.block_entity(
+    let (entity, audit) = THREAT_DETECTOR.block_entity(
         request.entity_type,
         request.address,
         request.category,
         request.reason,
-        "user_report".to_string(),
+        request
+            .reported_by
+            .unwrap_or_else(|| "user_report".to_string()),
     );
+    persist_audit(&state, &audit).await;
 
     Json(BlockResponse {
         success: true,
         entity,
+        audit,
     })
 }
 
@@ -205,19 +256,21 @@ pub async fn add_watch(
 }
 
 pub async fn whitelist_entity(
-    State(_config): State<AppState>,
+    State(state): State<AppState>,
     Json(request): Json<WhitelistRequest>,
 ) -> Json<WhitelistResponse> {
-    let entity = THREAT_DETECTOR.whitelist_entity(
+    let (entity, audit) = THREAT_DETECTOR.whitelist_entity(
         request.entity_type,
         request.address,
         request.reason,
-        "user".to_string(),
+        request.actor.unwrap_or_else(|| "user".to_string()),
     );
+    persist_audit(&state, &audit).await;
 
     Json(WhitelistResponse {
         success: true,
         entity,
+        audit,
     })
 }
 
@@ -261,28 +314,115 @@ pub async fn get_stats(State(_config): State<AppState>) -> Json<ThreatStats> {
     Json(stats)
 }
 
+pub async fn export_blocklist(
+    State(_config): State<AppState>,
+) -> Result<Json<SignedBlocklistFeed>, ErrorResponse> {
+    let config = &*BLOCKLIST_FEED_CONFIG;
+    config
+        .signing_key()
+        .and_then(|keypair| THREAT_DETECTOR.export_blocklist(config.source.clone(), keypair))
+        .map(Json)
+        .map_err(|e| {
+            json_error(
+                StatusCode::SERVICE_UNAVAILABLE,
+                &format!("Failed to export blocklist: {}", e),
+            )
+        })
+}
+
+pub async fn import_blocklist(
+    State(state): State<AppState>,
+    Json(feed): Json<SignedBlocklistFeed>,
+) -> Result<Json<BlocklistImportResponse>, ErrorResponse> {
+    let (merge, audit) = THREAT_DETECTOR
+        .import_blocklist(&feed, &BLOCKLIST_FEED_CONFIG.trusted_signers)
+        .map_err(|e| {
+            let status = match e {
+                AppError::Unauthorized(_) => StatusCode::FORBIDDEN,
+                _ => StatusCode::BAD_REQUEST,
+            };
+            json_error(status, &format!("Rejected blocklist feed: {}", e))
+        })?;
+    for entry in &audit {
+        persist_audit(&state, entry).await;
+    }
+
+    Ok(Json(BlocklistImportResponse {
+        success: true,
+        merge,
+        audit,
+    }))
+}
+
 pub async fn remove_from_blocklist(
     Path(address): Path<String>,
-    State(_config): State<AppState>,
+    Query(query): Query<AuditActorQuery>,
+    State(state): State<AppState>,
 ) -> Json<serde_json::Value> {
-    let removed = THREAT_DETECTOR.remove_from_blocklist(&address);
+    let audit = THREAT_DETECTOR.remove_from_blocklist(
+        &address,
+        query.actor.unwrap_or_else(|| "user".to_string()),
+        query.reason,
+    );
+    if let Some(ref entry) = audit {
+        persist_audit(&state, entry).await;
+    }
     Json(serde_json::json!({
-        "success": removed,
-        "address": address
+        "success": audit.is_some(),
+        "address": address,
+        "audit": audit
     }))
 }
 
 pub async fn remove_from_whitelist(
     Path(address): Path<String>,
-    State(_config): State<AppState>,
+    Query(query): Query<AuditActorQuery>,
+    State(state): State<AppState>,
 ) -> Json<serde_json::Value> {
-    let removed = THREAT_DETECTOR.remove_from_whitelist(&address);
+    let audit = THREAT_DETECTOR.remove_from_whitelist(
+        &address,
+        query.actor.unwrap_or_else(|| "user".to_string()),
+        query.reason,
+    );
+    if let Some(ref entry) = audit {
+        persist_audit(&state, entry).await;
+    }
     Json(serde_json::json!({
-        "success": removed,
-        "address": address
+        "success": audit.is_some(),
+        "address": address,
+        "audit": audit
     }))
 }
 
+pub async fn get_audit_log(
+    Query(query): Query<AuditQuery>,
+    State(state): State<AppState>,
+) -> Json<Vec<ThreatAuditRecord>> {
+    let limit = query.limit.unwrap_or(100);
+    match state
+        .threat_audit_repo
+        .list(
+            query.address.as_deref(),
+            query.action.as_deref(),
+            limit as i64,
+        )
+        .await
+    {
+        Ok(records) => Json(records),
+        Err(e) => {
+            warn!("Threat audit query failed, serving in-memory log: {}", e);
+            let records = THREAT_DETECTOR
+                .get_audit_log(query.address.as_deref(), usize::MAX)
+                .into_iter()
+                .map(ThreatAuditRecord::from)
+                .filter(|r| query.action.as_deref().map_or(true, |a| r.action == a))
+                .take(limit)
+                .collect();
+            Json(records)
+        }
+    }
+}
+
 pub async fn is_blocked(
     Path(address): Path<String>,
     State(_config): State<AppState>,
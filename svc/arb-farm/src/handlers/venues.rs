@@ -0,0 +1,64 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use std::sync::Arc;
+
+use crate::error::{AppError, AppResult};
+use crate::server::AppState;
+use crate::venues::fees::DEFAULT_FEE_COMPARE_SIZE_LAMPORTS;
+use crate::venues::{
+    compare_fees, compare_quotes, CompareQuoteRequest, FeeCompareQuery, FeeComparison, MevVenue,
+    QuoteComparison,
+};
+
+pub async fn compare_quote(
+    State(state): State<AppState>,
+    Json(request): Json<CompareQuoteRequest>,
+) -> AppResult<Json<QuoteComparison>> {
+    if request.mint.trim().is_empty() {
+        return Err(AppError::Validation("mint is required".to_string()));
+    }
+    if request.amount == 0 {
+        return Err(AppError::Validation(
+            "amount must be greater than zero".to_string(),
+        ));
+    }
+
+    let comparison = compare_quotes(&trading_venues(&state), &request.to_quote_params()).await;
+
+    tracing::info!(
+        mint = %request.mint,
+        side = ?request.side,
+        amount = request.amount,
+        quoted = comparison.quotes.len(),
+        skipped = comparison.skipped.len(),
+        best = ?comparison.best_venue,
+        "Compared venue quotes"
+    );
+
+    Ok(Json(comparison))
+}
+
+pub async fn compare_venue_fees(
+    State(state): State<AppState>,
+    Query(query): Query<FeeCompareQuery>,
+) -> AppResult<Json<FeeComparison>> {
+    let size_lamports = query.amount.unwrap_or(DEFAULT_FEE_COMPARE_SIZE_LAMPORTS);
+    if size_lamports == 0 {
+        return Err(AppError::Validation(
+            "amount must be greater than zero".to_string(),
+        ));
+    }
+
+    Ok(Json(compare_fees(&trading_venues(&state), size_lamports)))
+}
+
+fn trading_venues(state: &AppState) -> Vec<Arc<dyn MevVenue>> {
+    vec![
+        state.jupiter_venue.clone(),
+        state.raydium_venue.clone(),
+        state.pump_fun_venue.clone(),
+        state.moonshot_venue.clone(),
+    ]
+}
@@ -1,10 +1,17 @@
-use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::error::AppResult;
 use crate::server::AppState;
 use crate::wallet::turnkey::WalletSetupRequest;
-use crate::wallet::{ArbFarmPolicy, SignRequest, SignResult, WalletStatus};
+use crate::wallet::{
+    ArbFarmPolicy, SignAuditEntry, SignOutcome, SignRequest, SignResult, WalletStatus,
+};
 
 #[derive(Debug, Serialize)]
 pub struct WalletStatusResponse {
@@ -129,6 +136,19 @@ pub async fn update_policy(
 pub struct BalanceResponse {
     pub balance_lamports: u64,
     pub balance_sol: f64,
+    pub from_cache: bool,
+    pub cache_age_ms: Option<u64>,
+}
+
+impl BalanceResponse {
+    fn empty() -> Self {
+        Self {
+            balance_lamports: 0,
+            balance_sol: 0.0,
+            from_cache: false,
+            cache_age_ms: None,
+        }
+    }
 }
 
 pub async fn get_balance(State(state): State<AppState>) -> impl IntoResponse {
@@ -136,52 +156,67 @@ pub async fn get_balance(State(state): State<AppState>) -> impl IntoResponse {
 
     match status.wallet_address {
         Some(address) => {
-            // Fetch balance from RPC
-            match fetch_balance(&state.config.rpc_url, &address).await {
-                Ok(balance) => {
-                    // Update cached balance
-                    let _ = state.turnkey_signer.update_balance(balance).await;
+            let mut fetched = false;
+            let result = state
+                .balance_cache
+                .get_or_fetch(&address, || {
+                    fetched = true;
+                    fetch_balance(&state.config.rpc_url, &address)
+                })
+                .await;
+
+            match result {
+                Ok(cached) => {
+                    if fetched {
+                        let _ = state.turnkey_signer.update_balance(cached.lamports).await;
+                    }
 
                     (
                         StatusCode::OK,
                         Json(BalanceResponse {
-                            balance_lamports: balance,
-                            balance_sol: balance as f64 / 1_000_000_000.0,
+                            balance_lamports: cached.lamports,
+                            balance_sol: cached.sol(),
+                            from_cache: !fetched,
+                            cache_age_ms: Some(cached.age().as_millis() as u64),
                         }),
                     )
                 }
-                Err(e) => {
-                    // Return cached balance if RPC fails
+                Err(_) => {
+                    // Return last known signer balance if RPC fails (age unknown)
                     if let Some(cached) = status.balance_lamports {
                         (
                             StatusCode::OK,
                             Json(BalanceResponse {
                                 balance_lamports: cached,
                                 balance_sol: cached as f64 / 1_000_000_000.0,
+                                from_cache: true,
+                                cache_age_ms: None,
                             }),
                         )
                     } else {
                         (
                             StatusCode::SERVICE_UNAVAILABLE,
-                            Json(BalanceResponse {
-                                balance_lamports: 0,
-                                balance_sol: 0.0,
-                            }),
+                            Json(BalanceResponse::empty()),
                         )
                     }
                 }
             }
         }
-        None => (
-            StatusCode::BAD_REQUEST,
-            Json(BalanceResponse {
-                balance_lamports: 0,
-                balance_sol: 0.0,
-            }),
-        ),
+        None => (StatusCode::BAD_REQUEST, Json(BalanceResponse::empty())),
     }
 }
 
+pub async fn invalidate_balance_cache(State(state): State<AppState>) -> impl IntoResponse {
+    state.balance_cache.invalidate_all().await;
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": true,
+            "message": "Balance cache invalidated",
+        })),
+    )
+}
+
 async fn fetch_balance(rpc_url: &str, address: &str) -> AppResult<u64> {
     let client = reqwest::Client::new();
 
@@ -326,48 +361,47 @@ pub async fn test_sign(
 
     let dev_connected = state.dev_signer.get_status().await.is_connected;
 
-    if dev_connected {
-        match state.dev_signer.validate_transaction(&sign_request).await {
-            Ok(_) => (
-                StatusCode::OK,
-                Json(serde_json::json!({
-                    "success": true,
-                    "message": "Transaction would pass policy checks",
-                    "signing_mode": "dev_private_key",
-                    "amount_sol": request.amount_sol,
-                })),
-            ),
-            Err(violation) => (
-                StatusCode::BAD_REQUEST,
-                Json(serde_json::json!({
-                    "success": false,
-                    "violation": violation,
-                })),
-            ),
-        }
+    let (signing_mode, validation) = if dev_connected {
+        (
+            "dev_private_key",
+            state.dev_signer.validate_transaction(&sign_request).await,
+        )
     } else {
-        match state
-            .turnkey_signer
-            .validate_transaction(&sign_request)
-            .await
-        {
-            Ok(_) => (
-                StatusCode::OK,
-                Json(serde_json::json!({
-                    "success": true,
-                    "message": "Transaction would pass policy checks",
-                    "signing_mode": "turnkey",
-                    "amount_sol": request.amount_sol,
-                })),
-            ),
-            Err(violation) => (
-                StatusCode::BAD_REQUEST,
-                Json(serde_json::json!({
-                    "success": false,
-                    "violation": violation,
-                })),
-            ),
-        }
+        (
+            "turnkey",
+            state
+                .turnkey_signer
+                .validate_transaction(&sign_request)
+                .await,
+        )
+    };
+
+    state
+        .sign_audit_log
+        .record(SignAuditEntry::dry_run(
+            signing_mode,
+            &sign_request,
+            &validation,
+        ))
+        .await;
+
+    match validation {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(serde_json::json!({
+                "success": true,
+                "message": "Transaction would pass policy checks",
+                "signing_mode": signing_mode,
+                "amount_sol": request.amount_sol,
+            })),
+        ),
+        Err(violation) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "success": false,
+                "violation": violation,
+            })),
+        ),
     }
 }
 
@@ -461,6 +495,28 @@ pub async fn sign_transaction(
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SignLogQuery {
+    pub limit: Option<usize>,
+    pub signer: Option<String>,
+    pub outcome: Option<SignOutcome>,
+}
+
+pub async fn get_sign_log(
+    Query(query): Query<SignLogQuery>,
+    State(state): State<AppState>,
+) -> Json<Vec<SignAuditEntry>> {
+    let entries = state
+        .sign_audit_log
+        .list(
+            query.signer.as_deref(),
+            query.outcome,
+            query.limit.unwrap_or(100),
+        )
+        .await;
+    Json(entries)
+}
+
 #[derive(Debug, Serialize)]
 pub struct DailyUsageResponse {
     pub date: String,
@@ -514,6 +570,7 @@ pub struct CapitalUsageResponse {
 pub struct StrategyAllocationInfo {
     pub strategy_id: uuid::Uuid,
     pub max_allocation_percent: f64,
+    pub target_allocation_percent: f64,
     pub max_allocation_sol: f64,
     pub current_reserved_sol: f64,
     pub available_sol: f64,
@@ -538,6 +595,7 @@ pub async fn get_capital_usage(State(state): State<AppState>) -> impl IntoRespon
         .map(|usage| StrategyAllocationInfo {
             strategy_id: usage.strategy_id,
             max_allocation_percent: usage.max_allocation_percent,
+            target_allocation_percent: usage.target_allocation_percent,
             max_allocation_sol: usage.max_allocation_lamports as f64 / 1_000_000_000.0,
             current_reserved_sol: usage.current_reserved_lamports as f64 / 1_000_000_000.0,
             available_sol: usage.available_lamports as f64 / 1_000_000_000.0,
@@ -569,6 +627,63 @@ pub async fn get_capital_usage(State(state): State<AppState>) -> impl IntoRespon
     )
 }
 
+#[derive(Debug, Deserialize)]
+pub struct RebalanceCapitalRequest {
+    /// Strategy id -> budget as a percent of total balance
+    pub allocations: std::collections::HashMap<uuid::Uuid, f64>,
+}
+
+pub async fn rebalance_capital(
+    State(state): State<AppState>,
+    Json(request): Json<RebalanceCapitalRequest>,
+) -> impl IntoResponse {
+    if request
+        .allocations
+        .values()
+        .any(|p| !p.is_finite() || *p < 0.0 || *p > 100.0)
+    {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "success": false,
+                "error": "Allocation percents must be between 0 and 100",
+            })),
+        );
+    }
+
+    if let Err(e) = state.capital_manager.rebalance(request.allocations).await {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "success": false,
+                "error": e.to_string(),
+            })),
+        );
+    }
+
+    let allocations: Vec<serde_json::Value> = state
+        .capital_manager
+        .get_all_strategy_usage()
+        .await
+        .into_iter()
+        .map(|usage| {
+            serde_json::json!({
+                "strategy_id": usage.strategy_id,
+                "max_allocation_percent": usage.max_allocation_percent,
+                "target_allocation_percent": usage.target_allocation_percent,
+            })
+        })
+        .collect();
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": true,
+            "strategy_allocations": allocations,
+        })),
+    )
+}
+
 pub async fn sync_capital_balance(State(state): State<AppState>) -> impl IntoResponse {
     let wallet_status = state.turnkey_signer.get_status().await;
 
@@ -16,7 +16,8 @@ use crate::events::{kol as kol_topics, ArbEvent};
 use crate::models::KolTradeType;
 use crate::server::AppState;
 use crate::webhooks::helius::{
-    EnhancedTransactionEvent, HeliusWebhookPayload, TransactionType, WebhookConfig, WebhookType,
+    filter_subscribed_events, EnhancedTransactionEvent, HeliusWebhookPayload, TransactionType,
+    WebhookConfig, WebhookType, DEFAULT_REGISTER_TRANSACTION_TYPES,
 };
 use crate::webhooks::parser::{KOLTradeSignal, ParsedSwap, TransactionParser};
 
@@ -26,6 +27,7 @@ pub struct WebhookStatusResponse {
     pub active_webhooks: usize,
     pub processed_events: u64,
     pub last_event_at: Option<String>,
+    pub transaction_types: Vec<TransactionType>,
 }
 
 pub async fn get_webhook_status(
@@ -38,6 +40,7 @@ pub async fn get_webhook_status(
         active_webhooks: 0,
         processed_events: 0,
         last_event_at: None,
+        transaction_types: state.config.webhook_transaction_types(),
     }))
 }
 
@@ -75,7 +78,11 @@ pub async fn register_webhook(
     let config = WebhookConfig {
         webhook_url,
         webhook_type: WebhookType::Enhanced,
-        transaction_types: vec![TransactionType::Swap, TransactionType::Transfer],
+        transaction_types: state
+            .config
+            .helius_webhook_transaction_types
+            .clone()
+            .unwrap_or_else(|| DEFAULT_REGISTER_TRANSACTION_TYPES.to_vec()),
         account_addresses: request.wallet_addresses.clone(),
         auth_header: None,
     };
@@ -198,6 +205,12 @@ pub async fn receive_helius_webhook(
 
     tracing::info!("Received Helius webhook with {} events", payload.len());
 
+    let (payload, dropped) =
+        filter_subscribed_events(payload, &state.config.webhook_transaction_types());
+    if dropped > 0 {
+        tracing::debug!("Dropped {} webhook events of unsubscribed types", dropped);
+    }
+
     for event in payload {
         if TransactionParser::is_swap_transaction(&event) {
             if let Some(swap) = TransactionParser::parse_swap(&event) {
@@ -42,6 +42,11 @@ impl HeliusClient {
 
     #[cfg(test)]
     pub fn new_mock() -> Self {
+        Self::new_mock_with_rpc_url("http://localhost:8899")
+    }
+
+    #[cfg(test)]
+    pub fn new_mock_with_rpc_url(rpc_url: &str) -> Self {
         let http_client = Client::builder()
             .timeout(Duration::from_secs(5))
             .build()
@@ -50,7 +55,7 @@ impl HeliusClient {
         Self {
             http_client,
             api_key: None,
-            rpc_url: "http://localhost:8899".to_string(),
+            rpc_url: rpc_url.to_string(),
             sender_url: "http://localhost:8899".to_string(),
             laserstream_url: "ws://localhost:8899".to_string(),
             config: Arc::new(RwLock::new(HeliusConfig::default())),
@@ -164,6 +169,60 @@ impl HeliusClient {
         }
     }
 
+    pub async fn rpc_call_batch(
+        &self,
+        calls: Vec<(&str, serde_json::Value)>,
+    ) -> AppResult<Vec<AppResult<serde_json::Value>>> {
+        if calls.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let url = self.rpc_url_with_key();
+        let call_count = calls.len();
+
+        let request_body: Vec<serde_json::Value> = calls
+            .iter()
+            .enumerate()
+            .map(|(id, (method, params))| {
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "method": method,
+                    "params": params
+                })
+            })
+            .collect();
+
+        debug!("Helius RPC batch: {} calls to {}", call_count, self.rpc_url);
+
+        let response = self
+            .http_client
+            .post(&url)
+            .json(&request_body)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalApi(format!("Helius RPC batch request failed: {}", e))
+            })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            error!("Helius RPC batch error: status={}, body={}", status, body);
+            return Err(AppError::ExternalApi(format!(
+                "Helius RPC batch error: status={}, body={}",
+                status, body
+            )));
+        }
+
+        let rpc_responses: Vec<RpcResponse<serde_json::Value>> =
+            response.json().await.map_err(|e| {
+                AppError::Serialization(format!("Failed to parse Helius batch response: {}", e))
+            })?;
+
+        Ok(demux_batch_responses(&calls, rpc_responses))
+    }
+
     pub async fn emit_event(&self, topic: &str, event_type: &str, payload: serde_json::Value) {
         if let Some(bus) = &self.event_bus {
             let event = ArbEvent::new(
@@ -259,6 +318,214 @@ impl HeliusClient {
             .await?;
         Ok(result)
     }
+
+    /// Current status of a signature, or `None` if no node has seen it (never landed, or
+    /// dropped by a fork).
+    pub async fn get_signature_status(
+        &self,
+        signature: &str,
+    ) -> AppResult<Option<SignatureStatus>> {
+        self.signature_status(signature, false).await
+    }
+
+    /// Like `get_signature_status`, but also searches the ledger beyond the recent
+    /// status cache, so a transaction that landed a while ago is still found.
+    pub async fn search_signature_status(
+        &self,
+        signature: &str,
+    ) -> AppResult<Option<SignatureStatus>> {
+        self.signature_status(signature, true).await
+    }
+
+    async fn signature_status(
+        &self,
+        signature: &str,
+        search_transaction_history: bool,
+    ) -> AppResult<Option<SignatureStatus>> {
+        let response: RpcValue<Vec<Option<SignatureStatus>>> = self
+            .rpc_call(
+                "getSignatureStatuses",
+                json!([[signature], {"searchTransactionHistory": search_transaction_history}]),
+            )
+            .await?;
+        Ok(response.value.into_iter().next().flatten())
+    }
+
+    /// Fetches a single page of program accounts via Helius' paginated
+    /// `getProgramAccountsV2`. Pass the previous page's `pagination_key` to continue.
+    pub async fn get_program_accounts_page(
+        &self,
+        program_id: &str,
+        query: &ProgramAccountsQuery,
+        pagination_key: Option<&str>,
+    ) -> AppResult<ProgramAccountsPage> {
+        let mut config = json!({
+            "encoding": "base64",
+            "commitment": "confirmed",
+            "filters": query.filters,
+            "limit": query.page_size.clamp(1, MAX_PROGRAM_ACCOUNTS_PAGE_SIZE),
+        });
+        if let Some(slot) = query.changed_since_slot {
+            config["changedSinceSlot"] = json!(slot);
+        }
+        if let Some(key) = pagination_key {
+            config["paginationKey"] = json!(key);
+        }
+
+        self.rpc_call("getProgramAccountsV2", json!([program_id, config]))
+            .await
+    }
+
+    /// Fetches all program accounts matching the query's memcmp/dataSize filters,
+    /// following pagination until the RPC reports no more pages or `max_pages` is hit.
+    pub async fn get_program_accounts(
+        &self,
+        program_id: &str,
+        query: &ProgramAccountsQuery,
+    ) -> AppResult<Vec<ProgramAccount>> {
+        let mut accounts = Vec::new();
+        let mut pagination_key: Option<String> = None;
+
+        let max_pages = query.max_pages.max(1);
+
+        for _ in 0..max_pages {
+            let page = self
+                .get_program_accounts_page(program_id, query, pagination_key.as_deref())
+                .await?;
+            accounts.extend(page.accounts);
+
+            match page.pagination_key {
+                Some(key) if !key.is_empty() => pagination_key = Some(key),
+                _ => return Ok(accounts),
+            }
+        }
+
+        warn!(
+            "getProgramAccounts for {} stopped after {} pages ({} accounts)",
+            program_id,
+            max_pages,
+            accounts.len()
+        );
+        Ok(accounts)
+    }
+}
+
+pub const MAX_PROGRAM_ACCOUNTS_PAGE_SIZE: usize = 10_000;
+pub const DEFAULT_PROGRAM_ACCOUNTS_PAGE_SIZE: usize = 1_000;
+pub const DEFAULT_PROGRAM_ACCOUNTS_MAX_PAGES: usize = 20;
+
+/// Anchor account discriminator for pump.fun `BondingCurve` accounts.
+pub const PUMP_FUN_BONDING_CURVE_DISCRIMINATOR: [u8; 8] = [23, 183, 248, 55, 96, 216, 172, 96];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProgramAccountFilter {
+    Memcmp { offset: usize, bytes: String },
+    DataSize(u64),
+}
+
+impl ProgramAccountFilter {
+    pub fn memcmp(offset: usize, bytes: &[u8]) -> Self {
+        Self::Memcmp {
+            offset,
+            bytes: bs58::encode(bytes).into_string(),
+        }
+    }
+
+    pub fn data_size(size: u64) -> Self {
+        Self::DataSize(size)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ProgramAccountsQuery {
+    pub filters: Vec<ProgramAccountFilter>,
+    pub page_size: usize,
+    pub max_pages: usize,
+    /// Only return accounts modified after this slot.
+    pub changed_since_slot: Option<u64>,
+}
+
+impl Default for ProgramAccountsQuery {
+    fn default() -> Self {
+        Self {
+            filters: Vec::new(),
+            page_size: DEFAULT_PROGRAM_ACCOUNTS_PAGE_SIZE,
+            max_pages: DEFAULT_PROGRAM_ACCOUNTS_MAX_PAGES,
+            changed_since_slot: None,
+        }
+    }
+}
+
+impl ProgramAccountsQuery {
+    pub fn new(filters: Vec<ProgramAccountFilter>) -> Self {
+        Self {
+            filters,
+            ..Self::default()
+        }
+    }
+
+    /// Matches pump.fun bonding curve accounts by their Anchor discriminator.
+    pub fn pump_fun_bonding_curves() -> Self {
+        Self::new(vec![ProgramAccountFilter::memcmp(
+            0,
+            &PUMP_FUN_BONDING_CURVE_DISCRIMINATOR,
+        )])
+    }
+
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    pub fn with_max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = max_pages;
+        self
+    }
+
+    pub fn with_changed_since_slot(mut self, slot: u64) -> Self {
+        self.changed_since_slot = Some(slot);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgramAccountsPage {
+    pub accounts: Vec<ProgramAccount>,
+    pub pagination_key: Option<String>,
+    #[serde(default)]
+    pub total_results: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgramAccount {
+    pub pubkey: String,
+    pub account: ProgramAccountData,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgramAccountData {
+    pub lamports: u64,
+    pub owner: String,
+    /// `[data, encoding]` as returned for base64 encoding.
+    pub data: (String, String),
+    pub executable: bool,
+    #[serde(default)]
+    pub space: Option<u64>,
+}
+
+impl ProgramAccount {
+    pub fn data_bytes(&self) -> AppResult<Vec<u8>> {
+        base64::Engine::decode(
+            &base64::engine::general_purpose::STANDARD,
+            &self.account.data.0,
+        )
+        .map_err(|e| {
+            AppError::Serialization(format!("Invalid account data for {}: {}", self.pubkey, e))
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -292,6 +559,20 @@ pub struct SignatureInfo {
     pub err: Option<serde_json::Value>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+struct RpcValue<T> {
+    value: T,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignatureStatus {
+    pub slot: u64,
+    pub confirmations: Option<u64>,
+    pub err: Option<serde_json::Value>,
+    #[serde(rename = "confirmationStatus")]
+    pub confirmation_status: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionMeta {
     pub fee: u64,
@@ -319,6 +600,44 @@ pub struct TransactionResponse {
     pub slot: u64,
 }
 
+fn demux_batch_responses(
+    calls: &[(&str, serde_json::Value)],
+    responses: Vec<RpcResponse<serde_json::Value>>,
+) -> Vec<AppResult<serde_json::Value>> {
+    let mut slots: Vec<Option<RpcResponse<serde_json::Value>>> =
+        (0..calls.len()).map(|_| None).collect();
+
+    for response in responses {
+        if let Some(slot) = slots.get_mut(response.id as usize) {
+            *slot = Some(response);
+        }
+    }
+
+    slots
+        .into_iter()
+        .zip(calls.iter())
+        .map(|(slot, (method, _))| match slot {
+            Some(RpcResponse {
+                result: Some(result),
+                ..
+            }) => Ok(result),
+            Some(RpcResponse { error, .. }) => {
+                let error_msg = error
+                    .map(|e| format!("{}: {}", e.code, e.message))
+                    .unwrap_or_else(|| "Unknown error".to_string());
+                Err(AppError::ExternalApi(format!(
+                    "Helius RPC error ({}): {}",
+                    method, error_msg
+                )))
+            }
+            None => Err(AppError::ExternalApi(format!(
+                "Helius RPC batch response missing for {}",
+                method
+            ))),
+        })
+        .collect()
+}
+
 #[derive(Debug, Deserialize)]
 struct RpcResponse<T> {
     jsonrpc: String,
@@ -332,3 +651,253 @@ struct RpcError {
     code: i64,
     message: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn spawn_mock_rpc(
+        respond: impl Fn(Vec<serde_json::Value>) -> serde_json::Value + Clone + Send + Sync + 'static,
+    ) -> String {
+        spawn_mock_rpc_server(move |body| respond(serde_json::from_value(body).unwrap())).await
+    }
+
+    /// Serves JSON-RPC on `POST /` until the test ends.
+    async fn spawn_mock_rpc_server(
+        respond: impl Fn(serde_json::Value) -> serde_json::Value + Clone + Send + Sync + 'static,
+    ) -> String {
+        let app = axum::Router::new().route(
+            "/",
+            axum::routing::post(move |axum::Json(request): axum::Json<serde_json::Value>| {
+                let reply = respond(request);
+                async move { axum::Json(reply) }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        url
+    }
+
+    #[tokio::test]
+    async fn test_rpc_call_batch_preserves_call_order() {
+        let url = spawn_mock_rpc(|requests| {
+            let responses: Vec<serde_json::Value> = requests
+                .iter()
+                .rev()
+                .map(|r| {
+                    json!({
+                        "jsonrpc": "2.0",
+                        "id": r["id"],
+                        "result": { "method": r["method"] }
+                    })
+                })
+                .collect();
+            json!(responses)
+        })
+        .await;
+
+        let client = HeliusClient::new_mock_with_rpc_url(&url);
+        let results = client
+            .rpc_call_batch(vec![
+                ("getBalance", json!(["wallet"])),
+                ("getSlot", json!([])),
+                ("getSignatureStatuses", json!([["sig"]])),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 3);
+        let methods: Vec<String> = results
+            .into_iter()
+            .map(|r| r.unwrap()["method"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(
+            methods,
+            vec!["getBalance", "getSlot", "getSignatureStatuses"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rpc_call_batch_isolates_per_call_errors() {
+        let url = spawn_mock_rpc(|requests| {
+            let responses: Vec<serde_json::Value> = requests
+                .iter()
+                .map(|r| {
+                    if r["method"] == "getAccountInfo" {
+                        json!({
+                            "jsonrpc": "2.0",
+                            "id": r["id"],
+                            "error": { "code": -32602, "message": "Invalid param" }
+                        })
+                    } else {
+                        json!({
+                            "jsonrpc": "2.0",
+                            "id": r["id"],
+                            "result": { "value": 42 }
+                        })
+                    }
+                })
+                .collect();
+            json!(responses)
+        })
+        .await;
+
+        let client = HeliusClient::new_mock_with_rpc_url(&url);
+        let results = client
+            .rpc_call_batch(vec![
+                ("getBalance", json!(["wallet"])),
+                ("getAccountInfo", json!(["bad"])),
+                ("getBalance", json!(["other"])),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap()["value"], 42);
+        let err = results[1].as_ref().unwrap_err().to_string();
+        assert!(err.contains("getAccountInfo"));
+        assert!(err.contains("Invalid param"));
+        assert_eq!(results[2].as_ref().unwrap()["value"], 42);
+    }
+
+    #[test]
+    fn test_demux_reports_missing_responses() {
+        let calls = vec![("getSlot", json!([])), ("getBalance", json!(["w"]))];
+        let responses = vec![RpcResponse {
+            jsonrpc: "2.0".to_string(),
+            id: 0,
+            result: Some(json!(100)),
+            error: None,
+        }];
+
+        let results = demux_batch_responses(&calls, responses);
+
+        assert_eq!(results[0].as_ref().unwrap(), &json!(100));
+        assert!(results[1].is_err());
+    }
+
+    #[test]
+    fn test_program_account_filters_serialize_as_rpc_filters() {
+        let filters = vec![
+            ProgramAccountFilter::memcmp(0, &PUMP_FUN_BONDING_CURVE_DISCRIMINATOR),
+            ProgramAccountFilter::data_size(150),
+        ];
+
+        assert_eq!(
+            serde_json::to_value(&filters).unwrap(),
+            json!([
+                {
+                    "memcmp": {
+                        "offset": 0,
+                        "bytes": bs58::encode(PUMP_FUN_BONDING_CURVE_DISCRIMINATOR).into_string()
+                    }
+                },
+                { "dataSize": 150 }
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_program_accounts_sends_filters_and_follows_pagination() {
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+
+        let url = spawn_mock_rpc_server(move |request| {
+            recorded.lock().unwrap().push(request.clone());
+            let (accounts, next_key) = match request["params"][1]["paginationKey"].as_str() {
+                None => (vec!["curve1", "curve2"], json!("page-2")),
+                Some("page-2") => (vec!["curve3"], serde_json::Value::Null),
+                Some(other) => panic!("unexpected pagination key {}", other),
+            };
+            let accounts: Vec<serde_json::Value> = accounts
+                .into_iter()
+                .map(|pubkey| {
+                    json!({
+                        "pubkey": pubkey,
+                        "account": {
+                            "lamports": 1_500_000,
+                            "owner": crate::venues::curves::math::PUMP_FUN_PROGRAM_ID,
+                            "data": ["F7f4N2DYrGAB", "base64"],
+                            "executable": false,
+                            "rentEpoch": 0,
+                            "space": 9
+                        }
+                    })
+                })
+                .collect();
+            json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "result": { "accounts": accounts, "paginationKey": next_key }
+            })
+        })
+        .await;
+
+        let client = HeliusClient::new_mock_with_rpc_url(&url);
+        let query = ProgramAccountsQuery::pump_fun_bonding_curves()
+            .with_page_size(2)
+            .with_changed_since_slot(250_000_000);
+        let accounts = client
+            .get_program_accounts(crate::venues::curves::math::PUMP_FUN_PROGRAM_ID, &query)
+            .await
+            .unwrap();
+
+        let pubkeys: Vec<&str> = accounts.iter().map(|a| a.pubkey.as_str()).collect();
+        assert_eq!(pubkeys, vec!["curve1", "curve2", "curve3"]);
+        assert_eq!(
+            &accounts[0].data_bytes().unwrap()[..8],
+            &PUMP_FUN_BONDING_CURVE_DISCRIMINATOR
+        );
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0]["method"], "getProgramAccountsV2");
+        assert_eq!(
+            requests[0]["params"][0],
+            crate::venues::curves::math::PUMP_FUN_PROGRAM_ID
+        );
+        let config = &requests[0]["params"][1];
+        assert_eq!(config["encoding"], "base64");
+        assert_eq!(config["limit"], 2);
+        assert_eq!(config["changedSinceSlot"], 250_000_000);
+        assert_eq!(
+            config["filters"],
+            serde_json::to_value(&query.filters).unwrap()
+        );
+        assert!(config.get("paginationKey").is_none());
+        assert_eq!(requests[1]["params"][1]["paginationKey"], "page-2");
+    }
+
+    #[tokio::test]
+    async fn test_get_program_accounts_stops_at_max_pages() {
+        let url = spawn_mock_rpc_server(|request| {
+            json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "result": {
+                    "accounts": [{
+                        "pubkey": "curve",
+                        "account": {
+                            "lamports": 1,
+                            "owner": "owner",
+                            "data": ["", "base64"],
+                            "executable": false
+                        }
+                    }],
+                    "paginationKey": "more"
+                }
+            })
+        })
+        .await;
+
+        let client = HeliusClient::new_mock_with_rpc_url(&url);
+        let query = ProgramAccountsQuery::default().with_max_pages(3);
+        let accounts = client
+            .get_program_accounts("program", &query)
+            .await
+            .unwrap();
+
+        assert_eq!(accounts.len(), 3);
+    }
+}
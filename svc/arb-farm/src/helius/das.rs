@@ -309,6 +309,37 @@ impl DasClient {
         Ok(assets)
     }
 
+    /// One `getAssetsByOwner` page with the filter applied. Unlike `get_assets_by_owner`
+    /// this reads everything from the listing itself instead of a `getAsset` per item.
+    pub async fn get_owned_assets(
+        &self,
+        owner: &str,
+        page: u32,
+        limit: u32,
+        filter: &OwnedAssetFilter,
+    ) -> AppResult<Vec<OwnedAsset>> {
+        #[derive(Debug, Deserialize)]
+        struct AssetList {
+            items: Vec<serde_json::Value>,
+        }
+
+        let response: AssetList = self
+            .client
+            .rpc_call(
+                "getAssetsByOwner",
+                owned_assets_params(owner, page, limit, filter),
+            )
+            .await?;
+
+        let assets = response
+            .items
+            .iter()
+            .filter_map(OwnedAsset::from_das_item)
+            .collect();
+
+        Ok(filter.apply(assets))
+    }
+
     pub async fn search_assets(&self, query: AssetSearchQuery) -> AppResult<Vec<TokenMetadata>> {
         #[derive(Debug, Deserialize)]
         struct SearchResult {
@@ -382,35 +413,50 @@ impl DasClient {
     ) -> AppResult<Vec<TokenAccountInfo>> {
         let mut all_accounts = Vec::new();
 
-        // Query standard SPL Token program
-        let spl_response: TokenAccountsResponse = self
+        // Query standard SPL Token and Token-2022 (many pump.fun tokens use this) in one batch
+        let mut results = self
             .client
-            .rpc_call(
-                "getTokenAccountsByOwner",
-                json!([
-                    owner,
-                    {"programId": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},
-                    {"encoding": "jsonParsed"}
-                ]),
-            )
-            .await?;
+            .rpc_call_batch(vec![
+                (
+                    "getTokenAccountsByOwner",
+                    json!([
+                        owner,
+                        {"programId": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},
+                        {"encoding": "jsonParsed"}
+                    ]),
+                ),
+                (
+                    "getTokenAccountsByOwner",
+                    json!([
+                        owner,
+                        {"programId": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"},
+                        {"encoding": "jsonParsed"}
+                    ]),
+                ),
+            ])
+            .await?
+            .into_iter();
+
+        let spl_value = results.next().unwrap_or_else(|| {
+            Err(AppError::ExternalApi(
+                "Missing SPL token accounts response".to_string(),
+            ))
+        })?;
+        let spl_response: TokenAccountsResponse =
+            serde_json::from_value(spl_value).map_err(|e| {
+                AppError::Serialization(format!("Failed to parse token accounts: {}", e))
+            })?;
 
         all_accounts.extend(self.parse_token_accounts(spl_response));
 
-        // Query Token-2022 program (many pump.fun tokens use this)
-        match self
-            .client
-            .rpc_call::<TokenAccountsResponse>(
-                "getTokenAccountsByOwner",
-                json!([
-                    owner,
-                    {"programId": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"},
-                    {"encoding": "jsonParsed"}
-                ]),
-            )
-            .await
-        {
-            Ok(token2022_response) => {
+        match results.next().map(|r| {
+            r.and_then(|v| {
+                serde_json::from_value::<TokenAccountsResponse>(v).map_err(|e| {
+                    AppError::Serialization(format!("Failed to parse token accounts: {}", e))
+                })
+            })
+        }) {
+            Some(Ok(token2022_response)) => {
                 let token2022_accounts = self.parse_token_accounts(token2022_response);
                 info!(
                     "Found {} Token-2022 accounts with balance",
@@ -418,9 +464,10 @@ impl DasClient {
                 );
                 all_accounts.extend(token2022_accounts);
             }
-            Err(e) => {
+            Some(Err(e)) => {
                 warn!("Failed to fetch Token-2022 accounts: {}", e);
             }
+            None => {}
         }
 
         Ok(all_accounts)
@@ -458,3 +505,296 @@ pub struct AssetSearchQuery {
     pub page: Option<u32>,
     pub limit: Option<u32>,
 }
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetKind {
+    #[default]
+    Any,
+    Fungible,
+    Nft,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetSort {
+    /// Highest USD value first; assets without a price go last.
+    Value,
+    /// Most recently active first, ordered by DAS itself.
+    RecentActivity,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OwnedAssetFilter {
+    #[serde(default)]
+    pub kind: AssetKind,
+    pub collection: Option<String>,
+    /// Assets without price data never pass a minimum value.
+    pub min_value_usd: Option<f64>,
+    pub sort: Option<AssetSort>,
+}
+
+impl OwnedAssetFilter {
+    pub fn apply(&self, assets: Vec<OwnedAsset>) -> Vec<OwnedAsset> {
+        let mut assets: Vec<OwnedAsset> = assets
+            .into_iter()
+            .filter(|a| match self.kind {
+                AssetKind::Any => true,
+                AssetKind::Fungible => a.fungible,
+                AssetKind::Nft => !a.fungible,
+            })
+            .filter(|a| {
+                self.collection
+                    .as_ref()
+                    .map_or(true, |c| a.collection.as_ref() == Some(c))
+            })
+            .filter(|a| {
+                self.min_value_usd
+                    .map_or(true, |min| a.value_usd.is_some_and(|v| v >= min))
+            })
+            .collect();
+
+        if self.sort == Some(AssetSort::Value) {
+            assets.sort_by(|a, b| match (a.value_usd, b.value_usd) {
+                (Some(a), Some(b)) => b.total_cmp(&a),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            });
+        }
+        assets
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OwnedAsset {
+    pub id: String,
+    pub interface: String,
+    pub fungible: bool,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub collection: Option<String>,
+    pub balance: Option<f64>,
+    pub price_per_token_usd: Option<f64>,
+    pub value_usd: Option<f64>,
+}
+
+impl OwnedAsset {
+    fn from_das_item(item: &serde_json::Value) -> Option<Self> {
+        let id = item.get("id")?.as_str()?.to_string();
+        let interface = item
+            .get("interface")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unknown")
+            .to_string();
+        let metadata = item.pointer("/content/metadata");
+        let token_info = item.get("token_info");
+
+        let collection = item
+            .get("grouping")
+            .and_then(|g| g.as_array())
+            .and_then(|groups| {
+                groups
+                    .iter()
+                    .find(|g| g.get("group_key").and_then(|k| k.as_str()) == Some("collection"))
+            })
+            .and_then(|g| g.get("group_value"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string);
+
+        let balance = token_info.and_then(|info| {
+            let raw = info.get("balance")?.as_f64()?;
+            let decimals = info.get("decimals").and_then(|d| d.as_i64()).unwrap_or(0);
+            Some(raw / 10f64.powi(decimals as i32))
+        });
+
+        Some(Self {
+            fungible: interface.starts_with("Fungible"),
+            name: metadata
+                .and_then(|m| m.get("name"))
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            symbol: metadata
+                .and_then(|m| m.get("symbol"))
+                .or_else(|| token_info.and_then(|info| info.get("symbol")))
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            collection,
+            balance,
+            price_per_token_usd: token_info
+                .and_then(|info| info.pointer("/price_info/price_per_token"))
+                .and_then(|v| v.as_f64()),
+            value_usd: token_info
+                .and_then(|info| info.pointer("/price_info/total_price"))
+                .and_then(|v| v.as_f64()),
+            id,
+            interface,
+        })
+    }
+}
+
+fn owned_assets_params(
+    owner: &str,
+    page: u32,
+    limit: u32,
+    filter: &OwnedAssetFilter,
+) -> serde_json::Value {
+    let mut params = json!({
+        "ownerAddress": owner,
+        "page": page,
+        "limit": limit,
+        "displayOptions": {
+            "showFungible": filter.kind != AssetKind::Nft,
+        },
+    });
+    if filter.sort == Some(AssetSort::RecentActivity) {
+        params["sortBy"] = json!({
+            "sortBy": "recent_action",
+            "sortDirection": "desc",
+        });
+    }
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn das_page() -> Vec<OwnedAsset> {
+        let items = json!([
+            {
+                "interface": "FungibleToken",
+                "id": "BonkMint",
+                "content": { "metadata": { "name": "Bonk", "symbol": "BONK" } },
+                "token_info": {
+                    "balance": 5_000_000_000u64,
+                    "decimals": 5,
+                    "price_info": { "price_per_token": 0.00002, "total_price": 1.0 }
+                }
+            },
+            {
+                "interface": "ProgrammableNFT",
+                "id": "MadLad1",
+                "content": { "metadata": { "name": "Mad Lad #1" } },
+                "grouping": [{ "group_key": "collection", "group_value": "MadLadsCollection" }]
+            },
+            {
+                "interface": "FungibleToken",
+                "id": "JupMint",
+                "content": { "metadata": { "name": "Jupiter", "symbol": "JUP" } },
+                "token_info": {
+                    "balance": 250_000_000u64,
+                    "decimals": 6,
+                    "price_info": { "price_per_token": 0.8, "total_price": 200.0 }
+                }
+            },
+            {
+                "interface": "FungibleAsset",
+                "id": "UnpricedMint",
+                "content": { "metadata": { "name": "Fresh Launch" } },
+                "token_info": { "symbol": "FRESH", "balance": 1000, "decimals": 0 }
+            },
+            {
+                "interface": "V1_NFT",
+                "id": "OtherNft",
+                "grouping": [{ "group_key": "collection", "group_value": "OtherCollection" }]
+            },
+            {
+                "interface": "FungibleToken",
+                "id": "WifMint",
+                "content": { "metadata": { "name": "dogwifhat", "symbol": "WIF" } },
+                "token_info": {
+                    "balance": 40_000_000u64,
+                    "decimals": 6,
+                    "price_info": { "price_per_token": 2.5, "total_price": 100.0 }
+                }
+            }
+        ]);
+        items
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(OwnedAsset::from_das_item)
+            .collect()
+    }
+
+    fn ids(assets: &[OwnedAsset]) -> Vec<&str> {
+        assets.iter().map(|a| a.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_fungible_above_min_value_sorted_by_value() {
+        let filter = OwnedAssetFilter {
+            kind: AssetKind::Fungible,
+            min_value_usd: Some(50.0),
+            sort: Some(AssetSort::Value),
+            ..Default::default()
+        };
+
+        let assets = filter.apply(das_page());
+
+        assert_eq!(ids(&assets), vec!["JupMint", "WifMint"]);
+        assert_eq!(assets[0].balance, Some(250.0));
+        assert_eq!(assets[0].symbol.as_deref(), Some("JUP"));
+    }
+
+    #[test]
+    fn test_value_sort_puts_unpriced_assets_last() {
+        let filter = OwnedAssetFilter {
+            kind: AssetKind::Fungible,
+            sort: Some(AssetSort::Value),
+            ..Default::default()
+        };
+
+        let assets = filter.apply(das_page());
+
+        assert_eq!(
+            ids(&assets),
+            vec!["JupMint", "WifMint", "BonkMint", "UnpricedMint"]
+        );
+        assert_eq!(assets[3].symbol.as_deref(), Some("FRESH"));
+        assert_eq!(assets[3].value_usd, None);
+    }
+
+    #[test]
+    fn test_nft_and_collection_filters() {
+        let nfts = OwnedAssetFilter {
+            kind: AssetKind::Nft,
+            ..Default::default()
+        }
+        .apply(das_page());
+        assert_eq!(ids(&nfts), vec!["MadLad1", "OtherNft"]);
+
+        let collection = OwnedAssetFilter {
+            collection: Some("MadLadsCollection".to_string()),
+            ..Default::default()
+        }
+        .apply(das_page());
+        assert_eq!(ids(&collection), vec!["MadLad1"]);
+
+        // No filter keeps DAS order untouched
+        assert_eq!(OwnedAssetFilter::default().apply(das_page()).len(), 6);
+    }
+
+    #[test]
+    fn test_query_params_request_fungibles_and_recent_activity_sort() {
+        let filter = OwnedAssetFilter {
+            sort: Some(AssetSort::RecentActivity),
+            ..Default::default()
+        };
+        let params = owned_assets_params("owner1", 2, 50, &filter);
+        assert_eq!(params["displayOptions"]["showFungible"], true);
+        assert_eq!(params["sortBy"]["sortBy"], "recent_action");
+        assert_eq!(params["sortBy"]["sortDirection"], "desc");
+        assert_eq!(params["page"], 2);
+
+        let nft_only = OwnedAssetFilter {
+            kind: AssetKind::Nft,
+            sort: Some(AssetSort::Value),
+            ..Default::default()
+        };
+        let params = owned_assets_params("owner1", 1, 50, &nft_only);
+        assert_eq!(params["displayOptions"]["showFungible"], false);
+        assert!(params.get("sortBy").is_none());
+    }
+}
@@ -6,11 +6,17 @@ pub mod sender;
 pub mod types;
 
 pub use client::{
-    HeliusClient, TokenAccountBalance, TokenLargestAccountsResponse, TransactionMeta,
-    TransactionResponse,
+    HeliusClient, ProgramAccount, ProgramAccountFilter, ProgramAccountsPage, ProgramAccountsQuery,
+    SignatureStatus, TokenAccountBalance, TokenLargestAccountsResponse, TransactionMeta,
+    TransactionResponse, PUMP_FUN_BONDING_CURVE_DISCRIMINATOR,
+};
+pub use das::{
+    AssetKind, AssetSort, DasClient, OwnedAsset, OwnedAssetFilter, TokenAccountInfo,
 };
-pub use das::{DasClient, TokenAccountInfo};
 pub use laserstream::LaserStreamClient;
-pub use priority_fee::{PriorityFeeEstimate, PriorityLevel};
+pub use priority_fee::{
+    estimate_priority_fee_for_accounts, PriorityFeeEstimate, PriorityFeeScope, PriorityFeeSource,
+    PriorityLevel, TargetedPriorityFee,
+};
 pub use sender::HeliusSender;
 pub use types::*;
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
+use async_trait::async_trait;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -95,6 +96,65 @@ impl PriorityFeeResponse {
     }
 }
 
+/// Anything that can answer a Helius `getPriorityFeeEstimate` query.
+#[async_trait]
+pub trait PriorityFeeSource: Send + Sync {
+    async fn get_priority_fee_estimate(
+        &self,
+        transaction_base64: Option<&str>,
+        account_keys: Option<&[String]>,
+    ) -> AppResult<PriorityFeeResponse>;
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PriorityFeeScope {
+    /// Estimated from the fees paid to touch the transaction's writable accounts.
+    Accounts,
+    Global,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TargetedPriorityFee {
+    pub micro_lamports: u64,
+    pub scope: PriorityFeeScope,
+}
+
+/// Fee estimate for a transaction writing to `writable_accounts`. Contended accounts
+/// (a hot bonding curve) need more than the global estimate to land, so the
+/// account-scoped estimate is preferred; the global one is used when there are no
+/// accounts or the targeted query fails.
+pub async fn estimate_priority_fee_for_accounts(
+    source: &dyn PriorityFeeSource,
+    writable_accounts: &[String],
+    level: PriorityLevel,
+) -> AppResult<TargetedPriorityFee> {
+    if !writable_accounts.is_empty() {
+        match source
+            .get_priority_fee_estimate(None, Some(writable_accounts))
+            .await
+        {
+            Ok(fees) => {
+                return Ok(TargetedPriorityFee {
+                    micro_lamports: fees.get(level),
+                    scope: PriorityFeeScope::Accounts,
+                })
+            }
+            Err(e) => warn!(
+                "Account-scoped priority fee estimate failed for {} accounts, using global: {}",
+                writable_accounts.len(),
+                e
+            ),
+        }
+    }
+
+    let fees = source.get_priority_fee_estimate(None, None).await?;
+    Ok(TargetedPriorityFee {
+        micro_lamports: fees.get(level),
+        scope: PriorityFeeScope::Global,
+    })
+}
+
 pub struct PriorityFeeMonitor {
     client: Arc<HeliusClient>,
     event_bus: Arc<EventBus>,
@@ -184,8 +244,11 @@ impl PriorityFeeMonitor {
             recommended: response.priority_fee_estimate.unwrap_or(levels.medium),
         };
 
-        let mut cached = self.cached_fees.write().await;
-        *cached = Some(fee_response.clone());
+        // Only the global estimate is cached; scoped ones are specific to a transaction
+        if transaction_base64.is_none() && account_keys.is_none() {
+            let mut cached = self.cached_fees.write().await;
+            *cached = Some(fee_response.clone());
+        }
 
         Ok(fee_response)
     }
@@ -236,6 +299,17 @@ impl PriorityFeeMonitor {
     }
 }
 
+#[async_trait]
+impl PriorityFeeSource for PriorityFeeMonitor {
+    async fn get_priority_fee_estimate(
+        &self,
+        transaction_base64: Option<&str>,
+        account_keys: Option<&[String]>,
+    ) -> AppResult<PriorityFeeResponse> {
+        PriorityFeeMonitor::get_priority_fee_estimate(self, transaction_base64, account_keys).await
+    }
+}
+
 pub fn select_priority_level_for_profit(
     estimated_profit_lamports: i64,
     fees: &PriorityFeeResponse,
@@ -254,3 +328,102 @@ pub fn select_priority_level_for_profit(
         (PriorityLevel::Min, fees.min)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Answers targeted queries with `targeted` (an error when unset) and global
+    /// ones with `global`.
+    struct RecordingSource {
+        targeted: Option<u64>,
+        global: u64,
+        calls: Mutex<Vec<Option<Vec<String>>>>,
+    }
+
+    impl RecordingSource {
+        fn new(targeted: Option<u64>, global: u64) -> Self {
+            Self {
+                targeted,
+                global,
+                calls: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    fn levels(fee: u64) -> PriorityFeeResponse {
+        PriorityFeeResponse {
+            min: fee / 10,
+            low: fee / 4,
+            medium: fee / 2,
+            high: fee,
+            very_high: fee * 2,
+            unsafe_max: fee * 10,
+            recommended: fee / 2,
+        }
+    }
+
+    #[async_trait]
+    impl PriorityFeeSource for RecordingSource {
+        async fn get_priority_fee_estimate(
+            &self,
+            _transaction_base64: Option<&str>,
+            account_keys: Option<&[String]>,
+        ) -> AppResult<PriorityFeeResponse> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(account_keys.map(|keys| keys.to_vec()));
+            match account_keys {
+                Some(_) => self
+                    .targeted
+                    .map(levels)
+                    .ok_or_else(|| AppError::ExternalApi("rate limited".to_string())),
+                None => Ok(levels(self.global)),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_targeted_estimate_uses_writable_accounts() {
+        let source = RecordingSource::new(Some(400_000), 50_000);
+        let accounts = vec!["BondingCurve1".to_string(), "CurveVault1".to_string()];
+
+        let fee = estimate_priority_fee_for_accounts(&source, &accounts, PriorityLevel::High)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            fee,
+            TargetedPriorityFee {
+                micro_lamports: 400_000,
+                scope: PriorityFeeScope::Accounts,
+            }
+        );
+        assert_eq!(*source.calls.lock().unwrap(), vec![Some(accounts)]);
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_global_estimate() {
+        let source = RecordingSource::new(Some(400_000), 50_000);
+        let fee = estimate_priority_fee_for_accounts(&source, &[], PriorityLevel::High)
+            .await
+            .unwrap();
+        assert_eq!(fee.scope, PriorityFeeScope::Global);
+        assert_eq!(fee.micro_lamports, 50_000);
+        assert_eq!(*source.calls.lock().unwrap(), vec![None]);
+
+        let failing = RecordingSource::new(None, 50_000);
+        let fee = estimate_priority_fee_for_accounts(
+            &failing,
+            &["BondingCurve1".to_string()],
+            PriorityLevel::Medium,
+        )
+        .await
+        .unwrap();
+        assert_eq!(fee.scope, PriorityFeeScope::Global);
+        assert_eq!(fee.micro_lamports, 25_000);
+        assert_eq!(failing.calls.lock().unwrap().len(), 2);
+    }
+}
@@ -34,14 +34,31 @@ mod webhooks;
 use crate::config::Config;
 use crate::handlers::{
     approvals as approval_handlers, autonomous as autonomous_handlers, config_handlers,
-    consensus as consensus_handlers, curves, edges, engram as engram_handlers, health,
-    helius as helius_handlers, kol, positions as position_handlers, research as research_handlers,
-    scanner, settings, sniper as sniper_handlers, sse, strategies, swarm,
-    threat as threat_handlers, trades, wallet as wallet_handlers, webhooks as webhook_handlers,
+    consensus as consensus_handlers, curves, edges, engram as engram_handlers,
+    events as event_handlers, health, helius as helius_handlers, kol,
+    positions as position_handlers, research as research_handlers, scanner, settings,
+    sniper as sniper_handlers, sse, strategies, swarm, threat as threat_handlers, trades,
+    venues as venue_handlers, wallet as wallet_handlers, webhooks as webhook_handlers,
 };
 use crate::mcp::{get_all_tools, get_manifest, handlers as mcp_handlers};
 use axum::Json;
 
+async fn fetch_wallet_balance(
+    state: &server::AppState,
+    address: &str,
+) -> crate::error::AppResult<u64> {
+    let balance_json: serde_json::Value = state
+        .helius_rpc_client
+        .rpc_call("getBalance", serde_json::json!([address]))
+        .await?;
+    balance_json
+        .get("value")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| {
+            crate::error::AppError::ExternalApi("Unexpected getBalance response format".to_string())
+        })
+}
+
 async fn print_startup_summary(state: &server::AppState) {
     println!("\n{}", "=".repeat(70));
     println!("                    🌾 ARBFARM STARTUP SUMMARY 🌾");
@@ -54,21 +71,17 @@ async fn print_startup_summary(state: &server::AppState) {
         println!("   Address: {}", address);
         println!("   Signer:  ✅ DEV MODE (private key from env)");
 
-        // Try to get balance via RPC
-        let balance_result: Result<serde_json::Value, _> = state
-            .helius_rpc_client
-            .rpc_call("getBalance", serde_json::json!([address]))
+        // Try to get balance via RPC (cached so startup validation below reuses it)
+        let balance_result = state
+            .balance_cache
+            .get_or_fetch(address, || fetch_wallet_balance(state, address))
             .await;
         match balance_result {
-            Ok(balance_json) => {
-                if let Some(value) = balance_json.get("value").and_then(|v| v.as_u64()) {
-                    let sol_balance = value as f64 / 1_000_000_000.0;
-                    println!("   Balance: {:.4} SOL", sol_balance);
-                    if sol_balance < 0.1 {
-                        println!("   ⚠️  WARNING: Low balance - consider adding more SOL");
-                    }
-                } else {
-                    println!("   Balance: ❌ Unexpected response format");
+            Ok(cached) => {
+                let sol_balance = cached.sol();
+                println!("   Balance: {:.4} SOL", sol_balance);
+                if sol_balance < 0.1 {
+                    println!("   ⚠️  WARNING: Low balance - consider adding more SOL");
                 }
             }
             Err(e) => println!("   Balance: ❌ Failed to fetch ({})", e),
@@ -241,128 +254,125 @@ async fn main() -> anyhow::Result<()> {
     const MIN_BALANCE_SOL: f64 = 0.05;
     if state.dev_signer.is_configured() {
         if let Some(address) = state.dev_signer.get_address() {
-            let balance_result: Result<serde_json::Value, _> = state
-                .helius_rpc_client
-                .rpc_call("getBalance", serde_json::json!([address]))
+            let balance_result = state
+                .balance_cache
+                .get_or_fetch(address, || fetch_wallet_balance(&state, address))
                 .await;
 
             match balance_result {
-                Ok(balance_json) => {
-                    if let Some(value) = balance_json.get("value").and_then(|v| v.as_u64()) {
-                        let sol_balance = value as f64 / 1_000_000_000.0;
-
-                        // Reserve SOL for gas fees (~10 transactions worth)
-                        const GAS_RESERVE_SOL: f64 = 0.02;
-                        let available_for_trading = (sol_balance - GAS_RESERVE_SOL).max(0.0);
-
-                        if sol_balance < MIN_BALANCE_SOL {
-                            warn!(
-                                "⚠️ LOW BALANCE: Wallet has {:.4} SOL (below {:.2} SOL threshold). \
-                                New buys disabled, exits still allowed. Fund wallet {} for full trading.",
-                                sol_balance, MIN_BALANCE_SOL, address
-                            );
-                        } else {
-                            info!("✅ Wallet funding validated: {:.4} SOL (min: {:.2} SOL, reserved for gas: {:.2} SOL)",
-                                sol_balance, MIN_BALANCE_SOL, GAS_RESERVE_SOL);
-                        }
+                Ok(cached) => {
+                    let sol_balance = cached.sol();
+
+                    // Reserve SOL for gas fees (~10 transactions worth)
+                    const GAS_RESERVE_SOL: f64 = 0.02;
+                    let available_for_trading = (sol_balance - GAS_RESERVE_SOL).max(0.0);
+
+                    if sol_balance < MIN_BALANCE_SOL {
+                        warn!(
+                            "⚠️ LOW BALANCE: Wallet has {:.4} SOL (below {:.2} SOL threshold). \
+                            New buys disabled, exits still allowed. Fund wallet {} for full trading.",
+                            sol_balance, MIN_BALANCE_SOL, address
+                        );
+                    } else {
+                        info!("✅ Wallet funding validated: {:.4} SOL (min: {:.2} SOL, reserved for gas: {:.2} SOL)",
+                            sol_balance, MIN_BALANCE_SOL, GAS_RESERVE_SOL);
+                    }
 
-                        let explicit_max = std::env::var("DEFAULT_MAX_POSITION_SOL")
-                            .ok()
-                            .and_then(|v| v.parse::<f64>().ok());
+                    let explicit_max = std::env::var("DEFAULT_MAX_POSITION_SOL")
+                        .ok()
+                        .and_then(|v| v.parse::<f64>().ok());
 
-                        let dynamic_max_position = if let Some(explicit) = explicit_max {
-                            info!(
-                                "💰 Using explicit DEFAULT_MAX_POSITION_SOL={:.2} SOL (available: {:.2} SOL)",
-                                explicit, available_for_trading
-                            );
-                            explicit.min(available_for_trading)
+                    let dynamic_max_position = if let Some(explicit) = explicit_max {
+                        info!(
+                            "💰 Using explicit DEFAULT_MAX_POSITION_SOL={:.2} SOL (available: {:.2} SOL)",
+                            explicit, available_for_trading
+                        );
+                        explicit.min(available_for_trading)
+                    } else {
+                        const MAX_POSITION_CAP_SOL: f64 = 0.08;
+                        let (divisor, tier_name) = if available_for_trading < 1.0 {
+                            (10.0, "micro (<1 SOL)")
+                        } else if available_for_trading < 10.0 {
+                            (15.0, "small (1-10 SOL)")
+                        } else if available_for_trading < 50.0 {
+                            (20.0, "medium (10-50 SOL)")
                         } else {
-                            const MAX_POSITION_CAP_SOL: f64 = 0.08;
-                            let (divisor, tier_name) = if available_for_trading < 1.0 {
-                                (10.0, "micro (<1 SOL)")
-                            } else if available_for_trading < 10.0 {
-                                (15.0, "small (1-10 SOL)")
-                            } else if available_for_trading < 50.0 {
-                                (20.0, "medium (10-50 SOL)")
-                            } else {
-                                (25.0, "large (50+ SOL)")
-                            };
-                            let computed =
-                                (available_for_trading / divisor).min(MAX_POSITION_CAP_SOL);
-                            info!(
-                                "💰 Dynamic max position: {:.2} SOL (1/{} of {:.2} SOL, tier: {}, cap: {} SOL)",
-                                computed, divisor as u32, available_for_trading, tier_name, MAX_POSITION_CAP_SOL
-                            );
-                            computed
+                            (25.0, "large (50+ SOL)")
                         };
-                        let dynamic_max_position = (dynamic_max_position * 100.0).round() / 100.0;
+                        let computed = (available_for_trading / divisor).min(MAX_POSITION_CAP_SOL);
+                        info!(
+                            "💰 Dynamic max position: {:.2} SOL (1/{} of {:.2} SOL, tier: {}, cap: {} SOL)",
+                            computed, divisor as u32, available_for_trading, tier_name, MAX_POSITION_CAP_SOL
+                        );
+                        computed
+                    };
+                    let dynamic_max_position = (dynamic_max_position * 100.0).round() / 100.0;
 
-                        {
-                            let mut risk_config = state.risk_config.write().await;
-                            risk_config.max_position_sol = dynamic_max_position;
-                            risk_config.max_position_per_token_sol = dynamic_max_position;
-                        }
-                        {
-                            let mut wallet_max = state.wallet_max_position_sol.write().await;
-                            *wallet_max = dynamic_max_position;
-                        }
+                    {
+                        let mut risk_config = state.risk_config.write().await;
+                        risk_config.max_position_sol = dynamic_max_position;
+                        risk_config.max_position_per_token_sol = dynamic_max_position;
+                    }
+                    {
+                        let mut wallet_max = state.wallet_max_position_sol.write().await;
+                        *wallet_max = dynamic_max_position;
+                    }
 
-                        use crate::database::repositories::strategies::UpdateStrategyRecord;
-                        let strategies = state.strategy_engine.list_strategies().await;
-                        let active_strategies: Vec<_> =
-                            strategies.iter().filter(|s| s.is_active).collect();
-                        let strategy_count = active_strategies.len().max(1);
-                        let per_strategy_max = dynamic_max_position;
+                    use crate::database::repositories::strategies::UpdateStrategyRecord;
+                    let strategies = state.strategy_engine.list_strategies().await;
+                    let active_strategies: Vec<_> =
+                        strategies.iter().filter(|s| s.is_active).collect();
+                    let strategy_count = active_strategies.len().max(1);
+                    let per_strategy_max = dynamic_max_position;
 
-                        info!(
-                            "💰 Per-strategy max: {:.2} SOL ({} active strategies)",
-                            per_strategy_max, strategy_count
-                        );
+                    info!(
+                        "💰 Per-strategy max: {:.2} SOL ({} active strategies)",
+                        per_strategy_max, strategy_count
+                    );
 
-                        info!(
-                            "💰 Per-strategy budget allocation: {:.2} SOL per strategy ({} active strategies)",
-                            per_strategy_max, strategy_count
-                        );
+                    info!(
+                        "💰 Per-strategy budget allocation: {:.2} SOL per strategy ({} active strategies)",
+                        per_strategy_max, strategy_count
+                    );
 
-                        let mut synced = 0;
-                        for strategy in active_strategies {
-                            let mut params = strategy.risk_params.clone();
-                            params.max_position_sol = per_strategy_max;
+                    let mut synced = 0;
+                    for strategy in active_strategies {
+                        let mut params = strategy.risk_params.clone();
+                        params.max_position_sol = per_strategy_max;
 
-                            // Update in-memory
-                            if state
-                                .strategy_engine
-                                .set_risk_params(strategy.id, params.clone())
+                        // Update in-memory
+                        if state
+                            .strategy_engine
+                            .set_risk_params(strategy.id, params.clone())
+                            .await
+                            .is_ok()
+                        {
+                            // Persist to database
+                            if let Err(e) = state
+                                .strategy_repo
+                                .update(
+                                    strategy.id,
+                                    UpdateStrategyRecord {
+                                        name: None,
+                                        venue_types: None,
+                                        execution_mode: None,
+                                        risk_params: Some(params),
+                                        is_active: None,
+                                    },
+                                )
                                 .await
-                                .is_ok()
                             {
-                                // Persist to database
-                                if let Err(e) = state
-                                    .strategy_repo
-                                    .update(
-                                        strategy.id,
-                                        UpdateStrategyRecord {
-                                            name: None,
-                                            venue_types: None,
-                                            execution_mode: None,
-                                            risk_params: Some(params),
-                                            is_active: None,
-                                        },
-                                    )
-                                    .await
-                                {
-                                    warn!("Failed to persist per-strategy budget to DB for strategy {}: {}", strategy.id, e);
-                                }
-                                synced += 1;
-                                info!(
-                                    "  └─ {} ({}): {:.2} SOL max position",
-                                    strategy.name, strategy.strategy_type, per_strategy_max
-                                );
+                                warn!("Failed to persist per-strategy budget to DB for strategy {}: {}", strategy.id, e);
                             }
+                            synced += 1;
+                            info!(
+                                "  └─ {} ({}): {:.2} SOL max position",
+                                strategy.name, strategy.strategy_type, per_strategy_max
+                            );
                         }
-                        if synced > 0 {
-                            info!("✅ Synced {} strategies with per-strategy budget: {:.2} SOL each (persisted to DB)", synced, per_strategy_max);
-                        }
+                    }
+                    if synced > 0 {
+                        info!("✅ Synced {} strategies with per-strategy budget: {:.2} SOL each (persisted to DB)", synced, per_strategy_max);
                     }
                 }
                 Err(e) => {
@@ -388,12 +398,15 @@ async fn main() -> anyhow::Result<()> {
     let dev_signer_for_autostart = state.dev_signer.clone();
     let helius_das_for_autostart = state.helius_das.clone();
     let position_manager_for_autostart = state.position_manager.clone();
+    let reconcile_schedule_for_autostart = state.reconcile_schedule.clone();
     let on_chain_fetcher_for_autostart = state.on_chain_fetcher.clone();
     let metrics_collector_for_autostart = state.metrics_collector.clone();
     let jupiter_venue_for_autostart = state.jupiter_venue.clone();
     let risk_config_for_autostart = state.risk_config.clone();
     let graduation_sniper_for_autostart = state.graduation_sniper.clone();
     let consensus_engine_for_analysis = state.consensus_engine.clone();
+    let consensus_config_for_analysis = state.consensus_config.clone();
+    let consensus_limiter_for_analysis = state.consensus_limiter.clone();
     let engrams_client_for_analysis = state.engrams_client.clone();
     let db_pool_for_analysis = state.db_pool.clone();
     let event_tx_for_analysis = state.event_tx.clone();
@@ -415,10 +428,82 @@ async fn main() -> anyhow::Result<()> {
     let position_repo_for_metrics = state.position_repo.clone();
     let engrams_client_for_metrics = state.engrams_client.clone();
     let dev_signer_for_metrics = state.dev_signer.clone();
+    let engrams_client_for_outcomes = state.engrams_client.clone();
+    let model_accuracy_for_outcomes = state.model_accuracy.clone();
     let recent_mints_for_autostart = executor_for_autostart.get_recent_mints();
     let capital_manager_for_autostart = state.capital_manager.clone();
     let rpc_url_for_balance = state.config.rpc_url.clone();
 
+    // Trading-window schedule: flips execution on/off at window boundaries
+    let state_for_schedule = state.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(30));
+        loop {
+            interval.tick().await;
+            if let Some(enabled) = state_for_schedule
+                .approval_manager
+                .evaluate_schedule(chrono::Utc::now())
+                .await
+            {
+                info!(
+                    "🕒 Execution schedule {} auto-execution",
+                    if enabled { "enabled" } else { "paused" }
+                );
+                approval_handlers::apply_execution_state(&state_for_schedule, enabled).await;
+            }
+        }
+    });
+
+    let execution_log = state.autonomous_executor.execution_log().clone();
+    tokio::spawn(async move {
+        let retention_days = env::var("AUTO_EXECUTION_RETENTION_DAYS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(agents::autonomous_executor::DEFAULT_EXECUTION_RETENTION_DAYS);
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(6 * 3600));
+        loop {
+            interval.tick().await;
+            match execution_log
+                .prune(chrono::Duration::days(retention_days))
+                .await
+            {
+                Ok(removed) if removed > 0 => {
+                    info!(
+                        "🧹 Pruned {} autonomous execution records older than {} days",
+                        removed, retention_days
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Failed to prune autonomous execution records: {}", e),
+            }
+        }
+    });
+
+    let event_sink = state.event_sink.clone();
+    tokio::spawn(async move {
+        let retention_days = env::var("ARB_EVENT_LOG_RETENTION_DAYS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(events::sink::DEFAULT_EVENT_RETENTION_DAYS);
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600));
+        loop {
+            interval.tick().await;
+            match event_sink
+                .prune(chrono::Duration::days(retention_days))
+                .await
+            {
+                Ok(removed) if removed > 0 => {
+                    info!(
+                        "🧹 Pruned {} events older than {} days",
+                        removed, retention_days
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Failed to prune event log: {}", e),
+            }
+        }
+    });
+
     // Clone components for graceful shutdown
     let scanner_for_shutdown = state.scanner.clone();
     let executor_for_shutdown = state.autonomous_executor.clone();
@@ -770,7 +855,8 @@ async fn main() -> anyhow::Result<()> {
                 .await;
         }
 
-        // Start periodic wallet reconciliation (every 10 seconds) to catch orphaned tokens
+        // Start periodic wallet reconciliation to catch orphaned tokens
+        // (interval and pause are live-tunable via /settings/reconcile)
         let periodic_wallet = dev_signer_for_autostart
             .get_address()
             .map(|s| s.to_string());
@@ -781,14 +867,14 @@ async fn main() -> anyhow::Result<()> {
         let periodic_jupiter = jupiter_venue_for_autostart.clone();
         let periodic_risk_config = risk_config_for_autostart.clone();
         let periodic_recent_mints = recent_mints_for_autostart.clone();
+        let periodic_schedule = reconcile_schedule_for_autostart.clone();
 
         if periodic_wallet.is_some() {
             tokio::spawn(async move {
                 let wallet_address = periodic_wallet.unwrap();
-                let reconcile_interval = std::time::Duration::from_secs(10); // 10 seconds
 
                 loop {
-                    tokio::time::sleep(reconcile_interval).await;
+                    periodic_schedule.wait_for_next_run().await;
 
                     info!("🔄 [Periodic] Running wallet reconciliation...");
 
@@ -825,17 +911,9 @@ async fn main() -> anyhow::Result<()> {
                                 }
                             }
 
-                            // Configurable dust threshold (defaults match MIN_DUST_VALUE_SOL in position_monitor)
-                            let dust_balance_threshold: f64 =
-                                std::env::var("RECONCILE_DUST_BALANCE_THRESHOLD")
-                                    .ok()
-                                    .and_then(|v| v.parse().ok())
-                                    .unwrap_or(0.001); // 0.001 tokens (decimal-adjusted)
-                            let dust_sol_value_threshold: f64 =
-                                std::env::var("RECONCILE_DUST_SOL_VALUE_THRESHOLD")
-                                    .ok()
-                                    .and_then(|v| v.parse().ok())
-                                    .unwrap_or(0.0001); // 0.0001 SOL
+                            // Per-venue dust policy (env vars RECONCILE_DUST_* provide the defaults)
+                            let dust_policy = periodic_position_manager.get_dust_policy().await;
+                            let min_dust_balance = dust_policy.min_balance_threshold();
 
                             // Create positions for discovered tokens
                             for token in &result.discovered_tokens {
@@ -843,11 +921,11 @@ async fn main() -> anyhow::Result<()> {
                                     continue;
                                 }
 
-                                // Skip dust amounts (by token balance)
-                                if token.balance < dust_balance_threshold {
+                                // Skip dust amounts (by token balance) before any price lookups
+                                if token.balance < min_dust_balance {
                                     tracing::debug!(
                                         "[Periodic] Skipping {} - balance {:.6} below dust threshold {:.6}",
-                                        &token.mint[..12], token.balance, dust_balance_threshold
+                                        &token.mint[..12], token.balance, min_dust_balance
                                     );
                                     continue;
                                 }
@@ -865,56 +943,78 @@ async fn main() -> anyhow::Result<()> {
                                     }
                                 }
 
-                                let (estimated_price, is_dead_token) = match periodic_on_chain
-                                    .get_bonding_curve_state(&token.mint)
-                                    .await
-                                {
-                                    Ok(curve_state) => {
-                                        if curve_state.virtual_token_reserves > 0 {
-                                            (
-                                                curve_state.virtual_sol_reserves as f64
-                                                    / curve_state.virtual_token_reserves as f64,
-                                                false,
-                                            )
-                                        } else {
+                                let (estimated_price, is_dead_token, venue) =
+                                    match periodic_on_chain
+                                        .get_bonding_curve_state(&token.mint)
+                                        .await
+                                    {
+                                        Ok(curve_state) => {
+                                            if curve_state.virtual_token_reserves > 0 {
+                                                (
+                                                    curve_state.virtual_sol_reserves as f64
+                                                        / curve_state.virtual_token_reserves as f64,
+                                                    false,
+                                                    crate::models::VenueType::BondingCurve,
+                                                )
+                                            } else {
+                                                match periodic_jupiter
+                                                    .get_token_price(&token.mint)
+                                                    .await
+                                                {
+                                                    Ok(price) => {
+                                                        info!("[Periodic] 📈 {} - using Jupiter price (zero curve reserves)", &token.mint[..12]);
+                                                        (
+                                                            price,
+                                                            false,
+                                                            crate::models::VenueType::DexAmm,
+                                                        )
+                                                    }
+                                                    Err(_) => {
+                                                        warn!("[Periodic] 💀 {} - dead token (zero reserves, no Jupiter) - queueing immediate sell", &token.mint[..12]);
+                                                        (
+                                                            0.0000001,
+                                                            true,
+                                                            crate::models::VenueType::BondingCurve,
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Err(_) => {
                                             match periodic_jupiter
                                                 .get_token_price(&token.mint)
                                                 .await
                                             {
                                                 Ok(price) => {
-                                                    info!("[Periodic] 📈 {} - using Jupiter price (zero curve reserves)", &token.mint[..12]);
-                                                    (price, false)
+                                                    info!("[Periodic] 📈 {} - using Jupiter price (graduated/DEX token)", &token.mint[..12]);
+                                                    (price, false, crate::models::VenueType::DexAmm)
                                                 }
                                                 Err(_) => {
-                                                    warn!("[Periodic] 💀 {} - dead token (zero reserves, no Jupiter) - queueing immediate sell", &token.mint[..12]);
-                                                    (0.0000001, true)
+                                                    warn!("[Periodic] 💀 {} - dead token (no curve, no Jupiter) - queueing immediate sell", &token.mint[..12]);
+                                                    (
+                                                        0.0000001,
+                                                        true,
+                                                        crate::models::VenueType::DexAmm,
+                                                    )
                                                 }
                                             }
                                         }
-                                    }
-                                    Err(_) => {
-                                        match periodic_jupiter.get_token_price(&token.mint).await {
-                                            Ok(price) => {
-                                                info!("[Periodic] 📈 {} - using Jupiter price (graduated/DEX token)", &token.mint[..12]);
-                                                (price, false)
-                                            }
-                                            Err(_) => {
-                                                warn!("[Periodic] 💀 {} - dead token (no curve, no Jupiter) - queueing immediate sell", &token.mint[..12]);
-                                                (0.0000001, true)
-                                            }
-                                        }
-                                    }
-                                };
+                                    };
 
-                                // SOL-value-based dust check (more accurate than token balance alone)
+                                // Venue-specific dust check (balance + SOL value)
                                 let estimated_sol_value = token.balance * estimated_price;
                                 if !is_dead_token
-                                    && estimated_sol_value < dust_sol_value_threshold
-                                    && estimated_sol_value > 0.0
+                                    && dust_policy.is_dust(
+                                        venue,
+                                        token.balance,
+                                        estimated_sol_value,
+                                    )
                                 {
+                                    let thresholds = dust_policy.thresholds_for(venue);
                                     tracing::debug!(
-                                        "[Periodic] Skipping {} - SOL value {:.6} below dust threshold {:.6}",
-                                        &token.mint[..12], estimated_sol_value, dust_sol_value_threshold
+                                        "[Periodic] Skipping {} on {} - balance {:.6} / SOL value {:.6} below dust thresholds {:.6} / {:.6}",
+                                        &token.mint[..12], venue, token.balance, estimated_sol_value,
+                                        thresholds.balance, thresholds.sol_value
                                     );
                                     continue;
                                 }
@@ -998,13 +1098,20 @@ async fn main() -> anyhow::Result<()> {
                     }
                 }
             });
-            info!("✅ Periodic wallet reconciliation started (every 10 seconds)");
+            let schedule = reconcile_schedule_for_autostart.status();
+            info!(
+                "✅ Periodic wallet reconciliation started (every {}s, {})",
+                schedule.interval_secs,
+                if schedule.enabled { "enabled" } else { "paused" }
+            );
         }
 
         // Start periodic consensus analysis (every 5 minutes)
         let analysis_wallet = dev_signer_for_analysis.get_address().map(|s| s.to_string());
         let analysis_engrams = engrams_client_for_analysis.clone();
         let analysis_consensus = consensus_engine_for_analysis.clone();
+        let analysis_consensus_config = consensus_config_for_analysis.clone();
+        let analysis_limiter = consensus_limiter_for_analysis.clone();
         let analysis_db_pool = db_pool_for_analysis.clone();
         let analysis_event_tx = event_tx_for_analysis.clone();
 
@@ -1041,13 +1148,30 @@ async fn main() -> anyhow::Result<()> {
                         }
                     };
 
-                    // Skip analysis if no trading activity
-                    if pnl_stats.total_trades == 0 {
-                        info!("[Consensus] ℹ️ No trades yet, skipping analysis");
+                    // Skip analysis until there's enough trading history to be worth the LLM spend
+                    let skip_reason = analysis_consensus_config
+                        .read()
+                        .await
+                        .scheduled_analysis_skip_reason(pnl_stats.total_trades);
+                    if let Some(reason) = skip_reason {
+                        info!("[Consensus] ℹ️ {}, skipping analysis", reason);
                         tokio::time::sleep(analysis_interval).await;
                         continue;
                     }
 
+                    // Manual requests share this cap, so never run alongside one
+                    let limiter_config = analysis_consensus_config.read().await.clone();
+                    let analysis_permit = match analysis_limiter
+                        .try_acquire_scheduled(&limiter_config)
+                    {
+                        Ok(permit) => permit,
+                        Err(throttle) => {
+                            info!("[Consensus] ℹ️ {}, skipping analysis", throttle.message());
+                            tokio::time::sleep(analysis_interval).await;
+                            continue;
+                        }
+                    };
+
                     // Gather recent errors from engrams
                     let error_history = match analysis_engrams
                         .get_error_history(&wallet_address, Some(20))
@@ -1149,21 +1273,25 @@ async fn main() -> anyhow::Result<()> {
                     let context_recent_trades = context.recent_trades.clone();
 
                     // Request consensus analysis
-                    match analysis_consensus.request_analysis(context).await {
+                    let analysis_result = analysis_consensus.request_analysis(context).await;
+                    drop(analysis_permit);
+                    match analysis_result {
                         Ok(result) => {
                             {
                                 let mut last_q = consensus_last_queried.write().await;
                                 *last_q = Some(chrono::Utc::now());
                             }
                             info!(
-                                "[Consensus] ✅ Analysis complete: {} recommendations, {} risk alerts, confidence: {:.1}%",
+                                "[Consensus] ✅ Analysis complete: {} recommendations, {} risk alerts, confidence: {:.1}%, repaired votes: {}",
                                 result.recommendations.len(),
                                 result.risk_alerts.len(),
-                                result.avg_confidence * 100.0
+                                result.avg_confidence * 100.0,
+                                result.repaired_votes
                             );
 
                             // Generate conversation log
                             let session_id = uuid::Uuid::new_v4();
+                            let analysis_id = uuid::Uuid::new_v4();
                             let conversation = crate::engrams::schemas::ConversationLog {
                                 session_id,
                                 participants: result.model_votes.clone(),
@@ -1197,7 +1325,11 @@ async fn main() -> anyhow::Result<()> {
                                 outcome: crate::engrams::schemas::ConversationOutcome {
                                     consensus_reached: !result.recommendations.is_empty(),
                                     recommendations_generated: result.recommendations.len() as u32,
-                                    engram_refs: Vec::new(),
+                                    engram_refs: vec![
+                                        crate::engrams::schemas::generate_consensus_analysis_key(
+                                            &analysis_id,
+                                        ),
+                                    ],
                                     summary: Some(result.overall_assessment.clone()),
                                 },
                                 created_at: chrono::Utc::now(),
@@ -1215,8 +1347,11 @@ async fn main() -> anyhow::Result<()> {
 
                             // Save each recommendation as an engram and collect IDs
                             let mut recommendation_ids: Vec<uuid::Uuid> = Vec::new();
+                            let consensus_config = analysis_consensus_config.read().await.clone();
                             for rec in &result.recommendations {
                                 let rec_id = uuid::Uuid::new_v4();
+                                let below_confidence_floor =
+                                    !consensus_config.meets_action_floor(rec.confidence);
                                 recommendation_ids.push(rec_id);
                                 let recommendation = crate::engrams::schemas::Recommendation {
                                     recommendation_id: rec_id,
@@ -1258,6 +1393,9 @@ async fn main() -> anyhow::Result<()> {
                                     status: crate::engrams::schemas::RecommendationStatus::Pending,
                                     created_at: chrono::Utc::now(),
                                     applied_at: None,
+                                    below_confidence_floor,
+                                    models: result.model_votes.clone(),
+                                    outcome: None,
                                 };
 
                                 if let Err(e) = analysis_engrams
@@ -1269,12 +1407,16 @@ async fn main() -> anyhow::Result<()> {
                                         rec.title, e
                                     );
                                 } else {
-                                    info!("[Consensus] 💡 Saved recommendation: {} (confidence: {:.0}%)", rec.title, rec.confidence * 100.0);
+                                    info!(
+                                        "[Consensus] 💡 Saved recommendation: {} (confidence: {:.0}%{})",
+                                        rec.title,
+                                        rec.confidence * 100.0,
+                                        if below_confidence_floor { ", informational" } else { "" }
+                                    );
                                 }
                             }
 
                             // Save the full consensus analysis as an engram
-                            let analysis_id = uuid::Uuid::new_v4();
                             let consensus_analysis = crate::engrams::schemas::ConsensusAnalysis {
                                 analysis_id,
                                 analysis_type:
@@ -1425,6 +1567,23 @@ async fn main() -> anyhow::Result<()> {
         } else {
             info!("ℹ️ Daily metrics scheduler skipped (wallet/engrams not configured)");
         }
+
+        // Measure the P&L impact of applied recommendations (every 15 minutes)
+        let outcomes_wallet = dev_signer_for_metrics.get_address().map(|s| s.to_string());
+        if outcomes_wallet.is_some() && engrams_client_for_outcomes.is_configured() {
+            let wallet_address = outcomes_wallet.unwrap();
+            tokio::spawn(async move {
+                crate::consensus::outcomes::start_recommendation_outcome_scheduler(
+                    engrams_client_for_outcomes,
+                    model_accuracy_for_outcomes,
+                    wallet_address,
+                )
+                .await;
+            });
+            info!("✅ Recommendation outcome tracker started (every 15 minutes)");
+        } else {
+            info!("ℹ️ Recommendation outcome tracker skipped (wallet/engrams not configured)");
+        }
     });
 
     // Graceful shutdown handling
@@ -1531,6 +1690,9 @@ fn create_router(state: server::AppState) -> Router {
         .route("/mcp/manifest", get(mcp_manifest))
         .route("/mcp/tools", get(mcp_tools))
         .route("/mcp/call", post(mcp_handlers::call_tool))
+        .route("/mcp/metrics", get(mcp_handlers::get_tool_metrics))
+        .route("/mcp/jobs/:id", get(mcp_handlers::get_tool_job))
+        .route("/mcp/jobs/:id/stream", get(mcp_handlers::stream_tool_job))
         // Scanner
         .route("/scanner/status", get(scanner::get_scanner_status))
         .route("/scanner/start", post(scanner::start_scanner))
@@ -1538,11 +1700,19 @@ fn create_router(state: server::AppState) -> Router {
         .route("/scanner/signals", get(scanner::get_signals))
         .route("/scanner/contenders", get(scanner::get_contenders))
         .route("/scanner/process", post(scanner::process_signals))
+        .route(
+            "/scanner/venues/:venue/toggle",
+            post(scanner::toggle_venue),
+        )
         // Behavioral Strategies (scanner-driven)
         .route(
             "/scanner/strategies",
             get(scanner::list_behavioral_strategies),
         )
+        .route(
+            "/scanner/strategies/performance",
+            get(scanner::get_behavioral_strategy_performance),
+        )
         .route(
             "/scanner/strategies/:name",
             get(scanner::get_behavioral_strategy),
@@ -1582,6 +1752,10 @@ fn create_router(state: server::AppState) -> Router {
             post(strategies::set_risk_profile),
         )
         .route("/strategies/:id/kill", post(strategies::kill_strategy))
+        .route(
+            "/strategies/:id/preview",
+            post(strategies::preview_strategy),
+        )
         .route(
             "/strategies/:id/momentum",
             post(strategies::toggle_strategy_momentum),
@@ -1603,6 +1777,9 @@ fn create_router(state: server::AppState) -> Router {
         .route("/trades/stats", get(trades::get_trade_stats))
         .route("/trades/daily", get(trades::get_daily_stats))
         .route("/trades/:id", get(trades::get_trade))
+        // Cross-venue quote comparison (Jupiter, Raydium, pump.fun, moonshot)
+        .route("/venues/compare-quote", post(venue_handlers::compare_quote))
+        .route("/venues/fees", get(venue_handlers::compare_venue_fees))
         // Bonding Curves (pump.fun, moonshot)
         .route("/curves/tokens", get(curves::list_curve_tokens))
         .route("/curves/health", get(curves::get_venues_health))
@@ -1709,6 +1886,10 @@ fn create_router(state: server::AppState) -> Router {
             "/research/sources/:id/toggle",
             post(research_handlers::toggle_source),
         )
+        .route(
+            "/research/sources/:id/outcomes",
+            post(research_handlers::record_source_outcome),
+        )
         .route("/research/alerts", get(research_handlers::list_alerts))
         .route("/research/stats", get(research_handlers::get_monitor_stats))
         .route(
@@ -1764,6 +1945,10 @@ fn create_router(state: server::AppState) -> Router {
             "/consensus/learning",
             get(consensus_handlers::get_learning_summary),
         )
+        .route(
+            "/consensus/model-accuracy",
+            get(consensus_handlers::get_model_accuracy),
+        )
         .route("/consensus/engrams", get(consensus_handlers::list_engrams))
         .route(
             "/consensus/engrams/:key",
@@ -1805,11 +1990,16 @@ fn create_router(state: server::AppState) -> Router {
             "/consensus/:id",
             get(consensus_handlers::get_consensus_detail),
         )
+        .route(
+            "/consensus/:id/export",
+            get(consensus_handlers::export_consensus),
+        )
         // KOL Tracking + Copy Trading
         .route("/kol", get(kol::list_kols))
         .route("/kol", post(kol::add_kol))
         .route("/kol/copies/active", get(kol::list_active_copies))
         .route("/kol/copies/stats", get(kol::get_copy_stats))
+        .route("/kol/copies/budget", get(kol::get_copy_budgets))
         .route("/kol/:id", get(kol::get_kol))
         .route("/kol/:id", axum::routing::put(kol::update_kol))
         .route("/kol/:id", axum::routing::delete(kol::delete_kol))
@@ -1819,6 +2009,7 @@ fn create_router(state: server::AppState) -> Router {
         .route("/kol/:id/copy/enable", post(kol::enable_copy_trading))
         .route("/kol/:id/copy/disable", post(kol::disable_copy_trading))
         .route("/kol/:id/copy/history", get(kol::get_copy_history))
+        .route("/kol/:id/copy/budget/reset", post(kol::reset_copy_budget))
         // KOL Discovery
         .route("/kol/discovery/status", get(kol::get_discovery_status))
         .route("/kol/discovery/start", post(kol::start_discovery))
@@ -1836,6 +2027,14 @@ fn create_router(state: server::AppState) -> Router {
             get(threat_handlers::check_wallet),
         )
         .route("/threat/blocked", get(threat_handlers::list_blocked))
+        .route(
+            "/threat/blocked/export",
+            get(threat_handlers::export_blocklist),
+        )
+        .route(
+            "/threat/blocked/import",
+            post(threat_handlers::import_blocklist),
+        )
         .route(
             "/threat/blocked/:address",
             axum::routing::delete(threat_handlers::remove_from_blocklist),
@@ -1863,6 +2062,7 @@ fn create_router(state: server::AppState) -> Router {
             get(threat_handlers::get_score_history),
         )
         .route("/threat/stats", get(threat_handlers::get_stats))
+        .route("/threat/audit", get(threat_handlers::get_audit_log))
         // Engrams (Pattern Learning)
         .route("/engram", post(engram_handlers::create_engram))
         .route("/engram/search", get(engram_handlers::search_engrams))
@@ -1874,6 +2074,10 @@ fn create_router(state: server::AppState) -> Router {
         )
         .route("/engram/pattern", post(engram_handlers::create_pattern))
         .route("/engram/stats", get(engram_handlers::get_harvester_stats))
+        .route(
+            "/engram/strategy-suggestions",
+            post(engram_handlers::suggest_strategies),
+        )
         .route(
             "/engram/insights",
             get(engram_handlers::get_learning_insights),
@@ -1906,6 +2110,10 @@ fn create_router(state: server::AppState) -> Router {
         .route("/wallet/setup", post(wallet_handlers::setup_wallet))
         .route("/wallet/policy", post(wallet_handlers::update_policy))
         .route("/wallet/balance", get(wallet_handlers::get_balance))
+        .route(
+            "/wallet/balance/invalidate",
+            post(wallet_handlers::invalidate_balance_cache),
+        )
         .route(
             "/wallet/disconnect",
             post(wallet_handlers::disconnect_wallet),
@@ -1913,6 +2121,7 @@ fn create_router(state: server::AppState) -> Router {
         .route("/wallet/usage", get(wallet_handlers::get_daily_usage))
         .route("/wallet/test-sign", post(wallet_handlers::test_sign))
         .route("/wallet/sign", post(wallet_handlers::sign_transaction))
+        .route("/wallet/sign-log", get(wallet_handlers::get_sign_log))
         .route("/wallet/dev-mode", get(wallet_handlers::get_dev_mode))
         .route(
             "/wallet/dev-connect",
@@ -1923,11 +2132,22 @@ fn create_router(state: server::AppState) -> Router {
             "/wallet/capital/sync",
             post(wallet_handlers::sync_capital_balance),
         )
+        .route(
+            "/wallet/capital/rebalance",
+            post(wallet_handlers::rebalance_capital),
+        )
         // Settings
         .route("/settings", get(settings::get_all_settings))
         .route("/settings/risk", get(settings::get_risk_settings))
         .route("/settings/risk", post(settings::update_risk_settings))
         .route("/settings/venues", get(settings::get_venue_settings))
+        .route("/settings/dust", get(settings::get_dust_settings))
+        .route("/settings/dust", post(settings::update_dust_settings))
+        .route("/settings/reconcile", get(settings::get_reconcile_settings))
+        .route(
+            "/settings/reconcile",
+            post(settings::update_reconcile_settings),
+        )
         .route("/settings/api-keys", get(settings::get_api_key_status))
         // Config (Risk Level Presets)
         .route("/config/risk", get(config_handlers::get_risk_level))
@@ -1997,6 +2217,10 @@ fn create_router(state: server::AppState) -> Router {
             "/positions/history",
             get(position_handlers::get_position_history),
         )
+        .route(
+            "/positions/export",
+            get(position_handlers::export_position_history),
+        )
         .route("/positions/exposure", get(position_handlers::get_exposure))
         .route(
             "/positions/pnl-summary",
@@ -2039,6 +2263,15 @@ fn create_router(state: server::AppState) -> Router {
             "/positions/exit-config",
             axum::routing::put(position_handlers::update_all_positions_exit_config),
         )
+        .route(
+            "/positions/exit-templates",
+            get(position_handlers::list_exit_templates),
+        )
+        .route(
+            "/positions/exit-templates/:name",
+            axum::routing::put(position_handlers::save_exit_template)
+                .delete(position_handlers::delete_exit_template),
+        )
         .route("/positions/:id", get(position_handlers::get_position))
         .route(
             "/positions/:id/close",
@@ -2048,14 +2281,27 @@ fn create_router(state: server::AppState) -> Router {
             "/positions/:id/exit-config",
             axum::routing::put(position_handlers::update_position_exit_config),
         )
+        .route(
+            "/positions/:id/notes",
+            axum::routing::put(position_handlers::annotate_position),
+        )
+        .route(
+            "/positions/:id/scale-in",
+            axum::routing::put(position_handlers::set_position_scale_in),
+        )
         .route(
             "/positions/:id/auto-exit",
-            axum::routing::patch(position_handlers::toggle_position_auto_exit),
+            axum::routing::patch(position_handlers::toggle_position_auto_exit)
+                .delete(position_handlers::clear_position_auto_exit_override),
         )
         .route(
             "/positions/auto-exit-stats",
             get(position_handlers::get_auto_exit_stats),
         )
+        .route(
+            "/positions/auto-exit/categories/:category",
+            axum::routing::put(position_handlers::set_category_auto_exit),
+        )
         // Approvals (Execution Controls)
         .route("/approvals", get(approval_handlers::list_approvals))
         .route(
@@ -2091,6 +2337,15 @@ fn create_router(state: server::AppState) -> Router {
             "/execution/toggle",
             post(approval_handlers::toggle_execution),
         )
+        .route(
+            "/execution/schedule",
+            get(approval_handlers::get_execution_schedule)
+                .put(approval_handlers::update_execution_schedule),
+        )
+        .route(
+            "/execution/schedule/override",
+            axum::routing::delete(approval_handlers::clear_execution_override),
+        )
         // Autonomous Executor (Auto-execution for autonomous strategies)
         .route(
             "/executor/stats",
@@ -2108,6 +2363,9 @@ fn create_router(state: server::AppState) -> Router {
             "/executor/stop",
             post(autonomous_handlers::stop_autonomous_executor),
         )
+        // Event log
+        .route("/events", get(event_handlers::query_events))
+        .route("/events/stats", get(event_handlers::get_event_log_stats))
         // SSE Streams
         .route("/scanner/stream", get(sse::scanner_stream))
         .route("/edges/stream", get(sse::edges_stream))
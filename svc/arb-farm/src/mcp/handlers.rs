@@ -1,8 +1,24 @@
-use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
+    Json,
+};
+use futures::stream::Stream;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::convert::Infallible;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use uuid::Uuid;
 
+use crate::handlers::positions::exit_all_positions;
+use crate::mcp::jobs::{McpJobUpdate, ProgressReporter};
 use crate::mcp::tools::{get_manifest, McpToolResult};
+use crate::research::{BacktestConfig, BacktestEngine};
 use crate::server::AppState;
 
 #[derive(Debug, Deserialize)]
@@ -51,7 +67,18 @@ pub async fn call_tool(
     State(state): State<AppState>,
     Json(request): Json<ToolCallRequest>,
 ) -> impl IntoResponse {
-    let result = execute_tool(&state, &request.name, request.arguments).await;
+    if let Err(limited) = state.mcp_metrics.check_rate_limit(&request.name) {
+        let response: ToolCallResponse = McpToolResult::error(limited.message()).into();
+        return (StatusCode::TOO_MANY_REQUESTS, Json(response));
+    }
+
+    let result = state
+        .mcp_metrics
+        .measure(
+            &request.name,
+            execute_tool(&state, &request.name, request.arguments),
+        )
+        .await;
     let response: ToolCallResponse = result.into();
 
     if response.is_error.unwrap_or(false) {
@@ -61,6 +88,111 @@ pub async fn call_tool(
     }
 }
 
+/// Starts a tool call as a background job and returns its id for polling or streaming.
+pub async fn start_tool_job(state: &AppState, name: &str, args: Value) -> Uuid {
+    let state = state.clone();
+    let tool = name.to_string();
+    state
+        .mcp_jobs
+        .clone()
+        .spawn(name, move |reporter| async move {
+            state
+                .mcp_metrics
+                .measure(
+                    &tool,
+                    execute_tool_with_progress(&state, &tool, args, &reporter),
+                )
+                .await
+        })
+        .await
+}
+
+/// Runs a tool for a background job. Long-running tools report their own progress;
+/// the rest only report starting and finishing.
+async fn execute_tool_with_progress(
+    state: &AppState,
+    name: &str,
+    args: Value,
+    reporter: &ProgressReporter,
+) -> McpToolResult {
+    match name {
+        "research_backtest_strategy" => {
+            research_backtest_strategy(state, args, Some(reporter)).await
+        }
+        "position_emergency_close_all" => position_emergency_close_all(state, Some(reporter)).await,
+        _ => {
+            reporter
+                .report(0.0, Some(1.0), format!("Running {}", name))
+                .await;
+            let result = execute_tool(state, name, args).await;
+            reporter
+                .report(1.0, Some(1.0), format!("{} finished", name))
+                .await;
+            result
+        }
+    }
+}
+
+pub async fn get_tool_metrics(State(state): State<AppState>) -> impl IntoResponse {
+    Json(serde_json::json!({ "tools": state.mcp_metrics.snapshot() }))
+}
+
+pub async fn get_tool_job(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match state.mcp_jobs.get(id).await {
+        Some(job) => (StatusCode::OK, Json(serde_json::json!(job))),
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": "Job not found"})),
+        ),
+    }
+}
+
+pub async fn stream_tool_job(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, StatusCode> {
+    let (backlog, rx) = state
+        .mcp_jobs
+        .subscribe(id)
+        .await
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let updates = futures::stream::unfold(
+        (backlog.into_iter(), rx, false),
+        move |(mut backlog, mut rx, done)| async move {
+            if done {
+                return None;
+            }
+
+            let update = match backlog.next() {
+                Some(update) => update,
+                None => loop {
+                    match rx.recv().await {
+                        Ok(update) => break update,
+                        Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => return None,
+                    }
+                },
+            };
+
+            let terminal = update.is_terminal();
+            let event = Event::default()
+                .event(if terminal { "result" } else { "progress" })
+                .data(update.to_notification(id).to_string());
+            Some((Ok(event), (backlog, rx, terminal)))
+        },
+    );
+
+    Ok(Sse::new(updates).keep_alive(
+        KeepAlive::new()
+            .interval(Duration::from_secs(15))
+            .text("keep-alive"),
+    ))
+}
+
 pub async fn execute_tool(state: &AppState, name: &str, args: Value) -> McpToolResult {
     match name {
         // Scanner tools
@@ -116,9 +248,13 @@ pub async fn execute_tool(state: &AppState, name: &str, args: Value) -> McpToolR
         "engram_request_analysis" => engram_request_analysis(state, args).await,
         "engram_get_by_ids" => engram_get_by_ids(state, args).await,
 
+        // Research tools
+        "research_backtest_strategy" => research_backtest_strategy(state, args, None).await,
+
         // Position tools
         "position_list" => position_list(state).await,
         "position_details" => position_details(state, args).await,
+        "position_emergency_close_all" => position_emergency_close_all(state, None).await,
 
         // Wallet tools
         "wallet_balance" => wallet_balance(state).await,
@@ -899,6 +1035,93 @@ async fn position_details(state: &AppState, args: Value) -> McpToolResult {
     }
 }
 
+async fn position_emergency_close_all(
+    state: &AppState,
+    progress: Option<&ProgressReporter>,
+) -> McpToolResult {
+    let response = exit_all_positions(state, |done, total, result| {
+        let reporter = progress.cloned();
+        let token = result
+            .token_symbol
+            .clone()
+            .unwrap_or_else(|| result.token_mint.clone());
+        let message = if result.success {
+            format!("Exited {}", token)
+        } else {
+            format!("Failed to exit {}", token)
+        };
+        async move {
+            if let Some(reporter) = reporter {
+                reporter
+                    .report(done as f64, Some(total as f64), message)
+                    .await;
+            }
+        }
+    })
+    .await;
+
+    McpToolResult::success(serde_json::to_string_pretty(&response).unwrap_or_default())
+}
+
+// Research tool implementations
+async fn research_backtest_strategy(
+    state: &AppState,
+    args: Value,
+    progress: Option<&ProgressReporter>,
+) -> McpToolResult {
+    let strategy_id = match args
+        .get("strategy_id")
+        .and_then(|v| v.as_str())
+        .and_then(|id| Uuid::parse_str(id).ok())
+    {
+        Some(id) => id,
+        None => return McpToolResult::error("Valid strategy_id is required"),
+    };
+
+    let discovery = match state.discovery_store.get(strategy_id).await {
+        Some(discovery) => discovery,
+        None => return McpToolResult::error("Discovered strategy not found"),
+    };
+
+    let defaults = BacktestConfig::default();
+    let config = BacktestConfig {
+        period_days: args
+            .get("period_days")
+            .and_then(|v| v.as_u64())
+            .and_then(|days| u32::try_from(days).ok())
+            .unwrap_or(defaults.period_days),
+        initial_capital_sol: args
+            .get("initial_capital_sol")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(defaults.initial_capital_sol),
+        max_position_size_sol: args
+            .get("max_position_size_sol")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(defaults.max_position_size_sol),
+        ..defaults
+    };
+
+    let result = BacktestEngine::new()
+        .run_with_progress(&discovery.strategy, config, |done, total, stage| {
+            let reporter = progress.cloned();
+            async move {
+                if let Some(reporter) = reporter {
+                    reporter
+                        .report(done as f64, Some(total as f64), stage)
+                        .await;
+                }
+            }
+        })
+        .await;
+
+    match result {
+        Ok(backtest) => {
+            McpToolResult::success(serde_json::to_string_pretty(&backtest).unwrap_or_default())
+        }
+        Err(e) => McpToolResult::error(format!("Backtest failed: {}", e)),
+    }
+}
+
 // Wallet tool implementations
 async fn wallet_balance(state: &AppState) -> McpToolResult {
     let wallet_address = match state.dev_signer.get_address() {
@@ -911,18 +1134,26 @@ async fn wallet_balance(state: &AppState) -> McpToolResult {
         value: u64,
     }
 
-    let params = serde_json::json!([wallet_address]);
+    let fetch = move || async move {
+        let params = serde_json::json!([wallet_address]);
+        state
+            .helius_rpc_client
+            .rpc_call::<BalanceResult>("getBalance", params)
+            .await
+            .map(|result| result.value)
+    };
 
     match state
-        .helius_rpc_client
-        .rpc_call::<BalanceResult>("getBalance", params)
+        .balance_cache
+        .get_or_fetch(wallet_address, fetch)
         .await
     {
-        Ok(result) => McpToolResult::success(
+        Ok(cached) => McpToolResult::success(
             serde_json::json!({
                 "address": wallet_address,
-                "balance_lamports": result.value,
-                "balance_sol": result.value as f64 / 1_000_000_000.0
+                "balance_lamports": cached.lamports,
+                "balance_sol": cached.sol(),
+                "cache_age_ms": cached.age().as_millis() as u64
             })
             .to_string(),
         ),
@@ -1028,7 +1259,11 @@ async fn approval_approve(state: &AppState, args: Value) -> McpToolResult {
 
     let notes = args.get("notes").and_then(|v| v.as_str()).map(String::from);
 
-    match state.approval_manager.approve(approval_id, notes).await {
+    match state
+        .approval_manager
+        .approve(approval_id, notes, None)
+        .await
+    {
         Ok(approval) => McpToolResult::success(
             serde_json::json!({
                 "success": true,
@@ -1421,99 +1656,76 @@ async fn engram_apply_recommendation(state: &AppState, args: Value) -> McpToolRe
         ));
     }
 
+    if let Some(reason) = state
+        .consensus_config
+        .read()
+        .await
+        .action_floor_rejection(&recommendation)
+    {
+        return McpToolResult::error(reason);
+    }
+
     let action = &recommendation.suggested_action;
     let mut changes_made = Vec::new();
+    // Anything that kept the recommendation from being carried out in full
+    let mut failures = Vec::new();
+    let mut config_diff: Option<crate::consensus::RiskConfigDiff> = None;
 
     // Apply based on action type
     match action.action_type {
         crate::engrams::SuggestedActionType::ConfigChange => {
-            // Update consensus config
             let target = &action.target;
             let new_value = &action.suggested_value;
 
-            if target.starts_with("consensus.") {
-                let field = target.strip_prefix("consensus.").unwrap_or(target);
-                if !dry_run {
-                    let mut config = state.consensus_config.write().await;
-                    match field {
-                        "min_consensus_threshold" => {
-                            if let Some(v) = new_value.as_f64() {
-                                config.min_consensus_threshold = v;
+            if let Some(field) = target.strip_prefix("consensus.") {
+                match field {
+                    "min_consensus_threshold" => {
+                        match new_value.as_f64().filter(|v| *v > 0.0 && *v <= 1.0) {
+                            Some(v) if dry_run => changes_made
+                                .push(format!("[DRY RUN] Would set {} to {}", target, v)),
+                            Some(v) => {
+                                state.consensus_config.write().await.min_consensus_threshold = v;
                                 changes_made.push(format!(
                                     "Set consensus.min_consensus_threshold to {}",
                                     v
                                 ));
                             }
+                            None => failures.push(format!(
+                                "consensus.min_consensus_threshold must be above 0 and at most 1, got {}",
+                                new_value
+                            )),
                         }
-                        "review_interval_hours" => {
-                            if let Some(v) = new_value.as_u64() {
-                                config.review_interval_hours = v as u32;
+                    }
+                    "review_interval_hours" => {
+                        match new_value.as_u64().filter(|v| (1..=168).contains(v)) {
+                            Some(v) if dry_run => changes_made
+                                .push(format!("[DRY RUN] Would set {} to {}", target, v)),
+                            Some(v) => {
+                                state.consensus_config.write().await.review_interval_hours =
+                                    v as u32;
                                 changes_made
                                     .push(format!("Set consensus.review_interval_hours to {}", v));
                             }
-                        }
-                        _ => {
-                            changes_made.push(format!("Unknown consensus field: {}", field));
+                            None => failures.push(format!(
+                                "consensus.review_interval_hours must be between 1 and 168, got {}",
+                                new_value
+                            )),
                         }
                     }
-                } else {
-                    changes_made.push(format!("[DRY RUN] Would set {} to {:?}", target, new_value));
+                    _ => failures.push(format!("Unknown consensus field: {}", field)),
                 }
             } else if target.starts_with("risk.") {
-                let field = target.strip_prefix("risk.").unwrap_or(target);
-                if !dry_run {
-                    // ATOMIC: Acquire both locks together to prevent race conditions
-                    // Order: wallet_max first, then risk_config to maintain consistent lock ordering
-                    let wallet_max = *state.wallet_max_position_sol.read().await;
-                    let mut config = state.risk_config.write().await;
-                    // Re-read wallet_max inside the write lock to ensure consistency
-                    // (in case it changed between our read and acquiring the write lock)
-                    let current_wallet_max = *state.wallet_max_position_sol.read().await;
-                    let effective_wallet_max = wallet_max.min(current_wallet_max);
-
-                    match field {
-                        "max_position_sol" => {
-                            if let Some(v) = new_value.as_f64() {
-                                let capped = v.min(effective_wallet_max);
-                                config.max_position_sol = capped;
-                                if capped < v {
-                                    changes_made.push(format!("Set risk.max_position_sol to {} (capped from {} by wallet limit)", capped, v));
-                                } else {
-                                    changes_made
-                                        .push(format!("Set risk.max_position_sol to {}", capped));
-                                }
-                            }
-                        }
-                        "daily_loss_limit_sol" => {
-                            if let Some(v) = new_value.as_f64() {
-                                config.daily_loss_limit_sol = v;
-                                changes_made
-                                    .push(format!("Set risk.daily_loss_limit_sol to {}", v));
-                            }
-                        }
-                        "max_drawdown_percent" => {
-                            if let Some(v) = new_value.as_f64() {
-                                config.max_drawdown_percent = v;
-                                changes_made
-                                    .push(format!("Set risk.max_drawdown_percent to {}", v));
-                            }
-                        }
-                        "max_concurrent_positions" => {
-                            if let Some(v) = new_value.as_u64() {
-                                config.max_concurrent_positions = v as u32;
-                                changes_made
-                                    .push(format!("Set risk.max_concurrent_positions to {}", v));
-                            }
-                        }
-                        _ => {
-                            changes_made.push(format!("Unknown risk field: {}", field));
-                        }
-                    }
-                } else {
-                    changes_made.push(format!("[DRY RUN] Would set {} to {:?}", target, new_value));
+                // Same typed, allowlisted path as a RiskAdjustment
+                let risk_action = crate::engrams::SuggestedAction {
+                    action_type: crate::engrams::SuggestedActionType::RiskAdjustment,
+                    ..action.clone()
+                };
+                match apply_risk_adjustment(state, &risk_action, dry_run, &mut changes_made).await {
+                    Ok(diff) => config_diff = Some(diff),
+                    Err(e) => failures.push(e.to_string()),
                 }
             } else {
-                changes_made.push(format!("Unrecognized config target: {}", target));
+                failures.push(format!("Unrecognized config target: {}", target));
             }
         }
         crate::engrams::SuggestedActionType::StrategyToggle => {
@@ -1536,7 +1748,7 @@ async fn engram_apply_recommendation(state: &AppState, args: Value) -> McpToolRe
                             ));
                         }
                         Err(e) => {
-                            changes_made.push(format!("Failed to toggle strategy: {}", e));
+                            failures.push(format!("Failed to toggle strategy: {}", e));
                         }
                     }
                 } else {
@@ -1547,55 +1759,13 @@ async fn engram_apply_recommendation(state: &AppState, args: Value) -> McpToolRe
                     ));
                 }
             } else {
-                changes_made.push(format!("Invalid strategy ID: {}", strategy_id_str));
+                failures.push(format!("Invalid strategy ID: {}", strategy_id_str));
             }
         }
         crate::engrams::SuggestedActionType::RiskAdjustment => {
-            let target = &action.target;
-            let new_value = &action.suggested_value;
-
-            if !dry_run {
-                let wallet_max = *state.wallet_max_position_sol.read().await;
-                let mut config = state.risk_config.write().await;
-                match target.as_str() {
-                    "max_position_sol" => {
-                        if let Some(v) = new_value.as_f64() {
-                            let capped = v.min(wallet_max);
-                            config.max_position_sol = capped;
-                            if capped < v {
-                                changes_made.push(format!(
-                                    "Set max_position_sol to {} (capped from {} by wallet limit)",
-                                    capped, v
-                                ));
-                            } else {
-                                changes_made.push(format!("Set max_position_sol to {}", capped));
-                            }
-                        }
-                    }
-                    "daily_loss_limit_sol" => {
-                        if let Some(v) = new_value.as_f64() {
-                            config.daily_loss_limit_sol = v;
-                            changes_made.push(format!("Set daily_loss_limit_sol to {}", v));
-                        }
-                    }
-                    "max_concurrent_positions" => {
-                        if let Some(v) = new_value.as_u64() {
-                            config.max_concurrent_positions = v as u32;
-                            changes_made.push(format!("Set max_concurrent_positions to {}", v));
-                        }
-                    }
-                    "max_drawdown_percent" => {
-                        if let Some(v) = new_value.as_f64() {
-                            config.max_drawdown_percent = v;
-                            changes_made.push(format!("Set max_drawdown_percent to {}", v));
-                        }
-                    }
-                    _ => {
-                        changes_made.push(format!("Unknown risk adjustment target: {}", target));
-                    }
-                }
-            } else {
-                changes_made.push(format!("[DRY RUN] Would set {} to {:?}", target, new_value));
+            match apply_risk_adjustment(state, action, dry_run, &mut changes_made).await {
+                Ok(diff) => config_diff = Some(diff),
+                Err(e) => failures.push(e.to_string()),
             }
         }
         crate::engrams::SuggestedActionType::AvoidToken => {
@@ -1627,7 +1797,7 @@ async fn engram_apply_recommendation(state: &AppState, args: Value) -> McpToolRe
                         ));
                     }
                     Err(e) => {
-                        changes_made.push(format!("Failed to add avoidance: {}", e));
+                        failures.push(format!("Failed to add avoidance: {}", e));
                     }
                 }
             } else {
@@ -1639,37 +1809,102 @@ async fn engram_apply_recommendation(state: &AppState, args: Value) -> McpToolRe
         }
         crate::engrams::SuggestedActionType::VenueDisable => {
             // Venue disabling not yet implemented
-            changes_made.push(format!(
+            failures.push(format!(
                 "Venue disable for '{}' not yet implemented. Please disable manually.",
                 action.target
             ));
         }
     }
 
-    // Update recommendation status to Applied (unless dry run)
-    if !dry_run && !changes_made.is_empty() {
-        let _ = state
+    let status = if !failures.is_empty() {
+        "failed"
+    } else if dry_run {
+        "simulated"
+    } else {
+        "applied"
+    };
+
+    // Only a recommendation that was carried out in full is marked Applied
+    let mut status_update_error = None;
+    if status == "applied" {
+        if let Err(e) = state
             .engrams_client
             .update_recommendation_status(
                 &wallet,
                 &recommendation_id,
                 crate::engrams::RecommendationStatus::Applied,
             )
-            .await;
+            .await
+        {
+            status_update_error = Some(e.to_string());
+        }
     }
 
-    McpToolResult::success(
-        serde_json::json!({
-            "success": true,
-            "recommendation_id": recommendation_id.to_string(),
-            "dry_run": dry_run,
-            "action_type": format!("{:?}", action.action_type),
-            "target": action.target,
-            "changes_made": changes_made,
-            "status": if dry_run { "simulated" } else { "applied" }
-        })
-        .to_string(),
-    )
+    let body = serde_json::json!({
+        "success": failures.is_empty(),
+        "recommendation_id": recommendation_id.to_string(),
+        "dry_run": dry_run,
+        "action_type": format!("{:?}", action.action_type),
+        "target": action.target,
+        "changes_made": changes_made,
+        "errors": failures,
+        "config_diff": config_diff,
+        "status": status,
+        "status_update_error": status_update_error,
+    })
+    .to_string();
+
+    if status == "failed" {
+        McpToolResult::error(body)
+    } else {
+        McpToolResult::success(body)
+    }
+}
+
+/// Applies a typed, allowlisted risk change (see `consensus::diff_risk_adjustment`),
+/// capping `max_position_sol` at the wallet limit.
+async fn apply_risk_adjustment(
+    state: &AppState,
+    action: &crate::engrams::SuggestedAction,
+    dry_run: bool,
+    changes_made: &mut Vec<String>,
+) -> crate::error::AppResult<crate::consensus::RiskConfigDiff> {
+    let wallet_max = *state.wallet_max_position_sol.read().await;
+    let mut config = state.risk_config.write().await;
+    let mut diff = crate::consensus::diff_risk_adjustment(action, &config)?;
+
+    let requested = diff.to;
+    if let (
+        crate::consensus::RiskConfigField::MaxPositionSol,
+        crate::consensus::ConfigValue::Float(v),
+    ) = (diff.field, diff.to)
+    {
+        diff.to = crate::consensus::ConfigValue::Float(v.min(wallet_max));
+    }
+    let capped = if diff.to != requested {
+        format!(" (capped from {} by wallet limit)", requested)
+    } else {
+        String::new()
+    };
+    if diff.is_stale() {
+        if let Some(expected) = diff.expected_from {
+            changes_made.push(format!(
+                "Note: recommendation assumed {} was {}",
+                diff.field.as_str(),
+                expected
+            ));
+        }
+    }
+
+    if dry_run {
+        changes_made.push(format!("[DRY RUN] Would set {}{}", diff, capped));
+    } else if diff.is_noop() {
+        changes_made.push(format!("{} is already {}", diff.field.as_str(), diff.to));
+    } else {
+        diff.apply(&mut config)?;
+        changes_made.push(format!("Set {}{}", diff, capped));
+    }
+    Ok(diff)
 }
 
 async fn engram_get_trade_history(state: &AppState, args: Value) -> McpToolResult {
@@ -1914,12 +2149,33 @@ async fn engram_request_analysis(state: &AppState, args: Value) -> McpToolResult
     // Check if consensus engine is available
     let consensus_available = state.consensus_engine.is_ready().await;
 
+    // Counts against the same caps as POST /consensus/request; held until the analysis returns
+    let _permit = if consensus_available && total_trades > 0 {
+        let limiter_config = state.consensus_config.read().await.clone();
+        match state.consensus_limiter.try_acquire_manual(&limiter_config) {
+            Ok(permit) => Some(permit),
+            Err(throttle) => {
+                return McpToolResult::error(
+                    serde_json::json!({
+                        "error": throttle.message(),
+                        "retry_after_secs": throttle.retry_after_secs(),
+                        "throttle": throttle,
+                    })
+                    .to_string(),
+                )
+            }
+        }
+    } else {
+        None
+    };
+
     let result = if consensus_available && total_trades > 0 {
         // Run consensus analysis synchronously
         match state.consensus_engine.request_analysis(context).await {
             Ok(analysis_result) => {
                 // Save recommendations to engrams and collect IDs
                 let mut recommendation_ids: Vec<uuid::Uuid> = Vec::new();
+                let consensus_config = state.consensus_config.read().await.clone();
                 for rec in &analysis_result.recommendations {
                     let rec_id = uuid::Uuid::new_v4();
                     recommendation_ids.push(rec_id);
@@ -1973,6 +2229,10 @@ async fn engram_request_analysis(state: &AppState, args: Value) -> McpToolResult
                         status: crate::engrams::schemas::RecommendationStatus::Pending,
                         created_at: chrono::Utc::now(),
                         applied_at: None,
+                        below_confidence_floor: !consensus_config
+                            .meets_action_floor(rec.confidence),
+                        models: analysis_result.model_votes.clone(),
+                        outcome: None,
                     };
                     if let Err(e) = state
                         .engrams_client
@@ -2205,6 +2465,18 @@ async fn consensus_request_mcp(state: &AppState, args: Value) -> McpToolResult {
         &route_data,
     );
 
+    let limiter_config = state.consensus_config.read().await.clone();
+    let _permit = match state.consensus_limiter.try_acquire_manual(&limiter_config) {
+        Ok(permit) => permit,
+        Err(throttle) => {
+            return McpToolResult::error(format!(
+                "{} - retry in {}s",
+                throttle.message(),
+                throttle.retry_after_secs()
+            ))
+        }
+    };
+
     match state
         .consensus_engine
         .request_consensus(edge_id, &edge_context, models)
@@ -2322,7 +2594,7 @@ async fn consensus_config_update_mcp(state: &AppState, args: Value) -> McpToolRe
     McpToolResult::success(serde_json::json!({
         "message": "Configuration updates should be performed via REST API",
         "endpoint": "PUT /consensus/config",
-        "available_fields": ["enabled", "models", "min_consensus_threshold", "auto_apply_recommendations", "review_interval_hours"],
+        "available_fields": ["enabled", "models", "min_consensus_threshold", "auto_apply_recommendations", "min_action_confidence", "min_trades_for_analysis", "max_concurrent_requests", "max_manual_requests_per_hour", "review_interval_hours"],
         "note": "For security, configuration changes require direct REST API access"
     }).to_string())
 }
@@ -2462,7 +2734,7 @@ async fn web_search(state: &AppState, args: Value) -> McpToolResult {
 }
 
 async fn web_fetch(state: &AppState, args: Value) -> McpToolResult {
-    use crate::research::{ExtractMode, WebClient};
+    use crate::research::ExtractMode;
 
     let url = match args.get("url").and_then(|v| v.as_str()) {
         Some(u) => u,
@@ -2484,9 +2756,7 @@ async fn web_fetch(state: &AppState, args: Value) -> McpToolResult {
         .and_then(|v| v.as_u64())
         .unwrap_or(10000) as usize;
 
-    let client = WebClient::new();
-
-    match client.fetch(url, extract_mode, max_length).await {
+    match state.web_client.fetch(url, extract_mode, max_length).await {
         Ok(result) => {
             let response = serde_json::json!({
                 "id": result.id.to_string(),
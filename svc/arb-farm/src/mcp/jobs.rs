@@ -0,0 +1,358 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::{broadcast, RwLock};
+use uuid::Uuid;
+
+use super::tools::McpToolResult;
+
+const MAX_FINISHED_JOBS: usize = 100;
+/// Oldest progress updates are dropped past this, so a chatty tool can't grow a job unbounded.
+const MAX_PROGRESS_HISTORY: usize = 200;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum McpJobStatus {
+    Running,
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpJobProgress {
+    pub progress: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    pub at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpJob {
+    pub id: Uuid,
+    pub tool: String,
+    pub status: McpJobStatus,
+    pub progress: Vec<McpJobProgress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<McpToolResult>,
+    pub started_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+pub enum McpJobUpdate {
+    Progress(McpJobProgress),
+    Finished(McpJob),
+}
+
+impl McpJobUpdate {
+    /// JSON-RPC notification frame for this update, keyed by the job id as progress token.
+    pub fn to_notification(&self, job_id: Uuid) -> serde_json::Value {
+        match self {
+            McpJobUpdate::Progress(p) => serde_json::json!({
+                "jsonrpc": super::types::JSONRPC_VERSION,
+                "method": "notifications/progress",
+                "params": {
+                    "progressToken": job_id,
+                    "progress": p.progress,
+                    "total": p.total,
+                    "message": p.message,
+                }
+            }),
+            McpJobUpdate::Finished(job) => serde_json::json!({
+                "jsonrpc": super::types::JSONRPC_VERSION,
+                "method": "notifications/jobs/finished",
+                "params": {
+                    "jobId": job_id,
+                    "status": job.status,
+                    "result": job.result,
+                }
+            }),
+        }
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, McpJobUpdate::Finished(_))
+    }
+}
+
+struct JobEntry {
+    job: McpJob,
+    updates: broadcast::Sender<McpJobUpdate>,
+}
+
+/// Tracks tool calls started as background jobs so clients can poll or stream progress.
+#[derive(Clone, Default)]
+pub struct McpJobRegistry {
+    jobs: Arc<RwLock<HashMap<Uuid, JobEntry>>>,
+}
+
+#[derive(Clone)]
+pub struct ProgressReporter {
+    job_id: Uuid,
+    jobs: Arc<RwLock<HashMap<Uuid, JobEntry>>>,
+}
+
+impl ProgressReporter {
+    pub fn job_id(&self) -> Uuid {
+        self.job_id
+    }
+
+    pub async fn report(&self, progress: f64, total: Option<f64>, message: impl Into<String>) {
+        let update = McpJobProgress {
+            progress,
+            total,
+            message: Some(message.into()),
+            at: Utc::now(),
+        };
+
+        let mut jobs = self.jobs.write().await;
+        if let Some(entry) = jobs.get_mut(&self.job_id) {
+            entry.job.progress.push(update.clone());
+            let excess = entry
+                .job
+                .progress
+                .len()
+                .saturating_sub(MAX_PROGRESS_HISTORY);
+            entry.job.progress.drain(..excess);
+            let _ = entry.updates.send(McpJobUpdate::Progress(update));
+        }
+    }
+}
+
+impl McpJobRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `run` in the background and returns the job id immediately.
+    pub async fn spawn<F, Fut>(&self, tool: &str, run: F) -> Uuid
+    where
+        F: FnOnce(ProgressReporter) -> Fut + Send + 'static,
+        Fut: Future<Output = McpToolResult> + Send + 'static,
+    {
+        let id = Uuid::new_v4();
+        let (updates, _) = broadcast::channel(64);
+        let job = McpJob {
+            id,
+            tool: tool.to_string(),
+            status: McpJobStatus::Running,
+            progress: Vec::new(),
+            result: None,
+            started_at: Utc::now(),
+            finished_at: None,
+        };
+
+        {
+            let mut jobs = self.jobs.write().await;
+            Self::prune_finished(&mut jobs);
+            jobs.insert(id, JobEntry { job, updates });
+        }
+
+        let reporter = ProgressReporter {
+            job_id: id,
+            jobs: self.jobs.clone(),
+        };
+        let jobs = self.jobs.clone();
+        let tool = tool.to_string();
+        tokio::spawn(async move {
+            // Run the tool in its own task so a panic still finishes the job
+            let result = match tokio::spawn(run(reporter)).await {
+                Ok(result) => result,
+                Err(e) if e.is_panic() => McpToolResult::error(format!("{} panicked", tool)),
+                Err(e) => McpToolResult::error(format!("{} was cancelled: {}", tool, e)),
+            };
+
+            let mut jobs = jobs.write().await;
+            if let Some(entry) = jobs.get_mut(&id) {
+                entry.job.status = if result.is_error.unwrap_or(false) {
+                    McpJobStatus::Failed
+                } else {
+                    McpJobStatus::Completed
+                };
+                entry.job.result = Some(result);
+                entry.job.finished_at = Some(Utc::now());
+                let _ = entry
+                    .updates
+                    .send(McpJobUpdate::Finished(entry.job.clone()));
+            }
+        });
+
+        id
+    }
+
+    pub async fn get(&self, id: Uuid) -> Option<McpJob> {
+        self.jobs.read().await.get(&id).map(|e| e.job.clone())
+    }
+
+    /// Returns the updates recorded so far plus a receiver for the rest, taken under one
+    /// lock so no update is missed or duplicated between the two.
+    pub async fn subscribe(
+        &self,
+        id: Uuid,
+    ) -> Option<(Vec<McpJobUpdate>, broadcast::Receiver<McpJobUpdate>)> {
+        let jobs = self.jobs.read().await;
+        let entry = jobs.get(&id)?;
+
+        let mut backlog: Vec<McpJobUpdate> = entry
+            .job
+            .progress
+            .iter()
+            .cloned()
+            .map(McpJobUpdate::Progress)
+            .collect();
+        if entry.job.status != McpJobStatus::Running {
+            backlog.push(McpJobUpdate::Finished(entry.job.clone()));
+        }
+
+        Some((backlog, entry.updates.subscribe()))
+    }
+
+    fn prune_finished(jobs: &mut HashMap<Uuid, JobEntry>) {
+        let mut finished: Vec<(Uuid, DateTime<Utc>)> = jobs
+            .values()
+            .filter_map(|e| e.job.finished_at.map(|at| (e.job.id, at)))
+            .collect();
+        if finished.len() < MAX_FINISHED_JOBS {
+            return;
+        }
+
+        finished.sort_by_key(|(_, at)| *at);
+        let excess = finished.len() + 1 - MAX_FINISHED_JOBS;
+        for (id, _) in finished.into_iter().take(excess) {
+            jobs.remove(&id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    async fn mock_long_tool(reporter: ProgressReporter) -> McpToolResult {
+        for step in 1..=3 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            reporter
+                .report(step as f64, Some(3.0), format!("step {}", step))
+                .await;
+        }
+        McpToolResult::success("{\"closed\": 3}")
+    }
+
+    #[tokio::test]
+    async fn test_progress_notifications_then_terminal_result() {
+        let registry = McpJobRegistry::new();
+        let id = registry.spawn("mock_long_tool", mock_long_tool).await;
+
+        let (backlog, mut rx) = registry.subscribe(id).await.unwrap();
+        let mut updates = backlog;
+        while !updates.last().is_some_and(|u| u.is_terminal()) {
+            updates.push(rx.recv().await.unwrap());
+        }
+
+        let progress: Vec<f64> = updates
+            .iter()
+            .filter_map(|u| match u {
+                McpJobUpdate::Progress(p) => Some(p.progress),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(progress, vec![1.0, 2.0, 3.0]);
+
+        let terminal = updates.last().unwrap().to_notification(id);
+        assert_eq!(terminal["method"], "notifications/jobs/finished");
+        assert_eq!(terminal["params"]["status"], "completed");
+
+        let first = updates[0].to_notification(id);
+        assert_eq!(first["method"], "notifications/progress");
+        assert_eq!(first["params"]["progressToken"], id.to_string());
+
+        let job = registry.get(id).await.unwrap();
+        assert_eq!(job.status, McpJobStatus::Completed);
+        assert_eq!(job.result.unwrap().content[0].text, "{\"closed\": 3}");
+    }
+
+    #[tokio::test]
+    async fn test_late_subscriber_replays_history() {
+        let registry = McpJobRegistry::new();
+        let id = registry
+            .spawn("failing_tool", |reporter| async move {
+                reporter.report(0.5, None, "halfway").await;
+                McpToolResult::error("boom")
+            })
+            .await;
+
+        for _ in 0..50 {
+            if registry.get(id).await.unwrap().status != McpJobStatus::Running {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        let (backlog, _) = registry.subscribe(id).await.unwrap();
+        assert_eq!(backlog.len(), 2);
+        assert!(matches!(
+            &backlog[1],
+            McpJobUpdate::Finished(job) if job.status == McpJobStatus::Failed
+        ));
+    }
+
+    async fn wait_until_finished(registry: &McpJobRegistry, id: Uuid) -> McpJob {
+        for _ in 0..100 {
+            let job = registry.get(id).await.unwrap();
+            if job.status != McpJobStatus::Running {
+                return job;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        panic!("job {} never finished", id);
+    }
+
+    fn blow_up() -> McpToolResult {
+        panic!("tool blew up")
+    }
+
+    #[tokio::test]
+    async fn test_panicking_tool_marks_job_failed() {
+        let registry = McpJobRegistry::new();
+        let id = registry
+            .spawn("panicking_tool", |reporter| async move {
+                reporter.report(0.5, Some(1.0), "halfway").await;
+                blow_up()
+            })
+            .await;
+
+        let job = wait_until_finished(&registry, id).await;
+        assert_eq!(job.status, McpJobStatus::Failed);
+        assert!(job.finished_at.is_some());
+        let result = job.result.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(result.content[0].text, "panicking_tool panicked");
+    }
+
+    #[tokio::test]
+    async fn test_progress_history_keeps_latest_updates() {
+        let registry = McpJobRegistry::new();
+        let steps = MAX_PROGRESS_HISTORY + 50;
+        let id = registry
+            .spawn("chatty_tool", move |reporter| async move {
+                for step in 1..=steps {
+                    reporter
+                        .report(step as f64, Some(steps as f64), "step")
+                        .await;
+                }
+                McpToolResult::success("done")
+            })
+            .await;
+
+        let job = wait_until_finished(&registry, id).await;
+        assert_eq!(job.progress.len(), MAX_PROGRESS_HISTORY);
+        assert_eq!(job.progress.first().unwrap().progress, 51.0);
+        assert_eq!(job.progress.last().unwrap().progress, steps as f64);
+    }
+}
@@ -2,7 +2,8 @@ use axum::{extract::State, Json};
 use serde_json::json;
 use tracing::{error, info, warn};
 
-use super::handlers::execute_tool;
+use super::handlers::{execute_tool, start_tool_job};
+use super::jobs::McpJobStatus;
 use super::tools::get_all_tools;
 use super::types::*;
 use crate::server::AppState;
@@ -33,6 +34,7 @@ pub async fn handle_jsonrpc(
         }
         "tools/list" => handle_list_tools().await,
         "tools/call" => handle_call_tool(&state, request.params).await,
+        "jobs/get" => handle_get_job(&state, request.params).await,
         "ping" => {
             info!("Ping received");
             Ok(json!({}))
@@ -159,7 +161,44 @@ async fn handle_call_tool(
         None => json!({}),
     };
 
-    let tool_result = execute_tool(state, &call_request.name, args).await;
+    state
+        .mcp_metrics
+        .check_rate_limit(&call_request.name)
+        .map_err(|limited| {
+            warn!("{}", limited.message());
+            limited.to_jsonrpc_error()
+        })?;
+
+    if call_request
+        .meta
+        .as_ref()
+        .is_some_and(|meta| meta.wants_job())
+    {
+        let job_id = start_tool_job(state, &call_request.name, args).await;
+        info!("MCP tool {} started as job {}", call_request.name, job_id);
+
+        let handle = ToolJobHandle {
+            job_id,
+            status: McpJobStatus::Running,
+            poll_url: format!("/mcp/jobs/{}", job_id),
+            stream_url: format!("/mcp/jobs/{}/stream", job_id),
+        };
+        return serde_json::to_value(handle).map_err(|e| {
+            error!("Failed to serialize job handle: {}", e);
+            (
+                error_codes::INTERNAL_ERROR,
+                "Serialization error".to_string(),
+            )
+        });
+    }
+
+    let tool_result = state
+        .mcp_metrics
+        .measure(
+            &call_request.name,
+            execute_tool(state, &call_request.name, args),
+        )
+        .await;
 
     let result = CallToolResult {
         content: vec![ContentBlock::Text {
@@ -180,3 +219,30 @@ async fn handle_call_tool(
         )
     })
 }
+
+async fn handle_get_job(
+    state: &AppState,
+    params: Option<serde_json::Value>,
+) -> Result<serde_json::Value, (i32, String)> {
+    let job_id = params
+        .as_ref()
+        .and_then(|p| p.get("jobId"))
+        .and_then(|v| v.as_str())
+        .and_then(|s| uuid::Uuid::parse_str(s).ok())
+        .ok_or_else(|| (error_codes::INVALID_PARAMS, "jobId is required".to_string()))?;
+
+    let job = state.mcp_jobs.get(job_id).await.ok_or_else(|| {
+        (
+            error_codes::INVALID_PARAMS,
+            format!("Job not found: {}", job_id),
+        )
+    })?;
+
+    serde_json::to_value(job).map_err(|e| {
+        error!("Failed to serialize job: {}", e);
+        (
+            error_codes::INTERNAL_ERROR,
+            "Serialization error".to_string(),
+        )
+    })
+}
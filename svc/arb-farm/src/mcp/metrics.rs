@@ -0,0 +1,369 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::tools::{get_all_tools, McpToolResult};
+use super::types::error_codes;
+
+/// Calls to names that are not in the tool manifest are counted under this key, so callers
+/// cannot grow the stats map with made-up tool names.
+pub const UNKNOWN_TOOL_KEY: &str = "unknown";
+
+/// Upper bounds (inclusive) of the latency histogram buckets; slower calls land in overflow.
+pub const LATENCY_BUCKETS_MS: &[u64] = &[10, 50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolRateLimit {
+    pub max_calls: u32,
+    pub window_secs: u64,
+}
+
+impl ToolRateLimit {
+    fn window(&self) -> Duration {
+        Duration::from_secs(self.window_secs)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ToolRateLimited {
+    pub tool: String,
+    pub limit: ToolRateLimit,
+    pub retry_after_ms: u64,
+}
+
+impl ToolRateLimited {
+    pub fn message(&self) -> String {
+        format!(
+            "Rate limit exceeded for tool {}: {} calls per {}s (retry in {}ms)",
+            self.tool, self.limit.max_calls, self.limit.window_secs, self.retry_after_ms
+        )
+    }
+
+    pub fn to_jsonrpc_error(&self) -> (i32, String) {
+        (error_codes::RATE_LIMITED, self.message())
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LatencyBucket {
+    /// `None` is the overflow bucket.
+    pub le_ms: Option<u64>,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolMetricsSnapshot {
+    pub tool: String,
+    pub calls: u64,
+    pub errors: u64,
+    pub rate_limited: u64,
+    pub avg_latency_ms: f64,
+    pub max_latency_ms: u64,
+    pub latency_buckets: Vec<LatencyBucket>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<ToolRateLimit>,
+}
+
+#[derive(Debug, Default)]
+struct ToolStats {
+    calls: u64,
+    errors: u64,
+    rate_limited: u64,
+    total_latency_ms: u64,
+    max_latency_ms: u64,
+    buckets: Vec<u64>,
+    recent_calls: VecDeque<Instant>,
+}
+
+impl ToolStats {
+    fn observe(&mut self, latency_ms: u64, is_error: bool) {
+        if self.buckets.is_empty() {
+            self.buckets = vec![0; LATENCY_BUCKETS_MS.len() + 1];
+        }
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|le| latency_ms <= *le)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.buckets[bucket] += 1;
+
+        self.calls += 1;
+        if is_error {
+            self.errors += 1;
+        }
+        self.total_latency_ms += latency_ms;
+        self.max_latency_ms = self.max_latency_ms.max(latency_ms);
+    }
+}
+
+/// Per-tool call counts, latency histograms and optional sliding-window rate limits for
+/// MCP tool execution.
+#[derive(Clone)]
+pub struct McpToolMetrics {
+    stats: Arc<Mutex<HashMap<String, ToolStats>>>,
+    limits: Arc<HashMap<String, ToolRateLimit>>,
+    known_tools: Arc<HashSet<String>>,
+}
+
+impl Default for McpToolMetrics {
+    fn default() -> Self {
+        Self {
+            stats: Arc::new(Mutex::new(HashMap::new())),
+            limits: Arc::new(HashMap::new()),
+            known_tools: Arc::new(get_all_tools().into_iter().map(|t| t.name).collect()),
+        }
+    }
+}
+
+impl McpToolMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn stats_key<'a>(&self, tool: &'a str) -> &'a str {
+        if self.known_tools.contains(tool) {
+            tool
+        } else {
+            UNKNOWN_TOOL_KEY
+        }
+    }
+
+    /// `MCP_TOOL_RATE_LIMITS` as comma-separated `tool=calls/secs`, e.g.
+    /// `consensus_request=5/60,research_ingest_url=10/60`. Malformed entries are skipped.
+    pub fn from_env() -> Self {
+        let mut metrics = Self::new();
+        if let Ok(spec) = std::env::var("MCP_TOOL_RATE_LIMITS") {
+            for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+                match parse_rate_limit(entry) {
+                    Some((tool, limit)) => {
+                        metrics = metrics.with_rate_limit(&tool, limit.max_calls, limit.window())
+                    }
+                    None => tracing::warn!("Ignoring malformed MCP tool rate limit: {}", entry),
+                }
+            }
+        }
+        metrics
+    }
+
+    pub fn with_rate_limit(mut self, tool: &str, max_calls: u32, window: Duration) -> Self {
+        Arc::make_mut(&mut self.limits).insert(
+            tool.to_string(),
+            ToolRateLimit {
+                max_calls,
+                window_secs: window.as_secs().max(1),
+            },
+        );
+        self
+    }
+
+    /// Admits a call when the tool is under its limit, counting it towards the window.
+    pub fn check_rate_limit(&self, tool: &str) -> Result<(), ToolRateLimited> {
+        let Some(limit) = self.limits.get(tool).copied() else {
+            return Ok(());
+        };
+
+        let now = Instant::now();
+        let mut stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = stats.entry(self.stats_key(tool).to_string()).or_default();
+        while entry
+            .recent_calls
+            .front()
+            .is_some_and(|at| now.duration_since(*at) >= limit.window())
+        {
+            entry.recent_calls.pop_front();
+        }
+
+        if entry.recent_calls.len() >= limit.max_calls as usize {
+            entry.rate_limited += 1;
+            let retry_after = entry
+                .recent_calls
+                .front()
+                .map(|oldest| limit.window().saturating_sub(now.duration_since(*oldest)))
+                .unwrap_or_default();
+            return Err(ToolRateLimited {
+                tool: tool.to_string(),
+                limit,
+                retry_after_ms: retry_after.as_millis() as u64,
+            });
+        }
+
+        entry.recent_calls.push_back(now);
+        Ok(())
+    }
+
+    pub fn record(&self, tool: &str, latency: Duration, is_error: bool) {
+        let mut stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        stats
+            .entry(self.stats_key(tool).to_string())
+            .or_default()
+            .observe(latency.as_millis() as u64, is_error);
+    }
+
+    /// Runs a tool call and records its latency and outcome.
+    pub async fn measure<F>(&self, tool: &str, call: F) -> McpToolResult
+    where
+        F: Future<Output = McpToolResult>,
+    {
+        let started = Instant::now();
+        let result = call.await;
+        self.record(tool, started.elapsed(), result.is_error.unwrap_or(false));
+        result
+    }
+
+    /// Busiest tools first.
+    pub fn snapshot(&self) -> Vec<ToolMetricsSnapshot> {
+        let stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        let mut tools: Vec<ToolMetricsSnapshot> = stats
+            .iter()
+            .map(|(tool, s)| ToolMetricsSnapshot {
+                tool: tool.clone(),
+                calls: s.calls,
+                errors: s.errors,
+                rate_limited: s.rate_limited,
+                avg_latency_ms: if s.calls > 0 {
+                    s.total_latency_ms as f64 / s.calls as f64
+                } else {
+                    0.0
+                },
+                max_latency_ms: s.max_latency_ms,
+                latency_buckets: LATENCY_BUCKETS_MS
+                    .iter()
+                    .map(|le| Some(*le))
+                    .chain(std::iter::once(None))
+                    .enumerate()
+                    .map(|(i, le_ms)| LatencyBucket {
+                        le_ms,
+                        count: s.buckets.get(i).copied().unwrap_or(0),
+                    })
+                    .collect(),
+                rate_limit: self.limits.get(tool).copied(),
+            })
+            .collect();
+        tools.sort_by(|a, b| b.calls.cmp(&a.calls).then_with(|| a.tool.cmp(&b.tool)));
+        tools
+    }
+}
+
+fn parse_rate_limit(entry: &str) -> Option<(String, ToolRateLimit)> {
+    let (tool, limit) = entry.split_once('=')?;
+    let (calls, secs) = limit.split_once('/')?;
+    let max_calls = calls.trim().parse::<u32>().ok()?;
+    let window_secs = secs.trim().parse::<u64>().ok().filter(|s| *s > 0)?;
+    Some((
+        tool.trim().to_string(),
+        ToolRateLimit {
+            max_calls,
+            window_secs,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_metrics_accumulate_per_tool() {
+        let metrics = McpToolMetrics::new();
+
+        for _ in 0..3 {
+            metrics
+                .measure("scanner_status", async { McpToolResult::success("{}") })
+                .await;
+        }
+        metrics
+            .measure("edge_list", async {
+                tokio::time::sleep(Duration::from_millis(60)).await;
+                McpToolResult::error("boom")
+            })
+            .await;
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot[0].tool, "scanner_status");
+        assert_eq!(snapshot[0].calls, 3);
+        assert_eq!(snapshot[0].errors, 0);
+        assert_eq!(snapshot[0].latency_buckets[0].count, 3);
+
+        let edge = &snapshot[1];
+        assert_eq!(edge.calls, 1);
+        assert_eq!(edge.errors, 1);
+        assert!(edge.max_latency_ms >= 60);
+        let bucketed: u64 = edge.latency_buckets.iter().map(|b| b.count).sum();
+        assert_eq!(bucketed, 1);
+        assert_eq!(edge.latency_buckets[0].count, 0);
+        assert_eq!(edge.latency_buckets.last().unwrap().le_ms, None);
+    }
+
+    #[test]
+    fn test_exceeding_tool_rate_limit_returns_rate_limit_error() {
+        let metrics =
+            McpToolMetrics::new().with_rate_limit("consensus_request", 2, Duration::from_secs(60));
+
+        assert!(metrics.check_rate_limit("consensus_request").is_ok());
+        assert!(metrics.check_rate_limit("consensus_request").is_ok());
+        let limited = metrics.check_rate_limit("consensus_request").unwrap_err();
+        let (code, message) = limited.to_jsonrpc_error();
+        assert_eq!(code, error_codes::RATE_LIMITED);
+        assert!(message.contains("consensus_request"));
+        assert!(limited.retry_after_ms > 0);
+
+        // Tools without a limit are never throttled
+        for _ in 0..10 {
+            assert!(metrics.check_rate_limit("scanner_status").is_ok());
+        }
+
+        let snapshot = metrics.snapshot();
+        let consensus = snapshot
+            .iter()
+            .find(|t| t.tool == "consensus_request")
+            .unwrap();
+        assert_eq!(consensus.rate_limited, 1);
+        assert_eq!(
+            consensus.rate_limit,
+            Some(ToolRateLimit {
+                max_calls: 2,
+                window_secs: 60
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unknown_tool_names_share_one_bucket() {
+        let metrics = McpToolMetrics::new();
+
+        for i in 0..50 {
+            metrics
+                .measure(&format!("made_up_tool_{}", i), async {
+                    McpToolResult::error("Unknown tool")
+                })
+                .await;
+        }
+        metrics
+            .measure("scanner_status", async { McpToolResult::success("{}") })
+            .await;
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0].tool, UNKNOWN_TOOL_KEY);
+        assert_eq!(snapshot[0].calls, 50);
+        assert_eq!(snapshot[0].errors, 50);
+        assert_eq!(snapshot[1].tool, "scanner_status");
+    }
+
+    #[test]
+    fn test_parse_rate_limit_spec() {
+        assert_eq!(
+            parse_rate_limit("research_ingest_url=10/60"),
+            Some((
+                "research_ingest_url".to_string(),
+                ToolRateLimit {
+                    max_calls: 10,
+                    window_secs: 60
+                }
+            ))
+        );
+        assert_eq!(parse_rate_limit("bad"), None);
+        assert_eq!(parse_rate_limit("tool=5/0"), None);
+    }
+}
@@ -1,9 +1,13 @@
 pub mod handlers;
+pub mod jobs;
 pub mod jsonrpc;
+pub mod metrics;
 pub mod tools;
 pub mod types;
 
 pub use handlers::*;
+pub use jobs::*;
 pub use jsonrpc::handle_jsonrpc;
+pub use metrics::{McpToolMetrics, ToolMetricsSnapshot, ToolRateLimit, ToolRateLimited};
 pub use tools::*;
 pub use types::*;
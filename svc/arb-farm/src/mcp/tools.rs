@@ -1001,6 +1001,10 @@ pub fn get_research_tools() -> Vec<McpTool> {
                     "notes": {
                         "type": "string",
                         "description": "Optional approval notes"
+                    },
+                    "auto_backtest": {
+                        "type": "boolean",
+                        "description": "Backtest the strategy before approving and attach the result (defaults to ARB_DISCOVERY_AUTO_BACKTEST)"
                     }
                 },
                 "required": ["discovery_id"]
@@ -2002,7 +2006,7 @@ pub fn get_learning_tools() -> Vec<McpTool> {
         },
         McpTool {
             name: "engram_apply_recommendation".to_string(),
-            description: "Apply a recommendation's suggested action to the system. Modifies configs, strategies, or adds avoidances based on the recommendation type. Recommendation must be 'pending' or 'acknowledged'.".to_string(),
+            description: "Apply a recommendation's suggested action to the system. Modifies configs, strategies, or adds avoidances based on the recommendation type. Risk changes are limited to bounded, non-safety fields. Recommendation must be 'pending' or 'acknowledged', and is only marked applied when the change went through.".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
@@ -2109,6 +2113,20 @@ pub fn get_learning_tools() -> Vec<McpTool> {
     ]
 }
 
+pub fn get_position_tools() -> Vec<McpTool> {
+    vec![McpTool {
+        name: "position_emergency_close_all".to_string(),
+        description: "Force-sell every open position at market".to_string(),
+        input_schema: serde_json::json!({
+            "type": "object",
+            "properties": {},
+            "required": []
+        }),
+        annotations: Some(McpToolAnnotations::destructive()),
+        tags: None,
+    }]
+}
+
 pub fn get_approval_tools() -> Vec<McpTool> {
     vec![
         McpTool {
@@ -2372,6 +2390,7 @@ pub fn get_all_tools() -> Vec<McpTool> {
     tools.extend(get_learning_tools());
     tools.extend(get_consensus_tools());
     tools.extend(get_swarm_tools());
+    tools.extend(get_position_tools());
     tools.extend(get_approval_tools());
     tools.extend(get_internet_tools());
     tools
@@ -153,6 +153,35 @@ pub struct CallToolRequest {
     pub name: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub arguments: Option<HashMap<String, serde_json::Value>>,
+    #[serde(rename = "_meta", default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<CallToolMeta>,
+}
+
+/// Request metadata. Setting `async` (or supplying a `progressToken`) runs the tool as a
+/// background job and returns a job handle instead of blocking until the tool finishes.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CallToolMeta {
+    #[serde(rename = "async", default)]
+    pub run_async: bool,
+    #[serde(rename = "progressToken", skip_serializing_if = "Option::is_none")]
+    pub progress_token: Option<serde_json::Value>,
+}
+
+impl CallToolMeta {
+    pub fn wants_job(&self) -> bool {
+        self.run_async || self.progress_token.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolJobHandle {
+    #[serde(rename = "jobId")]
+    pub job_id: uuid::Uuid,
+    pub status: crate::mcp::jobs::McpJobStatus,
+    #[serde(rename = "pollUrl")]
+    pub poll_url: String,
+    #[serde(rename = "streamUrl")]
+    pub stream_url: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -175,6 +204,8 @@ pub mod error_codes {
     pub const METHOD_NOT_FOUND: i32 = -32601;
     pub const INVALID_PARAMS: i32 = -32602;
     pub const INTERNAL_ERROR: i32 = -32603;
+    /// Implementation-defined server error: a tool's call rate limit was exceeded.
+    pub const RATE_LIMITED: i32 = -32029;
 }
 
 impl JsonRpcResponse {
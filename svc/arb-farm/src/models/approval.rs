@@ -1,7 +1,10 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::error::{AppError, AppResult};
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum ApprovalStatus {
@@ -42,6 +45,119 @@ impl std::fmt::Display for ApprovalType {
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TradeSide {
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TradeApprovalPayload {
+    pub token_mint: String,
+    pub token_symbol: Option<String>,
+    pub side: TradeSide,
+    pub amount_sol: f64,
+    pub max_slippage_bps: Option<u16>,
+    pub venue: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConfigChangeApprovalPayload {
+    pub target: String,
+    pub field: String,
+    pub current_value: serde_json::Value,
+    pub proposed_value: serde_json::Value,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecommendationApprovalPayload {
+    pub source: String,
+    pub title: String,
+    pub description: String,
+    pub confidence: f64,
+    pub suggested_action: Option<serde_json::Value>,
+}
+
+/// Structured payload attached to an approval so the UI can render it per kind
+/// and the approval manager can validate kind-specific fields before approving.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ApprovalRequest {
+    Trade(TradeApprovalPayload),
+    ConfigChange(ConfigChangeApprovalPayload),
+    Recommendation(RecommendationApprovalPayload),
+}
+
+impl ApprovalRequest {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ApprovalRequest::Trade(_) => "trade",
+            ApprovalRequest::ConfigChange(_) => "config_change",
+            ApprovalRequest::Recommendation(_) => "recommendation",
+        }
+    }
+
+    pub fn validate(&self) -> AppResult<()> {
+        match self {
+            ApprovalRequest::Trade(trade) => {
+                if trade.token_mint.trim().is_empty() {
+                    return Err(AppError::Validation(
+                        "Trade approval requires a token_mint".to_string(),
+                    ));
+                }
+                if !trade.amount_sol.is_finite() || trade.amount_sol <= 0.0 {
+                    return Err(AppError::Validation(format!(
+                        "Trade approval amount_sol must be positive (got {})",
+                        trade.amount_sol
+                    )));
+                }
+                if let Some(slippage) = trade.max_slippage_bps {
+                    if slippage > 10_000 {
+                        return Err(AppError::Validation(format!(
+                            "Trade approval max_slippage_bps must be <= 10000 (got {})",
+                            slippage
+                        )));
+                    }
+                }
+            }
+            ApprovalRequest::ConfigChange(change) => {
+                if change.target.trim().is_empty() || change.field.trim().is_empty() {
+                    return Err(AppError::Validation(
+                        "Config change approval requires a target and field".to_string(),
+                    ));
+                }
+                if change.proposed_value.is_null() {
+                    return Err(AppError::Validation(
+                        "Config change approval requires a proposed_value".to_string(),
+                    ));
+                }
+                if change.current_value == change.proposed_value {
+                    return Err(AppError::Validation(format!(
+                        "Config change for {}.{} does not change the current value",
+                        change.target, change.field
+                    )));
+                }
+            }
+            ApprovalRequest::Recommendation(rec) => {
+                if rec.title.trim().is_empty() {
+                    return Err(AppError::Validation(
+                        "Recommendation approval requires a title".to_string(),
+                    ));
+                }
+                if !(0.0..=1.0).contains(&rec.confidence) {
+                    return Err(AppError::Validation(format!(
+                        "Recommendation confidence must be between 0 and 1 (got {})",
+                        rec.confidence
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PendingApproval {
     pub id: Uuid,
@@ -56,6 +172,8 @@ pub struct PendingApproval {
     pub token_symbol: Option<String>,
     pub amount_sol: Option<f64>,
     pub context: serde_json::Value,
+    #[serde(default)]
+    pub request: Option<ApprovalRequest>,
     pub expires_at: DateTime<Utc>,
     pub hecate_decision: Option<bool>,
     pub hecate_reasoning: Option<String>,
@@ -86,11 +204,12 @@ impl PendingApproval {
             status: ApprovalStatus::Pending,
             estimated_profit_lamports,
             risk_score,
+            request: trade_request(TradeSide::Buy, &token_mint, &token_symbol, amount_sol),
             token_mint,
             token_symbol,
             amount_sol,
             context,
-            expires_at: Utc::now() + chrono::Duration::seconds(timeout_secs as i64),
+            expires_at: Utc::now() + Duration::seconds(timeout_secs as i64),
             hecate_decision: None,
             hecate_reasoning: None,
             hecate_confidence: None,
@@ -118,11 +237,12 @@ impl PendingApproval {
             status: ApprovalStatus::Pending,
             estimated_profit_lamports: None,
             risk_score: None,
+            request: trade_request(TradeSide::Sell, &token_mint, &token_symbol, amount_sol),
             token_mint,
             token_symbol,
             amount_sol,
             context,
-            expires_at: Utc::now() + chrono::Duration::seconds(timeout_secs as i64),
+            expires_at: Utc::now() + Duration::seconds(timeout_secs as i64),
             hecate_decision: None,
             hecate_reasoning: None,
             hecate_confidence: None,
@@ -132,6 +252,18 @@ impl PendingApproval {
         }
     }
 
+    /// Replaces the typed payload, keeping the top-level trade fields in step with it.
+    pub fn set_request(&mut self, request: ApprovalRequest) {
+        if let ApprovalRequest::Trade(trade) = &request {
+            self.token_mint = Some(trade.token_mint.clone());
+            if trade.token_symbol.is_some() {
+                self.token_symbol = trade.token_symbol.clone();
+            }
+            self.amount_sol = Some(trade.amount_sol);
+        }
+        self.request = Some(request);
+    }
+
     pub fn is_expired(&self) -> bool {
         Utc::now() > self.expires_at
     }
@@ -142,6 +274,23 @@ impl PendingApproval {
     }
 }
 
+fn trade_request(
+    side: TradeSide,
+    token_mint: &Option<String>,
+    token_symbol: &Option<String>,
+    amount_sol: Option<f64>,
+) -> Option<ApprovalRequest> {
+    let (token_mint, amount_sol) = (token_mint.as_ref()?, amount_sol?);
+    Some(ApprovalRequest::Trade(TradeApprovalPayload {
+        token_mint: token_mint.clone(),
+        token_symbol: token_symbol.clone(),
+        side,
+        amount_sol,
+        max_slippage_bps: None,
+        venue: None,
+    }))
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GlobalExecutionConfig {
     pub auto_execution_enabled: bool,
@@ -156,9 +305,126 @@ pub struct GlobalExecutionConfig {
     pub auto_min_confidence: f64,
     pub auto_max_position_sol: f64,
     pub require_simulation: bool,
+    #[serde(default)]
+    pub schedule: ExecutionSchedule,
+    #[serde(default)]
+    pub manual_override: Option<ManualExecutionOverride>,
     pub updated_at: DateTime<Utc>,
 }
 
+impl GlobalExecutionConfig {
+    /// Execution state the schedule wants at `now`, or `None` when no schedule is active.
+    /// A manual override wins until the schedule next crosses a window boundary.
+    pub fn scheduled_execution_state(&self, now: DateTime<Utc>) -> Option<bool> {
+        if !self.schedule.enabled {
+            return None;
+        }
+
+        let in_window = self.schedule.is_within_window(now);
+        match &self.manual_override {
+            Some(o) if o.window_state_at_set == in_window => Some(o.enabled),
+            _ => Some(in_window),
+        }
+    }
+
+    pub fn has_stale_override(&self, now: DateTime<Utc>) -> bool {
+        match &self.manual_override {
+            Some(o) => {
+                !self.schedule.enabled
+                    || o.window_state_at_set != self.schedule.is_within_window(now)
+            }
+            None => false,
+        }
+    }
+}
+
+/// Daily window in the schedule's local time. `end` earlier than `start` wraps past midnight.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TradingWindow {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl TradingWindow {
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            time >= self.start && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExecutionSchedule {
+    pub enabled: bool,
+    /// IANA zone the windows are read in, e.g. "America/New_York". Follows DST.
+    #[serde(default = "default_schedule_timezone")]
+    pub timezone: String,
+    #[serde(default)]
+    pub windows: Vec<TradingWindow>,
+}
+
+fn default_schedule_timezone() -> String {
+    "UTC".to_string()
+}
+
+impl Default for ExecutionSchedule {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            timezone: default_schedule_timezone(),
+            windows: Vec::new(),
+        }
+    }
+}
+
+impl ExecutionSchedule {
+    pub fn tz(&self) -> AppResult<Tz> {
+        self.timezone.parse::<Tz>().map_err(|_| {
+            AppError::Validation(format!(
+                "Unknown timezone '{}', expected an IANA name such as America/New_York",
+                self.timezone
+            ))
+        })
+    }
+
+    /// Wall-clock time in the schedule's zone. Falls back to UTC if the zone
+    /// does not parse, which `validate` rules out for schedules that were set.
+    pub fn local_time(&self, now: DateTime<Utc>) -> NaiveTime {
+        let tz = self.tz().unwrap_or(Tz::UTC);
+        now.with_timezone(&tz).time()
+    }
+
+    pub fn is_within_window(&self, now: DateTime<Utc>) -> bool {
+        let local = self.local_time(now);
+        self.windows.iter().any(|w| w.contains(local))
+    }
+
+    pub fn validate(&self) -> AppResult<()> {
+        self.tz()?;
+        if self.enabled && self.windows.is_empty() {
+            return Err(AppError::Validation(
+                "An enabled schedule needs at least one trading window".to_string(),
+            ));
+        }
+        if let Some(w) = self.windows.iter().find(|w| w.start == w.end) {
+            return Err(AppError::Validation(format!(
+                "Trading window {} - {} is empty",
+                w.start, w.end
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ManualExecutionOverride {
+    pub enabled: bool,
+    pub set_at: DateTime<Utc>,
+    pub window_state_at_set: bool,
+}
+
 impl Default for GlobalExecutionConfig {
     fn default() -> Self {
         Self {
@@ -174,6 +440,8 @@ impl Default for GlobalExecutionConfig {
             auto_min_confidence: 0.8,
             auto_max_position_sol: 0.5,
             require_simulation: true,
+            schedule: ExecutionSchedule::default(),
+            manual_override: None,
             updated_at: Utc::now(),
         }
     }
@@ -182,6 +450,9 @@ impl Default for GlobalExecutionConfig {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApproveRequest {
     pub notes: Option<String>,
+    /// Optional amended payload; must be the same kind as the pending approval's request.
+    #[serde(default)]
+    pub request: Option<ApprovalRequest>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -217,3 +488,275 @@ pub struct HecateRecommendation {
     pub reasoning: String,
     pub confidence: f64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade_request() -> ApprovalRequest {
+        ApprovalRequest::Trade(TradeApprovalPayload {
+            token_mint: "So11111111111111111111111111111111111111112".to_string(),
+            token_symbol: Some("SOL".to_string()),
+            side: TradeSide::Buy,
+            amount_sol: 0.25,
+            max_slippage_bps: Some(150),
+            venue: Some("pump_fun".to_string()),
+        })
+    }
+
+    fn config_change_request() -> ApprovalRequest {
+        ApprovalRequest::ConfigChange(ConfigChangeApprovalPayload {
+            target: "strategy:curve-sniper".to_string(),
+            field: "max_position_sol".to_string(),
+            current_value: serde_json::json!(0.5),
+            proposed_value: serde_json::json!(1.0),
+            reason: Some("Win rate above 70% for 50 trades".to_string()),
+        })
+    }
+
+    fn recommendation_request() -> ApprovalRequest {
+        ApprovalRequest::Recommendation(RecommendationApprovalPayload {
+            source: "engram_harvester".to_string(),
+            title: "Tighten stop loss".to_string(),
+            description: "Losses cluster beyond -15%".to_string(),
+            confidence: 0.82,
+            suggested_action: Some(serde_json::json!({"stop_loss_percent": 12.0})),
+        })
+    }
+
+    #[test]
+    fn test_each_kind_round_trips_with_tag() {
+        for (request, kind) in [
+            (trade_request(), "trade"),
+            (config_change_request(), "config_change"),
+            (recommendation_request(), "recommendation"),
+        ] {
+            request.validate().unwrap();
+
+            let json = serde_json::to_value(&request).unwrap();
+            assert_eq!(json["kind"], kind);
+            assert_eq!(request.kind(), kind);
+
+            let decoded: ApprovalRequest = serde_json::from_value(json).unwrap();
+            assert_eq!(decoded, request);
+        }
+    }
+
+    #[test]
+    fn test_trade_validation() {
+        let ApprovalRequest::Trade(valid) = trade_request() else {
+            unreachable!()
+        };
+
+        let mut zero_amount = valid.clone();
+        zero_amount.amount_sol = 0.0;
+        assert!(ApprovalRequest::Trade(zero_amount).validate().is_err());
+
+        let mut missing_mint = valid.clone();
+        missing_mint.token_mint = " ".to_string();
+        assert!(ApprovalRequest::Trade(missing_mint).validate().is_err());
+
+        let mut bad_slippage = valid;
+        bad_slippage.max_slippage_bps = Some(10_001);
+        assert!(ApprovalRequest::Trade(bad_slippage).validate().is_err());
+    }
+
+    #[test]
+    fn test_config_change_validation() {
+        let ApprovalRequest::ConfigChange(valid) = config_change_request() else {
+            unreachable!()
+        };
+
+        let mut no_op = valid.clone();
+        no_op.proposed_value = no_op.current_value.clone();
+        assert!(ApprovalRequest::ConfigChange(no_op).validate().is_err());
+
+        let mut null_value = valid.clone();
+        null_value.proposed_value = serde_json::Value::Null;
+        assert!(ApprovalRequest::ConfigChange(null_value)
+            .validate()
+            .is_err());
+
+        let mut missing_field = valid;
+        missing_field.field = String::new();
+        assert!(ApprovalRequest::ConfigChange(missing_field)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_recommendation_validation() {
+        let ApprovalRequest::Recommendation(valid) = recommendation_request() else {
+            unreachable!()
+        };
+
+        let mut overconfident = valid.clone();
+        overconfident.confidence = 1.5;
+        assert!(ApprovalRequest::Recommendation(overconfident)
+            .validate()
+            .is_err());
+
+        let mut untitled = valid;
+        untitled.title = String::new();
+        assert!(ApprovalRequest::Recommendation(untitled)
+            .validate()
+            .is_err());
+    }
+
+    fn hm(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn test_trading_window_wraps_midnight() {
+        let overnight = TradingWindow {
+            start: hm(22, 0),
+            end: hm(2, 0),
+        };
+        assert!(overnight.contains(hm(23, 30)));
+        assert!(overnight.contains(hm(1, 59)));
+        assert!(!overnight.contains(hm(2, 0)));
+        assert!(!overnight.contains(hm(12, 0)));
+    }
+
+    #[test]
+    fn test_schedule_follows_timezone_across_dst() {
+        let schedule = ExecutionSchedule {
+            enabled: true,
+            timezone: "America/New_York".to_string(),
+            windows: vec![TradingWindow {
+                start: hm(9, 30),
+                end: hm(16, 0),
+            }],
+        };
+        let at = |month, day, h, m| {
+            chrono::NaiveDate::from_ymd_opt(2026, month, day)
+                .unwrap()
+                .and_hms_opt(h, m, 0)
+                .unwrap()
+                .and_utc()
+        };
+
+        // EST (UTC-5): the window is 14:30-21:00 UTC.
+        assert!(schedule.is_within_window(at(3, 2, 15, 0)));
+        assert!(!schedule.is_within_window(at(3, 2, 14, 0)));
+        assert!(schedule.is_within_window(at(3, 2, 20, 30)));
+
+        // EDT (UTC-4) after the March 8 switch: the window is 13:30-20:00 UTC.
+        assert!(schedule.is_within_window(at(3, 9, 14, 0)));
+        assert!(!schedule.is_within_window(at(3, 9, 20, 30)));
+        assert_eq!(schedule.local_time(at(3, 9, 14, 0)), hm(10, 0));
+        assert!(schedule.validate().is_ok());
+
+        let no_windows = ExecutionSchedule {
+            windows: vec![],
+            ..schedule.clone()
+        };
+        assert!(no_windows.validate().is_err());
+
+        let bad_zone = ExecutionSchedule {
+            timezone: "Mars/Olympus_Mons".to_string(),
+            ..schedule
+        };
+        assert!(bad_zone.validate().is_err());
+    }
+
+    #[test]
+    fn test_schedule_without_timezone_defaults_to_utc() {
+        let schedule: ExecutionSchedule = serde_json::from_value(serde_json::json!({
+            "enabled": true,
+            "windows": [{ "start": "13:00:00", "end": "17:00:00" }]
+        }))
+        .unwrap();
+
+        assert_eq!(schedule.timezone, "UTC");
+        assert!(schedule.is_within_window(
+            chrono::NaiveDate::from_ymd_opt(2026, 3, 2)
+                .unwrap()
+                .and_hms_opt(13, 0, 0)
+                .unwrap()
+                .and_utc()
+        ));
+    }
+
+    #[test]
+    fn test_pending_approval_without_request_deserializes() {
+        let approval = PendingApproval::new_exit(
+            Uuid::new_v4(),
+            None,
+            None,
+            None,
+            None,
+            serde_json::json!({}),
+            60,
+        );
+        let mut json = serde_json::to_value(&approval).unwrap();
+        json.as_object_mut().unwrap().remove("request");
+
+        let decoded: PendingApproval = serde_json::from_value(json).unwrap();
+        assert!(decoded.request.is_none());
+    }
+
+    #[test]
+    fn test_constructors_attach_trade_request() {
+        let entry = PendingApproval::new_entry(
+            Uuid::new_v4(),
+            None,
+            None,
+            None,
+            Some("MintA".to_string()),
+            Some("AAA".to_string()),
+            Some(0.2),
+            serde_json::json!({}),
+            60,
+        );
+        match entry.request {
+            Some(ApprovalRequest::Trade(trade)) => {
+                assert_eq!(trade.side, TradeSide::Buy);
+                assert_eq!(trade.token_mint, "MintA");
+                assert_eq!(trade.amount_sol, 0.2);
+            }
+            other => panic!("unexpected request: {:?}", other),
+        }
+
+        let exit = PendingApproval::new_exit(
+            Uuid::new_v4(),
+            None,
+            Some("MintA".to_string()),
+            None,
+            None,
+            serde_json::json!({}),
+            60,
+        );
+        assert!(exit.request.is_none());
+    }
+
+    #[test]
+    fn test_set_request_syncs_trade_fields() {
+        let mut approval = PendingApproval::new_exit(
+            Uuid::new_v4(),
+            None,
+            Some("MintA".to_string()),
+            Some("AAA".to_string()),
+            Some(1.0),
+            serde_json::json!({}),
+            60,
+        );
+
+        approval.set_request(ApprovalRequest::Trade(TradeApprovalPayload {
+            token_mint: "MintB".to_string(),
+            token_symbol: None,
+            side: TradeSide::Sell,
+            amount_sol: 0.4,
+            max_slippage_bps: Some(300),
+            venue: None,
+        }));
+
+        assert_eq!(approval.token_mint.as_deref(), Some("MintB"));
+        assert_eq!(approval.token_symbol.as_deref(), Some("AAA"));
+        assert_eq!(approval.amount_sol, Some(0.4));
+
+        approval.set_request(recommendation_request());
+        assert_eq!(approval.amount_sol, Some(0.4));
+    }
+}
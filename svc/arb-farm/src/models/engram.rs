@@ -247,3 +247,56 @@ pub struct PatternMatch {
     pub similarity_score: f64,
     pub recommended_action: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StrategySuggestionThresholds {
+    pub min_success_rate: f64,
+    pub min_sample_count: u32,
+    pub min_confidence: f64,
+    pub min_avg_profit_bps: f64,
+}
+
+impl Default for StrategySuggestionThresholds {
+    fn default() -> Self {
+        Self {
+            min_success_rate: 0.7,
+            min_sample_count: 20,
+            min_confidence: 0.7,
+            min_avg_profit_bps: 50.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategySuggestion {
+    pub suggestion_key: String,
+    pub pattern_key: String,
+    pub pattern_engram_id: Uuid,
+    pub status: String,
+    pub is_active: bool,
+    pub strategy: StrategyContent,
+    pub venue_types: Vec<String>,
+    pub rationale: Vec<String>,
+    pub suggested_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggestion_thresholds_fill_omitted_fields() {
+        let thresholds: StrategySuggestionThresholds =
+            serde_json::from_str(r#"{"min_sample_count": 5}"#).unwrap();
+        assert_eq!(thresholds.min_sample_count, 5);
+        assert_eq!(thresholds.min_success_rate, 0.7);
+        assert_eq!(thresholds.min_avg_profit_bps, 50.0);
+
+        assert!(serde_json::from_str::<StrategySuggestionThresholds>("{}").is_ok());
+        assert!(serde_json::from_str::<StrategySuggestionThresholds>(
+            r#"{"min_sample_count": "5"}"#
+        )
+        .is_err());
+    }
+}
@@ -94,6 +94,15 @@ pub struct RiskParams {
     pub momentum_adaptive_exits: bool,
     #[serde(default)]
     pub let_winners_run: bool,
+    /// Named exit-config template applied to positions this strategy opens.
+    #[serde(default)]
+    pub exit_template: Option<String>,
+    /// Kill the strategy after this many losing trades in a row.
+    #[serde(default)]
+    pub auto_kill_consecutive_losses: Option<u32>,
+    /// Kill the strategy once realized P&L falls this far (SOL) below its peak.
+    #[serde(default)]
+    pub auto_kill_max_drawdown_sol: Option<f64>,
 }
 
 fn default_base_currency() -> String {
@@ -139,6 +148,9 @@ impl Default for RiskParams {
             concurrent_positions: Some(1),
             momentum_adaptive_exits: true, // Enable momentum tracking by default
             let_winners_run: true,         // Let profitable positions run
+            exit_template: None,
+            auto_kill_consecutive_losses: None,
+            auto_kill_max_drawdown_sol: None,
         }
     }
 }
@@ -182,8 +194,13 @@ impl RiskParams {
             time_limit_minutes: self.time_limit_minutes,
             partial_take_profit: None,
             custom_exit_instructions: None,
+            exit_slippage_bps: None,
             momentum_adaptive,
             adaptive_partial_tp,
+            momentum_fade_partial: None,
+            move_stop_to_breakeven_after_tp: false,
+            time_limit_only_if_unprofitable: false,
+            exits_from_break_even: false,
         }
     }
 
@@ -209,6 +226,9 @@ impl RiskParams {
             concurrent_positions: Some(3),
             momentum_adaptive_exits: false,
             let_winners_run: false,
+            exit_template: None,
+            auto_kill_consecutive_losses: None,
+            auto_kill_max_drawdown_sol: None,
         }
     }
 
@@ -234,6 +254,9 @@ impl RiskParams {
             concurrent_positions: Some(2),
             momentum_adaptive_exits: false,
             let_winners_run: false,
+            exit_template: None,
+            auto_kill_consecutive_losses: None,
+            auto_kill_max_drawdown_sol: None,
         }
     }
 
@@ -259,6 +282,9 @@ impl RiskParams {
             concurrent_positions: Some(1),
             momentum_adaptive_exits: false,
             let_winners_run: false,
+            exit_template: None,
+            auto_kill_consecutive_losses: None,
+            auto_kill_max_drawdown_sol: None,
         }
     }
 
@@ -284,6 +310,9 @@ impl RiskParams {
             concurrent_positions: Some(2),
             momentum_adaptive_exits: false,
             let_winners_run: false,
+            exit_template: None,
+            auto_kill_consecutive_losses: None,
+            auto_kill_max_drawdown_sol: None,
         }
     }
 
@@ -309,6 +338,9 @@ impl RiskParams {
             concurrent_positions: Some(5),
             momentum_adaptive_exits: true, // Enable for aggressive strategy
             let_winners_run: true,
+            exit_template: None,
+            auto_kill_consecutive_losses: None,
+            auto_kill_max_drawdown_sol: None,
         }
     }
 
@@ -355,6 +387,10 @@ pub struct CreateStrategyRequest {
     pub venue_types: Vec<String>,
     pub execution_mode: String,
     pub risk_params: RiskParams,
+    /// Research discovery the strategy was built from. Its closed trades are then
+    /// credited to the source that surfaced the discovery.
+    #[serde(default)]
+    pub discovery_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
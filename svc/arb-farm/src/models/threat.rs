@@ -49,6 +49,11 @@ pub struct ThreatFactors {
     pub goplus_honeypot: Option<bool>,
     pub goplus_is_blacklisted: Option<bool>,
     pub community_warnings: u32,
+    /// 0-1 from name/symbol heuristics: copying or imitating a well-known token
+    #[serde(default)]
+    pub metadata_impersonation_score: f64,
+    #[serde(default)]
+    pub impersonates: Option<String>,
 }
 
 impl Default for ThreatFactors {
@@ -68,6 +73,8 @@ impl Default for ThreatFactors {
             goplus_honeypot: None,
             goplus_is_blacklisted: None,
             community_warnings: 0,
+            metadata_impersonation_score: 0.0,
+            impersonates: None,
         }
     }
 }
@@ -134,6 +141,7 @@ impl ThreatScore {
         }
 
         score += (factors.community_warnings.min(10) as f64 / 10.0) * 0.10;
+        score += factors.metadata_impersonation_score.clamp(0.0, 1.0) * 0.30;
 
         let overall_score = score.min(1.0);
 
@@ -191,6 +199,28 @@ pub struct BlockedEntity {
     pub reported_by: String,
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub provenance: BlocklistProvenance,
+}
+
+/// Where a blocklist entry came from. Imported entries keep the feed that supplied them
+/// and are never re-exported.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BlocklistProvenance {
+    #[default]
+    Local,
+    Imported {
+        feed_source: String,
+        feed_signer: String,
+        imported_at: DateTime<Utc>,
+    },
+}
+
+impl BlocklistProvenance {
+    pub fn is_local(&self) -> bool {
+        matches!(self, BlocklistProvenance::Local)
+    }
 }
 
 impl BlockedEntity {
@@ -212,6 +242,7 @@ impl BlockedEntity {
             reported_by,
             is_active: true,
             created_at: Utc::now(),
+            provenance: BlocklistProvenance::Local,
         }
     }
 }
@@ -323,6 +354,74 @@ impl ThreatAlert {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ThreatAuditAction {
+    Blocked,
+    Unblocked,
+    Whitelisted,
+    Unwhitelisted,
+    Imported,
+}
+
+impl ThreatAuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThreatAuditAction::Blocked => "blocked",
+            ThreatAuditAction::Unblocked => "unblocked",
+            ThreatAuditAction::Whitelisted => "whitelisted",
+            ThreatAuditAction::Unwhitelisted => "unwhitelisted",
+            ThreatAuditAction::Imported => "imported",
+        }
+    }
+}
+
+impl ThreatEntityType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThreatEntityType::Token => "token",
+            ThreatEntityType::Wallet => "wallet",
+            ThreatEntityType::Contract => "contract",
+        }
+    }
+}
+
+/// Record of a blocklist/whitelist change. `prior_score` is the threat score known for
+/// the address at the moment of the change, so bypasses of high-risk entities stand out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreatAuditEntry {
+    pub id: Uuid,
+    pub action: ThreatAuditAction,
+    pub entity_type: Option<ThreatEntityType>,
+    pub address: String,
+    pub actor: String,
+    pub reason: Option<String>,
+    pub prior_score: Option<f64>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ThreatAuditEntry {
+    pub fn new(
+        action: ThreatAuditAction,
+        entity_type: Option<ThreatEntityType>,
+        address: String,
+        actor: String,
+        reason: Option<String>,
+        prior_score: Option<f64>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            action,
+            entity_type,
+            address,
+            actor,
+            reason,
+            prior_score,
+            created_at: Utc::now(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CheckTokenRequest {
     pub token_mint: String,
@@ -1,9 +1,12 @@
 use chrono::{DateTime, Duration, Timelike, Utc};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 use uuid::Uuid;
 
 use super::strategy_extract::{ConditionType, ExtractedStrategy};
-use crate::error::AppResult;
+use crate::error::{AppError, AppResult};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BacktestConfig {
@@ -14,6 +17,8 @@ pub struct BacktestConfig {
     pub fee_bps: u16,
     pub include_gas_costs: bool,
     pub gas_cost_per_trade_sol: f64,
+    #[serde(default)]
+    pub monte_carlo: Option<MonteCarloConfig>,
 }
 
 impl Default for BacktestConfig {
@@ -26,6 +31,34 @@ impl Default for BacktestConfig {
             fee_bps: 30,
             include_gas_costs: true,
             gas_cost_per_trade_sol: 0.001,
+            monte_carlo: None,
+        }
+    }
+}
+
+const DEFAULT_MONTE_CARLO_RUNS: u32 = 1000;
+/// Upper bound on requested runs; each run replays the full trade list.
+pub const MAX_MONTE_CARLO_RUNS: u32 = 10_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonteCarloConfig {
+    /// Clamped to `MAX_MONTE_CARLO_RUNS`.
+    #[serde(default = "default_monte_carlo_runs")]
+    pub runs: u32,
+    /// Fixes the resampling so a distribution can be reproduced.
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+fn default_monte_carlo_runs() -> u32 {
+    DEFAULT_MONTE_CARLO_RUNS
+}
+
+impl Default for MonteCarloConfig {
+    fn default() -> Self {
+        Self {
+            runs: DEFAULT_MONTE_CARLO_RUNS,
+            seed: None,
         }
     }
 }
@@ -39,6 +72,8 @@ pub struct BacktestResult {
     pub trades: Vec<SimulatedTrade>,
     pub equity_curve: Vec<EquityPoint>,
     pub metrics: BacktestMetrics,
+    #[serde(default)]
+    pub monte_carlo: Option<MonteCarloDistribution>,
     pub started_at: DateTime<Utc>,
     pub completed_at: DateTime<Utc>,
 }
@@ -56,6 +91,23 @@ pub struct BacktestSummary {
     pub profit_factor: f64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PercentileBand {
+    pub p5: f64,
+    pub median: f64,
+    pub p95: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonteCarloDistribution {
+    pub runs: u32,
+    pub trades_per_run: u32,
+    pub return_percent: PercentileBand,
+    pub max_drawdown_percent: PercentileBand,
+    /// Share of runs (0-1) that finished below the starting capital.
+    pub probability_of_loss: f64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SimulatedTrade {
     pub id: Uuid,
@@ -123,18 +175,53 @@ impl BacktestEngine {
         strategy: &ExtractedStrategy,
         config: BacktestConfig,
     ) -> AppResult<BacktestResult> {
+        self.run_with_progress(strategy, config, |_, _, _| async {})
+            .await
+    }
+
+    /// Same as `run`, calling `on_progress(completed, total, stage)` as each stage starts
+    /// and once more with `completed == total` when the result is ready.
+    pub async fn run_with_progress<F, Fut>(
+        &self,
+        strategy: &ExtractedStrategy,
+        config: BacktestConfig,
+        mut on_progress: F,
+    ) -> AppResult<BacktestResult>
+    where
+        F: FnMut(u32, u32, &'static str) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        let total = if config.monte_carlo.is_some() { 4 } else { 3 };
         let started_at = Utc::now();
 
+        on_progress(0, total, "Loading price data").await;
         let data = if self.historical_data.is_empty() {
             self.generate_simulated_data(&config)
         } else {
             self.historical_data.clone()
         };
 
+        on_progress(1, total, "Simulating trades").await;
         let (trades, equity_curve) = self.simulate_strategy(strategy, &data, &config);
 
+        on_progress(2, total, "Calculating metrics").await;
         let summary = self.calculate_summary(&trades, config.initial_capital_sol);
         let metrics = self.calculate_metrics(&trades, &equity_curve, config.period_days);
+        let monte_carlo = match config.monte_carlo.clone() {
+            // Thousands of replays would stall the async workers
+            Some(mc) => {
+                on_progress(3, total, "Running Monte Carlo").await;
+                let resample = trades.clone();
+                let initial_capital = config.initial_capital_sol;
+                tokio::task::spawn_blocking(move || {
+                    run_monte_carlo(&resample, initial_capital, &mc)
+                })
+                .await
+                .map_err(|e| AppError::Internal(format!("Monte Carlo run failed: {}", e)))?
+            }
+            None => None,
+        };
+        on_progress(total, total, "Backtest complete").await;
 
         Ok(BacktestResult {
             id: Uuid::new_v4(),
@@ -144,6 +231,7 @@ impl BacktestEngine {
             trades,
             equity_curve,
             metrics,
+            monte_carlo,
             started_at,
             completed_at: Utc::now(),
         })
@@ -659,6 +747,70 @@ impl Default for BacktestEngine {
     }
 }
 
+/// Bootstraps the trade sequence: each run draws as many trades as the backtest made,
+/// with replacement and in random order, and replays them from the starting capital.
+/// Returns None when there are no trades to resample. CPU-bound; call it off the
+/// async workers.
+pub fn run_monte_carlo(
+    trades: &[SimulatedTrade],
+    initial_capital: f64,
+    config: &MonteCarloConfig,
+) -> Option<MonteCarloDistribution> {
+    let runs = config.runs.min(MAX_MONTE_CARLO_RUNS);
+    if trades.is_empty() || runs == 0 || initial_capital <= 0.0 {
+        return None;
+    }
+
+    let mut rng = SmallRng::seed_from_u64(config.seed.unwrap_or_else(rand::random));
+    let mut returns = Vec::with_capacity(runs as usize);
+    let mut drawdowns = Vec::with_capacity(runs as usize);
+
+    for _ in 0..runs {
+        let mut equity = initial_capital;
+        let mut peak = initial_capital;
+        let mut max_drawdown: f64 = 0.0;
+
+        for _ in 0..trades.len() {
+            equity += trades[rng.random_range(0..trades.len())].profit_sol;
+            peak = peak.max(equity);
+            if peak > 0.0 {
+                max_drawdown = max_drawdown.max((peak - equity) / peak * 100.0);
+            }
+        }
+
+        returns.push((equity - initial_capital) / initial_capital * 100.0);
+        drawdowns.push(max_drawdown);
+    }
+
+    let probability_of_loss =
+        returns.iter().filter(|r| **r < 0.0).count() as f64 / returns.len() as f64;
+
+    Some(MonteCarloDistribution {
+        runs,
+        trades_per_run: trades.len() as u32,
+        return_percent: percentile_band(returns),
+        max_drawdown_percent: percentile_band(drawdowns),
+        probability_of_loss,
+    })
+}
+
+fn percentile_band(mut values: Vec<f64>) -> PercentileBand {
+    values.sort_by(|a, b| a.total_cmp(b));
+    PercentileBand {
+        p5: percentile(&values, 5.0),
+        median: percentile(&values, 50.0),
+        p95: percentile(&values, 95.0),
+    }
+}
+
+/// Linear interpolation between closest ranks; `sorted` must be non-empty.
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    let rank = pct / 100.0 * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
 fn rand_simple() -> f64 {
     use std::time::{SystemTime, UNIX_EPOCH};
     let nanos = SystemTime::now()
@@ -686,4 +838,139 @@ mod tests {
         let sharpe = engine.calculate_sharpe_ratio(&returns);
         assert!(sharpe > 0.0);
     }
+
+    #[tokio::test]
+    async fn test_run_with_progress_reports_each_stage_then_completion() {
+        use crate::research::strategy_extract::{RiskParams, StrategyConfidence, StrategyType};
+
+        let strategy = ExtractedStrategy {
+            id: Uuid::new_v4(),
+            source_id: Uuid::new_v4(),
+            source_url: "https://example.com/thread".to_string(),
+            name: "Curve scalp".to_string(),
+            description: "Buy early curve, sell at 2x".to_string(),
+            strategy_type: StrategyType::BondingCurve,
+            entry_conditions: Vec::new(),
+            exit_conditions: Vec::new(),
+            risk_params: RiskParams::default(),
+            tokens_mentioned: Vec::new(),
+            confidence: StrategyConfidence::Medium,
+            confidence_score: 0.6,
+            raw_extraction: String::new(),
+            extracted_at: Utc::now(),
+        };
+        let config = BacktestConfig {
+            period_days: 2,
+            monte_carlo: Some(seeded(10)),
+            ..BacktestConfig::default()
+        };
+
+        let mut reported = Vec::new();
+        let result = BacktestEngine::new()
+            .run_with_progress(&strategy, config, |done, total, stage| {
+                reported.push((done, total, stage));
+                async {}
+            })
+            .await
+            .unwrap();
+
+        let steps: Vec<u32> = reported.iter().map(|(done, _, _)| *done).collect();
+        assert_eq!(steps, vec![0, 1, 2, 3, 4]);
+        assert!(reported.iter().all(|(_, total, _)| *total == 4));
+        assert_eq!(reported.last().unwrap().2, "Backtest complete");
+        assert!(result.monte_carlo.is_some());
+    }
+
+    fn trades_with_profits(profits: &[f64]) -> Vec<SimulatedTrade> {
+        let now = Utc::now();
+        profits
+            .iter()
+            .map(|profit| SimulatedTrade {
+                id: Uuid::new_v4(),
+                entry_time: now,
+                exit_time: now,
+                entry_price: 1.0,
+                exit_price: 1.0,
+                position_size_sol: 1.0,
+                profit_sol: *profit,
+                profit_percent: profit * 100.0,
+                fees_paid_sol: 0.0,
+                entry_reason: "test".to_string(),
+                exit_reason: "test".to_string(),
+            })
+            .collect()
+    }
+
+    fn seeded(runs: u32) -> MonteCarloConfig {
+        MonteCarloConfig {
+            runs,
+            seed: Some(42),
+        }
+    }
+
+    #[test]
+    fn test_monte_carlo_distribution_is_sensible() {
+        // 30 winners at +0.1 SOL, 20 losers at -0.05 SOL: +2 SOL (+20%) on 10 SOL
+        let profits: Vec<f64> = (0..50)
+            .map(|i| if i % 5 < 3 { 0.1 } else { -0.05 })
+            .collect();
+        let trades = trades_with_profits(&profits);
+
+        let dist = run_monte_carlo(&trades, 10.0, &seeded(2000)).unwrap();
+
+        assert_eq!(dist.runs, 2000);
+        assert_eq!(dist.trades_per_run, 50);
+        for band in [&dist.return_percent, &dist.max_drawdown_percent] {
+            assert!(band.p5 <= band.median && band.median <= band.p95);
+        }
+        assert!(dist.return_percent.p5 < dist.return_percent.p95);
+        assert!((dist.return_percent.median - 20.0).abs() < 1.0);
+        assert!(dist.max_drawdown_percent.p5 >= 0.0);
+        assert!(dist.max_drawdown_percent.p95 > 0.0);
+        assert!(dist.probability_of_loss >= 0.0 && dist.probability_of_loss < 0.5);
+
+        // Same seed, same distribution
+        let again = run_monte_carlo(&trades, 10.0, &seeded(2000)).unwrap();
+        assert_eq!(again.return_percent.median, dist.return_percent.median);
+        assert_eq!(
+            again.max_drawdown_percent.p95,
+            dist.max_drawdown_percent.p95
+        );
+
+        assert!(run_monte_carlo(&[], 10.0, &seeded(2000)).is_none());
+    }
+
+    #[test]
+    fn test_high_variance_strategy_has_wider_percentiles() {
+        // Same +0.01 SOL average per trade, very different spread
+        let steady: Vec<f64> = (0..40)
+            .map(|i| if i % 2 == 0 { 0.03 } else { -0.01 })
+            .collect();
+        let volatile: Vec<f64> = (0..40)
+            .map(|i| if i % 2 == 0 { 0.51 } else { -0.49 })
+            .collect();
+
+        let steady = run_monte_carlo(&trades_with_profits(&steady), 10.0, &seeded(2000)).unwrap();
+        let volatile =
+            run_monte_carlo(&trades_with_profits(&volatile), 10.0, &seeded(2000)).unwrap();
+
+        let width = |band: &PercentileBand| band.p95 - band.p5;
+        assert!(width(&volatile.return_percent) > width(&steady.return_percent) * 5.0);
+        assert!(volatile.max_drawdown_percent.p95 > steady.max_drawdown_percent.p95);
+        assert!(volatile.probability_of_loss > steady.probability_of_loss);
+    }
+
+    #[test]
+    fn test_monte_carlo_runs_clamped() {
+        let trades = trades_with_profits(&[0.1, -0.05]);
+        let dist = run_monte_carlo(&trades, 10.0, &seeded(u32::MAX)).unwrap();
+        assert_eq!(dist.runs, MAX_MONTE_CARLO_RUNS);
+    }
+
+    #[test]
+    fn test_monte_carlo_config_fields_default() {
+        let config: MonteCarloConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config.runs, DEFAULT_MONTE_CARLO_RUNS);
+        assert!(config.seed.is_none());
+    }
 }
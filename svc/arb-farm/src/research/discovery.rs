@@ -0,0 +1,298 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use super::backtest::{BacktestConfig, BacktestEngine, BacktestResult, MonteCarloConfig};
+use super::strategy_extract::ExtractedStrategy;
+use crate::error::{AppError, AppResult};
+
+/// Holds with a time limit at or under this are tested over a shorter window.
+const SHORT_HOLD_MINUTES: u32 = 60;
+const SHORT_HOLD_PERIOD_DAYS: u32 = 7;
+/// Starting capital in multiples of the strategy's max position size.
+const CAPITAL_POSITIONS: f64 = 10.0;
+
+pub fn get_discovery_auto_backtest() -> bool {
+    std::env::var("ARB_DISCOVERY_AUTO_BACKTEST")
+        .map(|v| v == "1" || v.to_lowercase() == "true")
+        .unwrap_or(false)
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscoveryStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl DiscoveryStatus {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "pending" => Some(Self::Pending),
+            "approved" => Some(Self::Approved),
+            "rejected" => Some(Self::Rejected),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Discovery {
+    pub strategy: ExtractedStrategy,
+    pub status: DiscoveryStatus,
+    pub notes: Option<String>,
+    pub rejection_reason: Option<String>,
+    /// Backtest run on approval, for review before a strategy is created from it.
+    pub backtest: Option<BacktestResult>,
+    /// Monitored source credited at review; strategies built from the discovery
+    /// report their P&L to it.
+    #[serde(default)]
+    pub source_id: Option<Uuid>,
+    pub discovered_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl Discovery {
+    pub fn id(&self) -> Uuid {
+        self.strategy.id
+    }
+}
+
+/// Backtest defaults for an extracted strategy: its own position size and slippage,
+/// capital for ten positions, and a one-week window for short-hold strategies.
+pub fn backtest_config_for(strategy: &ExtractedStrategy) -> BacktestConfig {
+    let defaults = BacktestConfig::default();
+    let risk = &strategy.risk_params;
+
+    let max_position_size_sol = risk
+        .max_position_sol
+        .filter(|sol| *sol > 0.0)
+        .unwrap_or(defaults.max_position_size_sol);
+    let period_days = match risk.time_limit_minutes {
+        Some(minutes) if minutes <= SHORT_HOLD_MINUTES => SHORT_HOLD_PERIOD_DAYS,
+        _ => defaults.period_days,
+    };
+
+    BacktestConfig {
+        period_days,
+        initial_capital_sol: max_position_size_sol * CAPITAL_POSITIONS,
+        max_position_size_sol,
+        slippage_bps: risk.max_slippage_bps.unwrap_or(defaults.slippage_bps),
+        monte_carlo: Some(MonteCarloConfig::default()),
+        ..defaults
+    }
+}
+
+/// Extracted strategies awaiting review. Approval can run a backtest and attach it.
+pub struct DiscoveryStore {
+    discoveries: Arc<RwLock<HashMap<Uuid, Discovery>>>,
+    auto_backtest: bool,
+}
+
+impl DiscoveryStore {
+    pub fn new() -> Self {
+        Self {
+            discoveries: Arc::new(RwLock::new(HashMap::new())),
+            auto_backtest: false,
+        }
+    }
+
+    /// Default for approvals that don't say whether to backtest.
+    pub fn with_auto_backtest(mut self, enabled: bool) -> Self {
+        self.auto_backtest = enabled;
+        self
+    }
+
+    pub fn auto_backtest(&self) -> bool {
+        self.auto_backtest
+    }
+
+    pub async fn add(&self, strategy: ExtractedStrategy) -> Discovery {
+        let discovery = Discovery {
+            strategy,
+            status: DiscoveryStatus::Pending,
+            notes: None,
+            rejection_reason: None,
+            backtest: None,
+            source_id: None,
+            discovered_at: Utc::now(),
+            reviewed_at: None,
+        };
+        self.discoveries
+            .write()
+            .await
+            .insert(discovery.id(), discovery.clone());
+        discovery
+    }
+
+    pub async fn get(&self, id: Uuid) -> Option<Discovery> {
+        self.discoveries.read().await.get(&id).cloned()
+    }
+
+    /// Newest first. Returns the page and the total matching `status`.
+    pub async fn list(
+        &self,
+        status: Option<DiscoveryStatus>,
+        limit: usize,
+        offset: usize,
+    ) -> (Vec<Discovery>, usize) {
+        let discoveries = self.discoveries.read().await;
+        let mut matching: Vec<Discovery> = discoveries
+            .values()
+            .filter(|d| status.is_none_or(|s| d.status == s))
+            .cloned()
+            .collect();
+        matching.sort_by(|a, b| b.discovered_at.cmp(&a.discovered_at));
+        let total = matching.len();
+        let page = matching.into_iter().skip(offset).take(limit).collect();
+        (page, total)
+    }
+
+    /// Approves a discovery, backtesting it first when `auto_backtest` (or the store
+    /// default) asks for it. The backtest runs before the status changes, so a failed
+    /// run leaves the discovery pending.
+    pub async fn approve(
+        &self,
+        id: Uuid,
+        notes: Option<String>,
+        auto_backtest: Option<bool>,
+        engine: &BacktestEngine,
+    ) -> AppResult<Discovery> {
+        let strategy = self
+            .get(id)
+            .await
+            .ok_or_else(|| AppError::NotFound(format!("Discovery {} not found", id)))?
+            .strategy;
+
+        let backtest = if auto_backtest.unwrap_or(self.auto_backtest) {
+            Some(
+                engine
+                    .run(&strategy, backtest_config_for(&strategy))
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        let mut discoveries = self.discoveries.write().await;
+        let discovery = discoveries
+            .get_mut(&id)
+            .ok_or_else(|| AppError::NotFound(format!("Discovery {} not found", id)))?;
+        discovery.status = DiscoveryStatus::Approved;
+        discovery.notes = notes;
+        discovery.reviewed_at = Some(Utc::now());
+        if backtest.is_some() {
+            discovery.backtest = backtest;
+        }
+        Ok(discovery.clone())
+    }
+
+    /// Records which monitored source surfaced a discovery.
+    pub async fn credit_source(&self, id: Uuid, source_id: Uuid) -> Option<Discovery> {
+        let mut discoveries = self.discoveries.write().await;
+        let discovery = discoveries.get_mut(&id)?;
+        discovery.source_id = Some(source_id);
+        Some(discovery.clone())
+    }
+
+    pub async fn reject(&self, id: Uuid, reason: String) -> AppResult<Discovery> {
+        let mut discoveries = self.discoveries.write().await;
+        let discovery = discoveries
+            .get_mut(&id)
+            .ok_or_else(|| AppError::NotFound(format!("Discovery {} not found", id)))?;
+        discovery.status = DiscoveryStatus::Rejected;
+        discovery.rejection_reason = Some(reason);
+        discovery.reviewed_at = Some(Utc::now());
+        Ok(discovery.clone())
+    }
+}
+
+impl Default for DiscoveryStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::research::strategy_extract::{RiskParams, StrategyConfidence, StrategyType};
+
+    fn extracted(risk_params: RiskParams) -> ExtractedStrategy {
+        ExtractedStrategy {
+            id: Uuid::new_v4(),
+            source_id: Uuid::new_v4(),
+            source_url: "https://example.com/thread".to_string(),
+            name: "Curve scalp".to_string(),
+            description: "Buy early curve, sell at 2x".to_string(),
+            strategy_type: StrategyType::BondingCurve,
+            entry_conditions: Vec::new(),
+            exit_conditions: Vec::new(),
+            risk_params,
+            tokens_mentioned: Vec::new(),
+            confidence: StrategyConfidence::Medium,
+            confidence_score: 0.6,
+            raw_extraction: String::new(),
+            extracted_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_approve_with_auto_backtest_links_result() {
+        let store = DiscoveryStore::new();
+        let discovery = store
+            .add(extracted(RiskParams {
+                max_position_sol: Some(0.5),
+                max_slippage_bps: Some(300),
+                time_limit_minutes: Some(15),
+                ..RiskParams::default()
+            }))
+            .await;
+
+        let approved = store
+            .approve(
+                discovery.id(),
+                Some("looks promising".to_string()),
+                Some(true),
+                &BacktestEngine::new(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(approved.status, DiscoveryStatus::Approved);
+        let backtest = approved.backtest.expect("backtest attached");
+        assert_eq!(backtest.strategy_id, discovery.id());
+        assert_eq!(backtest.config.max_position_size_sol, 0.5);
+        assert_eq!(backtest.config.initial_capital_sol, 5.0);
+        assert_eq!(backtest.config.slippage_bps, 300);
+        assert_eq!(backtest.config.period_days, SHORT_HOLD_PERIOD_DAYS);
+
+        let stored = store.get(discovery.id()).await.unwrap();
+        assert_eq!(stored.backtest.map(|b| b.id), Some(backtest.id));
+    }
+
+    #[tokio::test]
+    async fn test_approve_without_auto_backtest_runs_nothing() {
+        // The store default is on, but the approval opts out
+        let store = DiscoveryStore::new().with_auto_backtest(true);
+        let discovery = store.add(extracted(RiskParams::default())).await;
+
+        let approved = store
+            .approve(discovery.id(), None, Some(false), &BacktestEngine::new())
+            .await
+            .unwrap();
+
+        assert_eq!(approved.status, DiscoveryStatus::Approved);
+        assert!(approved.backtest.is_none());
+        assert!(store.get(discovery.id()).await.unwrap().backtest.is_none());
+
+        let missing = store
+            .approve(Uuid::new_v4(), None, None, &BacktestEngine::new())
+            .await;
+        assert!(matches!(missing, Err(AppError::NotFound(_))));
+    }
+}
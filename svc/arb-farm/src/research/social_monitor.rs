@@ -2,10 +2,13 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
 use uuid::Uuid;
 
+use crate::agents::strategy_engine::ClosedTradeTally;
+use crate::database::repositories::{SourceReliabilityRepository, StrategySourceRecord};
 use crate::error::AppResult;
+use crate::events::{position as position_topics, ArbEvent};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum SourceType {
@@ -25,9 +28,18 @@ impl SourceType {
             _ => SourceType::Twitter,
         }
     }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SourceType::Twitter => "twitter",
+            SourceType::Telegram => "telegram",
+            SourceType::Discord => "discord",
+            SourceType::Rss => "rss",
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum TrackType {
     Alpha,
     Threat,
@@ -58,9 +70,100 @@ pub struct MonitoredSource {
     pub last_post_id: Option<String>,
     pub total_posts_tracked: u64,
     pub alerts_generated: u64,
+    #[serde(default)]
+    pub reliability: SourceReliability,
     pub created_at: DateTime<Utc>,
 }
 
+/// Downstream result of something a source surfaced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum SourceOutcome {
+    Approved,
+    Rejected,
+    StrategyPnl { pnl_sol: f64 },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceReliability {
+    pub approved: u32,
+    pub rejected: u32,
+    pub profitable_strategies: u32,
+    pub losing_strategies: u32,
+    pub strategy_pnl_sol: f64,
+    /// 0.0 (junk) to 1.0 (consistently useful); 0.5 with no outcomes.
+    pub score: f64,
+    pub auto_muted: bool,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl Default for SourceReliability {
+    fn default() -> Self {
+        Self {
+            approved: 0,
+            rejected: 0,
+            profitable_strategies: 0,
+            losing_strategies: 0,
+            strategy_pnl_sol: 0.0,
+            score: 0.5,
+            auto_muted: false,
+            updated_at: None,
+        }
+    }
+}
+
+impl SourceReliability {
+    pub fn record(&mut self, outcome: &SourceOutcome) {
+        match outcome {
+            SourceOutcome::Approved => self.approved += 1,
+            SourceOutcome::Rejected => self.rejected += 1,
+            SourceOutcome::StrategyPnl { pnl_sol } => {
+                if *pnl_sol > 0.0 {
+                    self.profitable_strategies += 1;
+                } else {
+                    self.losing_strategies += 1;
+                }
+                self.strategy_pnl_sol += pnl_sol;
+            }
+        }
+
+        // Laplace-smoothed rates so a single outcome doesn't swing the score to 0 or 1
+        let approval_rate = (self.approved as f64 + 1.0) / (self.reviewed() as f64 + 2.0);
+        let strategies = self.profitable_strategies + self.losing_strategies;
+        self.score = if strategies == 0 {
+            approval_rate
+        } else {
+            let win_rate = (self.profitable_strategies as f64 + 1.0) / (strategies as f64 + 2.0);
+            (approval_rate + win_rate) / 2.0
+        };
+        self.updated_at = Some(Utc::now());
+    }
+
+    pub fn reviewed(&self) -> u32 {
+        self.approved + self.rejected
+    }
+
+    pub fn total_outcomes(&self) -> u32 {
+        self.reviewed() + self.profitable_strategies + self.losing_strategies
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReliabilityConfig {
+    /// Sources scoring below this are muted once they have `min_outcomes`.
+    pub mute_threshold: f64,
+    pub min_outcomes: u32,
+}
+
+impl Default for ReliabilityConfig {
+    fn default() -> Self {
+        Self {
+            mute_threshold: 0.25,
+            min_outcomes: 5,
+        }
+    }
+}
+
 impl MonitoredSource {
     pub fn new(source_type: SourceType, handle: String, track_type: TrackType) -> Self {
         Self {
@@ -75,6 +178,7 @@ impl MonitoredSource {
             last_post_id: None,
             total_posts_tracked: 0,
             alerts_generated: 0,
+            reliability: SourceReliability::default(),
             created_at: Utc::now(),
         }
     }
@@ -88,6 +192,15 @@ impl MonitoredSource {
         self.display_name = Some(name);
         self
     }
+
+    /// Handles are case-insensitive, so `@ZachXBT` and `zachxbt` share one score.
+    fn persisted_handle(&self) -> String {
+        self.handle.trim_start_matches('@').to_lowercase()
+    }
+
+    fn matches(&self, source_type: &str, handle: &str) -> bool {
+        self.source_type.as_str() == source_type && self.persisted_handle() == handle
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -130,6 +243,10 @@ pub struct SocialMonitor {
     alerts: Arc<RwLock<Vec<SocialAlert>>>,
     keywords_alpha: Vec<String>,
     keywords_threat: Vec<String>,
+    reliability_config: ReliabilityConfig,
+    /// Strategy id -> the source whose discovery it was built from.
+    strategy_sources: Arc<RwLock<HashMap<Uuid, StrategySourceRecord>>>,
+    repository: Option<Arc<SourceReliabilityRepository>>,
 }
 
 impl SocialMonitor {
@@ -161,15 +278,83 @@ impl SocialMonitor {
                 "drained".to_string(),
                 "stolen".to_string(),
             ],
+            reliability_config: ReliabilityConfig::default(),
+            strategy_sources: Arc::new(RwLock::new(HashMap::new())),
+            repository: None,
         }
     }
 
-    pub async fn add_source(&self, source: MonitoredSource) -> Uuid {
+    pub fn with_reliability_config(mut self, config: ReliabilityConfig) -> Self {
+        self.reliability_config = config;
+        self
+    }
+
+    /// Persists reliability scores and strategy attributions, and restores a source's
+    /// score when it is added again after a restart.
+    pub fn with_repository(mut self, repository: Arc<SourceReliabilityRepository>) -> Self {
+        self.repository = Some(repository);
+        self
+    }
+
+    pub async fn add_source(&self, mut source: MonitoredSource) -> Uuid {
+        if let Some(repo) = &self.repository {
+            match repo
+                .get(source.source_type.as_str(), &source.persisted_handle())
+                .await
+            {
+                Ok(Some(reliability)) => {
+                    if reliability.auto_muted {
+                        source.is_active = false;
+                    }
+                    source.reliability = reliability;
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!(
+                    "Failed to load reliability for source @{}: {}",
+                    source.handle,
+                    e
+                ),
+            }
+        }
+
         let id = source.id;
         self.sources.write().await.insert(id, source);
         id
     }
 
+    /// Loads persisted strategy attributions. Call once at startup.
+    pub async fn load_strategy_sources(&self) -> AppResult<usize> {
+        let Some(repo) = &self.repository else {
+            return Ok(0);
+        };
+        let records = repo.list_strategy_sources().await?;
+        let count = records.len();
+        let mut strategy_sources = self.strategy_sources.write().await;
+        for record in records {
+            strategy_sources.insert(record.strategy_id, record);
+        }
+        Ok(count)
+    }
+
+    async fn persist_reliability(&self, source: &MonitoredSource) {
+        if let Some(repo) = &self.repository {
+            if let Err(e) = repo
+                .upsert(
+                    source.source_type.as_str(),
+                    &source.persisted_handle(),
+                    &source.reliability,
+                )
+                .await
+            {
+                tracing::warn!(
+                    "Failed to persist reliability for source @{}: {}",
+                    source.handle,
+                    e
+                );
+            }
+        }
+    }
+
     pub async fn add_twitter_account(&self, handle: &str, track_type: TrackType) -> Uuid {
         let handle = handle.trim_start_matches('@').to_string();
         let source = MonitoredSource::new(SourceType::Twitter, handle, track_type);
@@ -184,8 +369,17 @@ impl SocialMonitor {
         self.sources.read().await.get(&source_id).cloned()
     }
 
+    /// Most reliable sources first.
     pub async fn list_sources(&self) -> Vec<MonitoredSource> {
-        self.sources.read().await.values().cloned().collect()
+        let mut sources: Vec<_> = self.sources.read().await.values().cloned().collect();
+        sources.sort_by(|a, b| {
+            b.reliability
+                .score
+                .partial_cmp(&a.reliability.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.created_at.cmp(&b.created_at))
+        });
+        sources
     }
 
     pub async fn list_sources_by_type(&self, source_type: SourceType) -> Vec<MonitoredSource> {
@@ -199,12 +393,164 @@ impl SocialMonitor {
     }
 
     pub async fn toggle_source(&self, source_id: Uuid, active: bool) -> AppResult<()> {
-        if let Some(source) = self.sources.write().await.get_mut(&source_id) {
-            source.is_active = active;
+        let unmuted = {
+            let mut sources = self.sources.write().await;
+            match sources.get_mut(&source_id) {
+                Some(source) => {
+                    source.is_active = active;
+                    let was_muted = source.reliability.auto_muted;
+                    if active {
+                        source.reliability.auto_muted = false;
+                    }
+                    (active && was_muted).then(|| source.clone())
+                }
+                None => None,
+            }
+        };
+        if let Some(source) = unmuted {
+            self.persist_reliability(&source).await;
         }
         Ok(())
     }
 
+    /// Records a downstream outcome and mutes the source if it has enough history
+    /// and its score falls below `mute_threshold`. Returns the updated source.
+    pub async fn record_source_outcome(
+        &self,
+        source_id: Uuid,
+        outcome: SourceOutcome,
+    ) -> Option<MonitoredSource> {
+        let updated = {
+            let mut sources = self.sources.write().await;
+            let source = sources.get_mut(&source_id)?;
+            source.reliability.record(&outcome);
+
+            let reliability = &source.reliability;
+            if source.is_active
+                && reliability.total_outcomes() >= self.reliability_config.min_outcomes
+                && reliability.score < self.reliability_config.mute_threshold
+            {
+                source.is_active = false;
+                source.reliability.auto_muted = true;
+                tracing::warn!(
+                    "🔇 Auto-muted source @{} (reliability {:.2} after {} outcomes)",
+                    source.handle,
+                    source.reliability.score,
+                    source.reliability.total_outcomes()
+                );
+            }
+
+            source.clone()
+        };
+
+        self.persist_reliability(&updated).await;
+        Some(updated)
+    }
+
+    /// Credits `strategy_id`'s closed trades to the source that surfaced `discovery_id`.
+    pub async fn attribute_strategy(
+        &self,
+        strategy_id: Uuid,
+        source_id: Uuid,
+        discovery_id: Option<Uuid>,
+    ) -> Option<MonitoredSource> {
+        let source = self.get_source(source_id).await?;
+        let record = StrategySourceRecord {
+            strategy_id,
+            source_type: source.source_type.as_str().to_string(),
+            handle: source.persisted_handle(),
+            discovery_id,
+        };
+
+        if let Some(repo) = &self.repository {
+            if let Err(e) = repo.attribute_strategy(&record).await {
+                tracing::warn!(
+                    "Failed to persist source attribution for strategy {}: {}",
+                    strategy_id,
+                    e
+                );
+            }
+        }
+        self.strategy_sources
+            .write()
+            .await
+            .insert(strategy_id, record);
+        Some(source)
+    }
+
+    /// Records a closed trade of an attributed strategy as a `strategy_pnl` outcome for
+    /// its source. Trades of other strategies are ignored.
+    pub async fn record_strategy_trade(
+        &self,
+        strategy_id: Uuid,
+        pnl_sol: f64,
+    ) -> Option<MonitoredSource> {
+        let record = self
+            .strategy_sources
+            .read()
+            .await
+            .get(&strategy_id)
+            .cloned()?;
+        let source_id = self
+            .sources
+            .read()
+            .await
+            .values()
+            .find(|s| s.matches(&record.source_type, &record.handle))
+            .map(|s| s.id)?;
+
+        self.record_source_outcome(source_id, SourceOutcome::StrategyPnl { pnl_sol })
+            .await
+    }
+
+    /// Feeds `position.closed` events of attributed strategies into source reliability,
+    /// one outcome per closed position.
+    pub fn start_attribution_loop(self: &Arc<Self>, event_tx: &broadcast::Sender<ArbEvent>) {
+        let mut event_rx = event_tx.subscribe();
+        let monitor = Arc::clone(self);
+
+        tokio::spawn(async move {
+            let mut closed_trades = ClosedTradeTally::default();
+            loop {
+                let event = match event_rx.recv().await {
+                    Ok(event) => event,
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!(
+                            "Source attribution loop lagged, skipped {} events",
+                            skipped
+                        );
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+
+                if event.topic != position_topics::CLOSED {
+                    continue;
+                }
+                let Some(strategy_id) = event
+                    .payload
+                    .get("strategy_id")
+                    .and_then(|v| v.as_str())
+                    .and_then(|s| Uuid::parse_str(s).ok())
+                else {
+                    continue;
+                };
+                if !monitor
+                    .strategy_sources
+                    .read()
+                    .await
+                    .contains_key(&strategy_id)
+                {
+                    continue;
+                }
+
+                if let Some(pnl_sol) = closed_trades.record(&event.payload) {
+                    monitor.record_strategy_trade(strategy_id, pnl_sol).await;
+                }
+            }
+        });
+    }
+
     pub fn analyze_content(&self, content: &str, source: &MonitoredSource) -> Option<SocialAlert> {
         let content_lower = content.to_lowercase();
 
@@ -496,4 +842,201 @@ mod tests {
         assert!(tokens.contains(&"BONK".to_string()));
         assert!(tokens.contains(&"SOL".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_reliability_diverges_and_bad_source_is_auto_muted() {
+        let monitor = SocialMonitor::new();
+        let good = monitor.add_twitter_account("@good", TrackType::Alpha).await;
+        let bad = monitor.add_twitter_account("@junk", TrackType::Alpha).await;
+
+        for _ in 0..3 {
+            monitor
+                .record_source_outcome(good, SourceOutcome::Approved)
+                .await;
+            monitor
+                .record_source_outcome(bad, SourceOutcome::Rejected)
+                .await;
+        }
+        monitor
+            .record_source_outcome(good, SourceOutcome::StrategyPnl { pnl_sol: 0.8 })
+            .await;
+        monitor
+            .record_source_outcome(bad, SourceOutcome::StrategyPnl { pnl_sol: -0.4 })
+            .await;
+
+        // Four outcomes is below `min_outcomes`, so the bad source is only down-ranked
+        let good_source = monitor.get_source(good).await.unwrap();
+        let bad_source = monitor.get_source(bad).await.unwrap();
+        assert!(good_source.reliability.score > 0.7);
+        assert!(bad_source.reliability.score < 0.3);
+        assert!(bad_source.is_active);
+        assert_eq!(monitor.list_sources().await[0].id, good);
+
+        let bad_source = monitor
+            .record_source_outcome(bad, SourceOutcome::StrategyPnl { pnl_sol: -0.2 })
+            .await
+            .unwrap();
+        assert!(bad_source.reliability.score < 0.25);
+        assert!(!bad_source.is_active);
+        assert!(bad_source.reliability.auto_muted);
+        assert!((bad_source.reliability.strategy_pnl_sol + 0.6).abs() < 1e-9);
+
+        let good_source = monitor
+            .record_source_outcome(good, SourceOutcome::Approved)
+            .await
+            .unwrap();
+        assert!(good_source.is_active);
+        assert!(!good_source.reliability.auto_muted);
+    }
+
+    #[tokio::test]
+    async fn test_closed_trades_of_attributed_strategy_credit_its_source() {
+        let monitor = Arc::new(SocialMonitor::new());
+        let source_id = monitor
+            .add_twitter_account("@Alpha", TrackType::Alpha)
+            .await;
+        let strategy_id = Uuid::new_v4();
+        assert!(monitor
+            .attribute_strategy(strategy_id, source_id, None)
+            .await
+            .is_some());
+
+        let (tx, _rx) = broadcast::channel(16);
+        monitor.start_attribution_loop(&tx);
+
+        let closed = |strategy_id: Uuid, position_id: Uuid, pnl: f64, is_final: bool| {
+            ArbEvent::new(
+                "position_exit_completed",
+                crate::events::EventSource::System,
+                position_topics::CLOSED,
+                serde_json::json!({
+                    "strategy_id": strategy_id,
+                    "position_id": position_id,
+                    "realized_pnl_sol": pnl,
+                    "is_final": is_final,
+                }),
+            )
+        };
+
+        // A partial take-profit plus the final exit make one trade; an unattributed
+        // strategy's exit is ignored
+        let position_id = Uuid::new_v4();
+        tx.send(closed(strategy_id, position_id, 0.3, false))
+            .unwrap();
+        tx.send(closed(Uuid::new_v4(), Uuid::new_v4(), -5.0, true))
+            .unwrap();
+        tx.send(closed(strategy_id, position_id, -0.1, true))
+            .unwrap();
+        tx.send(closed(strategy_id, Uuid::new_v4(), -0.4, true))
+            .unwrap();
+
+        let mut reliability = SourceReliability::default();
+        for _ in 0..100 {
+            reliability = monitor.get_source(source_id).await.unwrap().reliability;
+            if reliability.profitable_strategies + reliability.losing_strategies == 2 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+        assert_eq!(reliability.profitable_strategies, 1);
+        assert_eq!(reliability.losing_strategies, 1);
+        assert!((reliability.strategy_pnl_sol + 0.2).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_unattributed_strategy_trade_is_ignored() {
+        let monitor = SocialMonitor::new();
+        monitor
+            .add_twitter_account("@alpha", TrackType::Alpha)
+            .await;
+        assert!(monitor
+            .record_strategy_trade(Uuid::new_v4(), 1.0)
+            .await
+            .is_none());
+        assert!(monitor
+            .attribute_strategy(Uuid::new_v4(), Uuid::new_v4(), None)
+            .await
+            .is_none());
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    #[ignore = "needs DATABASE_URL pointing at a Postgres server"]
+    async fn test_reliability_and_attribution_survive_restart(pool: sqlx::PgPool) {
+        use crate::database::repositories::{CreateStrategyRecord, StrategyRepository};
+
+        let strategy = StrategyRepository::new(pool.clone())
+            .create(CreateStrategyRecord {
+                wallet_address: "wallet".to_string(),
+                name: "From @alpha".to_string(),
+                strategy_type: "custom".to_string(),
+                venue_types: vec!["pump_fun".to_string()],
+                execution_mode: "manual".to_string(),
+                risk_params: crate::models::RiskParams::default(),
+            })
+            .await
+            .unwrap();
+
+        let repo = Arc::new(SourceReliabilityRepository::new(pool));
+        let monitor = SocialMonitor::new().with_repository(repo.clone());
+        let source_id = monitor
+            .add_twitter_account("@Alpha", TrackType::Alpha)
+            .await;
+        monitor
+            .record_source_outcome(source_id, SourceOutcome::Approved)
+            .await;
+        monitor
+            .attribute_strategy(strategy.id, source_id, None)
+            .await
+            .unwrap();
+
+        // A fresh monitor gives the source a new id but restores its score by handle
+        let restarted = SocialMonitor::new().with_repository(repo);
+        assert_eq!(restarted.load_strategy_sources().await.unwrap(), 1);
+        let new_id = restarted
+            .add_twitter_account("alpha", TrackType::Alpha)
+            .await;
+        assert_ne!(new_id, source_id);
+        assert_eq!(
+            restarted
+                .get_source(new_id)
+                .await
+                .unwrap()
+                .reliability
+                .approved,
+            1
+        );
+
+        let credited = restarted
+            .record_strategy_trade(strategy.id, 0.5)
+            .await
+            .unwrap();
+        assert_eq!(credited.reliability.profitable_strategies, 1);
+    }
+
+    #[tokio::test]
+    async fn test_manual_reactivation_clears_auto_mute() {
+        let monitor = SocialMonitor::new().with_reliability_config(ReliabilityConfig {
+            mute_threshold: 0.4,
+            min_outcomes: 2,
+        });
+        let id = monitor.add_twitter_account("@junk", TrackType::Alpha).await;
+
+        monitor
+            .record_source_outcome(id, SourceOutcome::Rejected)
+            .await;
+        let muted = monitor
+            .record_source_outcome(id, SourceOutcome::Rejected)
+            .await
+            .unwrap();
+        assert!(muted.reliability.auto_muted);
+
+        monitor.toggle_source(id, true).await.unwrap();
+        let source = monitor.get_source(id).await.unwrap();
+        assert!(source.is_active);
+        assert!(!source.reliability.auto_muted);
+        assert!(monitor
+            .record_source_outcome(Uuid::new_v4(), SourceOutcome::Approved)
+            .await
+            .is_none());
+    }
 }
@@ -1,10 +1,17 @@
 use chrono::{DateTime, Utc};
-use reqwest::Client;
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::{Client, StatusCode};
 use scraper::{Html, Selector};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+const DEFAULT_WEB_CACHE_TTL_SECS: u64 = 300;
+const DEFAULT_WEB_CACHE_MAX_ENTRIES: usize = 256;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WebFetchResult {
     pub id: Uuid,
@@ -78,9 +85,60 @@ impl Default for ExtractMode {
     }
 }
 
+/// Pages younger than `ttl` are served without a request; older ones are revalidated
+/// with `If-None-Match`/`If-Modified-Since` when the server sent validators.
+#[derive(Debug, Clone, Copy)]
+pub struct WebCacheConfig {
+    pub ttl: Duration,
+    pub max_entries: usize,
+}
+
+impl Default for WebCacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(DEFAULT_WEB_CACHE_TTL_SECS),
+            max_entries: DEFAULT_WEB_CACHE_MAX_ENTRIES,
+        }
+    }
+}
+
+impl WebCacheConfig {
+    /// `WEB_CACHE_TTL_SECS` (default 300) and `WEB_CACHE_MAX_ENTRIES` (default 256).
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let ttl = std::env::var("WEB_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(defaults.ttl);
+        let max_entries = std::env::var("WEB_CACHE_MAX_ENTRIES")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(defaults.max_entries);
+
+        Self { ttl, max_entries }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CachedPage {
+    body: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    validated_at: Instant,
+}
+
+impl CachedPage {
+    fn has_validators(&self) -> bool {
+        self.etag.is_some() || self.last_modified.is_some()
+    }
+}
+
 pub struct WebClient {
     client: Client,
     user_agent: String,
+    cache_config: WebCacheConfig,
+    cache: RwLock<HashMap<String, CachedPage>>,
 }
 
 impl WebClient {
@@ -92,39 +150,122 @@ impl WebClient {
                 .build()
                 .unwrap_or_default(),
             user_agent: "Mozilla/5.0 (compatible; ArbFarm/1.0; +https://nullblock.io)".to_string(),
+            cache_config: WebCacheConfig::default(),
+            cache: RwLock::new(HashMap::new()),
         }
     }
 
-    pub async fn fetch(
-        &self,
-        url: &str,
-        extract_mode: ExtractMode,
-        max_length: usize,
-    ) -> Result<WebFetchResult, String> {
-        let start = std::time::Instant::now();
-        debug!("Fetching URL: {} mode={:?}", url, extract_mode);
+    pub fn with_cache_config(mut self, cache_config: WebCacheConfig) -> Self {
+        self.cache_config = cache_config;
+        self
+    }
+
+    /// Returns the page body, from cache when it is fresh or the server answers 304.
+    async fn fetch_html(&self, url: &str) -> Result<String, String> {
+        let cached = self.cache.read().await.get(url).cloned();
 
-        let response = self
+        if let Some(page) = &cached {
+            if page.validated_at.elapsed() < self.cache_config.ttl {
+                debug!("Serving {} from cache", url);
+                return Ok(page.body.clone());
+            }
+        }
+
+        let mut request = self
             .client
             .get(url)
             .header("User-Agent", &self.user_agent)
             .header(
                 "Accept",
                 "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8",
-            )
+            );
+        if let Some(page) = cached.as_ref().filter(|p| p.has_validators()) {
+            if let Some(etag) = &page.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &page.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let response = request
             .send()
             .await
             .map_err(|e| format!("Failed to fetch URL: {}", e))?;
 
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let Some(mut page) = cached {
+                debug!("{} not modified, serving cached copy", url);
+                page.validated_at = Instant::now();
+                let body = page.body.clone();
+                self.cache.write().await.insert(url.to_string(), page);
+                return Ok(body);
+            }
+            return Err("HTTP 304 for a page that isn't cached".to_string());
+        }
+
         if !response.status().is_success() {
             return Err(format!("HTTP error: {}", response.status()));
         }
 
-        let html = response
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        };
+        let etag = header(ETAG);
+        let last_modified = header(LAST_MODIFIED);
+
+        let body = response
             .text()
             .await
             .map_err(|e| format!("Failed to read response: {}", e))?;
 
+        self.store(
+            url,
+            CachedPage {
+                body: body.clone(),
+                etag,
+                last_modified,
+                validated_at: Instant::now(),
+            },
+        )
+        .await;
+
+        Ok(body)
+    }
+
+    async fn store(&self, url: &str, page: CachedPage) {
+        if self.cache_config.max_entries == 0 {
+            return;
+        }
+
+        let mut cache = self.cache.write().await;
+        if !cache.contains_key(url) && cache.len() >= self.cache_config.max_entries {
+            let oldest = cache
+                .iter()
+                .min_by_key(|(_, p)| p.validated_at)
+                .map(|(k, _)| k.clone());
+            if let Some(oldest) = oldest {
+                cache.remove(&oldest);
+            }
+        }
+        cache.insert(url.to_string(), page);
+    }
+
+    pub async fn fetch(
+        &self,
+        url: &str,
+        extract_mode: ExtractMode,
+        max_length: usize,
+    ) -> Result<WebFetchResult, String> {
+        let start = std::time::Instant::now();
+        debug!("Fetching URL: {} mode={:?}", url, extract_mode);
+
+        let html = self.fetch_html(url).await?;
+
         let document = Html::parse_document(&html);
         let content_type = WebContentType::from_url(url);
 
@@ -416,6 +557,131 @@ impl Default for WebClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::response::{IntoResponse, Response};
+    use std::sync::{Arc, Mutex};
+
+    fn page(title: &str) -> String {
+        format!(
+            "<html><head><title>{}</title></head><body><main>{}</main></body></html>",
+            title, title
+        )
+    }
+
+    /// Serves `GET /post`; `respond` gets the request headers and the number of
+    /// earlier requests. Returns the page URL and the headers of every request seen.
+    async fn spawn_mock_http_server(
+        respond: impl Fn(&HeaderMap, usize) -> Response + Clone + Send + Sync + 'static,
+    ) -> (String, Arc<Mutex<Vec<HeaderMap>>>) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = requests.clone();
+        let app = axum::Router::new().route(
+            "/post",
+            axum::routing::get(move |headers: HeaderMap| {
+                let count = {
+                    let mut seen = seen.lock().unwrap();
+                    seen.push(headers.clone());
+                    seen.len() - 1
+                };
+                let response = respond(&headers, count);
+                async move { response }
+            }),
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/post", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (url, requests)
+    }
+
+    fn ok_response(etag: &str, body: &str) -> Response {
+        (
+            [
+                ("content-type", "text/html"),
+                ("etag", etag),
+                ("last-modified", "Wed, 14 Oct 2026 10:00:00 GMT"),
+            ],
+            body.to_string(),
+        )
+            .into_response()
+    }
+
+    fn header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+        headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    fn revalidating_client() -> WebClient {
+        WebClient::new().with_cache_config(WebCacheConfig {
+            ttl: Duration::ZERO,
+            ..WebCacheConfig::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_not_modified_serves_cached_content() {
+        let (url, requests) = spawn_mock_http_server(|headers, _| {
+            if header(headers, "if-none-match") == Some("\"v1\"") {
+                (StatusCode::NOT_MODIFIED, [("etag", "\"v1\"")]).into_response()
+            } else {
+                ok_response("\"v1\"", &page("Original alpha"))
+            }
+        })
+        .await;
+        let client = revalidating_client();
+
+        let first = client.fetch(&url, ExtractMode::Full, 10_000).await.unwrap();
+        let second = client.fetch(&url, ExtractMode::Full, 10_000).await.unwrap();
+
+        assert_eq!(first.title.as_deref(), Some("Original alpha"));
+        assert_eq!(second.title, first.title);
+        assert_eq!(second.content, first.content);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(header(&requests[0], "if-none-match").is_none());
+        assert_eq!(header(&requests[1], "if-none-match"), Some("\"v1\""));
+        assert_eq!(
+            header(&requests[1], "if-modified-since"),
+            Some("Wed, 14 Oct 2026 10:00:00 GMT")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_changed_resource_is_refetched() {
+        let (url, requests) = spawn_mock_http_server(|_, count| {
+            if count == 0 {
+                ok_response("\"v1\"", &page("Original alpha"))
+            } else {
+                ok_response("\"v2\"", &page("Updated alpha"))
+            }
+        })
+        .await;
+        let client = revalidating_client();
+
+        client.fetch(&url, ExtractMode::Full, 10_000).await.unwrap();
+        let second = client.fetch(&url, ExtractMode::Full, 10_000).await.unwrap();
+        let third = client.fetch(&url, ExtractMode::Full, 10_000).await.unwrap();
+
+        assert_eq!(second.title.as_deref(), Some("Updated alpha"));
+        assert_eq!(third.title.as_deref(), Some("Updated alpha"));
+        let requests = requests.lock().unwrap();
+        assert_eq!(header(&requests[2], "if-none-match"), Some("\"v2\""));
+    }
+
+    #[tokio::test]
+    async fn test_fresh_entry_skips_network() {
+        let (url, requests) =
+            spawn_mock_http_server(|_, _| ok_response("\"v1\"", &page("Original alpha"))).await;
+        let client = WebClient::new().with_cache_config(WebCacheConfig {
+            ttl: Duration::from_secs(60),
+            ..WebCacheConfig::default()
+        });
+
+        client.fetch(&url, ExtractMode::Full, 10_000).await.unwrap();
+        client.fetch(&url, ExtractMode::Full, 10_000).await.unwrap();
+
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
 
     #[test]
     fn test_content_type_detection() {
@@ -16,25 +16,32 @@ use crate::database::repositories::ConsensusRepository;
 use crate::database::repositories::KolRepository;
 use crate::database::{EdgeRepository, PositionRepository, StrategyRepository, TradeRepository};
 use crate::engrams::EngramsClient;
-use crate::events::{ArbEvent, EventBus};
+use crate::events::{ArbEvent, EventBus, EventSink};
 use crate::execution::risk::RiskConfig;
 use crate::execution::{
     ApprovalManager, CapitalManager, CurveTransactionBuilder, ExecutorAgent, ExecutorConfig,
-    JitoClient, MonitorConfig, PositionCommand, PositionExecutor, PositionMonitor,
-    RealtimePositionMonitor, TransactionBuilder, TransactionSimulator,
+    ExitFallbackChain, JitoClient, MonitorConfig, PositionCommand, PositionExecutor,
+    PositionMonitor, RealtimePositionMonitor, RebalanceConfig, TransactionBuilder,
+    TransactionSimulator,
 };
 use crate::handlers::engram::init_harvester;
 use crate::handlers::swarm::{init_circuit_breakers, init_overseer};
 use crate::helius::{
     priority_fee::PriorityFeeMonitor, DasClient, HeliusClient, HeliusSender, LaserStreamClient,
+    PriorityLevel,
 };
-use crate::models::KOLTracker;
+use crate::models::{ExecutionSchedule, KOLTracker};
 use crate::resilience::CircuitBreakerRegistry;
 use crate::venues::curves::{HolderAnalyzer, OnChainFetcher};
 use crate::venues::curves::{MoonshotVenue, PumpFunVenue};
-use crate::venues::dex::JupiterVenue;
+use crate::venues::dex::{JupiterVenue, RaydiumVenue, RpcClmmPoolSource};
+use crate::venues::lending::monitor::DEFAULT_LENDING_POLL_SECS;
+use crate::venues::lending::{
+    KaminoVenue, LendingVenueHealth, LiquidationAlertThresholds, LiquidationRiskMonitor,
+    MarginfiVenue, MonitoredLendingAccount,
+};
 use crate::wallet::turnkey::{TurnkeyConfig, TurnkeySigner};
-use crate::wallet::DevWalletSigner;
+use crate::wallet::{DevWalletSigner, SignAuditLog};
 use crate::webhooks::helius::HeliusWebhookClient;
 use nullblock_mcp_client::McpClient;
 
@@ -54,6 +61,7 @@ pub struct AppState {
     pub db_pool: PgPool,
     pub event_tx: broadcast::Sender<ArbEvent>,
     pub event_bus: Arc<EventBus>,
+    pub event_sink: Arc<EventSink>,
     pub scanner: Arc<ScannerAgent>,
     pub executor: Arc<ExecutorAgent>,
     pub simulator: Arc<TransactionSimulator>,
@@ -62,6 +70,7 @@ pub struct AppState {
     pub strategy_repo: Arc<StrategyRepository>,
     pub trade_repo: Arc<TradeRepository>,
     pub jupiter_venue: Arc<JupiterVenue>,
+    pub raydium_venue: Arc<RaydiumVenue>,
     pub pump_fun_venue: Arc<PumpFunVenue>,
     pub moonshot_venue: Arc<MoonshotVenue>,
     pub turnkey_signer: Arc<TurnkeySigner>,
@@ -75,15 +84,23 @@ pub struct AppState {
     pub strategy_engine: Arc<StrategyEngine>,
     pub consensus_engine: Arc<ConsensusEngine>,
     pub consensus_config: Arc<RwLock<ConsensusConfig>>,
+    pub consensus_limiter: crate::consensus::ConsensusRateLimiter,
+    pub model_accuracy: crate::consensus::ModelAccuracyTracker,
     pub engrams_client: Arc<EngramsClient>,
     pub position_repo: Arc<PositionRepository>,
     pub consensus_repo: Arc<ConsensusRepository>,
     pub settings_repo: Arc<crate::database::SettingsRepository>,
+    pub threat_audit_repo: Arc<crate::database::ThreatAuditRepository>,
     pub kol_repo: Arc<KolRepository>,
     pub laserstream_client: Arc<LaserStreamClient>,
     pub kol_discovery: Arc<KolDiscoveryAgent>,
     pub dev_signer: Arc<DevWalletSigner>,
+    pub sign_audit_log: Arc<SignAuditLog>,
+    pub balance_cache: Arc<crate::wallet::BalanceCache>,
+    pub mcp_jobs: crate::mcp::McpJobRegistry,
+    pub mcp_metrics: crate::mcp::McpToolMetrics,
     pub position_manager: Arc<crate::execution::PositionManager>,
+    pub reconcile_schedule: Arc<crate::execution::ReconcileSchedule>,
     pub position_monitor: Arc<PositionMonitor>,
     pub jito_client: Arc<JitoClient>,
     pub approval_manager: Arc<ApprovalManager>,
@@ -94,12 +111,16 @@ pub struct AppState {
     pub holder_analyzer: Arc<HolderAnalyzer>,
     pub curve_scorer: Arc<CurveOpportunityScorer>,
     pub autonomous_executor: Arc<AutonomousExecutor>,
+    pub copy_executor: Arc<crate::execution::CopyTradeExecutor>,
     pub position_executor: Arc<PositionExecutor>,
     pub realtime_monitor: Arc<RealtimePositionMonitor>,
     pub graduation_sniper: Arc<GraduationSniper>,
     pub wallet_max_position_sol: Arc<RwLock<f64>>,
     pub consensus_scheduler_paused: Arc<AtomicBool>,
     pub consensus_last_queried: Arc<RwLock<Option<chrono::DateTime<chrono::Utc>>>>,
+    pub social_monitor: Arc<crate::research::SocialMonitor>,
+    pub discovery_store: Arc<crate::research::DiscoveryStore>,
+    pub web_client: Arc<crate::research::WebClient>,
 }
 
 impl AppState {
@@ -127,6 +148,21 @@ impl AppState {
         let event_bus = Arc::new(EventBus::new(event_tx.clone(), db_pool.clone()));
         tracing::info!("✅ Event bus initialized (capacity: {})", channel_capacity);
 
+        let event_log_enabled = std::env::var("ARB_EVENT_LOG_ENABLED")
+            .map(|v| v == "1" || v.to_lowercase() == "true")
+            .unwrap_or(true);
+        let event_log_repo = event_log_enabled.then(|| {
+            Arc::new(crate::database::repositories::EventLogRepository::new(
+                db_pool.clone(),
+            ))
+        });
+        let event_sink = EventSink::new(
+            crate::events::sink::DEFAULT_EVENT_SINK_CAPACITY,
+            event_log_repo,
+        );
+        event_sink.attach(&event_tx);
+        tracing::info!("✅ Event sink attached (persistent: {})", event_log_enabled);
+
         let scanner = Arc::new(ScannerAgent::new(
             event_tx.clone(),
             DEFAULT_SCAN_INTERVAL_MS,
@@ -134,6 +170,10 @@ impl AppState {
 
         // Initialize venues (shared between scanner and direct access)
         let jupiter_venue = Arc::new(JupiterVenue::new(config.jupiter_api_url.clone()));
+        let raydium_venue = Arc::new(
+            RaydiumVenue::new(config.jupiter_api_url.clone())
+                .with_pool_source(Arc::new(RpcClmmPoolSource::new(&config.rpc_url))),
+        );
         let pump_fun_venue = Arc::new(PumpFunVenue::new(
             config.pump_fun_api_url.clone(),
             config.dexscreener_api_url.clone(),
@@ -164,12 +204,21 @@ impl AppState {
         let trade_repo = Arc::new(TradeRepository::new(db_pool.clone()));
         tracing::info!("✅ Database repositories initialized");
 
+        let balance_cache = Arc::new(crate::wallet::BalanceCache::from_env());
+        balance_cache
+            .clone()
+            .spawn_settlement_invalidator(&event_tx);
+        tracing::info!(
+            "✅ Wallet balance cache initialized (TTL: {}s, invalidated on trade settlement)",
+            balance_cache.ttl().as_secs()
+        );
+
         // Initialize simulator, transaction builder, and executor
         let simulator = Arc::new(TransactionSimulator::new(config.rpc_url.clone()));
-        let tx_builder = Arc::new(TransactionBuilder::new(
-            config.jupiter_api_url.clone(),
-            config.rpc_url.clone(),
-        )?);
+        let tx_builder = Arc::new(
+            TransactionBuilder::new(config.jupiter_api_url.clone(), config.rpc_url.clone())?
+                .with_balance_cache(balance_cache.clone()),
+        );
         let executor = Arc::new(ExecutorAgent::new(
             config.jito_block_engine_url.clone(),
             config.rpc_url.clone(),
@@ -178,6 +227,11 @@ impl AppState {
         ));
         tracing::info!("✅ Executor agent initialized (Jito + Simulation + TransactionBuilder)");
 
+        // Every signing attempt from either signer lands in one persisted audit log
+        let sign_audit_log = Arc::new(SignAuditLog::new().with_repository(Arc::new(
+            crate::database::repositories::SignAuditRepository::new(db_pool.clone()),
+        )));
+
         // Initialize Turnkey signer for wallet delegation
         let turnkey_config = TurnkeyConfig {
             api_url: config.turnkey_api_url.clone(),
@@ -186,12 +240,13 @@ impl AppState {
             api_private_key: config.turnkey_api_private_key.clone(),
         };
         let is_dev_mode = std::env::var("ARBFARM_DEV_MODE").is_ok();
-        let turnkey_signer = Arc::new(if is_dev_mode {
+        let turnkey_signer = if is_dev_mode {
             tracing::info!("🔓 Dev mode: no daily volume limits");
             TurnkeySigner::new_dev(turnkey_config)
         } else {
             TurnkeySigner::new(turnkey_config)
-        });
+        };
+        let turnkey_signer = Arc::new(turnkey_signer.with_audit_log(sign_audit_log.clone()));
 
         // Initialize DevWalletSigner for development mode signing
         let dev_signer = match DevWalletSigner::new(
@@ -207,11 +262,15 @@ impl AppState {
                 } else {
                     tracing::info!("✅ Dev wallet signer initialized (no private key)");
                 }
-                Arc::new(signer)
+                Arc::new(signer.with_audit_log(sign_audit_log.clone()))
             }
             Err(e) => {
                 tracing::warn!("⚠️ Failed to initialize dev signer: {}", e);
-                Arc::new(DevWalletSigner::new(None, None).unwrap())
+                Arc::new(
+                    DevWalletSigner::new(None, None)
+                        .unwrap()
+                        .with_audit_log(sign_audit_log.clone()),
+                )
             }
         };
 
@@ -421,6 +480,9 @@ impl AppState {
                 concurrent_positions: Some(3),       // Up to 3 snipe positions
                 momentum_adaptive_exits: true,       // Enable for graduation snipes
                 let_winners_run: true,               // Let winners run post-graduation
+                exit_template: None,
+                auto_kill_consecutive_losses: None,
+                auto_kill_max_drawdown_sol: None,
             },
         )
         .await
@@ -456,6 +518,9 @@ impl AppState {
                 concurrent_positions: Some(2),
                 momentum_adaptive_exits: false,
                 let_winners_run: false,
+                exit_template: None,
+                auto_kill_consecutive_losses: None,
+                auto_kill_max_drawdown_sol: None,
             },
         )
         .await
@@ -610,7 +675,10 @@ impl AppState {
                 }
             };
 
-            Arc::new(engine_with_mcp)
+            Arc::new(
+                engine_with_mcp
+                    .with_wallet_address(config.wallet_address.clone().unwrap_or_default()),
+            )
         } else {
             tracing::error!("⚠️⚠️⚠️ NO OPENROUTER API KEY CONFIGURED ⚠️⚠️⚠️");
             tracing::error!("Consensus engine will be DISABLED. LLM analysis and recommendations will NOT work.");
@@ -1087,15 +1155,53 @@ impl AppState {
 
         // Initialize Position Repository and Manager for tracking open positions and exit conditions
         let position_repo = Arc::new(PositionRepository::new(db_pool.clone()));
-        let position_manager = Arc::new(crate::execution::PositionManager::with_repository(
-            position_repo.clone(),
-        ));
+        let sol_price = Arc::new(crate::execution::SolUsdPriceCache::from_env()?);
+        sol_price.start_refresh_loop();
+        let position_manager = Arc::new(
+            crate::execution::PositionManager::with_repository(position_repo.clone())
+                .with_event_tx(event_tx.clone())
+                .with_sol_price(sol_price)
+                .with_balance_cache(balance_cache.clone()),
+        );
+
+        let lending_accounts = MonitoredLendingAccount::list_from_env();
+        if lending_accounts.is_empty() {
+            tracing::info!(
+                "ℹ️ Lending liquidation monitor idle (LENDING_MONITORED_ACCOUNTS not set)"
+            );
+        } else {
+            let poll_secs = std::env::var("LENDING_MONITOR_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_LENDING_POLL_SECS)
+                .max(5);
+            let lending_health = LendingVenueHealth::new(
+                KaminoVenue::new(config.kamino_api_url.clone(), config.rpc_url.clone()),
+                MarginfiVenue::new(config.marginfi_api_url.clone(), config.rpc_url.clone()),
+            );
+            Arc::new(LiquidationRiskMonitor::new(
+                LiquidationAlertThresholds::from_env(),
+                event_tx.clone(),
+            ))
+            .start_polling(
+                Arc::new(lending_health),
+                lending_accounts.clone(),
+                std::time::Duration::from_secs(poll_secs),
+            );
+            tracing::info!(
+                "✅ Lending liquidation monitor polling {} account(s) every {}s",
+                lending_accounts.len(),
+                poll_secs
+            );
+        }
 
         // Initialize Consensus Repository for persisting LLM consensus decisions
         let consensus_repo = Arc::new(ConsensusRepository::new(db_pool.clone()));
         tracing::info!("✅ Consensus repository initialized (persisting to PostgreSQL)");
 
         let settings_repo = Arc::new(crate::database::SettingsRepository::new(db_pool.clone()));
+        let threat_audit_repo =
+            Arc::new(crate::database::ThreatAuditRepository::new(db_pool.clone()));
 
         let kol_repo = Arc::new(KolRepository::new(db_pool.clone()));
         tracing::info!("✅ KOL repository initialized (PostgreSQL persistence)");
@@ -1134,6 +1240,26 @@ impl AppState {
             }
         }
 
+        match position_manager.load_losing_exits_from_db().await {
+            Ok(count) if count > 0 => {
+                tracing::info!("✅ Restored re-entry cooldowns for {} mints", count);
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!("⚠️ Failed to restore re-entry cooldowns: {}", e);
+            }
+        }
+
+        match position_manager.load_exit_templates_from_db().await {
+            Ok(count) if count > 0 => {
+                tracing::info!("✅ Loaded {} exit config templates", count);
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!("⚠️ Failed to load exit config templates: {}", e);
+            }
+        }
+
         // Initialize Jito client for bundle submission (shared by executor and position monitor)
         let jito_client = Arc::new(JitoClient::new(config.jito_block_engine_url.clone(), None));
         tracing::info!(
@@ -1168,13 +1294,20 @@ impl AppState {
         approval_manager.sync_from_strategies(any_autonomous).await;
         tracing::info!("✅ Approval Manager initialized (execution controls + Hecate integration, synced from strategies: auto={})", any_autonomous);
 
+        strategy_engine.start_auto_kill_loop(strategy_repo.clone(), approval_manager.clone());
+
         // Spawn HecateNotifier to forward approval events to Hecate for recommendations
         let hecate_event_rx = event_tx.subscribe();
         spawn_hecate_notifier(config.agents_service_url.clone(), hecate_event_rx);
         tracing::info!("✅ Hecate Notifier spawned (listening for approval events)");
 
         // Initialize Capital Manager for per-strategy allocation tracking
-        let capital_manager = Arc::new(CapitalManager::new().with_db_pool(db_pool.clone()));
+        let capital_manager = Arc::new(
+            CapitalManager::new()
+                .with_db_pool(db_pool.clone())
+                .with_rebalance_config(RebalanceConfig::from_env())
+                .with_balance_cache(balance_cache.clone()),
+        );
 
         // Load existing reservations from database (recovery after restart)
         match capital_manager.load_reservations_from_db().await {
@@ -1212,15 +1345,37 @@ impl AppState {
 
         // Initialize on-chain fetcher and curve transaction builder for bonding curve operations
         let on_chain_fetcher = Arc::new(OnChainFetcher::new(&config.rpc_url));
-        let curve_builder = Arc::new(
-            CurveTransactionBuilder::new(&config.rpc_url)
-                .with_on_chain_fetcher(on_chain_fetcher.clone()),
-        );
+        let max_network_fee_fraction = std::env::var("SNIPE_MAX_NETWORK_FEE_FRACTION")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(crate::execution::curve_builder::DEFAULT_MAX_NETWORK_FEE_FRACTION);
+        let fee_inclusive_sizing = std::env::var("SNIPE_FEE_INCLUSIVE_SIZING")
+            .map(|v| v == "1" || v.to_lowercase() == "true")
+            .unwrap_or(false);
+        let max_priority_fee = std::env::var("SNIPE_MAX_PRIORITY_FEE_MICRO_LAMPORTS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(crate::execution::curve_builder::DEFAULT_MAX_PRIORITY_FEE_MICRO_LAMPORTS);
+        let mut curve_builder = CurveTransactionBuilder::new(&config.rpc_url)
+            .with_on_chain_fetcher(on_chain_fetcher.clone())
+            .with_max_priority_fee(max_priority_fee)
+            .with_max_network_fee_fraction(max_network_fee_fraction)
+            .with_fee_inclusive_sizing(fee_inclusive_sizing)
+            .with_balance_cache(balance_cache.clone());
+        if config.helius_api_key.is_some() {
+            // Price curve trades from the fees paid on the accounts they write
+            curve_builder = curve_builder
+                .with_priority_fee_source(priority_fee_monitor.clone(), PriorityLevel::High);
+        }
+        let curve_builder = Arc::new(curve_builder);
         tracing::info!("✅ Curve execution engine initialized (on-chain state + tx builder)");
 
         // Add curve state checker to position monitor (for curve price lookups only)
-        let position_monitor =
-            Arc::new(position_monitor_base.with_curve_state_checker(curve_builder.clone()));
+        let position_monitor = Arc::new(
+            position_monitor_base
+                .with_curve_state_checker(curve_builder.clone())
+                .with_risk_config(risk_config.clone()),
+        );
         tracing::info!("✅ Position Monitor initialized with curve support (monitoring only, execution via PositionExecutor)");
 
         // Initialize PositionExecutor for centralized sell execution
@@ -1232,13 +1387,20 @@ impl AppState {
                 jito_client.clone(),
                 event_tx.clone(),
                 dev_signer.clone(),
-                ExecutorConfig::default(),
+                ExecutorConfig {
+                    slippage_escalation: crate::execution::SlippageEscalation::from_env(),
+                    exit_dispatch: crate::execution::ExitDispatchConfig::from_env(),
+                    exit_settlement: crate::execution::ExitSettlementConfig::from_env(),
+                    sell_protection: crate::execution::SellProtection::from_env(),
+                    ..ExecutorConfig::default()
+                },
             )
             .with_curve_support(curve_builder.clone(), helius_sender.clone())
             .with_helius_client(helius_rpc_client.clone())
             .with_engrams(engrams_client.clone())
             .with_trade_repo(trade_repo.clone())
-            .with_capital_manager(capital_manager.clone()),
+            .with_capital_manager(capital_manager.clone())
+            .with_emergency_exit_chain(ExitFallbackChain::from_env()),
         );
         tracing::info!("✅ Position Executor initialized (centralized sell execution: curve + DEX + engrams + capital)");
 
@@ -1271,30 +1433,60 @@ impl AppState {
             .set_raydium_snipe_strategy(raydium_snipe_strategy)
             .await;
         scanner.set_pump_fun_venue(pump_fun_venue.clone()).await;
+        scanner
+            .get_strategy_registry()
+            .start_feedback_loop(&event_tx);
         tracing::info!("✅ Behavioral strategies registered (Graduation Sniper, Raydium Snipe)");
 
         // Rebalance capital manager to give all strategies equal allocation
         capital_manager.rebalance_equal().await;
         tracing::info!("✅ Capital allocation rebalanced: all strategies have equal share");
 
+        // Opt-in via CAPITAL_AUTO_REBALANCE=1: shift budget towards strategies with better recent P&L
+        if capital_manager.rebalance_config().auto_enabled {
+            capital_manager
+                .clone()
+                .start_auto_rebalance(trade_repo.clone())
+                .await;
+        }
+
         // Create CopyTradeExecutor for KOL copy trading (OFF by default for observation mode)
-        let copy_executor = Arc::new(CopyTradeExecutor::new(
-            kol_repo.clone(),
-            curve_builder.clone(),
-            dev_signer.clone(),
-            helius_sender.clone(),
-            position_manager.clone(),
-            engrams_client.clone(),
-            event_tx.clone(),
-            command_tx.clone(),
-            default_wallet.clone(),
-        ));
+        let copy_executor = Arc::new(
+            CopyTradeExecutor::new(
+                kol_repo.clone(),
+                curve_builder.clone(),
+                dev_signer.clone(),
+                helius_sender.clone(),
+                position_manager.clone(),
+                engrams_client.clone(),
+                event_tx.clone(),
+                command_tx.clone(),
+                default_wallet.clone(),
+            )
+            .with_risk_config(risk_config.clone()),
+        );
 
         // Enable copy trading via env var: ARBFARM_COPY_TRADING=1
         let copy_trading_enabled = std::env::var("ARBFARM_COPY_TRADING")
             .map(|v| v == "1" || v.to_lowercase() == "true")
             .unwrap_or(false);
 
+        // Per-KOL copy budget is opt-in: KOL_COPY_BUDGET_SOL=5
+        if let Some(budget_sol) = std::env::var("KOL_COPY_BUDGET_SOL")
+            .ok()
+            .and_then(|v| v.parse::<f64>().ok())
+            .filter(|v| *v > 0.0)
+        {
+            let mut copy_config = copy_executor.get_config().await;
+            copy_config.kol_budget_sol = Some(budget_sol);
+            copy_executor.update_config(copy_config).await;
+            tracing::info!("💰 KOL copy budget: {} SOL per KOL", budget_sol);
+        }
+        match copy_executor.load_kol_budgets().await {
+            Ok(kols) => tracing::info!("💰 Restored copy budget spend for {} KOLs", kols),
+            Err(e) => tracing::warn!("Failed to restore KOL copy budgets: {}", e),
+        }
+
         if copy_trading_enabled {
             let mut copy_config = copy_executor.get_config().await;
             copy_config.enabled = true;
@@ -1325,7 +1517,19 @@ impl AppState {
             default_wallet_for_executor,
             Some(trade_repo.clone()),
             Some(helius_rpc_client.clone()),
+            Some(Arc::new(
+                crate::database::repositories::AutoExecutionRepository::new(db_pool.clone()),
+            )),
         );
+        match autonomous_executor.execution_log().load_from_db().await {
+            Ok(count) if count > 0 => {
+                tracing::info!("✅ Restored {} autonomous execution records", count);
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!("⚠️ Failed to restore autonomous execution records: {}", e);
+            }
+        }
 
         // Connect CopyTradeExecutor to AutonomousExecutor for KOL copy trading
         autonomous_executor
@@ -1391,6 +1595,21 @@ impl AppState {
             tracing::info!("   Enable: set ARBFARM_ENABLE_EXECUTOR=1 or use UI toggle");
         }
 
+        match settings_repo.get("execution_schedule").await {
+            Ok(Some(record)) => match serde_json::from_str::<ExecutionSchedule>(&record.value) {
+                Ok(schedule) => {
+                    if let Err(e) = approval_manager.set_schedule(schedule).await {
+                        tracing::warn!("Ignoring invalid persisted execution schedule: {}", e);
+                    } else {
+                        tracing::info!("🕒 Execution schedule restored from saved state");
+                    }
+                }
+                Err(e) => tracing::warn!("Failed to parse persisted execution schedule: {}", e),
+            },
+            Ok(None) => {}
+            Err(e) => tracing::warn!("Failed to load execution schedule: {}", e),
+        }
+
         // Initialize Real-time Position Monitor for websocket-based price updates
         let realtime_monitor = Arc::new(RealtimePositionMonitor::new(
             laserstream_client.clone(),
@@ -1417,11 +1636,32 @@ impl AppState {
         );
         tracing::info!("✅ Graduation Sniper initialized (strategy engine + Jupiter + PositionManager + RiskConfig for exit monitoring)");
 
+        let social_monitor = Arc::new(crate::research::SocialMonitor::new().with_repository(
+            Arc::new(
+                crate::database::repositories::SourceReliabilityRepository::new(db_pool.clone()),
+            ),
+        ));
+        social_monitor.add_default_sources().await;
+        match social_monitor.load_strategy_sources().await {
+            Ok(count) => tracing::info!("📚 Loaded {} research strategy attributions", count),
+            Err(e) => tracing::warn!("Failed to load research strategy attributions: {}", e),
+        }
+        social_monitor.start_attribution_loop(&event_tx);
+        let discovery_store = Arc::new(
+            crate::research::DiscoveryStore::new()
+                .with_auto_backtest(crate::research::discovery::get_discovery_auto_backtest()),
+        );
+        let web_client = Arc::new(
+            crate::research::WebClient::new()
+                .with_cache_config(crate::research::WebCacheConfig::from_env()),
+        );
+
         Ok(Self {
             config,
             db_pool,
             event_tx,
             event_bus,
+            event_sink,
             scanner,
             executor,
             simulator,
@@ -1430,6 +1670,7 @@ impl AppState {
             strategy_repo,
             trade_repo,
             jupiter_venue,
+            raydium_venue,
             pump_fun_venue,
             moonshot_venue,
             turnkey_signer,
@@ -1443,14 +1684,20 @@ impl AppState {
             strategy_engine,
             consensus_engine,
             consensus_config,
+            consensus_limiter: crate::consensus::ConsensusRateLimiter::new(),
             engrams_client,
             position_repo,
             consensus_repo,
             settings_repo,
+            threat_audit_repo,
             kol_repo,
             laserstream_client,
             kol_discovery,
             dev_signer,
+            sign_audit_log,
+            balance_cache,
+            mcp_jobs: crate::mcp::McpJobRegistry::new(),
+            mcp_metrics: crate::mcp::McpToolMetrics::from_env(),
             position_manager,
             position_monitor,
             jito_client,
@@ -1462,12 +1709,18 @@ impl AppState {
             holder_analyzer,
             curve_scorer,
             autonomous_executor,
+            copy_executor,
             position_executor,
             realtime_monitor,
             graduation_sniper,
             wallet_max_position_sol: Arc::new(RwLock::new(10.0)),
             consensus_scheduler_paused: Arc::new(AtomicBool::new(true)), // ALWAYS start paused - manual trigger only
             consensus_last_queried: Arc::new(RwLock::new(None)),
+            model_accuracy: crate::consensus::ModelAccuracyTracker::new(),
+            reconcile_schedule: Arc::new(crate::execution::ReconcileSchedule::from_env()),
+            social_monitor,
+            discovery_store,
+            web_client,
         })
     }
 
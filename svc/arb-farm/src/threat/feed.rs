@@ -0,0 +1,365 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use tracing::warn;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{BlockedEntity, BlocklistProvenance, ThreatCategory, ThreatEntityType};
+
+pub const BLOCKLIST_FEED_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlocklistFeedEntry {
+    pub entity_type: ThreatEntityType,
+    pub address: String,
+    pub threat_category: ThreatCategory,
+    pub threat_score: Option<f64>,
+    pub reason: String,
+    pub evidence_url: Option<String>,
+    pub reported_by: String,
+    pub blocked_at: DateTime<Utc>,
+}
+
+impl From<&BlockedEntity> for BlocklistFeedEntry {
+    fn from(entity: &BlockedEntity) -> Self {
+        Self {
+            entity_type: entity.entity_type.clone(),
+            address: entity.address.clone(),
+            threat_category: entity.threat_category.clone(),
+            threat_score: entity.threat_score,
+            reason: entity.reason.clone(),
+            evidence_url: entity.evidence_url.clone(),
+            reported_by: entity.reported_by.clone(),
+            blocked_at: entity.created_at,
+        }
+    }
+}
+
+impl BlocklistFeedEntry {
+    fn into_entity(self, provenance: BlocklistProvenance) -> BlockedEntity {
+        let mut entity = BlockedEntity::new(
+            self.entity_type,
+            self.address,
+            self.threat_category,
+            self.reason,
+            self.reported_by,
+        );
+        entity.threat_score = self.threat_score;
+        entity.evidence_url = self.evidence_url;
+        entity.created_at = self.blocked_at;
+        entity.provenance = provenance;
+        entity
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlocklistFeed {
+    pub version: u32,
+    pub source: String,
+    pub exported_at: DateTime<Utc>,
+    pub entries: Vec<BlocklistFeedEntry>,
+}
+
+impl BlocklistFeed {
+    /// Active local entries only, so imported entries are not relayed back out.
+    pub fn from_entities<'a>(
+        source: String,
+        entities: impl IntoIterator<Item = &'a BlockedEntity>,
+    ) -> Self {
+        let mut entries: Vec<BlocklistFeedEntry> = entities
+            .into_iter()
+            .filter(|e| e.is_active && e.provenance.is_local())
+            .map(BlocklistFeedEntry::from)
+            .collect();
+        entries.sort_by(|a, b| a.address.cmp(&b.address));
+
+        Self {
+            version: BLOCKLIST_FEED_VERSION,
+            source,
+            exported_at: Utc::now(),
+            entries,
+        }
+    }
+}
+
+/// A feed and an ed25519 signature over its exact JSON text, so it verifies regardless
+/// of how the envelope is re-serialized in transit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedBlocklistFeed {
+    pub signer: String,
+    pub signature: String,
+    pub payload: String,
+}
+
+impl SignedBlocklistFeed {
+    pub fn sign(feed: &BlocklistFeed, keypair: &Keypair) -> AppResult<Self> {
+        let payload = serde_json::to_string(feed)
+            .map_err(|e| AppError::Serialization(format!("Blocklist feed: {}", e)))?;
+        let signature = keypair.sign_message(payload.as_bytes());
+
+        Ok(Self {
+            signer: keypair.pubkey().to_string(),
+            signature: signature.to_string(),
+            payload,
+        })
+    }
+
+    /// The feed, once its signer is trusted and the signature matches the payload.
+    pub fn verify(&self, trusted_signers: &[Pubkey]) -> AppResult<BlocklistFeed> {
+        let signer = Pubkey::from_str(&self.signer)
+            .map_err(|_| AppError::Validation(format!("Invalid feed signer {}", self.signer)))?;
+        if !trusted_signers.contains(&signer) {
+            return Err(AppError::Unauthorized(format!(
+                "Feed signer {} is not trusted",
+                self.signer
+            )));
+        }
+
+        let signature = Signature::from_str(&self.signature)
+            .map_err(|_| AppError::Validation("Invalid feed signature encoding".to_string()))?;
+        if !signature.verify(signer.as_ref(), self.payload.as_bytes()) {
+            return Err(AppError::Unauthorized(
+                "Feed signature does not match its payload".to_string(),
+            ));
+        }
+
+        let feed: BlocklistFeed = serde_json::from_str(&self.payload)
+            .map_err(|e| AppError::Validation(format!("Malformed feed payload: {}", e)))?;
+        if feed.version != BLOCKLIST_FEED_VERSION {
+            return Err(AppError::Validation(format!(
+                "Unsupported feed version {}",
+                feed.version
+            )));
+        }
+
+        Ok(feed)
+    }
+}
+
+/// Outcome of merging a feed. Skipped entries are counted by why they were skipped.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BlocklistMerge {
+    pub imported: Vec<BlockedEntity>,
+    pub already_blocked: usize,
+    pub whitelisted: usize,
+    pub duplicates: usize,
+}
+
+/// Adds feed entries that are neither blocked nor whitelisted here. Whatever is already
+/// in the blocklist wins, so a feed never overwrites a local decision or another feed.
+pub fn merge_feed(
+    blocked: &mut HashMap<String, BlockedEntity>,
+    is_whitelisted: impl Fn(&str) -> bool,
+    feed: BlocklistFeed,
+    signer: &str,
+) -> BlocklistMerge {
+    let BlocklistFeed {
+        source, entries, ..
+    } = feed;
+    let imported_at = Utc::now();
+    let mut merge = BlocklistMerge::default();
+    let mut seen = HashSet::new();
+
+    for entry in entries {
+        if !seen.insert(entry.address.clone()) {
+            merge.duplicates += 1;
+        } else if blocked.contains_key(&entry.address) {
+            merge.already_blocked += 1;
+        } else if is_whitelisted(&entry.address) {
+            merge.whitelisted += 1;
+        } else {
+            let entity = entry.into_entity(BlocklistProvenance::Imported {
+                feed_source: source.clone(),
+                feed_signer: signer.to_string(),
+                imported_at,
+            });
+            blocked.insert(entity.address.clone(), entity.clone());
+            merge.imported.push(entity);
+        }
+    }
+
+    merge
+}
+
+/// `THREAT_FEED_SIGNING_KEY` (base58 keypair) signs exports as `THREAT_FEED_SOURCE`;
+/// `THREAT_FEED_TRUSTED_SIGNERS` (comma-separated pubkeys) lists feeds accepted on import.
+pub struct BlocklistFeedConfig {
+    pub source: String,
+    pub trusted_signers: Vec<Pubkey>,
+    signing_key: Option<Keypair>,
+}
+
+impl BlocklistFeedConfig {
+    pub fn from_env() -> Self {
+        let signing_key = std::env::var("THREAT_FEED_SIGNING_KEY")
+            .ok()
+            .filter(|k| !k.is_empty())
+            .and_then(|key| {
+                let keypair = bs58::decode(key)
+                    .into_vec()
+                    .ok()
+                    .and_then(|bytes| Keypair::try_from(bytes.as_slice()).ok());
+                if keypair.is_none() {
+                    warn!("THREAT_FEED_SIGNING_KEY is not a valid keypair - blocklist export disabled");
+                }
+                keypair
+            });
+
+        let trusted_signers = std::env::var("THREAT_FEED_TRUSTED_SIGNERS")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .filter_map(|s| match Pubkey::from_str(s) {
+                Ok(pubkey) => Some(pubkey),
+                Err(_) => {
+                    warn!("Ignoring invalid trusted feed signer {}", s);
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            source: std::env::var("THREAT_FEED_SOURCE").unwrap_or_else(|_| "arb-farm".to_string()),
+            trusted_signers,
+            signing_key,
+        }
+    }
+
+    pub fn signing_key(&self) -> AppResult<&Keypair> {
+        self.signing_key.as_ref().ok_or_else(|| {
+            AppError::Configuration("THREAT_FEED_SIGNING_KEY is not configured".to_string())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blocked(address: &str, category: ThreatCategory) -> BlockedEntity {
+        let mut entity = BlockedEntity::new(
+            ThreatEntityType::Token,
+            address.to_string(),
+            category,
+            "rugged".to_string(),
+            "scanner".to_string(),
+        );
+        entity.threat_score = Some(0.9);
+        entity
+    }
+
+    fn store(entities: Vec<BlockedEntity>) -> HashMap<String, BlockedEntity> {
+        entities
+            .into_iter()
+            .map(|e| (e.address.clone(), e))
+            .collect()
+    }
+
+    #[test]
+    fn test_export_import_merges_with_provenance_and_dedup() {
+        let keypair = Keypair::new();
+        let mut exporter = store(vec![
+            blocked("MintA", ThreatCategory::RugPull),
+            blocked("MintB", ThreatCategory::Honeypot),
+            blocked("MintC", ThreatCategory::RugPull),
+        ]);
+        exporter.get_mut("MintC").unwrap().provenance = BlocklistProvenance::Imported {
+            feed_source: "elsewhere".to_string(),
+            feed_signer: "Signer".to_string(),
+            imported_at: Utc::now(),
+        };
+
+        let feed = BlocklistFeed::from_entities("node-a".to_string(), exporter.values());
+        let addresses: Vec<_> = feed.entries.iter().map(|e| e.address.as_str()).collect();
+        assert_eq!(addresses, ["MintA", "MintB"]);
+
+        let signed = SignedBlocklistFeed::sign(&feed, &keypair).unwrap();
+        let transported: SignedBlocklistFeed =
+            serde_json::from_str(&serde_json::to_string(&signed).unwrap()).unwrap();
+        let verified = transported.verify(&[keypair.pubkey()]).unwrap();
+        assert_eq!(verified, feed);
+
+        let mut importer = store(vec![blocked("MintB", ThreatCategory::ScamWallet)]);
+        let merge = merge_feed(&mut importer, |_| false, verified.clone(), &signed.signer);
+        assert_eq!(merge.imported.len(), 1);
+        assert_eq!(merge.already_blocked, 1);
+
+        let imported = &importer["MintA"];
+        assert_eq!(imported.threat_category, ThreatCategory::RugPull);
+        assert_eq!(imported.threat_score, Some(0.9));
+        assert_eq!(imported.created_at, exporter["MintA"].created_at);
+        match &imported.provenance {
+            BlocklistProvenance::Imported {
+                feed_source,
+                feed_signer,
+                ..
+            } => {
+                assert_eq!(feed_source, "node-a");
+                assert_eq!(feed_signer, &keypair.pubkey().to_string());
+            }
+            BlocklistProvenance::Local => panic!("imported entry marked local"),
+        }
+        // The local entry for the same address is left alone
+        assert!(importer["MintB"].provenance.is_local());
+        assert_eq!(
+            importer["MintB"].threat_category,
+            ThreatCategory::ScamWallet
+        );
+
+        // Re-importing the same feed adds nothing
+        let again = merge_feed(&mut importer, |_| false, verified, &signed.signer);
+        assert!(again.imported.is_empty());
+        assert_eq!(again.already_blocked, 2);
+        assert_eq!(importer.len(), 2);
+    }
+
+    #[test]
+    fn test_merge_skips_whitelisted_and_repeated_entries() {
+        let mut feed = BlocklistFeed::from_entities(
+            "node-a".to_string(),
+            &[
+                blocked("MintA", ThreatCategory::RugPull),
+                blocked("MintB", ThreatCategory::RugPull),
+            ],
+        );
+        feed.entries.push(feed.entries[0].clone());
+
+        let mut importer = HashMap::new();
+        let merge = merge_feed(&mut importer, |a| a == "MintB", feed, "Signer");
+        assert_eq!(merge.imported.len(), 1);
+        assert_eq!(merge.whitelisted, 1);
+        assert_eq!(merge.duplicates, 1);
+        assert!(importer.contains_key("MintA"));
+        assert!(!importer.contains_key("MintB"));
+    }
+
+    #[test]
+    fn test_untrusted_or_tampered_feed_rejected() {
+        let keypair = Keypair::new();
+        let feed = BlocklistFeed::from_entities(
+            "node-a".to_string(),
+            &[blocked("MintA", ThreatCategory::RugPull)],
+        );
+        let signed = SignedBlocklistFeed::sign(&feed, &keypair).unwrap();
+
+        assert!(matches!(
+            signed.verify(&[Keypair::new().pubkey()]),
+            Err(AppError::Unauthorized(_))
+        ));
+
+        let tampered = SignedBlocklistFeed {
+            payload: signed.payload.replace("MintA", "MintZ"),
+            ..signed.clone()
+        };
+        assert!(matches!(
+            tampered.verify(&[keypair.pubkey()]),
+            Err(AppError::Unauthorized(_))
+        ));
+    }
+}
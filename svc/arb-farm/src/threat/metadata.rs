@@ -0,0 +1,362 @@
+use serde::{Deserialize, Serialize};
+
+use crate::execution::{SOL_MINT, USDC_MINT, USDT_MINT};
+
+/// Names scammers most often clone. Tokens at the listed mint are the real thing and
+/// never flagged.
+const DEFAULT_PROTECTED_TOKENS: &[(&str, &str, &str)] = &[
+    ("SOL", "Solana", SOL_MINT),
+    ("USDC", "USD Coin", USDC_MINT),
+    ("USDT", "Tether", USDT_MINT),
+    (
+        "JUP",
+        "Jupiter",
+        "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN",
+    ),
+    (
+        "BONK",
+        "Bonk",
+        "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263",
+    ),
+    (
+        "RAY",
+        "Raydium",
+        "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+    ),
+    (
+        "WIF",
+        "dogwifhat",
+        "EKpQGSJtjMFqKZ9KQanSqYXRcF8fBopzLHYxdM65zcjm",
+    ),
+];
+
+const HOMOGLYPH_IMPERSONATION_SCORE: f64 = 1.0;
+const NAME_REUSE_SCORE: f64 = 0.7;
+const LOOKALIKE_SCORE: f64 = 0.5;
+const MIXED_SCRIPT_SCORE: f64 = 0.3;
+/// Shorter terms are too easy to hit by accident with a one-character edit.
+const MIN_LOOKALIKE_TERM_LEN: usize = 4;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtectedToken {
+    pub symbol: String,
+    pub name: Option<String>,
+    pub mint: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetadataFindings {
+    pub score: f64,
+    pub impersonates: Option<String>,
+    pub reasons: Vec<String>,
+}
+
+impl MetadataFindings {
+    fn record(&mut self, score: f64, impersonates: Option<&str>, reason: String) {
+        if score > self.score {
+            self.score = score;
+            if let Some(symbol) = impersonates {
+                self.impersonates = Some(symbol.to_string());
+            }
+        }
+        self.reasons.push(reason);
+    }
+}
+
+/// Flags token names/symbols that copy or imitate well-known tokens, including
+/// lookalike characters from other scripts (Cyrillic `С` for `C`, `0` for `O`, ...).
+#[derive(Debug, Clone)]
+pub struct MetadataHeuristics {
+    protected: Vec<ProtectedToken>,
+}
+
+impl Default for MetadataHeuristics {
+    fn default() -> Self {
+        Self {
+            protected: DEFAULT_PROTECTED_TOKENS
+                .iter()
+                .map(|(symbol, name, mint)| ProtectedToken {
+                    symbol: symbol.to_string(),
+                    name: Some(name.to_string()),
+                    mint: Some(mint.to_string()),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl MetadataHeuristics {
+    /// Extends the defaults with `THREAT_PROTECTED_TOKENS` (`SYMBOL` or `SYMBOL=mint`,
+    /// comma-separated).
+    pub fn from_env() -> Self {
+        let mut heuristics = Self::default();
+
+        if let Ok(extra) = std::env::var("THREAT_PROTECTED_TOKENS") {
+            for entry in extra.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+                let (symbol, mint) = match entry.split_once('=') {
+                    Some((symbol, mint)) => (symbol.trim(), Some(mint.trim())),
+                    None => (entry, None),
+                };
+                heuristics = heuristics.with_protected_token(symbol, None, mint);
+            }
+        }
+
+        heuristics
+    }
+
+    pub fn with_protected_token(
+        mut self,
+        symbol: &str,
+        name: Option<&str>,
+        mint: Option<&str>,
+    ) -> Self {
+        self.protected
+            .retain(|t| !t.symbol.eq_ignore_ascii_case(symbol));
+        self.protected.push(ProtectedToken {
+            symbol: symbol.to_string(),
+            name: name.map(str::to_string),
+            mint: mint.filter(|m| !m.is_empty()).map(str::to_string),
+        });
+        self
+    }
+
+    pub fn check(&self, mint: &str, name: Option<&str>, symbol: Option<&str>) -> MetadataFindings {
+        let mut findings = MetadataFindings::default();
+        if self
+            .protected
+            .iter()
+            .any(|t| t.mint.as_deref() == Some(mint))
+        {
+            return findings;
+        }
+
+        let fields = [("symbol", symbol), ("name", name)];
+
+        for (field, value) in fields {
+            let Some(value) = value.filter(|v| !v.trim().is_empty()) else {
+                continue;
+            };
+
+            if has_mixed_script(value) {
+                findings.record(
+                    MIXED_SCRIPT_SCORE,
+                    None,
+                    format!("{} '{}' mixes Latin and lookalike characters", field, value),
+                );
+            }
+
+            let plain = ascii_key(value);
+            let folded = confusable_key(value);
+            if folded.is_empty() {
+                continue;
+            }
+
+            for token in &self.protected {
+                for term in std::iter::once(token.symbol.as_str()).chain(token.name.as_deref()) {
+                    let term_folded = confusable_key(term);
+
+                    if plain == ascii_key(term) {
+                        findings.record(
+                            NAME_REUSE_SCORE,
+                            Some(&token.symbol),
+                            format!(
+                                "{} '{}' reuses {} from a different mint",
+                                field, value, token.symbol
+                            ),
+                        );
+                    } else if folded == term_folded {
+                        findings.record(
+                            HOMOGLYPH_IMPERSONATION_SCORE,
+                            Some(&token.symbol),
+                            format!(
+                                "{} '{}' imitates {} with lookalike characters",
+                                field, value, token.symbol
+                            ),
+                        );
+                    } else if term_folded.chars().count() >= MIN_LOOKALIKE_TERM_LEN
+                        && edit_distance(&folded, &term_folded) == 1
+                    {
+                        findings.record(
+                            LOOKALIKE_SCORE,
+                            Some(&token.symbol),
+                            format!(
+                                "{} '{}' is one character off {}",
+                                field, value, token.symbol
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        findings
+    }
+}
+
+/// Lowercase ASCII letters and digits only; what the text literally says.
+fn ascii_key(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Collapses visually confusable characters onto one ASCII letter so lookalikes of
+/// a protected term compare equal to it.
+fn confusable_key(value: &str) -> String {
+    value.chars().filter_map(fold_confusable).collect()
+}
+
+fn fold_confusable(c: char) -> Option<char> {
+    // Fullwidth ASCII (U+FF01..U+FF5E) sits at a fixed offset from the real thing
+    if ('\u{FF01}'..='\u{FF5E}').contains(&c) {
+        return char::from_u32(c as u32 - 0xFEE0).and_then(fold_confusable);
+    }
+
+    let lower = c.to_lowercase().next().unwrap_or(c);
+    let folded = match lower {
+        'a'..='z' => lower,
+        '0' => 'o',
+        '1' | '|' => 'l',
+        '3' => 'e',
+        '4' | '@' => 'a',
+        '5' | '$' => 's',
+        '7' => 't',
+        '2' | '6' | '8' | '9' => lower,
+        // Cyrillic
+        'а' => 'a',
+        'в' => 'b',
+        'с' => 'c',
+        'е' | 'ё' => 'e',
+        'һ' | 'н' => 'h',
+        'і' | 'ї' => 'l',
+        'ј' => 'j',
+        'к' => 'k',
+        'м' => 'm',
+        'о' => 'o',
+        'р' => 'p',
+        'ԛ' => 'q',
+        'ѕ' => 's',
+        'т' => 't',
+        'у' => 'y',
+        'ԝ' => 'w',
+        'х' => 'x',
+        // Greek
+        'α' => 'a',
+        'β' => 'b',
+        'ε' => 'e',
+        'η' => 'n',
+        'ι' => 'l',
+        'κ' => 'k',
+        'ν' => 'v',
+        'ο' => 'o',
+        'ρ' => 'p',
+        'τ' => 't',
+        'υ' => 'u',
+        'χ' => 'x',
+        'ζ' => 'z',
+        _ => return None,
+    };
+
+    // `i` and `l` are indistinguishable in many fonts; fold both the same way
+    Some(if folded == 'i' { 'l' } else { folded })
+}
+
+fn has_mixed_script(value: &str) -> bool {
+    let has_latin = value.chars().any(|c| c.is_ascii_alphabetic());
+    let has_lookalike = value
+        .chars()
+        .any(|c| !c.is_ascii() && fold_confusable(c).is_some());
+    has_latin && has_lookalike
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ThreatFactors, ThreatScore};
+
+    const RANDOM_MINT: &str = "9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin";
+
+    fn score_with(findings: &MetadataFindings) -> f64 {
+        let factors = ThreatFactors {
+            metadata_impersonation_score: findings.score,
+            impersonates: findings.impersonates.clone(),
+            ..ThreatFactors::default()
+        };
+        ThreatScore::calculate(RANDOM_MINT.to_string(), factors).overall_score
+    }
+
+    #[test]
+    fn test_homoglyph_impersonation_raises_threat() {
+        let heuristics = MetadataHeuristics::default();
+
+        // Cyrillic 'С' in the symbol and a zero for the 'o' in the name
+        let findings = heuristics.check(RANDOM_MINT, Some("USD C0in"), Some("USDС"));
+
+        assert_eq!(findings.score, HOMOGLYPH_IMPERSONATION_SCORE);
+        assert_eq!(findings.impersonates.as_deref(), Some("USDC"));
+        assert!(findings
+            .reasons
+            .iter()
+            .any(|r| r.contains("lookalike characters")));
+
+        let legit = heuristics.check(RANDOM_MINT, Some("Moon Frog"), Some("MFROG"));
+        assert!(score_with(&findings) > score_with(&legit));
+    }
+
+    #[test]
+    fn test_legitimate_names_are_not_flagged() {
+        let heuristics = MetadataHeuristics::default();
+
+        let meme = heuristics.check(RANDOM_MINT, Some("Moon Frog"), Some("MFROG"));
+        assert_eq!(meme.score, 0.0);
+        assert!(meme.impersonates.is_none());
+        assert!(meme.reasons.is_empty());
+
+        // The real USDC mint carries the protected name legitimately
+        let real = heuristics.check(USDC_MINT, Some("USD Coin"), Some("USDC"));
+        assert_eq!(real.score, 0.0);
+    }
+
+    #[test]
+    fn test_plain_reuse_and_one_off_lookalikes() {
+        let heuristics = MetadataHeuristics::default();
+
+        let reuse = heuristics.check(RANDOM_MINT, None, Some("BONK"));
+        assert_eq!(reuse.score, NAME_REUSE_SCORE);
+        assert_eq!(reuse.impersonates.as_deref(), Some("BONK"));
+
+        let lookalike = heuristics.check(RANDOM_MINT, Some("Jupyter"), None);
+        assert_eq!(lookalike.score, LOOKALIKE_SCORE);
+
+        // Three-letter symbols are only flagged on an exact match
+        assert_eq!(heuristics.check(RANDOM_MINT, None, Some("RAYS")).score, 0.0);
+    }
+
+    #[test]
+    fn test_custom_protected_token() {
+        let heuristics =
+            MetadataHeuristics::default().with_protected_token("NULL", Some("NullBlock"), None);
+
+        let findings = heuristics.check(RANDOM_MINT, Some("NuIIBIock"), None);
+        assert_eq!(findings.score, HOMOGLYPH_IMPERSONATION_SCORE);
+        assert_eq!(findings.impersonates.as_deref(), Some("NULL"));
+    }
+}
@@ -1,20 +1,29 @@
 pub mod external;
+pub mod feed;
+pub mod metadata;
 
+use serde::{Deserialize, Serialize};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair};
 use std::collections::HashMap;
 use std::sync::RwLock;
 use uuid::Uuid;
 
 use crate::error::AppResult;
+use crate::events::{topics, AgentType, ArbEvent, EventSource};
 use crate::models::{
-    AlertSeverity, BlockedEntity, ScamAssociation, ThreatAlert, ThreatAlertType, ThreatCategory,
-    ThreatEntityType, ThreatFactors, ThreatScore, ThreatStats, WalletAnalysis, WatchedWallet,
-    WhitelistedEntity,
+    AlertSeverity, BlockedEntity, ScamAssociation, ThreatAlert, ThreatAlertType, ThreatAuditAction,
+    ThreatAuditEntry, ThreatCategory, ThreatEntityType, ThreatFactors, ThreatScore, ThreatStats,
+    WalletAnalysis, WatchedWallet, WhitelistedEntity,
 };
 
 pub use external::{
     BirdeyeClient, GoPlusAnalysis, GoPlusClient, HolderAnalysis, RugCheckAnalysis, RugCheckClient,
     WashTradingAnalysis,
 };
+pub use feed::{
+    BlocklistFeed, BlocklistFeedConfig, BlocklistFeedEntry, BlocklistMerge, SignedBlocklistFeed,
+};
+pub use metadata::{MetadataFindings, MetadataHeuristics};
 
 lazy_static::lazy_static! {
     static ref BLOCKED_STORE: RwLock<HashMap<String, BlockedEntity>> = RwLock::new(HashMap::new());
@@ -23,12 +32,203 @@ lazy_static::lazy_static! {
     static ref ALERTS_STORE: RwLock<Vec<ThreatAlert>> = RwLock::new(Vec::new());
     static ref SCORE_CACHE: RwLock<HashMap<String, ThreatScore>> = RwLock::new(HashMap::new());
     static ref WALLET_ANALYSIS_CACHE: RwLock<HashMap<String, WalletAnalysis>> = RwLock::new(HashMap::new());
+    static ref AUDIT_STORE: RwLock<Vec<ThreatAuditEntry>> = RwLock::new(Vec::new());
+}
+
+/// Most recent cached analysis for a mint, without triggering a fresh external check.
+pub fn cached_score(mint: &str) -> Option<ThreatScore> {
+    SCORE_CACHE
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(mint)
+        .cloned()
+}
+
+pub const DEFAULT_ALERT_THRESHOLD: f64 = 0.7;
+pub const DEFAULT_AUTO_BLOCK_THRESHOLD: f64 = 0.85;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ThreatAutoAction {
+    AlertOnly,
+    AutoBlock,
+}
+
+/// What `check_token` does with a freshly computed score.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreatResponseConfig {
+    pub action: ThreatAutoAction,
+    pub alert_threshold: f64,
+    pub auto_block_threshold: f64,
+}
+
+impl Default for ThreatResponseConfig {
+    fn default() -> Self {
+        Self {
+            action: ThreatAutoAction::AutoBlock,
+            alert_threshold: DEFAULT_ALERT_THRESHOLD,
+            auto_block_threshold: DEFAULT_AUTO_BLOCK_THRESHOLD,
+        }
+    }
+}
+
+impl ThreatResponseConfig {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let action = match std::env::var("THREAT_AUTO_ACTION").ok().as_deref() {
+            Some("alert_only") => ThreatAutoAction::AlertOnly,
+            Some("auto_block") => ThreatAutoAction::AutoBlock,
+            _ => defaults.action,
+        };
+        let auto_block_threshold = std::env::var("THREAT_AUTO_BLOCK_THRESHOLD")
+            .ok()
+            .and_then(|v| v.parse::<f64>().ok())
+            .filter(|v| *v > 0.0 && *v <= 1.0)
+            .unwrap_or(defaults.auto_block_threshold);
+
+        Self {
+            action,
+            auto_block_threshold,
+            ..defaults
+        }
+    }
+}
+
+/// Whether an external provider is queried, and whether losing it is worth flagging.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ThreatProviderSetting {
+    pub enabled: bool,
+    pub required: bool,
+}
+
+impl ThreatProviderSetting {
+    fn from_env(prefix: &str, required: bool) -> Self {
+        let flag = |suffix: &str, default: bool| {
+            std::env::var(format!("{}_{}", prefix, suffix))
+                .ok()
+                .map(|v| v != "0" && v.to_lowercase() != "false")
+                .unwrap_or(default)
+        };
+        Self {
+            enabled: flag("ENABLED", true),
+            required: flag("REQUIRED", required),
+        }
+    }
+}
+
+/// Disabled providers are skipped entirely. Scores computed without a disabled
+/// provider, or without a required one that failed to answer, note reduced coverage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreatProviderConfig {
+    pub rugcheck: ThreatProviderSetting,
+    pub goplus: ThreatProviderSetting,
+    pub birdeye: ThreatProviderSetting,
+}
+
+impl Default for ThreatProviderConfig {
+    fn default() -> Self {
+        Self {
+            rugcheck: ThreatProviderSetting {
+                enabled: true,
+                required: true,
+            },
+            goplus: ThreatProviderSetting {
+                enabled: true,
+                required: true,
+            },
+            birdeye: ThreatProviderSetting {
+                enabled: true,
+                required: false,
+            },
+        }
+    }
+}
+
+impl ThreatProviderConfig {
+    /// `THREAT_<PROVIDER>_ENABLED` / `THREAT_<PROVIDER>_REQUIRED` for RUGCHECK, GOPLUS
+    /// and BIRDEYE.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            rugcheck: ThreatProviderSetting::from_env(
+                "THREAT_RUGCHECK",
+                defaults.rugcheck.required,
+            ),
+            goplus: ThreatProviderSetting::from_env("THREAT_GOPLUS", defaults.goplus.required),
+            birdeye: ThreatProviderSetting::from_env("THREAT_BIRDEYE", defaults.birdeye.required),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProviderCoverage {
+    pub disabled: Vec<String>,
+    pub unavailable: Vec<String>,
+}
+
+impl ProviderCoverage {
+    fn skip_or_miss(&mut self, name: &str, setting: ThreatProviderSetting, answered: bool) {
+        if !setting.enabled {
+            self.disabled.push(name.to_string());
+        } else if !answered && setting.required {
+            self.unavailable.push(name.to_string());
+        }
+    }
+
+    pub fn is_reduced(&self) -> bool {
+        !self.disabled.is_empty() || !self.unavailable.is_empty()
+    }
+
+    fn note(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.disabled.is_empty() {
+            parts.push(format!("{} disabled", self.disabled.join(", ")));
+        }
+        if !self.unavailable.is_empty() {
+            parts.push(format!("{} unavailable", self.unavailable.join(", ")));
+        }
+        format!("reduced coverage: {}", parts.join("; "))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ThreatResponse {
+    None,
+    Alerted(ThreatAlert),
+    AutoBlocked {
+        entity: BlockedEntity,
+        audit: ThreatAuditEntry,
+    },
+}
+
+impl ThreatResponse {
+    pub fn to_event(&self) -> Option<ArbEvent> {
+        let ThreatResponse::AutoBlocked { entity, .. } = self else {
+            return None;
+        };
+        Some(ArbEvent::new(
+            "threat.auto_blocked",
+            EventSource::Agent(AgentType::ThreatDetector),
+            topics::threat::BLOCKED,
+            serde_json::json!({
+                "address": entity.address,
+                "entity_type": entity.entity_type,
+                "category": entity.threat_category,
+                "score": entity.threat_score,
+                "reason": entity.reason,
+                "auto": true,
+            }),
+        ))
+    }
 }
 
 pub struct ThreatDetector {
     rugcheck: RugCheckClient,
     goplus: GoPlusClient,
     birdeye: BirdeyeClient,
+    response_config: ThreatResponseConfig,
+    provider_config: ThreatProviderConfig,
+    metadata_heuristics: MetadataHeuristics,
 }
 
 impl ThreatDetector {
@@ -42,10 +242,24 @@ impl ThreatDetector {
             rugcheck: RugCheckClient::new(rugcheck_url),
             goplus: GoPlusClient::new(goplus_url),
             birdeye: BirdeyeClient::new(birdeye_url, birdeye_key),
+            response_config: ThreatResponseConfig::from_env(),
+            provider_config: ThreatProviderConfig::from_env(),
+            metadata_heuristics: MetadataHeuristics::from_env(),
         }
     }
 
-    pub async fn check_token(&self, mint: &str) -> AppResult<ThreatScore> {
+    pub fn with_provider_config(mut self, config: ThreatProviderConfig) -> Self {
+        self.provider_config = config;
+        self
+    }
+
+    pub fn provider_config(&self) -> &ThreatProviderConfig {
+        &self.provider_config
+    }
+
+    /// Returns the score together with any alert or auto-block a fresh score triggered,
+    /// so the caller can persist the audit entry and publish the event.
+    pub async fn check_token(&self, mint: &str) -> AppResult<(ThreatScore, ThreatResponse)> {
         if let Some(cached) = SCORE_CACHE
             .read()
             .unwrap_or_else(|e| e.into_inner())
@@ -53,7 +267,7 @@ impl ThreatDetector {
         {
             let age = chrono::Utc::now() - cached.created_at;
             if age.num_minutes() < 5 {
-                return Ok(cached.clone());
+                return Ok((cached.clone(), ThreatResponse::None));
             }
         }
 
@@ -64,7 +278,7 @@ impl ThreatDetector {
         {
             let factors = ThreatFactors::default();
             let score = ThreatScore::calculate(mint.to_string(), factors);
-            return Ok(score);
+            return Ok((score, ThreatResponse::None));
         }
 
         if let Some(blocked) = BLOCKED_STORE
@@ -79,13 +293,28 @@ impl ThreatDetector {
             score.overall_score = blocked.threat_score.unwrap_or(1.0);
             score.risk_level = AlertSeverity::Critical;
             score.recommendation = format!("BLOCKED: {}", blocked.reason);
-            return Ok(score);
+            return Ok((score, ThreatResponse::None));
         }
 
         let mut factors = ThreatFactors::default();
         let mut external_data = serde_json::json!({});
+        let mut token_name: Option<String> = None;
+        let mut token_symbol: Option<String> = None;
+
+        let providers = &self.provider_config;
+        let mut coverage = ProviderCoverage::default();
 
-        if let Ok(rugcheck_response) = self.rugcheck.check_token(mint).await {
+        let rugcheck_response = if providers.rugcheck.enabled {
+            self.rugcheck.check_token(mint).await.ok()
+        } else {
+            None
+        };
+        coverage.skip_or_miss("rugcheck", providers.rugcheck, rugcheck_response.is_some());
+        if let Some(rugcheck_response) = rugcheck_response {
+            if let Some(meta) = &rugcheck_response.token_meta {
+                token_name = meta.name.clone();
+                token_symbol = meta.symbol.clone();
+            }
             let analysis = self.rugcheck.analyze_risks(&rugcheck_response);
             factors.has_mint_authority = analysis.has_mint_authority;
             factors.has_freeze_authority = analysis.has_freeze_authority;
@@ -94,7 +323,13 @@ impl ThreatDetector {
             external_data["rugcheck"] = serde_json::to_value(&analysis).unwrap_or_default();
         }
 
-        if let Ok(goplus_info) = self.goplus.check_token(mint).await {
+        let goplus_info = if providers.goplus.enabled {
+            self.goplus.check_token(mint).await.ok()
+        } else {
+            None
+        };
+        coverage.skip_or_miss("goplus", providers.goplus, goplus_info.is_some());
+        if let Some(goplus_info) = goplus_info {
             let analysis = self.goplus.analyze(&goplus_info);
             factors.goplus_honeypot = Some(analysis.is_honeypot);
             factors.goplus_is_blacklisted = Some(analysis.is_blacklisted);
@@ -105,8 +340,16 @@ impl ThreatDetector {
             external_data["goplus"] = serde_json::to_value(&analysis).unwrap_or_default();
         }
 
-        if let Ok(holders) = self.birdeye.get_holders(mint, 50).await {
+        let holders = if providers.birdeye.enabled {
+            self.birdeye.get_holders(mint, 50).await.ok()
+        } else {
+            None
+        };
+        coverage.skip_or_miss("birdeye", providers.birdeye, holders.is_some());
+        if let Some(holders) = holders {
             if let Ok(token_info) = self.birdeye.get_token_info(mint).await {
+                token_name = token_name.or_else(|| token_info.name.clone());
+                token_symbol = token_symbol.or_else(|| token_info.symbol.clone());
                 let supply = token_info.supply.unwrap_or(1.0);
                 let holder_analysis = self.birdeye.analyze_holders(&holders, supply);
                 if factors.top_10_concentration == 0.0 {
@@ -124,29 +367,69 @@ impl ThreatDetector {
             }
         }
 
+        let metadata_findings =
+            self.metadata_heuristics
+                .check(mint, token_name.as_deref(), token_symbol.as_deref());
+        if metadata_findings.score > 0.0 {
+            factors.metadata_impersonation_score = metadata_findings.score;
+            factors.impersonates = metadata_findings.impersonates.clone();
+            external_data["metadata"] =
+                serde_json::to_value(&metadata_findings).unwrap_or_default();
+        }
+
+        if coverage.is_reduced() {
+            external_data["coverage"] = serde_json::to_value(&coverage).unwrap_or_default();
+        }
+
         let mut score = ThreatScore::calculate(mint.to_string(), factors);
         score.external_data = external_data;
+        if coverage.is_reduced() {
+            score.recommendation = format!("{} ({})", score.recommendation, coverage.note());
+        }
 
         SCORE_CACHE
             .write()
             .unwrap_or_else(|e| e.into_inner())
             .insert(mint.to_string(), score.clone());
 
-        if score.overall_score >= 0.7 {
-            self.create_alert(
+        let response = self.respond_to_score(&score);
+        Ok((score, response))
+    }
+
+    /// Auto-blocks tokens at or above `auto_block_threshold` when configured to,
+    /// otherwise raises an alert once the score crosses `alert_threshold`.
+    pub fn respond_to_score(&self, score: &ThreatScore) -> ThreatResponse {
+        let config = &self.response_config;
+
+        if config.action == ThreatAutoAction::AutoBlock
+            && score.overall_score >= config.auto_block_threshold
+        {
+            let (entity, audit) = self.block_entity(
+                ThreatEntityType::Token,
+                score.token_mint.clone(),
+                infer_category(&score.factors),
+                auto_block_reason(score, config.auto_block_threshold),
+                "auto_block".to_string(),
+            );
+            return ThreatResponse::AutoBlocked { entity, audit };
+        }
+
+        if score.overall_score >= config.alert_threshold {
+            let alert = self.create_alert(
                 ThreatAlertType::SuspiciousActivity,
                 AlertSeverity::High,
                 ThreatEntityType::Token,
-                mint.to_string(),
+                score.token_mint.clone(),
                 serde_json::json!({
                     "score": score.overall_score,
                     "recommendation": &score.recommendation
                 }),
                 "flagged".to_string(),
             );
+            return ThreatResponse::Alerted(alert);
         }
 
-        Ok(score)
+        ThreatResponse::None
     }
 
     pub async fn check_wallet(&self, address: &str) -> AppResult<WalletAnalysis> {
@@ -207,28 +490,31 @@ impl ThreatDetector {
         category: ThreatCategory,
         reason: String,
         reported_by: String,
-    ) -> BlockedEntity {
+    ) -> (BlockedEntity, ThreatAuditEntry) {
+        let prior_score = self.prior_score(&address);
         let mut entity = BlockedEntity::new(
             entity_type.clone(),
             address.clone(),
             category,
             reason.clone(),
-            reported_by,
+            reported_by.clone(),
         );
-
-        if let Some(cached) = SCORE_CACHE
-            .read()
-            .unwrap_or_else(|e| e.into_inner())
-            .get(&address)
-        {
-            entity.threat_score = Some(cached.overall_score);
-        }
+        entity.threat_score = prior_score;
 
         BLOCKED_STORE
             .write()
             .unwrap_or_else(|e| e.into_inner())
             .insert(address.clone(), entity.clone());
 
+        let audit = self.record_audit(ThreatAuditEntry::new(
+            ThreatAuditAction::Blocked,
+            Some(entity_type.clone()),
+            address.clone(),
+            reported_by,
+            Some(reason.clone()),
+            prior_score,
+        ));
+
         self.create_alert(
             ThreatAlertType::SuspiciousActivity,
             AlertSeverity::High,
@@ -238,7 +524,7 @@ impl ThreatDetector {
             "blocked".to_string(),
         );
 
-        entity
+        (entity, audit)
     }
 
     pub fn whitelist_entity(
@@ -247,8 +533,14 @@ impl ThreatDetector {
         address: String,
         reason: String,
         whitelisted_by: String,
-    ) -> WhitelistedEntity {
-        let entity = WhitelistedEntity::new(entity_type, address.clone(), reason, whitelisted_by);
+    ) -> (WhitelistedEntity, ThreatAuditEntry) {
+        let prior_score = self.prior_score(&address);
+        let entity = WhitelistedEntity::new(
+            entity_type.clone(),
+            address.clone(),
+            reason.clone(),
+            whitelisted_by.clone(),
+        );
         WHITELIST_STORE
             .write()
             .unwrap_or_else(|e| e.into_inner())
@@ -257,7 +549,17 @@ impl ThreatDetector {
             .write()
             .unwrap_or_else(|e| e.into_inner())
             .remove(&address);
-        entity
+
+        let audit = self.record_audit(ThreatAuditEntry::new(
+            ThreatAuditAction::Whitelisted,
+            Some(entity_type),
+            address,
+            whitelisted_by,
+            Some(reason),
+            prior_score,
+        ));
+
+        (entity, audit)
     }
 
     pub fn add_watched_wallet(&self, wallet: WatchedWallet) -> WatchedWallet {
@@ -411,23 +713,200 @@ impl ThreatDetector {
             .contains_key(address)
     }
 
-    pub fn remove_from_blocklist(&self, address: &str) -> bool {
-        BLOCKED_STORE
+    pub fn remove_from_blocklist(
+        &self,
+        address: &str,
+        actor: String,
+        reason: Option<String>,
+    ) -> Option<ThreatAuditEntry> {
+        let prior_score = self.prior_score(address);
+        let removed = BLOCKED_STORE
             .write()
             .unwrap_or_else(|e| e.into_inner())
-            .remove(address)
-            .is_some()
+            .remove(address)?;
+
+        Some(self.record_audit(ThreatAuditEntry::new(
+            ThreatAuditAction::Unblocked,
+            Some(removed.entity_type),
+            address.to_string(),
+            actor,
+            reason,
+            prior_score,
+        )))
     }
 
-    pub fn remove_from_whitelist(&self, address: &str) -> bool {
-        WHITELIST_STORE
+    pub fn remove_from_whitelist(
+        &self,
+        address: &str,
+        actor: String,
+        reason: Option<String>,
+    ) -> Option<ThreatAuditEntry> {
+        let prior_score = self.prior_score(address);
+        let removed = WHITELIST_STORE
             .write()
             .unwrap_or_else(|e| e.into_inner())
-            .remove(address)
-            .is_some()
+            .remove(address)?;
+
+        Some(self.record_audit(ThreatAuditEntry::new(
+            ThreatAuditAction::Unwhitelisted,
+            Some(removed.entity_type),
+            address.to_string(),
+            actor,
+            reason,
+            prior_score,
+        )))
+    }
+
+    /// Signed snapshot of the locally blocked entities for other instances to import.
+    pub fn export_blocklist(
+        &self,
+        source: String,
+        keypair: &Keypair,
+    ) -> AppResult<SignedBlocklistFeed> {
+        let feed = BlocklistFeed::from_entities(
+            source,
+            BLOCKED_STORE
+                .read()
+                .unwrap_or_else(|e| e.into_inner())
+                .values(),
+        );
+        SignedBlocklistFeed::sign(&feed, keypair)
+    }
+
+    /// Verifies a feed against `trusted_signers` and merges it, auditing each entry added.
+    pub fn import_blocklist(
+        &self,
+        signed: &SignedBlocklistFeed,
+        trusted_signers: &[Pubkey],
+    ) -> AppResult<(BlocklistMerge, Vec<ThreatAuditEntry>)> {
+        let feed = signed.verify(trusted_signers)?;
+        let actor = format!("feed:{}", feed.source);
+
+        let merge = {
+            let mut blocked = BLOCKED_STORE.write().unwrap_or_else(|e| e.into_inner());
+            let whitelist = WHITELIST_STORE.read().unwrap_or_else(|e| e.into_inner());
+            feed::merge_feed(
+                &mut blocked,
+                |address| whitelist.contains_key(address),
+                feed,
+                &signed.signer,
+            )
+        };
+
+        let audits = merge
+            .imported
+            .iter()
+            .map(|entity| {
+                self.record_audit(ThreatAuditEntry::new(
+                    ThreatAuditAction::Imported,
+                    Some(entity.entity_type.clone()),
+                    entity.address.clone(),
+                    actor.clone(),
+                    Some(entity.reason.clone()),
+                    entity.threat_score,
+                ))
+            })
+            .collect();
+
+        Ok((merge, audits))
+    }
+
+    pub fn get_audit_log(&self, address: Option<&str>, limit: usize) -> Vec<ThreatAuditEntry> {
+        let store = AUDIT_STORE.read().unwrap_or_else(|e| e.into_inner());
+        store
+            .iter()
+            .rev()
+            .filter(|e| address.map_or(true, |a| e.address == a))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// Last known score for an address: a cached analysis wins, otherwise whatever
+    /// score was captured when it was blocked.
+    fn prior_score(&self, address: &str) -> Option<f64> {
+        if let Some(cached) = SCORE_CACHE
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(address)
+        {
+            return Some(cached.overall_score);
+        }
+        BLOCKED_STORE
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(address)
+            .and_then(|b| b.threat_score)
+    }
+
+    fn record_audit(&self, entry: ThreatAuditEntry) -> ThreatAuditEntry {
+        let mut store = AUDIT_STORE.write().unwrap_or_else(|e| e.into_inner());
+        store.push(entry.clone());
+        const MAX_AUDIT_ENTRIES: usize = 10_000;
+        if store.len() > MAX_AUDIT_ENTRIES {
+            let drain_count = store.len() - MAX_AUDIT_ENTRIES;
+            store.drain(..drain_count);
+        }
+        entry
+    }
+}
+
+fn infer_category(factors: &ThreatFactors) -> ThreatCategory {
+    if factors.goplus_honeypot == Some(true) {
+        ThreatCategory::Honeypot
+    } else if factors.goplus_is_blacklisted == Some(true) || factors.has_blacklist {
+        ThreatCategory::MaliciousContract
+    } else if factors.wash_trade_likelihood >= 0.5 {
+        ThreatCategory::WashTrader
+    } else {
+        ThreatCategory::RugPull
     }
 }
 
+fn auto_block_reason(score: &ThreatScore, threshold: f64) -> String {
+    let factors = &score.factors;
+    let mut signals = Vec::new();
+    if factors.goplus_honeypot == Some(true) {
+        signals.push("honeypot".to_string());
+    }
+    if factors.goplus_is_blacklisted == Some(true) {
+        signals.push("blacklisted".to_string());
+    }
+    if factors.has_mint_authority {
+        signals.push("mint authority".to_string());
+    }
+    if factors.has_freeze_authority {
+        signals.push("freeze authority".to_string());
+    }
+    if factors.top_10_concentration > 0.5 {
+        signals.push(format!(
+            "top 10 hold {:.0}%",
+            factors.top_10_concentration * 100.0
+        ));
+    }
+    if factors.creator_holdings_percent > 0.1 {
+        signals.push(format!(
+            "creator holds {:.0}%",
+            factors.creator_holdings_percent * 100.0
+        ));
+    }
+    if factors.wash_trade_likelihood >= 0.5 {
+        signals.push("wash trading".to_string());
+    }
+    if let Some(symbol) = &factors.impersonates {
+        signals.push(format!("impersonates {}", symbol));
+    }
+
+    let mut reason = format!(
+        "Auto-blocked: threat score {:.2} >= {:.2}",
+        score.overall_score, threshold
+    );
+    if !signals.is_empty() {
+        reason.push_str(&format!(" ({})", signals.join(", ")));
+    }
+    reason
+}
+
 impl Default for ThreatDetector {
     fn default() -> Self {
         Self::new(
@@ -438,3 +917,399 @@ impl Default for ThreatDetector {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn seed_score(address: &str, overall_score: f64) {
+        let mut score = ThreatScore::calculate(address.to_string(), ThreatFactors::default());
+        score.overall_score = overall_score;
+        SCORE_CACHE
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(address.to_string(), score);
+    }
+
+    #[test]
+    fn test_block_then_whitelist_records_audit_with_prior_score() {
+        let detector = ThreatDetector::default();
+        let address = "AuditBypassMint111111111111111111111111111";
+        seed_score(address, 0.92);
+
+        let (blocked, block_audit) = detector.block_entity(
+            ThreatEntityType::Token,
+            address.to_string(),
+            ThreatCategory::RugPull,
+            "dev dumped".to_string(),
+            "scanner".to_string(),
+        );
+        assert_eq!(blocked.threat_score, Some(0.92));
+        assert_eq!(block_audit.action, ThreatAuditAction::Blocked);
+        assert_eq!(block_audit.actor, "scanner");
+        assert_eq!(block_audit.prior_score, Some(0.92));
+
+        let (_, whitelist_audit) = detector.whitelist_entity(
+            ThreatEntityType::Token,
+            address.to_string(),
+            "false positive, verified team".to_string(),
+            "operator".to_string(),
+        );
+        assert!(!detector.is_blocked(address));
+        assert_eq!(whitelist_audit.action, ThreatAuditAction::Whitelisted);
+        assert_eq!(whitelist_audit.actor, "operator");
+        assert_eq!(
+            whitelist_audit.reason.as_deref(),
+            Some("false positive, verified team")
+        );
+        assert_eq!(whitelist_audit.prior_score, Some(0.92));
+
+        let log = detector.get_audit_log(Some(address), 10);
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[0].action, ThreatAuditAction::Whitelisted);
+        assert_eq!(log[1].action, ThreatAuditAction::Blocked);
+    }
+
+    #[test]
+    fn test_whitelist_of_blocked_entity_uses_blocked_score() {
+        let detector = ThreatDetector::default();
+        let address = "AuditBlockedWallet11111111111111111111111";
+        seed_score(address, 0.75);
+        detector.block_entity(
+            ThreatEntityType::Wallet,
+            address.to_string(),
+            ThreatCategory::ScamWallet,
+            "serial rugger".to_string(),
+            "user_report".to_string(),
+        );
+        SCORE_CACHE
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(address);
+
+        let (_, audit) = detector.whitelist_entity(
+            ThreatEntityType::Wallet,
+            address.to_string(),
+            "manual override".to_string(),
+            "operator".to_string(),
+        );
+        assert_eq!(audit.prior_score, Some(0.75));
+    }
+
+    #[test]
+    fn test_removals_are_audited_only_when_something_was_removed() {
+        let detector = ThreatDetector::default();
+        let address = "AuditRemovalMint11111111111111111111111111";
+
+        assert!(detector
+            .remove_from_blocklist(address, "operator".to_string(), None)
+            .is_none());
+
+        detector.block_entity(
+            ThreatEntityType::Token,
+            address.to_string(),
+            ThreatCategory::Honeypot,
+            "cannot sell".to_string(),
+            "scanner".to_string(),
+        );
+        let unblock = detector
+            .remove_from_blocklist(address, "operator".to_string(), Some("retest".to_string()))
+            .unwrap();
+        assert_eq!(unblock.action, ThreatAuditAction::Unblocked);
+        assert_eq!(unblock.entity_type, Some(ThreatEntityType::Token));
+        assert_eq!(unblock.prior_score, None);
+
+        detector.whitelist_entity(
+            ThreatEntityType::Token,
+            address.to_string(),
+            "trusted".to_string(),
+            "operator".to_string(),
+        );
+        let unwhitelist = detector
+            .remove_from_whitelist(address, "operator".to_string(), None)
+            .unwrap();
+        assert_eq!(unwhitelist.action, ThreatAuditAction::Unwhitelisted);
+
+        let actions: Vec<_> = detector
+            .get_audit_log(Some(address), 10)
+            .into_iter()
+            .map(|e| e.action)
+            .collect();
+        assert_eq!(
+            actions,
+            vec![
+                ThreatAuditAction::Unwhitelisted,
+                ThreatAuditAction::Whitelisted,
+                ThreatAuditAction::Unblocked,
+                ThreatAuditAction::Blocked,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_blocklist_export_imports_into_another_detector() {
+        use crate::models::BlocklistProvenance;
+        use solana_sdk::signature::Signer;
+
+        let exporter = ThreatDetector::default();
+        let importer = ThreatDetector::default();
+        let keypair = Keypair::new();
+        let exported_address = "FeedExportMint1111111111111111111111111111";
+        let local_address = "FeedLocalMint11111111111111111111111111111";
+
+        exporter.block_entity(
+            ThreatEntityType::Token,
+            exported_address.to_string(),
+            ThreatCategory::RugPull,
+            "liquidity pulled".to_string(),
+            "scanner".to_string(),
+        );
+        let signed = exporter
+            .export_blocklist("node-a".to_string(), &keypair)
+            .unwrap();
+        let feed = signed.verify(&[keypair.pubkey()]).unwrap();
+        assert!(feed.entries.iter().any(|e| e.address == exported_address));
+
+        // Stand in for a fresh instance: the importer only has its own local entry
+        exporter.remove_from_blocklist(exported_address, "operator".to_string(), None);
+        importer.block_entity(
+            ThreatEntityType::Token,
+            local_address.to_string(),
+            ThreatCategory::Honeypot,
+            "cannot sell".to_string(),
+            "operator".to_string(),
+        );
+        // Other tests share the store, so keep only this test's entry plus one that
+        // collides with the importer's local block
+        let exported = feed
+            .entries
+            .iter()
+            .find(|e| e.address == exported_address)
+            .cloned()
+            .unwrap();
+        let colliding = BlocklistFeedEntry {
+            address: local_address.to_string(),
+            ..exported.clone()
+        };
+        let shared = BlocklistFeed {
+            entries: vec![exported, colliding],
+            ..feed
+        };
+        let signed = SignedBlocklistFeed::sign(&shared, &keypair).unwrap();
+
+        assert!(importer.import_blocklist(&signed, &[]).is_err());
+        assert!(!importer.is_blocked(exported_address));
+
+        let (merge, audits) = importer
+            .import_blocklist(&signed, &[keypair.pubkey()])
+            .unwrap();
+        assert_eq!(merge.imported.len(), 1);
+        assert_eq!(merge.already_blocked, 1);
+        assert!(importer.is_blocked(exported_address));
+        assert_eq!(audits[0].action, ThreatAuditAction::Imported);
+        assert_eq!(audits[0].actor, "feed:node-a");
+
+        let blocked = importer.get_blocked(None, usize::MAX);
+        let provenance = |address: &str| {
+            blocked
+                .iter()
+                .find(|e| e.address == address)
+                .map(|e| e.provenance.clone())
+                .unwrap()
+        };
+        assert!(matches!(
+            provenance(exported_address),
+            BlocklistProvenance::Imported { ref feed_source, .. } if feed_source == "node-a"
+        ));
+        assert_eq!(provenance(local_address), BlocklistProvenance::Local);
+
+        // Imported entries are not re-exported
+        let reexported = importer
+            .export_blocklist("node-b".to_string(), &keypair)
+            .unwrap()
+            .verify(&[keypair.pubkey()])
+            .unwrap();
+        assert!(reexported
+            .entries
+            .iter()
+            .all(|e| e.address != exported_address));
+
+        let (again, _) = importer
+            .import_blocklist(&signed, &[keypair.pubkey()])
+            .unwrap();
+        assert!(again.imported.is_empty());
+        assert_eq!(again.already_blocked, 2);
+    }
+
+    fn honeypot_score(mint: &str) -> ThreatScore {
+        let factors = ThreatFactors {
+            goplus_honeypot: Some(true),
+            has_mint_authority: true,
+            has_freeze_authority: true,
+            top_10_concentration: 0.8,
+            ..ThreatFactors::default()
+        };
+        ThreatScore::calculate(mint.to_string(), factors)
+    }
+
+    fn detector_with_action(action: ThreatAutoAction) -> ThreatDetector {
+        let mut detector = ThreatDetector::default();
+        detector.response_config = ThreatResponseConfig {
+            action,
+            ..ThreatResponseConfig::default()
+        };
+        detector
+    }
+
+    #[test]
+    fn test_score_above_threshold_is_auto_blocked_when_enabled() {
+        let detector = detector_with_action(ThreatAutoAction::AutoBlock);
+        let mint = "AutoBlockMint1111111111111111111111111111";
+        let score = honeypot_score(mint);
+        assert!(score.overall_score >= DEFAULT_AUTO_BLOCK_THRESHOLD);
+
+        let response = detector.respond_to_score(&score);
+        let ThreatResponse::AutoBlocked { entity, audit } = &response else {
+            panic!("expected auto-block, got {:?}", response);
+        };
+        assert!(detector.is_blocked(mint));
+        assert_eq!(entity.threat_category, ThreatCategory::Honeypot);
+        assert!(entity.reason.contains("honeypot"));
+        assert_eq!(audit.action, ThreatAuditAction::Blocked);
+        assert_eq!(audit.actor, "auto_block");
+
+        let event = response.to_event().unwrap();
+        assert_eq!(event.topic, topics::threat::BLOCKED);
+        assert_eq!(event.payload["address"], mint);
+    }
+
+    #[test]
+    fn test_score_above_threshold_only_alerted_when_disabled() {
+        let detector = detector_with_action(ThreatAutoAction::AlertOnly);
+        let mint = "AlertOnlyMint1111111111111111111111111111";
+
+        let response = detector.respond_to_score(&honeypot_score(mint));
+        let ThreatResponse::Alerted(alert) = &response else {
+            panic!("expected alert, got {:?}", response);
+        };
+        assert_eq!(alert.action_taken, "flagged");
+        assert!(!detector.is_blocked(mint));
+        assert!(response.to_event().is_none());
+
+        let benign = ThreatScore::calculate(mint.to_string(), ThreatFactors::default());
+        assert!(matches!(
+            detector.respond_to_score(&benign),
+            ThreatResponse::None
+        ));
+    }
+
+    #[derive(Default)]
+    struct ProviderHits {
+        rugcheck: AtomicUsize,
+        goplus: AtomicUsize,
+        birdeye: AtomicUsize,
+    }
+
+    /// Serves all three providers under `/rugcheck`, `/goplus` and `/birdeye`. RugCheck
+    /// reports a mint authority; the others answer with errors.
+    async fn spawn_mock_providers() -> (String, Arc<ProviderHits>) {
+        let hits = Arc::new(ProviderHits::default());
+        let counters = Arc::clone(&hits);
+        let app = axum::Router::new().fallback(move |uri: axum::http::Uri| {
+            let path = uri.path();
+            let reply = if path.starts_with("/rugcheck") {
+                counters.rugcheck.fetch_add(1, Ordering::SeqCst);
+                let mint = path.split('/').nth(3).unwrap_or_default();
+                let report = serde_json::json!({
+                    "mint": mint,
+                    "score": 40.0,
+                    "risks": [{
+                        "name": "Mint Authority still enabled",
+                        "description": "",
+                        "level": "danger",
+                        "score": 10.0
+                    }],
+                    "token_meta": null,
+                    "creator": null,
+                    "top_holders": [],
+                    "markets": []
+                });
+                (axum::http::StatusCode::OK, axum::Json(report))
+            } else {
+                if path.starts_with("/goplus") {
+                    counters.goplus.fetch_add(1, Ordering::SeqCst);
+                } else {
+                    counters.birdeye.fetch_add(1, Ordering::SeqCst);
+                }
+                (
+                    axum::http::StatusCode::SERVICE_UNAVAILABLE,
+                    axum::Json(serde_json::json!({})),
+                )
+            };
+            async move { reply }
+        });
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (base, hits)
+    }
+
+    fn detector_against(base: &str, providers: ThreatProviderConfig) -> ThreatDetector {
+        ThreatDetector::new(
+            format!("{}/rugcheck", base),
+            format!("{}/goplus", base),
+            format!("{}/birdeye", base),
+            None,
+        )
+        .with_provider_config(providers)
+    }
+
+    #[tokio::test]
+    async fn test_disabled_provider_is_not_called() {
+        let (base, hits) = spawn_mock_providers().await;
+        let mut providers = ThreatProviderConfig::default();
+        providers.goplus.enabled = false;
+        providers.birdeye.enabled = false;
+        let detector = detector_against(&base, providers);
+        let mint = "ProviderToggleMint11111111111111111111111";
+
+        let (score, _) = detector.check_token(mint).await.unwrap();
+
+        assert_eq!(hits.rugcheck.load(Ordering::SeqCst), 1);
+        assert_eq!(hits.goplus.load(Ordering::SeqCst), 0);
+        assert_eq!(hits.birdeye.load(Ordering::SeqCst), 0);
+
+        // Still scored from RugCheck alone
+        assert!(score.factors.has_mint_authority);
+        assert_eq!(score.factors.rugcheck_score, Some(0.4));
+        assert!(score.factors.goplus_honeypot.is_none());
+        assert!(score.overall_score > 0.0);
+        assert!(score
+            .recommendation
+            .contains("reduced coverage: goplus, birdeye disabled"));
+        assert_eq!(
+            score.external_data["coverage"]["disabled"],
+            serde_json::json!(["goplus", "birdeye"])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_only_required_provider_failures_reduce_coverage() {
+        let (base, hits) = spawn_mock_providers().await;
+        let detector = detector_against(&base, ThreatProviderConfig::default());
+        let mint = "ProviderRequiredMint111111111111111111111";
+
+        let (score, _) = detector.check_token(mint).await.unwrap();
+
+        assert_eq!(hits.goplus.load(Ordering::SeqCst), 1);
+        assert_eq!(hits.birdeye.load(Ordering::SeqCst), 1);
+        assert!(score.factors.has_mint_authority);
+        // GoPlus is required and failed; Birdeye is optional so its failure isn't noted
+        assert!(score
+            .recommendation
+            .ends_with("(reduced coverage: goplus unavailable)"));
+    }
+}
@@ -117,7 +117,7 @@ impl PumpFunCurve {
     }
 
     fn calculate_fee(&self, amount: u64) -> u64 {
-        (amount as u128 * self.params.fee_bps as u128 / 10000) as u64
+        bps_of(amount, self.params.fee_bps as u32)
     }
 }
 
@@ -271,7 +271,7 @@ impl MoonshotCurve {
     }
 
     fn calculate_fee(&self, amount: u64) -> u64 {
-        (amount as u128 * self.params.base_params.fee_bps as u128 / 10000) as u64
+        bps_of(amount, self.params.base_params.fee_bps as u32)
     }
 
     pub fn graduation_progress(&self) -> f64 {
@@ -376,6 +376,11 @@ impl BondingCurveMath for MoonshotCurve {
     }
 }
 
+/// `bps` basis points of `amount`, rounded down.
+pub fn bps_of(amount: u64, bps: u32) -> u64 {
+    (amount as u128 * bps as u128 / 10_000) as u64
+}
+
 pub fn calculate_min_tokens_out(tokens_out: u64, slippage_bps: u16) -> u64 {
     let slippage_factor = 10000u64 - slippage_bps as u64;
     (tokens_out as u128 * slippage_factor as u128 / 10000) as u64
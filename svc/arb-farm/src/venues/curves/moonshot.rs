@@ -5,8 +5,12 @@ use uuid::Uuid;
 
 use crate::error::{AppError, AppResult};
 use crate::events::Significance;
+use crate::execution::SOL_MINT;
 use crate::models::{Signal, SignalType, VenueType};
-use crate::venues::{MevVenue, ProfitEstimate, Quote, QuoteParams};
+use crate::venues::curves::math::{ui_to_tokens, MOONSHOT_FEE_BPS};
+use crate::venues::{FeeEstimate, MevVenue, ProfitEstimate, Quote, QuoteParams};
+
+const MOONSHOT_TOKEN_DECIMALS: u8 = 9;
 
 pub struct MoonshotVenue {
     id: Uuid,
@@ -190,7 +194,7 @@ impl MoonshotVenue {
             token_amount: tokens_out,
             price_per_token,
             price_impact_percent: price_impact,
-            fee_sol: sol_amount * 0.01, // 1% fee estimate
+            fee_sol: sol_amount * MOONSHOT_FEE_BPS as f64 / 10_000.0,
             curve_type: token.curve_type,
         })
     }
@@ -219,7 +223,7 @@ impl MoonshotVenue {
             token_amount,
             price_per_token,
             price_impact_percent: price_impact,
-            fee_sol: sol_out * 0.01,
+            fee_sol: sol_out * MOONSHOT_FEE_BPS as f64 / 10_000.0,
             curve_type: token.curve_type,
         })
     }
@@ -396,13 +400,17 @@ impl MevVenue for MoonshotVenue {
         let quote = self
             .compute_buy_quote(&params.output_mint, params.amount_lamports as f64 / 1e9)
             .await?;
+        // The curve fee comes out of the SOL spent, so fewer tokens are received
+        let net_token_amount =
+            quote.token_amount * (1.0 - quote.fee_sol / quote.sol_amount.max(f64::EPSILON));
 
         Ok(Quote {
             input_mint: params.input_mint.clone(),
             output_mint: params.output_mint.clone(),
             input_amount: params.amount_lamports,
-            output_amount: (quote.token_amount * 1e9) as u64,
+            output_amount: ui_to_tokens(net_token_amount, MOONSHOT_TOKEN_DECIMALS),
             price_impact_bps: (quote.price_impact_percent * 100.0) as i32,
+            fee_amount: ui_to_tokens(quote.fee_sol, 9),
             route_plan: serde_json::json!({
                 "venue": "moonshot",
                 "type": "bonding_curve_buy",
@@ -413,6 +421,23 @@ impl MevVenue for MoonshotVenue {
         })
     }
 
+    fn unsupported_quote_reason(&self, params: &QuoteParams) -> Option<String> {
+        (params.input_mint != SOL_MINT)
+            .then(|| "moonshot only quotes SOL → token buys on the bonding curve".to_string())
+    }
+
+    fn fee_estimate(&self, size_lamports: u64) -> Option<FeeEstimate> {
+        Some(FeeEstimate::from_rates(
+            self.name(),
+            size_lamports,
+            &[(
+                "curve_fee",
+                MOONSHOT_FEE_BPS as u32,
+                MOONSHOT_FEE_BPS as u32,
+            )],
+        ))
+    }
+
     async fn is_healthy(&self) -> bool {
         // Test DexScreener API with a moonshot search
         let url = format!("{}/search?q=moonshot", self.base_url);
@@ -6,12 +6,37 @@ use uuid::Uuid;
 use crate::error::{AppError, AppResult};
 use crate::events::Significance;
 use crate::models::{Signal, SignalType, VenueType};
-use crate::venues::{MevVenue, ProfitEstimate, Quote, QuoteParams};
+use crate::venues::dex::raydium::RAYDIUM_AMM_V4_FEE_BPS;
+use crate::venues::{FeeEstimate, MevVenue, ProfitEstimate, Quote, QuoteParams};
+
+pub const RAYDIUM_DEX_LABELS: &[&str] = &["Raydium", "Raydium CP", "Raydium CLMM"];
+/// We don't configure a platform fee on Jupiter swaps, so none is charged.
+pub const JUPITER_PLATFORM_FEE_BPS: u32 = 0;
+/// Typical LP fee on the AMM a Jupiter route lands on (a Raydium AMM v4 pool); the real
+/// rate depends on the route.
+pub const JUPITER_ROUTE_LP_FEE_BPS: u32 = RAYDIUM_AMM_V4_FEE_BPS as u32;
+pub const RAYDIUM_LP_FEE_BPS: u32 = RAYDIUM_AMM_V4_FEE_BPS as u32;
+
+const JUPITER_FEE_RATES: &[(&str, u32, u32)] = &[
+    (
+        "platform_fee",
+        JUPITER_PLATFORM_FEE_BPS,
+        JUPITER_PLATFORM_FEE_BPS,
+    ),
+    (
+        "route_lp_fee",
+        JUPITER_ROUTE_LP_FEE_BPS,
+        JUPITER_ROUTE_LP_FEE_BPS,
+    ),
+];
 
 pub struct JupiterVenue {
     id: Uuid,
     client: Client,
     base_url: String,
+    name: String,
+    dexes: Option<String>,
+    fee_rates: Vec<(&'static str, u32, u32)>,
 }
 
 impl JupiterVenue {
@@ -20,9 +45,32 @@ impl JupiterVenue {
             id: Uuid::new_v4(),
             client: Client::new(),
             base_url,
+            name: "Jupiter".to_string(),
+            dexes: None,
+            fee_rates: JUPITER_FEE_RATES.to_vec(),
         }
     }
 
+    /// Restricts routing to the given Jupiter DEX labels, so the venue quotes a
+    /// single AMM family (e.g. Raydium) instead of the aggregated best route.
+    pub fn with_dexes(mut self, name: &str, dexes: &[&str]) -> Self {
+        self.name = name.to_string();
+        self.dexes = Some(dexes.join(","));
+        self
+    }
+
+    /// `(name, buy_bps, sell_bps)` fee components reported by `fee_estimate`.
+    pub fn with_fee_rates(mut self, rates: &[(&'static str, u32, u32)]) -> Self {
+        self.fee_rates = rates.to_vec();
+        self
+    }
+
+    pub fn raydium(base_url: String) -> Self {
+        Self::new(base_url)
+            .with_dexes("Raydium", RAYDIUM_DEX_LABELS)
+            .with_fee_rates(&[("lp_fee", RAYDIUM_LP_FEE_BPS, RAYDIUM_LP_FEE_BPS)])
+    }
+
     pub async fn get_quote_internal(
         &self,
         input_mint: &str,
@@ -35,9 +83,12 @@ impl JupiterVenue {
             self.base_url, input_mint, output_mint, amount, slippage_bps
         );
 
-        let response = self
-            .client
-            .get(&url)
+        let mut request = self.client.get(&url);
+        if let Some(dexes) = &self.dexes {
+            request = request.query(&[("dexes", dexes)]);
+        }
+
+        let response = request
             .timeout(std::time::Duration::from_secs(10))
             .send()
             .await
@@ -98,7 +149,7 @@ impl MevVenue for JupiterVenue {
     }
 
     fn name(&self) -> &str {
-        "Jupiter"
+        &self.name
     }
 
     async fn scan_for_signals(&self) -> AppResult<Vec<Signal>> {
@@ -127,15 +178,15 @@ impl MevVenue for JupiterVenue {
             )
             .await?;
 
-        Ok(Quote {
-            input_mint: params.input_mint.clone(),
-            output_mint: params.output_mint.clone(),
-            input_amount: jupiter_quote.in_amount.parse().unwrap_or(0),
-            output_amount: jupiter_quote.out_amount.parse().unwrap_or(0),
-            price_impact_bps: (jupiter_quote.price_impact_pct * 10000.0) as i32,
-            route_plan: serde_json::to_value(&jupiter_quote.route_plan).unwrap_or_default(),
-            expires_at: chrono::Utc::now() + chrono::Duration::seconds(30),
-        })
+        Ok(jupiter_quote.to_quote(params))
+    }
+
+    fn fee_estimate(&self, size_lamports: u64) -> Option<FeeEstimate> {
+        Some(FeeEstimate::from_rates(
+            &self.name,
+            size_lamports,
+            &self.fee_rates,
+        ))
     }
 
     async fn is_healthy(&self) -> bool {
@@ -168,6 +219,42 @@ pub struct JupiterQuoteResponse {
     pub route_plan: Vec<JupiterRoutePlan>,
 }
 
+impl JupiterQuoteResponse {
+    pub fn to_quote(&self, params: &QuoteParams) -> Quote {
+        let input_amount = self.in_amount.parse().unwrap_or(0);
+        let output_amount = self.out_amount.parse().unwrap_or(0);
+
+        Quote {
+            input_mint: params.input_mint.clone(),
+            output_mint: params.output_mint.clone(),
+            input_amount,
+            output_amount,
+            price_impact_bps: (self.price_impact_pct * 10000.0) as i32,
+            fee_amount: self.fee_in_input(input_amount, output_amount),
+            route_plan: serde_json::to_value(&self.route_plan).unwrap_or_default(),
+            expires_at: chrono::Utc::now() + chrono::Duration::seconds(30),
+        }
+    }
+
+    /// Sums per-hop AMM fees in input-mint units. Fees charged in the output mint are
+    /// converted at the quote's overall rate; fees in intermediate mints are ignored.
+    pub fn fee_in_input(&self, input_amount: u64, output_amount: u64) -> u64 {
+        self.route_plan
+            .iter()
+            .map(|hop| {
+                let fee = hop.swap_info.fee_amount.parse::<f64>().unwrap_or(0.0);
+                if hop.swap_info.fee_mint == self.input_mint {
+                    fee
+                } else if hop.swap_info.fee_mint == self.output_mint && output_amount > 0 {
+                    fee * input_amount as f64 / output_amount as f64
+                } else {
+                    0.0
+                }
+            })
+            .sum::<f64>() as u64
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct JupiterRoutePlan {
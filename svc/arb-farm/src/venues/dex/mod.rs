@@ -1,3 +1,5 @@
 pub mod jupiter;
+pub mod raydium;
 
 pub use jupiter::JupiterVenue;
+pub use raydium::{RaydiumPoolType, RaydiumVenue, RpcClmmPoolSource};
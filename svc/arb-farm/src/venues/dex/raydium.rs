@@ -0,0 +1,590 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{Signal, VenueType};
+use crate::venues::dex::jupiter::{JupiterQuoteResponse, JupiterVenue};
+use crate::venues::{FeeEstimate, MevVenue, ProfitEstimate, Quote, QuoteParams};
+
+pub const RAYDIUM_AMM_V4_PROGRAM_ID: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+pub const RAYDIUM_CPMM_PROGRAM_ID: &str = "CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C";
+pub const RAYDIUM_CLMM_PROGRAM_ID: &str = "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK";
+
+pub const RAYDIUM_AMM_V4_FEE_BPS: u16 = 25;
+/// CLMM fee rates are expressed in hundredths of a bip (1_000_000 = 100%).
+pub const CLMM_FEE_RATE_DENOMINATOR: u64 = 1_000_000;
+
+const Q64: f64 = 18_446_744_073_709_551_616.0;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum RaydiumPoolType {
+    AmmV4,
+    Cpmm,
+    Clmm,
+}
+
+impl RaydiumPoolType {
+    pub fn from_program_id(program_id: &str) -> Option<Self> {
+        match program_id {
+            RAYDIUM_AMM_V4_PROGRAM_ID => Some(Self::AmmV4),
+            RAYDIUM_CPMM_PROGRAM_ID => Some(Self::Cpmm),
+            RAYDIUM_CLMM_PROGRAM_ID => Some(Self::Clmm),
+            _ => None,
+        }
+    }
+
+    /// Maps Jupiter route-plan labels ("Raydium", "Raydium CP", "Raydium CLMM") to a pool type.
+    pub fn from_route_label(label: &str) -> Option<Self> {
+        let label = label.trim().to_lowercase();
+        if !label.starts_with("raydium") {
+            return None;
+        }
+        if label.contains("clmm") || label.contains("concentrated") {
+            Some(Self::Clmm)
+        } else if label.contains("cp") {
+            Some(Self::Cpmm)
+        } else {
+            Some(Self::AmmV4)
+        }
+    }
+
+    pub fn is_concentrated(&self) -> bool {
+        matches!(self, Self::Clmm)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConstantProductPool {
+    pub reserve_in: u64,
+    pub reserve_out: u64,
+    pub fee_bps: u16,
+}
+
+/// Active-range CLMM state. `sqrt_price_x64` is Q64.64 sqrt(token_b / token_a).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClmmPoolState {
+    pub sqrt_price_x64: u128,
+    pub liquidity: u128,
+    pub fee_rate: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RaydiumPool {
+    ConstantProduct {
+        pool_type: RaydiumPoolType,
+        pool: ConstantProductPool,
+    },
+    Clmm(ClmmPoolState),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoolQuote {
+    pub pool_type: RaydiumPoolType,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee_amount: u64,
+    pub price_impact_bps: i32,
+}
+
+impl RaydiumPool {
+    /// For CLMM pools `a_to_b` selects direction; constant-product reserves are already
+    /// oriented input → output so the flag is ignored.
+    pub fn quote(&self, amount_in: u64, a_to_b: bool) -> Option<PoolQuote> {
+        match self {
+            RaydiumPool::ConstantProduct { pool_type, pool } => {
+                quote_constant_product(pool, amount_in).map(|mut q| {
+                    q.pool_type = *pool_type;
+                    q
+                })
+            }
+            RaydiumPool::Clmm(state) => quote_clmm(state, amount_in, a_to_b),
+        }
+    }
+}
+
+pub fn quote_constant_product(pool: &ConstantProductPool, amount_in: u64) -> Option<PoolQuote> {
+    if pool.reserve_in == 0 || pool.reserve_out == 0 || amount_in == 0 {
+        return None;
+    }
+
+    let fee_amount = (amount_in as u128 * pool.fee_bps as u128 / 10_000) as u64;
+    let net_in = (amount_in - fee_amount) as u128;
+    let amount_out =
+        (net_in * pool.reserve_out as u128 / (pool.reserve_in as u128 + net_in)) as u64;
+
+    let spot_out = amount_in as f64 * pool.reserve_out as f64 / pool.reserve_in as f64;
+    Some(PoolQuote {
+        pool_type: RaydiumPoolType::AmmV4,
+        amount_in,
+        amount_out,
+        fee_amount,
+        price_impact_bps: impact_bps(spot_out, amount_out),
+    })
+}
+
+/// Swap within the active tick range: liquidity is treated as constant, so quotes for
+/// swaps large enough to cross an initialized tick are an approximation.
+pub fn quote_clmm(state: &ClmmPoolState, amount_in: u64, a_to_b: bool) -> Option<PoolQuote> {
+    if state.liquidity == 0 || state.sqrt_price_x64 == 0 || amount_in == 0 {
+        return None;
+    }
+
+    let fee_amount =
+        (amount_in as u128 * state.fee_rate as u128 / CLMM_FEE_RATE_DENOMINATOR as u128) as u64;
+    let net_in = (amount_in - fee_amount) as f64;
+
+    let liquidity = state.liquidity as f64;
+    let sqrt_p = state.sqrt_price_x64 as f64 / Q64;
+
+    let (amount_out, spot_out) = if a_to_b {
+        let sqrt_p_next = liquidity * sqrt_p / (liquidity + net_in * sqrt_p);
+        let out = liquidity * (sqrt_p - sqrt_p_next);
+        (out, amount_in as f64 * sqrt_p * sqrt_p)
+    } else {
+        let sqrt_p_next = sqrt_p + net_in / liquidity;
+        let out = liquidity * (sqrt_p_next - sqrt_p) / (sqrt_p * sqrt_p_next);
+        (out, amount_in as f64 / (sqrt_p * sqrt_p))
+    };
+
+    let amount_out = amount_out.max(0.0).floor() as u64;
+    Some(PoolQuote {
+        pool_type: RaydiumPoolType::Clmm,
+        amount_in,
+        amount_out,
+        fee_amount,
+        price_impact_bps: impact_bps(spot_out, amount_out),
+    })
+}
+
+fn impact_bps(spot_out: f64, amount_out: u64) -> i32 {
+    if spot_out <= 0.0 {
+        return 0;
+    }
+    ((1.0 - amount_out as f64 / spot_out) * 10_000.0).round() as i32
+}
+
+/// Pool state at quote time; `a_to_b` is true when the input mint is the pool's token A.
+#[derive(Debug, Clone)]
+pub struct PoolSnapshot {
+    pub pool: RaydiumPool,
+    pub a_to_b: bool,
+}
+
+#[async_trait]
+pub trait RaydiumPoolSource: Send + Sync {
+    /// Current state of `pool_address` for a swap out of `input_mint`, or None when the
+    /// pool can't be priced locally.
+    async fn snapshot(
+        &self,
+        pool_address: &str,
+        input_mint: &str,
+    ) -> AppResult<Option<PoolSnapshot>>;
+}
+
+// Raydium CLMM `PoolState` / `AmmConfig` account offsets (after the 8-byte discriminator)
+const CLMM_POOL_AMM_CONFIG_OFFSET: usize = 9;
+const CLMM_POOL_TOKEN_MINT_0_OFFSET: usize = 73;
+const CLMM_POOL_LIQUIDITY_OFFSET: usize = 237;
+const CLMM_POOL_SQRT_PRICE_OFFSET: usize = 253;
+const CLMM_CONFIG_TRADE_FEE_RATE_OFFSET: usize = 47;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClmmPoolAccount {
+    pub amm_config: Pubkey,
+    pub token_mint_0: Pubkey,
+    pub liquidity: u128,
+    pub sqrt_price_x64: u128,
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Option<Pubkey> {
+    let bytes: [u8; 32] = data.get(offset..offset + 32)?.try_into().ok()?;
+    Some(Pubkey::new_from_array(bytes))
+}
+
+fn read_u128(data: &[u8], offset: usize) -> Option<u128> {
+    Some(u128::from_le_bytes(
+        data.get(offset..offset + 16)?.try_into().ok()?,
+    ))
+}
+
+pub fn decode_clmm_pool(data: &[u8]) -> Option<ClmmPoolAccount> {
+    Some(ClmmPoolAccount {
+        amm_config: read_pubkey(data, CLMM_POOL_AMM_CONFIG_OFFSET)?,
+        token_mint_0: read_pubkey(data, CLMM_POOL_TOKEN_MINT_0_OFFSET)?,
+        liquidity: read_u128(data, CLMM_POOL_LIQUIDITY_OFFSET)?,
+        sqrt_price_x64: read_u128(data, CLMM_POOL_SQRT_PRICE_OFFSET)?,
+    })
+}
+
+pub fn decode_clmm_trade_fee_rate(data: &[u8]) -> Option<u32> {
+    let offset = CLMM_CONFIG_TRADE_FEE_RATE_OFFSET;
+    Some(u32::from_le_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+/// Reads CLMM pool and fee-config accounts over RPC. Constant-product pools are left to
+/// Jupiter's reserve-based quote.
+pub struct RpcClmmPoolSource {
+    rpc_client: Arc<RpcClient>,
+}
+
+impl RpcClmmPoolSource {
+    pub fn new(rpc_url: &str) -> Self {
+        Self {
+            rpc_client: Arc::new(RpcClient::new(rpc_url.to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl RaydiumPoolSource for RpcClmmPoolSource {
+    async fn snapshot(
+        &self,
+        pool_address: &str,
+        input_mint: &str,
+    ) -> AppResult<Option<PoolSnapshot>> {
+        let pool_pubkey = Pubkey::from_str(pool_address)
+            .map_err(|e| AppError::Validation(format!("Invalid pool address: {}", e)))?;
+        let account = self
+            .rpc_client
+            .get_account(&pool_pubkey)
+            .await
+            .map_err(|e| AppError::ExternalApi(format!("Failed to fetch CLMM pool: {}", e)))?;
+        if RaydiumPoolType::from_program_id(&account.owner.to_string())
+            != Some(RaydiumPoolType::Clmm)
+        {
+            return Ok(None);
+        }
+        let Some(pool) = decode_clmm_pool(&account.data) else {
+            return Ok(None);
+        };
+
+        let config_data = self
+            .rpc_client
+            .get_account_data(&pool.amm_config)
+            .await
+            .map_err(|e| AppError::ExternalApi(format!("Failed to fetch CLMM config: {}", e)))?;
+        let Some(fee_rate) = decode_clmm_trade_fee_rate(&config_data) else {
+            return Ok(None);
+        };
+
+        Ok(Some(PoolSnapshot {
+            pool: RaydiumPool::Clmm(ClmmPoolState {
+                sqrt_price_x64: pool.sqrt_price_x64,
+                liquidity: pool.liquidity,
+                fee_rate,
+            }),
+            a_to_b: pool.token_mint_0.to_string() == input_mint,
+        }))
+    }
+}
+
+/// Raydium quotes: Jupiter restricted to Raydium pools finds the pool, and single-hop
+/// routes through a pool the source can read are re-priced with the pool-type math.
+pub struct RaydiumVenue {
+    route: JupiterVenue,
+    pools: Option<Arc<dyn RaydiumPoolSource>>,
+}
+
+impl RaydiumVenue {
+    pub fn new(jupiter_api_url: String) -> Self {
+        Self {
+            route: JupiterVenue::raydium(jupiter_api_url),
+            pools: None,
+        }
+    }
+
+    pub fn with_pool_source(mut self, pools: Arc<dyn RaydiumPoolSource>) -> Self {
+        self.pools = Some(pools);
+        self
+    }
+
+    async fn local_quote(&self, response: &JupiterQuoteResponse) -> Option<PoolQuote> {
+        let pools = self.pools.as_ref()?;
+        let [hop] = response.route_plan.as_slice() else {
+            return None;
+        };
+        RaydiumPoolType::from_route_label(hop.swap_info.label.as_deref()?)?;
+
+        let amount_in = hop.swap_info.in_amount.parse().ok()?;
+        match pools
+            .snapshot(&hop.swap_info.amm_key, &hop.swap_info.input_mint)
+            .await
+        {
+            Ok(snapshot) => {
+                let snapshot = snapshot?;
+                snapshot.pool.quote(amount_in, snapshot.a_to_b)
+            }
+            Err(e) => {
+                debug!("Raydium pool {} not readable: {}", hop.swap_info.amm_key, e);
+                None
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl MevVenue for RaydiumVenue {
+    fn venue_id(&self) -> Uuid {
+        self.route.venue_id()
+    }
+
+    fn venue_type(&self) -> VenueType {
+        VenueType::DexAmm
+    }
+
+    fn name(&self) -> &str {
+        self.route.name()
+    }
+
+    async fn scan_for_signals(&self) -> AppResult<Vec<Signal>> {
+        Ok(vec![])
+    }
+
+    async fn estimate_profit(&self, signal: &Signal) -> AppResult<ProfitEstimate> {
+        self.route.estimate_profit(signal).await
+    }
+
+    async fn get_quote(&self, params: &QuoteParams) -> AppResult<Quote> {
+        let response = self
+            .route
+            .get_quote_internal(
+                &params.input_mint,
+                &params.output_mint,
+                params.amount_lamports,
+                params.slippage_bps,
+            )
+            .await?;
+
+        let mut quote = response.to_quote(params);
+        if let Some(local) = self.local_quote(&response).await {
+            quote.output_amount = local.amount_out;
+            quote.fee_amount = local.fee_amount;
+            quote.price_impact_bps = local.price_impact_bps;
+        }
+        Ok(quote)
+    }
+
+    fn fee_estimate(&self, size_lamports: u64) -> Option<FeeEstimate> {
+        self.route.fee_estimate(size_lamports)
+    }
+
+    async fn is_healthy(&self) -> bool {
+        self.route.is_healthy().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sqrt_price_x64(price: f64) -> u128 {
+        (price.sqrt() * Q64) as u128
+    }
+
+    #[test]
+    fn test_pool_type_detection() {
+        assert_eq!(
+            RaydiumPoolType::from_program_id(RAYDIUM_CLMM_PROGRAM_ID),
+            Some(RaydiumPoolType::Clmm)
+        );
+        assert_eq!(
+            RaydiumPoolType::from_program_id(RAYDIUM_AMM_V4_PROGRAM_ID),
+            Some(RaydiumPoolType::AmmV4)
+        );
+        assert_eq!(
+            RaydiumPoolType::from_route_label("Raydium CLMM"),
+            Some(RaydiumPoolType::Clmm)
+        );
+        assert_eq!(
+            RaydiumPoolType::from_route_label("Raydium CP"),
+            Some(RaydiumPoolType::Cpmm)
+        );
+        assert_eq!(
+            RaydiumPoolType::from_route_label("Raydium"),
+            Some(RaydiumPoolType::AmmV4)
+        );
+        assert_eq!(RaydiumPoolType::from_route_label("Orca"), None);
+    }
+
+    #[test]
+    fn test_clmm_quote_differs_from_constant_product() {
+        // Same spot price (1 SOL = 1_000_000 token base units) and same fee.
+        let cp = ConstantProductPool {
+            reserve_in: 100_000_000_000,
+            reserve_out: 100_000_000_000_000_000,
+            fee_bps: 25,
+        };
+        let virtual_liquidity = (cp.reserve_in as f64 * cp.reserve_out as f64).sqrt() as u128;
+
+        // Concentrated position: 10x the full-range liquidity at the current price.
+        let clmm = ClmmPoolState {
+            sqrt_price_x64: sqrt_price_x64(1_000_000.0),
+            liquidity: virtual_liquidity * 10,
+            fee_rate: 2_500,
+        };
+
+        let amount_in = 5_000_000_000;
+        let cp_quote = quote_constant_product(&cp, amount_in).unwrap();
+        let clmm_quote = quote_clmm(&clmm, amount_in, true).unwrap();
+
+        assert_eq!(cp_quote.fee_amount, clmm_quote.fee_amount);
+        assert!(clmm_quote.amount_out > cp_quote.amount_out);
+        assert!(clmm_quote.price_impact_bps < cp_quote.price_impact_bps);
+        assert_eq!(clmm_quote.pool_type, RaydiumPoolType::Clmm);
+
+        // With full-range liquidity the CLMM math collapses to constant product.
+        let full_range = ClmmPoolState {
+            liquidity: virtual_liquidity,
+            ..clmm
+        };
+        let full_range_quote = quote_clmm(&full_range, amount_in, true).unwrap();
+        let diff = full_range_quote.amount_out.abs_diff(cp_quote.amount_out) as f64;
+        assert!(diff / (cp_quote.amount_out as f64) < 1e-6);
+    }
+
+    #[test]
+    fn test_clmm_quote_direction() {
+        let clmm = ClmmPoolState {
+            sqrt_price_x64: sqrt_price_x64(4.0),
+            liquidity: 1_000_000_000_000,
+            fee_rate: 0,
+        };
+
+        let a_to_b = quote_clmm(&clmm, 1_000, true).unwrap();
+        let b_to_a = quote_clmm(&clmm, 1_000, false).unwrap();
+
+        assert!((3_990..=4_000).contains(&a_to_b.amount_out));
+        assert!((249..=250).contains(&b_to_a.amount_out));
+    }
+
+    #[test]
+    fn test_empty_pools_return_none() {
+        let empty = ClmmPoolState {
+            sqrt_price_x64: sqrt_price_x64(1.0),
+            liquidity: 0,
+            fee_rate: 2_500,
+        };
+        assert!(quote_clmm(&empty, 1_000, true).is_none());
+        assert!(RaydiumPool::Clmm(empty).quote(1_000, true).is_none());
+    }
+
+    struct FixedPools(PoolSnapshot);
+
+    #[async_trait]
+    impl RaydiumPoolSource for FixedPools {
+        async fn snapshot(&self, _: &str, _: &str) -> AppResult<Option<PoolSnapshot>> {
+            Ok(Some(self.0.clone()))
+        }
+    }
+
+    fn single_hop(label: &str, in_amount: u64) -> JupiterQuoteResponse {
+        serde_json::from_value(serde_json::json!({
+            "inputMint": "So11111111111111111111111111111111111111112",
+            "outputMint": "TokenMint",
+            "inAmount": in_amount.to_string(),
+            "outAmount": "1",
+            "otherAmountThreshold": "1",
+            "swapMode": "ExactIn",
+            "slippageBps": 50,
+            "priceImpactPct": 0.0,
+            "routePlan": [{
+                "swapInfo": {
+                    "ammKey": "PoolKey",
+                    "label": label,
+                    "inputMint": "So11111111111111111111111111111111111111112",
+                    "outputMint": "TokenMint",
+                    "inAmount": in_amount.to_string(),
+                    "outAmount": "1",
+                    "feeAmount": "0",
+                    "feeMint": "So11111111111111111111111111111111111111112"
+                },
+                "percent": 100
+            }]
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_venue_prices_clmm_route_from_pool_state() {
+        let cp = ConstantProductPool {
+            reserve_in: 100_000_000_000,
+            reserve_out: 100_000_000_000_000_000,
+            fee_bps: 25,
+        };
+        let virtual_liquidity = (cp.reserve_in as f64 * cp.reserve_out as f64).sqrt() as u128;
+        let clmm = ClmmPoolState {
+            sqrt_price_x64: sqrt_price_x64(1_000_000.0),
+            liquidity: virtual_liquidity * 10,
+            fee_rate: 2_500,
+        };
+        let amount_in = 5_000_000_000;
+
+        let clmm_venue =
+            RaydiumVenue::new(String::new()).with_pool_source(Arc::new(FixedPools(PoolSnapshot {
+                pool: RaydiumPool::Clmm(clmm),
+                a_to_b: true,
+            })));
+        let cp_venue =
+            RaydiumVenue::new(String::new()).with_pool_source(Arc::new(FixedPools(PoolSnapshot {
+                pool: RaydiumPool::ConstantProduct {
+                    pool_type: RaydiumPoolType::AmmV4,
+                    pool: cp,
+                },
+                a_to_b: true,
+            })));
+
+        let clmm_quote = clmm_venue
+            .local_quote(&single_hop("Raydium CLMM", amount_in))
+            .await
+            .unwrap();
+        let cp_quote = cp_venue
+            .local_quote(&single_hop("Raydium", amount_in))
+            .await
+            .unwrap();
+
+        assert_eq!(clmm_quote.pool_type, RaydiumPoolType::Clmm);
+        assert_eq!(cp_quote.pool_type, RaydiumPoolType::AmmV4);
+        assert!(clmm_quote.amount_out > cp_quote.amount_out);
+
+        // Non-Raydium hops keep Jupiter's own numbers
+        assert!(clmm_venue
+            .local_quote(&single_hop("Orca", amount_in))
+            .await
+            .is_none());
+    }
+
+    #[test]
+    fn test_decode_clmm_accounts() {
+        let amm_config = Pubkey::new_unique();
+        let mint_0 = Pubkey::new_unique();
+        let mut pool = vec![0u8; 300];
+        pool[CLMM_POOL_AMM_CONFIG_OFFSET..CLMM_POOL_AMM_CONFIG_OFFSET + 32]
+            .copy_from_slice(amm_config.as_ref());
+        pool[CLMM_POOL_TOKEN_MINT_0_OFFSET..CLMM_POOL_TOKEN_MINT_0_OFFSET + 32]
+            .copy_from_slice(mint_0.as_ref());
+        pool[CLMM_POOL_LIQUIDITY_OFFSET..CLMM_POOL_LIQUIDITY_OFFSET + 16]
+            .copy_from_slice(&42u128.to_le_bytes());
+        pool[CLMM_POOL_SQRT_PRICE_OFFSET..CLMM_POOL_SQRT_PRICE_OFFSET + 16]
+            .copy_from_slice(&sqrt_price_x64(4.0).to_le_bytes());
+
+        let decoded = decode_clmm_pool(&pool).unwrap();
+        assert_eq!(decoded.amm_config, amm_config);
+        assert_eq!(decoded.token_mint_0, mint_0);
+        assert_eq!(decoded.liquidity, 42);
+        assert!(decode_clmm_pool(&pool[..200]).is_none());
+
+        let mut config = vec![0u8; 64];
+        config[CLMM_CONFIG_TRADE_FEE_RATE_OFFSET..CLMM_CONFIG_TRADE_FEE_RATE_OFFSET + 4]
+            .copy_from_slice(&2_500u32.to_le_bytes());
+        assert_eq!(decode_clmm_trade_fee_rate(&config), Some(2_500));
+    }
+}
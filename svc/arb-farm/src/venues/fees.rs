@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::venues::{FeeEstimate, MevVenue};
+
+pub const DEFAULT_FEE_COMPARE_SIZE_LAMPORTS: u64 = 1_000_000_000; // 1 SOL
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FeeCompareQuery {
+    /// Trade size in lamports; defaults to 1 SOL.
+    pub amount: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RankedFeeEstimate {
+    pub rank: usize,
+    #[serde(flatten)]
+    pub estimate: FeeEstimate,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FeeComparison {
+    pub size_lamports: u64,
+    pub cheapest_venue: Option<String>,
+    pub venues: Vec<RankedFeeEstimate>,
+}
+
+/// Collects each venue's round-trip fee breakdown for `size_lamports` and ranks them
+/// cheapest first. Venues without a fee schedule are left out.
+pub fn compare_fees(venues: &[Arc<dyn MevVenue>], size_lamports: u64) -> FeeComparison {
+    let mut estimates: Vec<FeeEstimate> = venues
+        .iter()
+        .filter_map(|venue| venue.fee_estimate(size_lamports))
+        .collect();
+
+    estimates.sort_by(|a, b| {
+        a.total_fee_lamports
+            .cmp(&b.total_fee_lamports)
+            .then(a.total_bps.cmp(&b.total_bps))
+            .then(a.venue.cmp(&b.venue))
+    });
+
+    let ranked: Vec<RankedFeeEstimate> = estimates
+        .into_iter()
+        .enumerate()
+        .map(|(i, estimate)| RankedFeeEstimate {
+            rank: i + 1,
+            estimate,
+        })
+        .collect();
+
+    FeeComparison {
+        size_lamports,
+        cheapest_venue: ranked.first().map(|r| r.estimate.venue.clone()),
+        venues: ranked,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::venues::curves::math::PUMP_FUN_FEE_BPS;
+    use crate::venues::dex::raydium::RAYDIUM_AMM_V4_FEE_BPS;
+    use crate::venues::{JupiterVenue, KaminoVenue, MoonshotVenue, PumpFunVenue};
+
+    const SIZE: u64 = 2_000_000_000; // 2 SOL
+
+    fn venues() -> Vec<Arc<dyn MevVenue>> {
+        vec![
+            Arc::new(PumpFunVenue::new(String::new(), String::new())),
+            Arc::new(JupiterVenue::new(String::new())),
+            Arc::new(MoonshotVenue::new(String::new())),
+            Arc::new(JupiterVenue::raydium(String::new())),
+            Arc::new(KaminoVenue::new(String::new(), String::new())),
+        ]
+    }
+
+    #[test]
+    fn test_each_venue_reports_fee_breakdown() {
+        let pump = PumpFunVenue::new(String::new(), String::new())
+            .fee_estimate(SIZE)
+            .unwrap();
+        assert_eq!(pump.components.len(), 1);
+        assert_eq!(pump.components[0].name, "curve_fee");
+        // Same schedule the curve math charges
+        assert_eq!(pump.components[0].buy_bps, PUMP_FUN_FEE_BPS as u32);
+        assert_eq!(pump.components[0].sell_bps, PUMP_FUN_FEE_BPS as u32);
+        assert_eq!(pump.total_fee_lamports, 40_000_000);
+
+        let moonshot = MoonshotVenue::new(String::new())
+            .fee_estimate(SIZE)
+            .unwrap();
+        assert_eq!(moonshot.total_bps, 200);
+        assert_eq!(moonshot.total_fee_lamports, 40_000_000);
+
+        let jupiter = JupiterVenue::new(String::new()).fee_estimate(SIZE).unwrap();
+        let names: Vec<&str> = jupiter.components.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["platform_fee", "route_lp_fee"]);
+        assert_eq!(jupiter.components[0].amount_lamports, 0);
+        assert_eq!(jupiter.total_fee_lamports, 10_000_000);
+
+        let raydium = JupiterVenue::raydium(String::new())
+            .fee_estimate(SIZE)
+            .unwrap();
+        assert_eq!(raydium.venue, "Raydium");
+        assert_eq!(raydium.components[0].name, "lp_fee");
+        assert_eq!(raydium.total_bps, 2 * RAYDIUM_AMM_V4_FEE_BPS as u32);
+
+        let kamino = KaminoVenue::new(String::new(), String::new());
+        assert!(kamino.fee_estimate(SIZE).is_none());
+    }
+
+    #[test]
+    fn test_fee_comparison_ranks_cheapest_first() {
+        let comparison = compare_fees(&venues(), SIZE);
+
+        assert_eq!(comparison.size_lamports, SIZE);
+        let order: Vec<(&str, u64)> = comparison
+            .venues
+            .iter()
+            .map(|r| (r.estimate.venue.as_str(), r.estimate.total_fee_lamports))
+            .collect();
+        // Ties on fees fall back to name order
+        assert_eq!(
+            order,
+            vec![
+                ("Jupiter", 10_000_000),
+                ("Raydium", 10_000_000),
+                ("moonshot", 40_000_000),
+                ("pump.fun", 40_000_000),
+            ]
+        );
+        assert_eq!(comparison.cheapest_venue.as_deref(), Some("Jupiter"));
+        assert_eq!(
+            comparison.venues.iter().map(|r| r.rank).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+    }
+}
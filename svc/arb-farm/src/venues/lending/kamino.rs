@@ -3,6 +3,7 @@ use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::monitor::LendingPositionHealth;
 use crate::error::{AppError, AppResult};
 use crate::events::Significance;
 use crate::models::{Signal, SignalType, VenueType};
@@ -80,6 +81,14 @@ impl KaminoVenue {
         })
     }
 
+    pub async fn get_position_health(
+        &self,
+        obligation_address: &str,
+    ) -> AppResult<LendingPositionHealth> {
+        let detail = self.get_obligation_details(obligation_address).await?;
+        Ok(LendingPositionHealth::from_kamino(&detail))
+    }
+
     pub async fn get_liquidatable_obligations(
         &self,
         min_shortfall: f64,
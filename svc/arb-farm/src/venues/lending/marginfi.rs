@@ -3,6 +3,7 @@ use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::monitor::LendingPositionHealth;
 use crate::error::{AppError, AppResult};
 use crate::events::Significance;
 use crate::models::{Signal, SignalType, VenueType};
@@ -76,6 +77,14 @@ impl MarginfiVenue {
             .map_err(|e| AppError::ExternalApi(format!("Failed to parse health response: {}", e)))
     }
 
+    pub async fn get_position_health(
+        &self,
+        account_address: &str,
+    ) -> AppResult<LendingPositionHealth> {
+        let health = self.get_account_health(account_address).await?;
+        Ok(LendingPositionHealth::from_marginfi(&health))
+    }
+
     pub async fn get_liquidatable_accounts(
         &self,
         min_shortfall: f64,
@@ -1,5 +1,10 @@
 pub mod kamino;
 pub mod marginfi;
+pub mod monitor;
 
 pub use kamino::KaminoVenue;
 pub use marginfi::MarginfiVenue;
+pub use monitor::{
+    LendingVenueHealth, LiquidationAlertThresholds, LiquidationRiskMonitor,
+    MonitoredLendingAccount,
+};
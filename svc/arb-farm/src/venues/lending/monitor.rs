@@ -0,0 +1,507 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, RwLock};
+
+use super::kamino::{KaminoObligationDetail, KaminoVenue};
+use super::marginfi::{AccountHealth, MarginfiVenue};
+use crate::error::{AppError, AppResult};
+use crate::events::{broadcast_event, topics, ArbEvent, EventSource};
+use crate::models::LendingProvider;
+
+pub const DEFAULT_WARNING_HEALTH_FACTOR: f64 = 1.25;
+pub const DEFAULT_CRITICAL_HEALTH_FACTOR: f64 = 1.1;
+pub const DEFAULT_LENDING_POLL_SECS: u64 = 60;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum LiquidationRiskLevel {
+    Healthy,
+    Warning,
+    Critical,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiquidationAlertThresholds {
+    pub warning_health_factor: f64,
+    pub critical_health_factor: f64,
+}
+
+impl Default for LiquidationAlertThresholds {
+    fn default() -> Self {
+        Self {
+            warning_health_factor: DEFAULT_WARNING_HEALTH_FACTOR,
+            critical_health_factor: DEFAULT_CRITICAL_HEALTH_FACTOR,
+        }
+    }
+}
+
+impl LiquidationAlertThresholds {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let thresholds = Self {
+            warning_health_factor: std::env::var("LENDING_WARNING_HEALTH_FACTOR")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(defaults.warning_health_factor),
+            critical_health_factor: std::env::var("LENDING_CRITICAL_HEALTH_FACTOR")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(defaults.critical_health_factor),
+        };
+
+        match thresholds.validate() {
+            Ok(()) => thresholds,
+            Err(e) => {
+                tracing::warn!("Invalid lending alert thresholds ({}), using defaults", e);
+                defaults
+            }
+        }
+    }
+
+    pub fn validate(&self) -> AppResult<()> {
+        if self.critical_health_factor < 1.0 {
+            return Err(AppError::Validation(
+                "critical_health_factor must be at least 1.0".to_string(),
+            ));
+        }
+        if self.warning_health_factor <= self.critical_health_factor {
+            return Err(AppError::Validation(
+                "warning_health_factor must be above critical_health_factor".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn classify(&self, health_factor: f64) -> LiquidationRiskLevel {
+        if health_factor <= self.critical_health_factor {
+            LiquidationRiskLevel::Critical
+        } else if health_factor <= self.warning_health_factor {
+            LiquidationRiskLevel::Warning
+        } else {
+            LiquidationRiskLevel::Healthy
+        }
+    }
+}
+
+/// A monitored borrow position. Health factor is liquidation threshold over current
+/// LTV, so 1.0 is the liquidation point.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LendingPositionHealth {
+    pub provider: LendingProvider,
+    pub account_address: String,
+    pub health_factor: f64,
+    pub borrowed_value_usd: f64,
+}
+
+impl LendingPositionHealth {
+    pub fn from_kamino(detail: &KaminoObligationDetail) -> Self {
+        let metrics = &detail.health_metrics;
+        let health_factor = if metrics.current_ltv > 0.0 {
+            metrics.liquidation_ltv / metrics.current_ltv
+        } else {
+            f64::INFINITY
+        };
+
+        Self {
+            provider: LendingProvider::Kamino,
+            account_address: detail.obligation.address.clone(),
+            health_factor,
+            borrowed_value_usd: detail.obligation.borrowed_value_usd,
+        }
+    }
+
+    pub fn from_marginfi(health: &AccountHealth) -> Self {
+        let borrowed_value_usd = health
+            .positions
+            .iter()
+            .filter(|p| {
+                p.position_type.eq_ignore_ascii_case("borrow")
+                    || p.position_type.eq_ignore_ascii_case("liability")
+            })
+            .map(|p| p.value_usd)
+            .sum();
+
+        Self {
+            provider: LendingProvider::Marginfi,
+            account_address: health.address.clone(),
+            health_factor: health.health_factor,
+            borrowed_value_usd,
+        }
+    }
+
+    fn key(&self) -> String {
+        format!("{:?}:{}", self.provider, self.account_address)
+    }
+}
+
+/// A borrow position to watch, configured as `provider:address`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MonitoredLendingAccount {
+    pub provider: LendingProvider,
+    pub account_address: String,
+}
+
+impl MonitoredLendingAccount {
+    pub fn parse(entry: &str) -> AppResult<Self> {
+        let (provider, account_address) = entry.trim().split_once(':').ok_or_else(|| {
+            AppError::Validation(format!(
+                "Lending account '{}' must be provider:address",
+                entry
+            ))
+        })?;
+        let provider = match provider.trim().to_ascii_lowercase().as_str() {
+            "kamino" => LendingProvider::Kamino,
+            "marginfi" => LendingProvider::Marginfi,
+            other => {
+                return Err(AppError::Validation(format!(
+                    "Unknown lending provider '{}'",
+                    other
+                )))
+            }
+        };
+        let account_address = account_address.trim();
+        if account_address.is_empty() {
+            return Err(AppError::Validation(format!(
+                "Lending account '{}' has no address",
+                entry
+            )));
+        }
+
+        Ok(Self {
+            provider,
+            account_address: account_address.to_string(),
+        })
+    }
+
+    /// Reads `LENDING_MONITORED_ACCOUNTS`, e.g. `kamino:<obligation>,marginfi:<account>`.
+    /// Invalid entries are logged and skipped.
+    pub fn list_from_env() -> Vec<Self> {
+        std::env::var("LENDING_MONITORED_ACCOUNTS")
+            .unwrap_or_default()
+            .split(',')
+            .filter(|entry| !entry.trim().is_empty())
+            .filter_map(|entry| match Self::parse(entry) {
+                Ok(account) => Some(account),
+                Err(e) => {
+                    tracing::warn!("Skipping monitored lending account: {}", e);
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+pub trait LendingHealthSource: Send + Sync {
+    async fn position_health(
+        &self,
+        account: &MonitoredLendingAccount,
+    ) -> AppResult<LendingPositionHealth>;
+}
+
+/// Reads position health from the Kamino and Marginfi APIs.
+pub struct LendingVenueHealth {
+    kamino: KaminoVenue,
+    marginfi: MarginfiVenue,
+}
+
+impl LendingVenueHealth {
+    pub fn new(kamino: KaminoVenue, marginfi: MarginfiVenue) -> Self {
+        Self { kamino, marginfi }
+    }
+}
+
+#[async_trait]
+impl LendingHealthSource for LendingVenueHealth {
+    async fn position_health(
+        &self,
+        account: &MonitoredLendingAccount,
+    ) -> AppResult<LendingPositionHealth> {
+        match account.provider {
+            LendingProvider::Kamino => {
+                self.kamino
+                    .get_position_health(&account.account_address)
+                    .await
+            }
+            LendingProvider::Marginfi => {
+                self.marginfi
+                    .get_position_health(&account.account_address)
+                    .await
+            }
+        }
+    }
+}
+
+/// Tracks the risk level of each lending position and emits an event whenever a
+/// position escalates or recovers. Events fire on transitions only, so a position
+/// sitting at the warning level is not re-announced on every poll.
+pub struct LiquidationRiskMonitor {
+    thresholds: LiquidationAlertThresholds,
+    levels: RwLock<HashMap<String, LiquidationRiskLevel>>,
+    event_tx: broadcast::Sender<ArbEvent>,
+}
+
+impl LiquidationRiskMonitor {
+    pub fn new(
+        thresholds: LiquidationAlertThresholds,
+        event_tx: broadcast::Sender<ArbEvent>,
+    ) -> Self {
+        Self {
+            thresholds,
+            levels: RwLock::new(HashMap::new()),
+            event_tx,
+        }
+    }
+
+    pub async fn evaluate(&self, position: &LendingPositionHealth) -> LiquidationRiskLevel {
+        let thresholds = &self.thresholds;
+        let level = thresholds.classify(position.health_factor);
+
+        let previous = {
+            let mut levels = self.levels.write().await;
+            levels
+                .insert(position.key(), level)
+                .unwrap_or(LiquidationRiskLevel::Healthy)
+        };
+
+        if level == previous {
+            return level;
+        }
+
+        let topic = match level {
+            LiquidationRiskLevel::Critical => topics::lending::RISK_CRITICAL,
+            LiquidationRiskLevel::Warning if level > previous => topics::lending::RISK_WARNING,
+            LiquidationRiskLevel::Warning => return level,
+            LiquidationRiskLevel::Healthy => topics::lending::RISK_RECOVERED,
+        };
+
+        tracing::warn!(
+            "Lending position {} ({:?}) health factor {:.3}: {:?} -> {:?}",
+            position.account_address,
+            position.provider,
+            position.health_factor,
+            previous,
+            level
+        );
+
+        self.emit(
+            "lending_risk_changed",
+            topic,
+            serde_json::json!({
+                "provider": position.provider,
+                "account_address": position.account_address,
+                "health_factor": position.health_factor,
+                "borrowed_value_usd": position.borrowed_value_usd,
+                "level": level,
+                "previous_level": previous,
+                "warning_health_factor": thresholds.warning_health_factor,
+                "critical_health_factor": thresholds.critical_health_factor,
+            }),
+        );
+
+        level
+    }
+
+    /// Fetches and evaluates every account once. A failed fetch leaves the account at its
+    /// last known level.
+    pub async fn poll(
+        &self,
+        source: &dyn LendingHealthSource,
+        accounts: &[MonitoredLendingAccount],
+    ) {
+        for account in accounts {
+            match source.position_health(account).await {
+                Ok(health) => {
+                    self.evaluate(&health).await;
+                }
+                Err(e) => tracing::warn!(
+                    "Lending health check failed for {} ({:?}): {}",
+                    account.account_address,
+                    account.provider,
+                    e
+                ),
+            }
+        }
+    }
+
+    pub fn start_polling(
+        self: &Arc<Self>,
+        source: Arc<dyn LendingHealthSource>,
+        accounts: Vec<MonitoredLendingAccount>,
+        poll_interval: Duration,
+    ) {
+        let monitor = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(poll_interval);
+            loop {
+                interval.tick().await;
+                monitor.poll(source.as_ref(), &accounts).await;
+            }
+        });
+    }
+
+    fn emit(&self, event_type: &str, topic: &str, payload: serde_json::Value) {
+        broadcast_event(
+            &self.event_tx,
+            ArbEvent::new(
+                event_type,
+                EventSource::External("lending_monitor".to_string()),
+                topic,
+                payload,
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Serves the health factor currently set for each account; unknown accounts fail.
+    #[derive(Default)]
+    struct ScriptedHealth {
+        health_factors: Mutex<HashMap<String, f64>>,
+    }
+
+    impl ScriptedHealth {
+        fn set(&self, account_address: &str, health_factor: f64) {
+            self.health_factors
+                .lock()
+                .unwrap()
+                .insert(account_address.to_string(), health_factor);
+        }
+    }
+
+    #[async_trait]
+    impl LendingHealthSource for ScriptedHealth {
+        async fn position_health(
+            &self,
+            account: &MonitoredLendingAccount,
+        ) -> AppResult<LendingPositionHealth> {
+            let health_factor = *self
+                .health_factors
+                .lock()
+                .unwrap()
+                .get(&account.account_address)
+                .ok_or_else(|| AppError::ExternalApi("health request failed".to_string()))?;
+            Ok(LendingPositionHealth {
+                provider: account.provider,
+                account_address: account.account_address.clone(),
+                health_factor,
+                borrowed_value_usd: 1000.0,
+            })
+        }
+    }
+
+    fn account(provider: LendingProvider, account_address: &str) -> MonitoredLendingAccount {
+        MonitoredLendingAccount {
+            provider,
+            account_address: account_address.to_string(),
+        }
+    }
+
+    fn drain_topics(rx: &mut broadcast::Receiver<ArbEvent>) -> Vec<String> {
+        let mut topics = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            topics.push(event.topic);
+        }
+        topics
+    }
+
+    #[tokio::test]
+    async fn test_polled_health_factor_escalates_through_thresholds() {
+        let (event_tx, mut rx) = broadcast::channel(16);
+        let monitor = LiquidationRiskMonitor::new(LiquidationAlertThresholds::default(), event_tx);
+        let source = ScriptedHealth::default();
+        // The Marginfi account never answers and must not hold up the Kamino one
+        let accounts = vec![
+            account(LendingProvider::Marginfi, "marginfi-1"),
+            account(LendingProvider::Kamino, "obligation-1"),
+        ];
+
+        source.set("obligation-1", 1.6);
+        monitor.poll(&source, &accounts).await;
+        assert!(drain_topics(&mut rx).is_empty());
+
+        source.set("obligation-1", 1.2);
+        monitor.poll(&source, &accounts).await;
+        assert_eq!(drain_topics(&mut rx), vec![topics::lending::RISK_WARNING]);
+
+        source.set("obligation-1", 1.15);
+        monitor.poll(&source, &accounts).await;
+        assert!(drain_topics(&mut rx).is_empty());
+
+        source.set("obligation-1", 1.05);
+        monitor.poll(&source, &accounts).await;
+        let event = rx.try_recv().unwrap();
+        assert_eq!(event.topic, topics::lending::RISK_CRITICAL);
+        assert_eq!(event.payload["account_address"], "obligation-1");
+        assert_eq!(event.payload["previous_level"], "warning");
+
+        // Still critical: no repeat alert
+        source.set("obligation-1", 1.02);
+        monitor.poll(&source, &accounts).await;
+        assert!(drain_topics(&mut rx).is_empty());
+
+        source.set("obligation-1", 1.4);
+        monitor.poll(&source, &accounts).await;
+        assert_eq!(drain_topics(&mut rx), vec![topics::lending::RISK_RECOVERED]);
+
+        let levels = monitor.levels.read().await;
+        assert_eq!(levels.len(), 1);
+        assert_eq!(levels.values().next(), Some(&LiquidationRiskLevel::Healthy));
+    }
+
+    #[tokio::test]
+    async fn test_straight_to_critical_skips_warning() {
+        let (event_tx, mut rx) = broadcast::channel(16);
+        let monitor = LiquidationRiskMonitor::new(LiquidationAlertThresholds::default(), event_tx);
+        let source = ScriptedHealth::default();
+        let accounts = vec![account(LendingProvider::Kamino, "obligation-1")];
+
+        source.set("obligation-1", 1.0);
+        monitor.poll(&source, &accounts).await;
+        assert_eq!(drain_topics(&mut rx), vec![topics::lending::RISK_CRITICAL]);
+
+        // Improving from critical to warning is not announced as a new warning
+        source.set("obligation-1", 1.2);
+        monitor.poll(&source, &accounts).await;
+        assert!(drain_topics(&mut rx).is_empty());
+    }
+
+    #[test]
+    fn test_monitored_account_parsing() {
+        assert_eq!(
+            MonitoredLendingAccount::parse(" Kamino:Obl1 ").unwrap(),
+            account(LendingProvider::Kamino, "Obl1")
+        );
+        assert_eq!(
+            MonitoredLendingAccount::parse("marginfi:Acc1").unwrap(),
+            account(LendingProvider::Marginfi, "Acc1")
+        );
+        assert!(MonitoredLendingAccount::parse("solend:Acc1").is_err());
+        assert!(MonitoredLendingAccount::parse("kamino:").is_err());
+        assert!(MonitoredLendingAccount::parse("Obl1").is_err());
+    }
+
+    #[test]
+    fn test_thresholds_validation() {
+        assert!(LiquidationAlertThresholds::default().validate().is_ok());
+        assert!(LiquidationAlertThresholds {
+            warning_health_factor: 1.1,
+            critical_health_factor: 1.2,
+        }
+        .validate()
+        .is_err());
+        assert!(LiquidationAlertThresholds {
+            critical_health_factor: 0.9,
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+    }
+}
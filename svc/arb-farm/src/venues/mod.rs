@@ -1,9 +1,13 @@
 pub mod curves;
 pub mod dex;
+pub mod fees;
 pub mod lending;
+pub mod quote_compare;
 pub mod traits;
 
 pub use curves::{MoonshotVenue, PumpFunVenue};
 pub use dex::JupiterVenue;
+pub use fees::{compare_fees, FeeCompareQuery, FeeComparison};
 pub use lending::{KaminoVenue, MarginfiVenue};
+pub use quote_compare::{compare_quotes, CompareQuoteRequest, QuoteComparison, QuoteSide};
 pub use traits::*;
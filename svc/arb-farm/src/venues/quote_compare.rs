@@ -0,0 +1,323 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::execution::SOL_MINT;
+use crate::venues::{MevVenue, Quote, QuoteParams};
+
+const DEFAULT_COMPARE_SLIPPAGE_BPS: u16 = 100;
+const VENUE_QUOTE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuoteSide {
+    #[default]
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CompareQuoteRequest {
+    pub mint: String,
+    /// Size in input base units: lamports for buys, token base units for sells.
+    pub amount: u64,
+    #[serde(default)]
+    pub side: QuoteSide,
+    pub slippage_bps: Option<u16>,
+}
+
+impl CompareQuoteRequest {
+    pub fn to_quote_params(&self) -> QuoteParams {
+        let (input_mint, output_mint) = match self.side {
+            QuoteSide::Buy => (SOL_MINT.to_string(), self.mint.clone()),
+            QuoteSide::Sell => (self.mint.clone(), SOL_MINT.to_string()),
+        };
+
+        QuoteParams {
+            input_mint,
+            output_mint,
+            amount_lamports: self.amount,
+            slippage_bps: self.slippage_bps.unwrap_or(DEFAULT_COMPARE_SLIPPAGE_BPS),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RankedQuote {
+    pub rank: usize,
+    pub venue: String,
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub price_impact_bps: i32,
+    pub fee_amount: u64,
+    /// Output units received per input unit.
+    pub effective_rate: f64,
+    /// How much less output this venue returns than the best quote.
+    pub shortfall_vs_best_bps: i32,
+    pub route_plan: serde_json::Value,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SkippedVenue {
+    pub venue: String,
+    pub note: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct QuoteComparison {
+    pub input_mint: String,
+    pub output_mint: String,
+    pub amount: u64,
+    pub best_venue: Option<String>,
+    pub quotes: Vec<RankedQuote>,
+    pub skipped: Vec<SkippedVenue>,
+}
+
+/// Requests a quote from every venue concurrently and ranks them by output received
+/// (fees are already netted out), breaking ties on price impact then fees. Venues
+/// that can't quote the pair, error or time out are listed in `skipped`.
+pub async fn compare_quotes(venues: &[Arc<dyn MevVenue>], params: &QuoteParams) -> QuoteComparison {
+    let results = futures::future::join_all(venues.iter().map(|venue| async move {
+        let name = venue.name().to_string();
+
+        if let Some(reason) = venue.unsupported_quote_reason(params) {
+            return (name, Err(reason));
+        }
+
+        let result = match tokio::time::timeout(VENUE_QUOTE_TIMEOUT, venue.get_quote(params)).await
+        {
+            Ok(Ok(quote)) if quote.output_amount == 0 => Err("returned an empty quote".to_string()),
+            Ok(Ok(quote)) => Ok(quote),
+            Ok(Err(e)) => Err(format!("can't quote this mint: {}", e)),
+            Err(_) => Err(format!(
+                "timed out after {}s",
+                VENUE_QUOTE_TIMEOUT.as_secs()
+            )),
+        };
+        (name, result)
+    }))
+    .await;
+
+    let mut quotes: Vec<(String, Quote)> = Vec::new();
+    let mut skipped = Vec::new();
+    for (venue, result) in results {
+        match result {
+            Ok(quote) => quotes.push((venue, quote)),
+            Err(note) => {
+                tracing::debug!(venue = %venue, note = %note, "Venue skipped in quote comparison");
+                skipped.push(SkippedVenue { venue, note });
+            }
+        }
+    }
+
+    quotes.sort_by(|(_, a), (_, b)| {
+        b.output_amount
+            .cmp(&a.output_amount)
+            .then(a.price_impact_bps.cmp(&b.price_impact_bps))
+            .then(a.fee_amount.cmp(&b.fee_amount))
+    });
+
+    let best_output = quotes.first().map(|(_, q)| q.output_amount).unwrap_or(0);
+    let ranked: Vec<RankedQuote> = quotes
+        .into_iter()
+        .enumerate()
+        .map(|(i, (venue, quote))| RankedQuote {
+            rank: i + 1,
+            venue,
+            input_amount: quote.input_amount,
+            output_amount: quote.output_amount,
+            price_impact_bps: quote.price_impact_bps,
+            fee_amount: quote.fee_amount,
+            effective_rate: if quote.input_amount > 0 {
+                quote.output_amount as f64 / quote.input_amount as f64
+            } else {
+                0.0
+            },
+            shortfall_vs_best_bps: (((best_output - quote.output_amount) as f64
+                / best_output as f64)
+                * 10_000.0)
+                .round() as i32,
+            route_plan: quote.route_plan,
+            expires_at: quote.expires_at,
+        })
+        .collect();
+
+    QuoteComparison {
+        input_mint: params.input_mint.clone(),
+        output_mint: params.output_mint.clone(),
+        amount: params.amount_lamports,
+        best_venue: ranked.first().map(|q| q.venue.clone()),
+        quotes: ranked,
+        skipped,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{AppError, AppResult};
+    use crate::models::{Signal, VenueType};
+    use crate::venues::ProfitEstimate;
+    use async_trait::async_trait;
+    use uuid::Uuid;
+
+    const MINT: &str = "7GCihgDB8fe6KNjn2MYtkzZcRjQy3t9GHdC8uHYmW2hr";
+
+    struct MockVenue {
+        name: &'static str,
+        output_amount: Option<u64>,
+        price_impact_bps: i32,
+        fee_amount: u64,
+        buy_only: bool,
+    }
+
+    impl MockVenue {
+        fn quoting(name: &'static str, output_amount: u64, price_impact_bps: i32) -> Self {
+            Self {
+                name,
+                output_amount: Some(output_amount),
+                price_impact_bps,
+                fee_amount: 0,
+                buy_only: false,
+            }
+        }
+
+        fn failing(name: &'static str) -> Self {
+            Self {
+                name,
+                output_amount: None,
+                price_impact_bps: 0,
+                fee_amount: 0,
+                buy_only: false,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl MevVenue for MockVenue {
+        fn venue_id(&self) -> Uuid {
+            Uuid::nil()
+        }
+
+        fn venue_type(&self) -> VenueType {
+            VenueType::DexAmm
+        }
+
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn scan_for_signals(&self) -> AppResult<Vec<Signal>> {
+            Ok(Vec::new())
+        }
+
+        async fn estimate_profit(&self, _signal: &Signal) -> AppResult<ProfitEstimate> {
+            Err(AppError::Internal("not used".to_string()))
+        }
+
+        async fn get_quote(&self, params: &QuoteParams) -> AppResult<Quote> {
+            let output_amount = self
+                .output_amount
+                .ok_or_else(|| AppError::ExternalApi("No routes found".to_string()))?;
+
+            Ok(Quote {
+                input_mint: params.input_mint.clone(),
+                output_mint: params.output_mint.clone(),
+                input_amount: params.amount_lamports,
+                output_amount,
+                price_impact_bps: self.price_impact_bps,
+                fee_amount: self.fee_amount,
+                route_plan: serde_json::json!({ "venue": self.name }),
+                expires_at: chrono::Utc::now(),
+            })
+        }
+
+        fn unsupported_quote_reason(&self, params: &QuoteParams) -> Option<String> {
+            (self.buy_only && params.input_mint != SOL_MINT).then(|| "buys only".to_string())
+        }
+
+        async fn is_healthy(&self) -> bool {
+            true
+        }
+    }
+
+    fn buy(amount: u64) -> QuoteParams {
+        CompareQuoteRequest {
+            mint: MINT.to_string(),
+            amount,
+            side: QuoteSide::Buy,
+            slippage_bps: None,
+        }
+        .to_quote_params()
+    }
+
+    #[tokio::test]
+    async fn test_quotes_ranked_by_output_then_impact() {
+        let venues: Vec<Arc<dyn MevVenue>> = vec![
+            Arc::new(MockVenue::quoting("Jupiter", 9_800_000, 40)),
+            Arc::new(MockVenue::quoting("Raydium", 9_800_000, 25)),
+            Arc::new(MockVenue::quoting("pump.fun", 10_000_000, 120)),
+        ];
+
+        let comparison = compare_quotes(&venues, &buy(1_000_000_000)).await;
+
+        let order: Vec<&str> = comparison.quotes.iter().map(|q| q.venue.as_str()).collect();
+        assert_eq!(order, vec!["pump.fun", "Raydium", "Jupiter"]);
+        assert_eq!(comparison.best_venue.as_deref(), Some("pump.fun"));
+        assert_eq!(comparison.quotes[0].rank, 1);
+        assert_eq!(comparison.quotes[0].shortfall_vs_best_bps, 0);
+        assert_eq!(comparison.quotes[1].shortfall_vs_best_bps, 200);
+        assert!(comparison.skipped.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_venues_that_cannot_quote_are_skipped_with_note() {
+        let venues: Vec<Arc<dyn MevVenue>> = vec![
+            Arc::new(MockVenue::quoting("Jupiter", 5_000, 10)),
+            Arc::new(MockVenue::failing("Raydium")),
+            Arc::new(MockVenue {
+                buy_only: true,
+                ..MockVenue::quoting("moonshot", 9_000, 10)
+            }),
+            Arc::new(MockVenue::quoting("pump.fun", 0, 0)),
+        ];
+
+        let sell = CompareQuoteRequest {
+            mint: MINT.to_string(),
+            amount: 1_000_000,
+            side: QuoteSide::Sell,
+            slippage_bps: Some(50),
+        }
+        .to_quote_params();
+        assert_eq!(sell.output_mint, SOL_MINT);
+
+        let comparison = compare_quotes(&venues, &sell).await;
+
+        assert_eq!(comparison.quotes.len(), 1);
+        assert_eq!(comparison.best_venue.as_deref(), Some("Jupiter"));
+
+        let notes: Vec<(&str, &str)> = comparison
+            .skipped
+            .iter()
+            .map(|s| (s.venue.as_str(), s.note.as_str()))
+            .collect();
+        assert_eq!(notes.len(), 3);
+        assert!(notes
+            .iter()
+            .any(|(v, n)| *v == "Raydium" && n.contains("No routes found")));
+        assert!(notes.contains(&("moonshot", "buys only")));
+        assert!(notes.contains(&("pump.fun", "returned an empty quote")));
+    }
+
+    #[tokio::test]
+    async fn test_no_capable_venues_has_no_best() {
+        let venues: Vec<Arc<dyn MevVenue>> = vec![Arc::new(MockVenue::failing("Jupiter"))];
+
+        let comparison = compare_quotes(&venues, &buy(1_000)).await;
+
+        assert!(comparison.quotes.is_empty());
+        assert!(comparison.best_venue.is_none());
+        assert_eq!(comparison.skipped.len(), 1);
+    }
+}
@@ -4,6 +4,7 @@ use uuid::Uuid;
 
 use crate::error::AppResult;
 use crate::models::{Signal, VenueType};
+use crate::venues::curves::math::bps_of;
 
 #[async_trait]
 pub trait MevVenue: Send + Sync {
@@ -17,11 +18,65 @@ pub trait MevVenue: Send + Sync {
 
     async fn get_quote(&self, params: &QuoteParams) -> AppResult<Quote>;
 
+    /// Why this venue can't quote `params` at all (e.g. wrong direction), checked
+    /// before any request is made. None means the venue will try.
+    fn unsupported_quote_reason(&self, _params: &QuoteParams) -> Option<String> {
+        None
+    }
+
     async fn is_healthy(&self) -> bool;
 
     async fn scan_for_token_data(&self) -> AppResult<Vec<VenueTokenData>> {
         Ok(Vec::new())
     }
+
+    /// Fees for buying `size_lamports` worth and selling it back. None for venues
+    /// that don't trade (e.g. lending).
+    fn fee_estimate(&self, _size_lamports: u64) -> Option<FeeEstimate> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeComponent {
+    pub name: String,
+    pub buy_bps: u32,
+    pub sell_bps: u32,
+    /// Round-trip cost of this component in lamports.
+    pub amount_lamports: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeEstimate {
+    pub venue: String,
+    pub size_lamports: u64,
+    pub components: Vec<FeeComponent>,
+    pub total_bps: u32,
+    pub total_fee_lamports: u64,
+}
+
+impl FeeEstimate {
+    /// Builds a breakdown from `(name, buy_bps, sell_bps)` rates, assuming the sell
+    /// returns roughly the size that was bought.
+    pub fn from_rates(venue: &str, size_lamports: u64, rates: &[(&str, u32, u32)]) -> Self {
+        let components: Vec<FeeComponent> = rates
+            .iter()
+            .map(|(name, buy_bps, sell_bps)| FeeComponent {
+                name: name.to_string(),
+                buy_bps: *buy_bps,
+                sell_bps: *sell_bps,
+                amount_lamports: bps_of(size_lamports, buy_bps + sell_bps),
+            })
+            .collect();
+
+        Self {
+            venue: venue.to_string(),
+            size_lamports,
+            total_bps: components.iter().map(|c| c.buy_bps + c.sell_bps).sum(),
+            total_fee_lamports: components.iter().map(|c| c.amount_lamports).sum(),
+            components,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -65,6 +120,9 @@ pub struct Quote {
     pub input_amount: u64,
     pub output_amount: u64,
     pub price_impact_bps: i32,
+    /// Venue fees in input-mint base units; `output_amount` is already net of them.
+    #[serde(default)]
+    pub fee_amount: u64,
     pub route_plan: serde_json::Value,
     pub expires_at: chrono::DateTime<chrono::Utc>,
 }
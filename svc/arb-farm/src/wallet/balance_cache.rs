@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::{broadcast, RwLock};
+use tracing::{debug, warn};
+
+use crate::error::AppResult;
+use crate::events::{topics, ArbEvent};
+
+pub const DEFAULT_BALANCE_CACHE_TTL_SECS: u64 = 5;
+
+#[derive(Debug, Clone, Copy)]
+pub struct CachedBalance {
+    pub lamports: u64,
+    pub fetched_at: Instant,
+}
+
+impl CachedBalance {
+    pub fn age(&self) -> Duration {
+        self.fetched_at.elapsed()
+    }
+
+    pub fn sol(&self) -> f64 {
+        self.lamports as f64 / 1_000_000_000.0
+    }
+}
+
+pub struct BalanceCache {
+    ttl: Duration,
+    entries: RwLock<HashMap<String, CachedBalance>>,
+    /// Bumped on every invalidation so a fetch that started earlier doesn't cache its result
+    generation: AtomicU64,
+}
+
+impl BalanceCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: RwLock::new(HashMap::new()),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let ttl_secs = std::env::var("WALLET_BALANCE_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_BALANCE_CACHE_TTL_SECS);
+        Self::new(Duration::from_secs(ttl_secs))
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    pub async fn get(&self, address: &str) -> Option<CachedBalance> {
+        let entries = self.entries.read().await;
+        entries
+            .get(address)
+            .filter(|cached| cached.age() < self.ttl)
+            .copied()
+    }
+
+    pub async fn store(&self, address: &str, lamports: u64) -> CachedBalance {
+        let cached = CachedBalance {
+            lamports,
+            fetched_at: Instant::now(),
+        };
+        self.entries
+            .write()
+            .await
+            .insert(address.to_string(), cached);
+        cached
+    }
+
+    pub async fn get_or_fetch<F, Fut>(&self, address: &str, fetch: F) -> AppResult<CachedBalance>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = AppResult<u64>>,
+    {
+        if let Some(cached) = self.get(address).await {
+            return Ok(cached);
+        }
+
+        let generation = self.generation.load(Ordering::SeqCst);
+        let lamports = fetch().await?;
+        let cached = CachedBalance {
+            lamports,
+            fetched_at: Instant::now(),
+        };
+
+        // The generation is checked under the write lock that invalidations also take
+        let mut entries = self.entries.write().await;
+        if self.generation.load(Ordering::SeqCst) == generation {
+            entries.insert(address.to_string(), cached);
+        } else {
+            debug!(
+                "💰 Balance for {} fetched across an invalidation - not cached",
+                address
+            );
+        }
+        Ok(cached)
+    }
+
+    pub async fn invalidate(&self, address: &str) {
+        let mut entries = self.entries.write().await;
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        if entries.remove(address).is_some() {
+            debug!("💰 Balance cache invalidated for {}", address);
+        }
+    }
+
+    pub async fn invalidate_all(&self) {
+        let mut entries = self.entries.write().await;
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        entries.clear();
+    }
+
+    /// Drops cached balances whenever a buy or sell settles so the next read hits RPC.
+    /// `PositionManager` already invalidates synchronously when it records a fill; this
+    /// covers settlements announced only through events.
+    pub fn spawn_settlement_invalidator(self: Arc<Self>, event_tx: &broadcast::Sender<ArbEvent>) {
+        let mut event_rx = event_tx.subscribe();
+        tokio::spawn(async move {
+            loop {
+                match event_rx.recv().await {
+                    Ok(event) => {
+                        if event.topic == topics::position::OPENED
+                            || event.topic == topics::position::CLOSED
+                        {
+                            self.invalidate_all().await;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!(
+                            "Balance cache invalidator lagged {} events - clearing cache",
+                            skipped
+                        );
+                        self.invalidate_all().await;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+}
+
+impl Default for BalanceCache {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(DEFAULT_BALANCE_CACHE_TTL_SECS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventSource;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    const WALLET: &str = "TestWallet1111111111111111111111111111111111";
+
+    async fn fetch_counting(
+        cache: &BalanceCache,
+        calls: &Arc<AtomicU32>,
+        lamports: u64,
+    ) -> CachedBalance {
+        let calls = calls.clone();
+        cache
+            .get_or_fetch(WALLET, || async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(lamports)
+            })
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_cached_reads_within_ttl() {
+        let cache = BalanceCache::new(Duration::from_secs(60));
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let first = fetch_counting(&cache, &calls, 1_000_000_000).await;
+        let second = fetch_counting(&cache, &calls, 2_000_000_000).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(first.lamports, 1_000_000_000);
+        assert_eq!(second.lamports, 1_000_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_expired_entry_is_refetched() {
+        let cache = BalanceCache::new(Duration::from_millis(20));
+        let calls = Arc::new(AtomicU32::new(0));
+
+        fetch_counting(&cache, &calls, 1).await;
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        let refreshed = fetch_counting(&cache, &calls, 2).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(refreshed.lamports, 2);
+    }
+
+    #[tokio::test]
+    async fn test_invalidation_after_settlement_forces_refetch() {
+        let cache = Arc::new(BalanceCache::new(Duration::from_secs(60)));
+        let (event_tx, _) = broadcast::channel(16);
+        cache.clone().spawn_settlement_invalidator(&event_tx);
+
+        let calls = Arc::new(AtomicU32::new(0));
+        fetch_counting(&cache, &calls, 5_000_000_000).await;
+
+        event_tx
+            .send(ArbEvent::new(
+                "position.exit_completed",
+                EventSource::System,
+                topics::position::CLOSED,
+                serde_json::json!({}),
+            ))
+            .unwrap();
+
+        for _ in 0..50 {
+            if cache.get(WALLET).await.is_none() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        let after = fetch_counting(&cache, &calls, 4_500_000_000).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(after.lamports, 4_500_000_000);
+
+        cache.invalidate(WALLET).await;
+        assert!(cache.get(WALLET).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_spanning_invalidation_is_not_cached() {
+        let cache = Arc::new(BalanceCache::new(Duration::from_secs(60)));
+        let (started_tx, started_rx) = tokio::sync::oneshot::channel();
+        let (release_tx, release_rx) = tokio::sync::oneshot::channel::<()>();
+
+        let reader = {
+            let cache = cache.clone();
+            tokio::spawn(async move {
+                cache
+                    .get_or_fetch(WALLET, || async move {
+                        started_tx.send(()).unwrap();
+                        release_rx.await.unwrap();
+                        Ok(5_000_000_000)
+                    })
+                    .await
+                    .unwrap()
+            })
+        };
+
+        // A trade settles while the pre-trade balance is still in flight
+        started_rx.await.unwrap();
+        cache.invalidate_all().await;
+        release_tx.send(()).unwrap();
+
+        let stale = reader.await.unwrap();
+        assert_eq!(stale.lamports, 5_000_000_000);
+        assert!(cache.get(WALLET).await.is_none());
+
+        let calls = Arc::new(AtomicU32::new(0));
+        let fresh = fetch_counting(&cache, &calls, 4_500_000_000).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(fresh.lamports, 4_500_000_000);
+        assert_eq!(cache.get(WALLET).await.unwrap().lamports, 4_500_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_age_reporting() {
+        let cache = BalanceCache::new(Duration::from_secs(60));
+        let stored = cache.store(WALLET, 42).await;
+        assert!(stored.age() < Duration::from_millis(20));
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        let cached = cache.get(WALLET).await.unwrap();
+        assert!(cached.age() >= Duration::from_millis(30));
+        assert_eq!(cached.fetched_at, stored.fetched_at);
+    }
+}
@@ -8,6 +8,7 @@ use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
 
 use super::policy::{ArbFarmPolicy, DailyUsage, PolicyViolation};
+use super::sign_audit::{SignAuditEntry, SignAuditLog};
 use super::turnkey::{DelegationStatus, SignRequest, SignResult, WalletStatus};
 use crate::error::{AppError, AppResult};
 
@@ -15,6 +16,7 @@ pub struct DevWalletSigner {
     keypair: Option<Keypair>,
     wallet_address: Option<String>,
     wallet_status: Arc<RwLock<WalletStatus>>,
+    audit_log: Option<Arc<SignAuditLog>>,
 }
 
 impl DevWalletSigner {
@@ -51,9 +53,15 @@ impl DevWalletSigner {
             keypair,
             wallet_address: address,
             wallet_status: Arc::new(RwLock::new(WalletStatus::default())),
+            audit_log: None,
         })
     }
 
+    pub fn with_audit_log(mut self, audit_log: Arc<SignAuditLog>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
     pub fn is_configured(&self) -> bool {
         self.keypair.is_some()
     }
@@ -147,7 +155,21 @@ impl DevWalletSigner {
     }
 
     pub async fn sign_transaction(&self, request: SignRequest) -> AppResult<SignResult> {
-        if let Err(violation) = self.validate_transaction(&request).await {
+        let result = self.sign_with_policy(&request).await;
+        if let Some(audit_log) = &self.audit_log {
+            audit_log
+                .record(SignAuditEntry::from_attempt(
+                    "dev_private_key",
+                    &request,
+                    &result,
+                ))
+                .await;
+        }
+        result
+    }
+
+    async fn sign_with_policy(&self, request: &SignRequest) -> AppResult<SignResult> {
+        if let Err(violation) = self.validate_transaction(request).await {
             return Ok(SignResult::policy_error(violation));
         }
 
@@ -250,6 +272,127 @@ impl DevWalletSigner {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::wallet::policy::PolicyViolationType;
+    use crate::wallet::sign_audit::{SignOutcome, TransactionFormat};
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    };
+    use std::str::FromStr;
+
+    fn transfer_tx_base64(payer: &Pubkey, lamports: u64) -> String {
+        let system_program = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+        let mut data = 2u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&lamports.to_le_bytes());
+        let ix = Instruction::new_with_bytes(
+            system_program,
+            &data,
+            vec![
+                AccountMeta::new(*payer, true),
+                AccountMeta::new(Pubkey::new_unique(), false),
+            ],
+        );
+        let tx = Transaction::new_with_payer(&[ix], Some(payer));
+        base64::Engine::encode(
+            &base64::engine::general_purpose::STANDARD,
+            bincode::serialize(&tx).unwrap(),
+        )
+    }
+
+    async fn audited_signer() -> (DevWalletSigner, Arc<SignAuditLog>, String) {
+        let keypair = Keypair::new();
+        let secret = keypair.to_base58_string();
+        let log = Arc::new(SignAuditLog::new());
+        let signer = DevWalletSigner::new(Some(&secret), None)
+            .unwrap()
+            .with_audit_log(log.clone());
+        signer.connect().await.unwrap();
+        (signer, log, secret)
+    }
+
+    fn sign_request(transaction_base64: String, amount_lamports: u64) -> SignRequest {
+        SignRequest {
+            transaction_base64,
+            estimated_amount_lamports: amount_lamports,
+            estimated_profit_lamports: None,
+            edge_id: None,
+            description: "test transfer".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sign_records_audit_entry_with_decoded_summary() {
+        let (signer, log, secret) = audited_signer().await;
+        let payer = signer.get_pubkey().unwrap();
+        let tx = transfer_tx_base64(&payer, 25_000_000);
+
+        let result = signer
+            .sign_transaction(sign_request(tx.clone(), 25_000_000))
+            .await
+            .unwrap();
+        assert!(result.success);
+
+        let entries = log.recent(None, None, 10);
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.signer, "dev_private_key");
+        assert_eq!(entry.outcome, SignOutcome::Signed);
+        assert!(entry.policy_passed);
+        assert_eq!(entry.signature, result.signature);
+        assert_eq!(entry.summary.format, TransactionFormat::Legacy);
+        assert_eq!(entry.summary.fee_payer, Some(payer.to_string()));
+        assert_eq!(entry.summary.instructions.len(), 1);
+        let ix = &entry.summary.instructions[0];
+        assert_eq!(ix.program_label.as_deref(), Some("System Program"));
+        assert_eq!(ix.transfer_lamports, Some(25_000_000));
+
+        let serialized = serde_json::to_string(entry).unwrap();
+        assert!(!serialized.contains(&secret));
+        assert!(!serialized.contains(&tx));
+    }
+
+    #[tokio::test]
+    async fn test_policy_rejection_is_audited() {
+        let (signer, log, _) = audited_signer().await;
+        let payer = signer.get_pubkey().unwrap();
+        let amount = 6_000_000_000;
+
+        let result = signer
+            .sign_transaction(sign_request(transfer_tx_base64(&payer, amount), amount))
+            .await
+            .unwrap();
+        assert!(!result.success);
+
+        let entries = log.recent(None, Some(SignOutcome::PolicyRejected), 10);
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert!(!entry.policy_passed);
+        assert_eq!(
+            entry.policy_violation.as_ref().map(|v| v.violation_type),
+            Some(PolicyViolationType::AmountExceedsLimit)
+        );
+        assert!(entry.signature.is_none());
+        assert_eq!(
+            entry.summary.instructions[0].transfer_lamports,
+            Some(amount)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_undecodable_transaction_still_audited() {
+        let (signer, log, _) = audited_signer().await;
+
+        let result = signer
+            .sign_transaction(sign_request("not-a-transaction".to_string(), 1_000))
+            .await;
+        assert!(result.is_err());
+
+        let entries = log.recent(None, None, 10);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].outcome, SignOutcome::Failed);
+        assert_eq!(entries[0].summary.format, TransactionFormat::Undecodable);
+        assert!(entries[0].error.is_some());
+    }
 
     #[test]
     fn test_signer_without_key() {
@@ -1,7 +1,11 @@
+pub mod balance_cache;
 pub mod dev_signer;
 pub mod policy;
+pub mod sign_audit;
 pub mod turnkey;
 
+pub use balance_cache::{BalanceCache, CachedBalance};
 pub use dev_signer::DevWalletSigner;
 pub use policy::{ArbFarmPolicy, PolicyViolation, ALLOWED_PROGRAMS};
+pub use sign_audit::{SignAuditEntry, SignAuditLog, SignOutcome, TransactionSummary};
 pub use turnkey::{SignRequest, SignResult, TurnkeyConfig, TurnkeySigner, WalletStatus};
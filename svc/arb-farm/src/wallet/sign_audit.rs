@@ -0,0 +1,407 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    message::VersionedMessage,
+    transaction::{Transaction, VersionedTransaction},
+};
+use std::sync::{Arc, RwLock};
+use tracing::warn;
+use uuid::Uuid;
+
+use super::policy::PolicyViolation;
+use super::turnkey::{SignRequest, SignResult};
+use crate::database::repositories::{SignAuditRecord, SignAuditRepository};
+use crate::error::{AppError, AppResult};
+
+const MAX_SIGN_AUDIT_ENTRIES: usize = 10_000;
+const SYSTEM_PROGRAM_ID: &str = "11111111111111111111111111111111";
+const SYSTEM_TRANSFER_DISCRIMINATOR: u32 = 2;
+
+const KNOWN_PROGRAMS: &[(&str, &str)] = &[
+    (
+        "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4",
+        "Jupiter v6 Aggregator",
+    ),
+    ("JUP4Fb2cqiRUcaTHdrPC8h2gNsA2ETXiPDD33WcGuJB", "Jupiter v4"),
+    (
+        "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8",
+        "Raydium AMM v4",
+    ),
+    (
+        "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK",
+        "Raydium CPMM",
+    ),
+    ("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P", "pump.fun"),
+    ("MoonCVVNZFSYkqNXP6bxHLPL6QQJiMagDL3qcqUQTrG", "moonshot"),
+    (SYSTEM_PROGRAM_ID, "System Program"),
+    (
+        "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+        "Token Program",
+    ),
+    (
+        "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+        "Associated Token Program",
+    ),
+    (
+        "ComputeBudget111111111111111111111111111111",
+        "Compute Budget",
+    ),
+];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionFormat {
+    Versioned,
+    Legacy,
+    Undecodable,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InstructionSummary {
+    pub program_id: String,
+    pub program_label: Option<String>,
+    pub account_count: usize,
+    pub data_len: usize,
+    /// Set for System Program transfers, decoded from the instruction data.
+    pub transfer_lamports: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TransactionSummary {
+    pub format: TransactionFormat,
+    pub fee_payer: Option<String>,
+    pub instructions: Vec<InstructionSummary>,
+}
+
+impl TransactionSummary {
+    fn undecodable() -> Self {
+        Self {
+            format: TransactionFormat::Undecodable,
+            fee_payer: None,
+            instructions: Vec::new(),
+        }
+    }
+}
+
+/// Decodes just enough of a base64 transaction to describe what it does. The
+/// transaction bytes themselves are never kept.
+pub fn decode_transaction_summary(transaction_base64: &str) -> TransactionSummary {
+    let bytes = match base64::Engine::decode(
+        &base64::engine::general_purpose::STANDARD,
+        transaction_base64,
+    ) {
+        Ok(bytes) => bytes,
+        Err(_) => return TransactionSummary::undecodable(),
+    };
+
+    if let Ok(tx) = bincode::deserialize::<VersionedTransaction>(&bytes) {
+        let format = match tx.message {
+            VersionedMessage::Legacy(_) => TransactionFormat::Legacy,
+            VersionedMessage::V0(_) => TransactionFormat::Versioned,
+        };
+        let keys = tx.message.static_account_keys();
+        let instructions = tx
+            .message
+            .instructions()
+            .iter()
+            .map(|ix| {
+                summarize_instruction(
+                    keys.get(ix.program_id_index as usize)
+                        .map(|k| k.to_string()),
+                    ix.accounts.len(),
+                    &ix.data,
+                )
+            })
+            .collect();
+        return TransactionSummary {
+            format,
+            fee_payer: keys.first().map(|k| k.to_string()),
+            instructions,
+        };
+    }
+
+    if let Ok(tx) = bincode::deserialize::<Transaction>(&bytes) {
+        let keys = &tx.message.account_keys;
+        let instructions = tx
+            .message
+            .instructions
+            .iter()
+            .map(|ix| {
+                summarize_instruction(
+                    keys.get(ix.program_id_index as usize)
+                        .map(|k| k.to_string()),
+                    ix.accounts.len(),
+                    &ix.data,
+                )
+            })
+            .collect();
+        return TransactionSummary {
+            format: TransactionFormat::Legacy,
+            fee_payer: keys.first().map(|k| k.to_string()),
+            instructions,
+        };
+    }
+
+    TransactionSummary::undecodable()
+}
+
+fn summarize_instruction(
+    program_id: Option<String>,
+    account_count: usize,
+    data: &[u8],
+) -> InstructionSummary {
+    let program_id = program_id.unwrap_or_else(|| "unknown".to_string());
+    let program_label = KNOWN_PROGRAMS
+        .iter()
+        .find(|(id, _)| *id == program_id)
+        .map(|(_, label)| label.to_string());
+    let transfer_lamports = if program_id == SYSTEM_PROGRAM_ID {
+        decode_system_transfer(data)
+    } else {
+        None
+    };
+
+    InstructionSummary {
+        program_id,
+        program_label,
+        account_count,
+        data_len: data.len(),
+        transfer_lamports,
+    }
+}
+
+fn decode_system_transfer(data: &[u8]) -> Option<u64> {
+    if data.len() != 12 {
+        return None;
+    }
+    let discriminator = u32::from_le_bytes(data[..4].try_into().ok()?);
+    if discriminator != SYSTEM_TRANSFER_DISCRIMINATOR {
+        return None;
+    }
+    Some(u64::from_le_bytes(data[4..12].try_into().ok()?))
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SignOutcome {
+    Signed,
+    PolicyRejected,
+    Failed,
+    DryRun,
+}
+
+impl SignOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignOutcome::Signed => "signed",
+            SignOutcome::PolicyRejected => "policy_rejected",
+            SignOutcome::Failed => "failed",
+            SignOutcome::DryRun => "dry_run",
+        }
+    }
+}
+
+/// One signing request and what happened to it. Holds the decoded summary only;
+/// neither the raw transaction nor any key material is recorded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignAuditEntry {
+    pub id: Uuid,
+    pub signer: String,
+    pub description: String,
+    pub edge_id: Option<Uuid>,
+    pub amount_lamports: u64,
+    pub estimated_profit_lamports: Option<i64>,
+    pub summary: TransactionSummary,
+    pub outcome: SignOutcome,
+    pub policy_passed: bool,
+    pub policy_violation: Option<PolicyViolation>,
+    pub signature: Option<String>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl SignAuditEntry {
+    fn from_request(signer: &str, request: &SignRequest, outcome: SignOutcome) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            signer: signer.to_string(),
+            description: request.description.clone(),
+            edge_id: request.edge_id,
+            amount_lamports: request.estimated_amount_lamports,
+            estimated_profit_lamports: request.estimated_profit_lamports,
+            summary: decode_transaction_summary(&request.transaction_base64),
+            outcome,
+            policy_passed: true,
+            policy_violation: None,
+            signature: None,
+            error: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn from_attempt(
+        signer: &str,
+        request: &SignRequest,
+        result: &AppResult<SignResult>,
+    ) -> Self {
+        let mut entry = Self::from_request(signer, request, SignOutcome::Failed);
+        match result {
+            Ok(res) if res.success => {
+                entry.outcome = SignOutcome::Signed;
+                entry.signature = res.signature.clone();
+            }
+            Ok(res) if res.policy_violation.is_some() => {
+                entry.outcome = SignOutcome::PolicyRejected;
+                entry.policy_passed = false;
+                entry.policy_violation = res.policy_violation.clone();
+                entry.error = res.error.clone();
+            }
+            Ok(res) => entry.error = res.error.clone(),
+            Err(e) => entry.error = Some(e.to_string()),
+        }
+        entry
+    }
+
+    pub fn dry_run(
+        signer: &str,
+        request: &SignRequest,
+        validation: &Result<(), PolicyViolation>,
+    ) -> Self {
+        let mut entry = Self::from_request(signer, request, SignOutcome::DryRun);
+        if let Err(violation) = validation {
+            entry.policy_passed = false;
+            entry.policy_violation = Some(violation.clone());
+        }
+        entry
+    }
+}
+
+impl TryFrom<SignAuditRecord> for SignAuditEntry {
+    type Error = AppError;
+
+    fn try_from(record: SignAuditRecord) -> AppResult<Self> {
+        Ok(Self {
+            id: record.id,
+            signer: record.signer,
+            description: record.description,
+            edge_id: record.edge_id,
+            amount_lamports: record.amount_lamports as u64,
+            estimated_profit_lamports: record.estimated_profit_lamports,
+            summary: parse(record.summary)?,
+            outcome: parse(serde_json::Value::String(record.outcome))?,
+            policy_passed: record.policy_passed,
+            policy_violation: record.policy_violation.map(parse).transpose()?,
+            signature: record.signature,
+            error: record.error,
+            created_at: record.created_at,
+        })
+    }
+}
+
+fn parse<T: serde::de::DeserializeOwned>(value: serde_json::Value) -> AppResult<T> {
+    serde_json::from_value(value)
+        .map_err(|e| AppError::Internal(format!("Invalid sign audit record: {}", e)))
+}
+
+/// Shared by both signers so every signing attempt lands in one log. Entries are
+/// kept in memory and, when a repository is attached, persisted to Postgres without
+/// blocking the signer.
+#[derive(Default)]
+pub struct SignAuditLog {
+    entries: RwLock<Vec<SignAuditEntry>>,
+    repository: Option<Arc<SignAuditRepository>>,
+}
+
+impl SignAuditLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_repository(mut self, repository: Arc<SignAuditRepository>) -> Self {
+        self.repository = Some(repository);
+        self
+    }
+
+    pub async fn record(&self, entry: SignAuditEntry) {
+        {
+            let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+            entries.push(entry.clone());
+            if entries.len() > MAX_SIGN_AUDIT_ENTRIES {
+                let drain_count = entries.len() - MAX_SIGN_AUDIT_ENTRIES;
+                entries.drain(..drain_count);
+            }
+        }
+
+        // Persisted in the background so signing never waits on the database
+        if let Some(repo) = self.repository.clone() {
+            tokio::spawn(async move {
+                if let Err(e) = repo.insert(&entry).await {
+                    warn!("Failed to persist sign audit entry {}: {}", entry.id, e);
+                }
+            });
+        }
+    }
+
+    pub async fn list(
+        &self,
+        signer: Option<&str>,
+        outcome: Option<SignOutcome>,
+        limit: usize,
+    ) -> Vec<SignAuditEntry> {
+        if let Some(repo) = &self.repository {
+            match repo
+                .list(signer, outcome.map(|o| o.as_str()), limit as i64)
+                .await
+                .and_then(|records| {
+                    records
+                        .into_iter()
+                        .map(SignAuditEntry::try_from)
+                        .collect::<AppResult<Vec<_>>>()
+                }) {
+                Ok(entries) => return entries,
+                Err(e) => warn!("Sign audit query failed, serving in-memory log: {}", e),
+            }
+        }
+
+        self.recent(signer, outcome, limit)
+    }
+
+    pub fn recent(
+        &self,
+        signer: Option<&str>,
+        outcome: Option<SignOutcome>,
+        limit: usize,
+    ) -> Vec<SignAuditEntry> {
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+        entries
+            .iter()
+            .rev()
+            .filter(|e| signer.map_or(true, |s| e.signer == s))
+            .filter(|e| outcome.map_or(true, |o| e.outcome == o))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_garbage_transaction_is_undecodable() {
+        let summary = decode_transaction_summary("not base64!!");
+        assert_eq!(summary.format, TransactionFormat::Undecodable);
+        assert!(summary.instructions.is_empty());
+    }
+
+    #[test]
+    fn test_decode_system_transfer_data() {
+        let mut data = SYSTEM_TRANSFER_DISCRIMINATOR.to_le_bytes().to_vec();
+        data.extend_from_slice(&42_000u64.to_le_bytes());
+        assert_eq!(decode_system_transfer(&data), Some(42_000));
+
+        data[0] = 0;
+        assert_eq!(decode_system_transfer(&data), None);
+    }
+}
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use super::policy::{ArbFarmPolicy, DailyUsage, PolicyViolation};
+use super::sign_audit::{SignAuditEntry, SignAuditLog};
 use crate::error::{AppError, AppResult};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -122,6 +123,7 @@ pub struct TurnkeySigner {
     client: reqwest::Client,
     config: TurnkeyConfig,
     wallet_status: Arc<RwLock<WalletStatus>>,
+    audit_log: Option<Arc<SignAuditLog>>,
 }
 
 impl TurnkeySigner {
@@ -133,6 +135,7 @@ impl TurnkeySigner {
                 .expect("Failed to create HTTP client"),
             config,
             wallet_status: Arc::new(RwLock::new(WalletStatus::default())),
+            audit_log: None,
         }
     }
 
@@ -144,9 +147,15 @@ impl TurnkeySigner {
                 .expect("Failed to create HTTP client"),
             config,
             wallet_status: Arc::new(RwLock::new(WalletStatus::dev())),
+            audit_log: None,
         }
     }
 
+    pub fn with_audit_log(mut self, audit_log: Arc<SignAuditLog>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
     pub async fn get_status(&self) -> WalletStatus {
         self.wallet_status.read().await.clone()
     }
@@ -214,8 +223,18 @@ impl TurnkeySigner {
     }
 
     pub async fn sign_transaction(&self, request: SignRequest) -> AppResult<SignResult> {
+        let result = self.sign_with_policy(&request).await;
+        if let Some(audit_log) = &self.audit_log {
+            audit_log
+                .record(SignAuditEntry::from_attempt("turnkey", &request, &result))
+                .await;
+        }
+        result
+    }
+
+    async fn sign_with_policy(&self, request: &SignRequest) -> AppResult<SignResult> {
         // Validate against policy first
-        if let Err(violation) = self.validate_transaction(&request).await {
+        if let Err(violation) = self.validate_transaction(request).await {
             return Ok(SignResult::policy_error(violation));
         }
 
@@ -4,6 +4,8 @@ use uuid::Uuid;
 
 use crate::error::{AppError, AppResult};
 
+use super::parser::TransactionParser;
+
 #[derive(Debug, Clone)]
 pub struct HeliusWebhookClient {
     client: reqwest::Client,
@@ -68,6 +70,78 @@ impl Default for TransactionType {
     }
 }
 
+/// Only swaps are turned into KOL trade signals, so that's all we subscribe to by default.
+pub const DEFAULT_WEBHOOK_TRANSACTION_TYPES: &[TransactionType] = &[TransactionType::Swap];
+
+/// `POST /webhooks/register` has always subscribed to transfers as well.
+pub const DEFAULT_REGISTER_TRANSACTION_TYPES: &[TransactionType] =
+    &[TransactionType::Swap, TransactionType::Transfer];
+
+impl TransactionType {
+    /// Accepts Helius names in any case, e.g. `swap` or `NFT_SALE`.
+    pub fn parse(name: &str) -> Option<Self> {
+        serde_json::from_value(serde_json::Value::String(name.trim().to_ascii_uppercase())).ok()
+    }
+
+    pub fn name(&self) -> String {
+        serde_json::to_value(self)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+
+    pub fn matches(&self, event_type: &str) -> bool {
+        *self == Self::Any || self.name().eq_ignore_ascii_case(event_type.trim())
+    }
+
+    /// Like `matches`, but `Swap` also covers events the parser recognises as swaps from
+    /// their swap event, whatever their `type`.
+    pub fn matches_event(&self, event: &EnhancedTransactionEvent) -> bool {
+        match self {
+            Self::Swap => TransactionParser::is_swap_transaction(event),
+            _ => self.matches(&event.transaction_type),
+        }
+    }
+}
+
+/// Comma-separated Helius transaction types. Unknown names are skipped; `None` if nothing
+/// valid remains, so callers can fall back to their own default.
+pub fn parse_transaction_types(spec: &str) -> Option<Vec<TransactionType>> {
+    let mut types = Vec::new();
+    for name in spec.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+        match TransactionType::parse(name) {
+            Some(tx_type) if !types.contains(&tx_type) => types.push(tx_type),
+            Some(_) => {}
+            None => tracing::warn!("Ignoring unknown Helius transaction type: {}", name),
+        }
+    }
+
+    if types.is_empty() {
+        None
+    } else {
+        Some(types)
+    }
+}
+
+/// Drops events whose type isn't in `subscribed`, returning the kept events and how many
+/// were dropped. An empty subscription list keeps everything.
+pub fn filter_subscribed_events(
+    events: Vec<EnhancedTransactionEvent>,
+    subscribed: &[TransactionType],
+) -> (Vec<EnhancedTransactionEvent>, usize) {
+    if subscribed.is_empty() {
+        return (events, 0);
+    }
+
+    let total = events.len();
+    let kept: Vec<EnhancedTransactionEvent> = events
+        .into_iter()
+        .filter(|event| subscribed.iter().any(|t| t.matches_event(event)))
+        .collect();
+    let dropped = total - kept.len();
+    (kept, dropped)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WebhookRegistration {
     pub webhook_id: String,
@@ -93,6 +167,18 @@ struct CreateWebhookRequest {
     auth_header: Option<String>,
 }
 
+impl From<&WebhookConfig> for CreateWebhookRequest {
+    fn from(config: &WebhookConfig) -> Self {
+        Self {
+            webhook_url: config.webhook_url.clone(),
+            webhook_type: config.webhook_type,
+            transaction_types: config.transaction_types.clone(),
+            account_addresses: config.account_addresses.clone(),
+            auth_header: config.auth_header.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 struct CreateWebhookResponse {
     #[serde(rename = "webhookID")]
@@ -140,13 +226,7 @@ impl HeliusWebhookClient {
             .as_ref()
             .ok_or_else(|| AppError::Configuration("Helius API key not configured".to_string()))?;
 
-        let request = CreateWebhookRequest {
-            webhook_url: config.webhook_url.clone(),
-            webhook_type: config.webhook_type,
-            transaction_types: config.transaction_types.clone(),
-            account_addresses: config.account_addresses.clone(),
-            auth_header: config.auth_header.clone(),
-        };
+        let request = CreateWebhookRequest::from(config);
 
         let url = format!("{}/webhooks?api-key={}", self.api_url, api_key);
 
@@ -246,7 +326,11 @@ impl HeliusWebhookClient {
                 wallet_address: w.account_addresses.first().cloned().unwrap_or_default(),
                 webhook_url: w.webhook_url,
                 webhook_type: WebhookType::Enhanced,
-                transaction_types: vec![TransactionType::Swap],
+                transaction_types: w
+                    .transaction_types
+                    .iter()
+                    .filter_map(|t| TransactionType::parse(t))
+                    .collect(),
                 created_at: chrono::Utc::now(),
                 is_active: true,
             })
@@ -501,4 +585,92 @@ mod tests {
         let json = serde_json::to_string(&tx_type).unwrap();
         assert_eq!(json, "\"SWAP\"");
     }
+
+    fn event(transaction_type: &str, signature: &str) -> EnhancedTransactionEvent {
+        serde_json::from_value(serde_json::json!({
+            "accountData": [],
+            "description": "",
+            "events": {},
+            "fee": 5000,
+            "feePayer": "wallet1",
+            "instructions": [],
+            "nativeTransfers": [],
+            "signature": signature,
+            "slot": 1,
+            "source": "PUMP_FUN",
+            "timestamp": 0,
+            "tokenTransfers": [],
+            "type": transaction_type,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_registration_payload_uses_configured_transaction_types() {
+        let config = WebhookConfig {
+            webhook_url: "https://example.com/webhook".to_string(),
+            webhook_type: WebhookType::Enhanced,
+            transaction_types: parse_transaction_types("swap, nft_sale,bogus, SWAP").unwrap(),
+            account_addresses: vec!["address1".to_string()],
+            auth_header: None,
+        };
+
+        let payload = serde_json::to_value(CreateWebhookRequest::from(&config)).unwrap();
+        assert_eq!(
+            payload["transactionTypes"],
+            serde_json::json!(["SWAP", "NFT_SALE"])
+        );
+
+        assert_eq!(parse_transaction_types("bogus"), None);
+        assert_eq!(parse_transaction_types(""), None);
+    }
+
+    #[test]
+    fn test_unsubscribed_event_types_are_filtered() {
+        let events = vec![
+            event("SWAP", "sig_swap"),
+            event("NFT_SALE", "sig_nft"),
+            event("TRANSFER", "sig_transfer"),
+        ];
+
+        let (kept, dropped) = filter_subscribed_events(events.clone(), &[TransactionType::Swap]);
+        assert_eq!(dropped, 2);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].signature, "sig_swap");
+
+        let (kept, dropped) = filter_subscribed_events(events, &[TransactionType::Any]);
+        assert_eq!((kept.len(), dropped), (3, 0));
+    }
+
+    #[test]
+    fn test_swap_subscription_keeps_swaps_the_parser_detects() {
+        let mut routed = event("TRANSFER", "sig_routed_swap");
+        routed.events.swap = Some(SwapEvent {
+            native_input: Some(NativeAmount {
+                account: "wallet1".to_string(),
+                amount: "1000000000".to_string(),
+            }),
+            native_output: None,
+            token_inputs: vec![],
+            token_outputs: vec![],
+            token_fees: vec![],
+            native_fees: vec![],
+            inner_swaps: vec![],
+        });
+        let events = vec![
+            event("swap", "sig_lowercase"),
+            routed,
+            event("transfer", "sig_transfer"),
+        ];
+
+        let (kept, dropped) = filter_subscribed_events(events.clone(), &[TransactionType::Swap]);
+        let kept: Vec<&str> = kept.iter().map(|e| e.signature.as_str()).collect();
+        assert_eq!(kept, vec!["sig_lowercase", "sig_routed_swap"]);
+        assert_eq!(dropped, 1);
+
+        // Type names match in any case
+        let (kept, _) = filter_subscribed_events(events, &[TransactionType::Transfer]);
+        let kept: Vec<&str> = kept.iter().map(|e| e.signature.as_str()).collect();
+        assert_eq!(kept, vec!["sig_routed_swap", "sig_transfer"]);
+    }
 }
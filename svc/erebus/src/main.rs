@@ -47,6 +47,7 @@ use resources::agents::routes::{
     get_task_events,
     get_task_notifications,
     get_task_queues,
+    get_task_result,
     get_task_stats,
     get_task_suggestions,
     get_task_templates,
@@ -86,6 +87,7 @@ use resources::{
     create_arb_routes, create_content_routes, create_crossroads_routes, create_discovery_routes,
     create_engram_routes, create_mcp_routes, ExternalService, WalletManager,
 };
+use utils::body_limit::{read_body, BodyLimits};
 
 #[derive(Serialize)]
 struct StatusResponse {
@@ -204,6 +206,7 @@ async fn proxy_message_sse() -> Result<Response, StatusCode> {
 
 /// Comprehensive logging middleware for all requests and responses
 async fn logging_middleware(
+    State(body_limits): State<Arc<BodyLimits>>,
     request: Request,
     next: Next,
 ) -> Result<axum::response::Response, StatusCode> {
@@ -223,13 +226,17 @@ async fn logging_middleware(
     // Generate unique request ID for tracing
     let request_id = uuid::Uuid::new_v4().to_string();
 
-    // Extract request body for logging
+    // Extract request body for logging, bounded by the route's size limit
     let (parts, body) = request.into_parts();
-    let body_bytes = match axum::body::to_bytes(body, usize::MAX).await {
+    let body_limit = body_limits.limit_for(uri.path());
+    let body_bytes = match read_body(body, &headers, body_limit).await {
         Ok(bytes) => bytes,
-        Err(e) => {
-            error!("❌ [{}] Failed to read request body: {}", request_id, e);
-            return Err(StatusCode::BAD_REQUEST);
+        Err(status) => {
+            warn!(
+                "❌ [{}] Rejected request body for {} {} (limit {} bytes): {}",
+                request_id, method, uri, body_limit, status
+            );
+            return Err(status);
         }
     };
 
@@ -514,7 +521,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("✅ API key service initialized");
 
     // Create wallet manager
-    let wallet_manager = WalletManager::new();
+    let wallet_manager = WalletManager::from_env();
 
     // Create external service
     let external_service = Arc::new(ExternalService::new());
@@ -592,6 +599,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .route("/api/agents/tasks/suggestions", post(get_task_suggestions))
         .route("/api/agents/tasks/:task_id/learn", post(learn_from_task))
         .route("/api/agents/tasks/:task_id/process", post(process_task))
+        .route("/api/agents/tasks/:task_id/result", get(get_task_result))
         // A2A SSE streaming endpoints
         .route("/a2a/tasks/:task_id/sse", get(proxy_task_sse))
         .route("/a2a/messages/sse", get(proxy_message_sse))
@@ -621,7 +629,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .merge(create_discovery_routes())
         .with_state(app_state.clone())
         // Add logging middleware
-        .layer(middleware::from_fn(logging_middleware))
+        .layer(middleware::from_fn_with_state(
+            Arc::new(BodyLimits::from_env()),
+            logging_middleware,
+        ))
         // Add CORS layer
         .layer(
             CorsLayer::new()
@@ -739,6 +750,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
     info!("💡 Ready for agentic workflows, marketplace operations, engrams, MCP, and service discovery");
 
+    // Register external MCP services and re-list their tools in the background
+    tokio::spawn(resources::discovery::start_external_catalog_refresh());
+
     let addr = SocketAddr::from(([0, 0, 0, 0], 3000));
     let listener = tokio::net::TcpListener::bind(addr)
         .await
@@ -752,3 +766,54 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn spawn_echo_server(limits: BodyLimits) -> String {
+        let app = Router::new()
+            .route("/echo", post(|body: String| async move { body }))
+            .layer(middleware::from_fn_with_state(
+                Arc::new(limits),
+                logging_middleware,
+            ));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        format!("http://{}/echo", addr)
+    }
+
+    #[tokio::test]
+    async fn test_over_limit_body_is_rejected_with_413() {
+        let url = spawn_echo_server(BodyLimits::new(64)).await;
+
+        let response = reqwest::Client::new()
+            .post(&url)
+            .body(vec![b'a'; 65])
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), reqwest::StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn test_under_limit_body_proceeds() {
+        let url = spawn_echo_server(BodyLimits::new(1024).with_route_limit("/echo", 64)).await;
+
+        let response = reqwest::Client::new()
+            .post(&url)
+            .body("{\"hello\":\"world\"}")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+        assert_eq!(response.text().await.unwrap(), "{\"hello\":\"world\"}");
+    }
+}
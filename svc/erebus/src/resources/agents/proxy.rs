@@ -1,8 +1,11 @@
 // Agent proxy service for routing requests to agent backends
+use axum::http::StatusCode;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use tracing::{error, info, warn};
 
+use crate::utils::api_error::ApiError;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AgentRequest {
     pub message: String,
@@ -35,6 +38,34 @@ pub struct AgentErrorResponse {
     pub agent_available: bool,
 }
 
+impl AgentErrorResponse {
+    pub fn status_code(&self) -> StatusCode {
+        match self.code.as_str() {
+            "AGENT_UNAVAILABLE" | "STATUS_UNAVAILABLE" | "LLM_UNAVAILABLE" => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
+            "AGENT_HTTP_ERROR" | "AGENT_PARSE_ERROR" | "STATUS_HTTP_ERROR"
+            | "STATUS_PARSE_ERROR" | "LLM_HTTP_ERROR" | "LLM_PARSE_ERROR" => {
+                StatusCode::BAD_GATEWAY
+            }
+            "AGENT_NOT_SUPPORTED" => StatusCode::NOT_FOUND,
+            "MISSING_WALLET_ADDRESS" => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<AgentErrorResponse> for ApiError {
+    fn from(error: AgentErrorResponse) -> Self {
+        ApiError::new(error.status_code(), error.code, error.message).with_details(
+            serde_json::json!({
+                "error": error.error,
+                "agent_available": error.agent_available,
+            }),
+        )
+    }
+}
+
 pub struct AgentProxy {
     agent_base_url: String,
     timeout_seconds: u64,
@@ -528,3 +559,35 @@ impl AgentProxy {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn agent_error(code: &str) -> AgentErrorResponse {
+        AgentErrorResponse {
+            error: "test".to_string(),
+            code: code.to_string(),
+            message: "test".to_string(),
+            agent_available: false,
+        }
+    }
+
+    #[test]
+    fn test_agent_error_codes_map_to_statuses() {
+        let cases = [
+            ("AGENT_UNAVAILABLE", StatusCode::SERVICE_UNAVAILABLE),
+            ("STATUS_UNAVAILABLE", StatusCode::SERVICE_UNAVAILABLE),
+            ("AGENT_HTTP_ERROR", StatusCode::BAD_GATEWAY),
+            ("LLM_PARSE_ERROR", StatusCode::BAD_GATEWAY),
+            ("AGENT_NOT_SUPPORTED", StatusCode::NOT_FOUND),
+            ("MISSING_WALLET_ADDRESS", StatusCode::BAD_REQUEST),
+            ("USER_REGISTRATION_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+        ];
+        for (code, status) in cases {
+            let error = ApiError::from(agent_error(code));
+            assert_eq!(error.status, status, "{}", code);
+            assert_eq!(error.error.code, code);
+        }
+    }
+}
@@ -2,7 +2,7 @@
 use axum::{
     extract::{Json, Path, Query},
     http::{HeaderMap, StatusCode},
-    response::Json as ResponseJson,
+    response::{IntoResponse, Json as ResponseJson},
 };
 use serde_json::Value;
 use std::collections::HashMap;
@@ -10,6 +10,7 @@ use tracing::{error, info, warn};
 use uuid::Uuid;
 
 use super::proxy::{AgentErrorResponse, AgentProxy, AgentRequest, AgentResponse, AgentStatus};
+use crate::utils::api_error::ApiError;
 
 /// Hecate agent proxy instance - now points to Rust service
 fn get_hecate_proxy() -> AgentProxy {
@@ -139,7 +140,7 @@ async fn call_erebus_user_registration_api(
 pub async fn register_user(
     headers: HeaderMap,
     Json(request): Json<Value>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("👤 User registration request received");
     info!(
         "📝 Request payload: {}",
@@ -198,10 +199,7 @@ pub async fn register_user(
                     message: format!("Failed to register user via Erebus API: {}", e),
                     agent_available: true,
                 };
-                Err((
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    ResponseJson(error_response),
-                ))
+                Err(error_response.into())
             }
         }
     } else {
@@ -212,7 +210,7 @@ pub async fn register_user(
             message: "Wallet address is required for user registration".to_string(),
             agent_available: true,
         };
-        Err((StatusCode::BAD_REQUEST, ResponseJson(error_response)))
+        Err(error_response.into())
     }
 }
 
@@ -246,7 +244,7 @@ pub async fn agent_health() -> ResponseJson<Value> {
 /// Proxy chat request to Hecate agent
 pub async fn hecate_chat(
     Json(request): Json<AgentRequest>,
-) -> Result<ResponseJson<AgentResponse>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<AgentResponse>, ApiError> {
     info!("💬 Hecate chat request received");
     info!(
         "📝 Request payload: {}",
@@ -271,14 +269,7 @@ pub async fn hecate_chat(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -286,7 +277,7 @@ pub async fn hecate_chat(
 /// Proxy chat request to Siren agent
 pub async fn siren_chat(
     Json(request): Json<AgentRequest>,
-) -> Result<ResponseJson<AgentResponse>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<AgentResponse>, ApiError> {
     info!("🎭 Siren chat request received");
     info!(
         "📝 Request payload: {}",
@@ -311,14 +302,7 @@ pub async fn siren_chat(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -326,7 +310,7 @@ pub async fn siren_chat(
 /// Set Siren model selection
 pub async fn siren_set_model(
     Json(request): Json<Value>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("🎯 Siren set model request received");
     info!(
         "📝 Request payload: {}",
@@ -360,15 +344,12 @@ pub async fn siren_set_model(
                     }
                     Err(e) => {
                         error!("❌ Failed to parse Siren set-model response: {}", e);
-                        Err((
-                            StatusCode::BAD_GATEWAY,
-                            ResponseJson(AgentErrorResponse {
-                                error: "parse_error".to_string(),
-                                code: "AGENT_PARSE_ERROR".to_string(),
-                                message: format!("Failed to parse response: {}", e),
-                                agent_available: true,
-                            }),
-                        ))
+                        Err(ApiError::from(AgentErrorResponse {
+                            error: "parse_error".to_string(),
+                            code: "AGENT_PARSE_ERROR".to_string(),
+                            message: format!("Failed to parse response: {}", e),
+                            agent_available: true,
+                        }))
                     }
                 }
             } else {
@@ -381,35 +362,28 @@ pub async fn siren_set_model(
                     "❌ Siren set-model request failed with status {}: {}",
                     status, error_text
                 );
-                Err((
-                    StatusCode::BAD_GATEWAY,
-                    ResponseJson(AgentErrorResponse {
-                        error: "http_error".to_string(),
-                        code: "AGENT_HTTP_ERROR".to_string(),
-                        message: format!("Agent returned status {}: {}", status, error_text),
-                        agent_available: status.is_server_error(),
-                    }),
-                ))
+                Err(ApiError::from(AgentErrorResponse {
+                    error: "http_error".to_string(),
+                    code: "AGENT_HTTP_ERROR".to_string(),
+                    message: format!("Agent returned status {}: {}", status, error_text),
+                    agent_available: status.is_server_error(),
+                }))
             }
         }
         Err(e) => {
             error!("❌ Failed to connect to Siren agent: {}", e);
-            Err((
-                StatusCode::SERVICE_UNAVAILABLE,
-                ResponseJson(AgentErrorResponse {
-                    error: "connection_error".to_string(),
-                    code: "AGENT_UNAVAILABLE".to_string(),
-                    message: format!("Failed to connect to agent: {}", e),
-                    agent_available: false,
-                }),
-            ))
+            Err(ApiError::from(AgentErrorResponse {
+                error: "connection_error".to_string(),
+                code: "AGENT_UNAVAILABLE".to_string(),
+                message: format!("Failed to connect to agent: {}", e),
+                agent_available: false,
+            }))
         }
     }
 }
 
 /// Get Hecate agent status
-pub async fn hecate_status(
-) -> Result<ResponseJson<AgentStatus>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+pub async fn hecate_status() -> Result<ResponseJson<AgentStatus>, ApiError> {
     info!("📊 Hecate status request received");
 
     let proxy = get_hecate_proxy();
@@ -430,21 +404,13 @@ pub async fn hecate_status(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "STATUS_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "STATUS_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "STATUS_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
 
 /// Get Siren agent status
-pub async fn siren_status(
-) -> Result<ResponseJson<AgentStatus>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+pub async fn siren_status() -> Result<ResponseJson<AgentStatus>, ApiError> {
     info!("📊 Siren status request received");
 
     let proxy = get_siren_proxy();
@@ -465,14 +431,7 @@ pub async fn siren_status(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "STATUS_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "STATUS_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "STATUS_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -481,7 +440,7 @@ pub async fn siren_status(
 pub async fn agent_chat(
     Path(agent_name): Path<String>,
     Json(request): Json<AgentRequest>,
-) -> Result<ResponseJson<AgentResponse>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<AgentResponse>, ApiError> {
     info!("🤖 Generic agent chat request for: {}", agent_name);
     info!(
         "📝 Request payload: {}",
@@ -505,7 +464,7 @@ pub async fn agent_chat(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            Err((StatusCode::NOT_FOUND, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -513,7 +472,7 @@ pub async fn agent_chat(
 /// Generic agent status for future agents
 pub async fn agent_status(
     Path(agent_name): Path<String>,
-) -> Result<ResponseJson<AgentStatus>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<AgentStatus>, ApiError> {
     info!("📊 Generic agent status request for: {}", agent_name);
 
     match agent_name.as_str() {
@@ -533,7 +492,7 @@ pub async fn agent_status(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            Err((StatusCode::NOT_FOUND, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -541,7 +500,7 @@ pub async fn agent_status(
 /// Set Hecate agent personality
 pub async fn hecate_personality(
     Json(request): Json<Value>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("⚙️ Hecate personality request received");
     info!(
         "📝 Request payload: {}",
@@ -569,21 +528,13 @@ pub async fn hecate_personality(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
 
 /// Clear Hecate conversation history
-pub async fn hecate_clear(
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+pub async fn hecate_clear() -> Result<ResponseJson<Value>, ApiError> {
     info!("🧹 Hecate clear conversation request received");
 
     let proxy = get_hecate_proxy();
@@ -604,21 +555,13 @@ pub async fn hecate_clear(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
 
 /// Get Hecate conversation history
-pub async fn hecate_history(
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+pub async fn hecate_history() -> Result<ResponseJson<Value>, ApiError> {
     info!("📜 Hecate history request received");
 
     let proxy = get_hecate_proxy();
@@ -639,21 +582,13 @@ pub async fn hecate_history(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
 
 /// Get available models from Hecate agent
-pub async fn hecate_available_models(
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+pub async fn hecate_available_models() -> Result<ResponseJson<Value>, ApiError> {
     info!("🧠 Hecate available models request received");
 
     let proxy = get_hecate_proxy();
@@ -677,14 +612,7 @@ pub async fn hecate_available_models(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -692,7 +620,7 @@ pub async fn hecate_available_models(
 /// Set Hecate model selection
 pub async fn hecate_set_model(
     Json(request): Json<Value>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("🎯 Hecate set model request received");
     info!(
         "📝 Request payload: {}",
@@ -720,21 +648,13 @@ pub async fn hecate_set_model(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
 
 /// Get detailed model information from Hecate agent
-pub async fn hecate_model_info(
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+pub async fn hecate_model_info() -> Result<ResponseJson<Value>, ApiError> {
     info!("📋 Hecate model info request received");
 
     let proxy = get_hecate_proxy();
@@ -755,21 +675,13 @@ pub async fn hecate_model_info(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
 
 /// Get MCP tools available to Hecate agent
-pub async fn hecate_tools(
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+pub async fn hecate_tools() -> Result<ResponseJson<Value>, ApiError> {
     info!("🔧 Hecate tools request received");
 
     let proxy = get_hecate_proxy();
@@ -790,14 +702,7 @@ pub async fn hecate_tools(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -805,7 +710,7 @@ pub async fn hecate_tools(
 /// Search models via Hecate agent
 pub async fn hecate_search_models(
     Query(params): Query<HashMap<String, String>>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("🔍 Hecate search models request received");
     info!("📝 Query parameters: {:?}", params);
 
@@ -839,14 +744,7 @@ pub async fn hecate_search_models(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -859,7 +757,7 @@ pub async fn hecate_search_models(
 pub async fn create_task(
     headers: HeaderMap,
     Json(request): Json<Value>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("📋 Task creation request received");
     info!(
         "📝 Request payload: {}",
@@ -895,14 +793,7 @@ pub async fn create_task(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -911,7 +802,7 @@ pub async fn create_task(
 pub async fn get_tasks(
     headers: HeaderMap,
     Query(params): Query<HashMap<String, String>>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("📋 Get tasks request received");
     info!("📝 Query parameters: {:?}", params);
 
@@ -944,14 +835,7 @@ pub async fn get_tasks(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -959,7 +843,7 @@ pub async fn get_tasks(
 /// Get a specific task by ID
 pub async fn get_task(
     Path(task_id): Path<String>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("📋 Get task request received for ID: {}", task_id);
 
     let proxy = get_hecate_proxy();
@@ -977,14 +861,33 @@ pub async fn get_task(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
+            Err(error.into())
+        }
+    }
+}
 
-            Err((status_code, ResponseJson(error)))
+/// Get the full stored result of a task
+pub async fn get_task_result(
+    Path(task_id): Path<String>,
+) -> Result<ResponseJson<Value>, ApiError> {
+    info!("📄 Get task result request received for ID: {}", task_id);
+
+    let proxy = get_hecate_proxy();
+    let endpoint = format!("tasks/{}/result", task_id);
+
+    match proxy.proxy_request(&endpoint, "GET", None, None).await {
+        Ok(response) => {
+            info!("✅ Task result retrieved successfully");
+            Ok(ResponseJson(response))
+        }
+        Err(error) => {
+            error!("❌ Get task result request failed");
+            error!(
+                "📤 Error response: {}",
+                serde_json::to_string_pretty(&error).unwrap_or_default()
+            );
+
+            Err(error.into())
         }
     }
 }
@@ -993,7 +896,7 @@ pub async fn get_task(
 pub async fn update_task(
     Path(task_id): Path<String>,
     Json(request): Json<Value>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("📋 Task update request received for ID: {}", task_id);
     info!(
         "📝 Request payload: {}",
@@ -1022,14 +925,7 @@ pub async fn update_task(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -1037,7 +933,7 @@ pub async fn update_task(
 /// Delete a task
 pub async fn delete_task(
     Path(task_id): Path<String>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("📋 Task deletion request received for ID: {}", task_id);
 
     let proxy = get_hecate_proxy();
@@ -1055,14 +951,7 @@ pub async fn delete_task(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -1070,7 +959,7 @@ pub async fn delete_task(
 /// Start a task
 pub async fn start_task(
     Path(task_id): Path<String>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("▶️ Task start request received for ID: {}", task_id);
 
     let proxy = get_hecate_proxy();
@@ -1088,14 +977,7 @@ pub async fn start_task(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -1103,7 +985,7 @@ pub async fn start_task(
 /// Pause a task
 pub async fn pause_task(
     Path(task_id): Path<String>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("⏸️ Task pause request received for ID: {}", task_id);
 
     let proxy = get_hecate_proxy();
@@ -1121,14 +1003,7 @@ pub async fn pause_task(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -1136,7 +1011,7 @@ pub async fn pause_task(
 /// Resume a task
 pub async fn resume_task(
     Path(task_id): Path<String>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("▶️ Task resume request received for ID: {}", task_id);
 
     let proxy = get_hecate_proxy();
@@ -1154,14 +1029,7 @@ pub async fn resume_task(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -1169,7 +1037,7 @@ pub async fn resume_task(
 /// Cancel a task
 pub async fn cancel_task(
     Path(task_id): Path<String>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("❌ Task cancel request received for ID: {}", task_id);
 
     let proxy = get_hecate_proxy();
@@ -1187,14 +1055,7 @@ pub async fn cancel_task(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -1202,7 +1063,7 @@ pub async fn cancel_task(
 /// Retry a failed task
 pub async fn retry_task(
     Path(task_id): Path<String>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("🔄 Task retry request received for ID: {}", task_id);
 
     let proxy = get_hecate_proxy();
@@ -1220,21 +1081,13 @@ pub async fn retry_task(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
 
 /// Get task queues
-pub async fn get_task_queues(
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+pub async fn get_task_queues() -> Result<ResponseJson<Value>, ApiError> {
     info!("📋 Get task queues request received");
 
     let proxy = get_hecate_proxy();
@@ -1251,21 +1104,13 @@ pub async fn get_task_queues(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
 
 /// Get task templates
-pub async fn get_task_templates(
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+pub async fn get_task_templates() -> Result<ResponseJson<Value>, ApiError> {
     info!("📋 Get task templates request received");
 
     let proxy = get_hecate_proxy();
@@ -1285,14 +1130,7 @@ pub async fn get_task_templates(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -1300,7 +1138,7 @@ pub async fn get_task_templates(
 /// Create task from template
 pub async fn create_task_from_template(
     Json(request): Json<Value>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("📋 Create task from template request received");
     info!(
         "📝 Request payload: {}",
@@ -1324,14 +1162,7 @@ pub async fn create_task_from_template(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -1339,7 +1170,7 @@ pub async fn create_task_from_template(
 /// Get task statistics
 pub async fn get_task_stats(
     Query(params): Query<HashMap<String, String>>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("📊 Get task stats request received");
     info!("📝 Query parameters: {:?}", params);
 
@@ -1369,21 +1200,13 @@ pub async fn get_task_stats(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
 
 /// Get task notifications
-pub async fn get_task_notifications(
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+pub async fn get_task_notifications() -> Result<ResponseJson<Value>, ApiError> {
     info!("🔔 Get task notifications request received");
 
     let proxy = get_hecate_proxy();
@@ -1403,14 +1226,7 @@ pub async fn get_task_notifications(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -1418,7 +1234,7 @@ pub async fn get_task_notifications(
 /// Mark notification as read
 pub async fn mark_notification_read(
     Path(notification_id): Path<String>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!(
         "🔔 Mark notification read request received for ID: {}",
         notification_id
@@ -1439,14 +1255,7 @@ pub async fn mark_notification_read(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -1455,7 +1264,7 @@ pub async fn mark_notification_read(
 pub async fn handle_notification_action(
     Path(notification_id): Path<String>,
     Json(request): Json<Value>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!(
         "🔔 Handle notification action request received for ID: {}",
         notification_id
@@ -1483,14 +1292,7 @@ pub async fn handle_notification_action(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -1498,7 +1300,7 @@ pub async fn handle_notification_action(
 /// Get task events
 pub async fn get_task_events(
     Query(params): Query<HashMap<String, String>>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("⚡ Get task events request received");
     info!("📝 Query parameters: {:?}", params);
 
@@ -1528,14 +1330,7 @@ pub async fn get_task_events(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -1543,7 +1338,7 @@ pub async fn get_task_events(
 /// Publish task event (for automation/MCP hooks)
 pub async fn publish_task_event(
     Json(request): Json<Value>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("⚡ Publish task event request received");
     info!(
         "📝 Request payload: {}",
@@ -1567,21 +1362,13 @@ pub async fn publish_task_event(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
 
 /// Get Hecate motivation state
-pub async fn get_motivation_state(
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+pub async fn get_motivation_state() -> Result<ResponseJson<Value>, ApiError> {
     info!("🧠 Get motivation state request received");
 
     let proxy = get_hecate_proxy();
@@ -1601,14 +1388,7 @@ pub async fn get_motivation_state(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -1616,7 +1396,7 @@ pub async fn get_motivation_state(
 /// Update Hecate motivation state
 pub async fn update_motivation_state(
     Json(request): Json<Value>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("🧠 Update motivation state request received");
     info!(
         "📝 Request payload: {}",
@@ -1640,14 +1420,7 @@ pub async fn update_motivation_state(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -1655,7 +1428,7 @@ pub async fn update_motivation_state(
 /// Get task suggestions based on context
 pub async fn get_task_suggestions(
     Json(request): Json<Value>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("💡 Get task suggestions request received");
     info!(
         "📝 Request payload: {}",
@@ -1679,14 +1452,7 @@ pub async fn get_task_suggestions(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -1695,7 +1461,7 @@ pub async fn get_task_suggestions(
 pub async fn learn_from_task(
     Path(task_id): Path<String>,
     Json(request): Json<Value>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("🎓 Learn from task request received for ID: {}", task_id);
     info!(
         "📝 Request payload: {}",
@@ -1720,14 +1486,7 @@ pub async fn learn_from_task(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
@@ -1772,16 +1531,13 @@ pub async fn llm_chat_completions(
         Ok(r) => r,
         Err(e) => {
             error!("Failed to connect to agents service for LLM: {}", e);
-            return axum::response::Response::builder()
-                .status(StatusCode::SERVICE_UNAVAILABLE)
-                .header("content-type", "application/json")
-                .body(axum::body::Body::from(serde_json::to_string(&AgentErrorResponse {
-                    error: "connection_error".to_string(),
-                    code: "LLM_UNAVAILABLE".to_string(),
-                    message: format!("LLM service unavailable: {}", e),
-                    agent_available: false,
-                }).unwrap_or_default()))
-                .unwrap();
+            return ApiError::from(AgentErrorResponse {
+                error: "connection_error".to_string(),
+                code: "LLM_UNAVAILABLE".to_string(),
+                message: format!("LLM service unavailable: {}", e),
+                agent_available: false,
+            })
+            .into_response();
         }
     };
 
@@ -1789,16 +1545,13 @@ pub async fn llm_chat_completions(
         let status = response.status();
         let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
         error!("LLM proxy error {}: {}", status, error_text);
-        return axum::response::Response::builder()
-            .status(StatusCode::BAD_GATEWAY)
-            .header("content-type", "application/json")
-            .body(axum::body::Body::from(serde_json::to_string(&AgentErrorResponse {
-                error: "llm_error".to_string(),
-                code: "LLM_HTTP_ERROR".to_string(),
-                message: error_text,
-                agent_available: true,
-            }).unwrap_or_default()))
-            .unwrap();
+        return ApiError::from(AgentErrorResponse {
+            error: "llm_error".to_string(),
+            code: "LLM_HTTP_ERROR".to_string(),
+            message: error_text,
+            agent_available: true,
+        })
+        .into_response();
     }
 
     let content_type = response
@@ -1862,8 +1615,7 @@ pub async fn llm_chat_completions(
     }
 }
 
-pub async fn llm_list_models(
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+pub async fn llm_list_models() -> Result<ResponseJson<Value>, ApiError> {
     info!("LLM Proxy: list models request via Erebus");
 
     let client = reqwest::Client::new();
@@ -1883,41 +1635,32 @@ pub async fn llm_list_models(
                     Ok(json_response) => Ok(ResponseJson(json_response)),
                     Err(e) => {
                         error!("Failed to parse models list response: {}", e);
-                        Err((
-                            StatusCode::BAD_GATEWAY,
-                            ResponseJson(AgentErrorResponse {
-                                error: "parse_error".to_string(),
-                                code: "LLM_PARSE_ERROR".to_string(),
-                                message: format!("Failed to parse response: {}", e),
-                                agent_available: true,
-                            }),
-                        ))
+                        Err(ApiError::from(AgentErrorResponse {
+                            error: "parse_error".to_string(),
+                            code: "LLM_PARSE_ERROR".to_string(),
+                            message: format!("Failed to parse response: {}", e),
+                            agent_available: true,
+                        }))
                     }
                 }
             } else {
                 let status = response.status();
-                Err((
-                    StatusCode::BAD_GATEWAY,
-                    ResponseJson(AgentErrorResponse {
-                        error: "llm_error".to_string(),
-                        code: "LLM_HTTP_ERROR".to_string(),
-                        message: format!("Models endpoint returned status: {}", status),
-                        agent_available: true,
-                    }),
-                ))
+                Err(ApiError::from(AgentErrorResponse {
+                    error: "llm_error".to_string(),
+                    code: "LLM_HTTP_ERROR".to_string(),
+                    message: format!("Models endpoint returned status: {}", status),
+                    agent_available: true,
+                }))
             }
         }
         Err(e) => {
             error!("Failed to connect to agents service for models: {}", e);
-            Err((
-                StatusCode::SERVICE_UNAVAILABLE,
-                ResponseJson(AgentErrorResponse {
-                    error: "connection_error".to_string(),
-                    code: "LLM_UNAVAILABLE".to_string(),
-                    message: format!("LLM service unavailable: {}", e),
-                    agent_available: false,
-                }),
-            ))
+            Err(ApiError::from(AgentErrorResponse {
+                error: "connection_error".to_string(),
+                code: "LLM_UNAVAILABLE".to_string(),
+                message: format!("LLM service unavailable: {}", e),
+                agent_available: false,
+            }))
         }
     }
 }
@@ -1925,7 +1668,7 @@ pub async fn llm_list_models(
 /// Process task with Hecate agent
 pub async fn process_task(
     Path(task_id): Path<String>,
-) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<AgentErrorResponse>)> {
+) -> Result<ResponseJson<Value>, ApiError> {
     info!("⚡ Process task request received for ID: {}", task_id);
 
     let proxy = get_hecate_proxy();
@@ -1947,14 +1690,41 @@ pub async fn process_task(
                 serde_json::to_string_pretty(&error).unwrap_or_default()
             );
 
-            let status_code = match error.code.as_str() {
-                "AGENT_UNAVAILABLE" => StatusCode::SERVICE_UNAVAILABLE,
-                "AGENT_HTTP_ERROR" => StatusCode::BAD_GATEWAY,
-                "AGENT_PARSE_ERROR" => StatusCode::BAD_GATEWAY,
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-
-            Err((status_code, ResponseJson(error)))
+            Err(error.into())
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::api_error::response_error;
+
+    #[tokio::test]
+    async fn test_unsupported_agent_returns_standard_not_found() {
+        let request = AgentRequest {
+            message: "hello".to_string(),
+            user_context: None,
+        };
+        let error = agent_chat(Path("oracle".to_string()), Json(request))
+            .await
+            .unwrap_err();
+
+        let (status, body) = response_error(error.into_response()).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body.error.code, "AGENT_NOT_SUPPORTED");
+        assert_eq!(body.error.message, "Agent 'oracle' is not supported");
+        assert_eq!(body.error.details.unwrap()["agent_available"], false);
+    }
+
+    #[tokio::test]
+    async fn test_register_user_without_wallet_is_bad_request() {
+        let error = register_user(HeaderMap::new(), Json(serde_json::json!({})))
+            .await
+            .unwrap_err();
+
+        let (status, body) = response_error(error.into_response()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body.error.code, "MISSING_WALLET_ADDRESS");
+    }
+}
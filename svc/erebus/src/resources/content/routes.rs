@@ -20,6 +20,11 @@ pub struct ContentErrorResponse {
     pub message: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct GenerationMetricsQuery {
+    pub since_hours: Option<i64>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ListQuery {
     pub status: Option<String>,
@@ -180,6 +185,17 @@ async fn get_metrics(
     proxy_request("GET", &format!("api/content/metrics/{}", id), None).await
 }
 
+async fn get_generation_metrics(
+    Query(query): Query<GenerationMetricsQuery>,
+) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<ContentErrorResponse>)> {
+    info!("📊 Generation metrics request received");
+    let endpoint = match query.since_hours {
+        Some(hours) => format!("api/content/metrics?since_hours={}", hours),
+        None => "api/content/metrics".to_string(),
+    };
+    proxy_request("GET", &endpoint, None).await
+}
+
 async fn list_templates(
 ) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<ContentErrorResponse>)> {
     info!("📚 List templates request received");
@@ -197,6 +213,7 @@ where
         .route("/api/content/queue/:id", get(get_content))
         .route("/api/content/queue/:id", put(update_content))
         .route("/api/content/queue/:id", delete(delete_content))
+        .route("/api/content/metrics", get(get_generation_metrics))
         .route("/api/content/metrics/:id", get(get_metrics))
         .route("/api/content/templates", get(list_templates))
 }
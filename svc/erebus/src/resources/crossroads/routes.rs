@@ -1,6 +1,5 @@
 use axum::{
     extract::{Path, State},
-    http::StatusCode,
     response::Json,
     routing::{get, post},
     Router,
@@ -16,6 +15,7 @@ use crate::resources::crossroads::models::{
 use crate::resources::crossroads::repository::ArbFarmRepository;
 use crate::resources::crossroads::services::NullblockServiceIntegrator;
 use crate::resources::ExternalService;
+use crate::utils::api_error::ApiError;
 
 pub fn create_crossroads_routes(
     _external_service: &Arc<ExternalService>,
@@ -89,7 +89,7 @@ async fn get_listings() -> Json<Value> {
 
 async fn create_listing(
     Json(payload): Json<CreateListingRequest>,
-) -> Result<Json<Value>, StatusCode> {
+) -> Result<Json<Value>, ApiError> {
     info!("📦 Creating new marketplace listing: {}", payload.title);
 
     let listing_id = Uuid::new_v4();
@@ -255,7 +255,7 @@ async fn check_service_health(Path(endpoint): Path<String>) -> Json<Value> {
 }
 
 // Admin endpoints
-async fn approve_listing(Path(id): Path<Uuid>) -> Result<Json<Value>, StatusCode> {
+async fn approve_listing(Path(id): Path<Uuid>) -> Result<Json<Value>, ApiError> {
     info!("✅ Admin approving listing: {}", id);
 
     Ok(Json(json!({
@@ -266,7 +266,7 @@ async fn approve_listing(Path(id): Path<Uuid>) -> Result<Json<Value>, StatusCode
     })))
 }
 
-async fn reject_listing(Path(id): Path<Uuid>) -> Result<Json<Value>, StatusCode> {
+async fn reject_listing(Path(id): Path<Uuid>) -> Result<Json<Value>, ApiError> {
     warn!("❌ Admin rejecting listing: {}", id);
 
     Ok(Json(json!({
@@ -277,7 +277,7 @@ async fn reject_listing(Path(id): Path<Uuid>) -> Result<Json<Value>, StatusCode>
     })))
 }
 
-async fn feature_listing(Path(id): Path<Uuid>) -> Result<Json<Value>, StatusCode> {
+async fn feature_listing(Path(id): Path<Uuid>) -> Result<Json<Value>, ApiError> {
     info!("⭐ Admin featuring listing: {}", id);
 
     Ok(Json(json!({
@@ -309,7 +309,7 @@ async fn crossroads_health(State(app_state): State<crate::AppState>) -> Json<Val
 
 async fn list_arbfarm_cows(
     State(app_state): State<crate::AppState>,
-) -> Result<Json<Value>, StatusCode> {
+) -> Result<Json<Value>, ApiError> {
     info!("🐄 Listing ArbFarm COWs");
 
     let pool = app_state.database.pool();
@@ -325,7 +325,10 @@ async fn list_arbfarm_cows(
         }
         Err(e) => {
             warn!("❌ Failed to list ArbFarm COWs: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(ApiError::internal(
+                "DATABASE_ERROR",
+                "Failed to list ArbFarm COWs",
+            ))
         }
     }
 }
@@ -333,7 +336,7 @@ async fn list_arbfarm_cows(
 async fn create_arbfarm_cow(
     State(app_state): State<crate::AppState>,
     Json(payload): Json<CreateArbFarmCowRequest>,
-) -> Result<Json<Value>, StatusCode> {
+) -> Result<Json<Value>, ApiError> {
     info!("🐄 Creating ArbFarm COW: {}", payload.name);
 
     let pool = app_state.database.pool();
@@ -356,7 +359,10 @@ async fn create_arbfarm_cow(
         }))),
         Err(e) => {
             warn!("❌ Failed to create ArbFarm COW: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(ApiError::internal(
+                "DATABASE_ERROR",
+                "Failed to create ArbFarm COW",
+            ))
         }
     }
 }
@@ -364,7 +370,7 @@ async fn create_arbfarm_cow(
 async fn get_arbfarm_cow(
     State(app_state): State<crate::AppState>,
     Path(id): Path<Uuid>,
-) -> Result<Json<Value>, StatusCode> {
+) -> Result<Json<Value>, ApiError> {
     info!("🐄 Fetching ArbFarm COW: {}", id);
 
     let pool = app_state.database.pool();
@@ -372,11 +378,17 @@ async fn get_arbfarm_cow(
         Ok(Some(cow)) => Ok(Json(json!(cow))),
         Ok(None) => {
             warn!("❌ ArbFarm COW not found: {}", id);
-            Err(StatusCode::NOT_FOUND)
+            Err(ApiError::not_found(
+                "COW_NOT_FOUND",
+                format!("ArbFarm COW not found: {}", id),
+            ))
         }
         Err(e) => {
             warn!("❌ Failed to fetch ArbFarm COW: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(ApiError::internal(
+                "DATABASE_ERROR",
+                "Failed to fetch ArbFarm COW",
+            ))
         }
     }
 }
@@ -385,7 +397,7 @@ async fn fork_arbfarm_cow(
     State(app_state): State<crate::AppState>,
     Path(parent_id): Path<Uuid>,
     Json(payload): Json<ForkArbFarmCowRequest>,
-) -> Result<Json<Value>, StatusCode> {
+) -> Result<Json<Value>, ApiError> {
     let fork_name = payload
         .name
         .clone()
@@ -421,7 +433,10 @@ async fn fork_arbfarm_cow(
         }))),
         Err(e) => {
             warn!("❌ Failed to fork ArbFarm COW: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(ApiError::internal(
+                "DATABASE_ERROR",
+                "Failed to fork ArbFarm COW",
+            ))
         }
     }
 }
@@ -429,7 +444,7 @@ async fn fork_arbfarm_cow(
 async fn get_arbfarm_cow_strategies(
     State(app_state): State<crate::AppState>,
     Path(cow_id): Path<Uuid>,
-) -> Result<Json<Value>, StatusCode> {
+) -> Result<Json<Value>, ApiError> {
     info!("📊 Fetching strategies for ArbFarm COW: {}", cow_id);
 
     let pool = app_state.database.pool();
@@ -444,7 +459,10 @@ async fn get_arbfarm_cow_strategies(
         }
         Err(e) => {
             warn!("❌ Failed to fetch strategies: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(ApiError::internal(
+                "DATABASE_ERROR",
+                "Failed to fetch strategies",
+            ))
         }
     }
 }
@@ -452,7 +470,7 @@ async fn get_arbfarm_cow_strategies(
 async fn get_arbfarm_cow_forks(
     State(app_state): State<crate::AppState>,
     Path(cow_id): Path<Uuid>,
-) -> Result<Json<Value>, StatusCode> {
+) -> Result<Json<Value>, ApiError> {
     info!("🍴 Fetching forks of ArbFarm COW: {}", cow_id);
 
     let pool = app_state.database.pool();
@@ -467,7 +485,10 @@ async fn get_arbfarm_cow_forks(
         }
         Err(e) => {
             warn!("❌ Failed to fetch forks: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(ApiError::internal(
+                "DATABASE_ERROR",
+                "Failed to fetch forks",
+            ))
         }
     }
 }
@@ -475,7 +496,7 @@ async fn get_arbfarm_cow_forks(
 async fn get_arbfarm_cow_revenue(
     State(app_state): State<crate::AppState>,
     Path(cow_id): Path<Uuid>,
-) -> Result<Json<Value>, StatusCode> {
+) -> Result<Json<Value>, ApiError> {
     info!("💰 Fetching revenue for ArbFarm COW: {}", cow_id);
 
     let pool = app_state.database.pool();
@@ -530,7 +551,10 @@ async fn get_arbfarm_cow_revenue(
         }
         Err(e) => {
             warn!("❌ Failed to fetch revenue: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(ApiError::internal(
+                "DATABASE_ERROR",
+                "Failed to fetch revenue",
+            ))
         }
     }
 }
@@ -538,7 +562,7 @@ async fn get_arbfarm_cow_revenue(
 async fn get_arbfarm_earnings(
     State(app_state): State<crate::AppState>,
     Path(wallet): Path<String>,
-) -> Result<Json<Value>, StatusCode> {
+) -> Result<Json<Value>, ApiError> {
     info!("💰 Fetching ArbFarm earnings for wallet: {}", wallet);
 
     let pool = app_state.database.pool();
@@ -558,14 +582,17 @@ async fn get_arbfarm_earnings(
         }
         Err(e) => {
             warn!("❌ Failed to fetch earnings: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(ApiError::internal(
+                "DATABASE_ERROR",
+                "Failed to fetch earnings",
+            ))
         }
     }
 }
 
 async fn get_arbfarm_stats(
     State(app_state): State<crate::AppState>,
-) -> Result<Json<Value>, StatusCode> {
+) -> Result<Json<Value>, ApiError> {
     info!("📊 Fetching ArbFarm marketplace statistics");
 
     let pool = app_state.database.pool();
@@ -582,7 +609,10 @@ async fn get_arbfarm_stats(
         }))),
         Err(e) => {
             warn!("❌ Failed to fetch stats: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(ApiError::internal(
+                "DATABASE_ERROR",
+                "Failed to fetch stats",
+            ))
         }
     }
 }
@@ -3,4 +3,4 @@ pub mod models;
 pub mod providers;
 pub mod routes;
 
-pub use routes::create_discovery_routes;
+pub use routes::{create_discovery_routes, start_external_catalog_refresh};
@@ -1,10 +1,10 @@
 use nullblock_mcp_client::{
-    AuthConfig, McpServerConfig, RegistryStats, ServiceEndpoint, ServiceRegistry,
+    AuthConfig, McpServerConfig, RegistryStats, ServiceEndpoint, ServiceRegistry, ToolCatalogChange,
 };
 use std::path::Path;
 use std::sync::Arc;
-use std::time::Instant;
-use tokio::sync::RwLock;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
 use tracing::{debug, info, warn};
 
 use crate::resources::discovery::aggregator::DiscoveryProvider;
@@ -16,14 +16,14 @@ const CONFIG_FILE_PATH: &str = "config/mcp-services.toml";
 const ENV_VAR_EXTERNAL_MCP: &str = "EXTERNAL_MCP_SERVICES";
 
 pub struct ExternalMcpProvider {
-    registry: Arc<RwLock<ServiceRegistry>>,
+    registry: Arc<ServiceRegistry>,
     name: String,
 }
 
 impl ExternalMcpProvider {
     pub fn new() -> Self {
         Self {
-            registry: Arc::new(RwLock::new(ServiceRegistry::new())),
+            registry: Arc::new(ServiceRegistry::new()),
             name: "external-mcp".to_string(),
         }
     }
@@ -37,7 +37,7 @@ impl ExternalMcpProvider {
             return Ok(());
         }
 
-        let registry = self.registry.write().await;
+        let registry = &self.registry;
         for config in configs {
             info!(
                 name = %config.name,
@@ -189,7 +189,7 @@ impl ExternalMcpProvider {
     async fn discover_tools_impl(
         &self,
     ) -> Result<Vec<DiscoveredTool>, Box<dyn std::error::Error + Send + Sync>> {
-        let registry = self.registry.read().await;
+        let registry = &self.registry;
         let services = registry.list_services().await;
 
         if services.is_empty() {
@@ -201,11 +201,9 @@ impl ExternalMcpProvider {
             "🔧 Discovering tools from {} external MCP services",
             services.len()
         );
-        drop(registry);
 
         let mut all_tools = Vec::new();
 
-        let registry = self.registry.write().await;
         let mcp_tools = match registry.discover_all_tools().await {
             Ok(tools) => tools,
             Err(e) => {
@@ -213,9 +211,7 @@ impl ExternalMcpProvider {
                 vec![]
             }
         };
-        drop(registry);
 
-        let registry = self.registry.read().await;
         for tool in mcp_tools {
             let service_name = registry.get_service_for_tool(&tool.name).await;
             let service = if let Some(ref name) = service_name {
@@ -264,7 +260,7 @@ impl ExternalMcpProvider {
     async fn discover_protocols_impl(
         &self,
     ) -> Result<Vec<DiscoveredProtocol>, Box<dyn std::error::Error + Send + Sync>> {
-        let registry = self.registry.read().await;
+        let registry = &self.registry;
         let services = registry.list_services().await;
 
         let mut protocols = Vec::new();
@@ -295,7 +291,7 @@ impl ExternalMcpProvider {
 
     async fn health_impl(&self) -> ProviderHealth {
         let start = Instant::now();
-        let registry = self.registry.read().await;
+        let registry = &self.registry;
         let stats = registry.stats().await;
 
         if stats.service_count == 0 {
@@ -338,30 +334,50 @@ impl ExternalMcpProvider {
     }
 
     pub async fn get_registry_stats(&self) -> RegistryStats {
-        self.registry.read().await.stats().await
+        self.registry.stats().await
     }
 
     pub async fn list_services(&self) -> Vec<ServiceEndpoint> {
-        self.registry.read().await.list_services().await
+        self.registry.list_services().await
     }
 
     pub async fn register_service(
         &self,
         config: McpServerConfig,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let registry = self.registry.write().await;
-        registry.register_with_config(config).await?;
+        self.registry.register_with_config(config).await?;
         Ok(())
     }
 
     pub async fn unregister_service(&self, name: &str) {
-        let registry = self.registry.write().await;
-        registry.unregister(name).await;
+        self.registry.unregister(name).await;
+    }
+
+    /// Re-discovers external tools every `interval` and logs what changed, so the
+    /// registry notices servers adding or dropping tools without a manual refresh.
+    pub async fn start_catalog_refresh(&self, interval: Duration) -> JoinHandle<()> {
+        self.registry
+            .on_tool_catalog_change(|changes: &[ToolCatalogChange]| {
+                for change in changes {
+                    let kind = match change {
+                        ToolCatalogChange::Added { .. } => "added",
+                        ToolCatalogChange::Removed { .. } => "removed",
+                        ToolCatalogChange::SchemaChanged { .. } => "schema changed",
+                    };
+                    info!(
+                        service = change.service(),
+                        tool = change.tool_name(),
+                        "🔄 External MCP tool {}",
+                        kind
+                    );
+                }
+            })
+            .await;
+        self.registry.start_periodic_refresh(interval)
     }
 
     pub async fn refresh_tools(&self) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
-        let registry = self.registry.write().await;
-        let tools = registry.discover_all_tools().await?;
+        let tools = self.registry.discover_all_tools().await?;
         Ok(tools.len())
     }
 }
@@ -18,6 +18,8 @@ use super::models::{
 };
 use super::providers::{AgentsProvider, ArbFarmProvider, ExternalMcpProvider, ProtocolsProvider};
 
+const DEFAULT_EXTERNAL_MCP_REFRESH_SECS: u64 = 300;
+
 lazy_static::lazy_static! {
     static ref EXTERNAL_MCP_PROVIDER: Arc<RwLock<Option<Arc<ExternalMcpProvider>>>> = Arc::new(RwLock::new(None));
 }
@@ -61,6 +63,25 @@ async fn get_or_init_external_provider() -> Arc<ExternalMcpProvider> {
     guard.as_ref().unwrap().clone()
 }
 
+/// Initializes the external MCP provider and keeps its tool catalog fresh, every
+/// `EXTERNAL_MCP_REFRESH_SECS` (default 300).
+pub async fn start_external_catalog_refresh() {
+    let interval_secs = std::env::var("EXTERNAL_MCP_REFRESH_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(DEFAULT_EXTERNAL_MCP_REFRESH_SECS);
+
+    let provider = get_or_init_external_provider().await;
+    provider
+        .start_catalog_refresh(std::time::Duration::from_secs(interval_secs))
+        .await;
+    info!(
+        "🔄 External MCP tool catalog refreshing every {}s",
+        interval_secs
+    );
+}
+
 fn create_aggregator() -> DiscoveryAggregator {
     let providers: Vec<Arc<dyn super::aggregator::DiscoveryProvider>> = vec![
         Arc::new(ArbFarmProvider::new()),
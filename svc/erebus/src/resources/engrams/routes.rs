@@ -26,6 +26,11 @@ pub struct ListQuery {
     pub offset: Option<i64>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ExportQuery {
+    pub wallet: String,
+}
+
 async fn proxy_request(
     method: &str,
     endpoint: &str,
@@ -214,6 +219,31 @@ pub async fn publish_engram(
     proxy_request("POST", &format!("engrams/{}/publish", id), None).await
 }
 
+pub async fn export_engrams(
+    Query(query): Query<ExportQuery>,
+) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<EngramErrorResponse>)> {
+    info!(
+        "📦 Export engrams request received for wallet: {}",
+        query.wallet
+    );
+    proxy_request("GET", &export_endpoint(&query.wallet), None).await
+}
+
+/// Export path with the wallet form-encoded, so `&`, `#` or spaces in it can't
+/// rewrite the query sent to the Engram service.
+fn export_endpoint(wallet: &str) -> String {
+    let mut url = reqwest::Url::parse("http://engrams/engrams/export").expect("static url parses");
+    url.query_pairs_mut().append_pair("wallet", wallet);
+    format!("engrams/export?{}", url.query().unwrap_or_default())
+}
+
+pub async fn import_engrams(
+    Json(request): Json<Value>,
+) -> Result<ResponseJson<Value>, (StatusCode, ResponseJson<EngramErrorResponse>)> {
+    info!("📥 Import engrams request received");
+    proxy_request("POST", "engrams/import", Some(request)).await
+}
+
 pub fn create_engram_routes<S>() -> Router<S>
 where
     S: Clone + Send + Sync + 'static,
@@ -223,6 +253,8 @@ where
         .route("/api/engrams", post(create_engram))
         .route("/api/engrams", get(list_engrams))
         .route("/api/engrams/search", post(search_engrams))
+        .route("/api/engrams/export", get(export_engrams))
+        .route("/api/engrams/import", post(import_engrams))
         .route("/api/engrams/:id", get(get_engram))
         .route("/api/engrams/:id", put(update_engram))
         .route("/api/engrams/:id", delete(delete_engram))
@@ -234,3 +266,17 @@ where
         .route("/api/engrams/:id/fork", post(fork_engram))
         .route("/api/engrams/:id/publish", post(publish_engram))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_endpoint_encodes_wallet() {
+        assert_eq!(export_endpoint("0xabc"), "engrams/export?wallet=0xabc");
+        assert_eq!(
+            export_endpoint("0xabc&wallet=0xdef#x y"),
+            "engrams/export?wallet=0xabc%26wallet%3D0xdef%23x+y"
+        );
+    }
+}
@@ -1,26 +1,61 @@
-use super::types::{McpCapabilities, McpRequest, McpResponse, McpServerInfo};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::types::{
+    McpCapabilities, McpRequest, McpResponse, McpServerInfo, McpToolCallOutcome, McpToolCallStatus,
+};
 use super::worker::McpWorkerFactory;
 
+pub const DEFAULT_TOOL_TIMEOUT: Duration = Duration::from_secs(30);
+const MAX_TOOL_TIMEOUT: Duration = Duration::from_secs(300);
+
+pub type McpToolFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<serde_json::Value, String>> + Send + 'a>>;
+
+/// A tool served directly by Erebus. Calls are cancelled by dropping the future when
+/// they time out, so implementations must not leave work behind on drop.
+pub trait McpTool: Send + Sync {
+    fn name(&self) -> &str;
+    fn call(&self, arguments: serde_json::Value) -> McpToolFuture<'_>;
+}
+
 #[derive(Clone)]
 pub struct McpHandler {
     worker_factory: std::sync::Arc<McpWorkerFactory>,
+    tools: HashMap<String, Arc<dyn McpTool>>,
+    tool_timeout: Duration,
 }
 
 impl McpHandler {
     pub fn new() -> Self {
         Self {
             worker_factory: std::sync::Arc::new(McpWorkerFactory::new()),
+            tools: HashMap::new(),
+            tool_timeout: DEFAULT_TOOL_TIMEOUT,
         }
     }
 
+    pub fn with_tool(mut self, tool: Arc<dyn McpTool>) -> Self {
+        self.tools.insert(tool.name().to_string(), tool);
+        self
+    }
+
+    pub fn with_tool_timeout(mut self, timeout: Duration) -> Self {
+        self.tool_timeout = timeout;
+        self
+    }
+
     /// Handle MCP protocol requests
-    pub fn handle_request(&self, request: McpRequest) -> McpResponse {
+    pub async fn handle_request(&self, request: McpRequest) -> McpResponse {
         match request.method.as_str() {
             "ping" => self.handle_ping(&request.params),
             "initialize" => self.handle_initialize(&request.params),
             "resources/list" => self.handle_list_resources(&request.params),
             "tools/list" => self.handle_list_tools(&request.params),
-            "tools/call" => self.handle_tool_call(&request.params),
+            "tools/call" => self.handle_tool_call(&request.params).await,
             "prompts/list" => self.handle_list_prompts(&request.params),
             "prompts/get" => self.handle_get_prompt(&request.params),
             "resources/read" => self.handle_read_resource(&request.params),
@@ -215,27 +250,123 @@ impl McpHandler {
         McpResponse::success(prompts)
     }
 
-    fn handle_tool_call(&self, params: &Option<serde_json::Value>) -> McpResponse {
+    /// `tools/call` takes either a single `name`/`arguments` pair or a `calls` array.
+    /// Batches run concurrently and always succeed, with a per-tool outcome entry, so
+    /// one slow or failing tool doesn't sink the others. `timeout_ms` overrides the
+    /// per-tool timeout for this request.
+    async fn handle_tool_call(&self, params: &Option<serde_json::Value>) -> McpResponse {
+        let timeout = params
+            .as_ref()
+            .and_then(|p| p.get("timeout_ms"))
+            .and_then(|t| t.as_u64())
+            .map(|ms| Duration::from_millis(ms).min(MAX_TOOL_TIMEOUT))
+            .unwrap_or(self.tool_timeout);
+
+        if let Some(calls) = params
+            .as_ref()
+            .and_then(|p| p.get("calls"))
+            .and_then(|c| c.as_array())
+        {
+            println!("🔧 MCP batch tool call: {} tools", calls.len());
+            let outcomes = futures::future::join_all(calls.iter().map(|call| {
+                let name = call
+                    .get("name")
+                    .and_then(|n| n.as_str())
+                    .unwrap_or("unknown");
+                let arguments = call
+                    .get("arguments")
+                    .cloned()
+                    .unwrap_or_else(|| serde_json::json!({}));
+                self.call_tool(name, arguments, timeout)
+            }))
+            .await;
+
+            let succeeded = outcomes
+                .iter()
+                .filter(|o| o.status == McpToolCallStatus::Ok)
+                .count();
+            return McpResponse::success(serde_json::json!({
+                "results": outcomes,
+                "succeeded": succeeded,
+                "failed": outcomes.len() - succeeded,
+            }));
+        }
+
         let tool_name = params
             .as_ref()
             .and_then(|p| p.get("name"))
             .and_then(|n| n.as_str())
             .unwrap_or("unknown");
 
-        let empty_args = serde_json::json!({});
         let arguments = params
             .as_ref()
             .and_then(|p| p.get("arguments"))
-            .unwrap_or(&empty_args);
+            .cloned()
+            .unwrap_or_else(|| serde_json::json!({}));
 
         println!("🔧 MCP tool call: {} with args: {}", tool_name, arguments);
 
-        match tool_name {
-            "social_trading_analyze" => self.delegate_to_nullblock_mcp("social_trading", arguments),
-            _ => McpResponse::error(format!(
-                "Tool '{}' requires delegation to nullblock.mcp",
-                tool_name
-            )),
+        let outcome = self.call_tool(tool_name, arguments, timeout).await;
+        match outcome.status {
+            McpToolCallStatus::Ok => McpResponse::success(outcome.result.unwrap_or_default()),
+            _ => McpResponse::error(outcome.error.unwrap_or_default()),
+        }
+    }
+
+    async fn call_tool(
+        &self,
+        name: &str,
+        arguments: serde_json::Value,
+        timeout: Duration,
+    ) -> McpToolCallOutcome {
+        let started = Instant::now();
+        let outcome = |status, result, error| McpToolCallOutcome {
+            name: name.to_string(),
+            status,
+            result,
+            error,
+            elapsed_ms: started.elapsed().as_millis() as u64,
+        };
+
+        // Delegated tools run under the same timeout as registered ones
+        let call: McpToolFuture<'_> = match self.tools.get(name) {
+            Some(tool) => tool.call(arguments),
+            None => Box::pin(async move {
+                let response = match name {
+                    "social_trading_analyze" => {
+                        self.delegate_to_nullblock_mcp("social_trading", &arguments)
+                    }
+                    _ => McpResponse::error(format!(
+                        "Tool '{}' requires delegation to nullblock.mcp",
+                        name
+                    )),
+                };
+                match response.error {
+                    Some(error) => Err(error),
+                    None => Ok(response.result.unwrap_or_default()),
+                }
+            }),
+        };
+
+        match tokio::time::timeout(timeout, call).await {
+            Ok(Ok(result)) => outcome(McpToolCallStatus::Ok, Some(result), None),
+            Ok(Err(error)) => outcome(McpToolCallStatus::Error, None, Some(error)),
+            Err(_) => {
+                println!(
+                    "⏱️ MCP tool '{}' timed out after {}ms",
+                    name,
+                    timeout.as_millis()
+                );
+                outcome(
+                    McpToolCallStatus::TimedOut,
+                    None,
+                    Some(format!(
+                        "Tool '{}' timed out after {}ms",
+                        name,
+                        timeout.as_millis()
+                    )),
+                )
+            }
         }
     }
 
@@ -455,3 +586,159 @@ impl McpHandler {
         McpResponse::success(stats)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    struct EchoTool;
+
+    impl McpTool for EchoTool {
+        fn name(&self) -> &str {
+            "echo"
+        }
+
+        fn call(&self, arguments: serde_json::Value) -> McpToolFuture<'_> {
+            Box::pin(async move { Ok(serde_json::json!({ "echo": arguments })) })
+        }
+    }
+
+    struct FailingTool;
+
+    impl McpTool for FailingTool {
+        fn name(&self) -> &str {
+            "failing"
+        }
+
+        fn call(&self, _arguments: serde_json::Value) -> McpToolFuture<'_> {
+            Box::pin(async { Err("upstream unavailable".to_string()) })
+        }
+    }
+
+    struct DropFlag(Arc<AtomicBool>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    struct SlowTool {
+        completed: Arc<AtomicBool>,
+        dropped: Arc<AtomicBool>,
+    }
+
+    impl McpTool for SlowTool {
+        fn name(&self) -> &str {
+            "slow"
+        }
+
+        fn call(&self, _arguments: serde_json::Value) -> McpToolFuture<'_> {
+            let guard = DropFlag(self.dropped.clone());
+            Box::pin(async move {
+                let _guard = guard;
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                self.completed.store(true, Ordering::SeqCst);
+                Ok(serde_json::json!({}))
+            })
+        }
+    }
+
+    fn handler() -> (McpHandler, Arc<AtomicBool>, Arc<AtomicBool>) {
+        let completed = Arc::new(AtomicBool::new(false));
+        let dropped = Arc::new(AtomicBool::new(false));
+        let handler = McpHandler::new()
+            .with_tool(Arc::new(EchoTool))
+            .with_tool(Arc::new(FailingTool))
+            .with_tool(Arc::new(SlowTool {
+                completed: completed.clone(),
+                dropped: dropped.clone(),
+            }))
+            .with_tool_timeout(Duration::from_millis(50));
+        (handler, completed, dropped)
+    }
+
+    fn tool_call(params: serde_json::Value) -> McpRequest {
+        McpRequest {
+            method: "tools/call".to_string(),
+            params: Some(params),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_returns_partial_results() {
+        let (handler, completed, dropped) = handler();
+
+        let response = handler
+            .handle_request(tool_call(serde_json::json!({
+                "calls": [
+                    { "name": "echo", "arguments": { "value": 1 } },
+                    { "name": "slow" },
+                    { "name": "failing" },
+                ]
+            })))
+            .await;
+
+        assert!(response.error.is_none());
+        let result = response.result.unwrap();
+        assert_eq!(result["succeeded"], 1);
+        assert_eq!(result["failed"], 2);
+
+        let results = result["results"].as_array().unwrap();
+        assert_eq!(results[0]["status"], "ok");
+        assert_eq!(results[0]["result"]["echo"]["value"], 1);
+        assert_eq!(results[1]["name"], "slow");
+        assert_eq!(results[1]["status"], "timed_out");
+        assert!(results[1]["error"].as_str().unwrap().contains("timed out"));
+        assert_eq!(results[2]["status"], "error");
+        assert_eq!(results[2]["error"], "upstream unavailable");
+
+        assert!(dropped.load(Ordering::SeqCst));
+        assert!(!completed.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_single_call_timeout_and_override() {
+        let (handler, _, _) = handler();
+
+        let timed_out = handler
+            .handle_request(tool_call(serde_json::json!({ "name": "slow" })))
+            .await;
+        assert!(timed_out.error.unwrap().contains("timed out after 50ms"));
+
+        let fast = handler
+            .handle_request(tool_call(serde_json::json!({
+                "name": "echo",
+                "arguments": { "value": 2 },
+                "timeout_ms": 1000,
+            })))
+            .await;
+        assert_eq!(fast.result.unwrap()["echo"]["value"], 2);
+
+        let unknown = handler
+            .handle_request(tool_call(serde_json::json!({ "name": "missing" })))
+            .await;
+        assert!(unknown.error.unwrap().contains("requires delegation"));
+    }
+
+    #[tokio::test]
+    async fn test_delegated_tool_reported_in_batch() {
+        let (handler, _, _) = handler();
+
+        let response = handler
+            .handle_request(tool_call(serde_json::json!({
+                "calls": [
+                    { "name": "social_trading_analyze", "arguments": { "token": "BONK" } },
+                    { "name": "missing" },
+                ]
+            })))
+            .await;
+
+        let result = response.result.unwrap();
+        let results = result["results"].as_array().unwrap();
+        assert_eq!(results[0]["status"], "ok");
+        assert_eq!(results[0]["result"]["status"], "worker_created");
+        assert_eq!(results[1]["status"], "error");
+    }
+}
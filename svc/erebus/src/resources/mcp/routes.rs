@@ -1,5 +1,5 @@
 use axum::{
-    extract::Json,
+    extract::{Json, State},
     http::StatusCode,
     response::Json as ResponseJson,
     routing::{get, post},
@@ -7,8 +7,12 @@ use axum::{
 };
 use serde::Serialize;
 use serde_json::{json, Value};
+use std::sync::Arc;
 use tracing::{error, info};
 
+use super::handler::McpHandler;
+use super::types::{McpRequest, McpResponse};
+
 fn get_protocols_service_url() -> String {
     std::env::var("PROTOCOLS_SERVICE_URL").unwrap_or_else(|_| "http://localhost:8001".to_string())
 }
@@ -172,11 +176,26 @@ pub async fn mcp_health(
     }
 }
 
+/// Methods Erebus serves itself (`tools/call` batches with per-tool timeouts, worker
+/// status, wallet resources) rather than proxying to the Protocols service.
+pub async fn mcp_erebus(
+    State(handler): State<Arc<McpHandler>>,
+    Json(request): Json<McpRequest>,
+) -> ResponseJson<McpResponse> {
+    info!("🔌 Erebus MCP request: {}", request.method);
+    ResponseJson(handler.handle_request(request).await)
+}
+
 pub fn create_mcp_routes<S>() -> Router<S>
 where
     S: Clone + Send + Sync + 'static,
 {
+    let erebus_mcp = Router::new()
+        .route("/mcp/erebus", post(mcp_erebus))
+        .with_state(Arc::new(McpHandler::new()));
+
     Router::new()
+        .merge(erebus_mcp)
         .route("/mcp/jsonrpc", post(mcp_jsonrpc))
         .route("/mcp/tools", get(mcp_tools))
         .route("/mcp/resources", get(mcp_resources))
@@ -184,3 +203,41 @@ where
         .route("/mcp/health", get(mcp_health))
         .route("/api/tools", get(mcp_tools))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use tower::Service;
+
+    #[tokio::test]
+    async fn test_erebus_mcp_route_serves_tool_batches() {
+        let mut app: Router = create_mcp_routes();
+        let body = json!({
+            "method": "tools/call",
+            "params": {
+                "calls": [
+                    { "name": "social_trading_analyze", "arguments": { "token": "BONK" } },
+                    { "name": "missing" }
+                ]
+            }
+        });
+
+        let response = app
+            .call(
+                Request::post("/mcp/erebus")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["result"]["succeeded"], 1);
+        assert_eq!(json["result"]["failed"], 1);
+    }
+}
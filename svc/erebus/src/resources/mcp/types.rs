@@ -46,3 +46,22 @@ impl McpResponse {
         Self::error("Method not implemented".to_string())
     }
 }
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum McpToolCallStatus {
+    Ok,
+    Error,
+    TimedOut,
+}
+
+#[derive(Debug, Serialize)]
+pub struct McpToolCallOutcome {
+    pub name: String,
+    pub status: McpToolCallStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub elapsed_ms: u64,
+}
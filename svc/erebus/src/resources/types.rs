@@ -21,6 +21,7 @@ pub struct WalletChallengeResponse {
     pub challenge_id: String,
     pub message: String,
     pub wallet_address: String,
+    pub domain: String, // Origin the challenge is bound to, included in the signed message
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -62,12 +62,17 @@ impl WalletAdapter for BitgetAdapter {
         format!(
             "Welcome to Nullblock!\n\n\
              Sign this message to authenticate your Bitget Wallet ({}).\n\n\
+             Domain: {}\n\
              Wallet Address: {}\n\
              Challenge ID: {}\n\
              Timestamp: {}\n\n\
              This action will not trigger any blockchain transaction or cost gas fees.\n\n\
              By signing, you agree to connect your wallet to the Nullblock platform.",
-            chain_name, context.wallet_address, context.challenge_id, context.timestamp
+            chain_name,
+            context.domain,
+            context.wallet_address,
+            context.challenge_id,
+            context.timestamp
         )
     }
 
@@ -187,6 +192,7 @@ mod tests {
         let adapter = BitgetAdapter::new();
 
         let evm_context = ChallengeContext {
+            domain: "nullblock.ai".to_string(),
             wallet_address: "0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string(),
             challenge_id: "test-123".to_string(),
             chain: ChainType::Evm,
@@ -196,6 +202,7 @@ mod tests {
         assert!(evm_message.contains("EVM"));
 
         let solana_context = ChallengeContext {
+            domain: "nullblock.ai".to_string(),
             wallet_address: "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM6".to_string(),
             challenge_id: "test-456".to_string(),
             chain: ChainType::Solana,
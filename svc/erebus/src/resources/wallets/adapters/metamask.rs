@@ -53,12 +53,13 @@ impl WalletAdapter for MetaMaskAdapter {
         format!(
             "Welcome to Nullblock!\n\n\
              Sign this message to authenticate your MetaMask wallet.\n\n\
+             Domain: {}\n\
              Wallet Address: {}\n\
              Challenge ID: {}\n\
              Timestamp: {}\n\n\
              This action will not trigger any blockchain transaction or cost gas fees.\n\n\
              By signing, you agree to connect your wallet to the Nullblock platform.",
-            context.wallet_address, context.challenge_id, context.timestamp
+            context.domain, context.wallet_address, context.challenge_id, context.timestamp
         )
     }
 
@@ -170,6 +171,7 @@ mod tests {
     fn test_metamask_challenge_message() {
         let adapter = MetaMaskAdapter::new();
         let context = ChallengeContext {
+            domain: "nullblock.ai".to_string(),
             wallet_address: "0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string(),
             challenge_id: "test-challenge-123".to_string(),
             chain: ChainType::Evm,
@@ -178,6 +180,7 @@ mod tests {
 
         let message = adapter.create_challenge_message(&context);
         assert!(message.contains("MetaMask"));
+        assert!(message.contains("Domain: nullblock.ai"));
         assert!(message.contains(&context.wallet_address));
         assert!(message.contains(&context.challenge_id));
     }
@@ -53,12 +53,13 @@ impl WalletAdapter for PhantomAdapter {
         format!(
             "Nullblock Authentication\n\n\
              Connect your Phantom wallet to unlock agentic workflows.\n\n\
+             Domain: {}\n\
              Wallet: {}\n\
              Challenge: {}\n\
              Timestamp: {}\n\n\
              This signature will not trigger any blockchain transaction.\n\n\
              Welcome to the void, agent.",
-            context.wallet_address, context.challenge_id, context.timestamp
+            context.domain, context.wallet_address, context.challenge_id, context.timestamp
         )
     }
 
@@ -166,6 +167,7 @@ mod tests {
     fn test_phantom_challenge_message() {
         let adapter = PhantomAdapter::new();
         let context = ChallengeContext {
+            domain: "nullblock.ai".to_string(),
             wallet_address: "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM6".to_string(),
             challenge_id: "test-challenge-123".to_string(),
             chain: ChainType::Solana,
@@ -174,6 +176,7 @@ mod tests {
 
         let message = adapter.create_challenge_message(&context);
         assert!(message.contains("Phantom"));
+        assert!(message.contains("Domain: nullblock.ai"));
         assert!(message.contains(&context.wallet_address));
         assert!(message.contains(&context.challenge_id));
     }
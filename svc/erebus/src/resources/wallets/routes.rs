@@ -1,7 +1,7 @@
 // Wallet-specific HTTP routes and handlers
 use axum::{
     extract::{Path, State},
-    http::StatusCode,
+    http::{header, HeaderMap},
     response::Json,
     routing::{get, post},
     Router,
@@ -18,6 +18,7 @@ use crate::resources::{
     WalletVerifyRequest, WalletVerifyResponse,
 };
 use crate::user_references::{SourceType, UserReferenceService};
+use crate::utils::api_error::ApiError;
 use uuid::Uuid;
 
 /// Register user directly in Erebus database after successful wallet verification
@@ -132,16 +133,32 @@ async fn get_wallet_status(State(app_state): State<crate::AppState>) -> Json<Wal
     Json(response)
 }
 
+/// Origin the browser sent, falling back to Host for non-browser clients.
+///
+/// Either way the domain must be one of `WALLET_AUTH_DOMAINS`, and the signed message
+/// names it, so a header a non-browser client sets can't move a challenge to another site.
+fn request_origin(headers: &HeaderMap) -> &str {
+    headers
+        .get(header::ORIGIN)
+        .or_else(|| headers.get(header::HOST))
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+}
+
 /// Create wallet authentication challenge endpoint
 async fn create_wallet_challenge(
     State(app_state): State<crate::AppState>,
+    headers: HeaderMap,
     Json(request): Json<WalletChallengeRequest>,
-) -> Result<Json<WalletChallengeResponse>, StatusCode> {
-    match app_state.wallet_manager.create_wallet_challenge(request) {
+) -> Result<Json<WalletChallengeResponse>, ApiError> {
+    match app_state
+        .wallet_manager
+        .create_wallet_challenge(request, request_origin(&headers))
+    {
         Ok(response) => Ok(Json(response)),
         Err(error) => {
             println!("❌ Challenge creation failed: {}", error);
-            Err(StatusCode::BAD_REQUEST)
+            Err(ApiError::bad_request("CHALLENGE_CREATION_FAILED", error))
         }
     }
 }
@@ -149,8 +166,9 @@ async fn create_wallet_challenge(
 /// Verify wallet signature endpoint
 async fn verify_wallet_signature(
     State(app_state): State<crate::AppState>,
+    headers: HeaderMap,
     Json(request): Json<WalletVerifyRequest>,
-) -> Json<WalletVerifyResponse> {
+) -> Result<Json<WalletVerifyResponse>, ApiError> {
     use tracing::{error, info, warn};
 
     let wallet_address = request.wallet_address.clone();
@@ -159,53 +177,54 @@ async fn verify_wallet_signature(
         wallet_address
     );
 
-    let mut verification_response = app_state.wallet_manager.verify_wallet_signature(request);
+    let mut verification_response = app_state
+        .wallet_manager
+        .verify_wallet_signature(request, request_origin(&headers));
 
-    // If verification successful, register user via Erebus database
-    if verification_response.success {
-        info!(
-            "✅ Wallet signature verification successful for: {}",
-            wallet_address
+    if !verification_response.success {
+        warn!(
+            "❌ Wallet signature verification failed for {}: {}",
+            wallet_address, verification_response.message
         );
-        info!("🎯 Proceeding with user registration in Erebus database");
-
-        // Determine chain based on wallet type
-        let chain = if PhantomWallet::validate_solana_address(&wallet_address) {
-            info!("🔍 Detected Solana address format");
-            "solana"
-        } else if MetaMaskWallet::validate_ethereum_address(&wallet_address) {
-            info!("🔍 Detected Ethereum address format");
-            "ethereum"
-        } else {
-            warn!("⚠️ Unknown wallet address format: {}", wallet_address);
-            "unknown"
-        };
+        return Err(verification_failed(verification_response));
+    }
 
-        // Register user directly in Erebus database
-        match register_user_in_database(&wallet_address, chain, &app_state.database).await {
-            Ok(user_id) => {
-                info!("✅ User registration completed successfully");
-                info!("   User ID: {}", user_id);
-                verification_response.user_id = Some(user_id.to_string());
-                verification_response.registration_error = None;
-            }
-            Err(e) => {
-                error!("❌ Wallet verification succeeded but user registration failed");
-                error!("   Error: {}", e);
-                verification_response.user_id = None;
-                verification_response.registration_error = Some(e.clone());
-                // Note: We still return success=true because wallet verification succeeded
-                // Frontend can check registration_error to see if there was a registration issue
-                warn!("⚠️ Wallet is verified but user not registered in database");
-            }
-        }
+    // Verification succeeded, register user via Erebus database
+    info!(
+        "✅ Wallet signature verification successful for: {}",
+        wallet_address
+    );
+    info!("🎯 Proceeding with user registration in Erebus database");
+
+    // Determine chain based on wallet type
+    let chain = if PhantomWallet::validate_solana_address(&wallet_address) {
+        info!("🔍 Detected Solana address format");
+        "solana"
+    } else if MetaMaskWallet::validate_ethereum_address(&wallet_address) {
+        info!("🔍 Detected Ethereum address format");
+        "ethereum"
     } else {
-        warn!(
-            "❌ Wallet signature verification failed for: {}",
-            wallet_address
-        );
-        verification_response.user_id = None;
-        verification_response.registration_error = None;
+        warn!("⚠️ Unknown wallet address format: {}", wallet_address);
+        "unknown"
+    };
+
+    // Register user directly in Erebus database
+    match register_user_in_database(&wallet_address, chain, &app_state.database).await {
+        Ok(user_id) => {
+            info!("✅ User registration completed successfully");
+            info!("   User ID: {}", user_id);
+            verification_response.user_id = Some(user_id.to_string());
+            verification_response.registration_error = None;
+        }
+        Err(e) => {
+            error!("❌ Wallet verification succeeded but user registration failed");
+            error!("   Error: {}", e);
+            verification_response.user_id = None;
+            verification_response.registration_error = Some(e.clone());
+            // Note: We still return success=true because wallet verification succeeded
+            // Frontend can check registration_error to see if there was a registration issue
+            warn!("⚠️ Wallet is verified but user not registered in database");
+        }
     }
 
     info!(
@@ -215,16 +234,24 @@ async fn verify_wallet_signature(
         verification_response.registration_error
     );
 
-    Json(verification_response)
+    Ok(Json(verification_response))
+}
+
+/// 401 for a rejected signature, carrying the wallet manager's reason
+fn verification_failed(response: WalletVerifyResponse) -> ApiError {
+    ApiError::unauthorized("WALLET_VERIFICATION_FAILED", response.message)
 }
 
 /// Get supported networks for a specific wallet type
 async fn get_wallet_networks(
     Path(wallet_type): Path<String>,
-) -> Result<Json<serde_json::Value>, StatusCode> {
+) -> Result<Json<serde_json::Value>, ApiError> {
     let networks = WalletManager::get_wallet_networks(&wallet_type);
     if networks.is_empty() {
-        Err(StatusCode::NOT_FOUND)
+        Err(ApiError::not_found(
+            "WALLET_NOT_SUPPORTED",
+            format!("Wallet type '{}' is not supported", wallet_type),
+        ))
     } else {
         Ok(Json(serde_json::json!({
             "wallet_type": wallet_type,
@@ -240,3 +267,39 @@ async fn validate_session() -> Json<serde_json::Value> {
         "message": "Session validation not implemented yet"
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::api_error::response_error;
+    use axum::{http::StatusCode, response::IntoResponse};
+
+    #[tokio::test]
+    async fn test_unknown_wallet_networks_returns_standard_not_found() {
+        let error = get_wallet_networks(Path("unknown".to_string()))
+            .await
+            .unwrap_err();
+
+        let (status, body) = response_error(error.into_response()).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body.error.code, "WALLET_NOT_SUPPORTED");
+        assert!(body.error.details.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_failed_verification_is_unauthorized() {
+        let response = WalletVerifyResponse {
+            success: false,
+            session_token: None,
+            message: "Challenge domain mismatch".to_string(),
+            user_id: None,
+            registration_error: None,
+            network: None,
+        };
+
+        let (status, body) = response_error(verification_failed(response).into_response()).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body.error.code, "WALLET_VERIFICATION_FAILED");
+        assert_eq!(body.error.message, "Challenge domain mismatch");
+    }
+}
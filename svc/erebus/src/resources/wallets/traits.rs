@@ -41,6 +41,8 @@ pub struct WalletInfo {
 
 #[derive(Debug, Clone)]
 pub struct ChallengeContext {
+    /// Origin the challenge was issued to; signing binds the signature to it
+    pub domain: String,
     pub wallet_address: String,
     pub challenge_id: String,
     pub chain: ChainType,
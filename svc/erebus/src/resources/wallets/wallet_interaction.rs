@@ -1,6 +1,7 @@
 // Generic layer for agnostic wallet interaction
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use tracing::{info, warn};
 use uuid::Uuid;
 
 use super::registry::WALLET_REGISTRY;
@@ -17,6 +18,7 @@ pub struct ChallengeData {
     pub wallet_address: String,
     pub wallet_type: String,
     pub chain: ChainType,
+    pub domain: String,
 }
 
 // Storage for challenges and sessions (in production, use Redis or database)
@@ -27,6 +29,7 @@ pub type SessionStorage = Arc<Mutex<HashMap<String, WalletSession>>>;
 pub struct WalletManager {
     challenge_storage: ChallengeStorage,
     session_storage: SessionStorage,
+    allowed_domains: Vec<String>,
 }
 
 impl Default for WalletManager {
@@ -40,9 +43,55 @@ impl WalletManager {
         Self {
             challenge_storage: Arc::new(Mutex::new(HashMap::new())),
             session_storage: Arc::new(Mutex::new(HashMap::new())),
+            allowed_domains: Vec::new(),
         }
     }
 
+    /// `WALLET_AUTH_DOMAINS`: comma-separated domains wallet sign-in is served from.
+    /// Unset disables wallet sign-in, since any origin could otherwise bind a challenge.
+    pub fn from_env() -> Self {
+        let domains = std::env::var("WALLET_AUTH_DOMAINS").unwrap_or_default();
+        let manager = Self::new().with_allowed_domains(domains.split(','));
+        if manager.allowed_domains.is_empty() {
+            warn!("WALLET_AUTH_DOMAINS is not set - wallet challenges will be refused");
+        }
+        manager
+    }
+
+    pub fn with_allowed_domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.allowed_domains = domains
+            .into_iter()
+            .filter_map(|d| normalize_domain(d.as_ref()))
+            .collect();
+        self
+    }
+
+    /// The configured domain `origin` belongs to. Challenges are only ever bound to, and
+    /// verified against, a domain from `WALLET_AUTH_DOMAINS`.
+    fn bind_domain(&self, origin: &str) -> Result<String, String> {
+        if self.allowed_domains.is_empty() {
+            return Err(
+                "Wallet sign-in domain is not configured (WALLET_AUTH_DOMAINS)".to_string(),
+            );
+        }
+
+        let domain = normalize_domain(origin)
+            .ok_or_else(|| "Missing request origin for wallet challenge".to_string())?;
+
+        if !self.allowed_domains.contains(&domain) {
+            return Err(format!(
+                "Domain {} is not allowed to request wallet challenges",
+                domain
+            ));
+        }
+
+        Ok(domain)
+    }
+
     /// Get all supported wallets for API exposure
     pub fn get_supported_wallets() -> WalletListResponse {
         let wallets = WALLET_REGISTRY
@@ -61,11 +110,14 @@ impl WalletManager {
         }
     }
 
-    /// Create authentication challenge for any supported wallet
+    /// Create authentication challenge for any supported wallet, bound to the requesting origin
     pub fn create_wallet_challenge(
         &self,
         request: WalletChallengeRequest,
+        origin: &str,
     ) -> Result<WalletChallengeResponse, String> {
+        let domain = self.bind_domain(origin)?;
+
         // Get adapter from registry
         let adapter = WALLET_REGISTRY
             .get(&request.wallet_type)
@@ -98,6 +150,7 @@ impl WalletManager {
         // Generate challenge
         let challenge_id = Uuid::new_v4().to_string();
         let context = ChallengeContext {
+            domain: domain.clone(),
             wallet_address: request.wallet_address.clone(),
             challenge_id: challenge_id.clone(),
             chain: chain.clone(),
@@ -116,24 +169,31 @@ impl WalletManager {
                     wallet_address: request.wallet_address.clone(),
                     wallet_type: request.wallet_type.clone(),
                     chain,
+                    domain: domain.clone(),
                 },
             );
         }
 
-        println!(
-            "Created {} wallet challenge ({}) for {}: {}",
-            request.wallet_type, context.chain, request.wallet_address, challenge_id
+        info!(
+            "Created {} wallet challenge ({}) for {} on {}: {}",
+            request.wallet_type, context.chain, request.wallet_address, domain, challenge_id
         );
 
         Ok(WalletChallengeResponse {
             challenge_id,
             message,
             wallet_address: request.wallet_address,
+            domain,
         })
     }
 
-    /// Verify wallet signature and create session
-    pub fn verify_wallet_signature(&self, request: WalletVerifyRequest) -> WalletVerifyResponse {
+    /// Verify wallet signature and create session. The verifying origin must match the
+    /// domain the challenge was issued to.
+    pub fn verify_wallet_signature(
+        &self,
+        request: WalletVerifyRequest,
+        origin: &str,
+    ) -> WalletVerifyResponse {
         // Retrieve and remove challenge
         let challenge_data = {
             let mut challenges = self.challenge_storage.lock().unwrap();
@@ -166,6 +226,23 @@ impl WalletManager {
             };
         }
 
+        // A signature obtained on another site must not authenticate here
+        let bound = self.bind_domain(origin);
+        if bound.as_deref() != Ok(data.domain.as_str()) {
+            warn!(
+                "Rejected wallet verification for {}: challenge bound to {}, presented from {}",
+                request.wallet_address, data.domain, origin
+            );
+            return WalletVerifyResponse {
+                success: false,
+                session_token: None,
+                message: "Challenge domain mismatch".to_string(),
+                user_id: None,
+                registration_error: None,
+                network: None,
+            };
+        }
+
         // Get adapter from registry
         let adapter = match WALLET_REGISTRY.get(&data.wallet_type) {
             Some(a) => a,
@@ -207,7 +284,7 @@ impl WalletManager {
                     sessions.insert(session_token.clone(), session);
                 }
 
-                println!(
+                info!(
                     "{} wallet ({}) authenticated successfully for {}, session: {}",
                     data.wallet_type, data.chain, request.wallet_address, session_token
                 );
@@ -283,20 +360,163 @@ impl WalletManager {
     }
 }
 
+/// Reduces an `Origin`/`Host` value to lowercase `host[:port]`.
+pub fn normalize_domain(origin: &str) -> Option<String> {
+    let origin = origin.trim();
+    let without_scheme = origin
+        .split_once("://")
+        .map(|(_, rest)| rest)
+        .unwrap_or(origin);
+    let host = without_scheme
+        .split(['/', '?', '#'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+
+    (!host.is_empty() && host != "null").then_some(host)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const ORIGIN: &str = "https://nullblock.ai";
+    const EVM_ADDRESS: &str = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e";
+
+    fn manager() -> WalletManager {
+        WalletManager::new().with_allowed_domains([ORIGIN])
+    }
+
+    fn evm_challenge(manager: &WalletManager, origin: &str) -> WalletChallengeResponse {
+        manager
+            .create_wallet_challenge(
+                WalletChallengeRequest {
+                    wallet_address: EVM_ADDRESS.to_string(),
+                    wallet_type: "metamask".to_string(),
+                    chain: None,
+                },
+                origin,
+            )
+            .unwrap()
+    }
+
+    fn verify(
+        manager: &WalletManager,
+        challenge: &WalletChallengeResponse,
+        origin: &str,
+    ) -> WalletVerifyResponse {
+        manager.verify_wallet_signature(
+            WalletVerifyRequest {
+                challenge_id: challenge.challenge_id.clone(),
+                signature: format!("0x{}", "ab".repeat(65)),
+                wallet_address: EVM_ADDRESS.to_string(),
+            },
+            origin,
+        )
+    }
+
     #[test]
-    fn test_create_challenge_evm() {
+    fn test_signature_verifies_for_bound_domain() {
+        let manager = manager();
+        let challenge = evm_challenge(&manager, ORIGIN);
+        assert_eq!(challenge.domain, "nullblock.ai");
+        assert!(challenge.message.contains("Domain: nullblock.ai"));
+
+        let response = verify(&manager, &challenge, "https://NullBlock.ai/");
+        assert!(response.success, "{}", response.message);
+        assert!(response.session_token.is_some());
+    }
+
+    #[test]
+    fn test_signature_from_different_domain_is_rejected() {
+        let manager = manager();
+        let challenge = evm_challenge(&manager, ORIGIN);
+
+        let response = verify(&manager, &challenge, "https://nullblock.ai.evil.example");
+        assert!(!response.success);
+        assert_eq!(response.message, "Challenge domain mismatch");
+        assert!(response.session_token.is_none());
+
+        // The challenge is consumed, so it can't be retried from the right origin either
+        let retry = verify(&manager, &challenge, ORIGIN);
+        assert!(!retry.success);
+    }
+
+    #[test]
+    fn test_challenges_refused_without_configured_domain() {
         let manager = WalletManager::new();
+        let error = manager
+            .create_wallet_challenge(
+                WalletChallengeRequest {
+                    wallet_address: EVM_ADDRESS.to_string(),
+                    wallet_type: "metamask".to_string(),
+                    chain: None,
+                },
+                ORIGIN,
+            )
+            .unwrap_err();
+        assert!(error.contains("WALLET_AUTH_DOMAINS"));
+    }
+
+    #[test]
+    fn test_challenge_from_unlisted_origin_is_rejected() {
+        let manager = manager();
+        let phished = manager.create_wallet_challenge(
+            WalletChallengeRequest {
+                wallet_address: EVM_ADDRESS.to_string(),
+                wallet_type: "metamask".to_string(),
+                chain: None,
+            },
+            "https://phish.example",
+        );
+        assert!(phished.unwrap_err().contains("not allowed"));
+
+        // A challenge issued to the real domain can't be verified from an unlisted one
+        let challenge = evm_challenge(&manager, ORIGIN);
+        let response = verify(&manager, &challenge, "https://phish.example");
+        assert!(!response.success);
+        assert_eq!(response.message, "Challenge domain mismatch");
+    }
+
+    #[test]
+    fn test_challenge_requires_allowed_origin() {
+        let manager = WalletManager::new().with_allowed_domains(["https://nullblock.ai", ""]);
+        assert!(manager
+            .create_wallet_challenge(
+                WalletChallengeRequest {
+                    wallet_address: EVM_ADDRESS.to_string(),
+                    wallet_type: "metamask".to_string(),
+                    chain: None,
+                },
+                "https://phish.example",
+            )
+            .unwrap_err()
+            .contains("not allowed"));
+        assert!(manager
+            .create_wallet_challenge(
+                WalletChallengeRequest {
+                    wallet_address: EVM_ADDRESS.to_string(),
+                    wallet_type: "metamask".to_string(),
+                    chain: None,
+                },
+                "",
+            )
+            .is_err());
+
+        let challenge = evm_challenge(&manager, ORIGIN);
+        assert!(verify(&manager, &challenge, ORIGIN).success);
+    }
+
+    #[test]
+    fn test_create_challenge_evm() {
+        let manager = manager();
         let request = WalletChallengeRequest {
             wallet_address: "0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string(),
             wallet_type: "metamask".to_string(),
             chain: None,
         };
 
-        let result = manager.create_wallet_challenge(request);
+        let result = manager.create_wallet_challenge(request, ORIGIN);
         assert!(result.is_ok());
 
         let response = result.unwrap();
@@ -306,14 +526,14 @@ mod tests {
 
     #[test]
     fn test_create_challenge_solana() {
-        let manager = WalletManager::new();
+        let manager = manager();
         let request = WalletChallengeRequest {
             wallet_address: "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM6".to_string(),
             wallet_type: "phantom".to_string(),
             chain: None,
         };
 
-        let result = manager.create_wallet_challenge(request);
+        let result = manager.create_wallet_challenge(request, ORIGIN);
         assert!(result.is_ok());
 
         let response = result.unwrap();
@@ -323,14 +543,14 @@ mod tests {
 
     #[test]
     fn test_create_challenge_bitget_evm() {
-        let manager = WalletManager::new();
+        let manager = manager();
         let request = WalletChallengeRequest {
             wallet_address: "0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string(),
             wallet_type: "bitget".to_string(),
             chain: Some("evm".to_string()),
         };
 
-        let result = manager.create_wallet_challenge(request);
+        let result = manager.create_wallet_challenge(request, ORIGIN);
         assert!(result.is_ok());
 
         let response = result.unwrap();
@@ -340,14 +560,14 @@ mod tests {
 
     #[test]
     fn test_create_challenge_bitget_solana() {
-        let manager = WalletManager::new();
+        let manager = manager();
         let request = WalletChallengeRequest {
             wallet_address: "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM6".to_string(),
             wallet_type: "bitget".to_string(),
             chain: Some("solana".to_string()),
         };
 
-        let result = manager.create_wallet_challenge(request);
+        let result = manager.create_wallet_challenge(request, ORIGIN);
         assert!(result.is_ok());
 
         let response = result.unwrap();
@@ -357,14 +577,14 @@ mod tests {
 
     #[test]
     fn test_unsupported_wallet() {
-        let manager = WalletManager::new();
+        let manager = manager();
         let request = WalletChallengeRequest {
             wallet_address: "0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string(),
             wallet_type: "unknown_wallet".to_string(),
             chain: None,
         };
 
-        let result = manager.create_wallet_challenge(request);
+        let result = manager.create_wallet_challenge(request, ORIGIN);
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("Unsupported"));
     }
@@ -0,0 +1,175 @@
+// Request body size limits enforced before a body is buffered in memory
+
+use axum::{
+    body::{Body, Bytes},
+    http::{header, HeaderMap, StatusCode},
+};
+use http_body_util::LengthLimitError;
+
+pub const DEFAULT_MAX_REQUEST_BODY_BYTES: usize = 1024 * 1024;
+
+/// Routes that legitimately carry larger payloads (LLM prompts with history or
+/// inline images). Matched by path prefix; the longest matching prefix wins.
+const DEFAULT_ROUTE_LIMITS: &[(&str, usize)] = &[
+    ("/api/v1/chat/completions", 10 * 1024 * 1024),
+    ("/api/agents/", 4 * 1024 * 1024),
+];
+
+#[derive(Debug, Clone)]
+pub struct BodyLimits {
+    default_limit: usize,
+    route_limits: Vec<(String, usize)>,
+}
+
+impl Default for BodyLimits {
+    fn default() -> Self {
+        DEFAULT_ROUTE_LIMITS.iter().fold(
+            Self::new(DEFAULT_MAX_REQUEST_BODY_BYTES),
+            |limits, (prefix, limit)| limits.with_route_limit(prefix, *limit),
+        )
+    }
+}
+
+impl BodyLimits {
+    pub fn new(default_limit: usize) -> Self {
+        Self {
+            default_limit,
+            route_limits: Vec::new(),
+        }
+    }
+
+    /// Reads `EREBUS_MAX_REQUEST_BODY_BYTES` for the default limit and
+    /// `EREBUS_ROUTE_BODY_LIMITS` (`/prefix=bytes,...`) for per-route overrides.
+    pub fn from_env() -> Self {
+        let mut limits = Self::default();
+
+        if let Some(default_limit) = std::env::var("EREBUS_MAX_REQUEST_BODY_BYTES")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+        {
+            limits.default_limit = default_limit;
+        }
+
+        if let Ok(overrides) = std::env::var("EREBUS_ROUTE_BODY_LIMITS") {
+            for entry in overrides.split(',') {
+                match entry.trim().split_once('=') {
+                    Some((prefix, limit)) if prefix.starts_with('/') => {
+                        match limit.trim().parse::<usize>() {
+                            Ok(limit) => limits = limits.with_route_limit(prefix.trim(), limit),
+                            Err(_) => tracing::warn!("⚠️ Invalid route body limit: {}", entry),
+                        }
+                    }
+                    _ if entry.trim().is_empty() => {}
+                    _ => tracing::warn!("⚠️ Invalid route body limit: {}", entry),
+                }
+            }
+        }
+
+        limits
+    }
+
+    pub fn with_route_limit(mut self, prefix: &str, limit: usize) -> Self {
+        self.route_limits.retain(|(existing, _)| existing != prefix);
+        self.route_limits.push((prefix.to_string(), limit));
+        self
+    }
+
+    pub fn limit_for(&self, path: &str) -> usize {
+        self.route_limits
+            .iter()
+            .filter(|(prefix, _)| path.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, limit)| *limit)
+            .unwrap_or(self.default_limit)
+    }
+}
+
+/// Buffers a request body up to `limit` bytes. Bodies that declare a larger
+/// Content-Length are rejected without reading; streamed bodies stop being read as
+/// soon as they pass the limit.
+pub async fn read_body(body: Body, headers: &HeaderMap, limit: usize) -> Result<Bytes, StatusCode> {
+    let declared_length = headers
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<usize>().ok());
+
+    if declared_length.is_some_and(|length| length > limit) {
+        return Err(StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    axum::body::to_bytes(body, limit).await.map_err(|e| {
+        if is_length_limit_error(&e) {
+            StatusCode::PAYLOAD_TOO_LARGE
+        } else {
+            StatusCode::BAD_REQUEST
+        }
+    })
+}
+
+/// `to_bytes` reports the cap as an `http_body_util::LengthLimitError` somewhere in
+/// the error chain; anything else is a broken or malformed body.
+fn is_length_limit_error(error: &axum::Error) -> bool {
+    let mut source: Option<&(dyn std::error::Error + 'static)> = Some(error);
+    while let Some(err) = source {
+        if err.is::<LengthLimitError>() {
+            return true;
+        }
+        source = err.source();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_longest_prefix_wins() {
+        let limits = BodyLimits::new(100)
+            .with_route_limit("/api/agents/", 1_000)
+            .with_route_limit("/api/agents/hecate/chat", 5_000);
+
+        assert_eq!(limits.limit_for("/api/wallets/challenge"), 100);
+        assert_eq!(limits.limit_for("/api/agents/siren/chat"), 1_000);
+        assert_eq!(limits.limit_for("/api/agents/hecate/chat"), 5_000);
+    }
+
+    #[tokio::test]
+    async fn test_declared_length_over_limit_is_rejected() {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONTENT_LENGTH, "2048".parse().unwrap());
+
+        let result = read_body(Body::from(vec![b'a'; 2048]), &headers, 1024).await;
+        assert_eq!(result.unwrap_err(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn test_streamed_body_over_limit_is_rejected() {
+        let chunks: Vec<Result<Bytes, std::io::Error>> =
+            (0..8).map(|_| Ok(Bytes::from(vec![b'a'; 256]))).collect();
+        let body = Body::from_stream(futures::stream::iter(chunks));
+
+        let result = read_body(body, &HeaderMap::new(), 1024).await;
+        assert_eq!(result.unwrap_err(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn test_broken_stream_is_a_bad_request_not_too_large() {
+        let chunks: Vec<Result<Bytes, std::io::Error>> = vec![
+            Ok(Bytes::from_static(b"{\"partial\":")),
+            Err(std::io::Error::other("length limit exceeded by upstream")),
+        ];
+        let body = Body::from_stream(futures::stream::iter(chunks));
+
+        let result = read_body(body, &HeaderMap::new(), 1024).await;
+        assert_eq!(result.unwrap_err(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_body_within_limit_is_returned() {
+        let bytes = read_body(Body::from("{\"ok\":true}"), &HeaderMap::new(), 1024)
+            .await
+            .unwrap();
+        assert_eq!(&bytes[..], b"{\"ok\":true}");
+    }
+}
@@ -1 +1,3 @@
+pub mod api_error;
+pub mod body_limit;
 pub mod log_sanitizer;
@@ -70,6 +70,8 @@ import type {
   PriorityFees,
   SenderStats,
   TokenMetadata,
+  OwnedAsset,
+  OwnedAssetFilter,
   HeliusConfig,
   IngestUrlResponse,
   ExtractedStrategy,
@@ -2082,10 +2084,17 @@ class ArbFarmService {
     owner: string,
     page?: number,
     limit?: number,
-  ): Promise<ArbFarmServiceResponse<{ assets: TokenMetadata[]; total: number }>> {
+    filter?: OwnedAssetFilter,
+  ): Promise<ArbFarmServiceResponse<{ assets: OwnedAsset[]; total: number }>> {
     const params = new URLSearchParams({ owner });
     if (page) params.append('page', page.toString());
     if (limit) params.append('limit', limit.toString());
+    if (filter?.kind) params.append('kind', filter.kind);
+    if (filter?.collection) params.append('collection', filter.collection);
+    if (filter?.min_value_usd !== undefined) {
+      params.append('min_value_usd', filter.min_value_usd.toString());
+    }
+    if (filter?.sort) params.append('sort', filter.sort);
     return this.makeRequest(`/helius/das/assets?${params.toString()}`);
   }
 
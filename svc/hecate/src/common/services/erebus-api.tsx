@@ -73,6 +73,7 @@ interface WalletChallengeResponse {
   challenge_id: string;
   message: string;
   wallet_address: string;
+  domain: string;
 }
 
 interface WalletVerifyRequest {
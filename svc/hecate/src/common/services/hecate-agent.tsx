@@ -104,7 +104,9 @@ class HecateAgentService {
         try {
           const errorData = await response.json();
 
-          if (errorData.message) {
+          if (errorData.error?.message) {
+            errorMessage = errorData.error.message;
+          } else if (errorData.message) {
             errorMessage = errorData.message;
           } else if (errorData.error) {
             errorMessage = errorData.error;
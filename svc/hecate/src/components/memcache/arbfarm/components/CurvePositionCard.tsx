@@ -34,6 +34,8 @@ const CurvePositionCard: React.FC<CurvePositionCardProps> = ({
         return { text: 'Open', className: styles.statusOpen };
       case 'pending_exit':
         return { text: 'Exiting', className: styles.statusPending };
+      case 'settling':
+        return { text: 'Settling', className: styles.statusPending };
       case 'closed':
         return { text: 'Closed', className: styles.statusClosed };
       default:
@@ -79,6 +79,7 @@ const DashboardPositionCard: React.FC<DashboardPositionCardProps> = ({
       case 'open':
         return { symbol: '●', className: styles.logStatusOpen };
       case 'pending_exit':
+      case 'settling':
         return { symbol: '◐', className: styles.logStatusPending };
       case 'closed':
         return { symbol: '○', className: styles.logStatusClosed };
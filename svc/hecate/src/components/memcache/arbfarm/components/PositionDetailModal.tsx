@@ -135,6 +135,8 @@ const PositionDetailModal: React.FC<PositionDetailModalProps> = ({
         return { text: 'Open', className: styles.statusOpen };
       case 'pending_exit':
         return { text: 'Exiting', className: styles.statusPending };
+      case 'settling':
+        return { text: 'Settling', className: styles.statusPending };
       case 'closed':
         return { text: 'Closed', className: styles.statusClosed };
       default:
@@ -229,6 +229,14 @@ const RecommendationsTab: React.FC = () => {
                         <span className={`${styles.recConfidenceTag} ${styles[confidenceLevel]}`}>
                           {(rec.confidence * 100).toFixed(0)}% confidence
                         </span>
+                        {rec.below_confidence_floor && (
+                          <span
+                            className={styles.recInformationalTag}
+                            title="Below the consensus confidence floor - never auto-applied"
+                          >
+                            Informational
+                          </span>
+                        )}
                       </div>
                     </div>
                   </div>
@@ -393,6 +393,17 @@ export interface BlockedEntity {
   reported_by: string;
   is_active: boolean;
   created_at: string;
+  provenance?: BlocklistProvenance;
+}
+
+export type BlocklistProvenance =
+  | { kind: 'local' }
+  | { kind: 'imported'; feed_source: string; feed_signer: string; imported_at: string };
+
+export interface SignedBlocklistFeed {
+  signer: string;
+  signature: string;
+  payload: string;
 }
 
 // ============================================================================
@@ -551,7 +562,7 @@ export interface CurveQuote {
 // Curve Position & Strategy Types
 // ============================================================================
 
-export type CurvePositionStatus = 'open' | 'pending_exit' | 'closed';
+export type CurvePositionStatus = 'open' | 'pending_exit' | 'settling' | 'closed';
 
 export interface CurveExitConfig {
   sell_on_graduation: boolean;
@@ -1280,6 +1291,29 @@ export interface TokenMetadata {
   image_uri?: string;
 }
 
+export type AssetKind = 'any' | 'fungible' | 'nft';
+
+export type AssetSort = 'value' | 'recent_activity';
+
+export interface OwnedAssetFilter {
+  kind?: AssetKind;
+  collection?: string;
+  min_value_usd?: number;
+  sort?: AssetSort;
+}
+
+export interface OwnedAsset {
+  id: string;
+  interface: string;
+  fungible: boolean;
+  name?: string;
+  symbol?: string;
+  collection?: string;
+  balance?: number;
+  price_per_token_usd?: number;
+  value_usd?: number;
+}
+
 export interface TokenCreator {
   address: string;
   verified: boolean;
@@ -1725,6 +1759,32 @@ export interface ExitConfig {
   time_limit_minutes?: number;
 }
 
+export interface ScaleInTranche {
+  dip_percent: number;
+  add_percent: number;
+}
+
+export interface ScaleInConfig {
+  tranches: ScaleInTranche[];
+  max_total_base: number;
+}
+
+export interface ScaleInFill {
+  tranche: number;
+  amount_base: number;
+  token_amount: number;
+  price: number;
+  filled_at: string;
+  tx_signature?: string | null;
+}
+
+export interface ScaleInPlan {
+  config: ScaleInConfig;
+  reference_price: number;
+  reference_amount_base: number;
+  fills: ScaleInFill[];
+}
+
 export interface OpenPosition {
   id: string;
   edge_id?: string;
@@ -1751,6 +1811,9 @@ export interface OpenPosition {
   remaining_amount_base?: number;
   remaining_token_amount?: number;
   auto_exit_enabled?: boolean;
+  notes?: string;
+  tags?: string[];
+  scale_in?: ScaleInPlan | null;
   momentum?: {
     momentum_score: number;
     velocity: number;
@@ -1875,6 +1938,8 @@ export interface PositionHistoryItem {
   hold_duration_minutes: number;
   opened_at: string;
   closed_at: string;
+  notes?: string;
+  tags?: string[];
 }
 
 export interface PositionHistoryResponse {
@@ -3,6 +3,7 @@ export interface ConsensusConfig {
   models: ConsensusModelConfig[];
   min_consensus_threshold: number;
   auto_apply_recommendations: boolean;
+  min_action_confidence: number;
   review_interval_hours: number;
   max_tokens_per_request: number;
   timeout_ms: number;
@@ -28,6 +29,7 @@ export interface UpdateConsensusConfigRequest {
   models?: ConsensusModelConfig[];
   min_consensus_threshold?: number;
   auto_apply_recommendations?: boolean;
+  min_action_confidence?: number;
   review_interval_hours?: number;
 }
 
@@ -72,9 +74,22 @@ export interface ModelInfo {
   provider: string;
 }
 
+export interface ModelHealthStatus {
+  model: string;
+  excluded: boolean;
+  consecutive_failures: number;
+  total_successes: number;
+  total_failures: number;
+  last_error: string | null;
+  last_success_at: string | null;
+  last_failure_at: string | null;
+  excluded_until: string | null;
+}
+
 export interface AvailableModelsResponse {
   models: ModelInfo[];
   default_models: string[];
+  model_health: ModelHealthStatus[];
 }
 
 export type ConversationTopic =
@@ -191,6 +206,7 @@ export interface Recommendation {
   status: RecommendationStatus;
   created_at: string;
   applied_at?: string;
+  below_confidence_floor?: boolean;
 }
 
 export interface RecommendationListResponse {
@@ -0,0 +1,21 @@
+-- =============================================================================
+-- TASK RESULTS (full task output, kept out of the tasks row)
+-- =============================================================================
+-- tasks.action_result only holds a preview so task listings stay small; the
+-- complete (size-capped) output lives here. TEXT values are TOAST-compressed,
+-- so large outputs are stored compactly.
+
+CREATE TABLE IF NOT EXISTS task_results (
+    task_id UUID PRIMARY KEY REFERENCES tasks(id) ON DELETE CASCADE,
+    content TEXT NOT NULL,
+    content_type VARCHAR NOT NULL DEFAULT 'text/plain',
+    original_bytes BIGINT NOT NULL,
+    stored_bytes BIGINT NOT NULL,
+    truncated BOOLEAN NOT NULL DEFAULT false,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE task_results ALTER COLUMN content SET STORAGE EXTENDED;
+
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS action_result_truncated BOOLEAN NOT NULL DEFAULT false;
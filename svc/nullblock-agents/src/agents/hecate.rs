@@ -68,6 +68,7 @@ pub struct HecateAgent {
     personalities: HashMap<String, PersonalityConfig>,
     pub mcp_client: Arc<McpClient>,
     pub engrams_client: Option<Arc<crate::engrams::EngramsClient>>,
+    pub response_cache: Option<Arc<crate::llm::ResponseCache>>,
 }
 
 #[derive(Debug, Clone)]
@@ -203,6 +204,7 @@ TOOL AWARENESS:
             personalities,
             mcp_client: Arc::new(McpClient::new(&erebus_base_url)),
             engrams_client: None,
+            response_cache: None,
         }
     }
 
@@ -210,6 +212,10 @@ TOOL AWARENESS:
         self.engrams_client = Some(client);
     }
 
+    pub fn set_response_cache(&mut self, cache: Arc<crate::llm::ResponseCache>) {
+        self.response_cache = Some(cache);
+    }
+
     async fn load_persona_context(&self, wallet: &str) -> PersonaLoadResult {
         let client = match self.engrams_client.as_ref() {
             Some(c) => c,
@@ -268,6 +274,9 @@ TOOL AWARENESS:
         // Initialize LLM factory
         info!("🧠 Initializing LLM Service Factory...");
         let mut llm_factory = LLMServiceFactory::new();
+        if let Some(cache) = &self.response_cache {
+            llm_factory = llm_factory.with_response_cache(cache.clone());
+        }
         llm_factory.initialize(api_keys).await?;
         let llm_factory_arc = Arc::new(RwLock::new(llm_factory));
         self.llm_factory = Some(llm_factory_arc.clone());
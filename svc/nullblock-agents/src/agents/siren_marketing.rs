@@ -34,6 +34,7 @@ pub struct MarketingAgent {
     pub twitter_access_secret: Option<String>,
     pub content_themes: HashMap<String, ContentTheme>,
     pub posting_schedule: HashMap<String, String>,
+    pub response_cache: Option<Arc<crate::llm::ResponseCache>>,
 }
 
 #[derive(Debug, Clone)]
@@ -177,15 +178,23 @@ impl MarketingAgent {
             twitter_access_secret: None,
             content_themes,
             posting_schedule,
+            response_cache: None,
         }
     }
 
+    pub fn set_response_cache(&mut self, cache: Arc<crate::llm::ResponseCache>) {
+        self.response_cache = Some(cache);
+    }
+
     pub async fn start(&mut self, api_keys: &ApiKeys) -> AppResult<()> {
         info!("🚀 Starting Marketing Agent services...");
 
         // Initialize LLM factory
         info!("🧠 Initializing LLM Service Factory...");
         let mut llm_factory = LLMServiceFactory::new();
+        if let Some(cache) = &self.response_cache {
+            llm_factory = llm_factory.with_response_cache(cache.clone());
+        }
         llm_factory.initialize(api_keys).await?;
         self.llm_factory = Some(Arc::new(RwLock::new(llm_factory)));
         info!("✅ LLM Service Factory ready");
@@ -437,7 +446,12 @@ Always provide engaging, hype-fueled marketing advice with cyberpunk flair. Keep
         })
     }
 
-    pub async fn analyze_project_progress(&mut self) -> AppResult<ProjectAnalysis> {
+    /// Runs the project analysis. A `deterministic` analysis runs at temperature 0 without
+    /// the chat history, so repeats within the cache TTL are served from the response cache.
+    pub async fn analyze_project_progress(
+        &mut self,
+        deterministic: bool,
+    ) -> AppResult<ProjectAnalysis> {
         if !self.running {
             return Err(AppError::AgentNotRunning);
         }
@@ -477,12 +491,18 @@ Always provide engaging, hype-fueled marketing advice with cyberpunk flair. Keep
         Identify 3-5 key marketing opportunities and content ideas based on this analysis.
         "#;
 
+        let (messages, temperature) = if deterministic {
+            (None, 0.0)
+        } else {
+            (Some(self.build_messages_history().await), 0.7)
+        };
+
         let request = LLMRequest {
             prompt: analysis_prompt.to_string(),
             system_prompt: Some(self.build_system_prompt()),
-            messages: Some(self.build_messages_history().await),
+            messages,
             max_tokens: Some(1000),
-            temperature: Some(0.7),
+            temperature: Some(temperature),
             top_p: None,
             stop_sequences: None,
             tools: None,
@@ -873,3 +893,95 @@ pub struct ProjectAnalysis {
     pub technical_highlights: Vec<String>,
     pub target_audiences: Vec<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::llm::{providers::Provider, ResponseCache};
+    use crate::models::{LLMResponse, ModelConfig, ModelProvider};
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    struct CountingProvider {
+        calls: AtomicU32,
+    }
+
+    #[async_trait]
+    impl Provider for CountingProvider {
+        async fn generate(
+            &self,
+            _request: &LLMRequest,
+            config: &ModelConfig,
+        ) -> AppResult<LLMResponse> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(LLMResponse {
+                content: format!("Launch opportunity #{}", call),
+                model_used: config.name.clone(),
+                usage: HashMap::new(),
+                latency_ms: 10.0,
+                cost_estimate: 0.0,
+                finish_reason: "stop".to_string(),
+                confidence_score: 1.0,
+                tool_calls: None,
+                metadata: None,
+                reasoning: None,
+                reasoning_details: None,
+            })
+        }
+
+        fn provider_type(&self) -> ModelProvider {
+            ModelProvider::OpenRouter
+        }
+
+        async fn health_check(&self) -> AppResult<bool> {
+            Ok(true)
+        }
+    }
+
+    fn running_siren(provider: Arc<CountingProvider>) -> MarketingAgent {
+        let cache = Arc::new(ResponseCache::new(Duration::from_secs(60), 10));
+        let factory = LLMServiceFactory::new()
+            .with_response_cache(cache)
+            .with_provider(provider);
+
+        let mut agent = MarketingAgent::new(None);
+        agent.current_model = Some("test/model".to_string());
+        agent.llm_factory = Some(Arc::new(RwLock::new(factory)));
+        agent.running = true;
+        agent
+    }
+
+    #[tokio::test]
+    async fn test_repeated_deterministic_analysis_is_a_cache_hit() {
+        let provider = Arc::new(CountingProvider {
+            calls: AtomicU32::new(0),
+        });
+        let mut agent = running_siren(provider.clone());
+
+        let first = agent.analyze_project_progress(true).await.unwrap();
+        let second = agent.analyze_project_progress(true).await.unwrap();
+
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(first.key_opportunities, vec!["Launch opportunity #1"]);
+        assert_eq!(second.key_opportunities, first.key_opportunities);
+
+        let stats = agent.llm_factory.unwrap().read().await.get_stats().await;
+        assert_eq!(stats["response_cache"]["hits"], 1);
+        assert_eq!(stats["response_cache"]["misses"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_default_analysis_bypasses_cache() {
+        let provider = Arc::new(CountingProvider {
+            calls: AtomicU32::new(0),
+        });
+        let mut agent = running_siren(provider.clone());
+
+        agent.analyze_project_progress(false).await.unwrap();
+        let second = agent.analyze_project_progress(false).await.unwrap();
+
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 2);
+        assert_eq!(second.key_opportunities, vec!["Launch opportunity #2"]);
+    }
+}
@@ -57,6 +57,7 @@ pub struct TaskEntity {
     // Action tracking fields
     pub actioned_at: Option<DateTime<Utc>>,
     pub action_result: Option<String>,
+    pub action_result_truncated: bool,
     pub action_metadata: serde_json::Value,
     pub action_duration: Option<i64>,
 
@@ -125,6 +126,7 @@ impl TaskEntity {
 
             actioned_at: self.actioned_at,
             action_result: self.action_result,
+            action_result_truncated: self.action_result_truncated,
             action_metadata: serde_json::from_value(self.action_metadata)?,
             action_duration: self.action_duration.map(|d| d as u64),
 
@@ -212,6 +214,7 @@ impl TaskEntity {
 
             actioned_at: task.actioned_at,
             action_result: task.action_result.clone(),
+            action_result_truncated: task.action_result_truncated,
             action_metadata: serde_json::to_value(&task.action_metadata)?,
             action_duration: task.action_duration.map(|d| d as i64),
 
@@ -221,6 +224,33 @@ impl TaskEntity {
     }
 }
 
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct TaskResultEntity {
+    pub task_id: Uuid,
+    pub content: String,
+    pub content_type: String,
+    pub original_bytes: i64,
+    pub stored_bytes: i64,
+    pub truncated: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TaskResultEntity {
+    pub fn to_domain_model(self) -> crate::models::TaskResult {
+        crate::models::TaskResult {
+            task_id: self.task_id.to_string(),
+            content: self.content,
+            content_type: self.content_type,
+            original_bytes: self.original_bytes as u64,
+            stored_bytes: self.stored_bytes as u64,
+            truncated: self.truncated,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct AgentEntity {
     pub id: Uuid,
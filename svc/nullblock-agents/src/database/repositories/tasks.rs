@@ -5,16 +5,84 @@ use chrono::Utc;
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use crate::database::models::TaskEntity;
+use crate::database::models::{TaskEntity, TaskResultEntity};
 use crate::models::{CreateTaskRequest, UpdateTaskRequest};
 
+const DEFAULT_TASK_RESULT_MAX_BYTES: usize = 1024 * 1024;
+const DEFAULT_TASK_RESULT_PREVIEW_BYTES: usize = 4 * 1024;
+const TRUNCATION_MARKER: &str = "\n\n[... truncated]";
+
+/// Size caps for persisted task output. The full result (up to `max_bytes`) goes
+/// to `task_results`; `tasks.action_result` only keeps a `preview_bytes` preview.
+#[derive(Debug, Clone, Copy)]
+pub struct TaskResultLimits {
+    pub max_bytes: usize,
+    pub preview_bytes: usize,
+}
+
+impl Default for TaskResultLimits {
+    fn default() -> Self {
+        Self {
+            max_bytes: DEFAULT_TASK_RESULT_MAX_BYTES,
+            preview_bytes: DEFAULT_TASK_RESULT_PREVIEW_BYTES,
+        }
+    }
+}
+
+impl TaskResultLimits {
+    pub fn from_env() -> Self {
+        let max_bytes = std::env::var("TASK_RESULT_MAX_BYTES")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(DEFAULT_TASK_RESULT_MAX_BYTES);
+        let preview_bytes = std::env::var("TASK_RESULT_PREVIEW_BYTES")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(DEFAULT_TASK_RESULT_PREVIEW_BYTES);
+
+        Self {
+            max_bytes,
+            preview_bytes: preview_bytes.min(max_bytes),
+        }
+    }
+}
+
+/// Cuts `content` to at most `max_bytes` (marker included) on a char boundary.
+/// Returns the content unchanged when it already fits.
+pub fn truncate_result(content: &str, max_bytes: usize) -> (String, bool) {
+    if content.len() <= max_bytes {
+        return (content.to_string(), false);
+    }
+
+    let mut end = max_bytes.saturating_sub(TRUNCATION_MARKER.len());
+    while !content.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    let mut truncated = String::with_capacity(end + TRUNCATION_MARKER.len());
+    truncated.push_str(&content[..end]);
+    if max_bytes >= TRUNCATION_MARKER.len() {
+        truncated.push_str(TRUNCATION_MARKER);
+    }
+    (truncated, true)
+}
+
 pub struct TaskRepository {
     pool: PgPool,
+    result_limits: TaskResultLimits,
 }
 
 impl TaskRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            result_limits: TaskResultLimits::from_env(),
+        }
+    }
+
+    pub fn with_result_limits(mut self, result_limits: TaskResultLimits) -> Self {
+        self.result_limits = result_limits;
+        self
     }
 
     pub async fn create(
@@ -330,6 +398,8 @@ impl TaskRepository {
         Ok(task)
     }
 
+    /// Persists a task's output: the (size-capped) full result in `task_results`
+    /// and a short preview on the task row, in one transaction.
     pub async fn update_action_result(
         &self,
         task_id: &str,
@@ -339,26 +409,74 @@ impl TaskRepository {
         let uuid = Uuid::parse_str(task_id)?;
         let now = Utc::now();
 
+        let (stored, stored_truncated) =
+            truncate_result(action_result, self.result_limits.max_bytes);
+        let (preview, preview_truncated) =
+            truncate_result(&stored, self.result_limits.preview_bytes);
+
+        let mut tx = self.pool.begin().await?;
+
         let task = sqlx::query_as::<_, TaskEntity>(
             r#"
             UPDATE tasks SET
                 action_result = $2,
-                action_duration = $3,
-                updated_at = $4
+                action_result_truncated = $3,
+                action_duration = $4,
+                updated_at = $5
             WHERE id = $1
             RETURNING *
             "#,
         )
         .bind(uuid)
-        .bind(action_result)
+        .bind(&preview)
+        .bind(stored_truncated || preview_truncated)
         .bind(action_duration.map(|d| d as i64))
         .bind(now)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *tx)
         .await?;
 
+        if task.is_some() {
+            sqlx::query(
+                r#"
+                INSERT INTO task_results (
+                    task_id, content, original_bytes, stored_bytes, truncated,
+                    created_at, updated_at
+                ) VALUES ($1, $2, $3, $4, $5, $6, $6)
+                ON CONFLICT (task_id) DO UPDATE SET
+                    content = EXCLUDED.content,
+                    original_bytes = EXCLUDED.original_bytes,
+                    stored_bytes = EXCLUDED.stored_bytes,
+                    truncated = EXCLUDED.truncated,
+                    updated_at = EXCLUDED.updated_at
+                "#,
+            )
+            .bind(uuid)
+            .bind(&stored)
+            .bind(action_result.len() as i64)
+            .bind(stored.len() as i64)
+            .bind(stored_truncated)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
         Ok(task)
     }
 
+    pub async fn get_result(&self, task_id: &str) -> Result<Option<TaskResultEntity>> {
+        let uuid = Uuid::parse_str(task_id)?;
+
+        let result =
+            sqlx::query_as::<_, TaskResultEntity>("SELECT * FROM task_results WHERE task_id = $1")
+                .bind(uuid)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(result)
+    }
+
     pub async fn get_unactioned_tasks(
         &self,
         agent_id: Option<Uuid>,
@@ -509,3 +627,88 @@ impl TaskRepository {
         Ok(task)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::Database;
+    use crate::models::TaskType;
+
+    #[test]
+    fn test_truncate_result_respects_limit_and_char_boundaries() {
+        let (short, truncated) = truncate_result("done", 64);
+        assert_eq!(short, "done");
+        assert!(!truncated);
+
+        let content = "é".repeat(100);
+        let (cut, truncated) = truncate_result(&content, 64);
+        assert!(truncated);
+        assert!(cut.len() <= 64);
+        assert!(cut.ends_with(TRUNCATION_MARKER));
+        assert!(cut
+            .trim_end_matches(TRUNCATION_MARKER)
+            .chars()
+            .all(|c| c == 'é'));
+    }
+
+    #[tokio::test]
+    #[ignore = "needs TEST_DATABASE_URL pointing at a migrated agents database"]
+    async fn test_completed_task_result_survives_restart() {
+        let database_url =
+            std::env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL must be set");
+        let limits = TaskResultLimits {
+            max_bytes: 8 * 1024,
+            preview_bytes: 256,
+        };
+        let output = "line of task output\n".repeat(1_000);
+
+        let task_id = {
+            let db = Database::new(&database_url).await.unwrap();
+            let repo = TaskRepository::new(db.pool().clone()).with_result_limits(limits);
+            let request = CreateTaskRequest {
+                name: "result persistence test".to_string(),
+                description: "produce a large output".to_string(),
+                task_type: TaskType::System,
+                category: None,
+                priority: None,
+                parameters: None,
+                dependencies: None,
+                auto_start: Some(true),
+                user_approval_required: None,
+                assigned_agent_id: None,
+                sub_tasks: None,
+            };
+            let task = repo.create(&request, None, None, None).await.unwrap();
+            let task_id = task.id.to_string();
+
+            repo.update_action_result(&task_id, &output, Some(42))
+                .await
+                .unwrap()
+                .unwrap();
+            repo.update_status_with_message(&task_id, crate::models::TaskState::Completed, None)
+                .await
+                .unwrap();
+
+            db.pool().close().await;
+            task_id
+        };
+
+        let db = Database::new(&database_url).await.unwrap();
+        let repo = TaskRepository::new(db.pool().clone());
+
+        let task = repo.get_by_id(&task_id).await.unwrap().unwrap();
+        assert_eq!(task.status, "completed");
+        assert!(task.action_result_truncated);
+        assert!(task.action_result.unwrap().len() <= limits.preview_bytes);
+
+        let result = repo.get_result(&task_id).await.unwrap().unwrap();
+        assert!(result.truncated);
+        assert_eq!(result.original_bytes as usize, output.len());
+        assert_eq!(result.stored_bytes as usize, result.content.len());
+        assert!(result.content.len() <= limits.max_bytes);
+        assert!(output.starts_with(result.content.trim_end_matches(TRUNCATION_MARKER)));
+
+        repo.delete(&task_id).await.unwrap();
+        assert!(repo.get_result(&task_id).await.unwrap().is_none());
+    }
+}
@@ -20,6 +20,9 @@ pub enum AppError {
     #[error("Model not available: {0}")]
     ModelNotAvailable(String),
 
+    #[error("Model over budget: {0}")]
+    ModelOverBudget(String),
+
     #[error("Not found: {0}")]
     NotFound(String),
 
@@ -97,6 +100,8 @@ impl AppError {
 
             AppError::Forbidden(_) => StatusCode::FORBIDDEN,
 
+            AppError::ModelOverBudget(_) => StatusCode::PAYMENT_REQUIRED,
+
             AppError::TaskAlreadyActioned(_) => StatusCode::CONFLICT,
 
             AppError::AuthError(_) => StatusCode::UNAUTHORIZED,
@@ -127,6 +132,7 @@ impl AppError {
             AppError::AgentNotInitialized => "agent_not_initialized",
             AppError::AgentNotRunning => "agent_not_running",
             AppError::ModelNotAvailable(_) => "model_not_available",
+            AppError::ModelOverBudget(_) => "model_over_budget",
             AppError::NotFound(_) => "not_found",
             AppError::Forbidden(_) => "forbidden",
             AppError::LLMRequestFailed(_) => "llm_request_failed",
@@ -157,6 +163,9 @@ impl AppError {
             AppError::ModelNotAvailable(model) => {
                 format!("🧠 The model '{}' is not currently available. Please select a different model or check your API keys.", model)
             }
+            AppError::ModelOverBudget(model) => {
+                format!("💳 The model '{}' is over budget. Add credits or select a cheaper model.", model)
+            }
             AppError::LLMRequestFailed(details) => {
                 let details_lower = details.to_lowercase();
 
@@ -1,4 +1,8 @@
-use axum::{extract::State, http::StatusCode, response::Json};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::Json,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use tracing::{error, info};
@@ -14,6 +18,13 @@ pub struct GenerateContentRequest {
     pub context: Option<HashMap<String, serde_json::Value>>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ProjectAnalysisQuery {
+    /// Run at temperature 0 so repeated analyses are served from the response cache
+    #[serde(default)]
+    pub deterministic: bool,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateTwitterPostRequest {
     pub content: String,
@@ -114,12 +125,16 @@ pub async fn create_twitter_post(
 
 pub async fn analyze_project_progress(
     State(state): State<AppState>,
+    Query(params): Query<ProjectAnalysisQuery>,
 ) -> Result<Json<ProjectAnalysisResponse>, (StatusCode, Json<ErrorResponse>)> {
     info!("🔍 Analyzing project progress for marketing opportunities");
 
     let mut marketing_agent = state.marketing_agent.write().await;
 
-    match marketing_agent.analyze_project_progress().await {
+    match marketing_agent
+        .analyze_project_progress(params.deterministic)
+        .await
+    {
         Ok(analysis) => {
             info!("✅ Project analysis completed");
             Ok(Json(ProjectAnalysisResponse {
@@ -13,7 +13,10 @@ use crate::{
     database::repositories::user_references::UserReferenceRepository,
     database::repositories::{AgentRepository, TaskRepository},
     kafka::TaskLifecycleEvent,
-    models::{CreateTaskRequest, TaskListResponse, TaskResponse, TaskState, UpdateTaskRequest},
+    models::{
+        CreateTaskRequest, TaskListResponse, TaskResponse, TaskResultResponse, TaskState,
+        UpdateTaskRequest,
+    },
     server::AppState,
 };
 
@@ -414,8 +417,22 @@ pub async fn get_task(
     // Fetch task from database
     match task_repo.get_by_id(&task_id).await {
         Ok(Some(task_entity)) => match task_entity.to_domain_model() {
-            Ok(task) => {
+            Ok(mut task) => {
                 info!("✅ Found task: {}", task.name);
+
+                // The task row only carries a preview; return the full stored output
+                match task_repo.get_result(&task_id).await {
+                    Ok(Some(result)) => {
+                        task.action_result = Some(result.content);
+                        task.action_result_truncated = result.truncated;
+                    }
+                    Ok(None) => {}
+                    Err(e) => warn!(
+                        "⚠️ Failed to load stored result for task {}: {}",
+                        task_id, e
+                    ),
+                }
+
                 Ok(Json(TaskResponse {
                     success: true,
                     data: Some(task),
@@ -454,6 +471,61 @@ pub async fn get_task(
     }
 }
 
+pub async fn get_task_result(
+    State(state): State<AppState>,
+    Path(task_id): Path<String>,
+) -> Result<Json<TaskResultResponse>, StatusCode> {
+    info!("📄 Fetching result for task: {}", task_id);
+
+    let database = match &state.database {
+        Some(db) => db,
+        None => {
+            error!("❌ Database connection not available");
+            return Ok(Json(TaskResultResponse {
+                success: false,
+                data: None,
+                error: Some("Database connection not available".to_string()),
+                timestamp: Utc::now(),
+            }));
+        }
+    };
+
+    let task_repo = TaskRepository::new(database.pool().clone());
+
+    match task_repo.get_result(&task_id).await {
+        Ok(Some(result)) => {
+            info!(
+                "✅ Found result for task {} ({} bytes, truncated: {})",
+                task_id, result.stored_bytes, result.truncated
+            );
+            Ok(Json(TaskResultResponse {
+                success: true,
+                data: Some(result.to_domain_model()),
+                error: None,
+                timestamp: Utc::now(),
+            }))
+        }
+        Ok(None) => {
+            warn!("⚠️ No result stored for task: {}", task_id);
+            Ok(Json(TaskResultResponse {
+                success: false,
+                data: None,
+                error: Some(format!("No result stored for task: {}", task_id)),
+                timestamp: Utc::now(),
+            }))
+        }
+        Err(e) => {
+            error!("❌ Failed to fetch task result: {}", e);
+            Ok(Json(TaskResultResponse {
+                success: false,
+                data: None,
+                error: Some("Failed to fetch task result".to_string()),
+                timestamp: Utc::now(),
+            }))
+        }
+    }
+}
+
 pub async fn update_task(
     State(state): State<AppState>,
     Path(task_id): Path<String>,
@@ -0,0 +1,320 @@
+use crate::{
+    error::AppResult,
+    models::{LLMRequest, LLMResponse, ModelProvider},
+};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+use super::degradation::is_degraded;
+
+pub const CACHE_HIT_METADATA_KEY: &str = "cache_hit";
+
+struct CachedResponse {
+    response: LLMResponse,
+    stored_at: Instant,
+}
+
+/// Caches responses to deterministic requests (temperature 0, no tools), keyed by
+/// provider, model, every other sampling option and the whitespace-normalized prompt.
+pub struct ResponseCache {
+    ttl: Duration,
+    max_entries: usize,
+    entries: RwLock<HashMap<String, CachedResponse>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl ResponseCache {
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            ttl,
+            max_entries: max_entries.max(1),
+            entries: RwLock::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn is_cacheable(request: &LLMRequest) -> bool {
+        request.temperature.is_some_and(|t| t <= 0.0) && request.tools.is_none()
+    }
+
+    pub fn cache_key(provider: &ModelProvider, model: &str, request: &LLMRequest) -> String {
+        let messages = request
+            .messages
+            .as_ref()
+            .map(|m| normalize(&serde_json::Value::Array(m.clone()).to_string()))
+            .unwrap_or_default();
+
+        let reasoning = request
+            .reasoning
+            .as_ref()
+            .map(|r| serde_json::to_string(r).unwrap_or_default())
+            .unwrap_or_default();
+
+        format!(
+            "{}|{}|{:?}|{:?}|{:?}|{:?}|{:?}|{}|{}|{}|{}|{}",
+            provider.as_str(),
+            model,
+            request.model_override,
+            request.max_tokens,
+            request.max_chars,
+            request.top_p,
+            request.stop_sequences,
+            request.concise,
+            reasoning,
+            normalize(request.system_prompt.as_deref().unwrap_or_default()),
+            messages,
+            normalize(&request.prompt)
+        )
+    }
+
+    pub async fn get(&self, key: &str) -> Option<LLMResponse> {
+        let entries = self.entries.read().await;
+        entries
+            .get(key)
+            .filter(|cached| cached.stored_at.elapsed() < self.ttl)
+            .map(|cached| cached.response.clone())
+    }
+
+    pub async fn insert(&self, key: String, response: LLMResponse) {
+        let mut entries = self.entries.write().await;
+        if entries.len() >= self.max_entries && !entries.contains_key(&key) {
+            let ttl = self.ttl;
+            entries.retain(|_, cached| cached.stored_at.elapsed() < ttl);
+            if entries.len() >= self.max_entries {
+                if let Some(oldest) = entries
+                    .iter()
+                    .min_by_key(|(_, cached)| cached.stored_at)
+                    .map(|(k, _)| k.clone())
+                {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+        entries.insert(
+            key,
+            CachedResponse {
+                response,
+                stored_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Serves deterministic requests from the cache, calling `generate` on a miss.
+    /// Non-deterministic requests always call `generate`. Cached responses carry
+    /// `cache_hit: true` in their metadata.
+    pub async fn get_or_generate<F, Fut>(
+        &self,
+        provider: &ModelProvider,
+        model: &str,
+        request: &LLMRequest,
+        generate: F,
+    ) -> AppResult<LLMResponse>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = AppResult<LLMResponse>>,
+    {
+        if !Self::is_cacheable(request) {
+            return generate().await;
+        }
+
+        let key = Self::cache_key(provider, model, request);
+        if let Some(mut cached) = self.get(&key).await {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            cached
+                .metadata
+                .get_or_insert_with(HashMap::new)
+                .insert(CACHE_HIT_METADATA_KEY.to_string(), true.into());
+            return Ok(cached);
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let response = generate().await?;
+        // A degraded answer shouldn't be served for the primary model once it recovers
+        if !response.content.trim().is_empty() && !is_degraded(&response) {
+            self.insert(key, response.clone()).await;
+        }
+        Ok(response)
+    }
+
+    pub async fn stats(&self) -> serde_json::Value {
+        serde_json::json!({
+            "entries": self.entries.read().await.len(),
+            "hits": self.hits.load(Ordering::Relaxed),
+            "misses": self.misses.load(Ordering::Relaxed),
+            "ttl_seconds": self.ttl.as_secs(),
+            "max_entries": self.max_entries,
+        })
+    }
+}
+
+pub fn is_cache_hit(response: &LLMResponse) -> bool {
+    response
+        .metadata
+        .as_ref()
+        .and_then(|m| m.get(CACHE_HIT_METADATA_KEY))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ReasoningConfig;
+    use std::sync::atomic::AtomicU32;
+    use std::sync::Arc;
+
+    fn request(prompt: &str, temperature: Option<f64>) -> LLMRequest {
+        LLMRequest {
+            prompt: prompt.to_string(),
+            system_prompt: Some("You are a project analyst.".to_string()),
+            messages: None,
+            max_tokens: Some(500),
+            temperature,
+            top_p: None,
+            stop_sequences: None,
+            tools: None,
+            model_override: None,
+            concise: false,
+            max_chars: None,
+            reasoning: None,
+        }
+    }
+
+    async fn generate_counting(
+        cache: &ResponseCache,
+        calls: &Arc<AtomicU32>,
+        request: &LLMRequest,
+    ) -> LLMResponse {
+        let calls = calls.clone();
+        cache
+            .get_or_generate(
+                &ModelProvider::OpenRouter,
+                "test/model",
+                request,
+                || async move {
+                    let n = calls.fetch_add(1, Ordering::SeqCst) + 1;
+                    Ok(LLMResponse {
+                        content: format!("analysis #{}", n),
+                        model_used: "test/model".to_string(),
+                        usage: HashMap::new(),
+                        latency_ms: 10.0,
+                        cost_estimate: 0.001,
+                        finish_reason: "stop".to_string(),
+                        confidence_score: 1.0,
+                        tool_calls: None,
+                        metadata: None,
+                        reasoning: None,
+                        reasoning_details: None,
+                    })
+                },
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_identical_deterministic_prompt_hits_cache() {
+        let cache = ResponseCache::new(Duration::from_secs(60), 10);
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let first =
+            generate_counting(&cache, &calls, &request("Analyze NullBlock", Some(0.0))).await;
+        let second = generate_counting(
+            &cache,
+            &calls,
+            &request("  Analyze   NullBlock\n", Some(0.0)),
+        )
+        .await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(second.content, first.content);
+        assert!(!is_cache_hit(&first));
+        assert!(is_cache_hit(&second));
+
+        let other = generate_counting(&cache, &calls, &request("Analyze Erebus", Some(0.0))).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(other.content, "analysis #2");
+    }
+
+    #[tokio::test]
+    async fn test_randomized_prompt_bypasses_cache() {
+        let cache = ResponseCache::new(Duration::from_secs(60), 10);
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let creative = request("Write a tweet about NullBlock", Some(0.8));
+        let first = generate_counting(&cache, &calls, &creative).await;
+        let second = generate_counting(&cache, &calls, &creative).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_ne!(first.content, second.content);
+        assert!(!is_cache_hit(&second));
+
+        let provider_default = request("Write a tweet about NullBlock", None);
+        generate_counting(&cache, &calls, &provider_default).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(cache.stats().await["entries"], 0);
+    }
+
+    #[test]
+    fn test_cache_key_covers_sampling_options() {
+        let base = request("Analyze NullBlock", Some(0.0));
+        let key = |r: &LLMRequest| ResponseCache::cache_key(&ModelProvider::OpenRouter, "m", r);
+        let base_key = key(&base);
+
+        let variants = [
+            LLMRequest {
+                top_p: Some(0.5),
+                ..base.clone()
+            },
+            LLMRequest {
+                stop_sequences: Some(vec!["\n".to_string()]),
+                ..base.clone()
+            },
+            LLMRequest {
+                reasoning: Some(ReasoningConfig {
+                    enabled: true,
+                    effort: Some("high".to_string()),
+                    max_tokens: None,
+                    exclude: false,
+                }),
+                ..base.clone()
+            },
+            LLMRequest {
+                concise: true,
+                ..base.clone()
+            },
+            LLMRequest {
+                model_override: Some("other/model".to_string()),
+                ..base.clone()
+            },
+        ];
+        for variant in &variants {
+            assert_ne!(key(variant), base_key);
+        }
+        assert_eq!(key(&base.clone()), base_key);
+    }
+
+    #[tokio::test]
+    async fn test_expired_and_evicted_entries() {
+        let cache = ResponseCache::new(Duration::from_millis(20), 1);
+        let calls = Arc::new(AtomicU32::new(0));
+        let deterministic = request("Analyze NullBlock", Some(0.0));
+
+        generate_counting(&cache, &calls, &deterministic).await;
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        generate_counting(&cache, &calls, &deterministic).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        generate_counting(&cache, &calls, &request("Analyze Erebus", Some(0.0))).await;
+        assert_eq!(cache.stats().await["entries"], 1);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{
+    error::AppError,
+    models::{LLMResponse, ModelConfig, ModelTier},
+};
+
+pub const DEGRADED_METADATA_KEY: &str = "degraded";
+pub const DEGRADED_FROM_METADATA_KEY: &str = "degraded_from";
+pub const DEGRADED_REASON_METADATA_KEY: &str = "degraded_reason";
+
+/// Models to step down to when the selected model can't serve a request, ordered from
+/// largest to smallest.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DegradationLadder {
+    rungs: Vec<String>,
+}
+
+impl DegradationLadder {
+    pub fn new<I, S>(rungs: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            rungs: rungs
+                .into_iter()
+                .map(Into::into)
+                .map(|r| r.trim().to_string())
+                .filter(|r| !r.is_empty())
+                .collect(),
+        }
+    }
+
+    /// `LLM_DEGRADATION_LADDER`, comma-separated model names, largest first.
+    pub fn from_env() -> Self {
+        std::env::var("LLM_DEGRADATION_LADDER")
+            .map(|v| Self::new(v.split(',')))
+            .unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rungs.is_empty()
+    }
+
+    pub fn rungs(&self) -> &[String] {
+        &self.rungs
+    }
+
+    /// The rungs `model` may step down to, as configs from `config_for`. A model on the
+    /// ladder gets the rungs below it. A model off the ladder has no position to step
+    /// down from, so it only gets rungs in its tier or lower that cost no more per token.
+    pub fn rungs_below(
+        &self,
+        model: &ModelConfig,
+        config_for: impl Fn(&str) -> ModelConfig,
+    ) -> Vec<ModelConfig> {
+        match self.rungs.iter().position(|r| *r == model.name) {
+            Some(index) => self.rungs[index + 1..]
+                .iter()
+                .map(|rung| config_for(rung))
+                .collect(),
+            None => self
+                .rungs
+                .iter()
+                .map(|rung| config_for(rung))
+                .filter(|rung| {
+                    tier_rank(&rung.tier) <= tier_rank(&model.tier)
+                        && rung.metrics.cost_per_1k_tokens <= model.metrics.cost_per_1k_tokens
+                })
+                .collect(),
+        }
+    }
+}
+
+fn tier_rank(tier: &ModelTier) -> u8 {
+    match tier {
+        ModelTier::Free => 0,
+        ModelTier::Fast => 1,
+        ModelTier::Standard => 2,
+        ModelTier::Premium => 3,
+    }
+}
+
+/// Failures that another model could still serve: the model is missing or the account
+/// can't afford it. A `RateLimitError` is returned as before rather than retried on
+/// another model behind the same key.
+pub fn should_degrade(error: &AppError) -> bool {
+    matches!(
+        error,
+        AppError::ModelNotAvailable(_) | AppError::ModelOverBudget(_)
+    )
+}
+
+pub fn mark_degraded(response: &mut LLMResponse, from_model: &str, reason: &AppError) {
+    let metadata = response.metadata.get_or_insert_with(Default::default);
+    metadata.insert(DEGRADED_METADATA_KEY.to_string(), true.into());
+    metadata.insert(DEGRADED_FROM_METADATA_KEY.to_string(), from_model.into());
+    metadata.insert(
+        DEGRADED_REASON_METADATA_KEY.to_string(),
+        reason.error_type().into(),
+    );
+}
+
+pub fn is_degraded(response: &LLMResponse) -> bool {
+    response
+        .metadata
+        .as_ref()
+        .and_then(|m| m.get(DEGRADED_METADATA_KEY))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ModelMetrics, ModelProvider};
+
+    fn model(name: &str, tier: ModelTier, cost_per_1k_tokens: f64) -> ModelConfig {
+        ModelConfig {
+            name: name.to_string(),
+            display_name: name.to_string(),
+            icon: String::new(),
+            provider: ModelProvider::OpenRouter,
+            tier,
+            capabilities: Vec::new(),
+            metrics: ModelMetrics {
+                avg_latency_ms: 1000.0,
+                tokens_per_second: 50.0,
+                cost_per_1k_tokens,
+                context_window: 8000,
+                max_output_tokens: 4096,
+                quality_score: 0.75,
+                reliability_score: 0.8,
+            },
+            api_endpoint: String::new(),
+            api_key_env: None,
+            description: String::new(),
+            enabled: true,
+            supports_reasoning: false,
+            is_popular: false,
+            created: None,
+        }
+    }
+
+    fn catalog(name: &str) -> ModelConfig {
+        match name {
+            "big/model" => model(name, ModelTier::Premium, 3.0),
+            "mid/model" => model(name, ModelTier::Standard, 0.5),
+            _ => model(name, ModelTier::Free, 0.0),
+        }
+    }
+
+    fn names(configs: Vec<ModelConfig>) -> Vec<String> {
+        configs.into_iter().map(|c| c.name).collect()
+    }
+
+    #[test]
+    fn test_rungs_below_selected_model() {
+        let ladder = DegradationLadder::new(["big/model", " mid/model ", "", "small/model"]);
+        assert_eq!(ladder.rungs(), ["big/model", "mid/model", "small/model"]);
+        assert_eq!(
+            names(ladder.rungs_below(&catalog("mid/model"), catalog)),
+            ["small/model"]
+        );
+        assert!(ladder
+            .rungs_below(&catalog("small/model"), catalog)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_off_ladder_model_only_steps_down_in_cost_and_tier() {
+        let ladder = DegradationLadder::new(["big/model", "mid/model", "small/model"]);
+
+        let standard = model("other/model", ModelTier::Standard, 1.0);
+        assert_eq!(
+            names(ladder.rungs_below(&standard, catalog)),
+            ["mid/model", "small/model"]
+        );
+
+        // A cheap model never "degrades" to a pricier one
+        let free = model("other/free", ModelTier::Free, 0.0);
+        assert_eq!(names(ladder.rungs_below(&free, catalog)), ["small/model"]);
+
+        // Cheaper per token, but a higher tier
+        let fast = model("other/fast", ModelTier::Fast, 5.0);
+        assert_eq!(names(ladder.rungs_below(&fast, catalog)), ["small/model"]);
+    }
+
+    #[test]
+    fn test_only_capacity_failures_degrade() {
+        assert!(should_degrade(&AppError::ModelNotAvailable("x".into())));
+        assert!(should_degrade(&AppError::ModelOverBudget("x".into())));
+        assert!(!should_degrade(&AppError::RateLimitError("x".into())));
+        assert!(!should_degrade(&AppError::AuthError("x".into())));
+        assert!(!should_degrade(&AppError::BadRequest("x".into())));
+    }
+}
@@ -12,6 +12,8 @@ use tokio::sync::RwLock;
 use tracing::{error, info, warn};
 
 use super::{
+    cache::{is_cache_hit, ResponseCache},
+    degradation::{is_degraded, mark_degraded, should_degrade, DegradationLadder},
     providers::{
         AnthropicProvider, GroqProvider, OllamaProvider, OpenAIProvider, OpenRouterProvider,
         Provider,
@@ -26,6 +28,8 @@ pub struct LLMServiceFactory {
     cost_tracking: Arc<RwLock<HashMap<String, f64>>>,
     available_models_cache: Arc<RwLock<Option<(Vec<serde_json::Value>, std::time::Instant)>>>,
     api_keys: Option<ApiKeys>,
+    response_cache: Option<Arc<ResponseCache>>,
+    degradation_ladder: DegradationLadder,
 }
 
 impl LLMServiceFactory {
@@ -37,9 +41,27 @@ impl LLMServiceFactory {
             cost_tracking: Arc::new(RwLock::new(HashMap::new())),
             available_models_cache: Arc::new(RwLock::new(None)),
             api_keys: None,
+            response_cache: None,
+            degradation_ladder: DegradationLadder::from_env(),
         }
     }
 
+    pub fn with_response_cache(mut self, cache: Arc<ResponseCache>) -> Self {
+        self.response_cache = Some(cache);
+        self
+    }
+
+    /// Registers a provider directly instead of building it from API keys in `initialize`.
+    pub fn with_provider(mut self, provider: Arc<dyn Provider>) -> Self {
+        self.providers.insert(provider.provider_type(), provider);
+        self
+    }
+
+    pub fn with_degradation_ladder(mut self, ladder: DegradationLadder) -> Self {
+        self.degradation_ladder = ladder;
+        self
+    }
+
     pub async fn initialize(&mut self, api_keys: &ApiKeys) -> AppResult<()> {
         info!("🧠 Initializing LLM Service Factory...");
 
@@ -142,6 +164,24 @@ impl LLMServiceFactory {
         &self,
         request: &LLMRequest,
         requirements: Option<TaskRequirements>,
+    ) -> AppResult<LLMResponse> {
+        self.generate_routed(request, requirements, true).await
+    }
+
+    /// Skips the response cache, for calls that must reach the model (e.g. validation).
+    pub async fn generate_uncached(
+        &self,
+        request: &LLMRequest,
+        requirements: Option<TaskRequirements>,
+    ) -> AppResult<LLMResponse> {
+        self.generate_routed(request, requirements, false).await
+    }
+
+    async fn generate_routed(
+        &self,
+        request: &LLMRequest,
+        requirements: Option<TaskRequirements>,
+        use_cache: bool,
     ) -> AppResult<LLMResponse> {
         let requirements = requirements.unwrap_or_else(TaskRequirements::default);
 
@@ -167,16 +207,88 @@ impl LLMServiceFactory {
             selected_model, routing_decision.confidence
         );
 
-        // Try to generate response with the selected model, with fallback on 404
-        let mut response_result = self.generate_with_model(request, &model_config).await;
+        let mut response = match self.response_cache.as_ref().filter(|_| use_cache) {
+            Some(cache) => {
+                cache
+                    .get_or_generate(&model_config.provider, &selected_model, request, || {
+                        self.generate_with_fallbacks(
+                            request,
+                            &selected_model,
+                            &model_config,
+                            &routing_decision.fallback_models,
+                            &requirements,
+                        )
+                    })
+                    .await?
+            }
+            None => {
+                self.generate_with_fallbacks(
+                    request,
+                    &selected_model,
+                    &model_config,
+                    &routing_decision.fallback_models,
+                    &requirements,
+                )
+                .await?
+            }
+        };
 
-        if let Err(AppError::ModelNotAvailable(ref msg)) = response_result {
+        if is_cache_hit(&response) {
+            info!("♻️ Served {} response from cache", selected_model);
+            return Ok(response);
+        }
+
+        if is_degraded(&response) {
+            warn!(
+                "📉 Degraded from {} to {}",
+                selected_model, response.model_used
+            );
+        }
+
+        // Override confidence_score with routing confidence
+        response.confidence_score = routing_decision.confidence;
+
+        // Log model info
+        log_model_info!(
+            response.model_used,
+            model_config.provider.as_str(),
+            response.cost_estimate
+        );
+
+        // Update statistics
+        self.update_stats(&selected_model, &response).await;
+
+        Ok(response)
+    }
+
+    async fn generate_with_fallbacks(
+        &self,
+        request: &LLMRequest,
+        selected_model: &str,
+        model_config: &ModelConfig,
+        routing_fallbacks: &[String],
+        requirements: &TaskRequirements,
+    ) -> AppResult<LLMResponse> {
+        let primary_error = match self.generate_with_model(request, model_config).await {
+            Err(e) if should_degrade(&e) => e,
+            result => return result,
+        };
+
+        if let Some(response) = self
+            .generate_degraded(request, model_config, requirements, &primary_error)
+            .await
+        {
+            return Ok(response);
+        }
+
+        // Fall back to live free models on 404
+        if let AppError::ModelNotAvailable(ref msg) = primary_error {
             warn!(
                 "⚠️ Model {} not available ({}), fetching live free models for fallback",
                 selected_model, msg
             );
 
-            let mut fallback_models = routing_decision.fallback_models.clone();
+            let mut fallback_models = routing_fallbacks.to_vec();
 
             let live_fallbacks = self.get_free_model_fallbacks().await;
             if !live_fallbacks.is_empty() {
@@ -188,20 +300,20 @@ impl LLMServiceFactory {
             }
 
             for fallback_model in &fallback_models {
-                if fallback_model == &selected_model {
+                if fallback_model == selected_model {
                     continue;
                 }
                 info!("🔄 Trying fallback model: {}", fallback_model);
                 let fallback_config = self.create_model_config_for_override(fallback_model);
                 match self.generate_with_model(request, &fallback_config).await {
-                    Ok(r) => {
+                    Ok(mut r) => {
                         if r.content.trim().is_empty() {
                             warn!("⚠️ Fallback model {} returned empty response, trying next fallback", fallback_model);
                             continue;
                         }
                         info!("✅ Fallback successful with model: {}", fallback_model);
-                        response_result = Ok(r);
-                        break;
+                        mark_degraded(&mut r, selected_model, &primary_error);
+                        return Ok(r);
                     }
                     Err(e) => {
                         warn!("⚠️ Fallback model {} also failed: {}", fallback_model, e);
@@ -210,27 +322,76 @@ impl LLMServiceFactory {
                 }
             }
 
-            if response_result.is_err() {
-                warn!("❌ All fallback models failed for request");
-            }
+            warn!("❌ All fallback models failed for request");
         }
 
-        let mut response = response_result?;
+        Err(primary_error)
+    }
 
-        // Override confidence_score with routing confidence
-        response.confidence_score = routing_decision.confidence;
+    /// Walks the degradation ladder below the selected model, skipping rungs that don't
+    /// meet the task requirements, and returns the first response flagged as degraded.
+    async fn generate_degraded(
+        &self,
+        request: &LLMRequest,
+        model_config: &ModelConfig,
+        requirements: &TaskRequirements,
+        reason: &AppError,
+    ) -> Option<LLMResponse> {
+        let selected_model = model_config.name.as_str();
+        let router = self.router.read().await;
+        // Override configs carry placeholder cost and tier; prefer the catalog entry
+        let selected = router
+            .known_model(selected_model)
+            .unwrap_or_else(|| model_config.clone());
+        let rung_configs: Vec<ModelConfig> = self
+            .degradation_ladder
+            .rungs_below(&selected, |rung| {
+                router
+                    .known_model(rung)
+                    .unwrap_or_else(|| self.create_model_config_for_override(rung))
+            })
+            .into_iter()
+            .filter(|config| {
+                let meets = router.meets_requirements(config, requirements);
+                if !meets {
+                    info!(
+                        "⏭️ Skipping {}: does not meet task requirements",
+                        config.name
+                    );
+                }
+                meets
+            })
+            .collect();
+        drop(router);
 
-        // Log model info
-        log_model_info!(
-            response.model_used,
-            model_config.provider.as_str(),
-            response.cost_estimate
+        if rung_configs.is_empty() {
+            return None;
+        }
+
+        warn!(
+            "⚠️ Model {} failed ({}), degrading through {} smaller models",
+            selected_model,
+            reason,
+            rung_configs.len()
         );
 
-        // Update statistics
-        self.update_stats(&selected_model, &response).await;
+        for config in &rung_configs {
+            info!("📉 Trying degraded model: {}", config.name);
+            match self.generate_with_model(request, config).await {
+                Ok(r) if r.content.trim().is_empty() => {
+                    warn!("⚠️ Degraded model {} returned empty response", config.name);
+                }
+                Ok(mut r) => {
+                    mark_degraded(&mut r, selected_model, reason);
+                    return Some(r);
+                }
+                Err(e) => {
+                    warn!("⚠️ Degraded model {} also failed: {}", config.name, e);
+                }
+            }
+        }
 
-        Ok(response)
+        None
     }
 
     async fn generate_with_model(
@@ -328,10 +489,16 @@ impl LLMServiceFactory {
         let cost_tracking = self.cost_tracking.read().await;
         let router = self.router.read().await;
 
+        let response_cache = match &self.response_cache {
+            Some(cache) => cache.stats().await,
+            None => serde_json::Value::Null,
+        };
+
         serde_json::json!({
             "request_stats": *request_stats,
             "cost_tracking": *cost_tracking,
-            "router_stats": router.get_usage_stats()
+            "router_stats": router.get_usage_stats(),
+            "response_cache": response_cache
         })
     }
 
@@ -408,7 +575,16 @@ impl LLMServiceFactory {
             ..config
         };
 
-        let result = temp_provider.generate(request, &adjusted_config).await;
+        let result = match &self.response_cache {
+            Some(cache) => {
+                cache
+                    .get_or_generate(&provider_type, model_name, request, || {
+                        temp_provider.generate(request, &adjusted_config)
+                    })
+                    .await
+            }
+            None => temp_provider.generate(request, &adjusted_config).await,
+        };
 
         if let Err(AppError::ModelNotAvailable(ref msg)) = result {
             if provider_type == ModelProvider::OpenRouter {
@@ -433,7 +609,10 @@ impl LLMServiceFactory {
                             return Ok(r);
                         }
                         Ok(_) => {
-                            warn!("⚠️ Fallback model {} returned empty response", fallback_model);
+                            warn!(
+                                "⚠️ Fallback model {} returned empty response",
+                                fallback_model
+                            );
                         }
                         Err(e) => {
                             warn!("⚠️ Fallback model {} failed: {}", fallback_model, e);
@@ -663,3 +842,190 @@ impl LLMServiceFactory {
         "openrouter/free".to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::degradation::DEGRADED_FROM_METADATA_KEY;
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// Fails for the listed models and answers as itself for any other.
+    struct ScriptedProvider {
+        failures: HashMap<&'static str, fn(String) -> AppError>,
+        calls: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl Provider for ScriptedProvider {
+        async fn generate(
+            &self,
+            _request: &LLMRequest,
+            config: &ModelConfig,
+        ) -> AppResult<LLMResponse> {
+            self.calls.lock().unwrap().push(config.name.clone());
+            if let Some(error) = self.failures.get(config.name.as_str()) {
+                return Err(error(config.name.clone()));
+            }
+            Ok(LLMResponse {
+                content: format!("answer from {}", config.name),
+                model_used: config.name.clone(),
+                usage: HashMap::new(),
+                latency_ms: 10.0,
+                cost_estimate: 0.0,
+                finish_reason: "stop".to_string(),
+                confidence_score: 1.0,
+                tool_calls: None,
+                metadata: None,
+                reasoning: None,
+                reasoning_details: None,
+            })
+        }
+
+        fn provider_type(&self) -> ModelProvider {
+            ModelProvider::OpenRouter
+        }
+
+        async fn health_check(&self) -> AppResult<bool> {
+            Ok(true)
+        }
+    }
+
+    fn factory_with(
+        failures: &[(&'static str, fn(String) -> AppError)],
+        ladder: &[&str],
+    ) -> (LLMServiceFactory, Arc<ScriptedProvider>) {
+        let provider = Arc::new(ScriptedProvider {
+            failures: failures.iter().copied().collect(),
+            calls: Mutex::new(Vec::new()),
+        });
+        let mut factory = LLMServiceFactory::new()
+            .with_degradation_ladder(DegradationLadder::new(ladder.iter().copied()));
+        factory
+            .providers
+            .insert(ModelProvider::OpenRouter, provider.clone());
+        (factory, provider)
+    }
+
+    fn request_for(model: &str) -> LLMRequest {
+        LLMRequest {
+            prompt: "Summarize the roadmap".to_string(),
+            system_prompt: None,
+            messages: None,
+            max_tokens: Some(200),
+            temperature: Some(0.7),
+            top_p: None,
+            stop_sequences: None,
+            tools: None,
+            model_override: Some(model.to_string()),
+            concise: false,
+            max_chars: None,
+            reasoning: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unavailable_primary_degrades_to_next_rung() {
+        let (factory, provider) = factory_with(
+            &[("big/model", AppError::ModelNotAvailable)],
+            &["big/model", "small/model", "tiny/model"],
+        );
+
+        let response = factory
+            .generate(&request_for("big/model"), None)
+            .await
+            .unwrap();
+
+        assert_eq!(response.model_used, "small/model");
+        assert_eq!(response.content, "answer from small/model");
+        assert!(is_degraded(&response));
+        let metadata = response.metadata.unwrap();
+        assert_eq!(metadata[DEGRADED_FROM_METADATA_KEY], "big/model");
+        assert_eq!(
+            *provider.calls.lock().unwrap(),
+            vec!["big/model", "small/model"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_degradation_skips_rungs_below_requirements() {
+        let (factory, provider) = factory_with(
+            &[("big/model", AppError::ModelOverBudget)],
+            &["big/model", "small/model", "openrouter/free"],
+        );
+        let requirements = TaskRequirements {
+            min_quality_score: Some(0.8),
+            ..TaskRequirements::default()
+        };
+
+        let response = factory
+            .generate(&request_for("big/model"), Some(requirements))
+            .await
+            .unwrap();
+
+        // Unknown models get the 0.75 quality default, below the task minimum
+        assert_eq!(response.model_used, "openrouter/free");
+        assert!(is_degraded(&response));
+        assert_eq!(
+            *provider.calls.lock().unwrap(),
+            vec!["big/model", "openrouter/free"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_primary_success_and_other_errors_are_not_degraded() {
+        let (factory, provider) = factory_with(
+            &[
+                ("locked/model", AppError::AuthError),
+                ("busy/model", AppError::RateLimitError),
+            ],
+            &["big/model", "small/model"],
+        );
+
+        let response = factory
+            .generate(&request_for("big/model"), None)
+            .await
+            .unwrap();
+        assert_eq!(response.model_used, "big/model");
+        assert!(!is_degraded(&response));
+
+        let error = factory
+            .generate(&request_for("locked/model"), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, AppError::AuthError(_)));
+
+        // Rate limits are surfaced, not retried on the ladder
+        let error = factory
+            .generate(&request_for("busy/model"), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, AppError::RateLimitError(_)));
+        assert_eq!(
+            provider.calls.lock().unwrap().last().map(String::as_str),
+            Some("busy/model")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_off_ladder_model_degrades_only_to_cheaper_rungs() {
+        let free_model = "deepseek/deepseek-chat-v3.1:free";
+        let (factory, provider) = factory_with(
+            &[(free_model, AppError::ModelOverBudget)],
+            &["big/model", "openrouter/free"],
+        );
+
+        let response = factory
+            .generate(&request_for(free_model), None)
+            .await
+            .unwrap();
+
+        // big/model is a paid Standard model, a step up from a free one
+        assert_eq!(response.model_used, "openrouter/free");
+        assert!(is_degraded(&response));
+        assert_eq!(
+            *provider.calls.lock().unwrap(),
+            vec![free_model, "openrouter/free"]
+        );
+    }
+}
@@ -1,7 +1,11 @@
+pub mod cache;
+pub mod degradation;
 pub mod factory;
 pub mod providers;
 pub mod router;
 pub mod validator;
 
+pub use cache::ResponseCache;
+pub use degradation::DegradationLadder;
 pub use factory::LLMServiceFactory;
 pub use router::{OptimizationGoal, Priority, TaskRequirements};
@@ -740,6 +740,14 @@ impl Provider for OpenRouterProvider {
                 }
             }
 
+            if status == reqwest::StatusCode::PAYMENT_REQUIRED {
+                warn!("⚠️ Insufficient credits for model {}", config.name);
+                return Err(AppError::ModelOverBudget(format!(
+                    "Model '{}' exceeds the remaining OpenRouter credits",
+                    config.name
+                )));
+            }
+
             // Generic error handling for other non-success responses
             return Err(AppError::LLMRequestFailed(format!(
                 "OpenRouter API error {}: {}",
@@ -162,7 +162,7 @@ impl ModelRouter {
         self.model_status.get(model_name).copied().unwrap_or(true)
     }
 
-    fn meets_requirements(&self, model: &ModelConfig, requirements: &TaskRequirements) -> bool {
+    pub fn meets_requirements(&self, model: &ModelConfig, requirements: &TaskRequirements) -> bool {
         // Check if model has required capabilities
         for required_cap in &requirements.required_capabilities {
             if !model.capabilities.contains(required_cap) {
@@ -374,6 +374,12 @@ impl ModelRouter {
         score.max(0.0)
     }
 
+    pub fn known_model(&self, model_name: &str) -> Option<ModelConfig> {
+        self.get_static_models()
+            .into_iter()
+            .find(|m| m.name == model_name)
+    }
+
     fn get_fallback_models(&self) -> Option<Vec<String>> {
         Some(vec![
             "openrouter/free".to_string(),
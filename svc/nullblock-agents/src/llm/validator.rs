@@ -46,7 +46,11 @@ impl ModelValidator {
         let validation_timeout = Duration::from_secs(VALIDATION_TIMEOUT_SECS);
         let factory = self.llm_factory.read().await;
 
-        let validation_result = timeout(validation_timeout, factory.generate(&request, None)).await;
+        let validation_result = timeout(
+            validation_timeout,
+            factory.generate_uncached(&request, None),
+        )
+        .await;
 
         match validation_result {
             Ok(Ok(response)) => {
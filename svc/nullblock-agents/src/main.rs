@@ -131,6 +131,7 @@ fn create_router(state: server::AppState) -> Router {
         .route("/tasks/:task_id/cancel", post(tasks::cancel_task))
         .route("/tasks/:task_id/retry", post(tasks::retry_task))
         .route("/tasks/:task_id/process", post(tasks::process_task))
+        .route("/tasks/:task_id/result", get(tasks::get_task_result))
         // Siren Marketing agent endpoints
         .route("/siren/chat", post(siren_marketing::chat))
         .route(
@@ -463,6 +463,9 @@ pub struct Task {
     // Action tracking fields
     pub actioned_at: Option<DateTime<Utc>>,
     pub action_result: Option<String>,
+    /// True when `action_result` is shorter than the output the task produced.
+    #[serde(default)]
+    pub action_result_truncated: bool,
     pub action_metadata: HashMap<String, serde_json::Value>,
     pub action_duration: Option<u64>,
 
@@ -522,6 +525,26 @@ pub struct TaskResponse {
     pub timestamp: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskResult {
+    pub task_id: String,
+    pub content: String,
+    pub content_type: String,
+    pub original_bytes: u64,
+    pub stored_bytes: u64,
+    pub truncated: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaskResultResponse {
+    pub success: bool,
+    pub data: Option<TaskResult>,
+    pub error: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct TaskListResponse {
     pub success: bool,
@@ -5,6 +5,7 @@ use crate::{
     engrams::EngramsClient,
     error::AppResult,
     kafka::{KafkaConfig, KafkaProducer},
+    llm::ResponseCache,
     services::ErebusClient,
 };
 use std::collections::HashMap;
@@ -59,11 +60,24 @@ impl AppState {
         info!("🧠 Connecting to Engrams at {}", engrams_base_url);
         let engrams_client = Arc::new(EngramsClient::new(engrams_base_url));
 
+        // Cache for deterministic (temperature 0) calls: the LLM proxy and MCP tools through
+        // Hecate's factory, and Siren's deterministic project analysis. Both agents' chat runs
+        // hotter and bypasses it. A TTL of 0 disables it
+        let response_cache = (config.llm.cache_ttl_seconds > 0).then(|| {
+            Arc::new(ResponseCache::new(
+                std::time::Duration::from_secs(config.llm.cache_ttl_seconds),
+                config.llm.max_cache_size as usize,
+            ))
+        });
+
         // Initialize Hecate agent with default model from config
         let default_model = config.llm.default_model.clone();
         let mut hecate_agent = HecateAgent::new(None);
         hecate_agent.preferred_model = default_model.clone();
         hecate_agent.set_engrams_client(engrams_client.clone());
+        if let Some(cache) = &response_cache {
+            hecate_agent.set_response_cache(cache.clone());
+        }
 
         // Initialize Moros agent with default model from config
         let mut moros_agent = MorosAgent::new(None);
@@ -73,6 +87,9 @@ impl AppState {
         // Initialize Marketing agent with default model from config
         let mut marketing_agent = MarketingAgent::new(None);
         marketing_agent.preferred_model = default_model;
+        if let Some(cache) = &response_cache {
+            marketing_agent.set_response_cache(cache.clone());
+        }
 
         // Fetch API keys from Erebus (agent keys) with fallback to env vars
         let api_keys = Self::resolve_api_keys(&erebus_client, &config).await;
@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS content_generation_metrics (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    theme VARCHAR(50) NOT NULL,
+    template VARCHAR(100),
+    success BOOLEAN NOT NULL,
+    latency_ms BIGINT NOT NULL,
+    error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_content_generation_metrics_created_at ON content_generation_metrics(created_at);
+CREATE INDEX IF NOT EXISTS idx_content_generation_metrics_template ON content_generation_metrics(theme, template);
@@ -21,6 +21,11 @@ impl Database {
         Ok(Self { pool })
     }
 
+    #[cfg(test)]
+    pub fn from_pool(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
     pub async fn health_check(&self) -> Result<(), sqlx::Error> {
         sqlx::query("SELECT 1").fetch_one(&self.pool).await?;
         Ok(())
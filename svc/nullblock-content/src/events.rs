@@ -1,7 +1,12 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
 use uuid::Uuid;
 
+use crate::models::GenerateContentResponse;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "event_type")]
 pub enum ContentEvent {
@@ -43,9 +48,69 @@ impl EventPublisher for NoOpPublisher {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+        }
+    }
+}
+
+/// POSTs `body` as JSON, retrying failed requests and 5xx responses with exponential
+/// backoff. A 4xx is returned straight away, resending the same body won't change it.
+pub async fn post_json_with_retry<T: Serialize + ?Sized>(
+    client: &reqwest::Client,
+    url: &str,
+    body: &T,
+    policy: RetryPolicy,
+) -> Result<(), String> {
+    let mut backoff = policy.initial_backoff;
+    let mut last_error = String::new();
+
+    for attempt in 1..=policy.max_attempts.max(1) {
+        let result = client
+            .post(url)
+            .json(body)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status());
+
+        match result {
+            Ok(_) => return Ok(()),
+            Err(e) if e.status().is_some_and(|s| s.is_client_error()) => {
+                return Err(e.to_string());
+            }
+            Err(e) => {
+                last_error = e.to_string();
+                if attempt < policy.max_attempts {
+                    tracing::warn!(
+                        "POST to {} failed (attempt {}/{}): {}",
+                        url,
+                        attempt,
+                        policy.max_attempts,
+                        e
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                }
+            }
+        }
+    }
+
+    Err(last_error)
+}
+
 pub struct HttpEventPublisher {
     client: reqwest::Client,
     endpoint: String,
+    retry: RetryPolicy,
 }
 
 impl HttpEventPublisher {
@@ -53,6 +118,7 @@ impl HttpEventPublisher {
         Self {
             client: reqwest::Client::new(),
             endpoint,
+            retry: RetryPolicy::default(),
         }
     }
 }
@@ -60,10 +126,7 @@ impl HttpEventPublisher {
 #[async_trait::async_trait]
 impl EventPublisher for HttpEventPublisher {
     async fn publish(&self, event: ContentEvent) -> Result<(), String> {
-        self.client
-            .post(&self.endpoint)
-            .json(&event)
-            .send()
+        post_json_with_retry(&self.client, &self.endpoint, &event, self.retry)
             .await
             .map_err(|e| format!("Failed to publish event: {}", e))?;
 
@@ -71,3 +134,314 @@ impl EventPublisher for HttpEventPublisher {
         Ok(())
     }
 }
+
+/// Body POSTed to a request's `callback_url` once generation finishes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum CallbackPayload {
+    Completed {
+        content: GenerateContentResponse,
+        timestamp: DateTime<Utc>,
+    },
+    Failed {
+        theme: String,
+        error: String,
+        timestamp: DateTime<Utc>,
+    },
+}
+
+impl CallbackPayload {
+    pub fn completed(content: GenerateContentResponse) -> Self {
+        CallbackPayload::Completed {
+            content,
+            timestamp: Utc::now(),
+        }
+    }
+
+    pub fn failed(theme: &str, error: impl Into<String>) -> Self {
+        CallbackPayload::Failed {
+            theme: theme.to_string(),
+            error: error.into(),
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+/// A `callback_url` that passed validation, pinned to the address it resolved to so a
+/// DNS change between validation and delivery can't point it somewhere else.
+#[derive(Debug, Clone)]
+pub struct CallbackTarget {
+    url: reqwest::Url,
+    host: String,
+    addr: SocketAddr,
+}
+
+impl CallbackTarget {
+    pub fn url(&self) -> &str {
+        self.url.as_str()
+    }
+}
+
+/// Accepts `http(s)` URLs whose host resolves only to public addresses. Loopback,
+/// private (RFC 1918), shared, link-local and unique local (ULA) addresses are refused,
+/// so a caller can't use callbacks to reach the service's own network.
+pub async fn validate_callback_url(url: &str) -> Result<CallbackTarget, String> {
+    let parsed = reqwest::Url::parse(url).map_err(|e| format!("Invalid callback_url: {}", e))?;
+    match parsed.scheme() {
+        "http" | "https" => {}
+        scheme => {
+            return Err(format!(
+                "Invalid callback_url: unsupported scheme '{}'",
+                scheme
+            ))
+        }
+    }
+
+    let host = parsed
+        .host_str()
+        .ok_or_else(|| "Invalid callback_url: missing host".to_string())?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_string();
+    let port = parsed.port_or_known_default().unwrap_or(80);
+
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), port))
+        .await
+        .map_err(|e| format!("Invalid callback_url: could not resolve {}: {}", host, e))?
+        .collect();
+    if let Some(blocked) = addrs.iter().find(|addr| !is_public_ip(addr.ip())) {
+        return Err(format!(
+            "Invalid callback_url: {} resolves to non-public address {}",
+            host,
+            blocked.ip()
+        ));
+    }
+    let addr = *addrs
+        .first()
+        .ok_or_else(|| format!("Invalid callback_url: {} did not resolve", host))?;
+
+    Ok(CallbackTarget {
+        url: parsed,
+        host,
+        addr,
+    })
+}
+
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_public_ipv4(mapped),
+            None => is_public_ipv6(ip),
+        },
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+    let shared = first == 100 && (64..128).contains(&second);
+    !(ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || first == 0
+        || shared)
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    let unique_local = first & 0xfe00 == 0xfc00;
+    let link_local = first & 0xffc0 == 0xfe80;
+    !(ip.is_loopback() || ip.is_unspecified() || ip.is_multicast() || unique_local || link_local)
+}
+
+pub struct CallbackNotifier {
+    retry: RetryPolicy,
+}
+
+impl CallbackNotifier {
+    pub fn new(retry: RetryPolicy) -> Self {
+        Self { retry }
+    }
+
+    pub async fn notify(
+        &self,
+        target: &CallbackTarget,
+        payload: &CallbackPayload,
+    ) -> Result<(), String> {
+        // Connect to the validated address, and don't follow redirects off it
+        let client = reqwest::Client::builder()
+            .resolve(&target.host, target.addr)
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|e| format!("Callback to {} failed: {}", target.url(), e))?;
+
+        post_json_with_retry(&client, target.url(), payload, self.retry)
+            .await
+            .map_err(|e| format!("Callback to {} failed: {}", target.url(), e))
+    }
+
+    /// Delivers the callback in the background so the request isn't held open.
+    pub fn spawn_notify(self: &Arc<Self>, target: CallbackTarget, payload: CallbackPayload) {
+        let notifier = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = notifier.notify(&target, &payload).await {
+                tracing::warn!("{}", e);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::{engine::ContentGenerator, templates::TemplateLoader};
+    use axum::{extract::State, http::StatusCode, routing::post, Json, Router};
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use tokio::sync::Mutex;
+
+    #[derive(Clone, Default)]
+    struct Receiver {
+        payloads: Arc<Mutex<Vec<serde_json::Value>>>,
+        failures_remaining: Arc<AtomicU32>,
+        failure_status: Option<StatusCode>,
+        requests: Arc<AtomicU32>,
+    }
+
+    async fn receive(
+        State(receiver): State<Receiver>,
+        Json(body): Json<serde_json::Value>,
+    ) -> StatusCode {
+        receiver.requests.fetch_add(1, Ordering::SeqCst);
+        if receiver.failures_remaining.load(Ordering::SeqCst) > 0 {
+            receiver.failures_remaining.fetch_sub(1, Ordering::SeqCst);
+            return receiver
+                .failure_status
+                .unwrap_or(StatusCode::SERVICE_UNAVAILABLE);
+        }
+        receiver.payloads.lock().await.push(body);
+        StatusCode::OK
+    }
+
+    /// Receivers listen on loopback, which `validate_callback_url` refuses, so tests
+    /// build their targets directly.
+    async fn spawn_receiver(receiver: Receiver) -> CallbackTarget {
+        let app = Router::new()
+            .route("/callback", post(receive))
+            .with_state(receiver);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        CallbackTarget {
+            url: reqwest::Url::parse(&format!("http://callbacks.test:{}/callback", addr.port()))
+                .unwrap(),
+            host: "callbacks.test".to_string(),
+            addr,
+        }
+    }
+
+    fn notifier() -> CallbackNotifier {
+        CallbackNotifier::new(RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(10),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_successful_generation_posts_callback() {
+        let receiver = Receiver::default();
+        receiver.failures_remaining.store(1, Ordering::SeqCst);
+        let target = spawn_receiver(receiver.clone()).await;
+
+        let generator = ContentGenerator::new(TemplateLoader::seed_default_templates());
+        let content = generator.generate("morning_insight", false).unwrap();
+        let text = content.text.clone();
+
+        notifier()
+            .notify(&target, &CallbackPayload::completed(content))
+            .await
+            .unwrap();
+
+        let payloads = receiver.payloads.lock().await;
+        assert_eq!(payloads.len(), 1);
+        assert_eq!(payloads[0]["status"], "completed");
+        assert_eq!(payloads[0]["content"]["theme"], "morning_insight");
+        assert_eq!(payloads[0]["content"]["text"], text.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_failed_generation_posts_error_payload() {
+        let receiver = Receiver::default();
+        let target = spawn_receiver(receiver.clone()).await;
+
+        let generator = ContentGenerator::new(TemplateLoader::seed_default_templates());
+        let error = generator.generate("not_a_theme", false).unwrap_err();
+
+        notifier()
+            .notify(
+                &target,
+                &CallbackPayload::failed("not_a_theme", error.to_string()),
+            )
+            .await
+            .unwrap();
+
+        let payloads = receiver.payloads.lock().await;
+        assert_eq!(payloads.len(), 1);
+        assert_eq!(payloads[0]["status"], "failed");
+        assert_eq!(payloads[0]["theme"], "not_a_theme");
+        assert!(payloads[0]["error"]
+            .as_str()
+            .unwrap()
+            .contains("Unknown theme"));
+    }
+
+    #[tokio::test]
+    async fn test_client_errors_are_not_retried() {
+        let receiver = Receiver {
+            failure_status: Some(StatusCode::BAD_REQUEST),
+            ..Receiver::default()
+        };
+        receiver.failures_remaining.store(3, Ordering::SeqCst);
+        let target = spawn_receiver(receiver.clone()).await;
+
+        let error = notifier()
+            .notify(&target, &CallbackPayload::failed("eerie_fun", "boom"))
+            .await
+            .unwrap_err();
+
+        assert!(error.contains("400"));
+        assert_eq!(receiver.requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_validate_callback_url() {
+        let target = validate_callback_url("https://93.184.216.34/hooks/content")
+            .await
+            .unwrap();
+        assert_eq!(target.addr, "93.184.216.34:443".parse().unwrap());
+        assert!(validate_callback_url("ftp://example.com/hook")
+            .await
+            .is_err());
+        assert!(validate_callback_url("not a url").await.is_err());
+
+        for blocked in [
+            "http://127.0.0.1:8080/hook",
+            "http://localhost/hook",
+            "http://10.0.0.5/hook",
+            "http://172.16.3.4/hook",
+            "http://192.168.1.1/hook",
+            "http://169.254.169.254/latest/meta-data",
+            "http://100.64.0.1/hook",
+            "http://0.0.0.0/hook",
+            "http://[::1]/hook",
+            "http://[fd00::1]/hook",
+            "http://[fe80::1]/hook",
+            "http://[::ffff:127.0.0.1]/hook",
+        ] {
+            let error = validate_callback_url(blocked).await.unwrap_err();
+            assert!(error.contains("non-public"), "{}: {}", blocked, error);
+        }
+    }
+}
@@ -33,23 +33,24 @@ impl ContentGenerator {
 
         let mut rng = thread_rng();
 
-        let candidates: Vec<&TemplateVariant> = if include_image {
-            theme_config.variants.iter().collect()
+        let candidates: Vec<(usize, &TemplateVariant)> = if include_image {
+            theme_config.variants.iter().enumerate().collect()
         } else {
             theme_config
                 .variants
                 .iter()
-                .filter(|v| !v.requires_image)
+                .enumerate()
+                .filter(|(_, v)| !v.requires_image)
                 .collect()
         };
 
         let candidates = if candidates.is_empty() {
-            theme_config.variants.iter().collect::<Vec<_>>()
+            theme_config.variants.iter().enumerate().collect::<Vec<_>>()
         } else {
             candidates
         };
 
-        let variant = candidates.choose(&mut rng).ok_or_else(|| {
+        let (index, variant) = candidates.choose(&mut rng).ok_or_else(|| {
             ContentError::GenerationError("No template variants available".into())
         })?;
 
@@ -64,6 +65,7 @@ impl ContentGenerator {
         Ok(GenerateContentResponse {
             id: Uuid::new_v4(),
             theme: theme.to_string(),
+            template: template_id(theme, *index),
             text,
             tags: variant.tags.clone(),
             image_prompt,
@@ -109,3 +111,8 @@ impl ContentGenerator {
         result
     }
 }
+
+/// Identifies a template variant by its theme and position in the theme's variant list.
+pub fn template_id(theme: &str, index: usize) -> String {
+    format!("{}#{}", theme, index)
+}
@@ -0,0 +1,126 @@
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TemplateMetrics {
+    pub theme: String,
+    pub template: Option<String>,
+    /// Attempts, including failed ones.
+    pub generations: u64,
+    pub failures: u64,
+    pub failure_rate: f64,
+    pub avg_latency_ms: f64,
+    pub max_latency_ms: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GenerationMetricsReport {
+    pub total_generations: u64,
+    pub total_failures: u64,
+    pub failure_rate: f64,
+    /// Most used first.
+    pub templates: Vec<TemplateMetrics>,
+}
+
+/// One `(theme, template)` group as aggregated by
+/// `ContentRepository::generation_metrics_by_template`.
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct TemplateMetricsRow {
+    pub theme: String,
+    pub template: Option<String>,
+    pub generations: i64,
+    pub failures: i64,
+    pub avg_latency_ms: f64,
+    pub max_latency_ms: i64,
+}
+
+impl GenerationMetricsReport {
+    pub fn from_rows(rows: Vec<TemplateMetricsRow>) -> Self {
+        let mut templates: Vec<TemplateMetrics> = rows
+            .into_iter()
+            .map(|row| {
+                let generations = row.generations.max(0) as u64;
+                let failures = row.failures.max(0) as u64;
+                TemplateMetrics {
+                    theme: row.theme,
+                    template: row.template,
+                    generations,
+                    failures,
+                    failure_rate: failure_rate(failures, generations),
+                    avg_latency_ms: row.avg_latency_ms,
+                    max_latency_ms: row.max_latency_ms,
+                }
+            })
+            .collect();
+        templates.sort_by(|a, b| {
+            b.generations
+                .cmp(&a.generations)
+                .then_with(|| a.theme.cmp(&b.theme))
+                .then_with(|| a.template.cmp(&b.template))
+        });
+
+        let total_generations = templates.iter().map(|t| t.generations).sum();
+        let total_failures = templates.iter().map(|t| t.failures).sum();
+
+        Self {
+            total_generations,
+            total_failures,
+            failure_rate: failure_rate(total_failures, total_generations),
+            templates,
+        }
+    }
+}
+
+fn failure_rate(failures: u64, generations: u64) -> f64 {
+    if generations == 0 {
+        0.0
+    } else {
+        failures as f64 / generations as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(template: Option<&str>, generations: i64, failures: i64) -> TemplateMetricsRow {
+        TemplateMetricsRow {
+            theme: "community".to_string(),
+            template: template.map(str::to_string),
+            generations,
+            failures,
+            avg_latency_ms: 12.0,
+            max_latency_ms: 40,
+        }
+    }
+
+    #[test]
+    fn test_report_totals_and_orders_templates() {
+        let report = GenerationMetricsReport::from_rows(vec![
+            row(None, 2, 2),
+            row(Some("community#0"), 5, 1),
+            row(Some("community#1"), 5, 0),
+        ]);
+
+        assert_eq!(report.total_generations, 12);
+        assert_eq!(report.total_failures, 3);
+        assert!((report.failure_rate - 0.25).abs() < 1e-9);
+
+        let order: Vec<_> = report
+            .templates
+            .iter()
+            .map(|t| t.template.as_deref())
+            .collect();
+        assert_eq!(order, vec![Some("community#0"), Some("community#1"), None]);
+        assert!((report.templates[0].failure_rate - 0.2).abs() < 1e-9);
+        assert_eq!(report.templates[2].failure_rate, 1.0);
+    }
+
+    #[test]
+    fn test_empty_metrics_report() {
+        let report = GenerationMetricsReport::from_rows(Vec::new());
+        assert_eq!(report.total_generations, 0);
+        assert_eq!(report.failure_rate, 0.0);
+        assert!(report.templates.is_empty());
+    }
+}
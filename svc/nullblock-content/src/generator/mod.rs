@@ -1,3 +1,4 @@
 pub mod engine;
+pub mod metrics;
 pub mod templates;
 pub mod themes;
@@ -2,11 +2,14 @@ use axum::{extract::State, http::StatusCode, response::Json};
 use chrono::Utc;
 use serde::Serialize;
 use std::sync::Arc;
+use std::time::Instant;
 
 use crate::database::Database;
-use crate::events::{ContentEvent, EventPublisher};
+use crate::events::{
+    validate_callback_url, CallbackNotifier, CallbackPayload, ContentEvent, EventPublisher,
+};
 use crate::generator::engine::ContentGenerator;
-use crate::models::{CreateContentRequest, GenerateContentResponse};
+use crate::models::{CreateContentRequest, GenerateContentResponse, GenerationMetric};
 use crate::repository::ContentRepository;
 
 #[derive(Clone)]
@@ -14,24 +17,78 @@ pub struct AppState {
     pub db: Arc<Database>,
     pub generator: Arc<ContentGenerator>,
     pub event_publisher: Arc<dyn EventPublisher>,
+    pub callback_notifier: Arc<CallbackNotifier>,
 }
 
+type HandlerError = (StatusCode, Json<ErrorResponse>);
+
 pub async fn generate_content(
     State(state): State<AppState>,
     Json(payload): Json<CreateContentRequest>,
-) -> Result<Json<GenerateContentResponse>, (StatusCode, Json<ErrorResponse>)> {
-    let response = state
+) -> Result<Json<GenerateContentResponse>, HandlerError> {
+    let callback_target = match &payload.callback_url {
+        Some(url) => Some(
+            validate_callback_url(url)
+                .await
+                .map_err(|error| (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })))?,
+        ),
+        None => None,
+    };
+
+    let result = generate_and_store(&state, &payload).await;
+
+    if let Some(target) = callback_target {
+        let callback = match &result {
+            Ok(content) => CallbackPayload::completed(content.clone()),
+            Err((_, Json(e))) => CallbackPayload::failed(&payload.theme, e.error.clone()),
+        };
+        state.callback_notifier.spawn_notify(target, callback);
+    }
+
+    result.map(Json)
+}
+
+async fn generate_and_store(
+    state: &AppState,
+    payload: &CreateContentRequest,
+) -> Result<GenerateContentResponse, HandlerError> {
+    let started = Instant::now();
+    let response = match state
         .generator
         .generate(&payload.theme, payload.include_image)
-        .map_err(|e| {
-            (
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse {
-                    error: e.to_string(),
-                }),
+    {
+        Ok(response) => response,
+        Err(e) => {
+            let error = e.to_string();
+            record_generation(
+                state,
+                GenerationMetric::failed(&payload.theme, None, elapsed_ms(started), error.clone()),
             )
-        })?;
+            .await;
+            return Err((StatusCode::BAD_REQUEST, Json(ErrorResponse { error })));
+        }
+    };
+
+    let template = response.template.clone();
+    let result = store_generated(state, response).await;
+    let metric = match &result {
+        Ok(_) => GenerationMetric::succeeded(&payload.theme, &template, elapsed_ms(started)),
+        Err((_, Json(e))) => GenerationMetric::failed(
+            &payload.theme,
+            Some(&template),
+            elapsed_ms(started),
+            e.error.clone(),
+        ),
+    };
+    record_generation(state, metric).await;
+
+    result
+}
 
+async fn store_generated(
+    state: &AppState,
+    response: GenerateContentResponse,
+) -> Result<GenerateContentResponse, HandlerError> {
     let pool = Arc::new(state.db.pool().clone());
     let repo = ContentRepository::new(pool);
 
@@ -71,18 +128,163 @@ pub async fn generate_content(
         tracing::warn!("Failed to publish content.generated event: {}", e);
     }
 
-    Ok(Json(GenerateContentResponse {
+    Ok(GenerateContentResponse {
         id: content.id,
         theme: content.theme,
+        template: response.template,
         text: content.text,
         tags: content.tags,
         image_prompt: content.image_prompt,
         status: content.status,
         created_at: content.created_at,
-    }))
+    })
+}
+
+/// Metrics are best effort; a failed write never fails the generation.
+async fn record_generation(state: &AppState, metric: GenerationMetric) {
+    let repo = ContentRepository::new(Arc::new(state.db.pool().clone()));
+    if let Err(e) = repo.record_generation(&metric).await {
+        tracing::warn!("Failed to record generation metric: {}", e);
+    }
+}
+
+fn elapsed_ms(started: Instant) -> i64 {
+    started.elapsed().as_millis() as i64
 }
 
 #[derive(Serialize)]
 pub struct ErrorResponse {
     pub error: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{NoOpPublisher, RetryPolicy};
+    use crate::generator::templates::TemplateLoader;
+    use axum::routing::post;
+    use axum::Router;
+    use sqlx::postgres::PgPoolOptions;
+    use sqlx::PgPool;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// The database is never reached: a rejected callback fails the request first.
+    fn state() -> AppState {
+        state_on(
+            PgPoolOptions::new()
+                .connect_lazy("postgres://localhost:1/unused")
+                .unwrap(),
+        )
+    }
+
+    fn state_on(pool: PgPool) -> AppState {
+        AppState {
+            db: Arc::new(Database::from_pool(pool)),
+            generator: Arc::new(ContentGenerator::new(
+                TemplateLoader::seed_default_templates(),
+            )),
+            event_publisher: Arc::new(NoOpPublisher),
+            callback_notifier: Arc::new(CallbackNotifier::new(RetryPolicy::default())),
+        }
+    }
+
+    async fn spawn_internal_service(hits: Arc<AtomicU32>) -> u16 {
+        let app = Router::new().route(
+            "/callback",
+            post(move || async move {
+                hits.fetch_add(1, Ordering::SeqCst);
+                StatusCode::OK
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        port
+    }
+
+    #[tokio::test]
+    async fn test_internal_callback_urls_are_rejected() {
+        let hits = Arc::new(AtomicU32::new(0));
+        let port = spawn_internal_service(hits.clone()).await;
+
+        for url in [
+            format!("http://127.0.0.1:{}/callback", port),
+            format!("http://localhost:{}/callback", port),
+            "http://169.254.169.254/latest/meta-data".to_string(),
+            "http://10.1.2.3/callback".to_string(),
+            "http://[fd12::1]/callback".to_string(),
+        ] {
+            let request = CreateContentRequest {
+                theme: "morning_insight".to_string(),
+                include_image: false,
+                callback_url: Some(url.clone()),
+            };
+            let (status, Json(body)) = generate_content(State(state()), Json(request))
+                .await
+                .unwrap_err();
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", url);
+            assert!(body.error.contains("non-public"), "{}: {}", url, body.error);
+        }
+
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert_eq!(hits.load(Ordering::SeqCst), 0);
+    }
+
+    fn request(theme: &str) -> CreateContentRequest {
+        CreateContentRequest {
+            theme: theme.to_string(),
+            include_image: false,
+            callback_url: None,
+        }
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    #[ignore = "needs DATABASE_URL pointing at a Postgres server"]
+    async fn test_generation_attempts_recorded_and_aggregated(pool: PgPool) {
+        use crate::handlers::metrics::get_generation_metrics;
+        use crate::models::{GenerationMetricsQuery, MAX_METRIC_THEME_CHARS};
+        use axum::extract::Query;
+
+        let state = state_on(pool);
+        for _ in 0..3 {
+            let Ok(Json(content)) =
+                generate_content(State(state.clone()), Json(request("morning_insight"))).await
+            else {
+                panic!("morning_insight generation failed");
+            };
+            // Without an image, morning_insight only has its text-only variant
+            assert_eq!(content.template, "morning_insight#0");
+        }
+        // Longer than the theme column; the failed attempt must still be recorded
+        let long_theme = "x".repeat(80);
+        for _ in 0..2 {
+            let (status, _) = generate_content(State(state.clone()), Json(request(&long_theme)))
+                .await
+                .unwrap_err();
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+        }
+
+        let Ok(Json(report)) = get_generation_metrics(
+            State(state),
+            Query(GenerationMetricsQuery { since_hours: None }),
+        )
+        .await
+        else {
+            panic!("generation metrics query failed");
+        };
+
+        assert_eq!(report.total_generations, 5);
+        assert_eq!(report.total_failures, 2);
+
+        let morning = &report.templates[0];
+        assert_eq!(morning.template.as_deref(), Some("morning_insight#0"));
+        assert_eq!(morning.generations, 3);
+        assert_eq!(morning.failures, 0);
+
+        let unknown = &report.templates[1];
+        assert_eq!(unknown.template, None);
+        assert_eq!(unknown.theme.chars().count(), MAX_METRIC_THEME_CHARS);
+        assert_eq!(unknown.generations, 2);
+        assert_eq!(unknown.failure_rate, 1.0);
+    }
+}
@@ -1,9 +1,11 @@
-use axum::{extract::{Path, State}, http::StatusCode, response::Json};
+use axum::{extract::{Path, Query, State}, http::StatusCode, response::Json};
+use chrono::{DateTime, TimeDelta, Utc};
 use serde::Serialize;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::models::ContentMetrics;
+use crate::generator::metrics::GenerationMetricsReport;
+use crate::models::{ContentMetrics, GenerationMetricsQuery};
 use crate::repository::ContentRepository;
 
 use super::generate::AppState;
@@ -38,7 +40,91 @@ pub async fn get_metrics(
     Ok(Json(metrics))
 }
 
+const DEFAULT_METRICS_WINDOW_HOURS: i64 = 24 * 30;
+
+pub async fn get_generation_metrics(
+    State(state): State<AppState>,
+    Query(query): Query<GenerationMetricsQuery>,
+) -> Result<Json<GenerationMetricsReport>, (StatusCode, Json<ErrorResponse>)> {
+    let since = window_start(query.since_hours.unwrap_or(DEFAULT_METRICS_WINDOW_HOURS))
+        .map_err(|error| (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })))?;
+
+    let pool = Arc::new(state.db.pool().clone());
+    let repo = ContentRepository::new(pool);
+
+    let rows = repo
+        .generation_metrics_by_template(since)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                }),
+            )
+        })?;
+
+    Ok(Json(GenerationMetricsReport::from_rows(rows)))
+}
+
+/// Start of a `since_hours` window, rejecting windows that reach before the representable range.
+fn window_start(since_hours: i64) -> Result<DateTime<Utc>, String> {
+    TimeDelta::try_hours(since_hours.max(1))
+        .and_then(|window| Utc::now().checked_sub_signed(window))
+        .ok_or_else(|| format!("since_hours {} is out of range", since_hours))
+}
+
 #[derive(Serialize)]
 pub struct ErrorResponse {
     pub error: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::Database;
+    use crate::events::{CallbackNotifier, NoOpPublisher, RetryPolicy};
+    use crate::generator::engine::ContentGenerator;
+    use crate::generator::templates::TemplateLoader;
+    use sqlx::postgres::PgPoolOptions;
+
+    /// The database is never reached: an out-of-range window fails the request first.
+    fn state() -> AppState {
+        AppState {
+            db: Arc::new(Database::from_pool(
+                PgPoolOptions::new()
+                    .connect_lazy("postgres://localhost:1/unused")
+                    .unwrap(),
+            )),
+            generator: Arc::new(ContentGenerator::new(
+                TemplateLoader::seed_default_templates(),
+            )),
+            event_publisher: Arc::new(NoOpPublisher),
+            callback_notifier: Arc::new(CallbackNotifier::new(RetryPolicy::default())),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_out_of_range_window_is_bad_request() {
+        for since_hours in [i64::MAX, 9_223_372_036_854_775, 10_000_000_000] {
+            let (status, Json(body)) = get_generation_metrics(
+                State(state()),
+                Query(GenerationMetricsQuery {
+                    since_hours: Some(since_hours),
+                }),
+            )
+            .await
+            .unwrap_err();
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", since_hours);
+            assert!(body.error.contains("out of range"), "{}", body.error);
+        }
+    }
+
+    #[test]
+    fn test_window_start_accepts_default_and_clamps_non_positive() {
+        let start = window_start(DEFAULT_METRICS_WINDOW_HOURS).unwrap();
+        assert!(Utc::now() - start >= TimeDelta::hours(DEFAULT_METRICS_WINDOW_HOURS));
+        assert!(window_start(0).unwrap() < Utc::now());
+        assert!(window_start(i64::MIN).unwrap() < Utc::now());
+    }
+}
@@ -16,7 +16,7 @@ mod repository;
 mod routes;
 
 use database::Database;
-use events::{EventPublisher, HttpEventPublisher, NoOpPublisher};
+use events::{CallbackNotifier, EventPublisher, HttpEventPublisher, NoOpPublisher, RetryPolicy};
 use generator::engine::ContentGenerator;
 use generator::templates::TemplateLoader;
 use handlers::generate::AppState;
@@ -94,6 +94,7 @@ async fn main() {
         db: Arc::new(db),
         generator: Arc::new(generator),
         event_publisher,
+        callback_notifier: Arc::new(CallbackNotifier::new(RetryPolicy::default())),
     };
 
     let cors = CorsLayer::new()
@@ -37,12 +37,17 @@ pub enum ContentStatus {
 pub struct CreateContentRequest {
     pub theme: String,
     pub include_image: bool,
+    /// Receives the generated content (or the error) once generation completes.
+    #[serde(default)]
+    pub callback_url: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GenerateContentResponse {
     pub id: Uuid,
     pub theme: String,
+    /// The template variant the text was generated from.
+    pub template: String,
     pub text: String,
     pub tags: Vec<String>,
     pub image_prompt: Option<String>,
@@ -63,6 +68,51 @@ pub struct ContentMetrics {
     pub created_at: DateTime<Utc>,
 }
 
+/// Length of `content_generation_metrics.theme`. Failed attempts carry the caller's theme,
+/// which can be longer.
+pub const MAX_METRIC_THEME_CHARS: usize = 50;
+
+/// One generation attempt. `template` is unset when generation failed before a template
+/// was chosen, e.g. for an unknown theme.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct GenerationMetric {
+    pub id: Uuid,
+    pub theme: String,
+    pub template: Option<String>,
+    pub success: bool,
+    pub latency_ms: i64,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl GenerationMetric {
+    pub fn succeeded(theme: &str, template: &str, latency_ms: i64) -> Self {
+        Self::new(theme, Some(template), latency_ms, None)
+    }
+
+    pub fn failed(theme: &str, template: Option<&str>, latency_ms: i64, error: String) -> Self {
+        Self::new(theme, template, latency_ms, Some(error))
+    }
+
+    fn new(theme: &str, template: Option<&str>, latency_ms: i64, error: Option<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            theme: theme.chars().take(MAX_METRIC_THEME_CHARS).collect(),
+            template: template.map(str::to_string),
+            success: error.is_none(),
+            latency_ms,
+            error,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GenerationMetricsQuery {
+    /// Only attempts from the last `since_hours` hours; defaults to 30 days.
+    pub since_hours: Option<i64>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct ContentTemplate {
     pub id: Uuid,
@@ -1,9 +1,11 @@
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::error::ContentError;
-use crate::models::{ContentMetrics, ContentQueue, ContentTemplate};
+use crate::generator::metrics::TemplateMetricsRow;
+use crate::models::{ContentMetrics, ContentQueue, ContentTemplate, GenerationMetric};
 
 pub struct ContentRepository {
     pool: Arc<PgPool>,
@@ -143,6 +145,55 @@ impl ContentRepository {
         Ok(row)
     }
 
+    pub async fn record_generation(
+        &self,
+        metric: &GenerationMetric,
+    ) -> Result<(), ContentError> {
+        sqlx::query(
+            r#"
+            INSERT INTO content_generation_metrics (id, theme, template, success, latency_ms, error, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(metric.id)
+        .bind(&metric.theme)
+        .bind(&metric.template)
+        .bind(metric.success)
+        .bind(metric.latency_ms)
+        .bind(&metric.error)
+        .bind(metric.created_at)
+        .execute(self.pool.as_ref())
+        .await?;
+
+        Ok(())
+    }
+
+    /// Attempts since `since`, aggregated per theme and template.
+    pub async fn generation_metrics_by_template(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<TemplateMetricsRow>, ContentError> {
+        let rows = sqlx::query_as::<_, TemplateMetricsRow>(
+            r#"
+            SELECT
+                theme,
+                template,
+                COUNT(*) AS generations,
+                COUNT(*) FILTER (WHERE NOT success) AS failures,
+                AVG(latency_ms)::FLOAT8 AS avg_latency_ms,
+                MAX(latency_ms) AS max_latency_ms
+            FROM content_generation_metrics
+            WHERE created_at >= $1
+            GROUP BY theme, template
+            "#,
+        )
+        .bind(since)
+        .fetch_all(self.pool.as_ref())
+        .await?;
+
+        Ok(rows)
+    }
+
     pub async fn update_status(
         &self,
         id: Uuid,
@@ -5,7 +5,7 @@ use axum::{
 
 use crate::handlers::{
     generate::{generate_content, AppState},
-    metrics::get_metrics,
+    metrics::{get_generation_metrics, get_metrics},
     queue::{delete_content, get_content, list_queue, update_status},
     templates::list_templates,
 };
@@ -17,6 +17,7 @@ pub fn create_router(state: AppState) -> Router {
         .route("/api/content/queue/:id", get(get_content))
         .route("/api/content/queue/:id", put(update_status))
         .route("/api/content/queue/:id", delete(delete_content))
+        .route("/api/content/metrics", get(get_generation_metrics))
         .route("/api/content/metrics/:id", get(get_metrics))
         .route("/api/content/templates", get(list_templates))
         .with_state(state)
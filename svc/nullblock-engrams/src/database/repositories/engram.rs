@@ -3,8 +3,8 @@ use uuid::Uuid;
 
 use crate::error::{AppError, AppResult};
 use crate::models::{
-    CreateEngramRequest, Engram, EngramHistory, ForkEngramRequest, SearchEngramsRequest,
-    UpdateEngramRequest,
+    CreateEngramRequest, Engram, EngramHistory, ForkEngramRequest, ImportPlan,
+    SearchEngramsRequest, UpdateEngramRequest,
 };
 
 pub struct EngramRepository {
@@ -175,6 +175,10 @@ impl EngramRepository {
             param_count += 1;
             conditions.push(format!("tags && ${}", param_count));
         }
+        if req.created_after.is_some() {
+            param_count += 1;
+            conditions.push(format!("created_at >= ${}", param_count));
+        }
 
         let where_clause = conditions.join(" AND ");
 
@@ -199,6 +203,9 @@ impl EngramRepository {
         if let Some(ref tags) = req.tags {
             count_builder = count_builder.bind(tags);
         }
+        if let Some(created_after) = req.created_after {
+            count_builder = count_builder.bind(created_after);
+        }
 
         let total = count_builder.fetch_one(&self.pool).await?;
 
@@ -227,6 +234,9 @@ impl EngramRepository {
         if let Some(ref tags) = req.tags {
             builder = builder.bind(tags);
         }
+        if let Some(created_after) = req.created_after {
+            builder = builder.bind(created_after);
+        }
 
         let engrams = builder.fetch_all(&self.pool).await?;
 
@@ -311,6 +321,108 @@ impl EngramRepository {
         Ok((engrams, total))
     }
 
+    /// Engrams an import could collide with: same wallet, or same id.
+    pub async fn get_import_candidates(
+        &self,
+        wallets: &[String],
+        ids: &[Uuid],
+    ) -> AppResult<Vec<Engram>> {
+        let engrams = sqlx::query_as::<_, Engram>(
+            r#"
+            SELECT * FROM engrams
+            WHERE wallet_address = ANY($1) OR id = ANY($2)
+            "#,
+        )
+        .bind(wallets)
+        .bind(ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(engrams)
+    }
+
+    /// Applies an import plan in one transaction, so a failed row leaves nothing behind.
+    pub async fn apply_import(&self, plan: &ImportPlan) -> AppResult<()> {
+        let mut tx = self.pool.begin().await?;
+
+        for engram in &plan.inserts {
+            sqlx::query(
+                r#"
+                INSERT INTO engrams (
+                    id, wallet_address, engram_type, key, tags, content, summary,
+                    version, parent_id, lineage_root_id, is_public, is_mintable,
+                    price_mon, royalty_percent, priority, ttl_seconds, created_by,
+                    created_at, updated_at, accessed_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20)
+                "#,
+            )
+            .bind(engram.id)
+            .bind(&engram.wallet_address)
+            .bind(&engram.engram_type)
+            .bind(&engram.key)
+            .bind(&engram.tags)
+            .bind(&engram.content)
+            .bind(&engram.summary)
+            .bind(engram.version)
+            .bind(engram.parent_id)
+            .bind(engram.lineage_root_id)
+            .bind(engram.is_public)
+            .bind(engram.is_mintable)
+            .bind(engram.price_mon)
+            .bind(engram.royalty_percent)
+            .bind(engram.priority)
+            .bind(engram.ttl_seconds)
+            .bind(&engram.created_by)
+            .bind(engram.created_at)
+            .bind(engram.updated_at)
+            .bind(engram.accessed_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for engram in &plan.overwrites {
+            let result = sqlx::query(
+                r#"
+                UPDATE engrams
+                SET engram_type = $2, tags = $3, content = $4, summary = $5,
+                    parent_id = $6, lineage_root_id = $7, is_public = $8, is_mintable = $9,
+                    price_mon = $10, royalty_percent = $11, priority = $12, ttl_seconds = $13,
+                    created_by = $14, updated_at = NOW()
+                WHERE id = $1 AND wallet_address = $15
+                "#,
+            )
+            .bind(engram.id)
+            .bind(&engram.engram_type)
+            .bind(&engram.tags)
+            .bind(&engram.content)
+            .bind(&engram.summary)
+            .bind(engram.parent_id)
+            .bind(engram.lineage_root_id)
+            .bind(engram.is_public)
+            .bind(engram.is_mintable)
+            .bind(engram.price_mon)
+            .bind(engram.royalty_percent)
+            .bind(engram.priority)
+            .bind(engram.ttl_seconds)
+            .bind(&engram.created_by)
+            .bind(&engram.wallet_address)
+            .execute(&mut *tx)
+            .await?;
+
+            // Never touch another wallet's engram; dropping `tx` rolls back the inserts
+            if result.rows_affected() != 1 {
+                return Err(AppError::Conflict(format!(
+                    "Engram '{}' v{} not found for wallet {}",
+                    engram.key, engram.version, engram.wallet_address
+                )));
+            }
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
     pub async fn get_history(&self, engram_id: Uuid) -> AppResult<Vec<EngramHistory>> {
         let history = sqlx::query_as::<_, EngramHistory>(
             r#"
@@ -326,3 +438,128 @@ impl EngramRepository {
         Ok(history)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn create_request(wallet: &str, key: &str) -> CreateEngramRequest {
+        CreateEngramRequest {
+            wallet_address: wallet.to_string(),
+            engram_type: "strategy".to_string(),
+            key: key.to_string(),
+            content: json!({ "threshold": 0.5 }),
+            tags: vec!["curve".to_string()],
+            summary: None,
+            priority: 0,
+            ttl_seconds: None,
+            created_by: None,
+        }
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    #[ignore = "needs DATABASE_URL pointing at a Postgres server"]
+    async fn test_import_candidates_match_wallet_or_id(pool: PgPool) {
+        let repo = EngramRepository::new(pool);
+        let mine = repo.create(&create_request("0xabc", "base")).await.unwrap();
+        let theirs = repo.create(&create_request("0xdef", "base")).await.unwrap();
+        repo.create(&create_request("0x123", "other"))
+            .await
+            .unwrap();
+
+        let mut found: Vec<Uuid> = repo
+            .get_import_candidates(&["0xabc".to_string()], &[theirs.id])
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.id)
+            .collect();
+        found.sort();
+        let mut expected = vec![mine.id, theirs.id];
+        expected.sort();
+        assert_eq!(found, expected);
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    #[ignore = "needs DATABASE_URL pointing at a Postgres server"]
+    async fn test_search_filters_by_created_after(pool: PgPool) {
+        let repo = EngramRepository::new(pool.clone());
+        let old = repo.create(&create_request("0xabc", "old")).await.unwrap();
+        let recent = repo
+            .create(&create_request("0xabc", "recent"))
+            .await
+            .unwrap();
+        sqlx::query("UPDATE engrams SET created_at = NOW() - INTERVAL '3 days' WHERE id = $1")
+            .bind(old.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let (found, total) = repo
+            .search(&SearchEngramsRequest {
+                wallet_address: Some("0xabc".to_string()),
+                engram_type: None,
+                tags: Some(vec!["curve".to_string()]),
+                is_public: None,
+                created_after: Some(chrono::Utc::now() - chrono::Duration::days(1)),
+                limit: None,
+                offset: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(total, 1);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, recent.id);
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    #[ignore = "needs DATABASE_URL pointing at a Postgres server"]
+    async fn test_apply_import_overwrite_is_scoped_to_wallet(pool: PgPool) {
+        let repo = EngramRepository::new(pool);
+        let theirs = repo.create(&create_request("0xdef", "base")).await.unwrap();
+        let mine = repo.create(&create_request("0xabc", "base")).await.unwrap();
+
+        let fresh = Engram {
+            id: Uuid::new_v4(),
+            key: "fresh".to_string(),
+            ..mine.clone()
+        };
+        // Another wallet's id, relabelled as ours
+        let hijack = Engram {
+            wallet_address: "0xabc".to_string(),
+            content: json!({ "threshold": 0.9 }),
+            ..theirs.clone()
+        };
+        let plan = ImportPlan {
+            inserts: vec![fresh.clone()],
+            overwrites: vec![hijack],
+            ..ImportPlan::default()
+        };
+
+        assert!(matches!(
+            repo.apply_import(&plan).await,
+            Err(AppError::Conflict(_))
+        ));
+        let untouched = repo.get_by_id(theirs.id).await.unwrap();
+        assert_eq!(untouched.content, theirs.content);
+        assert_eq!(untouched.wallet_address, "0xdef");
+        // The whole plan rolled back, inserts included
+        assert!(matches!(
+            repo.get_by_id(fresh.id).await,
+            Err(AppError::NotFound(_))
+        ));
+
+        let own = ImportPlan {
+            overwrites: vec![Engram {
+                content: json!({ "threshold": 0.9 }),
+                ..mine.clone()
+            }],
+            ..ImportPlan::default()
+        };
+        repo.apply_import(&own).await.unwrap();
+        let updated = repo.get_by_id(mine.id).await.unwrap();
+        assert_eq!(updated.content, json!({ "threshold": 0.9 }));
+    }
+}
@@ -8,8 +8,9 @@ use uuid::Uuid;
 
 use crate::error::AppResult;
 use crate::models::{
-    CreateEngramRequest, EngramResponse, EngramsListResponse, ForkEngramRequest,
-    SearchEngramsRequest, UpdateEngramRequest,
+    plan_import, CreateEngramRequest, EngramExport, EngramResponse, EngramsListResponse,
+    ForkEngramRequest, ImportEngramsRequest, ImportEngramsResponse, SearchEngramsRequest,
+    UpdateEngramRequest,
 };
 use crate::server::AppState;
 
@@ -19,6 +20,11 @@ pub struct ListQuery {
     pub offset: Option<i64>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ExportQuery {
+    pub wallet: String,
+}
+
 pub async fn create_engram(
     State(state): State<AppState>,
     Json(req): Json<CreateEngramRequest>,
@@ -180,3 +186,172 @@ pub async fn publish_engram(
         error: None,
     }))
 }
+
+pub async fn export_engrams(
+    State(state): State<AppState>,
+    Query(query): Query<ExportQuery>,
+) -> AppResult<Json<EngramExport>> {
+    let engrams = state.engram_repo.get_by_wallet(&query.wallet).await?;
+    let export = EngramExport::new(&query.wallet, engrams);
+
+    tracing::info!(
+        "Exported {} engrams for wallet={} ({} secret fields redacted)",
+        export.engrams.len(),
+        query.wallet,
+        export.redacted_fields
+    );
+
+    Ok(Json(export))
+}
+
+pub async fn import_engrams(
+    State(state): State<AppState>,
+    Json(req): Json<ImportEngramsRequest>,
+) -> AppResult<Json<ImportEngramsResponse>> {
+    let mut wallets: Vec<String> = req
+        .export
+        .engrams
+        .iter()
+        .map(|e| e.wallet_address.clone())
+        .collect();
+    wallets.extend(req.target_wallet.clone());
+    wallets.sort();
+    wallets.dedup();
+    let ids: Vec<Uuid> = req.export.engrams.iter().map(|e| e.id).collect();
+
+    let existing = state
+        .engram_repo
+        .get_import_candidates(&wallets, &ids)
+        .await?;
+    let plan = plan_import(&req, &existing)?;
+    state.engram_repo.apply_import(&plan).await?;
+
+    tracing::info!(
+        "Imported engrams: inserted={}, overwritten={}, skipped={}",
+        plan.inserts.len(),
+        plan.overwrites.len(),
+        plan.skipped.len()
+    );
+
+    Ok(Json(ImportEngramsResponse {
+        success: true,
+        imported: plan.inserts.len(),
+        overwritten: plan.overwrites.len(),
+        skipped: plan.skipped,
+        id_map: plan.id_map,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use sqlx::PgPool;
+
+    use crate::config::Config;
+    use crate::database::repositories::EngramRepository;
+    use crate::error::AppError;
+    use crate::models::{ImportConflictMode, ImportIdMode};
+
+    fn state_on(pool: PgPool) -> AppState {
+        AppState {
+            config: Config {
+                service_name: "nullblock-engrams".to_string(),
+                database_url: String::new(),
+                erebus_url: String::new(),
+            },
+            engram_repo: Arc::new(EngramRepository::new(pool.clone())),
+            db_pool: pool,
+        }
+    }
+
+    fn create_request(key: &str, content: serde_json::Value) -> CreateEngramRequest {
+        CreateEngramRequest {
+            wallet_address: "0xabc".to_string(),
+            engram_type: "strategy".to_string(),
+            key: key.to_string(),
+            content,
+            tags: vec!["curve".to_string()],
+            summary: None,
+            priority: 0,
+            ttl_seconds: None,
+            created_by: None,
+        }
+    }
+
+    fn import_request(
+        export: EngramExport,
+        id_mode: ImportIdMode,
+        target_wallet: &str,
+    ) -> ImportEngramsRequest {
+        ImportEngramsRequest {
+            export,
+            id_mode,
+            on_conflict: ImportConflictMode::Skip,
+            target_wallet: Some(target_wallet.to_string()),
+        }
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    #[ignore = "needs DATABASE_URL pointing at a Postgres server"]
+    async fn test_export_then_import_into_another_wallet(pool: PgPool) {
+        let state = state_on(pool);
+        for (key, content) in [
+            ("base", json!({ "threshold": 0.5, "api_key": "sk-live" })),
+            ("tuned", json!({ "threshold": 0.7 })),
+        ] {
+            let Json(created) =
+                create_engram(State(state.clone()), Json(create_request(key, content)))
+                    .await
+                    .unwrap();
+            assert!(created.success);
+        }
+
+        let Json(export) = export_engrams(
+            State(state.clone()),
+            Query(ExportQuery {
+                wallet: "0xabc".to_string(),
+            }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(export.engrams.len(), 2);
+        assert_eq!(export.redacted_fields, 1);
+
+        let Json(imported) = import_engrams(
+            State(state.clone()),
+            Json(import_request(export.clone(), ImportIdMode::Remap, "0xdef")),
+        )
+        .await
+        .unwrap();
+        assert_eq!(imported.imported, 2);
+        let copies = state.engram_repo.get_by_wallet("0xdef").await.unwrap();
+        assert_eq!(copies.len(), 2);
+        let base = copies.iter().find(|e| e.key == "base").unwrap();
+        assert_eq!(base.content, json!({ "threshold": 0.5 }));
+
+        // Same slots again: skipped, nothing added
+        let Json(again) = import_engrams(
+            State(state.clone()),
+            Json(import_request(export.clone(), ImportIdMode::Remap, "0xdef")),
+        )
+        .await
+        .unwrap();
+        assert_eq!((again.imported, again.skipped.len()), (0, 2));
+
+        // Preserved ids still belong to the source wallet
+        let result = import_engrams(
+            State(state.clone()),
+            Json(import_request(export, ImportIdMode::Preserve, "0x123")),
+        )
+        .await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+        assert!(state
+            .engram_repo
+            .get_by_wallet("0x123")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -91,6 +91,8 @@ fn create_router(state: server::AppState) -> Router {
         )
         // Search and operations
         .route("/engrams/search", post(engrams::search_engrams))
+        .route("/engrams/export", get(engrams::export_engrams))
+        .route("/engrams/import", post(engrams::import_engrams))
         .route("/engrams/:id/fork", post(engrams::fork_engram))
         .route("/engrams/:id/publish", post(engrams::publish_engram))
         // Add state
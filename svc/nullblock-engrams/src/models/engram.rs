@@ -90,6 +90,8 @@ pub struct SearchEngramsRequest {
     pub engram_type: Option<String>,
     pub tags: Option<Vec<String>>,
     pub is_public: Option<bool>,
+    /// Only engrams created at or after this time
+    pub created_after: Option<DateTime<Utc>>,
     pub limit: Option<i64>,
     pub offset: Option<i64>,
 }
@@ -0,0 +1,494 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use super::engram::{Engram, EngramType};
+use crate::error::{AppError, AppResult};
+
+pub const EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// Content fields whose names contain one of these are dropped from exports.
+const SECRET_FIELD_MARKERS: &[&str] = &[
+    "secret",
+    "password",
+    "private_key",
+    "api_key",
+    "apikey",
+    "mnemonic",
+    "seed_phrase",
+    "access_token",
+    "refresh_token",
+];
+
+/// Portable engram: everything but the NFT binding and access time, which only
+/// mean something in the environment the engram came from.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExportedEngram {
+    pub id: Uuid,
+    pub wallet_address: String,
+    pub engram_type: String,
+    pub key: String,
+    pub tags: Vec<String>,
+    pub content: serde_json::Value,
+    pub summary: Option<String>,
+    pub version: i32,
+    pub parent_id: Option<Uuid>,
+    pub lineage_root_id: Option<Uuid>,
+    pub is_public: bool,
+    pub is_mintable: bool,
+    pub price_mon: Option<rust_decimal::Decimal>,
+    pub royalty_percent: Option<i32>,
+    pub priority: i32,
+    pub ttl_seconds: Option<i32>,
+    pub created_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EngramExport {
+    pub format_version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub wallet_address: String,
+    pub engrams: Vec<ExportedEngram>,
+    /// Number of secret-looking content fields left out of the dump
+    #[serde(default)]
+    pub redacted_fields: usize,
+}
+
+impl EngramExport {
+    pub fn new(wallet_address: &str, engrams: Vec<Engram>) -> Self {
+        let mut redacted_fields = 0;
+        let engrams = engrams
+            .into_iter()
+            .map(|engram| {
+                let mut content = engram.content;
+                redacted_fields += redact_secrets(&mut content);
+                ExportedEngram {
+                    id: engram.id,
+                    wallet_address: engram.wallet_address,
+                    engram_type: engram.engram_type,
+                    key: engram.key,
+                    tags: engram.tags,
+                    content,
+                    summary: engram.summary,
+                    version: engram.version,
+                    parent_id: engram.parent_id,
+                    lineage_root_id: engram.lineage_root_id,
+                    is_public: engram.is_public,
+                    is_mintable: engram.is_mintable,
+                    price_mon: engram.price_mon,
+                    royalty_percent: engram.royalty_percent,
+                    priority: engram.priority,
+                    ttl_seconds: engram.ttl_seconds,
+                    created_by: engram.created_by,
+                    created_at: engram.created_at,
+                    updated_at: engram.updated_at,
+                }
+            })
+            .collect();
+
+        Self {
+            format_version: EXPORT_FORMAT_VERSION,
+            exported_at: Utc::now(),
+            wallet_address: wallet_address.to_string(),
+            engrams,
+            redacted_fields,
+        }
+    }
+}
+
+/// Removes secret-looking fields from JSON objects at any depth, returning how many.
+pub fn redact_secrets(value: &mut serde_json::Value) -> usize {
+    match value {
+        serde_json::Value::Object(map) => {
+            let before = map.len();
+            map.retain(|field, _| {
+                let field = field.to_lowercase();
+                !SECRET_FIELD_MARKERS
+                    .iter()
+                    .any(|marker| field.contains(marker))
+            });
+            let removed = before - map.len();
+            removed + map.values_mut().map(redact_secrets).sum::<usize>()
+        }
+        serde_json::Value::Array(items) => items.iter_mut().map(redact_secrets).sum(),
+        _ => 0,
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportIdMode {
+    /// Assign fresh ids, rewriting parent and lineage links inside the dump
+    #[default]
+    Remap,
+    /// Keep the exported ids
+    Preserve,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportConflictMode {
+    /// Keep the existing engram and leave the imported one out
+    #[default]
+    Skip,
+    /// Replace the existing engram's content and settings with the imported one
+    Overwrite,
+    /// Reject the whole import
+    Fail,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportEngramsRequest {
+    pub export: EngramExport,
+    #[serde(default)]
+    pub id_mode: ImportIdMode,
+    #[serde(default)]
+    pub on_conflict: ImportConflictMode,
+    /// Import into this wallet instead of the exported one
+    pub target_wallet: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ImportPlan {
+    /// New rows, parents ahead of their children
+    pub inserts: Vec<Engram>,
+    /// Existing rows to replace, keyed by their current id
+    pub overwrites: Vec<Engram>,
+    /// `wallet/key@vN` of engrams left out as conflicts
+    pub skipped: Vec<String>,
+    /// Exported id → id in this store
+    pub id_map: HashMap<Uuid, Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportEngramsResponse {
+    pub success: bool,
+    pub imported: usize,
+    pub overwritten: usize,
+    pub skipped: Vec<String>,
+    pub id_map: HashMap<Uuid, Uuid>,
+}
+
+/// Works out what an import does against the engrams already in the store that
+/// share a wallet or (in preserve mode) an id with the dump. A conflict is an
+/// existing engram with the same wallet, key and version, or the same id.
+pub fn plan_import(req: &ImportEngramsRequest, existing: &[Engram]) -> AppResult<ImportPlan> {
+    if req.export.format_version > EXPORT_FORMAT_VERSION {
+        return Err(AppError::BadRequest(format!(
+            "Unsupported export format version {} (max {})",
+            req.export.format_version, EXPORT_FORMAT_VERSION
+        )));
+    }
+
+    let by_slot: HashMap<(&str, &str, i32), &Engram> = existing
+        .iter()
+        .map(|e| ((e.wallet_address.as_str(), e.key.as_str(), e.version), e))
+        .collect();
+    let by_id: HashMap<Uuid, &Engram> = existing.iter().map(|e| (e.id, e)).collect();
+
+    let mut plan = ImportPlan::default();
+    let mut conflicts: HashMap<Uuid, &Engram> = HashMap::new();
+
+    for exported in &req.export.engrams {
+        if EngramType::from_str(&exported.engram_type).is_none() {
+            return Err(AppError::BadRequest(format!(
+                "Invalid engram type '{}' for key '{}'",
+                exported.engram_type, exported.key
+            )));
+        }
+
+        let wallet = req
+            .target_wallet
+            .as_deref()
+            .unwrap_or(&exported.wallet_address);
+        let conflict = by_slot
+            .get(&(wallet, exported.key.as_str(), exported.version))
+            .or_else(|| match req.id_mode {
+                ImportIdMode::Preserve => by_id.get(&exported.id),
+                ImportIdMode::Remap => None,
+            });
+
+        if let Some(existing) = conflict {
+            // A preserved id held by another wallet can be neither reused nor overwritten
+            if existing.wallet_address != wallet {
+                return Err(AppError::Conflict(format!(
+                    "Engram id {} for key '{}' belongs to another wallet",
+                    exported.id, exported.key
+                )));
+            }
+            if req.on_conflict == ImportConflictMode::Fail {
+                return Err(AppError::Conflict(format!(
+                    "Engram '{}' v{} already exists for wallet {}",
+                    exported.key, exported.version, wallet
+                )));
+            }
+            conflicts.insert(exported.id, existing);
+            plan.id_map.insert(exported.id, existing.id);
+        } else {
+            let id = match req.id_mode {
+                ImportIdMode::Remap => Uuid::new_v4(),
+                ImportIdMode::Preserve => exported.id,
+            };
+            plan.id_map.insert(exported.id, id);
+        }
+    }
+
+    let now = Utc::now();
+    for exported in import_order(&req.export.engrams) {
+        let id = plan.id_map[&exported.id];
+        let wallet = req
+            .target_wallet
+            .clone()
+            .unwrap_or_else(|| exported.wallet_address.clone());
+        // Parents outside the dump are dropped unless preserved ids still resolve here
+        let parent_id = exported.parent_id.and_then(|parent| {
+            plan.id_map.get(&parent).copied().or_else(|| {
+                (req.id_mode == ImportIdMode::Preserve && by_id.contains_key(&parent))
+                    .then_some(parent)
+            })
+        });
+        let lineage_root_id = exported
+            .lineage_root_id
+            .map(|root| plan.id_map.get(&root).copied().unwrap_or(root));
+
+        let engram = Engram {
+            id,
+            wallet_address: wallet,
+            engram_type: exported.engram_type.clone(),
+            key: exported.key.clone(),
+            tags: exported.tags.clone(),
+            content: exported.content.clone(),
+            summary: exported.summary.clone(),
+            version: exported.version,
+            parent_id,
+            lineage_root_id,
+            is_public: exported.is_public,
+            is_mintable: exported.is_mintable,
+            nft_token_id: None,
+            price_mon: exported.price_mon,
+            royalty_percent: exported.royalty_percent,
+            priority: exported.priority,
+            ttl_seconds: exported.ttl_seconds,
+            created_by: exported.created_by.clone(),
+            created_at: exported.created_at,
+            updated_at: exported.updated_at,
+            accessed_at: now,
+        };
+
+        match conflicts.get(&exported.id) {
+            None => plan.inserts.push(engram),
+            Some(existing) => match req.on_conflict {
+                ImportConflictMode::Overwrite => plan.overwrites.push(Engram {
+                    id: existing.id,
+                    nft_token_id: existing.nft_token_id.clone(),
+                    created_at: existing.created_at,
+                    ..engram
+                }),
+                _ => plan.skipped.push(format!(
+                    "{}/{}@v{}",
+                    engram.wallet_address, engram.key, engram.version
+                )),
+            },
+        }
+    }
+
+    Ok(plan)
+}
+
+/// Orders engrams so a parent inside the dump is always inserted before its children.
+fn import_order(engrams: &[ExportedEngram]) -> Vec<&ExportedEngram> {
+    let in_dump: HashSet<Uuid> = engrams.iter().map(|e| e.id).collect();
+    let mut placed: HashSet<Uuid> = HashSet::new();
+    let mut ordered = Vec::with_capacity(engrams.len());
+    let mut remaining: Vec<&ExportedEngram> = engrams.iter().collect();
+
+    while !remaining.is_empty() {
+        let (ready, blocked): (Vec<_>, Vec<_>) = remaining.into_iter().partition(|e| {
+            e.parent_id
+                .is_none_or(|parent| !in_dump.contains(&parent) || placed.contains(&parent))
+        });
+        if ready.is_empty() {
+            // A parent cycle can't come from this service; keep the dump order
+            ordered.extend(blocked);
+            break;
+        }
+        placed.extend(ready.iter().map(|e| e.id));
+        ordered.extend(ready);
+        remaining = blocked;
+    }
+
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn engram(wallet: &str, key: &str, tags: &[&str], is_public: bool) -> Engram {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+        Engram {
+            id,
+            wallet_address: wallet.to_string(),
+            engram_type: "strategy".to_string(),
+            key: key.to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            content: json!({ "threshold": 0.5 }),
+            summary: Some(format!("{} summary", key)),
+            version: 1,
+            parent_id: None,
+            lineage_root_id: Some(id),
+            is_public,
+            is_mintable: false,
+            nft_token_id: None,
+            price_mon: None,
+            royalty_percent: Some(5),
+            priority: 1,
+            ttl_seconds: None,
+            created_by: Some("test".to_string()),
+            created_at: now,
+            updated_at: now,
+            accessed_at: now,
+        }
+    }
+
+    fn round_trip(export: &EngramExport) -> EngramExport {
+        serde_json::from_str(&serde_json::to_string(export).unwrap()).unwrap()
+    }
+
+    fn import_request(export: EngramExport, id_mode: ImportIdMode) -> ImportEngramsRequest {
+        ImportEngramsRequest {
+            export,
+            id_mode,
+            on_conflict: ImportConflictMode::Skip,
+            target_wallet: None,
+        }
+    }
+
+    #[test]
+    fn test_export_import_round_trip_into_fresh_store() {
+        let wallet = "0xabc";
+        let parent = engram(wallet, "base", &["curve", "sniper"], true);
+        let mut child = engram(wallet, "base-tuned", &["curve"], false);
+        child.parent_id = Some(parent.id);
+        child.lineage_root_id = Some(parent.id);
+        let original = vec![child.clone(), parent.clone()];
+
+        let export = round_trip(&EngramExport::new(wallet, original.clone()));
+        let plan = plan_import(&import_request(export.clone(), ImportIdMode::Remap), &[]).unwrap();
+
+        assert_eq!(plan.inserts.len(), 2);
+        assert!(plan.overwrites.is_empty() && plan.skipped.is_empty());
+        // Parent goes in first so the child's foreign key resolves
+        assert_eq!(plan.inserts[0].key, "base");
+        for source in &original {
+            let imported = plan.inserts.iter().find(|e| e.key == source.key).unwrap();
+            assert_ne!(imported.id, source.id);
+            assert_eq!(plan.id_map[&source.id], imported.id);
+            assert_eq!(imported.wallet_address, source.wallet_address);
+            assert_eq!(imported.tags, source.tags);
+            assert_eq!(imported.is_public, source.is_public);
+            assert_eq!(imported.content, source.content);
+            assert_eq!(imported.summary, source.summary);
+            assert_eq!(imported.version, source.version);
+        }
+        let new_parent = plan.id_map[&parent.id];
+        let new_child = plan.inserts.iter().find(|e| e.key == "base-tuned").unwrap();
+        assert_eq!(new_child.parent_id, Some(new_parent));
+        assert_eq!(new_child.lineage_root_id, Some(new_parent));
+
+        let preserved = plan_import(&import_request(export, ImportIdMode::Preserve), &[]).unwrap();
+        for source in &original {
+            let imported = preserved
+                .inserts
+                .iter()
+                .find(|e| e.id == source.id)
+                .unwrap();
+            assert_eq!(imported.parent_id, source.parent_id);
+            assert_eq!(imported.tags, source.tags);
+            assert_eq!(imported.is_public, source.is_public);
+        }
+    }
+
+    #[test]
+    fn test_export_excludes_secrets() {
+        let mut source = engram("0xabc", "exchange", &[], false);
+        source.content = json!({
+            "venue": "jupiter",
+            "api_key": "sk-live",
+            "auth": { "Private_Key": "5J...", "label": "main" },
+            "accounts": [{ "password": "hunter2", "name": "alt" }]
+        });
+
+        let export = EngramExport::new("0xabc", vec![source]);
+
+        assert_eq!(export.redacted_fields, 3);
+        assert_eq!(
+            export.engrams[0].content,
+            json!({
+                "venue": "jupiter",
+                "auth": { "label": "main" },
+                "accounts": [{ "name": "alt" }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_import_conflict_modes() {
+        let wallet = "0xabc";
+        let existing = engram(wallet, "base", &["old"], false);
+        let mut incoming = engram(wallet, "base", &["new"], true);
+        incoming.content = json!({ "threshold": 0.9 });
+        let export = EngramExport::new(wallet, vec![incoming]);
+
+        let mut req = import_request(export, ImportIdMode::Remap);
+        let skipped = plan_import(&req, std::slice::from_ref(&existing)).unwrap();
+        assert!(skipped.inserts.is_empty() && skipped.overwrites.is_empty());
+        assert_eq!(skipped.skipped, vec!["0xabc/base@v1".to_string()]);
+
+        req.on_conflict = ImportConflictMode::Overwrite;
+        let overwritten = plan_import(&req, std::slice::from_ref(&existing)).unwrap();
+        assert_eq!(overwritten.overwrites.len(), 1);
+        assert_eq!(overwritten.overwrites[0].id, existing.id);
+        assert_eq!(overwritten.overwrites[0].tags, vec!["new".to_string()]);
+        assert!(overwritten.overwrites[0].is_public);
+
+        req.on_conflict = ImportConflictMode::Fail;
+        assert!(matches!(
+            plan_import(&req, std::slice::from_ref(&existing)),
+            Err(AppError::Conflict(_))
+        ));
+
+        // A different target wallet has no conflict
+        req.target_wallet = Some("0xdef".to_string());
+        let retargeted = plan_import(&req, std::slice::from_ref(&existing)).unwrap();
+        assert_eq!(retargeted.inserts[0].wallet_address, "0xdef");
+    }
+
+    #[test]
+    fn test_preserved_id_from_another_wallet_is_rejected() {
+        let theirs = engram("0xdef", "base", &[], false);
+        let mut incoming = engram("0xabc", "mine", &[], false);
+        incoming.id = theirs.id;
+        let export = EngramExport::new("0xabc", vec![incoming]);
+
+        let mut req = import_request(export, ImportIdMode::Preserve);
+        for mode in [ImportConflictMode::Skip, ImportConflictMode::Overwrite] {
+            req.on_conflict = mode;
+            assert!(matches!(
+                plan_import(&req, std::slice::from_ref(&theirs)),
+                Err(AppError::Conflict(_))
+            ));
+        }
+
+        // Remapped ids never collide with another wallet's engram
+        req.id_mode = ImportIdMode::Remap;
+        let plan = plan_import(&req, std::slice::from_ref(&theirs)).unwrap();
+        assert_eq!(plan.inserts.len(), 1);
+        assert_ne!(plan.inserts[0].id, theirs.id);
+    }
+}
@@ -1,3 +1,5 @@
 mod engram;
+mod export;
 
 pub use engram::*;
+pub use export::*;
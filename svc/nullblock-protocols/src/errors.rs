@@ -1,5 +1,5 @@
 use axum::{
-    http::StatusCode,
+    http::{header, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
@@ -10,11 +10,17 @@ pub enum ProtocolError {
     InvalidRequest(String),
     TaskNotFound(String),
     AuthenticationRequired,
+    RateLimited { retry_after_secs: u64 },
     InternalError(String),
 }
 
 impl IntoResponse for ProtocolError {
     fn into_response(self) -> Response {
+        let retry_after = match &self {
+            ProtocolError::RateLimited { retry_after_secs } => Some(*retry_after_secs),
+            _ => None,
+        };
+
         let (status, error_message) = match self {
             ProtocolError::InvalidRequest(msg) => (StatusCode::BAD_REQUEST, msg),
             ProtocolError::TaskNotFound(msg) => (StatusCode::NOT_FOUND, msg),
@@ -22,6 +28,10 @@ impl IntoResponse for ProtocolError {
                 StatusCode::UNAUTHORIZED,
                 "Authentication required".to_string(),
             ),
+            ProtocolError::RateLimited { .. } => (
+                StatusCode::TOO_MANY_REQUESTS,
+                "Rate limit exceeded".to_string(),
+            ),
             ProtocolError::InternalError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
         };
 
@@ -30,7 +40,13 @@ impl IntoResponse for ProtocolError {
             "status": status.as_u16()
         }));
 
-        (status, body).into_response()
+        let mut response = (status, body).into_response();
+        if let Some(secs) = retry_after {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(secs));
+        }
+        response
     }
 }
 
@@ -40,6 +56,9 @@ impl std::fmt::Display for ProtocolError {
             ProtocolError::InvalidRequest(msg) => write!(f, "Invalid request: {}", msg),
             ProtocolError::TaskNotFound(msg) => write!(f, "Task not found: {}", msg),
             ProtocolError::AuthenticationRequired => write!(f, "Authentication required"),
+            ProtocolError::RateLimited { retry_after_secs } => {
+                write!(f, "Rate limit exceeded, retry after {}s", retry_after_secs)
+            }
             ProtocolError::InternalError(msg) => write!(f, "Internal error: {}", msg),
         }
     }
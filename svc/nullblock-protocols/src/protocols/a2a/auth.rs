@@ -76,3 +76,41 @@ pub async fn auth_middleware(
 
     Ok(next.run(request).await)
 }
+
+/// Who sent a task-creating request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Caller {
+    /// A credential that names the caller: the service token, or a key from `API_KEYS`.
+    Verified(String),
+    /// A credential was accepted but says nothing about who sent it (any API key when
+    /// `API_KEYS` is empty, or a bearer token), so it can't key a rate limit bucket.
+    Unverified,
+}
+
+/// Identifies the caller of a task-creating request. Unlike `auth_middleware`, this
+/// always requires a credential, regardless of `REQUIRE_A2A_AUTH`; `None` means it was
+/// missing or invalid.
+pub fn identify_caller(headers: &HeaderMap, config: &AuthConfig) -> Option<Caller> {
+    if let Some(service_token) = extract_service_token(headers) {
+        return validate_service_token(&service_token)
+            .then(|| Caller::Verified("service".to_string()));
+    }
+
+    if let Some(api_key) = extract_api_key(headers) {
+        if config.api_keys.is_empty() {
+            return Some(Caller::Unverified);
+        }
+        return validate_api_key(&api_key, config)
+            .then(|| Caller::Verified(format!("api_key:{}", api_key)));
+    }
+
+    if config.enable_bearer_tokens {
+        if let Some(bearer_token) = extract_bearer_token(headers) {
+            return validate_bearer_token(&bearer_token)
+                .ok()
+                .map(|_| Caller::Unverified);
+        }
+    }
+
+    None
+}
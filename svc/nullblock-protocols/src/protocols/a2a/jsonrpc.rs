@@ -1,11 +1,20 @@
-use axum::{extract::State, Json};
+use axum::{
+    extract::{ConnectInfo, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
 use serde_json::Value;
+use std::net::SocketAddr;
 
+use crate::auth::AuthConfig;
+use crate::errors::ProtocolError;
 use crate::protocols::a2a::handlers::{
     cancel_task, delete_push_notification_config, get_agent_card, get_push_notification_config,
     get_task, list_push_notification_configs, list_tasks, resubscribe_task, send_message,
     send_streaming_message, set_push_notification_config,
 };
+use crate::protocols::a2a::rate_limit::authorize_task_creation;
 use crate::protocols::a2a::types::{
     JsonRpcError, JsonRpcRequest, JsonRpcResponse, MessageSendRequest, MessageStreamRequest,
     PushNotificationConfigRequest, TaskCancelRequest, TaskListRequest,
@@ -14,8 +23,10 @@ use crate::server::AppState;
 
 pub async fn handle_jsonrpc(
     State(state): State<AppState>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    headers: HeaderMap,
     Json(request): Json<JsonRpcRequest>,
-) -> Json<JsonRpcResponse> {
+) -> Response {
     if request.jsonrpc != "2.0" {
         return Json(JsonRpcResponse {
             jsonrpc: "2.0".to_string(),
@@ -26,7 +37,19 @@ pub async fn handle_jsonrpc(
                 data: None,
             }),
             id: request.id,
-        });
+        })
+        .into_response();
+    }
+
+    if matches!(request.method.as_str(), "message/send" | "message/stream") {
+        if let Err(e) = authorize_task_creation(
+            &state.task_rate_limiter,
+            &AuthConfig::default(),
+            &headers,
+            connect_info.map(|ConnectInfo(addr)| addr.ip()),
+        ) {
+            return task_creation_rejected(e, request.id);
+        }
     }
 
     let result = match request.method.as_str() {
@@ -54,14 +77,46 @@ pub async fn handle_jsonrpc(
             result: Some(result),
             error: None,
             id: request.id,
-        }),
+        })
+        .into_response(),
         Err(error) => Json(JsonRpcResponse {
             jsonrpc: "2.0".to_string(),
             result: None,
             error: Some(error),
             id: request.id,
+        })
+        .into_response(),
+    }
+}
+
+fn task_creation_rejected(error: ProtocolError, id: Option<Value>) -> Response {
+    let (status, code, retry_after) = match &error {
+        ProtocolError::RateLimited { retry_after_secs } => (
+            StatusCode::TOO_MANY_REQUESTS,
+            -32029,
+            Some(*retry_after_secs),
+        ),
+        _ => (StatusCode::UNAUTHORIZED, -32001, None),
+    };
+
+    let body = Json(JsonRpcResponse {
+        jsonrpc: "2.0".to_string(),
+        result: None,
+        error: Some(JsonRpcError {
+            code,
+            message: error.to_string(),
+            data: None,
         }),
+        id,
+    });
+
+    let mut response = (status, body).into_response();
+    if let Some(secs) = retry_after {
+        response
+            .headers_mut()
+            .insert(header::RETRY_AFTER, HeaderValue::from(secs));
     }
+    response
 }
 
 async fn handle_message_send(params: Option<Value>) -> Result<Value, JsonRpcError> {
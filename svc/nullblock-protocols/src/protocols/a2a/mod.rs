@@ -1,6 +1,7 @@
 pub mod auth;
 pub mod handlers;
 pub mod jsonrpc;
+pub mod rate_limit;
 pub mod routes;
 pub mod sse;
 pub mod types;
@@ -0,0 +1,242 @@
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::HeaderMap,
+    middleware::Next,
+    response::Response,
+};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+use super::auth::{identify_caller, Caller};
+use crate::auth::AuthConfig;
+use crate::errors::ProtocolError;
+
+pub const DEFAULT_TASK_RATE_LIMIT: u32 = 30;
+pub const DEFAULT_TASK_RATE_WINDOW_SECS: u64 = 60;
+const MAX_TRACKED_CALLERS: usize = 10_000;
+
+/// Fixed-window request counter keyed by caller identity.
+pub struct RateLimiter {
+    max_requests: u32,
+    window: Duration,
+    windows: Mutex<HashMap<String, (Instant, u32)>>,
+}
+
+impl RateLimiter {
+    pub fn new(max_requests: u32, window: Duration) -> Self {
+        Self {
+            max_requests: max_requests.max(1),
+            window,
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let max_requests = std::env::var("A2A_TASK_RATE_LIMIT")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_TASK_RATE_LIMIT);
+        let window_secs = std::env::var("A2A_TASK_RATE_WINDOW_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_TASK_RATE_WINDOW_SECS);
+
+        Self::new(max_requests, Duration::from_secs(window_secs))
+    }
+
+    /// Counts a request for `caller`, returning the time until the window resets
+    /// if the caller is over its limit.
+    pub fn check(&self, caller: &str) -> Result<(), Duration> {
+        let now = Instant::now();
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+
+        if windows.len() >= MAX_TRACKED_CALLERS && !windows.contains_key(caller) {
+            let window = self.window;
+            windows.retain(|_, (started, _)| now.duration_since(*started) < window);
+        }
+
+        let entry = windows.entry(caller.to_string()).or_insert((now, 0));
+        if now.duration_since(entry.0) >= self.window {
+            *entry = (now, 0);
+        }
+
+        if entry.1 >= self.max_requests {
+            return Err(self.window.saturating_sub(now.duration_since(entry.0)));
+        }
+
+        entry.1 += 1;
+        Ok(())
+    }
+}
+
+/// Authenticates and rate limits a task-creating request. Verified callers get their
+/// own bucket; unverified credentials share the bucket of the peer address they came
+/// from, so rotating made-up keys doesn't reset the limit.
+pub fn authorize_task_creation(
+    limiter: &RateLimiter,
+    config: &AuthConfig,
+    headers: &HeaderMap,
+    peer: Option<IpAddr>,
+) -> Result<String, ProtocolError> {
+    let bucket = match identify_caller(headers, config) {
+        Some(Caller::Verified(identity)) => identity,
+        Some(Caller::Unverified) => match peer {
+            Some(ip) => format!("ip:{}", ip),
+            None => "ip:unknown".to_string(),
+        },
+        None => return Err(ProtocolError::AuthenticationRequired),
+    };
+
+    limiter.check(&bucket).map_err(|retry_after| {
+        warn!("❌ A2A: Task creation rate limit exceeded");
+        ProtocolError::RateLimited {
+            retry_after_secs: retry_after.as_secs().max(1),
+        }
+    })?;
+
+    Ok(bucket)
+}
+
+pub fn peer_ip(request: &Request) -> Option<IpAddr> {
+    request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip())
+}
+
+pub async fn task_creation_middleware(
+    State(limiter): State<Arc<RateLimiter>>,
+    headers: HeaderMap,
+    request: Request,
+    next: Next,
+) -> Result<Response, ProtocolError> {
+    authorize_task_creation(
+        &limiter,
+        &AuthConfig::default(),
+        &headers,
+        peer_ip(&request),
+    )?;
+    Ok(next.run(request).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::StatusCode, middleware, routing::post, Router};
+    use std::collections::HashSet;
+    use tower::ServiceExt;
+
+    fn app(limiter: Arc<RateLimiter>) -> Router {
+        Router::new()
+            .route("/messages", post(|| async { "created" }))
+            .layer(middleware::from_fn_with_state(
+                limiter,
+                task_creation_middleware,
+            ))
+    }
+
+    fn create_request(api_key: Option<&str>, peer: [u8; 4]) -> Request {
+        let mut builder = Request::builder().method("POST").uri("/messages");
+        if let Some(key) = api_key {
+            builder = builder.header("x-api-key", key);
+        }
+        let mut request = builder.body(Body::empty()).unwrap();
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from((peer, 40_000))));
+        request
+    }
+
+    fn key_headers(api_key: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-api-key", api_key.parse().unwrap());
+        headers
+    }
+
+    fn config_with_keys(keys: &[&str]) -> AuthConfig {
+        AuthConfig {
+            api_keys: keys.iter().map(|k| k.to_string()).collect::<HashSet<_>>(),
+            enable_bearer_tokens: true,
+            require_auth: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unauthenticated_task_creation_rejected() {
+        let app = app(Arc::new(RateLimiter::new(10, Duration::from_secs(60))));
+
+        let response = app
+            .oneshot(create_request(None, [10, 0, 0, 1]))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_rotating_unverified_keys_still_rate_limited() {
+        // No API_KEYS configured, so every key is accepted but none identifies the caller
+        let app = app(Arc::new(RateLimiter::new(2, Duration::from_secs(60))));
+
+        for i in 0..2 {
+            let response = app
+                .clone()
+                .oneshot(create_request(
+                    Some(&format!("made-up-{}", i)),
+                    [10, 0, 0, 1],
+                ))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        let limited = app
+            .clone()
+            .oneshot(create_request(Some("made-up-2"), [10, 0, 0, 1]))
+            .await
+            .unwrap();
+        assert_eq!(limited.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(limited.headers().contains_key("retry-after"));
+
+        let other_peer = app
+            .oneshot(create_request(Some("made-up-3"), [10, 0, 0, 2]))
+            .await
+            .unwrap();
+        assert_eq!(other_peer.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn test_configured_keys_get_their_own_bucket() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(60));
+        let config = config_with_keys(&["caller-a", "caller-b"]);
+        let peer = Some(IpAddr::from([10, 0, 0, 1]));
+
+        for _ in 0..2 {
+            assert_eq!(
+                authorize_task_creation(&limiter, &config, &key_headers("caller-a"), peer).unwrap(),
+                "api_key:caller-a"
+            );
+        }
+        assert!(matches!(
+            authorize_task_creation(&limiter, &config, &key_headers("caller-a"), peer),
+            Err(ProtocolError::RateLimited { .. })
+        ));
+        assert!(authorize_task_creation(&limiter, &config, &key_headers("caller-b"), peer).is_ok());
+        // With a key list configured, unknown keys are rejected rather than bucketed
+        assert!(matches!(
+            authorize_task_creation(&limiter, &config, &key_headers("caller-z"), peer),
+            Err(ProtocolError::AuthenticationRequired)
+        ));
+    }
+
+    #[test]
+    fn test_window_resets() {
+        let limiter = RateLimiter::new(1, Duration::from_millis(20));
+        assert!(limiter.check("caller").is_ok());
+        assert!(limiter.check("caller").is_err());
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(limiter.check("caller").is_ok());
+    }
+}
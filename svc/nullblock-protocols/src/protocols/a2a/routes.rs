@@ -11,17 +11,26 @@ use super::handlers::{
     resubscribe_task, send_message, send_streaming_message, set_push_notification_config,
 };
 use super::jsonrpc::handle_jsonrpc;
+use super::rate_limit::task_creation_middleware;
 use super::sse::{message_stream_handler, task_subscribe_handler};
 use crate::server::AppState;
 
 pub fn create_a2a_routes(state: AppState) -> Router {
+    // Task-creating endpoints always require auth and are rate limited per caller
+    let task_creation_routes = Router::new()
+        .route("/messages", post(send_message))
+        .route("/messages/stream", post(send_streaming_message))
+        .layer(middleware::from_fn_with_state(
+            state.task_rate_limiter.clone(),
+            task_creation_middleware,
+        ));
+
     Router::new()
         // Agent Card endpoints
         .route("/card", get(get_agent_card))
         .route("/.well-known/agent-card.json", get(get_agent_card))
         // Message endpoints
-        .route("/messages", post(send_message))
-        .route("/messages/stream", post(send_streaming_message))
+        .merge(task_creation_routes)
         .route("/messages/sse", get(message_stream_handler))
         // Task endpoints
         .route("/tasks", get(list_tasks))
@@ -5,6 +5,7 @@ use tower_http::cors::CorsLayer;
 use tracing::info;
 
 use crate::health::health_check;
+use crate::protocols::a2a::rate_limit::RateLimiter;
 use crate::protocols::a2a::routes::create_a2a_routes;
 use crate::protocols::a2a::sse::KafkaSSEBridge;
 use crate::protocols::mcp::routes::create_mcp_routes;
@@ -16,6 +17,7 @@ pub struct AppState {
     pub erebus_base_url: String,
     pub arbfarm_url: String,
     pub kafka_bridge: Option<Arc<KafkaSSEBridge>>,
+    pub task_rate_limiter: Arc<RateLimiter>,
 }
 
 pub struct Server {
@@ -58,6 +60,7 @@ impl Server {
             erebus_base_url,
             arbfarm_url,
             kafka_bridge,
+            task_rate_limiter: Arc::new(RateLimiter::from_env()),
         };
 
         let a2a_router = create_a2a_routes(state.clone());
@@ -107,7 +110,13 @@ impl Server {
         info!("🧠 MCP Protocol Version: 2025-11-25",);
 
         let listener = tokio::net::TcpListener::bind(addr).await?;
-        axum::serve(listener, self.app).await?;
+        // Peer addresses key the task creation rate limit for unverified callers
+        axum::serve(
+            listener,
+            self.app
+                .into_make_service_with_connect_info::<std::net::SocketAddr>(),
+        )
+        .await?;
 
         Ok(())
     }